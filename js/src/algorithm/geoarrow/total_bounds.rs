@@ -0,0 +1,44 @@
+use crate::data::*;
+use crate::vector::*;
+use geoarrow::algorithm::native::bounding_rect::BoundingRect;
+use wasm_bindgen::prelude::*;
+
+fn bounds_to_vec(bounds: BoundingRect) -> Vec<f64> {
+    vec![bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()]
+}
+
+macro_rules! impl_total_bounds {
+    ($struct_name:ident) => {
+        #[wasm_bindgen]
+        impl $struct_name {
+            /// Return the bounding box of all geometries as `[minx, miny, maxx, maxy]`.
+            ///
+            /// Null geometries are skipped.
+            #[wasm_bindgen(js_name = totalBounds)]
+            pub fn total_bounds(&self) -> Vec<f64> {
+                use geoarrow::algorithm::native::TotalBounds;
+                bounds_to_vec(TotalBounds::total_bounds(&self.0))
+            }
+        }
+    };
+}
+
+impl_total_bounds!(PointData);
+impl_total_bounds!(LineStringData);
+impl_total_bounds!(PolygonData);
+impl_total_bounds!(MultiPointData);
+impl_total_bounds!(MultiLineStringData);
+impl_total_bounds!(MultiPolygonData);
+impl_total_bounds!(MixedGeometryData);
+impl_total_bounds!(GeometryCollectionData);
+impl_total_bounds!(RectData);
+
+impl_total_bounds!(PointVector);
+impl_total_bounds!(LineStringVector);
+impl_total_bounds!(PolygonVector);
+impl_total_bounds!(MultiPointVector);
+impl_total_bounds!(MultiLineStringVector);
+impl_total_bounds!(MultiPolygonVector);
+impl_total_bounds!(MixedGeometryVector);
+impl_total_bounds!(GeometryCollectionVector);
+impl_total_bounds!(RectVector);
@@ -1,4 +1,3 @@
-// use arrow2::error::Error as ArrowError;
 use arrow_wasm::arrow1::error::ArrowWasmError;
 use thiserror::Error;
 use wasm_bindgen::JsError;
@@ -94,6 +94,7 @@ class PointArray:
     def length(self) -> Float64Array: ...
     def simplify(self, epsilon: float) -> Self: ...
     def simplify_vw(self, epsilon: float) -> Self: ...
+    def total_bounds(self) -> Tuple[float, float, float, float]: ...
     @classmethod
     def from_arrow(cls, input: ArrowArrayExportable) -> Self: ...
     @classmethod
@@ -877,6 +878,7 @@ def simplify_vw(
     | MultiLineStringArray
     | MultiPolygonArray
 ): ...
+def total_bounds(input: ArrowArrayExportable) -> Tuple[float, float, float, float]: ...
 
 # I/O
 def read_csv(
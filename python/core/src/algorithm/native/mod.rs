@@ -2,3 +2,4 @@ pub mod concatenate;
 pub mod eq;
 pub mod explode;
 pub mod len;
+pub mod total_bounds;
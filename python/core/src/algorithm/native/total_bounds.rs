@@ -0,0 +1,61 @@
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::error::PyGeoArrowResult;
+use crate::ffi::from_python::import_arrow_c_array;
+use geoarrow::algorithm::native::bounding_rect::BoundingRect;
+use geoarrow::algorithm::native::TotalBounds;
+use geoarrow::array::from_arrow_array;
+use pyo3::prelude::*;
+
+fn bounds_to_tuple(bounds: BoundingRect) -> (f64, f64, f64, f64) {
+    (bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy())
+}
+
+/// Computes the total bounds (extent) of the geometry.
+///
+/// Args:
+///     input: input geometry array
+///
+/// Returns:
+///     tuple of (xmin, ymin, xmax, ymax).
+#[pyfunction]
+pub fn total_bounds(input: &PyAny) -> PyGeoArrowResult<(f64, f64, f64, f64)> {
+    let (array, field) = import_arrow_c_array(input)?;
+    let array = from_arrow_array(&array, &field)?;
+    Ok(bounds_to_tuple(array.as_ref().total_bounds()?))
+}
+
+macro_rules! impl_total_bounds {
+    ($struct_name:ident) => {
+        #[pymethods]
+        impl $struct_name {
+            /// Computes the total bounds (extent) of the geometry.
+            ///
+            /// Returns:
+            ///     tuple of (xmin, ymin, xmax, ymax).
+            pub fn total_bounds(&self) -> (f64, f64, f64, f64) {
+                bounds_to_tuple(self.0.total_bounds())
+            }
+        }
+    };
+}
+
+impl_total_bounds!(PointArray);
+impl_total_bounds!(LineStringArray);
+impl_total_bounds!(PolygonArray);
+impl_total_bounds!(MultiPointArray);
+impl_total_bounds!(MultiLineStringArray);
+impl_total_bounds!(MultiPolygonArray);
+impl_total_bounds!(MixedGeometryArray);
+impl_total_bounds!(GeometryCollectionArray);
+impl_total_bounds!(RectArray);
+
+impl_total_bounds!(ChunkedPointArray);
+impl_total_bounds!(ChunkedLineStringArray);
+impl_total_bounds!(ChunkedPolygonArray);
+impl_total_bounds!(ChunkedMultiPointArray);
+impl_total_bounds!(ChunkedMultiLineStringArray);
+impl_total_bounds!(ChunkedMultiPolygonArray);
+impl_total_bounds!(ChunkedMixedGeometryArray);
+impl_total_bounds!(ChunkedGeometryCollectionArray);
+impl_total_bounds!(ChunkedRectArray);
@@ -3,8 +3,10 @@ use arrow_array::types::ArrowPrimitiveType;
 use arrow_array::PrimitiveArray;
 use arrow_buffer::ArrowNativeType;
 
-/// An enum over primitive types defined by [`arrow2::types::NativeType`]. These include u8, i32,
-/// f64, etc.
+use crate::error::{GeoArrowError, Result};
+
+/// An enum over primitive types defined by [`ArrowPrimitiveType`]. These include u8, i32, f64,
+/// etc.
 ///
 /// [`IntoIterator`] is implemented for this, where it will iterate over the `Array` variant
 /// normally but will iterate over the `Scalar` variant forever.
@@ -17,6 +19,22 @@ where
     Array(PrimitiveArray<T>),
 }
 
+impl<T: ArrowPrimitiveType> BroadcastablePrimitive<T> {
+    /// Check that an `Array` variant has one value per geometry, as iterating over it would
+    /// otherwise silently stop at the shorter of the two. A `Scalar` matches any length.
+    pub(crate) fn check_len(&self, name: &str, expected: usize) -> Result<()> {
+        match self {
+            BroadcastablePrimitive::Array(arr) if arr.len() != expected => {
+                Err(GeoArrowError::General(format!(
+                    "{name} array has length {}, but expected {expected}",
+                    arr.len()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 pub enum BroadcastIter<'a, T: ArrowPrimitiveType> {
     Scalar(T::Native),
     Array(ArrayIter<&'a PrimitiveArray<T>>),
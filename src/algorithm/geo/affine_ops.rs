@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
+use crate::algorithm::native::simd;
 use crate::array::*;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
-use geo::{AffineTransform, MapCoords};
+use geo::{AffineTransform, Coord, MapCoords};
 
 /// Apply an [`AffineTransform`] like [`scale`](AffineTransform::scale),
 /// [`skew`](AffineTransform::skew), or [`rotate`](AffineTransform::rotate) to geometries.
@@ -58,25 +59,43 @@ pub trait AffineOps<Rhs> {
 // │ Implementations for RHS scalars │
 // └─────────────────────────────────┘
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
+/// Transform the coordinate buffer directly. Offsets and validity are reused as-is.
+fn transform_coords(coords: &CoordBuffer, transform: &AffineTransform) -> CoordBuffer {
+    simd::map_xy(coords, |x, y| {
+        let coord = transform.apply(Coord { x, y });
+        (coord.x, coord.y)
+    })
+}
+
 impl AffineOps<&AffineTransform> for PointArray {
     type Output = Self;
 
     fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
+        self.clone()
+            .with_coords(transform_coords(&self.coords, transform))
+    }
+}
 
-        self.iter_geo().for_each(|maybe_g| {
-            output_array.push_point(
-                maybe_g
-                    .map(|geom| geom.map_coords(|coord| transform.apply(coord)))
-                    .as_ref(),
-            )
-        });
+/// Implementation that transforms the coordinate buffer
+macro_rules! coords_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> AffineOps<&AffineTransform> for $type {
+            type Output = Self;
 
-        output_array.finish()
-    }
+            fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
+                self.clone()
+                    .with_coords(transform_coords(&self.coords, transform))
+            }
+        }
+    };
 }
 
+coords_impl!(LineStringArray<O>);
+coords_impl!(PolygonArray<O>);
+coords_impl!(MultiPointArray<O>);
+coords_impl!(MultiLineStringArray<O>);
+coords_impl!(MultiPolygonArray<O>);
+
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
     ($type:ty, $builder_type:ty, $push_func:ident) => {
@@ -102,19 +121,6 @@ macro_rules! iter_geo_impl {
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
-iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
-iter_geo_impl!(
-    MultiLineStringArray<O>,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
-);
-iter_geo_impl!(
-    MultiPolygonArray<O>,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
-);
 iter_geo_impl!(
     MixedGeometryArray<O>,
     MixedGeometryBuilder<O>,
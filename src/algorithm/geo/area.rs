@@ -1,11 +1,11 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
-use crate::datatypes::GeoDataType;
-use crate::error::{GeoArrowError, Result};
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary};
+use crate::error::Result;
 use crate::trait_::GeometryScalarTrait;
 use crate::GeometryArrayTrait;
+use arrow_array::types::{ArrowDictionaryKeyType, RunEndIndexType};
 use arrow_array::{Float64Array, OffsetSizeTrait};
 use geo::prelude::Area as GeoArea;
 
@@ -105,78 +105,65 @@ iter_geo_impl!(MixedGeometryArray<O>);
 iter_geo_impl!(GeometryCollectionArray<O>);
 iter_geo_impl!(WKBArray<O>);
 
-impl Area for &dyn GeometryArrayTrait {
+impl_dyn_unary!(Area -> Float64Array {
+    fn signed_area(&self);
+    fn unsigned_area(&self);
+} [
+    Point => as_point,
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiPoint => as_multi_point,
+    LargeMultiPoint => as_large_multi_point,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+    Mixed => as_mixed,
+    LargeMixed => as_large_mixed,
+    GeometryCollection => as_geometry_collection,
+    LargeGeometryCollection => as_large_geometry_collection,
+]);
+
+impl_chunked_unary!(dyn Area {
+    fn signed_area(&self);
+    fn unsigned_area(&self);
+});
+
+impl<K: ArrowDictionaryKeyType, G: GeometryArrayTrait + Area<Output = Float64Array>> Area
+    for DictionaryGeometryArray<K, G>
+{
     type Output = Result<Float64Array>;
 
     fn signed_area(&self) -> Self::Output {
-        let result = match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().signed_area(),
-            GeoDataType::LineString(_) => self.as_line_string().signed_area(),
-            GeoDataType::LargeLineString(_) => self.as_large_line_string().signed_area(),
-            GeoDataType::Polygon(_) => self.as_polygon().signed_area(),
-            GeoDataType::LargePolygon(_) => self.as_large_polygon().signed_area(),
-            GeoDataType::MultiPoint(_) => self.as_multi_point().signed_area(),
-            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().signed_area(),
-            GeoDataType::MultiLineString(_) => self.as_multi_line_string().signed_area(),
-            GeoDataType::LargeMultiLineString(_) => self.as_large_multi_line_string().signed_area(),
-            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().signed_area(),
-            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().signed_area(),
-            GeoDataType::Mixed(_) => self.as_mixed().signed_area(),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().signed_area(),
-            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().signed_area(),
-            GeoDataType::LargeGeometryCollection(_) => {
-                self.as_large_geometry_collection().signed_area()
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        self.take_values(&self.values().signed_area())
     }
 
     fn unsigned_area(&self) -> Self::Output {
-        let result = match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().unsigned_area(),
-            GeoDataType::LineString(_) => self.as_line_string().unsigned_area(),
-            GeoDataType::LargeLineString(_) => self.as_large_line_string().unsigned_area(),
-            GeoDataType::Polygon(_) => self.as_polygon().unsigned_area(),
-            GeoDataType::LargePolygon(_) => self.as_large_polygon().unsigned_area(),
-            GeoDataType::MultiPoint(_) => self.as_multi_point().unsigned_area(),
-            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().unsigned_area(),
-            GeoDataType::MultiLineString(_) => self.as_multi_line_string().unsigned_area(),
-            GeoDataType::LargeMultiLineString(_) => {
-                self.as_large_multi_line_string().unsigned_area()
-            }
-            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().unsigned_area(),
-            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().unsigned_area(),
-            GeoDataType::Mixed(_) => self.as_mixed().unsigned_area(),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().unsigned_area(),
-            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().unsigned_area(),
-            GeoDataType::LargeGeometryCollection(_) => {
-                self.as_large_geometry_collection().unsigned_area()
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        self.take_values(&self.values().unsigned_area())
     }
 }
 
-impl<G: GeometryArrayTrait> Area for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<Float64Array>>;
+impl<R: RunEndIndexType, G: GeometryArrayTrait + Area<Output = Float64Array>> Area
+    for RunEndEncodedGeometryArray<R, G>
+{
+    type Output = Result<Float64Array>;
 
     fn signed_area(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().signed_area())?
-            .try_into()
+        self.take_values(&self.values().signed_area())
     }
 
     fn unsigned_area(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().unsigned_area())?
-            .try_into()
+        self.take_values(&self.values().unsigned_area())
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test::polygon::p_array;
+    use crate::test::polygon::{p0, p1, p_array};
+    use arrow_array::types::Int32Type;
 
     #[test]
     fn area() {
@@ -184,4 +171,21 @@ mod test {
         let area = arr.unsigned_area();
         assert_eq!(area, Float64Array::new(vec![28., 18.].into(), None));
     }
+
+    #[test]
+    fn dictionary_area() {
+        let arr: PolygonArray<i32> = vec![p0(), p1(), p0()].as_slice().into();
+        let dict = DictionaryGeometryArray::<Int32Type, _>::try_encode(&arr).unwrap();
+        let area = dict.unsigned_area().unwrap();
+        assert_eq!(area, arr.unsigned_area());
+    }
+
+    #[test]
+    fn run_end_encoded_area() {
+        let arr: PolygonArray<i32> = vec![p0(), p0(), p1(), p0()].as_slice().into();
+        let ree = RunEndEncodedGeometryArray::<Int32Type, _>::try_encode(&arr).unwrap();
+        assert_eq!(ree.values().len(), 3);
+        let area = ree.unsigned_area().unwrap();
+        assert_eq!(area, arr.unsigned_area());
+    }
 }
@@ -0,0 +1,115 @@
+use crate::array::PointArray;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::Float64Builder;
+use arrow_array::Float64Array;
+use geo::{GeodesicBearing as _GeodesicBearing, Point, RhumbBearing as _RhumbBearing};
+
+/// Returns the bearing to another point on a geodesic.
+///
+/// This uses the geodesic methods given by [Karney (2013)].
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicBearing<Rhs = Self> {
+    /// Returns the bearing from each point to the corresponding point of `rhs`, in degrees
+    /// clockwise from north in the range `[-180, 180]`. Coordinates must be longitude and
+    /// latitude in degrees. If either point is null, the bearing is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::GeodesicBearing;
+    /// use geoarrow::array::PointArray;
+    /// use geo::point;
+    ///
+    /// let from: PointArray = vec![point!(x: 9., y: 10.)].as_slice().into();
+    /// let to: PointArray = vec![point!(x: 9., y: 11.)].as_slice().into();
+    /// let bearing = from.geodesic_bearing(&to);
+    /// assert_eq!(bearing.value(0), 0.);
+    /// ```
+    fn geodesic_bearing(&self, rhs: &Rhs) -> Float64Array;
+}
+
+/// Returns the bearing to another point along a rhumb line, i.e. a line of constant bearing.
+pub trait RhumbBearing<Rhs = Self> {
+    /// Returns the bearing from each point to the corresponding point of `rhs`, in degrees
+    /// clockwise from north in the range `[0, 360)`. Coordinates must be longitude and latitude
+    /// in degrees. If either point is null, the bearing is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::RhumbBearing;
+    /// use geoarrow::array::PointArray;
+    /// use geo::point;
+    ///
+    /// let from: PointArray = vec![point!(x: 9., y: 10.)].as_slice().into();
+    /// let to: PointArray = vec![point!(x: 8., y: 10.)].as_slice().into();
+    /// let bearing = from.rhumb_bearing(&to);
+    /// assert_eq!(bearing.value(0), 270.);
+    /// ```
+    fn rhumb_bearing(&self, rhs: &Rhs) -> Float64Array;
+}
+
+fn pairwise_bearing(
+    from: &PointArray,
+    to: &PointArray,
+    op: impl Fn(Point, Point) -> f64,
+) -> Float64Array {
+    assert_eq!(from.len(), to.len());
+    let mut output_array = Float64Builder::with_capacity(from.len());
+
+    from.iter_geo()
+        .zip(to.iter_geo())
+        .for_each(|(first, second)| match (first, second) {
+            (Some(first), Some(second)) => output_array.append_value(op(first, second)),
+            _ => output_array.append_null(),
+        });
+
+    output_array.finish()
+}
+
+impl GeodesicBearing for PointArray {
+    fn geodesic_bearing(&self, rhs: &Self) -> Float64Array {
+        pairwise_bearing(self, rhs, |first, second| first.geodesic_bearing(second))
+    }
+}
+
+impl RhumbBearing for PointArray {
+    fn rhumb_bearing(&self, rhs: &Self) -> Float64Array {
+        pairwise_bearing(self, rhs, |first, second| first.rhumb_bearing(second))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Array;
+    use geo::point;
+
+    #[test]
+    fn geodesic_and_rhumb_differ() {
+        let from: PointArray = vec![Some(point!(x: -74.006, y: 40.7128)), None].into();
+        let to: PointArray = vec![
+            Some(point!(x: -0.1278, y: 51.5074)),
+            Some(point!(x: 0., y: 0.)),
+        ]
+        .into();
+
+        // The great circle from New York to London starts out north of the rhumb line
+        let geodesic = from.geodesic_bearing(&to);
+        let rhumb = from.rhumb_bearing(&to);
+        assert!((geodesic.value(0) - 51.2).abs() < 0.1);
+        assert!((rhumb.value(0) - 78.0).abs() < 0.1);
+        assert!(geodesic.is_null(1));
+        assert!(rhumb.is_null(1));
+    }
+}
@@ -1,9 +1,10 @@
 use crate::array::*;
-use crate::chunked_array::ChunkedGeometryArray;
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
+use arrow_array::types::{ArrowDictionaryKeyType, RunEndIndexType};
 use arrow_array::OffsetSizeTrait;
 use geo::algorithm::bounding_rect::BoundingRect as GeoBoundingRect;
 use geo::Rect;
@@ -100,17 +101,34 @@ impl BoundingRect for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().bounding_rect()
             }
+            GeoDataType::WKB => self.as_wkb().bounding_rect(),
+            GeoDataType::LargeWKB => self.as_large_wkb().bounding_rect(),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
         Ok(result)
     }
 }
 
-impl<G: GeometryArrayTrait> BoundingRect for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedGeometryArray<RectArray>>;
+impl_chunked_unary!(dyn BoundingRect {
+    fn bounding_rect(&self);
+});
+
+impl<K: ArrowDictionaryKeyType, G: GeometryArrayTrait + BoundingRect<Output = RectArray>>
+    BoundingRect for DictionaryGeometryArray<K, G>
+{
+    type Output = DictionaryGeometryArray<K, RectArray>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.map_values(|values| values.bounding_rect())
+    }
+}
+
+impl<R: RunEndIndexType, G: GeometryArrayTrait + BoundingRect<Output = RectArray>> BoundingRect
+    for RunEndEncodedGeometryArray<R, G>
+{
+    type Output = RunEndEncodedGeometryArray<R, RectArray>;
 
     fn bounding_rect(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().bounding_rect())?
-            .try_into()
+        self.map_values(|values| values.bounding_rect())
     }
 }
@@ -1,5 +1,5 @@
 use crate::array::*;
-use crate::chunked_array::ChunkedGeometryArray;
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -80,10 +80,6 @@ impl Center for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> Center for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedGeometryArray<PointArray>>;
-
-    fn center(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().center())?.try_into()
-    }
-}
+impl_chunked_unary!(dyn Center {
+    fn center(&self);
+});
@@ -1,9 +1,10 @@
 use crate::array::*;
-use crate::chunked_array::ChunkedGeometryArray;
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
+use arrow_array::types::{ArrowDictionaryKeyType, RunEndIndexType};
 use arrow_array::OffsetSizeTrait;
 use geo::algorithm::centroid::Centroid as GeoCentroid;
 
@@ -127,10 +128,26 @@ impl Centroid for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> Centroid for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedGeometryArray<PointArray>>;
+impl_chunked_unary!(dyn Centroid {
+    fn centroid(&self);
+});
+
+impl<K: ArrowDictionaryKeyType, G: GeometryArrayTrait + Centroid<Output = PointArray>> Centroid
+    for DictionaryGeometryArray<K, G>
+{
+    type Output = DictionaryGeometryArray<K, PointArray>;
+
+    fn centroid(&self) -> Self::Output {
+        self.map_values(|values| values.centroid())
+    }
+}
+
+impl<R: RunEndIndexType, G: GeometryArrayTrait + Centroid<Output = PointArray>> Centroid
+    for RunEndEncodedGeometryArray<R, G>
+{
+    type Output = RunEndEncodedGeometryArray<R, PointArray>;
 
     fn centroid(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().centroid())?.try_into()
+        self.map_values(|values| values.centroid())
     }
 }
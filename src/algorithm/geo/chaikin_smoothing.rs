@@ -1,11 +1,6 @@
-use std::sync::Arc;
-
 use crate::array::*;
-use crate::chunked_array::*;
-use crate::datatypes::GeoDataType;
-use crate::error::{GeoArrowError, Result};
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary};
 use crate::trait_::GeometryArrayAccessor;
-use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::ChaikinSmoothing as _ChaikinSmoothing;
 
@@ -52,67 +47,19 @@ iter_geo_impl!(PolygonArray<O>, geo::Polygon);
 iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
 iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
 
-impl ChaikinSmoothing for &dyn GeometryArrayTrait {
-    type Output = Result<Arc<dyn GeometryArrayTrait>>;
-
-    fn chaikin_smoothing(&self, n_iterations: u32) -> Self::Output {
-        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
-            // GeoDataType::Point(_) => Arc::new(self.as_point().chaikin_smoothing(n_iterations)),
-            GeoDataType::LineString(_) => {
-                Arc::new(self.as_line_string().chaikin_smoothing(n_iterations))
-            }
-            GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().chaikin_smoothing(n_iterations))
-            }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().chaikin_smoothing(n_iterations)),
-            GeoDataType::LargePolygon(_) => {
-                Arc::new(self.as_large_polygon().chaikin_smoothing(n_iterations))
-            }
-            // GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().chaikin_smoothing(n_iterations)),
-            // GeoDataType::LargeMultiPoint(_) => {
-            //     Arc::new(self.as_large_multi_point().chaikin_smoothing(n_iterations))
-            // }
-            GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().chaikin_smoothing(n_iterations))
-            }
-            GeoDataType::LargeMultiLineString(_) => Arc::new(
-                self.as_large_multi_line_string()
-                    .chaikin_smoothing(n_iterations),
-            ),
-            GeoDataType::MultiPolygon(_) => {
-                Arc::new(self.as_multi_polygon().chaikin_smoothing(n_iterations))
-            }
-            GeoDataType::LargeMultiPolygon(_) => Arc::new(
-                self.as_large_multi_polygon()
-                    .chaikin_smoothing(n_iterations),
-            ),
-            // GeoDataType::Mixed(_) => self.as_mixed().chaikin_smoothing(n_iterations),
-            // GeoDataType::LargeMixed(_) => self.as_large_mixed().chaikin_smoothing(n_iterations
-            // GeoDataType::GeometryCollection(_) => self.as_geometry_collection().chaikin_smoothing(n_iterations
-            // GeoDataType::LargeGeometryCollection(_) => {
-            //     self.as_large_geometry_collection().chaikin_smoothing(n_iterations
-            // }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
-    }
-}
-
-macro_rules! impl_chunked {
-    ($chunked_array:ty) => {
-        impl<O: OffsetSizeTrait> ChaikinSmoothing for $chunked_array {
-            type Output = Self;
-
-            fn chaikin_smoothing(&self, n_iterations: u32) -> Self::Output {
-                self.map(|chunk| chunk.chaikin_smoothing(n_iterations.into()))
-                    .try_into()
-                    .unwrap()
-            }
-        }
-    };
-}
+impl_dyn_unary!(ChaikinSmoothing -> Arc<dyn GeometryArrayTrait> {
+    fn chaikin_smoothing(&self, n_iterations: u32);
+} [
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+]);
 
-impl_chunked!(ChunkedLineStringArray<O>);
-impl_chunked!(ChunkedPolygonArray<O>);
-impl_chunked!(ChunkedMultiLineStringArray<O>);
-impl_chunked!(ChunkedMultiPolygonArray<O>);
+impl_chunked_unary!(ChaikinSmoothing {
+    fn chaikin_smoothing(&self, n_iterations: u32);
+});
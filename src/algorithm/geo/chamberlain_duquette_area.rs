@@ -1,6 +1,6 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -222,24 +222,7 @@ impl ChamberlainDuquetteArea for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> ChamberlainDuquetteArea for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<Float64Array>>;
-
-    fn chamberlain_duquette_signed_area(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.as_ref().chamberlain_duquette_signed_area()?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
-    }
-
-    fn chamberlain_duquette_unsigned_area(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.as_ref().chamberlain_duquette_unsigned_area()?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
-    }
-}
+impl_chunked_unary!(dyn ChamberlainDuquetteArea {
+    fn chamberlain_duquette_signed_area(&self);
+    fn chamberlain_duquette_unsigned_area(&self);
+});
@@ -0,0 +1,181 @@
+use crate::array::*;
+use crate::chunked_array::impl_dyn_unary;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geo::{Closest, ClosestPoint as _ClosestPoint};
+
+/// The closest point of each geometry to a query point, as returned by
+/// [`ClosestPoint::closest_point`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosestPoints {
+    /// The point on each geometry nearest to the query point. Null where either input is null,
+    /// or where the closest point is indeterminate, e.g. because the geometry is empty.
+    pub points: PointArray,
+
+    /// Whether the query point lies on the geometry, in which case the closest point is the query
+    /// point itself. Null where `points` is null.
+    pub intersects: BooleanArray,
+}
+
+/// Find the point on each geometry that is closest to the corresponding query point.
+///
+/// # Panics
+///
+/// Panics if the arrays have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::ClosestPoint;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{line_string, point};
+///
+/// let lines: LineStringArray<i32> = vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+/// ]
+/// .as_slice()
+/// .into();
+/// let points: PointArray = vec![point!(x: 3., y: 4.), point!(x: 5., y: 0.)].as_slice().into();
+///
+/// let closest = lines.closest_point(&points);
+/// assert_eq!(closest.points.value_as_geo(0), point!(x: 3., y: 0.));
+/// assert!(!closest.intersects.value(0));
+/// assert!(closest.intersects.value(1));
+/// ```
+pub trait ClosestPoint<Rhs = PointArray> {
+    type Output;
+
+    fn closest_point(&self, points: &Rhs) -> Self::Output;
+}
+
+/// Collect the closest point of each pair of geometry and query point.
+fn closest_points<G: _ClosestPoint<f64>>(
+    geoms: impl Iterator<Item = Option<G>>,
+    geoms_len: usize,
+    points: &PointArray,
+) -> ClosestPoints {
+    assert_eq!(geoms_len, points.len());
+    let mut output_points = PointBuilder::with_capacity(points.len());
+    let mut intersects = BooleanBuilder::with_capacity(points.len());
+
+    geoms
+        .zip(points.iter_geo())
+        .for_each(|(maybe_g, maybe_point)| {
+            let closest = match (maybe_g, maybe_point) {
+                (Some(geom), Some(point)) => geom.closest_point(&point),
+                _ => Closest::Indeterminate,
+            };
+            match closest {
+                Closest::Intersection(point) => {
+                    output_points.push_point(Some(&point));
+                    intersects.append_value(true);
+                }
+                Closest::SinglePoint(point) => {
+                    output_points.push_point(Some(&point));
+                    intersects.append_value(false);
+                }
+                Closest::Indeterminate => {
+                    output_points.push_null();
+                    intersects.append_null();
+                }
+            }
+        });
+
+    ClosestPoints {
+        points: output_points.into(),
+        intersects: intersects.finish(),
+    }
+}
+
+impl ClosestPoint for PointArray {
+    type Output = ClosestPoints;
+
+    fn closest_point(&self, points: &PointArray) -> Self::Output {
+        closest_points(self.iter_geo(), self.len(), points)
+    }
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> ClosestPoint for $type {
+            type Output = ClosestPoints;
+
+            fn closest_point(&self, points: &PointArray) -> Self::Output {
+                closest_points(self.iter_geo(), self.len(), points)
+            }
+        }
+    };
+}
+
+iter_geo_impl!(LineStringArray<O>);
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiPointArray<O>);
+iter_geo_impl!(MultiLineStringArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+impl_dyn_unary!(ClosestPoint -> ClosestPoints {
+    fn closest_point(&self, points: &PointArray);
+} [
+    Point => as_point,
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiPoint => as_multi_point,
+    LargeMultiPoint => as_large_multi_point,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+    Mixed => as_mixed,
+    LargeMixed => as_large_mixed,
+    GeometryCollection => as_geometry_collection,
+    LargeGeometryCollection => as_large_geometry_collection,
+]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use arrow_array::Array;
+    use geo::point;
+
+    #[test]
+    fn nulls_and_vertices() {
+        let polygons = p_array();
+        let inside = polygons.value_as_geo(0).exterior().0[0];
+        let points: PointArray = vec![Some(geo::Point(inside)), None].into();
+
+        let closest = polygons.closest_point(&points);
+        assert_eq!(closest.points.value_as_geo(0), geo::Point(inside));
+        assert!(closest.intersects.value(0));
+        assert!(closest.points.get_as_geo(1).is_none());
+        assert!(closest.intersects.is_null(1));
+
+        let dyn_array: &dyn GeometryArrayTrait = &polygons;
+        assert_eq!(dyn_array.closest_point(&points).unwrap(), closest);
+    }
+
+    #[test]
+    fn empty_is_indeterminate() {
+        let multi_points: MultiPointArray<i32> = vec![
+            geo::MultiPoint::new(vec![]),
+            geo::MultiPoint::new(vec![point!(x: 1., y: 0.)]),
+        ]
+        .as_slice()
+        .into();
+        let points: PointArray = vec![point!(x: 0., y: 0.); 2].as_slice().into();
+        let closest = multi_points.closest_point(&points);
+        assert!(closest.points.get_as_geo(0).is_none());
+        assert_eq!(closest.points.value_as_geo(1), point!(x: 1., y: 0.));
+        assert!(!closest.intersects.value(1));
+    }
+}
@@ -1,11 +1,6 @@
-use std::sync::Arc;
-
 use crate::array::*;
-use crate::chunked_array::*;
-use crate::datatypes::GeoDataType;
-use crate::error::{GeoArrowError, Result};
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary};
 use crate::trait_::GeometryArrayAccessor;
-use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::Densify as _Densify;
 
@@ -54,48 +49,19 @@ iter_geo_impl!(PolygonArray<O>, geo::Polygon);
 iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
 iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
 
-impl Densify for &dyn GeometryArrayTrait {
-    type Output = Result<Arc<dyn GeometryArrayTrait>>;
-
-    fn densify(&self, max_distance: f64) -> Self::Output {
-        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
-            GeoDataType::LineString(_) => Arc::new(self.as_line_string().densify(max_distance)),
-            GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().densify(max_distance))
-            }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().densify(max_distance)),
-            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().densify(max_distance)),
-            GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().densify(max_distance))
-            }
-            GeoDataType::LargeMultiLineString(_) => {
-                Arc::new(self.as_large_multi_line_string().densify(max_distance))
-            }
-            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().densify(max_distance)),
-            GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().densify(max_distance))
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
-    }
-}
-
-macro_rules! impl_chunked {
-    ($struct_name:ty) => {
-        impl<O: OffsetSizeTrait> Densify for $struct_name {
-            type Output = $struct_name;
-
-            fn densify(&self, max_distance: f64) -> Self::Output {
-                self.map(|chunk| chunk.densify(max_distance))
-                    .try_into()
-                    .unwrap()
-            }
-        }
-    };
-}
+impl_dyn_unary!(Densify -> Arc<dyn GeometryArrayTrait> {
+    fn densify(&self, max_distance: f64);
+} [
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+]);
 
-impl_chunked!(ChunkedLineStringArray<O>);
-impl_chunked!(ChunkedPolygonArray<O>);
-impl_chunked!(ChunkedMultiLineStringArray<O>);
-impl_chunked!(ChunkedMultiPolygonArray<O>);
+impl_chunked_unary!(Densify {
+    fn densify(&self, max_distance: f64);
+});
@@ -1,5 +1,5 @@
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -106,11 +106,6 @@ impl HasDimensions for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> HasDimensions for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<BooleanArray>>;
-
-    fn is_empty(&self) -> Self::Output {
-        self.try_map(|chunk| HasDimensions::is_empty(&chunk.as_ref()))?
-            .try_into()
-    }
-}
+impl_chunked_unary!(dyn HasDimensions {
+    fn is_empty(&self);
+});
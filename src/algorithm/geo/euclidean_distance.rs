@@ -265,3 +265,92 @@ iter_geo_impl_scalar!(MultiPolygonArray<O>, Point<'a>);
 // iter_geo_impl_scalar!(MultiPolygonArray<O>, MultiPoint<'a, O>);
 // iter_geo_impl_scalar!(MultiPolygonArray<O>, MultiLineString<'a, O>);
 // iter_geo_impl_scalar!(MultiPolygonArray<O>, MultiPolygon<'a, O>);
+
+// ┌─────────────────────────────────────┐
+// │ Implementations for RHS geo scalars │
+// └─────────────────────────────────────┘
+
+macro_rules! non_generic_iter_geo_impl_geo_scalar {
+    ($first:ty, $second:ty) => {
+        impl EuclideanDistance<$second> for $first {
+            fn euclidean_distance(&self, other: &$second) -> Float64Array {
+                let mut output_array = Float64Builder::with_capacity(self.len());
+
+                self.iter_geo().for_each(|maybe_geom| {
+                    let output = maybe_geom.map(|geom| geom.euclidean_distance(other));
+                    output_array.append_option(output)
+                });
+
+                output_array.finish()
+            }
+        }
+    };
+}
+
+// Implementations on PointArray
+non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::Point);
+non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::LineString);
+non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::Polygon);
+non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::MultiPoint);
+non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::MultiLineString);
+non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::MultiPolygon);
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl_geo_scalar {
+    ($first:ty, $second:ty) => {
+        impl<O: OffsetSizeTrait> EuclideanDistance<$second> for $first {
+            fn euclidean_distance(&self, other: &$second) -> Float64Array {
+                let mut output_array = Float64Builder::with_capacity(self.len());
+
+                self.iter_geo().for_each(|maybe_geom| {
+                    let output = maybe_geom.map(|geom| geom.euclidean_distance(other));
+                    output_array.append_option(output)
+                });
+
+                output_array.finish()
+            }
+        }
+    };
+}
+
+// Implementations on LineStringArray
+iter_geo_impl_geo_scalar!(LineStringArray<O>, geo::Point);
+iter_geo_impl_geo_scalar!(LineStringArray<O>, geo::LineString);
+iter_geo_impl_geo_scalar!(LineStringArray<O>, geo::Polygon);
+
+// Implementations on PolygonArray
+iter_geo_impl_geo_scalar!(PolygonArray<O>, geo::Point);
+iter_geo_impl_geo_scalar!(PolygonArray<O>, geo::LineString);
+iter_geo_impl_geo_scalar!(PolygonArray<O>, geo::Polygon);
+
+// Implementations on MultiPointArray
+iter_geo_impl_geo_scalar!(MultiPointArray<O>, geo::Point);
+
+// Implementations on MultiLineStringArray
+iter_geo_impl_geo_scalar!(MultiLineStringArray<O>, geo::Point);
+
+// Implementations on MultiPolygonArray
+iter_geo_impl_geo_scalar!(MultiPolygonArray<O>, geo::Point);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Array;
+    use geo::{line_string, point, polygon};
+
+    #[test]
+    fn broadcast_geo_scalar() {
+        let array: PointArray =
+            vec![Some(point!(x: 0., y: 0.)), None, Some(point!(x: 3., y: 4.))].into();
+        let distances = array.euclidean_distance(&point!(x: 0., y: 0.));
+        assert_eq!(distances.value(0), 0.);
+        assert!(distances.is_null(1));
+        assert_eq!(distances.value(2), 5.);
+
+        let lines: LineStringArray<i32> = vec![line_string![(x: 0., y: 2.), (x: 4., y: 2.)]]
+            .as_slice()
+            .into();
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert_eq!(lines.euclidean_distance(&square).value(0), 1.);
+    }
+}
@@ -1,13 +1,12 @@
 use crate::algorithm::geo::utils::zeroes;
-use crate::algorithm::native::Unary;
+use crate::array::util::OffsetBufferUtils;
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::trait_::GeometryScalarTrait;
 use crate::GeometryArrayTrait;
+use arrow_array::types::{ArrowDictionaryKeyType, RunEndIndexType};
 use arrow_array::{Float64Array, OffsetSizeTrait};
-use geo::EuclideanLength as _EuclideanLength;
 
 pub trait EuclideanLength {
     type Output;
@@ -61,21 +60,64 @@ macro_rules! zero_impl {
 
 zero_impl!(MultiPointArray<O>);
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> EuclideanLength for $type {
-            type Output = Float64Array;
+/// The planar length of the linestring made up of the coordinates `start..end` of `coords`.
+///
+/// This reads directly from the underlying coordinate buffers instead of constructing
+/// intermediate [`geo`] objects.
+fn coords_euclidean_length(coords: &CoordBuffer, start: usize, end: usize) -> f64 {
+    if end <= start + 1 {
+        return 0.;
+    }
 
-            fn euclidean_length(&self) -> Self::Output {
-                self.unary_primitive(|geom| geom.to_geo().euclidean_length())
-            }
+    match coords {
+        CoordBuffer::Interleaved(cb) => cb.coords[start * 2..end * 2]
+            .chunks_exact(2)
+            .zip(cb.coords[(start + 1) * 2..end * 2].chunks_exact(2))
+            .map(|(a, b)| (b[0] - a[0]).hypot(b[1] - a[1]))
+            .sum(),
+        CoordBuffer::Separated(cb) => {
+            let x = &cb.x[start..end];
+            let y = &cb.y[start..end];
+            x.windows(2)
+                .zip(y.windows(2))
+                .map(|(x, y)| (x[1] - x[0]).hypot(y[1] - y[0]))
+                .sum()
         }
-    };
+    }
+}
+
+impl<O: OffsetSizeTrait> EuclideanLength for LineStringArray<O> {
+    type Output = Float64Array;
+
+    fn euclidean_length(&self) -> Self::Output {
+        let values = (0..self.len())
+            .map(|geom_idx| {
+                let (start, end) = self.geom_offsets.start_end(geom_idx);
+                coords_euclidean_length(&self.coords, start, end)
+            })
+            .collect::<Vec<_>>();
+        Float64Array::new(values.into(), self.nulls().cloned())
+    }
 }
 
-iter_geo_impl!(LineStringArray<O>);
-iter_geo_impl!(MultiLineStringArray<O>);
+impl<O: OffsetSizeTrait> EuclideanLength for MultiLineStringArray<O> {
+    type Output = Float64Array;
+
+    fn euclidean_length(&self) -> Self::Output {
+        let values = (0..self.len())
+            .map(|geom_idx| {
+                let (start_line, end_line) = self.geom_offsets.start_end(geom_idx);
+                (start_line..end_line)
+                    .map(|line_idx| {
+                        let (start, end) = self.ring_offsets.start_end(line_idx);
+                        coords_euclidean_length(&self.coords, start, end)
+                    })
+                    .sum()
+            })
+            .collect::<Vec<_>>();
+        Float64Array::new(values.into(), self.nulls().cloned())
+    }
+}
 
 impl EuclideanLength for &dyn GeometryArrayTrait {
     type Output = Result<Float64Array>;
@@ -132,12 +174,39 @@ chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
 chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
 chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
 
+impl<K, G> EuclideanLength for DictionaryGeometryArray<K, G>
+where
+    K: ArrowDictionaryKeyType,
+    G: GeometryArrayTrait + EuclideanLength<Output = Float64Array>,
+{
+    type Output = Result<Float64Array>;
+
+    fn euclidean_length(&self) -> Self::Output {
+        self.take_values(&self.values().euclidean_length())
+    }
+}
+
+impl<R, G> EuclideanLength for RunEndEncodedGeometryArray<R, G>
+where
+    R: RunEndIndexType,
+    G: GeometryArrayTrait + EuclideanLength<Output = Float64Array>,
+{
+    type Output = Result<Float64Array>;
+
+    fn euclidean_length(&self) -> Self::Output {
+        self.take_values(&self.values().euclidean_length())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::array::LineStringArray;
+    use crate::test::multilinestring::ml_array;
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
     use arrow_array::Array;
     use geo::line_string;
+    use geo::EuclideanLength as _EuclideanLength;
 
     #[test]
     fn euclidean_length_geoarrow_linestring() {
@@ -156,4 +225,27 @@ mod tests {
         assert_eq!(expected, result_array.value(0).round());
         assert!(result_array.is_valid(0));
     }
+
+    #[test]
+    fn euclidean_length_matches_geo() {
+        let arr = ml_array();
+        let expected: Vec<f64> = arr
+            .iter_geo_values()
+            .map(|geom| geom.euclidean_length())
+            .collect();
+
+        let interleaved = arr.euclidean_length();
+        let separated = arr
+            .clone()
+            .into_coord_type(CoordType::Separated)
+            .euclidean_length();
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(interleaved.value(i), *expected);
+            assert_eq!(separated.value(i), *expected);
+        }
+
+        let sliced = arr.slice(1, 1).euclidean_length();
+        assert_eq!(sliced.len(), 1);
+        assert_eq!(sliced.value(0), expected[1]);
+    }
 }
@@ -0,0 +1,176 @@
+use crate::array::*;
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary, ChunkedOutput};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geo::algorithm::extremes::{Extremes as GeoExtremes, Outcome};
+
+/// The vertices of each geometry with the minimum and maximum x and y coordinates, as returned by
+/// [`Extremes::extremes`].
+///
+/// Each array has one point per input geometry, which is null where the input geometry is null
+/// or empty. Only the exterior coordinates of polygons are considered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtremePoints {
+    /// The west-most vertex of each geometry.
+    pub x_min: PointArray,
+
+    /// The south-most vertex of each geometry.
+    pub y_min: PointArray,
+
+    /// The east-most vertex of each geometry.
+    pub x_max: PointArray,
+
+    /// The north-most vertex of each geometry.
+    pub y_max: PointArray,
+}
+
+/// Find the extreme vertices of each geometry.
+///
+/// Where several vertices have the same extreme coordinate, the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::Extremes;
+/// use geoarrow::array::PolygonArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{point, polygon};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: -1.),
+///     (x: 3., y: 1.),
+///     (x: 1., y: 2.),
+///     (x: 0., y: 0.),
+/// ];
+/// let polygon_array: PolygonArray<i32> = vec![polygon].as_slice().into();
+///
+/// let extremes = polygon_array.extremes();
+/// assert_eq!(extremes.x_min.get_as_geo(0), Some(point!(x: 0., y: 0.)));
+/// assert_eq!(extremes.y_min.get_as_geo(0), Some(point!(x: 2., y: -1.)));
+/// assert_eq!(extremes.x_max.get_as_geo(0), Some(point!(x: 3., y: 1.)));
+/// assert_eq!(extremes.y_max.get_as_geo(0), Some(point!(x: 1., y: 2.)));
+/// ```
+pub trait Extremes {
+    type Output;
+
+    fn extremes(&self) -> Self::Output;
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Extremes for $type {
+            type Output = ExtremePoints;
+
+            fn extremes(&self) -> Self::Output {
+                let mut x_min = PointBuilder::with_capacity(self.len());
+                let mut y_min = PointBuilder::with_capacity(self.len());
+                let mut x_max = PointBuilder::with_capacity(self.len());
+                let mut y_max = PointBuilder::with_capacity(self.len());
+                self.iter_geo().for_each(|maybe_g| {
+                    let outcome = maybe_g.as_ref().and_then(|g| g.extremes());
+                    let point = |f: fn(&Outcome<f64>) -> geo::Coord| {
+                        outcome.as_ref().map(|outcome| geo::Point(f(outcome)))
+                    };
+                    x_min.push_point(point(|outcome| outcome.x_min.coord).as_ref());
+                    y_min.push_point(point(|outcome| outcome.y_min.coord).as_ref());
+                    x_max.push_point(point(|outcome| outcome.x_max.coord).as_ref());
+                    y_max.push_point(point(|outcome| outcome.y_max.coord).as_ref());
+                });
+                ExtremePoints {
+                    x_min: x_min.into(),
+                    y_min: y_min.into(),
+                    x_max: x_max.into(),
+                    y_max: y_max.into(),
+                }
+            }
+        }
+    };
+}
+
+iter_geo_impl!(LineStringArray<O>);
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiPointArray<O>);
+iter_geo_impl!(MultiLineStringArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+/// Every extreme of a point is the point itself.
+impl Extremes for PointArray {
+    type Output = ExtremePoints;
+
+    fn extremes(&self) -> Self::Output {
+        ExtremePoints {
+            x_min: self.clone(),
+            y_min: self.clone(),
+            x_max: self.clone(),
+            y_max: self.clone(),
+        }
+    }
+}
+
+impl_dyn_unary!(Extremes -> ExtremePoints {
+    fn extremes(&self);
+} [
+    Point => as_point,
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiPoint => as_multi_point,
+    LargeMultiPoint => as_large_multi_point,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+    Mixed => as_mixed,
+    LargeMixed => as_large_mixed,
+    GeometryCollection => as_geometry_collection,
+    LargeGeometryCollection => as_large_geometry_collection,
+]);
+
+/// The extreme points of a chunked array are those of each chunk.
+impl ChunkedOutput for ExtremePoints {
+    type Chunked = Vec<ExtremePoints>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        chunks
+    }
+}
+
+impl_chunked_unary!(dyn Extremes {
+    fn extremes(&self);
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use geo::{line_string, point};
+
+    #[test]
+    fn extremes_with_nulls() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 1., y: 1.), (x: -1., y: 3.), (x: 4., y: -2.)]),
+            None,
+        ]
+        .into();
+        let extremes = array.extremes();
+        assert_eq!(extremes.x_min.get_as_geo(0), Some(point!(x: -1., y: 3.)));
+        assert_eq!(extremes.y_min.get_as_geo(0), Some(point!(x: 4., y: -2.)));
+        assert_eq!(extremes.x_max.get_as_geo(0), Some(point!(x: 4., y: -2.)));
+        assert_eq!(extremes.y_max.get_as_geo(0), Some(point!(x: -1., y: 3.)));
+        assert!(extremes.x_min.get_as_geo(1).is_none());
+    }
+
+    #[test]
+    fn dyn_matches_typed() {
+        let array = p_array();
+        let dyn_array: &dyn GeometryArrayTrait = &array;
+        assert_eq!(dyn_array.extremes().unwrap(), array.extremes());
+    }
+}
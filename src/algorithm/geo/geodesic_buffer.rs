@@ -0,0 +1,335 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::algorithm::geo::utils::cascaded_union;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geo::{
+    coord, Centroid, ConvexHull, Coord, GeodesicBearing, GeodesicDestination, Geometry, Line,
+    LineString, MapCoords, MultiPoint, MultiPolygon, Point, Polygon,
+};
+
+/// Buffer geometries with longitude and latitude coordinates by a distance in meters.
+///
+/// Buffering longitude and latitude coordinates with a planar algorithm treats degrees as a unit
+/// of distance, which gives distorted results that are rarely what is wanted, since a degree of
+/// longitude is much shorter near the poles than at the equator. Instead, each geometry is
+/// projected into an azimuthal equidistant projection centered on its centroid, buffered there in
+/// meters, and projected back. Distances from the centroid are exact in this projection, so the
+/// result is accurate for geometries that are small compared to the size of the earth.
+///
+/// Geometries are buffered by the union of circles around their vertices and the hulls of those
+/// circles along their segments, together with the interiors of polygons. Circles are
+/// approximated with `quadrant_segments` segments per quarter circle. Only non-negative distances
+/// are supported.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::GeodesicBuffer;
+/// use geoarrow::array::{MultiPolygonArray, PointArray};
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{point, BoundingRect};
+///
+/// let array: PointArray = vec![point!(x: 10., y: 60.)].as_slice().into();
+/// let buffered: MultiPolygonArray<i32> = array.geodesic_buffer(1000., 8).unwrap();
+/// let bbox = buffered.value_as_geo(0).bounding_rect().unwrap();
+///
+/// // At 60° latitude, a degree of longitude is about half as long as a degree of latitude
+/// assert!(bbox.width() > 1.9 * bbox.height());
+/// ```
+pub trait GeodesicBuffer<O: OffsetSizeTrait> {
+    type Output;
+
+    fn geodesic_buffer(&self, distance: f64, quadrant_segments: usize) -> Self::Output;
+}
+
+/// An azimuthal equidistant projection centered on a point, in meters.
+struct AzimuthalEquidistant {
+    center: Point,
+}
+
+impl AzimuthalEquidistant {
+    fn forward(&self, coord: Coord) -> Coord {
+        let (bearing, distance) = self.center.geodesic_bearing_distance(Point(coord));
+        let azimuth = bearing.to_radians();
+        coord! { x: distance * azimuth.sin(), y: distance * azimuth.cos() }
+    }
+
+    fn inverse(&self, coord: Coord) -> Coord {
+        let distance = coord.x.hypot(coord.y);
+        if distance == 0. {
+            return self.center.0;
+        }
+        let bearing = coord.x.atan2(coord.y).to_degrees();
+        self.center.geodesic_destination(bearing, distance).0
+    }
+}
+
+/// The vertices of a circle, approximated as a regular polygon.
+fn circle(center: Coord, radius: f64, quadrant_segments: usize) -> Vec<Coord> {
+    let num_segments = 4 * quadrant_segments.max(1);
+    (0..num_segments)
+        .map(|i| {
+            let angle = i as f64 * FRAC_PI_2 / quadrant_segments.max(1) as f64;
+            center + coord! { x: radius * angle.cos(), y: radius * angle.sin() }
+        })
+        .collect()
+}
+
+/// The polygons, segments and isolated points that make up a geometry.
+#[derive(Default)]
+struct Parts {
+    polygons: Vec<MultiPolygon>,
+    lines: Vec<Line>,
+    points: Vec<Coord>,
+}
+
+impl Parts {
+    fn add_line_string(&mut self, line_string: &LineString) {
+        match line_string.0.as_slice() {
+            [] => {}
+            [coord] => self.points.push(*coord),
+            _ => self.lines.extend(line_string.lines()),
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon) {
+        self.add_line_string(polygon.exterior());
+        polygon
+            .interiors()
+            .iter()
+            .for_each(|interior| self.add_line_string(interior));
+    }
+
+    fn add_geometry(&mut self, geom: &Geometry) {
+        match geom {
+            Geometry::Point(point) => self.points.push(point.0),
+            Geometry::Line(line) => self.lines.push(*line),
+            Geometry::LineString(line_string) => self.add_line_string(line_string),
+            Geometry::Polygon(polygon) => {
+                self.add_polygon(polygon);
+                self.polygons.push(polygon.clone().into());
+            }
+            Geometry::MultiPoint(multi_point) => {
+                self.points.extend(multi_point.iter().map(|point| point.0))
+            }
+            Geometry::MultiLineString(multi_line_string) => multi_line_string
+                .iter()
+                .for_each(|line_string| self.add_line_string(line_string)),
+            Geometry::MultiPolygon(multi_polygon) => {
+                multi_polygon
+                    .iter()
+                    .for_each(|polygon| self.add_polygon(polygon));
+                self.polygons.push(multi_polygon.clone());
+            }
+            Geometry::GeometryCollection(collection) => {
+                collection.iter().for_each(|g| self.add_geometry(g))
+            }
+            Geometry::Rect(rect) => self.add_geometry(&rect.to_polygon().into()),
+            Geometry::Triangle(triangle) => self.add_geometry(&triangle.to_polygon().into()),
+        }
+    }
+}
+
+/// Buffer a geometry in planar coordinates.
+fn planar_buffer(geom: &Geometry, distance: f64, quadrant_segments: usize) -> MultiPolygon {
+    let mut parts = Parts::default();
+    parts.add_geometry(geom);
+
+    let mut polygons = parts.polygons;
+    if distance > 0. {
+        // Hulls of the circles at either end of each segment
+        for line in parts.lines {
+            let mut coords = circle(line.start, distance, quadrant_segments);
+            coords.extend(circle(line.end, distance, quadrant_segments));
+            polygons.push(MultiPoint::from(coords).convex_hull().into());
+        }
+
+        for coord in parts.points {
+            let ring = circle(coord, distance, quadrant_segments);
+            polygons.push(Polygon::new(ring.into(), vec![]).into());
+        }
+    }
+
+    cascaded_union(polygons)
+}
+
+/// Buffer a geometry with longitude and latitude coordinates by a distance in meters.
+fn geodesic_buffer_geometry(
+    geom: &Geometry,
+    distance: f64,
+    quadrant_segments: usize,
+) -> MultiPolygon {
+    let Some(center) = geom.centroid() else {
+        return MultiPolygon::new(vec![]);
+    };
+    let projection = AzimuthalEquidistant { center };
+    let projected = geom.map_coords(|coord| projection.forward(coord));
+    planar_buffer(&projected, distance, quadrant_segments)
+        .map_coords(|coord| projection.inverse(coord))
+}
+
+fn check_distance(distance: f64) -> Result<()> {
+    if distance.is_nan() || distance < 0. {
+        return Err(GeoArrowError::General(
+            "Buffer distance must be non-negative".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl<O: OffsetSizeTrait> GeodesicBuffer<O> for PointArray {
+    type Output = Result<MultiPolygonArray<O>>;
+
+    fn geodesic_buffer(&self, distance: f64, quadrant_segments: usize) -> Self::Output {
+        check_distance(distance)?;
+        let output_geoms: Vec<Option<MultiPolygon>> = self
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g
+                    .map(|geom| geodesic_buffer_geometry(&geom.into(), distance, quadrant_segments))
+            })
+            .collect();
+        Ok(output_geoms.into())
+    }
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty, $to_geometry:expr) => {
+        impl<O: OffsetSizeTrait, O2: OffsetSizeTrait> GeodesicBuffer<O> for $type {
+            type Output = Result<MultiPolygonArray<O>>;
+
+            fn geodesic_buffer(&self, distance: f64, quadrant_segments: usize) -> Self::Output {
+                check_distance(distance)?;
+                let output_geoms: Vec<Option<MultiPolygon>> = self
+                    .iter_geo()
+                    .map(|maybe_g| {
+                        maybe_g.map(|geom| {
+                            let geom = ($to_geometry)(geom);
+                            geodesic_buffer_geometry(&geom, distance, quadrant_segments)
+                        })
+                    })
+                    .collect();
+                Ok(output_geoms.into())
+            }
+        }
+    };
+}
+
+iter_geo_impl!(LineStringArray<O2>, Geometry::from);
+iter_geo_impl!(PolygonArray<O2>, Geometry::from);
+iter_geo_impl!(MultiPointArray<O2>, Geometry::from);
+iter_geo_impl!(MultiLineStringArray<O2>, Geometry::from);
+iter_geo_impl!(MultiPolygonArray<O2>, Geometry::from);
+iter_geo_impl!(MixedGeometryArray<O2>, Geometry::from);
+iter_geo_impl!(GeometryCollectionArray<O2>, Geometry::GeometryCollection);
+iter_geo_impl!(WKBArray<O2>, Geometry::from);
+
+impl<O: OffsetSizeTrait> GeodesicBuffer<O> for &dyn GeometryArrayTrait {
+    type Output = Result<MultiPolygonArray<O>>;
+
+    fn geodesic_buffer(&self, distance: f64, quadrant_segments: usize) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LineString(_) => self
+                .as_line_string()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeLineString(_) => self
+                .as_large_line_string()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::Polygon(_) => self
+                .as_polygon()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargePolygon(_) => self
+                .as_large_polygon()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::MultiPoint(_) => self
+                .as_multi_point()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeMultiPoint(_) => self
+                .as_large_multi_point()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::MultiLineString(_) => self
+                .as_multi_line_string()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeMultiLineString(_) => self
+                .as_large_multi_line_string()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::MultiPolygon(_) => self
+                .as_multi_polygon()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeMultiPolygon(_) => self
+                .as_large_multi_polygon()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::Mixed(_) => self.as_mixed().geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeMixed(_) => self
+                .as_large_mixed()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::GeometryCollection(_) => self
+                .as_geometry_collection()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeGeometryCollection(_) => self
+                .as_large_geometry_collection()
+                .geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::WKB => self.as_wkb().geodesic_buffer(distance, quadrant_segments),
+            GeoDataType::LargeWKB => self
+                .as_large_wkb()
+                .geodesic_buffer(distance, quadrant_segments),
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait, G: GeometryArrayTrait> GeodesicBuffer<O> for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedGeometryArray<MultiPolygonArray<O>>>;
+
+    fn geodesic_buffer(&self, distance: f64, quadrant_segments: usize) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().geodesic_buffer(distance, quadrant_segments))?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, CoordsIter, GeodesicArea, GeodesicDistance};
+
+    #[test]
+    fn point_buffer_distance() {
+        let center = point!(x: -73.98, y: 40.75);
+        let array: PointArray = vec![Some(center), None].into();
+        let buffered: MultiPolygonArray<i32> = array.geodesic_buffer(500., 16).unwrap();
+        assert!(buffered.is_null(1));
+
+        let polygon = buffered.value_as_geo(0);
+        for coord in polygon.coords_iter() {
+            let distance = center.geodesic_distance(&Point(coord));
+            assert!((distance - 500.).abs() < 1e-6);
+        }
+
+        // The area of a 64-gon inscribed in a circle with radius 500 m
+        let expected_area = 32. * 500f64.powi(2) * (2. * std::f64::consts::PI / 64.).sin();
+        let area = polygon.geodesic_area_unsigned();
+        assert!((area - expected_area).abs() / expected_area < 1e-3);
+    }
+
+    #[test]
+    fn line_string_buffer() {
+        let array: LineStringArray<i32> = vec![line_string![(x: 0., y: 0.), (x: 0.01, y: 0.)]]
+            .as_slice()
+            .into();
+        let buffered: MultiPolygonArray<i32> = array.geodesic_buffer(100., 8).unwrap();
+        let polygon = buffered.value_as_geo(0);
+        assert_eq!(polygon.0.len(), 1);
+        assert!(polygon.0[0].interiors().is_empty());
+
+        let negative: Result<MultiPolygonArray<i32>> = array.geodesic_buffer(-1., 8);
+        assert!(negative.is_err());
+    }
+}
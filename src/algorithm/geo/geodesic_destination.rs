@@ -0,0 +1,154 @@
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::array::{PointArray, PointBuilder};
+use crate::chunked_array::ChunkedPointArray;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
+use geo::GeodesicDestination as _GeodesicDestination;
+
+/// Returns a new point having travelled the given distance along a geodesic, starting with the
+/// given bearing. This solves the direct geodesic problem.
+///
+/// This uses the geodesic methods given by [Karney (2013)].
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicDestination {
+    type Output;
+
+    /// Returns the destination of each point, given a bearing in degrees clockwise from north
+    /// and a distance in meters. Bearings and distances can be either a single value applied to
+    /// every point or an array with one value per point. Coordinates must be longitude and
+    /// latitude in degrees. If the point, bearing or distance is null, the output is null.
+    ///
+    /// # Errors
+    ///
+    /// - if an array of bearings or distances doesn't have one value per point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::Float64Array;
+    /// use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
+    /// use geoarrow::algorithm::geo::GeodesicDestination;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 0., y: 0.), point!(x: 0., y: 0.)].as_slice().into();
+    /// let bearings = BroadcastablePrimitive::Array(Float64Array::from(vec![0., 90.]));
+    /// let destinations = array
+    ///     .geodesic_destination(bearings, 111_319.49.into())
+    ///     .unwrap();
+    ///
+    /// // Due north and due east along the equator
+    /// assert!((destinations.value_as_geo(0).y() - 1.).abs() < 0.01);
+    /// assert!((destinations.value_as_geo(1).x() - 1.).abs() < 1e-6);
+    /// ```
+    fn geodesic_destination(
+        &self,
+        bearing: BroadcastablePrimitive<Float64Type>,
+        distance: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output;
+}
+
+impl GeodesicDestination for PointArray {
+    type Output = Result<PointArray>;
+
+    fn geodesic_destination(
+        &self,
+        bearing: BroadcastablePrimitive<Float64Type>,
+        distance: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        bearing.check_len("Bearing", self.len())?;
+        distance.check_len("Distance", self.len())?;
+        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
+
+        self.iter_geo()
+            .zip(&bearing)
+            .zip(&distance)
+            .for_each(|((maybe_g, bearing), distance)| {
+                let destination = match (maybe_g, bearing, distance) {
+                    (Some(geom), Some(bearing), Some(distance)) => {
+                        Some(geom.geodesic_destination(bearing, distance))
+                    }
+                    _ => None,
+                };
+                output_array.push_point(destination.as_ref());
+            });
+
+        Ok(output_array.finish())
+    }
+}
+
+impl GeodesicDestination for ChunkedPointArray {
+    type Output = Result<ChunkedPointArray>;
+
+    /// Arrays of bearings or distances must have the same length as the whole chunked array, and
+    /// are sliced to match each chunk.
+    fn geodesic_destination(
+        &self,
+        bearing: BroadcastablePrimitive<Float64Type>,
+        distance: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        let slice = |values: &BroadcastablePrimitive<Float64Type>, offset, length| match values {
+            BroadcastablePrimitive::Scalar(value) => BroadcastablePrimitive::Scalar(*value),
+            BroadcastablePrimitive::Array(array) => {
+                BroadcastablePrimitive::Array(array.slice(offset, length))
+            }
+        };
+
+        bearing.check_len("Bearing", self.len())?;
+        distance.check_len("Distance", self.len())?;
+
+        let mut offset = 0;
+        let chunks = self
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                let length = chunk.len();
+                let output = chunk.geodesic_destination(
+                    slice(&bearing, offset, length),
+                    slice(&distance, offset, length),
+                );
+                offset += length;
+                output
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedPointArray::new(chunks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Float64Array;
+    use geo::{point, GeodesicDistance};
+
+    #[test]
+    fn broadcast_and_nulls() {
+        let start = point!(x: 13.4, y: 52.5);
+        let array: PointArray = vec![Some(start), None, Some(start)].into();
+        let distances =
+            BroadcastablePrimitive::Array(Float64Array::from(vec![Some(1000.), Some(1.), None]));
+        let destinations = array.geodesic_destination(45.0.into(), distances).unwrap();
+
+        let destination = destinations.value_as_geo(0);
+        assert!((start.geodesic_distance(&destination) - 1000.).abs() < 1e-6);
+        assert!(destinations.is_null(1));
+        assert!(destinations.is_null(2));
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let array: PointArray = vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]
+            .as_slice()
+            .into();
+        let bearings = BroadcastablePrimitive::Array(Float64Array::from(vec![0.]));
+        assert!(array.geodesic_destination(bearings, 1000.0.into()).is_err());
+
+        let chunked = ChunkedPointArray::new(vec![array]);
+        let distances = BroadcastablePrimitive::Array(Float64Array::from(vec![1., 2., 3.]));
+        assert!(chunked.geodesic_destination(0.0.into(), distances).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryScalarTrait;
@@ -152,6 +152,25 @@ chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
 chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
 chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
 
+impl GeodesicLength for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn geodesic_length(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().geodesic_length(),
+            GeoDataType::LineString(_) => self.as_line_string().geodesic_length(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().geodesic_length(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().geodesic_length(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().geodesic_length(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().geodesic_length(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().geodesic_length()
+            }
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,217 @@
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::array::{LineStringArray, PointArray, PointBuilder};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
+use geo::{HaversineIntermediate as _HaversineIntermediate, LineString, Point};
+
+/// Returns points along the great circle between two points, e.g. to draw flight paths over
+/// longitude and latitude data.
+///
+/// This uses a spherical model of the earth with the mean earth radius. Coordinates must be
+/// longitude and latitude in degrees. If either point is null, the output is null.
+///
+/// # Errors
+///
+/// - if the arrays have different lengths
+pub trait HaversineIntermediate<Rhs = Self> {
+    /// Returns the point a fraction of the way from each point to the corresponding point of
+    /// `other`. Fractions can be either a single value applied to every pair or an array with one
+    /// value per pair. If the fraction is null, the output is null.
+    ///
+    /// # Errors
+    ///
+    /// - if an array of fractions doesn't have one value per pair
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::HaversineIntermediate;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::point;
+    ///
+    /// let from: PointArray = vec![point!(x: 0., y: 0.)].as_slice().into();
+    /// let to: PointArray = vec![point!(x: 90., y: 0.)].as_slice().into();
+    ///
+    /// let halfway = from.haversine_intermediate(&to, 0.5.into()).unwrap();
+    /// assert!((halfway.value_as_geo(0).x() - 45.).abs() < 1e-9);
+    /// ```
+    fn haversine_intermediate(
+        &self,
+        other: &Rhs,
+        fraction: BroadcastablePrimitive<Float64Type>,
+    ) -> Result<PointArray>;
+
+    /// Returns the great circle from each point to the corresponding point of `other` as a line
+    /// string, with intermediate points at most `max_distance` meters apart.
+    ///
+    /// # Errors
+    ///
+    /// - if `max_distance` is not positive
+    fn haversine_intermediate_fill(
+        &self,
+        other: &Rhs,
+        max_distance: f64,
+    ) -> Result<LineStringArray<i32>>;
+
+    /// Returns the great circle from each point to the corresponding point of `other` as a line
+    /// string, with `n` equally spaced intermediate points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::HaversineIntermediate;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::point;
+    ///
+    /// let from: PointArray = vec![point!(x: -74.006, y: 40.7128)].as_slice().into();
+    /// let to: PointArray = vec![point!(x: -0.1278, y: 51.5074)].as_slice().into();
+    ///
+    /// let flight_path = from.haversine_intermediate_n(&to, 8).unwrap().value_as_geo(0);
+    /// assert_eq!(flight_path.0.len(), 10);
+    /// // The great circle from New York to London passes north of both cities
+    /// assert!(flight_path.0[5].y > 52.);
+    /// ```
+    fn haversine_intermediate_n(&self, other: &Rhs, n: usize) -> Result<LineStringArray<i32>>;
+}
+
+fn pairwise_line_strings(
+    from: &PointArray,
+    to: &PointArray,
+    op: impl Fn(Point, Point) -> LineString,
+) -> Result<LineStringArray<i32>> {
+    check_same_len(from, to)?;
+    let output_geoms: Vec<Option<LineString>> = from
+        .iter_geo()
+        .zip(to.iter_geo())
+        .map(|(first, second)| match (first, second) {
+            (Some(first), Some(second)) => Some(op(first, second)),
+            _ => None,
+        })
+        .collect();
+    Ok(output_geoms.into())
+}
+
+fn check_same_len(from: &PointArray, to: &PointArray) -> Result<()> {
+    if from.len() != to.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            from.len(),
+            to.len()
+        )));
+    }
+    Ok(())
+}
+
+impl HaversineIntermediate for PointArray {
+    fn haversine_intermediate(
+        &self,
+        other: &Self,
+        fraction: BroadcastablePrimitive<Float64Type>,
+    ) -> Result<PointArray> {
+        check_same_len(self, other)?;
+        fraction.check_len("Fraction", self.len())?;
+        let mut output_array = PointBuilder::with_capacity(self.len());
+
+        self.iter_geo()
+            .zip(other.iter_geo())
+            .zip(&fraction)
+            .for_each(|((first, second), fraction)| {
+                let intermediate = match (first, second, fraction) {
+                    (Some(first), Some(second), Some(fraction)) => {
+                        Some(first.haversine_intermediate(&second, fraction))
+                    }
+                    _ => None,
+                };
+                output_array.push_point(intermediate.as_ref());
+            });
+
+        Ok(output_array.finish())
+    }
+
+    fn haversine_intermediate_fill(
+        &self,
+        other: &Self,
+        max_distance: f64,
+    ) -> Result<LineStringArray<i32>> {
+        if max_distance.is_nan() || max_distance <= 0. {
+            return Err(GeoArrowError::General(format!(
+                "Maximum distance must be positive, got {max_distance}"
+            )));
+        }
+        pairwise_line_strings(self, other, |first, second| {
+            first
+                .haversine_intermediate_fill(&second, max_distance, true)
+                .into()
+        })
+    }
+
+    fn haversine_intermediate_n(&self, other: &Self, n: usize) -> Result<LineStringArray<i32>> {
+        pairwise_line_strings(self, other, |first, second| {
+            (0..n + 2)
+                .map(|idx| first.haversine_intermediate(&second, idx as f64 / (n + 1) as f64))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Float64Array;
+    use geo::{point, HaversineDistance};
+
+    #[test]
+    fn nulls_and_fill() {
+        let from: PointArray =
+            vec![Some(point!(x: 0., y: 0.)), None, Some(point!(x: 0., y: 0.))].into();
+        let to: PointArray = vec![
+            Some(point!(x: 0., y: 10.)),
+            Some(point!(x: 1., y: 1.)),
+            Some(point!(x: 10., y: 0.)),
+        ]
+        .into();
+
+        let fractions = Float64Array::from(vec![Some(0.5), Some(0.5), None]);
+        let intermediate = from
+            .haversine_intermediate(&to, BroadcastablePrimitive::Array(fractions))
+            .unwrap();
+        assert!((intermediate.value_as_geo(0).y() - 5.).abs() < 1e-9);
+        assert!(intermediate.get_as_geo(1).is_none());
+        assert!(intermediate.get_as_geo(2).is_none());
+
+        let max_distance = point!(x: 0., y: 0.).haversine_distance(&point!(x: 0., y: 10.)) / 4.;
+        let filled = from
+            .haversine_intermediate_fill(&to, max_distance + 1.)
+            .unwrap();
+        assert_eq!(filled.value_as_geo(0).0.len(), 5);
+        assert!(filled.get_as_geo(1).is_none());
+        assert_eq!(
+            filled.value_as_geo(2).0.last().copied(),
+            Some(point!(x: 10., y: 0.).0)
+        );
+
+        let n = from.haversine_intermediate_n(&to, 0).unwrap();
+        assert_eq!(n.value_as_geo(0).0.len(), 2);
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let from: PointArray = vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]
+            .as_slice()
+            .into();
+        let to: PointArray = vec![point!(x: 0., y: 10.)].as_slice().into();
+        assert!(from.haversine_intermediate(&to, 0.5.into()).is_err());
+        assert!(from.haversine_intermediate_n(&to, 1).is_err());
+
+        let fractions = BroadcastablePrimitive::Array(Float64Array::from(vec![0.5]));
+        assert!(from.haversine_intermediate(&from, fractions).is_err());
+
+        assert!(from.haversine_intermediate_fill(&from, 0.).is_err());
+        assert!(from.haversine_intermediate_fill(&from, -1.).is_err());
+        assert!(from.haversine_intermediate_fill(&from, f64::NAN).is_err());
+    }
+}
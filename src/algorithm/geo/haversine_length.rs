@@ -1,7 +1,7 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryScalarTrait;
@@ -146,6 +146,25 @@ chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
 chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
 chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
 
+impl HaversineLength for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn haversine_length(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().haversine_length(),
+            GeoDataType::LineString(_) => self.as_line_string().haversine_length(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().haversine_length(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().haversine_length(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().haversine_length(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().haversine_length(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().haversine_length()
+            }
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +188,23 @@ mod tests {
         assert_eq!(expected, result_array.value(0).round());
         assert!(result_array.is_valid(0));
     }
+
+    #[test]
+    fn haversine_length_chunked() {
+        let input_geom = line_string![
+            // New York City
+            (x: -74.006, y: 40.7128),
+            // London
+            (x: -0.1278, y: 51.5074),
+        ];
+        let chunk: LineStringArray<i32> = vec![input_geom].as_slice().into();
+        let chunked = ChunkedGeometryArray::new(vec![chunk.clone(), chunk]);
+        let chunked_ref: &dyn ChunkedGeometryArrayTrait = &chunked;
+        let result = chunked_ref.haversine_length().unwrap();
+
+        assert_eq!(result.len(), 2);
+        for chunk in result.chunks() {
+            assert_eq!(5_570_230.0_f64, chunk.value(0).round());
+        }
+    }
 }
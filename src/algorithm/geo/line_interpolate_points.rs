@@ -0,0 +1,163 @@
+use crate::array::{LineStringArray, MultiPointArray};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use arrow_array::OffsetSizeTrait;
+use geo::{Coord, GeodesicDistance, GeodesicIntermediate, MultiPoint, Point};
+
+/// How distances along a line are measured by [`line_interpolate_points`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistanceMethod {
+    /// Planar distances in the units of the coordinates.
+    #[default]
+    Euclidean,
+
+    /// Distances in meters along geodesics on the WGS84 ellipsoid, with coordinates as longitude
+    /// and latitude in degrees.
+    ///
+    /// This uses the geodesic methods given by [Karney (2013)].
+    ///
+    /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+    Geodesic,
+}
+
+impl DistanceMethod {
+    fn length(&self, start: Coord, end: Coord) -> f64 {
+        match self {
+            DistanceMethod::Euclidean => (end.x - start.x).hypot(end.y - start.y),
+            DistanceMethod::Geodesic => Point(start).geodesic_distance(&Point(end)),
+        }
+    }
+
+    /// The point a fraction of the way from `start` to `end`.
+    fn interpolate(&self, start: Coord, end: Coord, fraction: f64) -> Point {
+        match self {
+            DistanceMethod::Euclidean => Point(start + (end - start) * fraction),
+            DistanceMethod::Geodesic => Point(start).geodesic_intermediate(&Point(end), fraction),
+        }
+    }
+}
+
+/// The points at every multiple of `spacing` along a line string.
+fn points_at_interval(coords: &[Coord], spacing: f64, method: DistanceMethod) -> MultiPoint {
+    let Some(first) = coords.first() else {
+        return MultiPoint::new(vec![]);
+    };
+
+    let mut points = vec![Point(*first)];
+    let mut station = 1;
+    let mut start_distance = 0.;
+    for segment in coords.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let length = method.length(start, end);
+        let end_distance = start_distance + length;
+        // Multiply rather than accumulate the spacing so that errors don't build up
+        while station as f64 * spacing <= end_distance {
+            let fraction = (station as f64 * spacing - start_distance) / length;
+            points.push(method.interpolate(start, end, fraction));
+            station += 1;
+        }
+        start_distance = end_distance;
+    }
+    MultiPoint::new(points)
+}
+
+/// Place points at regular intervals along each line string, e.g. for stationing or sampling
+/// along routes.
+///
+/// The first point of each output is the start of the line string, and the following points are
+/// `spacing` apart measured along the line string. The end of the line string is only included
+/// if its length is a multiple of `spacing`. A null line string gives a null output, and an empty
+/// line string gives an empty multi point.
+///
+/// # Errors
+///
+/// - if `spacing` is not a finite number greater than zero
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::{line_interpolate_points, DistanceMethod};
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{line_string, point, MultiPoint};
+///
+/// let lines: LineStringArray<i32> =
+///     vec![line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 3.)]]
+///         .as_slice()
+///         .into();
+///
+/// let points = line_interpolate_points(&lines, 2., DistanceMethod::Euclidean).unwrap();
+/// assert_eq!(
+///     points.value_as_geo(0),
+///     MultiPoint::new(vec![
+///         point!(x: 0., y: 0.),
+///         point!(x: 2., y: 0.),
+///         point!(x: 2., y: 2.),
+///     ])
+/// );
+/// ```
+pub fn line_interpolate_points<O: OffsetSizeTrait>(
+    lines: &LineStringArray<O>,
+    spacing: f64,
+    method: DistanceMethod,
+) -> Result<MultiPointArray<O>> {
+    if !(spacing.is_finite() && spacing > 0.) {
+        return Err(GeoArrowError::General(format!(
+            "Spacing must be a positive number, got {}",
+            spacing
+        )));
+    }
+
+    let output_geoms: Vec<Option<MultiPoint>> = lines
+        .iter_geo()
+        .map(|maybe_line| maybe_line.map(|line| points_at_interval(&line.0, spacing, method)))
+        .collect();
+    Ok(output_geoms.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryArrayTrait;
+    use approx::assert_relative_eq;
+    use geo::{line_string, point, GeodesicLength};
+
+    #[test]
+    fn nulls_and_repeated_points() {
+        let lines: LineStringArray<i32> = vec![
+            None,
+            Some(line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 0., y: 1.)]),
+            Some(line_string![]),
+        ]
+        .into();
+        let points = line_interpolate_points(&lines, 0.5, DistanceMethod::Euclidean).unwrap();
+        assert!(points.is_null(0));
+        assert_eq!(
+            points.value_as_geo(1),
+            MultiPoint::new(vec![
+                point!(x: 0., y: 0.),
+                point!(x: 0., y: 0.5),
+                point!(x: 0., y: 1.)
+            ])
+        );
+        assert_eq!(points.value_as_geo(2), MultiPoint::new(vec![]));
+
+        assert!(line_interpolate_points(&lines, 0., DistanceMethod::Euclidean).is_err());
+    }
+
+    #[test]
+    fn geodesic() {
+        let line = line_string![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)];
+        let length = line.geodesic_length();
+        let lines: LineStringArray<i64> = vec![line].as_slice().into();
+
+        let points = line_interpolate_points(&lines, length / 4., DistanceMethod::Geodesic)
+            .unwrap()
+            .value_as_geo(0);
+        assert_eq!(points.0.len(), 5);
+        assert_relative_eq!(points.0[4], point!(x: 1., y: 1.), epsilon = 1e-6);
+        // A degree of latitude is a bit shorter than a degree of longitude near the equator, so
+        // the midpoint lies on the second segment
+        assert!(points.0[2].x() > 0.);
+    }
+}
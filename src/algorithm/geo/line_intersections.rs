@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use crate::array::*;
+use crate::trait_::GeometryArrayAccessor;
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{Coord, Line};
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, AABB};
+
+/// The intersections between the line strings of two arrays, as returned by
+/// [`line_intersections`].
+///
+/// There is one row per pair of intersecting line strings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineIntersectionPairs {
+    /// The index into the first array of each intersecting pair.
+    pub left: UInt32Array,
+
+    /// The index into the second array of each intersecting pair.
+    pub right: UInt32Array,
+
+    /// The distinct intersection points of each intersecting pair.
+    pub points: MultiPointArray<i32>,
+}
+
+/// A segment of a line string, tagged with the index of the line string.
+type IndexedSegment = GeomWithData<Rectangle<[f64; 2]>, (usize, Line)>;
+
+fn envelope(line: &Line) -> AABB<[f64; 2]> {
+    AABB::from_corners(line.start.into(), line.end.into())
+}
+
+/// Find the points where the line strings of `a` intersect the line strings of `b`.
+///
+/// An R-Tree of the segments of `b` is built once and queried with each segment of `a`, so only
+/// segments with intersecting bounding boxes are tested exactly. Where segments overlap, both ends
+/// of the overlap are reported. Null line strings never intersect.
+///
+/// The output is sorted by the index into `a` and then the index into `b`. The indices can be
+/// passed to [`Take`][crate::algorithm::native::Take] to carry over attributes.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::line_intersections;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use arrow_array::UInt32Array;
+/// use geo::{line_string, point, MultiPoint};
+///
+/// let a: LineStringArray<i32> = vec![line_string![(x: 0., y: 0.), (x: 2., y: 2.)]]
+///     .as_slice()
+///     .into();
+/// let b: LineStringArray<i32> = vec![
+///     line_string![(x: 5., y: 5.), (x: 6., y: 6.)],
+///     line_string![(x: 0., y: 2.), (x: 2., y: 0.)],
+/// ]
+/// .as_slice()
+/// .into();
+///
+/// let intersections = line_intersections(&a, &b);
+/// assert_eq!(intersections.left, UInt32Array::from(vec![0]));
+/// assert_eq!(intersections.right, UInt32Array::from(vec![1]));
+/// assert_eq!(
+///     intersections.points.value_as_geo(0),
+///     MultiPoint::new(vec![point!(x: 1., y: 1.)])
+/// );
+/// ```
+pub fn line_intersections<O1: OffsetSizeTrait, O2: OffsetSizeTrait>(
+    a: &LineStringArray<O1>,
+    b: &LineStringArray<O2>,
+) -> LineIntersectionPairs {
+    let segments = b
+        .iter_geo()
+        .enumerate()
+        .filter_map(|(geom_idx, maybe_line_string)| {
+            maybe_line_string.map(|line_string| (geom_idx, line_string))
+        })
+        .flat_map(|(geom_idx, line_string)| {
+            line_string
+                .lines()
+                .map(|line| {
+                    let envelope = envelope(&line);
+                    IndexedSegment::new(Rectangle::from_aabb(envelope), (geom_idx, line))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let tree = RTree::bulk_load(segments);
+
+    let mut pairs: BTreeMap<(usize, usize), Vec<Coord>> = BTreeMap::new();
+    for (left_idx, maybe_line_string) in a.iter_geo().enumerate() {
+        let Some(line_string) = maybe_line_string else {
+            continue;
+        };
+        for line in line_string.lines() {
+            for candidate in tree.locate_in_envelope_intersecting(&envelope(&line)) {
+                let (right_idx, other) = candidate.data;
+                let points = match line_intersection(line, other) {
+                    Some(LineIntersection::SinglePoint { intersection, .. }) => vec![intersection],
+                    Some(LineIntersection::Collinear { intersection }) => {
+                        vec![intersection.start, intersection.end]
+                    }
+                    None => continue,
+                };
+                pairs
+                    .entry((left_idx, right_idx))
+                    .or_default()
+                    .extend(points);
+            }
+        }
+    }
+
+    let mut left = Vec::with_capacity(pairs.len());
+    let mut right = Vec::with_capacity(pairs.len());
+    let mut points = Vec::with_capacity(pairs.len());
+    for ((left_idx, right_idx), mut coords) in pairs {
+        coords.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        coords.dedup();
+        left.push(left_idx as u32);
+        right.push(right_idx as u32);
+        points.push(geo::MultiPoint::from(coords));
+    }
+    LineIntersectionPairs {
+        left: left.into(),
+        right: right.into(),
+        points: points.as_slice().into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryArrayTrait;
+    use geo::{line_string, point, MultiPoint};
+
+    #[test]
+    fn pairs_with_nulls_and_overlaps() {
+        let a: LineStringArray<i32> = vec![
+            None,
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)]),
+        ]
+        .into();
+        let b: LineStringArray<i64> = vec![
+            // Crosses both segments of a[1]
+            Some(line_string![(x: 1., y: -1.), (x: 1., y: 1.), (x: 5., y: 1.)]),
+            None,
+            // Overlaps the first segment of a[1]
+            Some(line_string![(x: 2., y: 0.), (x: 6., y: 0.)]),
+            Some(line_string![(x: 10., y: 10.), (x: 11., y: 11.)]),
+        ]
+        .into();
+
+        let intersections = line_intersections(&a, &b);
+        assert_eq!(intersections.left, UInt32Array::from(vec![1, 1]));
+        assert_eq!(intersections.right, UInt32Array::from(vec![0, 2]));
+        assert_eq!(
+            intersections.points.value_as_geo(0),
+            MultiPoint::new(vec![point!(x: 1., y: 0.), point!(x: 4., y: 1.)])
+        );
+        // The overlap ends at the shared vertex of both segments of a[1]
+        assert_eq!(
+            intersections.points.value_as_geo(1),
+            MultiPoint::new(vec![point!(x: 2., y: 0.), point!(x: 4., y: 0.)])
+        );
+
+        let empty = line_intersections(
+            &a,
+            &LineStringArray::<i32>::from(vec![None::<geo::LineString>]),
+        );
+        assert_eq!(empty.points.len(), 0);
+    }
+}
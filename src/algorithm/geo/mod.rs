@@ -14,6 +14,10 @@ pub use geo::AffineTransform;
 mod area;
 pub use area::Area;
 
+/// Calculate the geodesic or rhumb line bearing between points.
+mod bearing;
+pub use bearing::{GeodesicBearing, RhumbBearing};
+
 /// Calculate the bounding rectangle of geometries.
 mod bounding_rect;
 pub use bounding_rect::BoundingRect;
@@ -34,6 +38,10 @@ pub use chaikin_smoothing::ChaikinSmoothing;
 mod chamberlain_duquette_area;
 pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 
+/// Find the point on geometries closest to query points.
+mod closest_point;
+pub use closest_point::{ClosestPoint, ClosestPoints};
+
 /// Determine whether `Geometry` `A` completely encloses `Geometry` `B`.
 mod contains;
 pub use contains::Contains;
@@ -59,14 +67,30 @@ pub use euclidean_length::EuclideanLength;
 mod euclidean_distance;
 pub use euclidean_distance::EuclideanDistance;
 
+/// Find the extreme vertices of geometries.
+mod extremes;
+pub use extremes::{ExtremePoints, Extremes};
+
 /// Calculate the Geodesic area and perimeter of polygons.
 mod geodesic_area;
 pub use geodesic_area::GeodesicArea;
 
+/// Buffer geometries with longitude and latitude coordinates by a distance in meters.
+mod geodesic_buffer;
+pub use geodesic_buffer::GeodesicBuffer;
+
+/// Calculate the destination of points travelling along a geodesic.
+mod geodesic_destination;
+pub use geodesic_destination::GeodesicDestination;
+
 /// Calculate the Geodesic length of a line.
 mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
 
+/// Calculate points along the great circle between points.
+mod haversine_intermediate;
+pub use haversine_intermediate::HaversineIntermediate;
+
 /// Calculate the Haversine length of a Line.
 mod haversine_length;
 pub use haversine_length::HaversineLength;
@@ -75,10 +99,18 @@ pub use haversine_length::HaversineLength;
 mod intersects;
 pub use intersects::Intersects;
 
+/// Find the intersection points between the line strings of two arrays.
+mod line_intersections;
+pub use line_intersections::{line_intersections, LineIntersectionPairs};
+
 /// Interpolate a point along a `LineStringArray`.
 mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
 
+/// Place points at regular intervals along a `LineStringArray`.
+mod line_interpolate_points;
+pub use line_interpolate_points::{line_interpolate_points, DistanceMethod};
+
 /// Locate a point along a `LineStringArray`.
 mod line_locate_point;
 pub use line_locate_point::LineLocatePoint;
@@ -99,6 +131,10 @@ pub use rotate::Rotate;
 mod scale;
 pub use scale::Scale;
 
+/// Find where the linework of geometries intersects itself.
+mod self_intersections;
+pub use self_intersections::{SelfIntersectionPoints, SelfIntersections};
+
 /// Simplify geometries using the Ramer-Douglas-Peucker algorithm.
 mod simplify;
 pub use simplify::Simplify;
@@ -111,14 +147,30 @@ pub use simplify_vw::SimplifyVw;
 mod skew;
 pub use skew::Skew;
 
+/// Sort geometries by their area or length.
+mod sort_indices;
+pub use sort_indices::SortIndices;
+
+/// Split geometries crossing the antimeridian into parts on either side of it.
+mod split_antimeridian;
+pub use split_antimeridian::SplitAntimeridian;
+
 /// Translate geometries along the given offsets.
 mod translate;
 pub use translate::Translate;
 
+/// Check that polygons form a valid coverage without overlaps or gaps.
+mod validate_coverage;
+pub use validate_coverage::{CoverageValidation, ValidateCoverage};
+
 /// Calculate the Vincenty length of a [`LineStringArray`][crate::array::LineStringArray].
 mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Query the winding order of polygon rings.
+mod winding_order;
+pub use winding_order::Winding;
+
 /// Determine whether `Geometry` `A` is completely within by `Geometry` `B`.
 mod within;
 pub use within::Within;
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::array::*;
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -112,3 +113,7 @@ impl RemoveRepeatedPoints for &dyn GeometryArrayTrait {
         Ok(result)
     }
 }
+
+impl_chunked_unary!(RemoveRepeatedPoints {
+    fn remove_repeated_points(&self);
+});
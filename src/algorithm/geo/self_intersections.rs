@@ -0,0 +1,231 @@
+use crate::algorithm::native::segments::PartSegment;
+use crate::array::*;
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary, ChunkedOutput};
+use crate::trait_::GeometryArrayAccessor;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geo::line_intersection::LineIntersection;
+use geo::sweep::Intersections;
+use geo::{Coord, CoordsIter};
+
+/// Whether each geometry self-intersects, and where, as returned by
+/// [`SelfIntersections::self_intersections`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfIntersectionPoints {
+    /// Whether each geometry self-intersects. Null where the input geometry is null.
+    pub self_intersects: BooleanArray,
+
+    /// The distinct self-intersection points of each geometry, which are empty where the
+    /// geometry doesn't self-intersect. Null where the input geometry is null.
+    pub points: MultiPointArray<i32>,
+}
+
+/// Find the points where the linework of each geometry intersects itself, using a planar sweep.
+///
+/// All rings or parts of a geometry are tested against each other, so the rings of a polygon
+/// that touch or cross, or the parts of a multi linestring that cross, are reported too. The
+/// vertex shared by consecutive segments is not an intersection, but consecutive segments that
+/// overlap are, in which case both ends of the overlap are reported. Repeated consecutive
+/// coordinates are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::SelfIntersections;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{line_string, point, MultiPoint};
+///
+/// let bowtie = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// let simple = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+/// let array: LineStringArray<i32> = vec![bowtie, simple].as_slice().into();
+///
+/// let output = array.self_intersections();
+/// assert!(output.self_intersects.value(0));
+/// assert!(!output.self_intersects.value(1));
+/// assert_eq!(
+///     output.points.value_as_geo(0),
+///     MultiPoint::new(vec![point!(x: 1., y: 1.)])
+/// );
+/// ```
+pub trait SelfIntersections {
+    type Output;
+
+    fn self_intersections(&self) -> Self::Output;
+}
+
+/// The self-intersection points of a geometry given its rings or parts, sorted and deduplicated.
+fn ring_intersections<'a>(rings: impl Iterator<Item = &'a geo::LineString>) -> Vec<Coord> {
+    let rings = rings
+        .map(|ring| {
+            let mut coords = ring.coords().copied().collect::<Vec<_>>();
+            coords.dedup();
+            coords
+        })
+        .collect::<Vec<_>>();
+    let segments = PartSegment::from_parts(rings.iter().map(|coords| coords.as_slice()));
+
+    let mut points = vec![];
+    for (a, b, intersection) in Intersections::from_iter(segments) {
+        match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                if !a.is_adjacent(&b) {
+                    points.push(intersection);
+                }
+            }
+            LineIntersection::Collinear { intersection } => {
+                points.push(intersection.start);
+                points.push(intersection.end);
+            }
+        }
+    }
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    points
+}
+
+/// The rings of a polygon, or the parts of a multi geometry, as line strings.
+trait Rings {
+    fn rings(&self) -> Vec<&geo::LineString>;
+}
+
+impl Rings for geo::LineString {
+    fn rings(&self) -> Vec<&geo::LineString> {
+        vec![self]
+    }
+}
+
+impl Rings for geo::Polygon {
+    fn rings(&self) -> Vec<&geo::LineString> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect()
+    }
+}
+
+impl Rings for geo::MultiLineString {
+    fn rings(&self) -> Vec<&geo::LineString> {
+        self.0.iter().collect()
+    }
+}
+
+impl Rings for geo::MultiPolygon {
+    fn rings(&self) -> Vec<&geo::LineString> {
+        self.0.iter().flat_map(|polygon| polygon.rings()).collect()
+    }
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> SelfIntersections for $type {
+            type Output = SelfIntersectionPoints;
+
+            fn self_intersections(&self) -> Self::Output {
+                let points = self
+                    .iter_geo()
+                    .map(|maybe_g| {
+                        maybe_g.map(|geom| {
+                            let coords = ring_intersections(geom.rings().into_iter());
+                            geo::MultiPoint::from(coords)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let self_intersects = points
+                    .iter()
+                    .map(|maybe_points| {
+                        maybe_points
+                            .as_ref()
+                            .map(|points| points.coords_count() > 0)
+                    })
+                    .collect();
+                SelfIntersectionPoints {
+                    self_intersects,
+                    points: points.into(),
+                }
+            }
+        }
+    };
+}
+
+iter_geo_impl!(LineStringArray<O>);
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiLineStringArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+
+impl_dyn_unary!(SelfIntersections -> SelfIntersectionPoints {
+    fn self_intersections(&self);
+} [
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+]);
+
+/// The self-intersections of a chunked array are those of each chunk.
+impl ChunkedOutput for SelfIntersectionPoints {
+    type Chunked = Vec<SelfIntersectionPoints>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        chunks
+    }
+}
+
+impl_chunked_unary!(dyn SelfIntersections {
+    fn self_intersections(&self);
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryArrayTrait;
+    use arrow_array::Array;
+    use geo::{line_string, point, polygon, MultiPoint};
+
+    #[test]
+    fn rings_and_repeated_points() {
+        let array: PolygonArray<i32> = vec![
+            // Valid square with a repeated vertex
+            Some(polygon![
+                (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.),
+                (x: 0., y: 0.)
+            ]),
+            None,
+            // A hole crossing the exterior
+            Some(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                interiors: [[(x: 3., y: 1.), (x: 5., y: 1.), (x: 5., y: 2.), (x: 3., y: 2.)]],
+            )),
+        ]
+        .into();
+        let output = array.self_intersections();
+        assert!(!output.self_intersects.value(0));
+        assert!(output.self_intersects.is_null(1));
+        assert!(output.points.is_null(1));
+        assert_eq!(
+            output.points.value_as_geo(2),
+            MultiPoint::new(vec![point!(x: 4., y: 1.), point!(x: 4., y: 2.)])
+        );
+    }
+
+    #[test]
+    fn overlapping_segments() {
+        // Goes back along itself
+        let array: LineStringArray<i32> =
+            vec![line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)]]
+                .as_slice()
+                .into();
+        let output = array.self_intersections();
+        assert!(output.self_intersects.value(0));
+        assert_eq!(
+            output.points.value_as_geo(0),
+            MultiPoint::new(vec![point!(x: 1., y: 0.), point!(x: 2., y: 0.)])
+        );
+
+        let dyn_array: &dyn GeometryArrayTrait = &array;
+        assert_eq!(dyn_array.self_intersections().unwrap(), output);
+    }
+}
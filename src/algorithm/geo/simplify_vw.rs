@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::array::*;
-use crate::chunked_array::ChunkedGeometryArray;
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -140,33 +140,6 @@ impl SimplifyVw for &dyn GeometryArrayTrait {
     }
 }
 
-impl SimplifyVw for ChunkedGeometryArray<PointArray> {
-    type Output = Self;
-
-    fn simplify_vw(&self, epsilon: &f64) -> Self::Output {
-        self.map(|chunk| chunk.simplify_vw(epsilon))
-            .try_into()
-            .unwrap()
-    }
-}
-
-/// Implementation that iterates over chunks
-macro_rules! chunked_impl {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> SimplifyVw for $type {
-            type Output = Self;
-
-            fn simplify_vw(&self, epsilon: &f64) -> Self {
-                self.map(|chunk| chunk.simplify_vw(epsilon))
-                    .try_into()
-                    .unwrap()
-            }
-        }
-    };
-}
-
-chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<PolygonArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPolygonArray<O>>);
+impl_chunked_unary!(SimplifyVw {
+    fn simplify_vw(&self, epsilon: &f64);
+});
@@ -0,0 +1,142 @@
+use crate::algorithm::geo::{Area, EuclideanLength};
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+use arrow::compute::{concat, sort_to_indices, SortOptions};
+use arrow_array::{Array, OffsetSizeTrait, UInt32Array};
+
+/// Sort geometries by a measure, returning the indices of the sorted geometries.
+///
+/// The returned indices can be passed to [`Take`][crate::algorithm::native::Take] to reorder
+/// the array. By default, geometries are sorted in ascending order with null geometries first;
+/// pass [`SortOptions`] to sort in descending order or to put nulls last.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::SortIndices;
+/// use geoarrow::array::LineStringArray;
+/// use arrow::compute::SortOptions;
+/// use arrow_array::UInt32Array;
+/// use geo::line_string;
+///
+/// let short = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+/// let long = line_string![(x: 0., y: 0.), (x: 5., y: 0.)];
+/// let array: LineStringArray<i32> = vec![Some(long), None, Some(short)].into();
+///
+/// let options = SortOptions {
+///     descending: true,
+///     nulls_first: false,
+/// };
+/// let indices = array.sort_indices_by_length(Some(options)).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![0, 2, 1]));
+/// ```
+pub trait SortIndices {
+    /// Sort geometries by their unsigned planar area.
+    fn sort_indices_by_area(&self, options: Option<SortOptions>) -> Result<UInt32Array>;
+
+    /// Sort geometries by their planar length.
+    ///
+    /// This is only supported for point, line string, multi-point and multi-line string arrays.
+    fn sort_indices_by_length(&self, options: Option<SortOptions>) -> Result<UInt32Array>;
+}
+
+impl SortIndices for &dyn GeometryArrayTrait {
+    fn sort_indices_by_area(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+        Ok(sort_to_indices(&self.unsigned_area()?, options, None)?)
+    }
+
+    fn sort_indices_by_length(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+        Ok(sort_to_indices(&self.euclidean_length()?, options, None)?)
+    }
+}
+
+impl SortIndices for PointArray {
+    fn sort_indices_by_area(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+        self.as_ref().sort_indices_by_area(options)
+    }
+
+    fn sort_indices_by_length(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+        self.as_ref().sort_indices_by_length(options)
+    }
+}
+
+/// Implementation that sorts through the dyn array
+macro_rules! dyn_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> SortIndices for $type {
+            fn sort_indices_by_area(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+                self.as_ref().sort_indices_by_area(options)
+            }
+
+            fn sort_indices_by_length(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+                self.as_ref().sort_indices_by_length(options)
+            }
+        }
+    };
+}
+
+dyn_impl!(LineStringArray<O>);
+dyn_impl!(PolygonArray<O>);
+dyn_impl!(MultiPointArray<O>);
+dyn_impl!(MultiLineStringArray<O>);
+dyn_impl!(MultiPolygonArray<O>);
+dyn_impl!(MixedGeometryArray<O>);
+dyn_impl!(GeometryCollectionArray<O>);
+
+/// The indices are into the full chunked array, i.e. as if all chunks were concatenated.
+impl<G: GeometryArrayTrait> SortIndices for ChunkedGeometryArray<G> {
+    fn sort_indices_by_area(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+        let areas = self.try_map(|chunk| chunk.as_ref().unsigned_area())?;
+        let areas = areas
+            .iter()
+            .map(|arr| arr as &dyn Array)
+            .collect::<Vec<_>>();
+        Ok(sort_to_indices(concat(&areas)?.as_ref(), options, None)?)
+    }
+
+    fn sort_indices_by_length(&self, options: Option<SortOptions>) -> Result<UInt32Array> {
+        let lengths = self.try_map(|chunk| chunk.as_ref().euclidean_length())?;
+        let lengths = lengths
+            .iter()
+            .map(|arr| arr as &dyn Array)
+            .collect::<Vec<_>>();
+        Ok(sort_to_indices(concat(&lengths)?.as_ref(), options, None)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::Take;
+    use crate::test::polygon::{p0, p1};
+    use crate::trait_::GeometryArrayAccessor;
+
+    #[test]
+    fn sort_by_area() {
+        let array: PolygonArray<i32> = vec![p1(), p0()].as_slice().into();
+        let indices = array.sort_indices_by_area(None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 1]));
+
+        let sorted = array.take(&indices).unwrap();
+        assert_eq!(sorted.value_as_geo(0), p1());
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let indices = array.sort_indices_by_area(Some(options)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 0]));
+    }
+
+    #[test]
+    fn sort_chunked_by_area() {
+        let chunked = ChunkedGeometryArray::new(vec![
+            PolygonArray::<i32>::from(vec![p0()].as_slice()),
+            PolygonArray::<i32>::from(vec![p1()].as_slice()),
+        ]);
+        let indices = chunked.sort_indices_by_area(None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 0]));
+    }
+}
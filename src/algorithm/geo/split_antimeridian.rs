@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geo::{coord, BooleanOps, BoundingRect, Coord, LineString, MultiLineString, MultiPolygon};
+use geo::{Polygon, Rect, Translate};
+
+/// Split geometries that cross the antimeridian (±180° longitude) into parts on either side of it.
+///
+/// Coordinates are interpreted as longitude and latitude in degrees, with longitudes in
+/// `[-180, 180]`. A segment is considered to cross the antimeridian if its endpoints are more than
+/// 180° of longitude apart, i.e. every segment is assumed to take the shorter way around the
+/// globe. Crossing line strings and polygons are split exactly at ±180°, so that no part of the
+/// output wraps around the globe. Geometries that don't cross the antimeridian are returned
+/// unchanged, apart from being converted to multi geometries.
+///
+/// Polygons that contain a pole are not split, as these can't be represented in longitude and
+/// latitude without wrapping around the globe.
+pub trait SplitAntimeridian {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::SplitAntimeridian;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::line_string;
+    ///
+    /// let array: LineStringArray<i32> =
+    ///     vec![line_string![(x: 170., y: 0.), (x: -170., y: 10.)]].as_slice().into();
+    /// let split = array.split_antimeridian();
+    /// let parts = split.value_as_geo(0);
+    /// assert_eq!(parts.0[0], line_string![(x: 170., y: 0.), (x: 180., y: 5.)]);
+    /// assert_eq!(parts.0[1], line_string![(x: -180., y: 5.), (x: -170., y: 10.)]);
+    /// ```
+    fn split_antimeridian(&self) -> Self::Output;
+}
+
+/// Split a line string at each segment that crosses the antimeridian.
+fn split_line_string(line_string: &LineString) -> Vec<LineString> {
+    let mut parts = vec![];
+    let mut current: Vec<Coord> = vec![];
+    for coord in line_string.coords() {
+        if let Some(prev) = current.last().copied() {
+            let dx = coord.x - prev.x;
+            if dx.abs() > 180. {
+                // Make the segment continuous across the antimeridian to interpolate its latitude
+                let shifted_x = coord.x - 360. * dx.signum();
+                let edge = if shifted_x > prev.x { 180. } else { -180. };
+                let t = (edge - prev.x) / (shifted_x - prev.x);
+                let y = prev.y + t * (coord.y - prev.y);
+
+                let crossing = coord! { x: edge, y: y };
+                if crossing != prev {
+                    current.push(crossing);
+                }
+                // A part that only touches the antimeridian at a vertex isn't a line
+                let part = std::mem::take(&mut current);
+                if part.len() > 1 {
+                    parts.push(LineString::new(part));
+                }
+                let crossing = coord! { x: -edge, y: y };
+                if crossing != *coord {
+                    current.push(crossing);
+                }
+            }
+        }
+        current.push(*coord);
+    }
+    if current.len() > 1 || parts.is_empty() {
+        parts.push(LineString::new(current));
+    }
+    parts
+}
+
+/// Shift longitudes by multiples of 360° so that no segment is longer than 180° of longitude.
+fn unwrap_ring(ring: &LineString) -> LineString {
+    let mut shift = 0.;
+    let mut prev_x: Option<f64> = None;
+    let coords = ring
+        .coords()
+        .map(|coord| {
+            if let Some(prev_x) = prev_x {
+                let dx = coord.x - prev_x;
+                if dx.abs() > 180. {
+                    shift -= 360. * dx.signum();
+                }
+            }
+            prev_x = Some(coord.x);
+            coord! { x: coord.x + shift, y: coord.y }
+        })
+        .collect();
+    LineString::new(coords)
+}
+
+/// Split a polygon into the parts on either side of the antimeridian.
+fn split_polygon(polygon: &Polygon) -> MultiPolygon {
+    let exterior = unwrap_ring(polygon.exterior());
+    if exterior.0.first() != exterior.0.last() {
+        // The exterior wraps around the globe, i.e. the polygon contains a pole
+        return MultiPolygon::new(vec![polygon.clone()]);
+    }
+    let Some(bbox) = exterior.bounding_rect() else {
+        return MultiPolygon::new(vec![polygon.clone()]);
+    };
+    if bbox.min().x >= -180. && bbox.max().x <= 180. {
+        return MultiPolygon::new(vec![polygon.clone()]);
+    }
+
+    // Move each hole next to the exterior
+    let center_x = bbox.center().x;
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(|interior| {
+            let mut interior = unwrap_ring(interior);
+            if let Some(first) = interior.0.first() {
+                let shift = 360. * ((center_x - first.x) / 360.).round();
+                interior.translate_mut(shift, 0.);
+            }
+            interior
+        })
+        .collect();
+    let unwrapped = Polygon::new(exterior, interiors);
+
+    let mut parts = vec![];
+    for shift in [-360., 0., 360.] {
+        let (min_x, max_x) = (shift - 180., shift + 180.);
+        if bbox.max().x <= min_x || bbox.min().x >= max_x {
+            continue;
+        }
+        let window = Rect::new(
+            coord! { x: min_x, y: bbox.min().y },
+            coord! { x: max_x, y: bbox.max().y },
+        );
+        let clipped = unwrapped.intersection(&window.to_polygon());
+        parts.extend(clipped.translate(-shift, 0.));
+    }
+    MultiPolygon::new(parts)
+}
+
+impl<O: OffsetSizeTrait> SplitAntimeridian for LineStringArray<O> {
+    type Output = MultiLineStringArray<O>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| MultiLineString::new(split_line_string(&geom))))
+            .collect();
+        output_geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait> SplitAntimeridian for MultiLineStringArray<O> {
+    type Output = MultiLineStringArray<O>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g.map(|geom| {
+                    MultiLineString::new(geom.iter().flat_map(split_line_string).collect())
+                })
+            })
+            .collect();
+        output_geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait> SplitAntimeridian for PolygonArray<O> {
+    type Output = MultiPolygonArray<O>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let output_geoms: Vec<Option<MultiPolygon>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| split_polygon(&geom)))
+            .collect();
+        output_geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait> SplitAntimeridian for MultiPolygonArray<O> {
+    type Output = MultiPolygonArray<O>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let output_geoms: Vec<Option<MultiPolygon>> = self
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g.map(|geom| MultiPolygon::new(geom.iter().flat_map(split_polygon).collect()))
+            })
+            .collect();
+        output_geoms.into()
+    }
+}
+
+impl SplitAntimeridian for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().split_antimeridian()),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().split_antimeridian())
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().split_antimeridian()),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().split_antimeridian()),
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().split_antimeridian())
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().split_antimeridian())
+            }
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().split_antimeridian()),
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().split_antimeridian())
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+macro_rules! impl_chunked {
+    ($chunked_array:ty) => {
+        impl<O: OffsetSizeTrait> SplitAntimeridian for $chunked_array {
+            type Output = ChunkedMultiLineStringArray<O>;
+
+            fn split_antimeridian(&self) -> Self::Output {
+                self.map(|chunk| chunk.split_antimeridian())
+                    .try_into()
+                    .unwrap()
+            }
+        }
+    };
+}
+
+impl_chunked!(ChunkedLineStringArray<O>);
+impl_chunked!(ChunkedMultiLineStringArray<O>);
+
+macro_rules! impl_chunked_polygon {
+    ($chunked_array:ty) => {
+        impl<O: OffsetSizeTrait> SplitAntimeridian for $chunked_array {
+            type Output = ChunkedMultiPolygonArray<O>;
+
+            fn split_antimeridian(&self) -> Self::Output {
+                self.map(|chunk| chunk.split_antimeridian())
+                    .try_into()
+                    .unwrap()
+            }
+        }
+    };
+}
+
+impl_chunked_polygon!(ChunkedPolygonArray<O>);
+impl_chunked_polygon!(ChunkedMultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, polygon, Area};
+
+    #[test]
+    fn line_string_not_crossing() {
+        let line_string = line_string![(x: -170., y: 0.), (x: -10., y: 0.), (x: 150., y: 0.)];
+        let array: LineStringArray<i32> = vec![Some(line_string.clone()), None].into();
+        let split = array.split_antimeridian();
+        assert_eq!(
+            split.value_as_geo(0),
+            MultiLineString::new(vec![line_string])
+        );
+        assert!(split.is_null(1));
+    }
+
+    #[test]
+    fn line_string_crossing_twice() {
+        let line_string = line_string![(x: 170., y: 0.), (x: -170., y: 0.), (x: 170., y: 10.)];
+        let parts = split_line_string(&line_string);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(
+            parts[1],
+            line_string![(x: -180., y: 0.), (x: -170., y: 0.), (x: -180., y: 5.)]
+        );
+    }
+
+    #[test]
+    fn line_string_vertex_on_antimeridian() {
+        let line_string = line_string![(x: 180., y: 0.), (x: -170., y: 5.), (x: 170., y: 5.)];
+        let parts = split_line_string(&line_string);
+        assert_eq!(
+            parts,
+            vec![
+                line_string![(x: -180., y: 0.), (x: -170., y: 5.), (x: -180., y: 5.)],
+                line_string![(x: 180., y: 5.), (x: 170., y: 5.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn polygon_crossing() {
+        let polygon = polygon![
+            (x: 170., y: 0.),
+            (x: -170., y: 0.),
+            (x: -170., y: 10.),
+            (x: 170., y: 10.),
+            (x: 170., y: 0.),
+        ];
+        let array: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let split = array.split_antimeridian().value_as_geo(0);
+        assert_eq!(split.0.len(), 2);
+        for part in split.iter() {
+            assert_eq!(part.unsigned_area(), 100.);
+            let bbox = part.bounding_rect().unwrap();
+            assert!(bbox.min().x >= -180. && bbox.max().x <= 180.);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::native::simd;
 use crate::array::*;
-use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
 use geo::Translate as _Translate;
@@ -48,6 +49,21 @@ pub trait Translate {
     // fn translate_mut(&mut self, x_offset: T, y_offset: T);
 }
 
+/// When both offsets are scalars, translate the coordinate buffer directly instead of iterating
+/// over geometries. Returns `None` if either offset is an array.
+fn translate_coords(
+    coords: &CoordBuffer,
+    x_offset: &BroadcastablePrimitive<Float64Type>,
+    y_offset: &BroadcastablePrimitive<Float64Type>,
+) -> Option<CoordBuffer> {
+    match (x_offset, y_offset) {
+        (BroadcastablePrimitive::Scalar(x_offset), BroadcastablePrimitive::Scalar(y_offset)) => {
+            Some(simd::map_xy(coords, |x, y| (x + x_offset, y + y_offset)))
+        }
+        _ => None,
+    }
+}
+
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl Translate for PointArray {
     fn translate(
@@ -55,6 +71,10 @@ impl Translate for PointArray {
         x_offset: BroadcastablePrimitive<Float64Type>,
         y_offset: BroadcastablePrimitive<Float64Type>,
     ) -> Self {
+        if let Some(coords) = translate_coords(&self.coords, &x_offset, &y_offset) {
+            return self.clone().with_coords(coords);
+        }
+
         let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
 
         self.iter_geo().zip(&x_offset).zip(&y_offset).for_each(
@@ -80,6 +100,10 @@ macro_rules! iter_geo_impl {
                 x_offset: BroadcastablePrimitive<Float64Type>,
                 y_offset: BroadcastablePrimitive<Float64Type>,
             ) -> Self {
+                if let Some(coords) = translate_coords(&self.coords, &x_offset, &y_offset) {
+                    return self.clone().with_coords(coords);
+                }
+
                 let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
 
                 self.iter_geo().zip(&x_offset).zip(&y_offset).for_each(
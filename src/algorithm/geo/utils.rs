@@ -1,7 +1,24 @@
 use arrow_array::Float64Array;
 use arrow_buffer::NullBuffer;
+use geo::{BooleanOps, MultiPolygon};
 
 pub(crate) fn zeroes(len: usize, nulls: Option<&NullBuffer>) -> Float64Array {
     let values = vec![0.0f64; len];
     Float64Array::new(values.into(), nulls.cloned())
 }
+
+/// Union polygons by repeatedly merging pairs, which is much faster than adding one polygon at a
+/// time to a growing union.
+pub(crate) fn cascaded_union(mut parts: Vec<MultiPolygon>) -> MultiPolygon {
+    while parts.len() > 1 {
+        parts = parts
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => left.union(right),
+                [single] => single.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    parts.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
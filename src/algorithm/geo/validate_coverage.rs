@@ -0,0 +1,167 @@
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+use geo::{Area, BooleanOps, EuclideanLength, MultiPolygon, Polygon};
+
+use crate::algorithm::geo::utils::cascaded_union;
+use crate::algorithm::rstar::envelope_tree;
+use crate::array::*;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The result of [`ValidateCoverage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageValidation<O: OffsetSizeTrait> {
+    /// The index of the first geometry of each overlapping pair.
+    pub overlap_left: UInt32Array,
+
+    /// The index of the second geometry of each overlapping pair, always larger than the first.
+    pub overlap_right: UInt32Array,
+
+    /// The region where each pair of geometries overlaps.
+    pub overlaps: MultiPolygonArray<O>,
+
+    /// Gaps between geometries that are narrower than the gap width.
+    pub gaps: PolygonArray<O>,
+}
+
+impl<O: OffsetSizeTrait> CoverageValidation<O> {
+    /// Whether the geometries form a valid coverage, i.e. there are no overlaps and no gaps.
+    pub fn is_valid(&self) -> bool {
+        self.overlaps.is_empty() && self.gaps.is_empty()
+    }
+}
+
+/// Check that polygons form a valid coverage, like the parcels of a cadastre or the units of an
+/// administrative hierarchy.
+///
+/// A valid coverage has no overlaps, i.e. the interiors of any two polygons are disjoint, and no
+/// gaps, i.e. no narrow holes in the union of all polygons. Holes are only reported as gaps if
+/// they are narrower than `gap_width`, so that deliberate holes like lakes are not flagged. The
+/// width of a hole is approximated as twice its area divided by its perimeter, which is exact for
+/// long thin slivers. Null geometries are skipped.
+///
+/// Candidate overlapping pairs are found with an R-Tree of bounding boxes, so only polygons
+/// whose bounding boxes intersect are compared.
+pub trait ValidateCoverage {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::ValidateCoverage;
+    /// use geoarrow::array::PolygonArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::polygon;
+    ///
+    /// let array: PolygonArray<i32> = vec![
+    ///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+    ///     polygon![(x: 1., y: 0.), (x: 3., y: 0.), (x: 3., y: 2.), (x: 1., y: 2.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let validation = array.validate_coverage(0.1);
+    /// assert!(!validation.is_valid());
+    /// assert_eq!(validation.overlaps.len(), 1);
+    /// ```
+    fn validate_coverage(&self, gap_width: f64) -> Self::Output;
+}
+
+/// Find holes in the union of the geometries that are narrower than `gap_width`.
+fn find_gaps(geoms: Vec<MultiPolygon>, gap_width: f64) -> Vec<Polygon> {
+    cascaded_union(geoms)
+        .into_iter()
+        .flat_map(|polygon| polygon.into_inner().1)
+        .filter_map(|interior| {
+            let gap = Polygon::new(interior, vec![]);
+            let perimeter = gap.exterior().euclidean_length();
+            let width = 2. * gap.unsigned_area() / perimeter;
+            (width < gap_width).then_some(gap)
+        })
+        .collect()
+}
+
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> ValidateCoverage for $type {
+            type Output = CoverageValidation<O>;
+
+            fn validate_coverage(&self, gap_width: f64) -> Self::Output {
+                let tree = envelope_tree(self);
+                let mut candidates: Vec<(usize, usize)> = tree
+                    .intersection_candidates_with_other_tree(&tree)
+                    .map(|(left, right)| (left.data, right.data))
+                    .filter(|(left, right)| left < right)
+                    .collect();
+                candidates.sort_unstable();
+
+                let mut overlap_left = vec![];
+                let mut overlap_right = vec![];
+                let mut overlaps = vec![];
+                for (left, right) in candidates {
+                    let overlap = self
+                        .value_as_geo(left)
+                        .intersection(&self.value_as_geo(right));
+                    if overlap.unsigned_area() > 0. {
+                        overlap_left.push(left as u32);
+                        overlap_right.push(right as u32);
+                        overlaps.push(overlap);
+                    }
+                }
+
+                let geoms = self.iter_geo().flatten().map(MultiPolygon::from).collect();
+                let gaps = find_gaps(geoms, gap_width);
+
+                CoverageValidation {
+                    overlap_left: overlap_left.into(),
+                    overlap_right: overlap_right.into(),
+                    overlaps: overlaps.as_slice().into(),
+                    gaps: gaps.as_slice().into(),
+                }
+            }
+        }
+    };
+}
+
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::polygon;
+
+    #[test]
+    fn valid_coverage() {
+        let array: PolygonArray<i32> = vec![
+            Some(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)]),
+            None,
+            Some(polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)]),
+        ]
+        .into();
+        assert!(array.validate_coverage(0.1).is_valid());
+    }
+
+    #[test]
+    fn narrow_gap_and_wide_hole() {
+        // A 0.05 wide sliver between the first two polygons inside the frame, and a wide hole in the
+        // last polygon
+        let array: PolygonArray<i32> = vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 4.), (x: 0., y: 4.)],
+            polygon![(x: 1.05, y: 0.), (x: 2., y: 0.), (x: 2., y: 4.), (x: 1.05, y: 4.)],
+            polygon!(
+                exterior: [(x: -1., y: -1.), (x: 3., y: -1.), (x: 3., y: 5.), (x: -1., y: 5.)],
+                interiors: [[(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 4.), (x: 0., y: 4.)]],
+            ),
+            polygon!(
+                exterior: [(x: 10., y: 10.), (x: 20., y: 10.), (x: 20., y: 20.), (x: 10., y: 20.)],
+                interiors: [[(x: 12., y: 12.), (x: 18., y: 12.), (x: 18., y: 18.), (x: 12., y: 18.)]],
+            ),
+        ]
+        .as_slice()
+        .into();
+        let validation = array.validate_coverage(0.1);
+        assert!(validation.overlaps.is_empty());
+        assert_eq!(validation.gaps.len(), 1);
+        let gap = validation.gaps.value_as_geo(0);
+        assert!((gap.unsigned_area() - 0.2).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::{BooleanBuilder, ListBuilder};
+use arrow_array::{BooleanArray, ListArray, OffsetSizeTrait};
+use geo::winding_order::WindingOrder;
+use geo::{LineString, Winding as _Winding};
+
+/// Query the winding order of polygon rings.
+///
+/// Rings whose winding order is undefined, for example because all of their points are
+/// collinear, have a null result.
+pub trait Winding {
+    type Output;
+    type RingOutput;
+
+    /// Returns whether the exterior ring of each polygon is wound counter-clockwise.
+    ///
+    /// For multi polygons, this is true if the exterior rings of all polygons are
+    /// counter-clockwise. Null geometries and empty polygons have a null result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::geo::Winding;
+    /// use geoarrow::array::PolygonArray;
+    /// use geo::polygon;
+    ///
+    /// let ccw = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// let cw = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.)];
+    /// let array: PolygonArray<i32> = vec![ccw, cw].as_slice().into();
+    ///
+    /// let is_ccw = array.is_ccw();
+    /// assert!(is_ccw.value(0));
+    /// assert!(!is_ccw.value(1));
+    /// ```
+    fn is_ccw(&self) -> Self::Output;
+
+    /// Returns whether each ring of each polygon is wound counter-clockwise, as a list per
+    /// geometry.
+    ///
+    /// The rings of a polygon are listed exterior ring first, followed by its interior rings.
+    /// The rings of a multi polygon are listed polygon by polygon.
+    fn is_ccw_per_ring(&self) -> Self::RingOutput;
+}
+
+fn ring_is_ccw(ring: &LineString) -> Option<bool> {
+    ring.winding_order()
+        .map(|order| order == WindingOrder::CounterClockwise)
+}
+
+fn polygon_is_ccw(polygon: &geo::Polygon) -> Option<bool> {
+    ring_is_ccw(polygon.exterior())
+}
+
+fn multi_polygon_is_ccw(multi_polygon: &geo::MultiPolygon) -> Option<bool> {
+    if multi_polygon.0.is_empty() {
+        return None;
+    }
+    multi_polygon.iter().try_fold(true, |all_ccw, polygon| {
+        polygon_is_ccw(polygon).map(|is_ccw| all_ccw && is_ccw)
+    })
+}
+
+fn polygon_rings(polygon: &geo::Polygon) -> impl Iterator<Item = &LineString> {
+    std::iter::once(polygon.exterior())
+        .filter(|exterior| !exterior.0.is_empty())
+        .chain(polygon.interiors())
+}
+
+/// Build the per-ring output, given a function that lists the rings of each geometry.
+fn per_ring<G>(
+    len: usize,
+    geometries: impl Iterator<Item = Option<G>>,
+    rings: impl Fn(&G) -> Vec<Option<bool>>,
+) -> ListArray {
+    let mut builder = ListBuilder::with_capacity(BooleanBuilder::new(), len);
+    for maybe_g in geometries {
+        if let Some(geom) = maybe_g {
+            builder.values().extend(rings(&geom));
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    builder.finish()
+}
+
+impl<O: OffsetSizeTrait> Winding for PolygonArray<O> {
+    type Output = BooleanArray;
+    type RingOutput = ListArray;
+
+    fn is_ccw(&self) -> Self::Output {
+        self.iter_geo()
+            .map(|maybe_g| maybe_g.and_then(|polygon| polygon_is_ccw(&polygon)))
+            .collect()
+    }
+
+    fn is_ccw_per_ring(&self) -> Self::RingOutput {
+        per_ring(self.len(), self.iter_geo(), |polygon| {
+            polygon_rings(polygon).map(ring_is_ccw).collect()
+        })
+    }
+}
+
+impl<O: OffsetSizeTrait> Winding for MultiPolygonArray<O> {
+    type Output = BooleanArray;
+    type RingOutput = ListArray;
+
+    fn is_ccw(&self) -> Self::Output {
+        self.iter_geo()
+            .map(|maybe_g| maybe_g.and_then(|multi_polygon| multi_polygon_is_ccw(&multi_polygon)))
+            .collect()
+    }
+
+    fn is_ccw_per_ring(&self) -> Self::RingOutput {
+        per_ring(self.len(), self.iter_geo(), |multi_polygon| {
+            multi_polygon
+                .iter()
+                .flat_map(polygon_rings)
+                .map(ring_is_ccw)
+                .collect()
+        })
+    }
+}
+
+impl Winding for &dyn GeometryArrayTrait {
+    type Output = Result<BooleanArray>;
+    type RingOutput = Result<ListArray>;
+
+    fn is_ccw(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Polygon(_) => self.as_polygon().is_ccw(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().is_ccw(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().is_ccw(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().is_ccw(),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+
+    fn is_ccw_per_ring(&self) -> Self::RingOutput {
+        let result = match self.data_type() {
+            GeoDataType::Polygon(_) => self.as_polygon().is_ccw_per_ring(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().is_ccw_per_ring(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().is_ccw_per_ring(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().is_ccw_per_ring(),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait> Winding for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<BooleanArray>>;
+    type RingOutput = Result<ChunkedArray<ListArray>>;
+
+    fn is_ccw(&self) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().is_ccw())?.try_into()
+    }
+
+    fn is_ccw_per_ring(&self) -> Self::RingOutput {
+        self.try_map(|chunk| chunk.as_ref().is_ccw_per_ring())?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::Array;
+    use geo::{polygon, MultiPolygon};
+
+    #[test]
+    fn rings_and_nulls() {
+        // Both rings are wound counter-clockwise
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        let array: MultiPolygonArray<i32> = vec![
+            Some(MultiPolygon::new(vec![polygon])),
+            None,
+            Some(MultiPolygon::new(vec![])),
+        ]
+        .into();
+
+        let is_ccw = array.is_ccw();
+        assert!(is_ccw.value(0));
+        assert!(is_ccw.is_null(1));
+        assert!(is_ccw.is_null(2));
+
+        let per_ring = array.is_ccw_per_ring();
+        assert_eq!(
+            per_ring.value(0).as_boolean(),
+            &BooleanArray::from(vec![true, true])
+        );
+        assert!(per_ring.is_null(1));
+        assert_eq!(per_ring.value(2).len(), 0);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryScalarTrait;
@@ -85,13 +85,9 @@ impl Area for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> Area for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<Float64Array>>;
-
-    fn area(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().area())?.try_into()
-    }
-}
+impl_chunked_unary!(dyn Area {
+    fn area(&self);
+});
 
 #[cfg(test)]
 mod test {
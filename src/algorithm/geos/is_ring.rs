@@ -1,5 +1,5 @@
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -93,15 +93,6 @@ impl IsRing for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> IsRing for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<BooleanArray>>;
-
-    fn is_ring(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.as_ref().is_ring()?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
-    }
-}
+impl_chunked_unary!(dyn IsRing {
+    fn is_ring(&self);
+});
@@ -1,5 +1,5 @@
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -95,15 +95,6 @@ impl IsValid for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> IsValid for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<BooleanArray>>;
-
-    fn is_valid(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(IsValid::is_valid(&chunk.as_ref())?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
-    }
-}
+impl_chunked_unary!(dyn IsValid {
+    fn is_valid(&self);
+});
@@ -1,7 +1,7 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
-use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::chunked_array::impl_chunked_unary;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryScalarTrait;
@@ -71,10 +71,6 @@ impl Length for &dyn GeometryArrayTrait {
     }
 }
 
-impl<G: GeometryArrayTrait> Length for ChunkedGeometryArray<G> {
-    type Output = Result<ChunkedArray<Float64Array>>;
-
-    fn length(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().length())?.try_into()
-    }
-}
+impl_chunked_unary!(dyn Length {
+    fn length(&self);
+});
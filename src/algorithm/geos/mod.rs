@@ -5,9 +5,11 @@ mod buffer;
 mod is_ring;
 mod is_valid;
 mod length;
+mod topology_preserving_simplify;
 
 pub use area::Area;
 pub use buffer::Buffer;
 pub use is_ring::IsRing;
 pub use is_valid::IsValid;
 pub use length::Length;
+pub use topology_preserving_simplify::TopologyPreservingSimplify;
@@ -0,0 +1,124 @@
+use crate::array::*;
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary};
+use crate::error::Result;
+use crate::io::geos::{FromGEOS, ToGEOS};
+use arrow_array::OffsetSizeTrait;
+use geos::Geom;
+
+/// Simplify geometries with GEOS' topology preserving simplifier.
+///
+/// Like [`Simplify`][crate::algorithm::geo::Simplify] this uses the Ramer–Douglas–Peucker
+/// algorithm, but vertices are kept wherever removing them would change the topology of the
+/// geometry: rings don't collapse or cross each other, holes stay inside their shell, and the
+/// polygons of a multi polygon don't come to overlap. To keep adjacent polygons from crossing each
+/// other along their shared boundary, they must be the parts of the same multi polygon, since
+/// separate rows are simplified independently.
+///
+/// Null geometries stay null.
+pub trait TopologyPreservingSimplify {
+    type Output;
+
+    fn topology_preserving_simplify(&self, tolerance: f64) -> Self::Output;
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> TopologyPreservingSimplify for $type {
+            type Output = Result<$type>;
+
+            fn topology_preserving_simplify(&self, tolerance: f64) -> Self::Output {
+                let output_geoms = self
+                    .to_geos()?
+                    .into_iter()
+                    .map(|maybe_g| {
+                        maybe_g
+                            .map(|g| g.topology_preserve_simplify(tolerance))
+                            .transpose()
+                    })
+                    .collect::<std::result::Result<Vec<_>, geos::Error>>()?;
+                <$type>::try_from_geos(output_geoms)
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray<O>);
+iter_geos_impl!(PolygonArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>);
+iter_geos_impl!(MixedGeometryArray<O>);
+iter_geos_impl!(GeometryCollectionArray<O>);
+
+impl_dyn_unary!(TopologyPreservingSimplify -> Result<Arc<dyn GeometryArrayTrait>> {
+    fn topology_preserving_simplify(&self, tolerance: f64);
+} [
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+    Mixed => as_mixed,
+    LargeMixed => as_large_mixed,
+    GeometryCollection => as_geometry_collection,
+    LargeGeometryCollection => as_large_geometry_collection,
+]);
+
+impl_chunked_unary!(TopologyPreservingSimplify {
+    fn topology_preserving_simplify(&self, tolerance: f64);
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArrayAccessor;
+    use geo::{line_string, polygon, MultiPolygon};
+
+    #[test]
+    fn keeps_hole_inside_shell() {
+        // Plain simplification would collapse the shell onto a triangle that cuts the hole
+        let polygon = polygon!(
+            exterior: [
+                (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 10.5),
+                (x: 0., y: 10.), (x: 0., y: 0.)
+            ],
+            interiors: [
+                [(x: 4., y: 9.), (x: 6., y: 9.), (x: 6., y: 10.2), (x: 4., y: 10.2), (x: 4., y: 9.)],
+            ],
+        );
+        let array: PolygonArray<i32> = vec![Some(polygon), None].into();
+        let simplified = array.topology_preserving_simplify(1.).unwrap();
+        assert!(simplified.get_as_geo(1).is_none());
+        let simplified = simplified.value_as_geo(0);
+        assert_eq!(simplified.interiors().len(), 1);
+        assert!(simplified.exterior().0.len() >= 5);
+    }
+
+    #[test]
+    fn shared_boundary() {
+        let left = polygon![
+            (x: 0., y: 0.), (x: 1., y: 0.), (x: 1.1, y: 0.5), (x: 1., y: 1.), (x: 0., y: 1.)
+        ];
+        let right = polygon![
+            (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.), (x: 1.1, y: 0.5)
+        ];
+        let array: MultiPolygonArray<i32> =
+            vec![MultiPolygon::new(vec![left, right])].as_slice().into();
+        let simplified = array.topology_preserving_simplify(0.2).unwrap();
+        assert_eq!(simplified.value_as_geo(0).0.len(), 2);
+
+        let lines: LineStringArray<i64> =
+            vec![line_string![(x: 0., y: 0.), (x: 1., y: 0.01), (x: 2., y: 0.)]]
+                .as_slice()
+                .into();
+        assert_eq!(
+            lines
+                .topology_preserving_simplify(0.1)
+                .unwrap()
+                .value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)]
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Bindings to the [`h3o`] crate for indexing geometries with the [H3](https://h3geo.org/)
+//! hexagonal grid.
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_array::{ListArray, OffsetSizeTrait, UInt64Array};
+use h3o::geom::{PolyfillConfig, ToCells};
+use h3o::{LatLng, Resolution};
+
+fn resolution(resolution: u8) -> Result<Resolution> {
+    Resolution::try_from(resolution).map_err(|err| GeoArrowError::General(err.to_string()))
+}
+
+/// Find the H3 cell containing each point.
+///
+/// Point coordinates are interpreted as longitude and latitude in degrees.
+pub trait H3Cell {
+    type Output;
+
+    /// Returns the id of the H3 cell at `resolution` (0-15) containing each point. Null points
+    /// have a null cell id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::h3::H3Cell;
+    /// use geoarrow::array::PointArray;
+    /// use arrow_array::Array;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 2.3522, y: 48.8566)].as_slice().into();
+    /// let cells = array.h3_cell(9).unwrap();
+    /// assert!(cells.is_valid(0));
+    /// ```
+    fn h3_cell(&self, resolution: u8) -> Self::Output;
+}
+
+impl H3Cell for PointArray {
+    type Output = Result<UInt64Array>;
+
+    fn h3_cell(&self, resolution: u8) -> Self::Output {
+        let resolution = self::resolution(resolution)?;
+        self.iter_geo()
+            .map(|maybe_point| {
+                maybe_point
+                    .map(|point| {
+                        let lat_lng = LatLng::new(point.y(), point.x())
+                            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                        Ok(u64::from(lat_lng.to_cell(resolution)))
+                    })
+                    .transpose()
+            })
+            .collect()
+    }
+}
+
+impl H3Cell for ChunkedGeometryArray<PointArray> {
+    type Output = Result<ChunkedArray<UInt64Array>>;
+
+    fn h3_cell(&self, resolution: u8) -> Self::Output {
+        self.try_map(|chunk| chunk.h3_cell(resolution))?.try_into()
+    }
+}
+
+/// Find the H3 cells covering each polygon.
+///
+/// Polygon coordinates are interpreted as longitude and latitude in degrees.
+pub trait H3Polyfill {
+    type Output;
+
+    /// Returns the ids of the H3 cells at `resolution` (0-15) whose centroids are contained in
+    /// each polygon. Null polygons have a null list of cells.
+    fn h3_polyfill(&self, resolution: u8) -> Self::Output;
+}
+
+impl<O: OffsetSizeTrait> H3Polyfill for PolygonArray<O> {
+    type Output = Result<ListArray>;
+
+    fn h3_polyfill(&self, resolution: u8) -> Self::Output {
+        let config = PolyfillConfig::new(self::resolution(resolution)?);
+        let mut builder = ListBuilder::with_capacity(UInt64Builder::new(), self.len());
+        for maybe_polygon in self.iter_geo() {
+            if let Some(polygon) = maybe_polygon {
+                let polygon = h3o::geom::Polygon::from_degrees(polygon)
+                    .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                builder
+                    .values()
+                    .extend(polygon.to_cells(config).map(|cell| Some(u64::from(cell))));
+                builder.append(true);
+            } else {
+                builder.append_null();
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+impl<O: OffsetSizeTrait> H3Polyfill for ChunkedGeometryArray<PolygonArray<O>> {
+    type Output = Result<ChunkedArray<ListArray>>;
+
+    fn h3_polyfill(&self, resolution: u8) -> Self::Output {
+        self.try_map(|chunk| chunk.h3_polyfill(resolution))?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use arrow_array::Array;
+    use geo::{point, polygon};
+
+    #[test]
+    fn h3_cell_of_points() {
+        let array: PointArray = vec![Some(point!(x: 2.3522, y: 48.8566)), None].into();
+        let cells = array.h3_cell(9).unwrap();
+        assert!(cells.is_null(1));
+
+        let coarse = array.h3_cell(0).unwrap();
+        assert_ne!(cells.value(0), coarse.value(0));
+
+        assert!(array.h3_cell(16).is_err());
+    }
+
+    #[test]
+    fn h3_polyfill_contains_cell_of_center() {
+        let polygon = polygon![
+            (x: 2.2, y: 48.8),
+            (x: 2.5, y: 48.8),
+            (x: 2.5, y: 48.9),
+            (x: 2.2, y: 48.9),
+            (x: 2.2, y: 48.8),
+        ];
+        let array: PolygonArray<i32> = vec![Some(polygon), None].into();
+        let cells = array.h3_polyfill(7).unwrap();
+        assert!(cells.is_null(1));
+
+        let center: PointArray = vec![point!(x: 2.35, y: 48.85)].as_slice().into();
+        let center_cell = center.h3_cell(7).unwrap().value(0);
+        let polygon_cells = cells.value(0);
+        assert!(polygon_cells
+            .as_primitive::<UInt64Type>()
+            .values()
+            .contains(&center_cell));
+    }
+}
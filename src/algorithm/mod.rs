@@ -6,6 +6,8 @@ pub mod geo;
 pub mod geodesy;
 #[cfg(feature = "geos")]
 pub mod geos;
+#[cfg(feature = "h3")]
+pub mod h3;
 pub mod native;
 #[cfg(feature = "proj")]
 pub mod proj;
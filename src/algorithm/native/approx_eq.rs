@@ -0,0 +1,332 @@
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+
+use crate::algorithm::native::Binary;
+use crate::array::*;
+use crate::error::Result;
+use crate::geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+use crate::scalar::*;
+
+/// Approximate equality of geometries, for comparing geometries after floating point operations
+/// like reprojection round-trips, where exact equality is too strict.
+///
+/// Two geometries are approximately equal if they have the same type and structure, i.e. the
+/// same number of parts, rings and coordinates, and every pair of corresponding coordinate values
+/// is approximately equal. Like the [`approx`](https://docs.rs/approx) crate, values are compared
+/// either by their absolute difference or relative to their magnitude. Two NaN values, as used by
+/// empty points, are considered equal.
+///
+/// Scalars return a `bool`, while arrays return a [`BooleanArray`] comparing corresponding
+/// geometries, which is null where either geometry is null.
+pub trait ApproxEq<Rhs = Self> {
+    type Output;
+
+    /// Whether the absolute difference of every pair of coordinate values is at most `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::ApproxEq;
+    /// use geoarrow::array::LineStringArray;
+    /// use geo::line_string;
+    ///
+    /// let left: LineStringArray<i32> =
+    ///     vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]].as_slice().into();
+    /// let right: LineStringArray<i32> =
+    ///     vec![line_string![(x: 0., y: 1e-10), (x: 1., y: 1.)]].as_slice().into();
+    ///
+    /// assert!(left.abs_diff_eq(&right, 1e-9).unwrap().value(0));
+    /// assert!(!left.abs_diff_eq(&right, 1e-11).unwrap().value(0));
+    /// ```
+    fn abs_diff_eq(&self, other: &Rhs, epsilon: f64) -> Self::Output;
+
+    /// Whether every pair of coordinate values differs by at most `epsilon`, or by at most
+    /// `max_relative` times the larger of their magnitudes.
+    fn relative_eq(&self, other: &Rhs, epsilon: f64, max_relative: f64) -> Self::Output;
+}
+
+fn abs_diff_eq(left: f64, right: f64, epsilon: f64) -> bool {
+    (left.is_nan() && right.is_nan()) || (left - right).abs() <= epsilon
+}
+
+fn relative_eq(left: f64, right: f64, epsilon: f64, max_relative: f64) -> bool {
+    if abs_diff_eq(left, right, epsilon) {
+        return true;
+    }
+    let largest = left.abs().max(right.abs());
+    (left - right).abs() <= largest * max_relative
+}
+
+fn coord_approx_eq(
+    left: &impl CoordTrait<T = f64>,
+    right: &impl CoordTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    value_eq(left.x(), right.x()) && value_eq(left.y(), right.y())
+}
+
+fn point_approx_eq(
+    left: &impl PointTrait<T = f64>,
+    right: &impl PointTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    value_eq(left.x(), right.x()) && value_eq(left.y(), right.y())
+}
+
+fn line_string_approx_eq(
+    left: &impl LineStringTrait<T = f64>,
+    right: &impl LineStringTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    left.num_coords() == right.num_coords()
+        && left
+            .coords()
+            .zip(right.coords())
+            .all(|(l, r)| coord_approx_eq(&l, &r, value_eq))
+}
+
+fn polygon_approx_eq(
+    left: &impl PolygonTrait<T = f64>,
+    right: &impl PolygonTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    if left.num_interiors() != right.num_interiors() {
+        return false;
+    }
+
+    let exteriors_eq = match (left.exterior(), right.exterior()) {
+        (None, None) => true,
+        (Some(l), Some(r)) => line_string_approx_eq(&l, &r, value_eq),
+        _ => false,
+    };
+    exteriors_eq
+        && left
+            .interiors()
+            .zip(right.interiors())
+            .all(|(l, r)| line_string_approx_eq(&l, &r, value_eq))
+}
+
+fn multi_point_approx_eq(
+    left: &impl MultiPointTrait<T = f64>,
+    right: &impl MultiPointTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    left.num_points() == right.num_points()
+        && left
+            .points()
+            .zip(right.points())
+            .all(|(l, r)| point_approx_eq(&l, &r, value_eq))
+}
+
+fn multi_line_string_approx_eq(
+    left: &impl MultiLineStringTrait<T = f64>,
+    right: &impl MultiLineStringTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    left.num_lines() == right.num_lines()
+        && left
+            .lines()
+            .zip(right.lines())
+            .all(|(l, r)| line_string_approx_eq(&l, &r, value_eq))
+}
+
+fn multi_polygon_approx_eq(
+    left: &impl MultiPolygonTrait<T = f64>,
+    right: &impl MultiPolygonTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    left.num_polygons() == right.num_polygons()
+        && left
+            .polygons()
+            .zip(right.polygons())
+            .all(|(l, r)| polygon_approx_eq(&l, &r, value_eq))
+}
+
+fn rect_approx_eq(
+    left: &impl RectTrait<T = f64>,
+    right: &impl RectTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    coord_approx_eq(&left.lower(), &right.lower(), value_eq)
+        && coord_approx_eq(&left.upper(), &right.upper(), value_eq)
+}
+
+fn geometry_approx_eq(
+    left: &impl GeometryTrait<T = f64>,
+    right: &impl GeometryTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    match (left.as_type(), right.as_type()) {
+        (GeometryType::Point(l), GeometryType::Point(r)) => point_approx_eq(l, r, value_eq),
+        (GeometryType::LineString(l), GeometryType::LineString(r)) => {
+            line_string_approx_eq(l, r, value_eq)
+        }
+        (GeometryType::Polygon(l), GeometryType::Polygon(r)) => polygon_approx_eq(l, r, value_eq),
+        (GeometryType::MultiPoint(l), GeometryType::MultiPoint(r)) => {
+            multi_point_approx_eq(l, r, value_eq)
+        }
+        (GeometryType::MultiLineString(l), GeometryType::MultiLineString(r)) => {
+            multi_line_string_approx_eq(l, r, value_eq)
+        }
+        (GeometryType::MultiPolygon(l), GeometryType::MultiPolygon(r)) => {
+            multi_polygon_approx_eq(l, r, value_eq)
+        }
+        (GeometryType::Rect(l), GeometryType::Rect(r)) => rect_approx_eq(l, r, value_eq),
+        (GeometryType::GeometryCollection(l), GeometryType::GeometryCollection(r)) => {
+            geometry_collection_approx_eq(l, r, value_eq)
+        }
+        _ => false,
+    }
+}
+
+fn geometry_collection_approx_eq(
+    left: &impl GeometryCollectionTrait<T = f64>,
+    right: &impl GeometryCollectionTrait<T = f64>,
+    value_eq: &impl Fn(f64, f64) -> bool,
+) -> bool {
+    left.num_geometries() == right.num_geometries()
+        && left
+            .geometries()
+            .zip(right.geometries())
+            .all(|(l, r)| geometry_approx_eq(&l, &r, value_eq))
+}
+
+macro_rules! scalar_impl {
+    ($type:ty, $eq_func:ident) => {
+        impl<'a> ApproxEq for $type {
+            type Output = bool;
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> Self::Output {
+                $eq_func(self, other, &|l, r| abs_diff_eq(l, r, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> Self::Output {
+                $eq_func(self, other, &|l, r| {
+                    relative_eq(l, r, epsilon, max_relative)
+                })
+            }
+        }
+    };
+}
+
+scalar_impl!(Point<'a>, point_approx_eq);
+scalar_impl!(Rect<'a>, rect_approx_eq);
+
+macro_rules! generic_scalar_impl {
+    ($type:ty, $eq_func:ident) => {
+        impl<'a, O: OffsetSizeTrait> ApproxEq for $type {
+            type Output = bool;
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> Self::Output {
+                $eq_func(self, other, &|l, r| abs_diff_eq(l, r, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> Self::Output {
+                $eq_func(self, other, &|l, r| {
+                    relative_eq(l, r, epsilon, max_relative)
+                })
+            }
+        }
+    };
+}
+
+generic_scalar_impl!(LineString<'a, O>, line_string_approx_eq);
+generic_scalar_impl!(Polygon<'a, O>, polygon_approx_eq);
+generic_scalar_impl!(MultiPoint<'a, O>, multi_point_approx_eq);
+generic_scalar_impl!(MultiLineString<'a, O>, multi_line_string_approx_eq);
+generic_scalar_impl!(MultiPolygon<'a, O>, multi_polygon_approx_eq);
+generic_scalar_impl!(Geometry<'a, O>, geometry_approx_eq);
+generic_scalar_impl!(GeometryCollection<'a, O>, geometry_collection_approx_eq);
+
+impl ApproxEq for PointArray {
+    type Output = Result<BooleanArray>;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> Self::Output {
+        self.binary_boolean(other, |l, r| l.abs_diff_eq(&r, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> Self::Output {
+        self.binary_boolean(other, |l, r| l.relative_eq(&r, epsilon, max_relative))
+    }
+}
+
+macro_rules! array_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> ApproxEq for $type {
+            type Output = Result<BooleanArray>;
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> Self::Output {
+                self.binary_boolean(other, |l, r| l.abs_diff_eq(&r, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> Self::Output {
+                self.binary_boolean(other, |l, r| l.relative_eq(&r, epsilon, max_relative))
+            }
+        }
+    };
+}
+
+array_impl!(LineStringArray<O>);
+array_impl!(PolygonArray<O>);
+array_impl!(MultiPointArray<O>);
+array_impl!(MultiLineStringArray<O>);
+array_impl!(MultiPolygonArray<O>);
+array_impl!(MixedGeometryArray<O>);
+array_impl!(GeometryCollectionArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::Array;
+    use geo::{point, polygon};
+
+    #[test]
+    fn relative_tolerance() {
+        let left: PointArray = vec![
+            Some(point!(x: 1e9, y: 1.)),
+            None,
+            Some(point!(x: f64::NAN, y: f64::NAN)),
+        ]
+        .into();
+        let right: PointArray = vec![
+            Some(point!(x: 1e9 + 1., y: 1.)),
+            Some(point!(x: 0., y: 0.)),
+            Some(point!(x: f64::NAN, y: f64::NAN)),
+        ]
+        .into();
+
+        let abs = left.abs_diff_eq(&right, 1e-6).unwrap();
+        assert!(!abs.value(0));
+        assert!(abs.is_null(1));
+        assert!(abs.value(2));
+
+        let rel = left.relative_eq(&right, 1e-6, 1e-8).unwrap();
+        assert!(rel.value(0));
+
+        assert!(left.value(0).relative_eq(&right.value(0), 0., 1e-8));
+    }
+
+    #[test]
+    fn structure_must_match() {
+        let left: PolygonArray<i32> = vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+        ]
+        .as_slice()
+        .into();
+        let right: PolygonArray<i32> = vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+        ]
+        .as_slice()
+        .into();
+        let eq = left.abs_diff_eq(&right, 1.).unwrap();
+        assert_eq!(eq, BooleanArray::from(vec![true, false]));
+
+        let short: PolygonArray<i32> = vec![polygon![(x: 0., y: 0.)]].as_slice().into();
+        assert!(left.abs_diff_eq(&short, 1.).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use geo::coord;
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::geo_traits::{CoordTrait, RectTrait};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Test whether the bounding box of each geometry intersects a query rectangle.
+///
+/// Only bounding boxes are compared, so this is much cheaper than an exact intersection test. It
+/// can be used as a prefilter ahead of exact predicates, since a geometry can only intersect the
+/// query if its bounding box does, or on its own as a fast viewport filter. For arrays with a
+/// coordinate buffer, bounding boxes are computed directly from the buffer. Boxes that only touch
+/// the query rectangle intersect it. Null geometries have a null result, and empty geometries
+/// never intersect the query rectangle.
+pub trait BBoxIntersects {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::BBoxIntersects;
+    /// use geoarrow::array::LineStringArray;
+    /// use geo::{coord, line_string, Rect};
+    ///
+    /// let array: LineStringArray<i32> = vec![
+    ///     line_string![(x: 0., y: 0.), (x: 2., y: 2.)],
+    ///     line_string![(x: 5., y: 5.), (x: 6., y: 5.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let viewport = Rect::new(coord! { x: 1., y: 1. }, coord! { x: 3., y: 3. });
+    /// let mask = array.bbox_intersects(&viewport);
+    /// assert!(mask.value(0));
+    /// assert!(!mask.value(1));
+    /// ```
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output;
+}
+
+/// The bounds of a query rectangle.
+fn query_bounds(rect: &impl RectTrait<T = f64>) -> BoundingRect {
+    let mut bounds = BoundingRect::new();
+    bounds.add_rect(rect);
+    bounds
+}
+
+fn intersects(bounds: &BoundingRect, query: &BoundingRect) -> bool {
+    !bounds.is_empty()
+        && bounds.minx() <= query.maxx()
+        && bounds.maxx() >= query.minx()
+        && bounds.miny() <= query.maxy()
+        && bounds.maxy() >= query.miny()
+}
+
+/// Test the bounds of each geometry, given a function that returns the range of coordinate
+/// indices of each geometry.
+fn intersects_coord_ranges(
+    coords: &CoordBuffer,
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    query: &BoundingRect,
+    coord_range: impl Fn(usize) -> (usize, usize),
+) -> BooleanArray {
+    let values = (0..len).map(|geom_idx| {
+        let (start, end) = coord_range(geom_idx);
+        let mut bounds = BoundingRect::new();
+        if end > start {
+            bounds.add_coords(coords, start, end);
+        }
+        intersects(&bounds, query)
+    });
+    BooleanArray::new(BooleanBuffer::from_iter(values), nulls.cloned())
+}
+
+impl BBoxIntersects for PointArray {
+    type Output = BooleanArray;
+
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+        let query = query_bounds(rect);
+        intersects_coord_ranges(&self.coords, self.len(), self.nulls(), &query, |i| {
+            (i, i + 1)
+        })
+    }
+}
+
+/// Implementation for arrays with one level of offsets
+macro_rules! one_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> BBoxIntersects for $type {
+            type Output = BooleanArray;
+
+            fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+                let query = query_bounds(rect);
+                intersects_coord_ranges(&self.coords, self.len(), self.nulls(), &query, |i| {
+                    self.geom_offsets.start_end(i)
+                })
+            }
+        }
+    };
+}
+
+one_level_impl!(LineStringArray<O>);
+one_level_impl!(MultiPointArray<O>);
+
+/// Implementation for arrays with two levels of offsets
+macro_rules! two_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> BBoxIntersects for $type {
+            type Output = BooleanArray;
+
+            fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+                let query = query_bounds(rect);
+                intersects_coord_ranges(&self.coords, self.len(), self.nulls(), &query, |i| {
+                    let (start_ring, end_ring) = self.geom_offsets.start_end(i);
+                    (
+                        self.ring_offsets[start_ring].to_usize().unwrap(),
+                        self.ring_offsets[end_ring].to_usize().unwrap(),
+                    )
+                })
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>);
+two_level_impl!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> BBoxIntersects for MultiPolygonArray<O> {
+    type Output = BooleanArray;
+
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+        let query = query_bounds(rect);
+        intersects_coord_ranges(&self.coords, self.len(), self.nulls(), &query, |i| {
+            let (start_polygon, end_polygon) = self.geom_offsets.start_end(i);
+            let start_ring = self.polygon_offsets[start_polygon].to_usize().unwrap();
+            let end_ring = self.polygon_offsets[end_polygon].to_usize().unwrap();
+            (
+                self.ring_offsets[start_ring].to_usize().unwrap(),
+                self.ring_offsets[end_ring].to_usize().unwrap(),
+            )
+        })
+    }
+}
+
+/// Implementation that iterates over geometry scalars
+macro_rules! iter_impl {
+    ($type:ty, $add_func:ident) => {
+        impl<O: OffsetSizeTrait> BBoxIntersects for $type {
+            type Output = BooleanArray;
+
+            fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+                let query = query_bounds(rect);
+                self.iter()
+                    .map(|maybe_geom| {
+                        maybe_geom.map(|geom| {
+                            let mut bounds = BoundingRect::new();
+                            bounds.$add_func(&geom);
+                            intersects(&bounds, &query)
+                        })
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+iter_impl!(MixedGeometryArray<O>, add_geometry);
+iter_impl!(GeometryCollectionArray<O>, add_geometry_collection);
+
+impl<O: OffsetSizeTrait> BBoxIntersects for WKBArray<O> {
+    type Output = BooleanArray;
+
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+        let query = query_bounds(rect);
+        self.iter()
+            .map(|maybe_wkb| {
+                maybe_wkb.map(|wkb| {
+                    let mut bounds = BoundingRect::new();
+                    bounds.add_geometry(&wkb.to_wkb_object());
+                    intersects(&bounds, &query)
+                })
+            })
+            .collect()
+    }
+}
+
+impl BBoxIntersects for RectArray {
+    type Output = BooleanArray;
+
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+        let query = query_bounds(rect);
+        self.iter()
+            .map(|maybe_rect| maybe_rect.map(|rect| intersects(&query_bounds(&rect), &query)))
+            .collect()
+    }
+}
+
+impl BBoxIntersects for &dyn GeometryArrayTrait {
+    type Output = BooleanArray;
+
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().bbox_intersects(rect),
+            GeoDataType::LineString(_) => self.as_line_string().bbox_intersects(rect),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().bbox_intersects(rect),
+            GeoDataType::Polygon(_) => self.as_polygon().bbox_intersects(rect),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().bbox_intersects(rect),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().bbox_intersects(rect),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().bbox_intersects(rect),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().bbox_intersects(rect),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().bbox_intersects(rect)
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().bbox_intersects(rect),
+            GeoDataType::LargeMultiPolygon(_) => {
+                self.as_large_multi_polygon().bbox_intersects(rect)
+            }
+            GeoDataType::Mixed(_) => self.as_mixed().bbox_intersects(rect),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().bbox_intersects(rect),
+            GeoDataType::GeometryCollection(_) => {
+                self.as_geometry_collection().bbox_intersects(rect)
+            }
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().bbox_intersects(rect)
+            }
+            GeoDataType::WKB => self.as_wkb().bbox_intersects(rect),
+            GeoDataType::LargeWKB => self.as_large_wkb().bbox_intersects(rect),
+            GeoDataType::Rect => self.as_rect().bbox_intersects(rect),
+        }
+    }
+}
+
+impl<G: GeometryArrayTrait> BBoxIntersects for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<BooleanArray>>;
+
+    fn bbox_intersects(&self, rect: &impl RectTrait<T = f64>) -> Self::Output {
+        // Copy the query into a type that can be shared between threads
+        let (lower, upper) = (rect.lower(), rect.upper());
+        let query = geo::Rect::new(
+            coord! { x: lower.x(), y: lower.y() },
+            coord! { x: upper.x(), y: upper.y() },
+        );
+        self.map(|chunk| chunk.as_ref().bbox_intersects(&query))
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::{p0, p1};
+    use arrow_array::Array;
+    use geo::{point, Rect};
+
+    #[test]
+    fn points_touching_and_null() {
+        let array: PointArray = vec![
+            Some(point!(x: 1., y: 1.)),
+            None,
+            Some(point!(x: 3., y: 0.)),
+            Some(point!(x: 4., y: 0.)),
+        ]
+        .into();
+        let query = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 3., y: 3. });
+        let mask = array.bbox_intersects(&query);
+        assert!(mask.value(0));
+        assert!(mask.is_null(1));
+        assert!(mask.value(2));
+        assert!(!mask.value(3));
+    }
+
+    #[test]
+    fn polygons_match_geo_bounding_rect() {
+        let array: PolygonArray<i32> = vec![p0(), p1()].as_slice().into();
+        let query = Rect::new(coord! { x: -108., y: 40. }, coord! { x: -100., y: 50. });
+        let mask = array.bbox_intersects(&query);
+        assert_eq!(mask, BooleanArray::from(vec![true, true]));
+
+        let geoms: Vec<geo::Geometry> = vec![p0().into(), p1().into()];
+        let mixed: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        let dyn_mask = mixed.as_ref().bbox_intersects(&query);
+        assert_eq!(dyn_mask, mask);
+    }
+}
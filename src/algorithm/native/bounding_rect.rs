@@ -1,11 +1,16 @@
+use crate::algorithm::native::simd;
+use crate::array::CoordBuffer;
 use crate::geo_traits::{
     CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
     MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
 };
 use geo::{Coord, Rect};
 
-#[derive(Debug, Clone, Copy)]
-struct BoundingRect {
+/// A 2D axis-aligned bounding box that can be grown incrementally.
+///
+/// An empty box has infinite mins and negative infinite maxes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingRect {
     minx: f64,
     miny: f64,
     maxx: f64,
@@ -23,6 +28,46 @@ impl BoundingRect {
         }
     }
 
+    pub fn minx(&self) -> f64 {
+        self.minx
+    }
+
+    pub fn miny(&self) -> f64 {
+        self.miny
+    }
+
+    pub fn maxx(&self) -> f64 {
+        self.maxx
+    }
+
+    pub fn maxy(&self) -> f64 {
+        self.maxy
+    }
+
+    /// Whether no coordinates have been added to this box.
+    pub fn is_empty(&self) -> bool {
+        self.minx > self.maxx || self.miny > self.maxy
+    }
+
+    /// Grow this box to also contain `other`.
+    pub fn update(&mut self, other: &BoundingRect) {
+        self.minx = self.minx.min(other.minx);
+        self.miny = self.miny.min(other.miny);
+        self.maxx = self.maxx.max(other.maxx);
+        self.maxy = self.maxy.max(other.maxy);
+    }
+
+    /// Add the coordinates `start..end` of a coordinate buffer.
+    pub(crate) fn add_coords(&mut self, coords: &CoordBuffer, start: usize, end: usize) {
+        let [minx, miny, maxx, maxy] = simd::bounds(coords, start, end);
+        self.update(&BoundingRect {
+            minx,
+            miny,
+            maxx,
+            maxy,
+        });
+    }
+
     pub fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
         if coord.x() < self.minx {
             self.minx = coord.x();
@@ -0,0 +1,490 @@
+use std::sync::Arc;
+
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geo::{
+    Coord, CoordsIter, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon, Rect,
+};
+
+use crate::algorithm::native::Tile;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Geometries clipped to a tile, as returned by [`ClipToTile::clip_to_tile`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileClip<G> {
+    /// The part of each geometry within the buffered tile. Null where the input is null or lies
+    /// entirely outside the buffered tile.
+    pub geometry: G,
+
+    /// Whether any part of each geometry was cut away. Null where the input is null.
+    pub clipped: BooleanArray,
+}
+
+/// Clip geometries to the envelope of an XYZ tile, expanded by a buffer.
+///
+/// The buffer is given in tile units, for a tile that is `extent` units across, as in vector
+/// tiles, and coordinates are interpreted as longitude and latitude in degrees. Geometries that
+/// lie within the buffered tile are passed through unchanged. Lines are clipped segment by
+/// segment and polygon rings are clipped against each edge of the envelope in turn, which is
+/// considerably cheaper than general overlay operations. As is usual for tiling, a concave
+/// polygon whose parts are only joined outside the envelope stays a single polygon, with
+/// degenerate edges along the envelope.
+///
+/// Line strings and polygons are returned as multi line strings and multi polygons, since
+/// clipping can split them into several parts.
+///
+/// # Panics
+///
+/// Panics if `extent` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::{ClipToTile, Tile};
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::line_string;
+///
+/// let array: LineStringArray<i32> = vec![
+///     line_string![(x: -10., y: 10.), (x: 10., y: 10.)],
+///     line_string![(x: 10., y: 10.), (x: 20., y: 20.)],
+/// ]
+/// .as_slice()
+/// .into();
+///
+/// // The eastern half of the world at zoom 1
+/// let output = array.clip_to_tile(&Tile::new(1, 1, 0), 4096, 0);
+/// assert_eq!(
+///     output.geometry.value_as_geo(0).0[0],
+///     line_string![(x: 0., y: 10.), (x: 10., y: 10.)]
+/// );
+/// assert!(output.clipped.value(0));
+/// assert!(!output.clipped.value(1));
+/// ```
+pub trait ClipToTile {
+    type Output;
+
+    fn clip_to_tile(&self, tile: &Tile, extent: u32, buffer: u32) -> Self::Output;
+}
+
+fn contains(rect: &Rect, coord: &Coord) -> bool {
+    coord.x >= rect.min().x
+        && coord.x <= rect.max().x
+        && coord.y >= rect.min().y
+        && coord.y <= rect.max().y
+}
+
+/// The range of `t` in `0..=1` for which `start + t * (end - start)` lies within `rect`, using
+/// the Liang–Barsky algorithm.
+fn clip_segment(start: Coord, end: Coord, rect: &Rect) -> Option<(f64, f64)> {
+    let delta = end - start;
+    let (min, max) = (rect.min(), rect.max());
+    let (mut t0, mut t1) = (0., 1.);
+    for (p, q) in [
+        (-delta.x, start.x - min.x),
+        (delta.x, max.x - start.x),
+        (-delta.y, start.y - min.y),
+        (delta.y, max.y - start.y),
+    ] {
+        if p == 0. {
+            if q < 0. {
+                return None;
+            }
+        } else {
+            let r = q / p;
+            if p < 0. {
+                if r > t1 {
+                    return None;
+                }
+                t0 = f64::max(t0, r);
+            } else {
+                if r < t0 {
+                    return None;
+                }
+                t1 = f64::min(t1, r);
+            }
+        }
+    }
+    Some((t0, t1))
+}
+
+fn clip_line_string(line_string: &LineString, rect: &Rect, parts: &mut Vec<LineString>) {
+    let mut current: Vec<Coord> = vec![];
+    let mut finish = |current: &mut Vec<Coord>| {
+        if current.len() >= 2 {
+            parts.push(LineString::new(std::mem::take(current)));
+        }
+        current.clear();
+    };
+
+    for line in line_string.lines() {
+        let Some((t0, t1)) = clip_segment(line.start, line.end, rect) else {
+            finish(&mut current);
+            continue;
+        };
+        let at = |t: f64| {
+            if t == 0. {
+                line.start
+            } else if t == 1. {
+                line.end
+            } else {
+                line.start + line.delta() * t
+            }
+        };
+        if t0 > 0. {
+            finish(&mut current);
+        }
+        if current.is_empty() {
+            current.push(at(t0));
+        }
+        current.push(at(t1));
+        if t1 < 1. {
+            finish(&mut current);
+        }
+    }
+    finish(&mut current);
+}
+
+/// Clip a ring against each edge of `rect` in turn, using the Sutherland–Hodgman algorithm.
+fn clip_ring(ring: &LineString, rect: &Rect) -> Option<LineString> {
+    let mut coords = ring.0.clone();
+    if ring.is_closed() {
+        coords.pop();
+    }
+
+    let (min, max) = (rect.min(), rect.max());
+    // Each edge of the envelope as whether it is vertical, its position, and whether the inside
+    // lies above it
+    let edges = [
+        (true, min.x, true),
+        (true, max.x, false),
+        (false, min.y, true),
+        (false, max.y, false),
+    ];
+    for (vertical, value, above) in edges {
+        let Some(mut prev) = coords.last().copied() else {
+            break;
+        };
+        let inside = |coord: &Coord| {
+            let position = if vertical { coord.x } else { coord.y };
+            if above {
+                position >= value
+            } else {
+                position <= value
+            }
+        };
+        let intersection = |a: Coord, b: Coord| {
+            if vertical {
+                let t = (value - a.x) / (b.x - a.x);
+                Coord {
+                    x: value,
+                    y: a.y + t * (b.y - a.y),
+                }
+            } else {
+                let t = (value - a.y) / (b.y - a.y);
+                Coord {
+                    x: a.x + t * (b.x - a.x),
+                    y: value,
+                }
+            }
+        };
+
+        let input = std::mem::take(&mut coords);
+        for current in input {
+            let current_inside = inside(&current);
+            let prev_inside = inside(&prev);
+            if current_inside != prev_inside {
+                coords.push(intersection(prev, current));
+            }
+            if current_inside {
+                coords.push(current);
+            }
+            prev = current;
+        }
+    }
+
+    if coords.len() < 3 {
+        return None;
+    }
+    let mut ring = LineString::new(coords);
+    ring.close();
+    Some(ring)
+}
+
+fn clip_polygon(polygon: &Polygon, rect: &Rect) -> Option<Polygon> {
+    let exterior = clip_ring(polygon.exterior(), rect)?;
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter_map(|ring| clip_ring(ring, rect))
+        .collect();
+    Some(Polygon::new(exterior, interiors))
+}
+
+/// Clip each geometry with `clip`, unless all of its coordinates lie within `rect`.
+fn clip_geoms<T: CoordsIter<Scalar = f64>>(
+    geoms: impl Iterator<Item = Option<T>>,
+    rect: &Rect,
+    clip: impl Fn(&T) -> Option<T>,
+) -> (Vec<Option<T>>, BooleanArray) {
+    let mut clipped = BooleanBuilder::new();
+    let output_geoms = geoms
+        .map(|maybe_g| {
+            let Some(geom) = maybe_g else {
+                clipped.append_null();
+                return None;
+            };
+            if geom.coords_iter().all(|coord| contains(rect, &coord)) {
+                clipped.append_value(false);
+                Some(geom)
+            } else {
+                clipped.append_value(true);
+                clip(&geom)
+            }
+        })
+        .collect();
+    (output_geoms, clipped.finish())
+}
+
+fn clip_multi_points<O: OffsetSizeTrait, G: Into<MultiPoint>>(
+    geoms: impl Iterator<Item = Option<G>>,
+    rect: &Rect,
+) -> TileClip<MultiPointArray<O>> {
+    let (output_geoms, clipped) = clip_geoms(
+        geoms.map(|g| g.map(Into::into)),
+        rect,
+        |geom: &MultiPoint| {
+            let points = geom
+                .iter()
+                .filter(|point| contains(rect, &point.0))
+                .copied()
+                .collect::<Vec<_>>();
+            (!points.is_empty()).then(|| MultiPoint::new(points))
+        },
+    );
+    TileClip {
+        geometry: output_geoms.into(),
+        clipped,
+    }
+}
+
+fn clip_multi_line_strings<O: OffsetSizeTrait, G: Into<MultiLineString>>(
+    geoms: impl Iterator<Item = Option<G>>,
+    rect: &Rect,
+) -> TileClip<MultiLineStringArray<O>> {
+    let (output_geoms, clipped) = clip_geoms(
+        geoms.map(|g| g.map(Into::into)),
+        rect,
+        |geom: &MultiLineString| {
+            let mut parts = vec![];
+            geom.iter()
+                .for_each(|line_string| clip_line_string(line_string, rect, &mut parts));
+            (!parts.is_empty()).then(|| MultiLineString::new(parts))
+        },
+    );
+    TileClip {
+        geometry: output_geoms.into(),
+        clipped,
+    }
+}
+
+fn clip_multi_polygons<O: OffsetSizeTrait, G: Into<MultiPolygon>>(
+    geoms: impl Iterator<Item = Option<G>>,
+    rect: &Rect,
+) -> TileClip<MultiPolygonArray<O>> {
+    let (output_geoms, clipped) = clip_geoms(
+        geoms.map(|g| g.map(Into::into)),
+        rect,
+        |geom: &MultiPolygon| {
+            let polygons = geom
+                .iter()
+                .filter_map(|polygon| clip_polygon(polygon, rect))
+                .collect::<Vec<_>>();
+            (!polygons.is_empty()).then(|| MultiPolygon::new(polygons))
+        },
+    );
+    TileClip {
+        geometry: output_geoms.into(),
+        clipped,
+    }
+}
+
+impl ClipToTile for PointArray {
+    type Output = TileClip<PointArray>;
+
+    fn clip_to_tile(&self, tile: &Tile, extent: u32, buffer: u32) -> Self::Output {
+        assert!(extent > 0, "Tile extent must be greater than 0");
+        let rect = tile.buffered_bounds(extent, buffer);
+        let mut output_array = PointBuilder::with_capacity(self.len());
+        let mut clipped = BooleanBuilder::with_capacity(self.len());
+        for maybe_point in self.iter_geo() {
+            match maybe_point {
+                Some(point) if contains(&rect, &point.0) => {
+                    output_array.push_point(Some(&point));
+                    clipped.append_value(false);
+                }
+                Some(_) => {
+                    output_array.push_null();
+                    clipped.append_value(true);
+                }
+                None => {
+                    output_array.push_null();
+                    clipped.append_null();
+                }
+            }
+        }
+        TileClip {
+            geometry: output_array.finish(),
+            clipped: clipped.finish(),
+        }
+    }
+}
+
+/// Implementation that clips geo objects of the given kind
+macro_rules! iter_geo_impl {
+    ($type:ty, $output_type:ty, $clip_func:ident) => {
+        impl<O: OffsetSizeTrait> ClipToTile for $type {
+            type Output = TileClip<$output_type>;
+
+            fn clip_to_tile(&self, tile: &Tile, extent: u32, buffer: u32) -> Self::Output {
+                assert!(extent > 0, "Tile extent must be greater than 0");
+                $clip_func(self.iter_geo(), &tile.buffered_bounds(extent, buffer))
+            }
+        }
+    };
+}
+
+iter_geo_impl!(MultiPointArray<O>, MultiPointArray<O>, clip_multi_points);
+iter_geo_impl!(
+    LineStringArray<O>,
+    MultiLineStringArray<O>,
+    clip_multi_line_strings
+);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    MultiLineStringArray<O>,
+    clip_multi_line_strings
+);
+iter_geo_impl!(PolygonArray<O>, MultiPolygonArray<O>, clip_multi_polygons);
+iter_geo_impl!(
+    MultiPolygonArray<O>,
+    MultiPolygonArray<O>,
+    clip_multi_polygons
+);
+
+impl ClipToTile for &dyn GeometryArrayTrait {
+    type Output = Result<TileClip<Arc<dyn GeometryArrayTrait>>>;
+
+    fn clip_to_tile(&self, tile: &Tile, extent: u32, buffer: u32) -> Self::Output {
+        macro_rules! call {
+            ($downcast:ident) => {{
+                let output = self.$downcast().clip_to_tile(tile, extent, buffer);
+                TileClip {
+                    geometry: Arc::new(output.geometry) as Arc<dyn GeometryArrayTrait>,
+                    clipped: output.clipped,
+                }
+            }};
+        }
+
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => call!(as_point),
+            GeoDataType::LineString(_) => call!(as_line_string),
+            GeoDataType::LargeLineString(_) => call!(as_large_line_string),
+            GeoDataType::Polygon(_) => call!(as_polygon),
+            GeoDataType::LargePolygon(_) => call!(as_large_polygon),
+            GeoDataType::MultiPoint(_) => call!(as_multi_point),
+            GeoDataType::LargeMultiPoint(_) => call!(as_large_multi_point),
+            GeoDataType::MultiLineString(_) => call!(as_multi_line_string),
+            GeoDataType::LargeMultiLineString(_) => call!(as_large_multi_line_string),
+            GeoDataType::MultiPolygon(_) => call!(as_multi_polygon),
+            GeoDataType::LargeMultiPolygon(_) => call!(as_large_multi_polygon),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait> ClipToTile for ChunkedGeometryArray<G> {
+    type Output = Result<Vec<TileClip<Arc<dyn GeometryArrayTrait>>>>;
+
+    fn clip_to_tile(&self, tile: &Tile, extent: u32, buffer: u32) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().clip_to_tile(tile, extent, buffer))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Array;
+    use geo::{line_string, polygon, Area};
+
+    #[test]
+    fn polygon_with_hole() {
+        // A square around the origin with a hole in the north-east quadrant
+        let polygon = polygon!(
+            exterior: [(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        );
+        let array: PolygonArray<i32> = vec![Some(polygon), None].into();
+
+        // The north-east quarter of the world
+        let output = array.clip_to_tile(&Tile::new(1, 1, 0), 4096, 0);
+        assert!(output.clipped.value(0));
+        assert!(output.clipped.is_null(1));
+        assert!(output.geometry.get_as_geo(1).is_none());
+
+        let clipped = output.geometry.value_as_geo(0);
+        assert_eq!(clipped.0.len(), 1);
+        assert_eq!(clipped.0[0].interiors().len(), 1);
+        assert!((clipped.unsigned_area() - 96.).abs() < 1e-9);
+
+        let dyn_array: &dyn GeometryArrayTrait = &array;
+        let dyn_output = dyn_array
+            .clip_to_tile(&Tile::new(1, 1, 0), 4096, 0)
+            .unwrap();
+        assert_eq!(dyn_output.clipped, output.clipped);
+    }
+
+    #[test]
+    fn buffer_and_outside() {
+        let tile = Tile::new(1, 1, 0);
+        let points: PointArray = vec![
+            Some(geo::point!(x: -1., y: 10.)),
+            Some(geo::point!(x: -100., y: 10.)),
+        ]
+        .into();
+
+        let output = points.clip_to_tile(&tile, 4096, 0);
+        assert!(output.geometry.get_as_geo(0).is_none());
+        assert!(output.clipped.value(0));
+
+        // 64 of 4096 units of a 180 degree wide tile is ~2.8 degrees
+        let output = points.clip_to_tile(&tile, 4096, 64);
+        assert_eq!(output.geometry.value_as_geo(0), geo::point!(x: -1., y: 10.));
+        assert!(!output.clipped.value(0));
+        assert!(output.geometry.get_as_geo(1).is_none());
+        assert!(output.clipped.value(1));
+    }
+
+    #[test]
+    fn line_leaving_and_entering() {
+        let lines: LineStringArray<i32> = vec![line_string![
+            (x: 10., y: 10.), (x: -10., y: 10.), (x: -10., y: 20.), (x: 10., y: 20.)
+        ]]
+        .as_slice()
+        .into();
+        let output = lines.clip_to_tile(&Tile::new(1, 1, 0), 4096, 0);
+        assert_eq!(
+            output.geometry.value_as_geo(0),
+            MultiLineString::new(vec![
+                line_string![(x: 10., y: 10.), (x: 0., y: 10.)],
+                line_string![(x: 0., y: 20.), (x: 10., y: 20.)],
+            ])
+        );
+    }
+}
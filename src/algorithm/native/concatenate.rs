@@ -1,42 +1,83 @@
-use arrow_array::OffsetSizeTrait;
+use arrow::compute::concat;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, OffsetSizeTrait};
 
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::error::Result;
-use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
 
+/// Concatenate multiple arrays of the same type into a single contiguous array.
+///
+/// The coordinate type and metadata of the output are taken from the first array.
 pub trait Concatenate: Sized {
     type Output;
 
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Concatenate;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::point;
+    ///
+    /// let a: PointArray = vec![point!(x: 1., y: 2.)].as_slice().into();
+    /// let b: PointArray = vec![point!(x: 3., y: 4.), point!(x: 5., y: 6.)].as_slice().into();
+    /// let combined = [&a, &b].as_slice().concatenate().unwrap();
+    /// assert_eq!(combined.len(), 3);
+    /// ```
     fn concatenate(&self) -> Self::Output;
 }
 
-impl Concatenate for &[PointArray] {
-    type Output = Result<PointArray>;
+macro_rules! impl_point_concatenate {
+    ($slice:ty) => {
+        impl Concatenate for $slice {
+            type Output = Result<PointArray>;
 
-    fn concatenate(&self) -> Self::Output {
-        let output_capacity = self.iter().fold(0, |sum, val| sum + val.buffer_lengths());
-        let mut builder = PointBuilder::with_capacity(output_capacity);
-        self.iter()
-            .for_each(|chunk| chunk.iter().for_each(|p| builder.push_point(p.as_ref())));
-        Ok(builder.finish())
-    }
+            fn concatenate(&self) -> Self::Output {
+                let output_capacity = self.iter().fold(0, |sum, val| sum + val.buffer_lengths());
+                let mut builder = match self.first() {
+                    Some(first) => PointBuilder::with_capacity_and_options(
+                        output_capacity,
+                        first.coord_type(),
+                        first.metadata(),
+                    ),
+                    None => PointBuilder::new(),
+                };
+                self.iter()
+                    .for_each(|chunk| builder.extend_from_array(chunk));
+                Ok(builder.finish())
+            }
+        }
+    };
 }
 
+impl_point_concatenate!(&[PointArray]);
+impl_point_concatenate!(&[&PointArray]);
+
 macro_rules! impl_concatenate {
-    ($array:ty, $capacity:ty, $builder:ty, $push_func:ident) => {
-        impl<O: OffsetSizeTrait> Concatenate for &[$array] {
+    ($array:ty, $capacity:ty, $builder:ty) => {
+        impl_concatenate!(&[$array], $array, $capacity, $builder);
+        impl_concatenate!(&[&$array], $array, $capacity, $builder);
+    };
+    ($slice:ty, $array:ty, $capacity:ty, $builder:ty) => {
+        impl<O: OffsetSizeTrait> Concatenate for $slice {
             type Output = Result<$array>;
 
             fn concatenate(&self) -> Self::Output {
                 let output_capacity = self.iter().fold(<$capacity>::new_empty(), |sum, val| {
                     sum + val.buffer_lengths()
                 });
-                let mut builder = <$builder>::with_capacity(output_capacity);
+                let mut builder = match self.first() {
+                    Some(first) => <$builder>::with_capacity_and_options(
+                        output_capacity,
+                        first.coord_type(),
+                        first.metadata(),
+                    ),
+                    None => <$builder>::new(),
+                };
                 for chunk in self.iter() {
-                    for geom in chunk.iter() {
-                        builder.$push_func(geom.as_ref())?;
-                    }
+                    builder.extend_from_array(chunk)?;
                 }
                 Ok(builder.finish())
             }
@@ -44,49 +85,51 @@ macro_rules! impl_concatenate {
     };
 }
 
-impl_concatenate!(
-    LineStringArray<O>,
-    LineStringCapacity,
-    LineStringBuilder<O>,
-    push_line_string
-);
-impl_concatenate!(
-    PolygonArray<O>,
-    PolygonCapacity,
-    PolygonBuilder<O>,
-    push_polygon
-);
-impl_concatenate!(
-    MultiPointArray<O>,
-    MultiPointCapacity,
-    MultiPointBuilder<O>,
-    push_multi_point
-);
+impl_concatenate!(LineStringArray<O>, LineStringCapacity, LineStringBuilder<O>);
+impl_concatenate!(PolygonArray<O>, PolygonCapacity, PolygonBuilder<O>);
+impl_concatenate!(MultiPointArray<O>, MultiPointCapacity, MultiPointBuilder<O>);
 impl_concatenate!(
     MultiLineStringArray<O>,
     MultiLineStringCapacity,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
+    MultiLineStringBuilder<O>
 );
 impl_concatenate!(
     MultiPolygonArray<O>,
     MultiPolygonCapacity,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
+    MultiPolygonBuilder<O>
 );
 impl_concatenate!(
     MixedGeometryArray<O>,
     MixedCapacity,
-    MixedGeometryBuilder<O>,
-    push_geometry
+    MixedGeometryBuilder<O>
 );
 impl_concatenate!(
     GeometryCollectionArray<O>,
     GeometryCollectionCapacity,
-    GeometryCollectionBuilder<O>,
-    push_geometry_collection
+    GeometryCollectionBuilder<O>
 );
 
+macro_rules! impl_wkb_concatenate {
+    ($slice:ty) => {
+        impl<O: OffsetSizeTrait> Concatenate for $slice {
+            type Output = Result<WKBArray<O>>;
+
+            fn concatenate(&self) -> Self::Output {
+                let metadata = self
+                    .first()
+                    .map(|first| first.metadata())
+                    .unwrap_or_default();
+                let arrays: Vec<&dyn Array> = self.iter().map(|chunk| &chunk.array as _).collect();
+                let array = concat(&arrays)?;
+                Ok(WKBArray::new(array.as_binary::<O>().clone(), metadata))
+            }
+        }
+    };
+}
+
+impl_wkb_concatenate!(&[WKBArray<O>]);
+impl_wkb_concatenate!(&[&WKBArray<O>]);
+
 impl Concatenate for ChunkedPointArray {
     type Output = Result<PointArray>;
 
@@ -117,3 +160,46 @@ impl_chunked_concatenate!(
     ChunkedGeometryCollectionArray<O>,
     GeometryCollectionArray<O>
 );
+impl_chunked_concatenate!(ChunkedWKBArray<O>, WKBArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::{p0, p1};
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+
+    #[test]
+    fn sliced_polygons_with_nulls() {
+        let arr: PolygonArray<i32> = vec![Some(p0()), None, Some(p1()), Some(p0())].into();
+        let combined = [arr.slice(1, 2), arr.slice(2, 2)]
+            .as_slice()
+            .concatenate()
+            .unwrap();
+        assert_eq!(combined.len(), 4);
+        assert_eq!(combined.null_count(), 1);
+        assert!(combined.get_as_geo(0).is_none());
+        assert_eq!(combined.value_as_geo(1), p1());
+        assert_eq!(combined.value_as_geo(2), p1());
+        assert_eq!(combined.value_as_geo(3), p0());
+    }
+
+    #[test]
+    fn sliced_mixed() {
+        let geoms = vec![
+            geo::Geometry::Polygon(p0()),
+            geo::Geometry::Point(geo::point!(x: 1., y: 2.)),
+            geo::Geometry::Polygon(p1()),
+        ];
+        let arr: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        let combined = [arr.slice(1, 2), arr.clone()]
+            .as_slice()
+            .concatenate()
+            .unwrap();
+        assert_eq!(combined.len(), 5);
+        assert_eq!(combined.value_as_geo(0), arr.value_as_geo(1));
+        assert_eq!(combined.value_as_geo(1), arr.value_as_geo(2));
+        for i in 0..arr.len() {
+            assert_eq!(combined.value_as_geo(i + 2), arr.value_as_geo(i));
+        }
+    }
+}
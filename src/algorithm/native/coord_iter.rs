@@ -0,0 +1,163 @@
+use arrow_array::OffsetSizeTrait;
+use geo::CoordsIter;
+
+use crate::algorithm::native::coord_range::CoordRange;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Iterate over the coordinates of all geometries in an array as `(x, y)` tuples.
+///
+/// For arrays with a single coordinate buffer this reads the buffer directly, without
+/// constructing any scalars. Coordinates are yielded in order of geometry, and the coordinates of
+/// null geometries are skipped.
+pub trait CoordIter {
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::CoordIter;
+    /// use geoarrow::array::LineStringArray;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+    /// let array: LineStringArray<i32> = vec![line_string].as_slice().into();
+    ///
+    /// let coords: Vec<(f64, f64)> = array.coord_iter().collect();
+    /// assert_eq!(coords, vec![(1., 2.), (3., 4.)]);
+    /// ```
+    fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_>;
+}
+
+/// Iterate over the coordinates of all valid geometries, reading the coordinate buffer directly.
+fn valid_coords(array: &impl CoordRange) -> impl Iterator<Item = (f64, f64)> + '_ {
+    let coords = array.coord_buffer();
+    array
+        .valid_coord_ranges()
+        .flat_map(|(_, range)| range)
+        .map(move |coord_idx| (coords.get_x(coord_idx), coords.get_y(coord_idx)))
+}
+
+impl CoordIter for PointArray {
+    fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(valid_coords(self))
+    }
+}
+
+/// Implementation for arrays with a single coordinate buffer
+macro_rules! coord_range_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> CoordIter for $type {
+            fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+                Box::new(valid_coords(self))
+            }
+        }
+    };
+}
+
+coord_range_impl!(LineStringArray<O>);
+coord_range_impl!(PolygonArray<O>);
+coord_range_impl!(MultiPointArray<O>);
+coord_range_impl!(MultiLineStringArray<O>);
+coord_range_impl!(MultiPolygonArray<O>);
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> CoordIter for $type {
+            fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+                Box::new(self.iter_geo().flatten().flat_map(|geom| {
+                    geom.coords_iter()
+                        .map(|coord| coord.x_y())
+                        .collect::<Vec<_>>()
+                }))
+            }
+        }
+    };
+}
+
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+/// The coordinates of a rect are its four corners, as in [`geo::Rect`].
+impl CoordIter for RectArray {
+    fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(self.iter_geo().flatten().flat_map(|rect| {
+            rect.coords_iter()
+                .map(|coord| coord.x_y())
+                .collect::<Vec<_>>()
+        }))
+    }
+}
+
+impl CoordIter for &dyn GeometryArrayTrait {
+    fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().coord_iter(),
+            GeoDataType::LineString(_) => self.as_line_string().coord_iter(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().coord_iter(),
+            GeoDataType::Polygon(_) => self.as_polygon().coord_iter(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().coord_iter(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().coord_iter(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().coord_iter(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().coord_iter(),
+            GeoDataType::LargeMultiLineString(_) => self.as_large_multi_line_string().coord_iter(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().coord_iter(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().coord_iter(),
+            GeoDataType::Mixed(_) => self.as_mixed().coord_iter(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().coord_iter(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().coord_iter(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().coord_iter()
+            }
+            GeoDataType::WKB => self.as_wkb().coord_iter(),
+            GeoDataType::LargeWKB => self.as_large_wkb().coord_iter(),
+            GeoDataType::Rect => self.as_rect().coord_iter(),
+        }
+    }
+}
+
+impl<G: GeometryArrayTrait + CoordIter> CoordIter for ChunkedGeometryArray<G> {
+    fn coord_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(self.chunks().iter().flat_map(|chunk| chunk.coord_iter()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArraySelfMethods;
+    use geo::{line_string, polygon};
+
+    #[test]
+    fn skips_nulls_and_sliced_geometries() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            None,
+            Some(line_string![(x: 2., y: 2.), (x: 3., y: 3.)]),
+            Some(line_string![(x: 4., y: 4.)]),
+        ]
+        .into();
+        let coords: Vec<_> = array.slice(1, 2).coord_iter().collect();
+        assert_eq!(coords, vec![(2., 2.), (3., 3.)]);
+    }
+
+    #[test]
+    fn polygons_and_dyn() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        let array: PolygonArray<i32> = vec![polygon.clone()].as_slice().into();
+        assert_eq!(array.coord_iter().count(), 8);
+
+        let mixed: MixedGeometryArray<i32> = vec![geo::Geometry::Polygon(polygon)]
+            .as_slice()
+            .try_into()
+            .unwrap();
+        let dyn_array = &mixed as &dyn GeometryArrayTrait;
+        assert_eq!(dyn_array.coord_iter().count(), 8);
+    }
+}
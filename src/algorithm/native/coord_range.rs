@@ -0,0 +1,89 @@
+use std::ops::Range;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::GeometryArrayTrait;
+
+/// Arrays that store the coordinates of all geometries in a single [`CoordBuffer`], where each
+/// geometry owns a contiguous range of it.
+pub(crate) trait CoordRange: GeometryArrayTrait {
+    /// The coordinate buffer shared by all geometries.
+    fn coord_buffer(&self) -> &CoordBuffer;
+
+    /// The range of indices into the coordinate buffer of the geometry at `geom_idx`.
+    fn coord_range(&self, geom_idx: usize) -> Range<usize>;
+
+    /// The coordinate ranges of all valid geometries, tagged with their geometry index.
+    fn valid_coord_ranges(&self) -> Box<dyn Iterator<Item = (usize, Range<usize>)> + '_> {
+        Box::new(
+            (0..self.len())
+                .filter(|geom_idx| self.is_valid(*geom_idx))
+                .map(|geom_idx| (geom_idx, self.coord_range(geom_idx))),
+        )
+    }
+}
+
+impl CoordRange for PointArray {
+    fn coord_buffer(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
+    fn coord_range(&self, geom_idx: usize) -> Range<usize> {
+        geom_idx..geom_idx + 1
+    }
+}
+
+/// Implementation for arrays with one level of offsets
+macro_rules! one_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> CoordRange for $type {
+            fn coord_buffer(&self) -> &CoordBuffer {
+                &self.coords
+            }
+
+            fn coord_range(&self, geom_idx: usize) -> Range<usize> {
+                let (start, end) = self.geom_offsets.start_end(geom_idx);
+                start..end
+            }
+        }
+    };
+}
+
+one_level_impl!(LineStringArray<O>);
+one_level_impl!(MultiPointArray<O>);
+
+/// Implementation for arrays with two levels of offsets
+macro_rules! two_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> CoordRange for $type {
+            fn coord_buffer(&self) -> &CoordBuffer {
+                &self.coords
+            }
+
+            fn coord_range(&self, geom_idx: usize) -> Range<usize> {
+                let (start_ring, end_ring) = self.geom_offsets.start_end(geom_idx);
+                self.ring_offsets[start_ring].to_usize().unwrap()
+                    ..self.ring_offsets[end_ring].to_usize().unwrap()
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>);
+two_level_impl!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> CoordRange for MultiPolygonArray<O> {
+    fn coord_buffer(&self) -> &CoordBuffer {
+        &self.coords
+    }
+
+    fn coord_range(&self, geom_idx: usize) -> Range<usize> {
+        let (start_polygon, end_polygon) = self.geom_offsets.start_end(geom_idx);
+        let start_ring = self.polygon_offsets[start_polygon].to_usize().unwrap();
+        let end_ring = self.polygon_offsets[end_polygon].to_usize().unwrap();
+        self.ring_offsets[start_ring].to_usize().unwrap()
+            ..self.ring_offsets[end_ring].to_usize().unwrap()
+    }
+}
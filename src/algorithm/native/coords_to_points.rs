@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use arrow_array::{OffsetSizeTrait, UInt64Array};
+use geo::CoordsIter;
+
+use crate::algorithm::native::coord_range::CoordRange;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Extract every vertex of a geometry array as a point.
+///
+/// Returns a [`PointArray`] with one point per coordinate, along with the index of the input
+/// geometry that each point came from. These indices can be passed into a
+/// [`take`][arrow::compute::take] operation to carry attributes over to the points. Null
+/// geometries produce no points.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+///
+/// use geoarrow::algorithm::native::CoordsToPoints;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::GeometryArrayTrait;
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+/// let array: LineStringArray<i32> = vec![line_string].as_slice().into();
+/// let (points, parents) = array.coords_to_points();
+/// assert_eq!(points.len(), 3);
+/// assert_eq!(parents.values().as_ref(), &[0, 0, 0]);
+/// ```
+pub trait CoordsToPoints {
+    type Output;
+
+    fn coords_to_points(&self) -> Self::Output;
+}
+
+/// Build points from the coordinate range of each valid geometry, tagged with the index of their
+/// parent geometry.
+fn points_from_ranges(array: &impl CoordRange) -> (PointArray, UInt64Array) {
+    let coords = array.coord_buffer();
+    let mut builder =
+        PointBuilder::with_capacity_and_options(0, array.coord_type(), array.metadata());
+    let mut parents = vec![];
+    for (parent, range) in array.valid_coord_ranges() {
+        builder.reserve(range.len());
+        for coord_idx in range {
+            let point = geo::Point::new(coords.get_x(coord_idx), coords.get_y(coord_idx));
+            builder.push_point(Some(&point));
+            parents.push(parent as u64);
+        }
+    }
+    (builder.into(), UInt64Array::new(parents.into(), None))
+}
+
+impl CoordsToPoints for PointArray {
+    type Output = (PointArray, UInt64Array);
+
+    fn coords_to_points(&self) -> Self::Output {
+        points_from_ranges(self)
+    }
+}
+
+/// Implementation for arrays with a single coordinate buffer
+macro_rules! coord_range_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> CoordsToPoints for $type {
+            type Output = (PointArray, UInt64Array);
+
+            fn coords_to_points(&self) -> Self::Output {
+                points_from_ranges(self)
+            }
+        }
+    };
+}
+
+coord_range_impl!(LineStringArray<O>);
+coord_range_impl!(PolygonArray<O>);
+coord_range_impl!(MultiPointArray<O>);
+coord_range_impl!(MultiLineStringArray<O>);
+coord_range_impl!(MultiPolygonArray<O>);
+
+/// Build points from the coordinates of each geo object, tagged with the index of their parent
+/// geometry.
+fn points_from_geo<G: CoordsIter<Scalar = f64>>(
+    geoms: impl Iterator<Item = Option<G>>,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+) -> (PointArray, UInt64Array) {
+    let mut builder = PointBuilder::with_capacity_and_options(0, coord_type, metadata);
+    let mut parents = vec![];
+    for (i, maybe_geom) in geoms.enumerate() {
+        if let Some(geom) = maybe_geom {
+            for coord in geom.coords_iter() {
+                builder.push_point(Some(&geo::Point(coord)));
+                parents.push(i as u64);
+            }
+        }
+    }
+    (builder.into(), UInt64Array::new(parents.into(), None))
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> CoordsToPoints for $type {
+            type Output = (PointArray, UInt64Array);
+
+            fn coords_to_points(&self) -> Self::Output {
+                points_from_geo(self.iter_geo(), self.coord_type(), self.metadata())
+            }
+        }
+    };
+}
+
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+/// The coordinates of a rect are its four corners, as in [`geo::Rect`].
+impl CoordsToPoints for RectArray {
+    type Output = (PointArray, UInt64Array);
+
+    fn coords_to_points(&self) -> Self::Output {
+        // Rect arrays have no coordinate buffer to take the coordinate type from
+        points_from_geo(self.iter_geo(), CoordType::default(), self.metadata())
+    }
+}
+
+impl CoordsToPoints for &dyn GeometryArrayTrait {
+    type Output = Result<(PointArray, UInt64Array)>;
+
+    fn coords_to_points(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().coords_to_points(),
+            GeoDataType::LineString(_) => self.as_line_string().coords_to_points(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().coords_to_points(),
+            GeoDataType::Polygon(_) => self.as_polygon().coords_to_points(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().coords_to_points(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().coords_to_points(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().coords_to_points(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().coords_to_points(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().coords_to_points()
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().coords_to_points(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().coords_to_points(),
+            GeoDataType::Mixed(_) => self.as_mixed().coords_to_points(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().coords_to_points(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().coords_to_points(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().coords_to_points()
+            }
+            GeoDataType::WKB => self.as_wkb().coords_to_points(),
+            GeoDataType::LargeWKB => self.as_large_wkb().coords_to_points(),
+            GeoDataType::Rect => self.as_rect().coords_to_points(),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::mp_array;
+    use crate::test::polygon::p_array;
+    use crate::trait_::GeometryArraySelfMethods;
+
+    #[test]
+    fn polygon_vertices() {
+        let arr = p_array();
+        let (points, parents) = arr.coords_to_points();
+        let expected: Vec<(u64, geo::Coord)> = arr
+            .iter_geo_values()
+            .enumerate()
+            .flat_map(|(i, p)| {
+                p.coords_iter()
+                    .map(move |c| (i as u64, c))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(points.len(), expected.len());
+        for (j, (parent, coord)) in expected.into_iter().enumerate() {
+            assert_eq!(parents.value(j), parent);
+            assert_eq!(points.value_as_geo(j), geo::Point(coord));
+        }
+
+        // Sliced arrays only return their own vertices
+        let sliced = arr.slice(1, 1);
+        let (points, parents) = sliced.coords_to_points();
+        assert_eq!(points.len(), sliced.value_as_geo(0).coords_count());
+        assert!(parents.values().iter().all(|i| *i == 0));
+    }
+
+    #[test]
+    fn matches_geo_iteration() {
+        let arr = mp_array();
+        let mixed: MixedGeometryArray<i32> = arr
+            .iter_geo_values()
+            .map(geo::Geometry::MultiPolygon)
+            .collect::<Vec<_>>()
+            .as_slice()
+            .try_into()
+            .unwrap();
+        let (points, parents) = arr.coords_to_points();
+        let (mixed_points, mixed_parents) = mixed.coords_to_points();
+        assert_eq!(parents, mixed_parents);
+        assert_eq!(points.len(), mixed_points.len());
+        for i in 0..points.len() {
+            assert_eq!(points.value_as_geo(i), mixed_points.value_as_geo(i));
+        }
+    }
+
+    #[test]
+    fn rect_corners() {
+        let rect = geo::Rect::new((0., 0.), (1., 2.));
+        let arr: RectArray = vec![Some(rect), None, Some(rect)].into();
+        let (points, parents) = arr.coords_to_points();
+        assert_eq!(parents.values().as_ref(), &[0, 0, 0, 0, 2, 2, 2, 2]);
+        for (j, coord) in rect.coords_iter().enumerate() {
+            assert_eq!(points.value_as_geo(j), geo::Point(coord));
+        }
+
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        assert_eq!(dyn_arr.coords_to_points().unwrap().1, parents);
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+
+use crate::algorithm::native::Take;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+
+/// Remove null geometries from an array.
+///
+/// The valid geometries are copied into new, compacted buffers, so the output holds no memory for
+/// the removed geometries. This is useful before writing to formats that cannot represent null
+/// geometries.
+pub trait DropNulls {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::DropNulls;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+    /// let array: LineStringArray<i32> = vec![None, Some(line_string)].into();
+    ///
+    /// let dropped = array.drop_nulls().unwrap();
+    /// assert_eq!(dropped.len(), 1);
+    /// assert_eq!(dropped.null_count(), 0);
+    /// ```
+    fn drop_nulls(&self) -> Self::Output;
+}
+
+/// The indices of all valid geometries in an array.
+fn valid_indices(array: &dyn GeometryArrayTrait) -> UInt32Array {
+    (0..array.len())
+        .filter(|i| array.is_valid(*i))
+        .map(|i| i as u32)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+macro_rules! drop_nulls_impl {
+    ($type:ty) => {
+        impl DropNulls for $type {
+            type Output = Result<Self>;
+
+            fn drop_nulls(&self) -> Self::Output {
+                self.take(&valid_indices(self))
+            }
+        }
+    };
+}
+
+drop_nulls_impl!(PointArray);
+drop_nulls_impl!(RectArray);
+
+macro_rules! drop_nulls_offset_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> DropNulls for $type {
+            type Output = Result<Self>;
+
+            fn drop_nulls(&self) -> Self::Output {
+                self.take(&valid_indices(self))
+            }
+        }
+    };
+}
+
+drop_nulls_offset_impl!(LineStringArray<O>);
+drop_nulls_offset_impl!(PolygonArray<O>);
+drop_nulls_offset_impl!(MultiPointArray<O>);
+drop_nulls_offset_impl!(MultiLineStringArray<O>);
+drop_nulls_offset_impl!(MultiPolygonArray<O>);
+drop_nulls_offset_impl!(MixedGeometryArray<O>);
+drop_nulls_offset_impl!(GeometryCollectionArray<O>);
+drop_nulls_offset_impl!(WKBArray<O>);
+
+impl DropNulls for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn drop_nulls(&self) -> Self::Output {
+        self.take(&valid_indices(*self))
+    }
+}
+
+impl DropNulls for ChunkedGeometryArray<PointArray> {
+    type Output = Result<ChunkedGeometryArray<PointArray>>;
+
+    fn drop_nulls(&self) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.drop_nulls())?,
+        ))
+    }
+}
+
+/// Implementation that iterates over chunks
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> DropNulls for $type {
+            type Output = Result<$type>;
+
+            fn drop_nulls(&self) -> Self::Output {
+                Ok(ChunkedGeometryArray::new(
+                    self.try_map(|chunk| chunk.drop_nulls())?,
+                ))
+            }
+        }
+    };
+}
+
+chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
+chunked_impl!(ChunkedGeometryArray<PolygonArray<O>>);
+chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
+chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
+chunked_impl!(ChunkedGeometryArray<MultiPolygonArray<O>>);
+chunked_impl!(ChunkedGeometryArray<MixedGeometryArray<O>>);
+chunked_impl!(ChunkedGeometryArray<GeometryCollectionArray<O>>);
+chunked_impl!(ChunkedGeometryArray<WKBArray<O>>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::{p0, p1};
+    use crate::trait_::GeometryArrayAccessor;
+
+    #[test]
+    fn drops_nulls_in_chunks() {
+        let chunk: PointArray = vec![None, Some(p0()), None, Some(p1())].into();
+        let chunked = ChunkedGeometryArray::new(vec![chunk.clone(), chunk]);
+
+        let dropped = chunked.drop_nulls().unwrap();
+        for chunk in dropped.chunks() {
+            assert_eq!(chunk.null_count(), 0);
+            assert_eq!(chunk.value_as_geo(0), p0());
+            assert_eq!(chunk.value_as_geo(1), p1());
+        }
+    }
+
+    #[test]
+    fn dyn_array() {
+        let array: MixedGeometryArray<i32> = vec![Some(geo::Geometry::Point(p0())), None]
+            .as_slice()
+            .try_into()
+            .unwrap();
+        let dropped = (&array as &dyn GeometryArrayTrait).drop_nulls().unwrap();
+        assert_eq!(dropped.len(), 1);
+    }
+}
@@ -0,0 +1,313 @@
+use std::sync::Arc;
+
+use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
+
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The value that replaces null geometries in [`FillNull`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FillValue {
+    /// An empty geometry of the array's geometry type, e.g. `LINESTRING EMPTY` in a
+    /// [`LineStringArray`] or `GEOMETRYCOLLECTION EMPTY` in a [`WKBArray`].
+    ///
+    /// Rect and mixed arrays cannot store an empty geometry, so filling them with `Empty` errors.
+    Empty,
+
+    /// A geometry that is compatible with the array's geometry type.
+    Geometry(geo::Geometry),
+}
+
+/// Replace null geometries in an array.
+///
+/// This is useful before writing to formats that cannot represent null geometries. Geometries
+/// that are already valid are copied unchanged.
+pub trait FillNull {
+    type Output;
+
+    /// Replace every null geometry with `value`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `value` is a geometry that can't be stored in this array, or if the array can't
+    /// store an empty geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{FillNull, FillValue};
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![Some(point!(x: 1., y: 2.)), None].into();
+    ///
+    /// let fill = FillValue::Geometry(point!(x: 0., y: 0.).into());
+    /// let filled = array.fill_null(&fill).unwrap();
+    /// assert_eq!(filled.null_count(), 0);
+    /// assert_eq!(filled.value_as_geo(1), point!(x: 0., y: 0.));
+    ///
+    /// // Empty points have NaN coordinates
+    /// let filled = array.fill_null(&FillValue::Empty).unwrap();
+    /// assert!(filled.value_as_geo(1).x().is_nan());
+    /// ```
+    fn fill_null(&self, value: &FillValue) -> Self::Output;
+}
+
+fn empty_not_supported(array_name: &str) -> GeoArrowError {
+    GeoArrowError::General(format!(
+        "{array_name} cannot store empty geometries to fill nulls with"
+    ))
+}
+
+impl FillNull for PointArray {
+    type Output = Result<Self>;
+
+    fn fill_null(&self, value: &FillValue) -> Self::Output {
+        let mut builder = PointBuilder::with_capacity_and_options(
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
+        for maybe_g in self.iter() {
+            match (maybe_g, value) {
+                (Some(geom), _) => builder.push_point(Some(&geom)),
+                (None, FillValue::Empty) => builder.push_empty(),
+                (None, FillValue::Geometry(fill)) => builder.push_geometry(Some(fill))?,
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+/// Implementation for arrays whose builder can push any compatible geometry
+macro_rules! fill_null_impl {
+    ($type:ty, $builder_type:ty, $push_func:ident, |$builder:ident| $push_empty:expr) => {
+        impl<O: OffsetSizeTrait> FillNull for $type {
+            type Output = Result<Self>;
+
+            fn fill_null(&self, value: &FillValue) -> Self::Output {
+                let mut builder = <$builder_type>::with_capacity_and_options(
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
+                for maybe_g in self.iter() {
+                    match (maybe_g, value) {
+                        (Some(geom), _) => builder.$push_func(Some(&geom))?,
+                        (None, FillValue::Empty) => {
+                            let $builder = &mut builder;
+                            $push_empty
+                        }
+                        (None, FillValue::Geometry(fill)) => builder.push_geometry(Some(fill))?,
+                    }
+                }
+                Ok(builder.finish())
+            }
+        }
+    };
+}
+
+fill_null_impl!(
+    LineStringArray<O>,
+    LineStringBuilder<O>,
+    push_line_string,
+    |builder| builder.push_line_string(Some(&geo::LineString::<f64>::new(vec![])))?
+);
+fill_null_impl!(
+    PolygonArray<O>,
+    PolygonBuilder<O>,
+    push_polygon,
+    |builder| builder.push_empty()
+);
+fill_null_impl!(
+    MultiPointArray<O>,
+    MultiPointBuilder<O>,
+    push_multi_point,
+    |builder| builder.push_multi_point(Some(&geo::MultiPoint::<f64>::new(vec![])))?
+);
+fill_null_impl!(
+    MultiLineStringArray<O>,
+    MultiLineStringBuilder<O>,
+    push_multi_line_string,
+    |builder| builder.push_multi_line_string(Some(&geo::MultiLineString::<f64>::new(vec![])))?
+);
+fill_null_impl!(
+    MultiPolygonArray<O>,
+    MultiPolygonBuilder<O>,
+    push_multi_polygon,
+    |builder| builder.push_empty()
+);
+fill_null_impl!(
+    MixedGeometryArray<O>,
+    MixedGeometryBuilder<O>,
+    push_geometry,
+    |_builder| return Err(empty_not_supported("MixedGeometryArray"))
+);
+
+impl<O: OffsetSizeTrait> FillNull for GeometryCollectionArray<O> {
+    type Output = Result<Self>;
+
+    fn fill_null(&self, value: &FillValue) -> Self::Output {
+        let mut builder = GeometryCollectionBuilder::<O>::with_capacity_and_options(
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
+        let empty = geo::GeometryCollection::<f64>::new_from(vec![]);
+        for maybe_g in self.iter() {
+            match (maybe_g, value) {
+                (Some(geom), _) => builder.push_geometry_collection(Some(&geom))?,
+                (None, FillValue::Empty) => builder.push_geometry_collection(Some(&empty))?,
+                (None, FillValue::Geometry(fill)) => builder.push_geometry(Some(fill), false)?,
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+impl FillNull for RectArray {
+    type Output = Result<Self>;
+
+    fn fill_null(&self, value: &FillValue) -> Self::Output {
+        let fill = match value {
+            FillValue::Empty => return Err(empty_not_supported("RectArray")),
+            FillValue::Geometry(geo::Geometry::Rect(rect)) => rect,
+            FillValue::Geometry(_) => {
+                return Err(GeoArrowError::General(
+                    "RectArray can only be filled with a Rect".to_string(),
+                ))
+            }
+        };
+        let mut builder = RectBuilder::with_capacity(self.len(), self.metadata());
+        for maybe_g in self.iter() {
+            match maybe_g {
+                Some(geom) => builder.push_rect(Some(&geom)),
+                None => builder.push_rect(Some(fill)),
+            }
+        }
+        Ok(builder.into())
+    }
+}
+
+impl<O: OffsetSizeTrait> FillNull for WKBArray<O> {
+    type Output = Result<Self>;
+
+    // The WKB buffers of valid geometries are copied as-is without being parsed
+    fn fill_null(&self, value: &FillValue) -> Self::Output {
+        let mut fill_builder = WKBBuilder::<O>::new();
+        match value {
+            FillValue::Empty => fill_builder
+                .push_geometry_collection(Some(&geo::GeometryCollection::<f64>::new_from(vec![]))),
+            FillValue::Geometry(fill) => fill_builder.push_geometry(Some(fill)),
+        }
+        let fill_array: WKBArray<O> = fill_builder.finish();
+        let fill = fill_array.array.value(0);
+
+        let array: GenericBinaryArray<O> = self
+            .array
+            .iter()
+            .map(|maybe_wkb| Some(maybe_wkb.unwrap_or(fill)))
+            .collect();
+        Ok(WKBArray::new(array, self.metadata()))
+    }
+}
+
+impl FillNull for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn fill_null(&self, value: &FillValue) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().fill_null(value)?),
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().fill_null(value)?),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().fill_null(value)?)
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().fill_null(value)?),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().fill_null(value)?),
+            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().fill_null(value)?),
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().fill_null(value)?)
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().fill_null(value)?)
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().fill_null(value)?)
+            }
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().fill_null(value)?),
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().fill_null(value)?)
+            }
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().fill_null(value)?),
+            GeoDataType::LargeMixed(_) => Arc::new(self.as_large_mixed().fill_null(value)?),
+            GeoDataType::GeometryCollection(_) => {
+                Arc::new(self.as_geometry_collection().fill_null(value)?)
+            }
+            GeoDataType::LargeGeometryCollection(_) => {
+                Arc::new(self.as_large_geometry_collection().fill_null(value)?)
+            }
+            GeoDataType::WKB => Arc::new(self.as_wkb().fill_null(value)?),
+            GeoDataType::LargeWKB => Arc::new(self.as_large_wkb().fill_null(value)?),
+            GeoDataType::Rect => Arc::new(self.as_rect().fill_null(value)?),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait + FillNull<Output = Result<G>>> FillNull for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedGeometryArray<G>>;
+
+    fn fill_null(&self, value: &FillValue) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.fill_null(value))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::NumParts;
+    use crate::test::polygon::p0;
+    use geo::{line_string, point};
+
+    #[test]
+    fn fill_empty() {
+        let array: PolygonArray<i32> = vec![None, Some(p0())].into();
+        let filled = array.fill_null(&FillValue::Empty).unwrap();
+        assert_eq!(filled.null_count(), 0);
+        assert_eq!(filled.num_parts().value(0), 0);
+        assert_eq!(filled.value_as_geo(1), p0());
+    }
+
+    #[test]
+    fn fill_incompatible_geometry() {
+        let array: LineStringArray<i32> = vec![None, Some(line_string![(x: 0., y: 0.)])].into();
+        let fill = FillValue::Geometry(point!(x: 0., y: 0.).into());
+        assert!(array.fill_null(&fill).is_err());
+    }
+
+    #[test]
+    fn fill_wkb() {
+        let array: WKBArray<i32> = vec![None, Some(geo::Geometry::Point(point!(x: 1., y: 2.)))]
+            .as_slice()
+            .try_into()
+            .unwrap();
+        let fill = FillValue::Geometry(point!(x: 0., y: 0.).into());
+        let filled = array.fill_null(&fill).unwrap();
+        assert_eq!(
+            filled.value_as_geo(0),
+            geo::Geometry::Point(point!(x: 0., y: 0.))
+        );
+        assert_eq!(
+            filled.value_as_geo(1),
+            geo::Geometry::Point(point!(x: 1., y: 2.))
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use arrow_array::{GenericStringArray, OffsetSizeTrait, StringArray};
+use geo::{coord, Rect};
+
+use crate::array::{PointArray, RectArray};
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+
+/// The geohash base32 alphabet
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The maximum supported geohash length, which has a precision of a few centimeters.
+const MAX_PRECISION: usize = 12;
+
+/// Encode points as [geohashes](https://en.wikipedia.org/wiki/Geohash).
+///
+/// Point coordinates are interpreted as longitude and latitude in degrees. Null points are
+/// encoded as null strings.
+pub trait Geohash {
+    type Output;
+
+    /// Encode each point as a geohash of `precision` characters, between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Geohash;
+    /// use geoarrow::array::PointArray;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: -5.6, y: 42.6)].as_slice().into();
+    /// let geohashes = array.geohash(5).unwrap();
+    /// assert_eq!(geohashes.value(0), "ezs42");
+    /// ```
+    fn geohash(&self, precision: usize) -> Self::Output;
+}
+
+fn check_precision(precision: usize) -> Result<()> {
+    if (1..=MAX_PRECISION).contains(&precision) {
+        Ok(())
+    } else {
+        Err(GeoArrowError::General(format!(
+            "Geohash precision must be between 1 and {MAX_PRECISION}, got {precision}"
+        )))
+    }
+}
+
+/// Encode a single longitude/latitude pair.
+fn encode(lon: f64, lat: f64, precision: usize) -> Result<String> {
+    if !(-180. ..=180.).contains(&lon) || !(-90. ..=90.).contains(&lat) {
+        return Err(GeoArrowError::General(format!(
+            "Invalid coordinate for geohash: ({lon}, {lat})"
+        )));
+    }
+
+    let (mut lon_range, mut lat_range) = ((-180., 180.), (-90., 90.));
+    let mut geohash = String::with_capacity(precision);
+    // Bits alternate between longitude and latitude, starting with longitude
+    let mut is_lon = true;
+    for _ in 0..precision {
+        let mut char_index = 0;
+        for _ in 0..5 {
+            let (range, value) = if is_lon {
+                (&mut lon_range, lon)
+            } else {
+                (&mut lat_range, lat)
+            };
+            let mid = (range.0 + range.1) / 2.;
+            char_index <<= 1;
+            if value >= mid {
+                char_index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+        geohash.push(BASE32[char_index] as char);
+    }
+    Ok(geohash)
+}
+
+/// Decode a single geohash to its bounding box.
+fn decode(geohash: &str) -> Result<Rect> {
+    if geohash.is_empty() || geohash.len() > MAX_PRECISION {
+        return Err(GeoArrowError::General(format!(
+            "Invalid geohash length: {geohash:?}"
+        )));
+    }
+
+    let (mut lon_range, mut lat_range) = ((-180., 180.), (-90., 90.));
+    let mut is_lon = true;
+    for c in geohash.bytes() {
+        let char_index = BASE32
+            .iter()
+            .position(|b| *b == c.to_ascii_lowercase())
+            .ok_or_else(|| {
+                GeoArrowError::General(format!("Invalid geohash character in {geohash:?}"))
+            })?;
+        for bit in (0..5).rev() {
+            let range: &mut (f64, f64) = if is_lon {
+                &mut lon_range
+            } else {
+                &mut lat_range
+            };
+            let mid = (range.0 + range.1) / 2.;
+            if (char_index >> bit) & 1 == 1 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+    }
+
+    Ok(Rect::new(
+        coord! { x: lon_range.0, y: lat_range.0 },
+        coord! { x: lon_range.1, y: lat_range.1 },
+    ))
+}
+
+impl Geohash for PointArray {
+    type Output = Result<StringArray>;
+
+    fn geohash(&self, precision: usize) -> Self::Output {
+        check_precision(precision)?;
+        self.iter_geo()
+            .map(|maybe_point| {
+                maybe_point
+                    .map(|point| encode(point.x(), point.y(), precision))
+                    .transpose()
+            })
+            .collect()
+    }
+}
+
+impl Geohash for ChunkedGeometryArray<PointArray> {
+    type Output = Result<ChunkedArray<StringArray>>;
+
+    fn geohash(&self, precision: usize) -> Self::Output {
+        self.try_map(|chunk| chunk.geohash(precision))?.try_into()
+    }
+}
+
+/// Decode geohashes to the bounding boxes of their cells.
+///
+/// Geohashes are case insensitive. Null strings are decoded as null rects.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::geohash_to_rect;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use arrow_array::StringArray;
+///
+/// let geohashes = StringArray::from(vec!["ezs42"]);
+/// let rects = geohash_to_rect(&geohashes).unwrap();
+/// let rect = rects.value_as_geo(0);
+/// assert!(rect.min().x < -5.6 && rect.max().x > -5.6);
+/// assert!(rect.min().y < 42.6 && rect.max().y > 42.6);
+/// ```
+pub fn geohash_to_rect<O: OffsetSizeTrait>(geohashes: &GenericStringArray<O>) -> Result<RectArray> {
+    let rects = geohashes
+        .iter()
+        .map(|maybe_geohash| maybe_geohash.map(decode).transpose())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(rects.into())
+}
+
+/// Decode geohashes to the centers of their cells.
+///
+/// Geohashes are case insensitive. Null strings are decoded as null points.
+pub fn geohash_to_point<O: OffsetSizeTrait>(
+    geohashes: &GenericStringArray<O>,
+) -> Result<PointArray> {
+    let points = geohashes
+        .iter()
+        .map(|maybe_geohash| {
+            maybe_geohash
+                .map(|geohash| Ok(decode(geohash)?.center().into()))
+                .transpose()
+        })
+        .collect::<Result<Vec<Option<geo::Point>>>>()?;
+    Ok(points.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryArrayTrait;
+    use arrow_array::Array;
+    use geo::point;
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let array: PointArray = vec![
+            Some(point!(x: 2.3522, y: 48.8566)),
+            None,
+            Some(point!(x: -122.4194, y: 37.7749)),
+        ]
+        .into();
+        let geohashes = array.geohash(9).unwrap();
+        assert_eq!(geohashes.value(0), "u09tvw0f6");
+        assert!(geohashes.is_null(1));
+        assert_eq!(geohashes.value(2), "9q8yyk8yt");
+
+        let points = geohash_to_point(&geohashes).unwrap();
+        assert!(points.is_null(1));
+        for (decoded, original) in points.iter_geo().zip(array.iter_geo()) {
+            if let (Some(decoded), Some(original)) = (decoded, original) {
+                assert!((decoded.x() - original.x()).abs() < 1e-4);
+                assert!((decoded.y() - original.y()).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        let array: PointArray = vec![point!(x: 0., y: 0.)].as_slice().into();
+        assert!(array.geohash(0).is_err());
+        assert!(array.geohash(13).is_err());
+
+        let out_of_range: PointArray = vec![point!(x: 200., y: 0.)].as_slice().into();
+        assert!(out_of_range.geohash(5).is_err());
+
+        let geohashes = StringArray::from(vec!["ezs4a"]);
+        assert!(geohash_to_rect(&geohashes).is_err());
+    }
+}
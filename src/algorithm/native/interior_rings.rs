@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// Extract the interior rings (holes) of polygonal geometries.
+///
+/// Each output geometry is a MultiLineString holding the interior rings of the input geometry,
+/// which is empty for polygons without holes. The output is computed from the offsets of the
+/// input and copies coordinates of interior rings only; no geometries are constructed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+///
+/// use geoarrow::algorithm::native::InteriorRings;
+/// use geoarrow::array::PolygonArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 0.)],
+///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+/// );
+/// let array: PolygonArray<i32> = vec![polygon].as_slice().into();
+/// let interiors = array.interior_rings();
+/// assert_eq!(interiors.value_as_geo(0).0.len(), 1);
+/// ```
+pub trait InteriorRings {
+    type Output;
+
+    fn interior_rings(&self) -> Self::Output;
+}
+
+/// The ring indices of the interior rings of the polygon at `polygon_idx`.
+fn interior_ring_range<O: OffsetSizeTrait>(
+    geom_offsets: &arrow_buffer::OffsetBuffer<O>,
+    polygon_idx: usize,
+) -> std::ops::Range<usize> {
+    let (start, end) = geom_offsets.start_end(polygon_idx);
+    (start + 1).min(end)..end
+}
+
+impl<O: OffsetSizeTrait> InteriorRings for PolygonArray<O> {
+    type Output = MultiLineStringArray<O>;
+
+    fn interior_rings(&self) -> Self::Output {
+        let mut coord_capacity = 0;
+        let mut ring_capacity = 0;
+        for i in 0..self.len() {
+            let rings = interior_ring_range(&self.geom_offsets, i);
+            ring_capacity += rings.len();
+            for ring_idx in rings {
+                let (start, end) = self.ring_offsets.start_end(ring_idx);
+                coord_capacity += end - start;
+            }
+        }
+
+        let capacity = MultiLineStringCapacity::new(coord_capacity, ring_capacity, self.len());
+        let mut builder = MultiLineStringBuilder::<O>::with_capacity_and_options(
+            capacity,
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                builder.push_null();
+                continue;
+            }
+
+            let rings = interior_ring_range(&self.geom_offsets, i);
+            // Offsets can't overflow, as they are bounded by the offsets of the input
+            unsafe {
+                builder.try_push_geom_offset(rings.len()).unwrap();
+                for ring_idx in rings {
+                    let (start, end) = self.ring_offsets.start_end(ring_idx);
+                    builder.try_push_ring_offset(end - start).unwrap();
+                    for coord_idx in start..end {
+                        builder
+                            .push_xy(self.coords.get_x(coord_idx), self.coords.get_y(coord_idx))
+                            .unwrap();
+                    }
+                }
+            }
+        }
+
+        builder.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> InteriorRings for MultiPolygonArray<O> {
+    type Output = MultiLineStringArray<O>;
+
+    fn interior_rings(&self) -> Self::Output {
+        let mut coord_capacity = 0;
+        let mut ring_capacity = 0;
+        for i in 0..self.len() {
+            let (start, end) = self.geom_offsets.start_end(i);
+            for polygon_idx in start..end {
+                let rings = interior_ring_range(&self.polygon_offsets, polygon_idx);
+                ring_capacity += rings.len();
+                for ring_idx in rings {
+                    let (start, end) = self.ring_offsets.start_end(ring_idx);
+                    coord_capacity += end - start;
+                }
+            }
+        }
+
+        let capacity = MultiLineStringCapacity::new(coord_capacity, ring_capacity, self.len());
+        let mut builder = MultiLineStringBuilder::<O>::with_capacity_and_options(
+            capacity,
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                builder.push_null();
+                continue;
+            }
+
+            let (start, end) = self.geom_offsets.start_end(i);
+            let num_interiors = (start..end)
+                .map(|polygon_idx| interior_ring_range(&self.polygon_offsets, polygon_idx).len())
+                .sum();
+
+            // Offsets can't overflow, as they are bounded by the offsets of the input
+            unsafe {
+                builder.try_push_geom_offset(num_interiors).unwrap();
+                for polygon_idx in start..end {
+                    for ring_idx in interior_ring_range(&self.polygon_offsets, polygon_idx) {
+                        let (start, end) = self.ring_offsets.start_end(ring_idx);
+                        builder.try_push_ring_offset(end - start).unwrap();
+                        for coord_idx in start..end {
+                            builder
+                                .push_xy(self.coords.get_x(coord_idx), self.coords.get_y(coord_idx))
+                                .unwrap();
+                        }
+                    }
+                }
+            }
+        }
+
+        builder.finish()
+    }
+}
+
+impl InteriorRings for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn interior_rings(&self) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().interior_rings()),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().interior_rings()),
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().interior_rings()),
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().interior_rings())
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl<O: OffsetSizeTrait> InteriorRings for ChunkedGeometryArray<PolygonArray<O>> {
+    type Output = ChunkedGeometryArray<MultiLineStringArray<O>>;
+
+    fn interior_rings(&self) -> Self::Output {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.interior_rings()))
+    }
+}
+
+impl<O: OffsetSizeTrait> InteriorRings for ChunkedGeometryArray<MultiPolygonArray<O>> {
+    type Output = ChunkedGeometryArray<MultiLineStringArray<O>>;
+
+    fn interior_rings(&self) -> Self::Output {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.interior_rings()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::mp_array;
+    use crate::test::polygon::p_array;
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+    use geo::MultiLineString;
+
+    #[test]
+    fn polygon_interior_rings() {
+        let arr = p_array();
+        let interiors = arr.interior_rings();
+        for i in 0..arr.len() {
+            let expected = MultiLineString::new(arr.value_as_geo(i).interiors().to_vec());
+            assert_eq!(interiors.value_as_geo(i), expected);
+        }
+
+        // Sliced arrays keep the coordinates of the original array
+        let sliced = arr.slice(1, 1);
+        let expected = MultiLineString::new(sliced.value_as_geo(0).interiors().to_vec());
+        assert_eq!(sliced.interior_rings().value_as_geo(0), expected);
+    }
+
+    #[test]
+    fn multi_polygon_interior_rings() {
+        let arr = mp_array();
+        let interiors = arr.interior_rings();
+        for i in 0..arr.len() {
+            let expected = MultiLineString::new(
+                arr.value_as_geo(i)
+                    .0
+                    .iter()
+                    .flat_map(|polygon| polygon.interiors().to_vec())
+                    .collect(),
+            );
+            assert_eq!(interiors.value_as_geo(i), expected);
+        }
+    }
+
+    #[test]
+    fn null_interior_rings() {
+        let arr: PolygonArray<i32> = vec![None::<geo::Polygon>].into();
+        assert!(arr.interior_rings().is_null(0));
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{Coord, LineString, MultiLineString};
+
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+
+/// Merge line strings that share endpoints into maximal line strings.
+///
+/// Two line strings are joined where an endpoint of one is exactly equal to an endpoint of the
+/// other and no other line string ends at that point, reversing line strings where needed.
+/// Merging stops at points where more than two line strings meet, like road junctions. Line
+/// strings forming closed loops are merged into a single closed line string.
+pub trait LineMerge {
+    type Output;
+    type AggregateOutput;
+
+    /// Merge the line strings of each geometry separately. Null geometries stay null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::LineMerge;
+    /// use geoarrow::array::MultiLineStringArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::{line_string, MultiLineString};
+    ///
+    /// let segments = MultiLineString::new(vec![
+    ///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+    ///     line_string![(x: 2., y: 0.), (x: 1., y: 0.)],
+    /// ]);
+    /// let array: MultiLineStringArray<i32> = vec![segments].as_slice().into();
+    /// let merged = array.line_merge();
+    /// assert_eq!(
+    ///     merged.value_as_geo(0),
+    ///     MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]])
+    /// );
+    /// ```
+    fn line_merge(&self) -> Self::Output;
+
+    /// Merge the line strings of all geometries in the array together. Null geometries are
+    /// skipped.
+    fn line_merge_all(&self) -> Self::AggregateOutput;
+}
+
+/// An endpoint of a line string, compared by exact coordinate equality.
+pub(crate) fn node_key(coord: &Coord) -> (u64, u64) {
+    // Normalize -0.0 so that it matches 0.0
+    ((coord.x + 0.).to_bits(), (coord.y + 0.).to_bits())
+}
+
+/// Merge line strings that share endpoints, in the order they are first encountered.
+pub(crate) fn merge_line_strings(line_strings: Vec<LineString>) -> Vec<LineString> {
+    // Line strings without a start and end point can't be merged
+    let (edges, mut merged): (Vec<_>, Vec<_>) =
+        line_strings.into_iter().partition(|ls| ls.0.len() > 1);
+
+    // The edges incident to each node, and whether the node is the start of the edge
+    let mut nodes: HashMap<(u64, u64), Vec<(usize, bool)>> = HashMap::new();
+    for (edge_idx, edge) in edges.iter().enumerate() {
+        nodes
+            .entry(node_key(&edge.0[0]))
+            .or_default()
+            .push((edge_idx, true));
+        nodes
+            .entry(node_key(edge.0.last().unwrap()))
+            .or_default()
+            .push((edge_idx, false));
+    }
+
+    let mut used = vec![false; edges.len()];
+    let walk = |edge_idx: usize, from_start: bool, used: &mut Vec<bool>| -> LineString {
+        let mut coords: Vec<Coord> = Vec::new();
+        let (mut edge_idx, mut from_start) = (edge_idx, from_start);
+        loop {
+            used[edge_idx] = true;
+            let edge = &edges[edge_idx].0;
+            let skip = usize::from(!coords.is_empty());
+            if from_start {
+                coords.extend(edge.iter().skip(skip));
+            } else {
+                coords.extend(edge.iter().rev().skip(skip));
+            }
+
+            // Continue only through nodes where exactly two edges meet
+            let incident = &nodes[&node_key(coords.last().unwrap())];
+            if incident.len() != 2 {
+                break;
+            }
+            match incident.iter().find(|(other_idx, _)| !used[*other_idx]) {
+                Some((other_idx, is_start)) => {
+                    edge_idx = *other_idx;
+                    from_start = *is_start;
+                }
+                None => break,
+            }
+        }
+        LineString::new(coords)
+    };
+
+    // Start at the ends of chains first, so that open chains are merged from end to end
+    for (edge_idx, edge) in edges.iter().enumerate() {
+        for (coord, from_start) in [(&edge.0[0], true), (edge.0.last().unwrap(), false)] {
+            if !used[edge_idx] && nodes[&node_key(coord)].len() != 2 {
+                merged.push(walk(edge_idx, from_start, &mut used));
+            }
+        }
+    }
+
+    // Any remaining edges form closed loops
+    for edge_idx in 0..edges.len() {
+        if !used[edge_idx] {
+            merged.push(walk(edge_idx, true, &mut used));
+        }
+    }
+    merged
+}
+
+impl<O: OffsetSizeTrait> LineMerge for LineStringArray<O> {
+    type Output = LineStringArray<O>;
+    type AggregateOutput = LineStringArray<O>;
+
+    /// Each geometry is a single line string, so this returns a copy of the array.
+    fn line_merge(&self) -> Self::Output {
+        self.clone()
+    }
+
+    fn line_merge_all(&self) -> Self::AggregateOutput {
+        merge_line_strings(self.iter_geo().flatten().collect())
+            .as_slice()
+            .into()
+    }
+}
+
+impl<O: OffsetSizeTrait> LineMerge for MultiLineStringArray<O> {
+    type Output = MultiLineStringArray<O>;
+    type AggregateOutput = LineStringArray<O>;
+
+    fn line_merge(&self) -> Self::Output {
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| MultiLineString::new(merge_line_strings(geom.0))))
+            .collect();
+        output_geoms.into()
+    }
+
+    fn line_merge_all(&self) -> Self::AggregateOutput {
+        let line_strings = self.iter_geo().flatten().flat_map(|geom| geom.0).collect();
+        merge_line_strings(line_strings).as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait> LineMerge for ChunkedGeometryArray<MultiLineStringArray<O>> {
+    type Output = Result<ChunkedGeometryArray<MultiLineStringArray<O>>>;
+    type AggregateOutput = LineStringArray<O>;
+
+    fn line_merge(&self) -> Self::Output {
+        self.map(|chunk| chunk.line_merge()).try_into()
+    }
+
+    /// Line strings are merged across chunks.
+    fn line_merge_all(&self) -> Self::AggregateOutput {
+        let line_strings = self
+            .chunks()
+            .iter()
+            .flat_map(|chunk| chunk.iter_geo().flatten().flat_map(|geom| geom.0))
+            .collect();
+        merge_line_strings(line_strings).as_slice().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryArrayTrait;
+    use geo::line_string;
+
+    #[test]
+    fn merge_stops_at_junctions() {
+        // Three line strings meeting at (1, 0), and one continuing from the end of another
+        let line_strings = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 3., y: 0.), (x: 2., y: 0.)],
+        ];
+        let merged = merge_line_strings(line_strings);
+        assert_eq!(
+            merged,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_closed_loop() {
+        let line_strings = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+        ];
+        let merged = merge_line_strings(line_strings);
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0].is_closed());
+        assert_eq!(merged[0].0.len(), 4);
+    }
+
+    #[test]
+    fn merge_all_across_geometries() {
+        let array: MultiLineStringArray<i32> = vec![
+            Some(MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            ])),
+            None,
+            Some(MultiLineString::new(vec![
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            ])),
+        ]
+        .into();
+
+        let merged = array.line_merge();
+        assert!(merged.is_null(1));
+        assert_eq!(merged.value_as_geo(0).0.len(), 1);
+
+        let merged_all = array.line_merge_all();
+        assert_eq!(merged_all.len(), 1);
+        assert_eq!(
+            merged_all.value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]
+        );
+    }
+}
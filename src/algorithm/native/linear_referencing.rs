@@ -0,0 +1,502 @@
+use arrow_array::builder::Float64Builder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, Float64Array, ListArray, OffsetSizeTrait, UInt32Array};
+use geo::{Coord, LineString};
+
+use crate::array::{LineStringArray, PointArray, PointBuilder};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// A route of a [`Routes`] collection: its vertices with the measure of each vertex.
+#[derive(Debug, Clone)]
+struct Route {
+    coords: Vec<Coord>,
+    measures: Vec<f64>,
+}
+
+impl Route {
+    /// The position on the route closest to a point, as the index of a segment and the fraction
+    /// along that segment.
+    fn project(&self, point: Coord) -> (usize, f64) {
+        let mut closest = (0, 0., f64::INFINITY);
+        for (segment_idx, segment) in self.coords.windows(2).enumerate() {
+            let (start, end) = (segment[0], segment[1]);
+            let delta = end - start;
+            let length_2 = delta.x * delta.x + delta.y * delta.y;
+            let t = if length_2 > 0. {
+                let offset = point - start;
+                ((offset.x * delta.x + offset.y * delta.y) / length_2).clamp(0., 1.)
+            } else {
+                0.
+            };
+            let position = start + delta * t;
+            let distance = (point.x - position.x).hypot(point.y - position.y);
+            if distance < closest.2 {
+                closest = (segment_idx, t, distance);
+            }
+        }
+        (closest.0, closest.1)
+    }
+
+    /// The measure of the position closest to a point.
+    fn locate(&self, point: Coord) -> f64 {
+        if self.coords.len() == 1 {
+            return self.measures[0];
+        }
+        let (segment_idx, t) = self.project(point);
+        let (start, end) = (self.measures[segment_idx], self.measures[segment_idx + 1]);
+        start + (end - start) * t
+    }
+
+    /// The position at a measure, or `None` if the measure is not on the route.
+    fn interpolate(&self, measure: f64) -> Option<Coord> {
+        let first = *self.measures.first()?;
+        let last = *self.measures.last()?;
+        if !(first..=last).contains(&measure) {
+            return None;
+        }
+        if self.coords.len() == 1 {
+            return Some(self.coords[0]);
+        }
+        // The first segment whose end is at or after the measure
+        let end_idx = self
+            .measures
+            .partition_point(|m| *m < measure)
+            .clamp(1, self.coords.len() - 1);
+        let (start, end) = (self.coords[end_idx - 1], self.coords[end_idx]);
+        let (start_m, end_m) = (self.measures[end_idx - 1], self.measures[end_idx]);
+        if end_m == start_m {
+            return Some(end);
+        }
+        Some(start + (end - start) * ((measure - start_m) / (end_m - start_m)))
+    }
+
+    /// The part of the route between two measures, or `None` if the ranges don't overlap.
+    fn substring(&self, from: f64, to: f64) -> Option<LineString> {
+        let (from, to) = (from.min(to), from.max(to));
+        let first = *self.measures.first()?;
+        let last = *self.measures.last()?;
+        if to < first || from > last {
+            return None;
+        }
+        let (from, to) = (from.max(first), to.min(last));
+
+        let mut coords = vec![self.interpolate(from)?];
+        self.coords
+            .iter()
+            .zip(&self.measures)
+            .filter(|(_, m)| from < **m && **m < to)
+            .for_each(|(coord, _)| coords.push(*coord));
+        coords.push(self.interpolate(to)?);
+        Some(LineString::new(coords))
+    }
+}
+
+/// Line strings with a measure at each vertex, for linear referencing along roads, railways or
+/// pipelines.
+///
+/// Events are positions or ranges along a route given by the index of the route and a measure,
+/// such as a distance from the start of the route or a kilometre post. Measures along a route must
+/// be non-decreasing, and are interpolated linearly between vertices. Point events can be
+/// projected onto routes with [`locate`](Routes::locate) and placed on them with
+/// [`interpolate`](Routes::interpolate), and line events cut from routes with
+/// [`substring`](Routes::substring).
+///
+/// Geometries only have x and y coordinates, so measures are stored alongside them rather than as
+/// M values of the coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::{Float64Array, UInt32Array};
+/// use geoarrow::algorithm::native::Routes;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{line_string, point};
+///
+/// let lines: LineStringArray<i32> = vec![line_string![(x: 0., y: 0.), (x: 100., y: 0.)]]
+///     .as_slice()
+///     .into();
+/// // The route starts at kilometre 12
+/// let routes = Routes::try_with_range(
+///     &lines,
+///     &Float64Array::from(vec![12.]),
+///     &Float64Array::from(vec![13.]),
+/// )
+/// .unwrap();
+///
+/// let route_idx = UInt32Array::from(vec![0]);
+/// let points: PointArray = vec![point!(x: 25., y: 3.)].as_slice().into();
+/// let measures = routes.locate(&route_idx, &points).unwrap();
+/// assert_eq!(measures.value(0), 12.25);
+///
+/// let sections = routes
+///     .substring(
+///         &route_idx,
+///         &Float64Array::from(vec![12.5]),
+///         &Float64Array::from(vec![14.]),
+///     )
+///     .unwrap();
+/// assert_eq!(
+///     sections.value_as_geo(0),
+///     line_string![(x: 50., y: 0.), (x: 100., y: 0.)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Routes {
+    routes: Vec<Option<Route>>,
+}
+
+impl Routes {
+    /// Create routes measured by their euclidean length from the start of each route.
+    ///
+    /// Null and empty line strings become null routes.
+    pub fn new<O: OffsetSizeTrait>(lines: &LineStringArray<O>) -> Self {
+        let routes = lines
+            .iter_geo()
+            .map(|maybe_line| {
+                maybe_line
+                    .filter(|line| !line.0.is_empty())
+                    .map(|line| Route {
+                        measures: cumulative_lengths(&line.0),
+                        coords: line.0,
+                    })
+            })
+            .collect();
+        Self { routes }
+    }
+
+    /// Create routes whose measures increase linearly with length from `start` at the first
+    /// vertex to `end` at the last vertex of each route.
+    ///
+    /// Routes where either measure is null are null.
+    ///
+    /// # Errors
+    ///
+    /// - if `start` or `end` doesn't have one value per route
+    /// - if `end` is less than `start` for any route
+    pub fn try_with_range<O: OffsetSizeTrait>(
+        lines: &LineStringArray<O>,
+        start: &Float64Array,
+        end: &Float64Array,
+    ) -> Result<Self> {
+        if start.len() != lines.len() || end.len() != lines.len() {
+            return Err(GeoArrowError::General(
+                "Expected one start and end measure per route".to_string(),
+            ));
+        }
+
+        let mut routes = Self::new(lines).routes;
+        for (maybe_route, (start, end)) in routes.iter_mut().zip(start.iter().zip(end.iter())) {
+            let (Some(route), Some(start), Some(end)) = (maybe_route.as_mut(), start, end) else {
+                *maybe_route = None;
+                continue;
+            };
+            if end < start {
+                return Err(GeoArrowError::General(format!(
+                    "Route measures must be non-decreasing, got a range from {} to {}",
+                    start, end
+                )));
+            }
+            let length = *route.measures.last().unwrap();
+            route.measures.iter_mut().for_each(|m| {
+                let fraction = if length > 0. { *m / length } else { 0. };
+                *m = start + (end - start) * fraction;
+            });
+        }
+        Ok(Self { routes })
+    }
+
+    /// Create routes with the given measure at each vertex, such as the M values read from a
+    /// file.
+    ///
+    /// Routes where either the line string or the list of measures is null are null.
+    ///
+    /// # Errors
+    ///
+    /// - if `measures` doesn't have one list per route
+    /// - if a list doesn't have one measure per vertex, or its values are not [`Float64Array`]
+    /// - if the measures of a route decrease or are null
+    pub fn try_with_measures<O: OffsetSizeTrait>(
+        lines: &LineStringArray<O>,
+        measures: &ListArray,
+    ) -> Result<Self> {
+        if measures.len() != lines.len() {
+            return Err(GeoArrowError::General(
+                "Expected one list of measures per route".to_string(),
+            ));
+        }
+
+        let routes = lines
+            .iter_geo()
+            .zip(measures.iter())
+            .map(|(maybe_line, maybe_measures)| {
+                let (Some(line), Some(measures)) = (maybe_line, maybe_measures) else {
+                    return Ok(None);
+                };
+                let measures = measures.as_primitive_opt::<Float64Type>().ok_or_else(|| {
+                    GeoArrowError::General("Measures must be Float64".to_string())
+                })?;
+                if measures.len() != line.0.len() {
+                    return Err(GeoArrowError::General(format!(
+                        "Expected {} measures, got {}",
+                        line.0.len(),
+                        measures.len()
+                    )));
+                }
+                if measures.null_count() > 0
+                    || measures.values().windows(2).any(|pair| pair[1] < pair[0])
+                {
+                    return Err(GeoArrowError::General(
+                        "Route measures must be non-null and non-decreasing".to_string(),
+                    ));
+                }
+                Ok((!line.0.is_empty()).then(|| Route {
+                    coords: line.0,
+                    measures: measures.values().to_vec(),
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { routes })
+    }
+
+    /// The number of routes.
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Whether there are no routes.
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// The route of each event, erroring if an index is out of bounds.
+    fn event_routes<'a>(
+        &'a self,
+        route_idx: &'a UInt32Array,
+    ) -> impl Iterator<Item = Result<Option<&'a Route>>> + 'a {
+        route_idx.iter().map(|maybe_idx| {
+            maybe_idx
+                .map(|idx| {
+                    self.routes.get(idx as usize).ok_or_else(|| {
+                        GeoArrowError::General(format!(
+                            "Route index {} out of bounds for {} routes",
+                            idx,
+                            self.routes.len()
+                        ))
+                    })
+                })
+                .transpose()
+                .map(|maybe_route| maybe_route.and_then(|route| route.as_ref()))
+        })
+    }
+
+    /// Project point events onto their routes, returning the measure of the closest position
+    /// along each route.
+    ///
+    /// The output is null where the route index, the route or the point is null.
+    ///
+    /// # Errors
+    ///
+    /// - if `route_idx` and `points` have different lengths
+    /// - if a route index is out of bounds
+    pub fn locate(&self, route_idx: &UInt32Array, points: &PointArray) -> Result<Float64Array> {
+        if route_idx.len() != points.len() {
+            return Err(GeoArrowError::General(
+                "Expected one route index per point".to_string(),
+            ));
+        }
+
+        let mut output_array = Float64Builder::with_capacity(points.len());
+        for (route, point) in self.event_routes(route_idx).zip(points.iter_geo()) {
+            match (route?, point) {
+                (Some(route), Some(point)) => output_array.append_value(route.locate(point.0)),
+                _ => output_array.append_null(),
+            }
+        }
+        Ok(output_array.finish())
+    }
+
+    /// Place point events at their measure along their routes.
+    ///
+    /// The output is null where any input is null, or where the measure is outside the range of
+    /// measures of the route.
+    ///
+    /// # Errors
+    ///
+    /// - if `route_idx` and `measures` have different lengths
+    /// - if a route index is out of bounds
+    pub fn interpolate(
+        &self,
+        route_idx: &UInt32Array,
+        measures: &Float64Array,
+    ) -> Result<PointArray> {
+        if route_idx.len() != measures.len() {
+            return Err(GeoArrowError::General(
+                "Expected one route index per measure".to_string(),
+            ));
+        }
+
+        let mut output_array = PointBuilder::with_capacity(measures.len());
+        for (route, measure) in self.event_routes(route_idx).zip(measures.iter()) {
+            let point = match (route?, measure) {
+                (Some(route), Some(measure)) => route.interpolate(measure).map(geo::Point),
+                _ => None,
+            };
+            output_array.push_point(point.as_ref());
+        }
+        Ok(output_array.finish())
+    }
+
+    /// Cut line events from their routes, between the `from` and `to` measures of each event.
+    ///
+    /// Ranges extending beyond a route are clipped to it, and ranges with `from` greater than
+    /// `to` follow the direction of the route as well. The output is null where any input is null,
+    /// or where the range doesn't overlap the range of measures of the route.
+    ///
+    /// # Errors
+    ///
+    /// - if `route_idx`, `from` and `to` have different lengths
+    /// - if a route index is out of bounds
+    pub fn substring(
+        &self,
+        route_idx: &UInt32Array,
+        from: &Float64Array,
+        to: &Float64Array,
+    ) -> Result<LineStringArray<i32>> {
+        if route_idx.len() != from.len() || route_idx.len() != to.len() {
+            return Err(GeoArrowError::General(
+                "Expected one route index per measure range".to_string(),
+            ));
+        }
+
+        let output_geoms = self
+            .event_routes(route_idx)
+            .zip(from.iter().zip(to.iter()))
+            .map(|(route, (from, to))| {
+                Ok(match (route?, from, to) {
+                    (Some(route), Some(from), Some(to)) => route.substring(from, to),
+                    _ => None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(output_geoms.into())
+    }
+}
+
+/// The euclidean length from the start of a line string to each of its vertices.
+fn cumulative_lengths(coords: &[Coord]) -> Vec<f64> {
+    let mut length = 0.;
+    let mut lengths = Vec::with_capacity(coords.len());
+    lengths.push(0.);
+    for segment in coords.windows(2) {
+        length += (segment[1].x - segment[0].x).hypot(segment[1].y - segment[0].y);
+        lengths.push(length);
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::builder::{Float64Builder, ListBuilder};
+    use geo::{line_string, point};
+
+    fn lines() -> LineStringArray<i32> {
+        vec![
+            Some(line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]),
+            None,
+        ]
+        .into()
+    }
+
+    #[test]
+    fn length_measures() {
+        let routes = Routes::new(&lines());
+        let route_idx = UInt32Array::from(vec![Some(0), Some(1), None, Some(0)]);
+
+        let points: PointArray = vec![
+            point!(x: 12., y: 5.),
+            point!(x: 0., y: 0.),
+            point!(x: 0., y: 0.),
+            point!(x: -5., y: 1.),
+        ]
+        .as_slice()
+        .into();
+        let measures = routes.locate(&route_idx, &points).unwrap();
+        assert_eq!(measures.value(0), 15.);
+        assert!(measures.is_null(1));
+        assert!(measures.is_null(2));
+        assert_eq!(measures.value(3), 0.);
+
+        let measures = Float64Array::from(vec![Some(15.), Some(0.), Some(0.), Some(21.)]);
+        let points = routes.interpolate(&route_idx, &measures).unwrap();
+        assert_eq!(points.get_as_geo(0), Some(point!(x: 10., y: 5.)));
+        assert!(points.get_as_geo(1).is_none());
+        assert!(points.get_as_geo(3).is_none());
+
+        // Out of bounds route index
+        let points: PointArray = vec![point!(x: 0., y: 0.)].as_slice().into();
+        assert!(routes.locate(&UInt32Array::from(vec![2]), &points).is_err());
+    }
+
+    #[test]
+    fn vertex_measures() {
+        let mut builder = ListBuilder::new(Float64Builder::new());
+        builder.values().append_slice(&[100., 100., 200.]);
+        builder.append(true);
+        builder.append(false);
+        let routes = Routes::try_with_measures(&lines(), &builder.finish()).unwrap();
+
+        let route_idx = UInt32Array::from(vec![0, 0]);
+        // A range in reverse, and one extending beyond the route
+        let sections = routes
+            .substring(
+                &route_idx,
+                &Float64Array::from(vec![150., 50.]),
+                &Float64Array::from(vec![100., 120.]),
+            )
+            .unwrap();
+        assert_eq!(
+            sections.value_as_geo(0),
+            line_string![(x: 10., y: 0.), (x: 10., y: 5.)]
+        );
+        assert_eq!(
+            sections.value_as_geo(1),
+            line_string![(x: 10., y: 0.), (x: 10., y: 2.)]
+        );
+
+        // The first segment has a constant measure
+        let points = routes
+            .interpolate(&UInt32Array::from(vec![0]), &Float64Array::from(vec![100.]))
+            .unwrap();
+        assert_eq!(points.value_as_geo(0), point!(x: 10., y: 0.));
+
+        let mut decreasing = ListBuilder::new(Float64Builder::new());
+        decreasing.values().append_slice(&[2., 1., 3.]);
+        decreasing.append(true);
+        decreasing.append(false);
+        assert!(Routes::try_with_measures(&lines(), &decreasing.finish()).is_err());
+    }
+
+    #[test]
+    fn single_vertex_route() {
+        let lines: LineStringArray<i32> = vec![line_string![(x: 1., y: 2.)]].as_slice().into();
+        let routes = Routes::new(&lines);
+        let route_idx = UInt32Array::from(vec![0, 0]);
+
+        let points = routes
+            .interpolate(&route_idx, &Float64Array::from(vec![0., 1.]))
+            .unwrap();
+        assert_eq!(points.value_as_geo(0), point!(x: 1., y: 2.));
+        assert!(points.get_as_geo(1).is_none());
+
+        let points: PointArray = vec![point!(x: 5., y: 5.), point!(x: 1., y: 2.)]
+            .as_slice()
+            .into();
+        let measures = routes.locate(&route_idx, &points).unwrap();
+        assert_eq!(measures.value(0), 0.);
+    }
+}
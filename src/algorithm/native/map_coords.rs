@@ -0,0 +1,445 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::algorithm::native::simd;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+use crate::GeometryArrayTrait;
+
+/// Apply a function to every coordinate of an array, returning a new array of the same type.
+///
+/// For arrays with a coordinate buffer, the function is applied to the buffer directly and
+/// offsets and validity are reused as-is. This is the generic escape hatch for custom
+/// projections and transforms.
+pub trait MapCoords {
+    type Output;
+
+    /// Apply `map_op` to every `(x, y)` coordinate of this array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::MapCoords;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::line_string;
+    ///
+    /// let array: LineStringArray<i32> = vec![line_string![(x: 1., y: 2.), (x: 3., y: 4.)]]
+    ///     .as_slice()
+    ///     .into();
+    /// let swapped = array.map_coords(|x, y| (y, x));
+    /// assert_eq!(swapped.value_as_geo(0), line_string![(x: 2., y: 1.), (x: 4., y: 3.)]);
+    /// ```
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync;
+
+    /// Apply a fallible `map_op` to every `(x, y)` coordinate of this array.
+    ///
+    /// The first error returned by `map_op` is returned.
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync;
+}
+
+/// Apply a fallible `map_op` to every coordinate of the buffer, returning a new buffer with the
+/// same coordinate type.
+fn try_map_xy<F>(coords: &CoordBuffer, map_op: F) -> Result<CoordBuffer>
+where
+    F: Fn(f64, f64) -> Result<(f64, f64)>,
+{
+    match coords {
+        CoordBuffer::Interleaved(cb) => {
+            let mut values = Vec::with_capacity(cb.coords.len());
+            for xy in cb.coords.chunks_exact(2) {
+                let (x, y) = map_op(xy[0], xy[1])?;
+                values.push(x);
+                values.push(y);
+            }
+            Ok(CoordBuffer::Interleaved(InterleavedCoordBuffer::new(
+                values.into(),
+            )))
+        }
+        CoordBuffer::Separated(cb) => {
+            let mut x_values = Vec::with_capacity(cb.x.len());
+            let mut y_values = Vec::with_capacity(cb.y.len());
+            for (x, y) in cb.x.iter().zip(cb.y.iter()) {
+                let (x, y) = map_op(*x, *y)?;
+                x_values.push(x);
+                y_values.push(y);
+            }
+            Ok(CoordBuffer::Separated(SeparatedCoordBuffer::new(
+                x_values.into(),
+                y_values.into(),
+            )))
+        }
+    }
+}
+
+impl MapCoords for PointArray {
+    type Output = Self;
+
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        self.clone().with_coords(simd::map_xy(&self.coords, map_op))
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        Ok(self.clone().with_coords(try_map_xy(&self.coords, map_op)?))
+    }
+}
+
+/// Implementation for arrays with a single coordinate buffer
+macro_rules! coord_buffer_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> MapCoords for $type {
+            type Output = Self;
+
+            fn map_coords<F>(&self, map_op: F) -> Self::Output
+            where
+                F: Fn(f64, f64) -> (f64, f64) + Sync,
+            {
+                self.clone().with_coords(simd::map_xy(&self.coords, map_op))
+            }
+
+            fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+            where
+                F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+            {
+                Ok(self.clone().with_coords(try_map_xy(&self.coords, map_op)?))
+            }
+        }
+    };
+}
+
+coord_buffer_impl!(LineStringArray<O>);
+coord_buffer_impl!(PolygonArray<O>);
+coord_buffer_impl!(MultiPointArray<O>);
+coord_buffer_impl!(MultiLineStringArray<O>);
+coord_buffer_impl!(MultiPolygonArray<O>);
+
+impl<O: OffsetSizeTrait> MapCoords for MixedGeometryArray<O> {
+    type Output = Self;
+
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        let mut output = self.clone();
+        output.points = self.points.as_ref().map(|arr| arr.map_coords(&map_op));
+        output.line_strings = self
+            .line_strings
+            .as_ref()
+            .map(|arr| arr.map_coords(&map_op));
+        output.polygons = self.polygons.as_ref().map(|arr| arr.map_coords(&map_op));
+        output.multi_points = self
+            .multi_points
+            .as_ref()
+            .map(|arr| arr.map_coords(&map_op));
+        output.multi_line_strings = self
+            .multi_line_strings
+            .as_ref()
+            .map(|arr| arr.map_coords(&map_op));
+        output.multi_polygons = self
+            .multi_polygons
+            .as_ref()
+            .map(|arr| arr.map_coords(&map_op));
+        output
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        let mut output = self.clone();
+        output.points = self
+            .points
+            .as_ref()
+            .map(|arr| arr.try_map_coords(&map_op))
+            .transpose()?;
+        output.line_strings = self
+            .line_strings
+            .as_ref()
+            .map(|arr| arr.try_map_coords(&map_op))
+            .transpose()?;
+        output.polygons = self
+            .polygons
+            .as_ref()
+            .map(|arr| arr.try_map_coords(&map_op))
+            .transpose()?;
+        output.multi_points = self
+            .multi_points
+            .as_ref()
+            .map(|arr| arr.try_map_coords(&map_op))
+            .transpose()?;
+        output.multi_line_strings = self
+            .multi_line_strings
+            .as_ref()
+            .map(|arr| arr.try_map_coords(&map_op))
+            .transpose()?;
+        output.multi_polygons = self
+            .multi_polygons
+            .as_ref()
+            .map(|arr| arr.try_map_coords(&map_op))
+            .transpose()?;
+        Ok(output)
+    }
+}
+
+impl<O: OffsetSizeTrait> MapCoords for GeometryCollectionArray<O> {
+    type Output = Self;
+
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        let mut output = self.clone();
+        output.array = self.array.map_coords(map_op);
+        output
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        let mut output = self.clone();
+        output.array = self.array.try_map_coords(map_op)?;
+        Ok(output)
+    }
+}
+
+impl MapCoords for RectArray {
+    type Output = Self;
+
+    // All four corners are transformed, and the result is their bounding rect, so that rotations
+    // and other transforms that don't preserve the axes still cover the transformed rect
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        self.try_map_coords(|x, y| Ok(map_op(x, y))).unwrap()
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        let mut values = Vec::with_capacity(self.values.len());
+        for rect in self.values.chunks_exact(4) {
+            let corners = [
+                map_op(rect[0], rect[1])?,
+                map_op(rect[2], rect[1])?,
+                map_op(rect[2], rect[3])?,
+                map_op(rect[0], rect[3])?,
+            ];
+            let (mut minx, mut miny) = corners[0];
+            let (mut maxx, mut maxy) = corners[0];
+            for (x, y) in &corners[1..] {
+                minx = minx.min(*x);
+                miny = miny.min(*y);
+                maxx = maxx.max(*x);
+                maxy = maxy.max(*y);
+            }
+            values.extend_from_slice(&[minx, miny, maxx, maxy]);
+        }
+        Ok(RectArray::new(
+            values.into(),
+            self.nulls().cloned(),
+            self.metadata(),
+        ))
+    }
+}
+
+impl<O: OffsetSizeTrait> MapCoords for WKBArray<O> {
+    type Output = Self;
+
+    // WKB has no coordinate buffer, so each geometry is parsed, mapped and encoded again
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        self.try_map_coords(|x, y| Ok(map_op(x, y))).unwrap()
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        let geoms = self
+            .iter_geo()
+            .map(|maybe_geom| {
+                maybe_geom
+                    .map(|geom| {
+                        geo::MapCoords::try_map_coords(&geom, |coord| {
+                            let (x, y) = map_op(coord.x, coord.y)?;
+                            Ok(geo::coord! { x: x, y: y })
+                        })
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = WKBBuilder::<O>::with_capacity_and_options_from_iter(
+            geoms.iter().map(|geom| geom.as_ref()),
+            self.metadata(),
+        );
+        builder.extend_from_iter(geoms.iter().map(|geom| geom.as_ref()));
+        Ok(builder.finish())
+    }
+}
+
+impl MapCoords for &dyn GeometryArrayTrait {
+    type Output = Arc<dyn GeometryArrayTrait>;
+
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        self.try_map_coords(|x, y| Ok(map_op(x, y))).unwrap()
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().try_map_coords(map_op)?),
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().try_map_coords(map_op)?),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().try_map_coords(map_op)?)
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().try_map_coords(map_op)?),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().try_map_coords(map_op)?)
+            }
+            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().try_map_coords(map_op)?),
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().try_map_coords(map_op)?)
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().try_map_coords(map_op)?)
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().try_map_coords(map_op)?)
+            }
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().try_map_coords(map_op)?)
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().try_map_coords(map_op)?)
+            }
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().try_map_coords(map_op)?),
+            GeoDataType::LargeMixed(_) => Arc::new(self.as_large_mixed().try_map_coords(map_op)?),
+            GeoDataType::GeometryCollection(_) => {
+                Arc::new(self.as_geometry_collection().try_map_coords(map_op)?)
+            }
+            GeoDataType::LargeGeometryCollection(_) => {
+                Arc::new(self.as_large_geometry_collection().try_map_coords(map_op)?)
+            }
+            GeoDataType::WKB => Arc::new(self.as_wkb().try_map_coords(map_op)?),
+            GeoDataType::LargeWKB => Arc::new(self.as_large_wkb().try_map_coords(map_op)?),
+            GeoDataType::Rect => Arc::new(self.as_rect().try_map_coords(map_op)?),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait + MapCoords<Output = G>> MapCoords for ChunkedGeometryArray<G> {
+    type Output = Self;
+
+    fn map_coords<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(f64, f64) -> (f64, f64) + Sync,
+    {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.map_coords(&map_op)))
+    }
+
+    fn try_map_coords<F>(&self, map_op: F) -> Result<Self::Output>
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64)> + Sync,
+    {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::GeoArrowError;
+    use crate::test::{multipolygon, point};
+    use geo::{point, MapCoords as _MapCoords};
+
+    #[test]
+    fn map_coords_matches_geo() {
+        let array = multipolygon::mp_array();
+        let expected = array
+            .iter_geo_values()
+            .map(|geom| geom.map_coords(|c| geo::coord! { x: c.x * 2., y: c.y + 1. }))
+            .collect::<Vec<_>>();
+
+        for array in [
+            array.clone(),
+            array.clone().into_coord_type(CoordType::Separated),
+        ] {
+            let mapped = array.map_coords(|x, y| (x * 2., y + 1.));
+            assert_eq!(mapped.iter_geo_values().collect::<Vec<_>>(), expected);
+        }
+
+        let wkb_array = crate::io::wkb::to_wkb::<i32>(&array);
+        let mapped = wkb_array.map_coords(|x, y| (x * 2., y + 1.));
+        assert_eq!(
+            mapped
+                .iter_geo_values()
+                .map(|geom| geom.try_into().unwrap())
+                .collect::<Vec<geo::MultiPolygon>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn try_map_coords_returns_first_error() {
+        let array = point::point_array();
+        let result = array.try_map_coords(|x, y| {
+            if x > 1. {
+                Err(GeoArrowError::General("x out of range".to_string()))
+            } else {
+                Ok((x, y))
+            }
+        });
+        assert!(result.is_err());
+
+        let mapped = array.try_map_coords(|x, y| Ok((x, -y))).unwrap();
+        assert_eq!(mapped.value_as_geo(0), point!(x: 0., y: -1.));
+    }
+
+    #[test]
+    fn map_coords_rect_keeps_ordering() {
+        let array: RectArray = vec![geo::Rect::new((0., 0.), (1., 2.))].as_slice().into();
+        let mapped = array.map_coords(|x, y| (-x, -y));
+        assert_eq!(mapped.value_as_geo(0), geo::Rect::new((-1., -2.), (0., 0.)));
+    }
+
+    #[test]
+    fn map_coords_rect_rotated() {
+        // Rotate by 45 degrees around the origin
+        let array: RectArray = vec![geo::Rect::new((0., 0.), (1., 1.))].as_slice().into();
+        let (sin, cos) = std::f64::consts::FRAC_PI_4.sin_cos();
+        let mapped = array.map_coords(|x, y| (x * cos - y * sin, x * sin + y * cos));
+        let rect = mapped.value_as_geo(0);
+        assert!((rect.min().x + sin).abs() < 1e-12);
+        assert!(rect.min().y.abs() < 1e-12);
+        assert!((rect.max().x - sin).abs() < 1e-12);
+        assert!((rect.max().y - 2. * sin).abs() < 1e-12);
+    }
+}
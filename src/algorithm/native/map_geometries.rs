@@ -0,0 +1,491 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Apply a function to each geometry of an array, collecting the results into a new array.
+///
+/// Each non-null geometry is passed to the function as a [`geo::Geometry`]; null geometries stay
+/// null. The type of the output array is inferred from the returned geometries:
+///
+/// - If all geometries have the same type, an array of that type is returned.
+/// - If single and multi geometries of the same dimension are mixed, e.g. polygons and
+///   multi-polygons, the multi type is used.
+/// - If any geometry collection is returned, a [`GeometryCollectionArray`] is returned.
+/// - Otherwise, including when every output geometry is null, a [`MixedGeometryArray`] is
+///   returned.
+///
+/// `Line`s are converted to line strings, and `Rect`s and `Triangle`s to polygons.
+pub trait MapGeometries {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::MapGeometries;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::GeoDataType;
+    /// use geo::{point, BoundingRect};
+    ///
+    /// let array: PointArray = vec![point!(x: 1., y: 2.)].as_slice().into();
+    /// let buffered = array
+    ///     .map_geometries(|geom| {
+    ///         let rect = geom.bounding_rect().unwrap();
+    ///         geo::Rect::new(rect.min() - (1., 1.).into(), rect.max() + (1., 1.).into()).into()
+    ///     })
+    ///     .unwrap();
+    /// assert!(matches!(buffered.data_type(), GeoDataType::Polygon(_)));
+    /// ```
+    fn map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> geo::Geometry;
+
+    /// Apply a fallible function to each geometry of an array.
+    ///
+    /// The first error returned by `map_op` is returned.
+    fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<geo::Geometry>;
+}
+
+/// Convert geometry types that have no GeoArrow equivalent.
+fn normalize(geom: geo::Geometry) -> geo::Geometry {
+    match geom {
+        geo::Geometry::Line(line) => geo::Geometry::LineString(line.into()),
+        geo::Geometry::Rect(rect) => geo::Geometry::Polygon(rect.to_polygon()),
+        geo::Geometry::Triangle(triangle) => geo::Geometry::Polygon(triangle.to_polygon()),
+        geo::Geometry::GeometryCollection(gc) => {
+            geo::Geometry::GeometryCollection(gc.into_iter().map(normalize).collect())
+        }
+        geom => geom,
+    }
+}
+
+/// Apply `map_op` to each non-null geometry.
+fn map_geo_geometries<G: Into<geo::Geometry>>(
+    geoms: impl Iterator<Item = Option<G>>,
+    map_op: impl Fn(geo::Geometry) -> Result<geo::Geometry>,
+) -> Result<Vec<Option<geo::Geometry>>> {
+    geoms
+        .map(|maybe_geom| {
+            maybe_geom
+                .map(|geom| map_op(geom.into()).map(normalize))
+                .transpose()
+        })
+        .collect()
+}
+
+/// Infer the smallest array type that can hold all of the given (normalized) geometries.
+fn infer_data_type<'a>(
+    geoms: impl Iterator<Item = &'a geo::Geometry>,
+    coord_type: CoordType,
+    large: bool,
+) -> GeoDataType {
+    let (mut points, mut line_strings, mut polygons) = (false, false, false);
+    let (mut multi_points, mut multi_line_strings, mut multi_polygons) = (false, false, false);
+    let mut geometry_collections = false;
+    for geom in geoms {
+        match geom {
+            geo::Geometry::Point(_) => points = true,
+            geo::Geometry::LineString(_) => line_strings = true,
+            geo::Geometry::Polygon(_) => polygons = true,
+            geo::Geometry::MultiPoint(_) => multi_points = true,
+            geo::Geometry::MultiLineString(_) => multi_line_strings = true,
+            geo::Geometry::MultiPolygon(_) => multi_polygons = true,
+            _ => geometry_collections = true,
+        }
+    }
+
+    let dimensions = [
+        points || multi_points,
+        line_strings || multi_line_strings,
+        polygons || multi_polygons,
+    ];
+    let data_type = match (geometry_collections, dimensions) {
+        (true, _) => GeoDataType::GeometryCollection(coord_type),
+        (false, [true, false, false]) if !multi_points => GeoDataType::Point(coord_type),
+        (false, [true, false, false]) => GeoDataType::MultiPoint(coord_type),
+        (false, [false, true, false]) if !multi_line_strings => GeoDataType::LineString(coord_type),
+        (false, [false, true, false]) => GeoDataType::MultiLineString(coord_type),
+        (false, [false, false, true]) if !multi_polygons => GeoDataType::Polygon(coord_type),
+        (false, [false, false, true]) => GeoDataType::MultiPolygon(coord_type),
+        _ => GeoDataType::Mixed(coord_type),
+    };
+
+    match (large, data_type) {
+        (true, GeoDataType::LineString(ct)) => GeoDataType::LargeLineString(ct),
+        (true, GeoDataType::Polygon(ct)) => GeoDataType::LargePolygon(ct),
+        (true, GeoDataType::MultiPoint(ct)) => GeoDataType::LargeMultiPoint(ct),
+        (true, GeoDataType::MultiLineString(ct)) => GeoDataType::LargeMultiLineString(ct),
+        (true, GeoDataType::MultiPolygon(ct)) => GeoDataType::LargeMultiPolygon(ct),
+        (true, GeoDataType::Mixed(ct)) => GeoDataType::LargeMixed(ct),
+        (true, GeoDataType::GeometryCollection(ct)) => GeoDataType::LargeGeometryCollection(ct),
+        (_, data_type) => data_type,
+    }
+}
+
+/// Build an array of type `data_type` from the given geometries.
+fn build_array(
+    geoms: &[Option<geo::Geometry>],
+    data_type: GeoDataType,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    macro_rules! push_geometries {
+        ($builder:ty, $coord_type:expr) => {{
+            let mut builder = <$builder>::new_with_options($coord_type, metadata);
+            geoms
+                .iter()
+                .try_for_each(|geom| builder.push_geometry(geom.as_ref()))?;
+            Arc::new(builder.finish())
+        }};
+    }
+
+    let result: Arc<dyn GeometryArrayTrait> = match data_type {
+        GeoDataType::Point(ct) => push_geometries!(PointBuilder, ct),
+        GeoDataType::LineString(ct) => push_geometries!(LineStringBuilder<i32>, ct),
+        GeoDataType::LargeLineString(ct) => push_geometries!(LineStringBuilder<i64>, ct),
+        GeoDataType::Polygon(ct) => push_geometries!(PolygonBuilder<i32>, ct),
+        GeoDataType::LargePolygon(ct) => push_geometries!(PolygonBuilder<i64>, ct),
+        GeoDataType::MultiPoint(ct) => push_geometries!(MultiPointBuilder<i32>, ct),
+        GeoDataType::LargeMultiPoint(ct) => push_geometries!(MultiPointBuilder<i64>, ct),
+        GeoDataType::MultiLineString(ct) => push_geometries!(MultiLineStringBuilder<i32>, ct),
+        GeoDataType::LargeMultiLineString(ct) => {
+            push_geometries!(MultiLineStringBuilder<i64>, ct)
+        }
+        GeoDataType::MultiPolygon(ct) => push_geometries!(MultiPolygonBuilder<i32>, ct),
+        GeoDataType::LargeMultiPolygon(ct) => push_geometries!(MultiPolygonBuilder<i64>, ct),
+        GeoDataType::Mixed(ct) => Arc::new(
+            MixedGeometryBuilder::<i32>::from_nullable_geometries(
+                geoms,
+                Some(ct),
+                metadata,
+                false,
+            )?
+            .finish(),
+        ),
+        GeoDataType::LargeMixed(ct) => Arc::new(
+            MixedGeometryBuilder::<i64>::from_nullable_geometries(
+                geoms,
+                Some(ct),
+                metadata,
+                false,
+            )?
+            .finish(),
+        ),
+        GeoDataType::GeometryCollection(ct) => Arc::new(
+            GeometryCollectionBuilder::<i32>::from_nullable_geometries(
+                geoms,
+                Some(ct),
+                metadata,
+                false,
+            )?
+            .finish(),
+        ),
+        GeoDataType::LargeGeometryCollection(ct) => Arc::new(
+            GeometryCollectionBuilder::<i64>::from_nullable_geometries(
+                geoms,
+                Some(ct),
+                metadata,
+                false,
+            )?
+            .finish(),
+        ),
+        dt => {
+            return Err(GeoArrowError::IncorrectType(
+                format!("Unexpected output type {dt:?}").into(),
+            ))
+        }
+    };
+    Ok(result)
+}
+
+impl MapGeometries for PointArray {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> geo::Geometry,
+    {
+        self.try_map_geometries(|geom| Ok(map_op(geom)))
+    }
+
+    fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<geo::Geometry>,
+    {
+        let geoms = map_geo_geometries(self.iter(), map_op)?;
+        let data_type = infer_data_type(geoms.iter().flatten(), self.coord_type(), false);
+        build_array(&geoms, data_type, self.metadata())
+    }
+}
+
+impl MapGeometries for RectArray {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> geo::Geometry,
+    {
+        self.try_map_geometries(|geom| Ok(map_op(geom)))
+    }
+
+    fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<geo::Geometry>,
+    {
+        let geoms = map_geo_geometries(self.iter(), map_op)?;
+        let data_type = infer_data_type(geoms.iter().flatten(), Default::default(), false);
+        build_array(&geoms, data_type, self.metadata())
+    }
+}
+
+/// Implementation that iterates over geometry scalars
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> MapGeometries for $type {
+            type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+            fn map_geometries<F>(&self, map_op: F) -> Self::Output
+            where
+                F: Fn(geo::Geometry) -> geo::Geometry,
+            {
+                self.try_map_geometries(|geom| Ok(map_op(geom)))
+            }
+
+            fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+            where
+                F: Fn(geo::Geometry) -> Result<geo::Geometry>,
+            {
+                let geoms = map_geo_geometries(self.iter(), map_op)?;
+                let data_type =
+                    infer_data_type(geoms.iter().flatten(), self.coord_type(), O::IS_LARGE);
+                build_array(&geoms, data_type, self.metadata())
+            }
+        }
+    };
+}
+
+iter_geo_impl!(LineStringArray<O>);
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiPointArray<O>);
+iter_geo_impl!(MultiLineStringArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+impl MapGeometries for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> geo::Geometry,
+    {
+        self.try_map_geometries(|geom| Ok(map_op(geom)))
+    }
+
+    fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<geo::Geometry>,
+    {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().try_map_geometries(map_op),
+            GeoDataType::LineString(_) => self.as_line_string().try_map_geometries(map_op),
+            GeoDataType::LargeLineString(_) => {
+                self.as_large_line_string().try_map_geometries(map_op)
+            }
+            GeoDataType::Polygon(_) => self.as_polygon().try_map_geometries(map_op),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().try_map_geometries(map_op),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().try_map_geometries(map_op),
+            GeoDataType::LargeMultiPoint(_) => {
+                self.as_large_multi_point().try_map_geometries(map_op)
+            }
+            GeoDataType::MultiLineString(_) => {
+                self.as_multi_line_string().try_map_geometries(map_op)
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().try_map_geometries(map_op)
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().try_map_geometries(map_op),
+            GeoDataType::LargeMultiPolygon(_) => {
+                self.as_large_multi_polygon().try_map_geometries(map_op)
+            }
+            GeoDataType::Mixed(_) => self.as_mixed().try_map_geometries(map_op),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().try_map_geometries(map_op),
+            GeoDataType::GeometryCollection(_) => {
+                self.as_geometry_collection().try_map_geometries(map_op)
+            }
+            GeoDataType::LargeGeometryCollection(_) => self
+                .as_large_geometry_collection()
+                .try_map_geometries(map_op),
+            GeoDataType::WKB => self.as_wkb().try_map_geometries(map_op),
+            GeoDataType::LargeWKB => self.as_large_wkb().try_map_geometries(map_op),
+            GeoDataType::Rect => self.as_rect().try_map_geometries(map_op),
+        }
+    }
+}
+
+/// Build a chunked array from the mapped geometries of each chunk, inferring a single output
+/// type for all chunks.
+fn build_chunked_array(
+    chunks: Vec<Vec<Option<geo::Geometry>>>,
+    coord_type: CoordType,
+    large: bool,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let data_type = infer_data_type(chunks.iter().flatten().flatten(), coord_type, large);
+    let arrays = chunks
+        .iter()
+        .map(|geoms| build_array(geoms, data_type, metadata.clone()))
+        .collect::<Result<Vec<_>>>()?;
+    let array_refs = arrays.iter().map(|arr| arr.as_ref()).collect::<Vec<_>>();
+    from_geoarrow_chunks(&array_refs)
+}
+
+impl MapGeometries for ChunkedPointArray {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> geo::Geometry,
+    {
+        self.try_map_geometries(|geom| Ok(map_op(geom)))
+    }
+
+    fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<geo::Geometry>,
+    {
+        let chunks = self
+            .chunks()
+            .iter()
+            .map(|chunk| map_geo_geometries(chunk.iter(), &map_op))
+            .collect::<Result<Vec<_>>>()?;
+        let (coord_type, metadata) = match self.chunks().first() {
+            Some(chunk) => (chunk.coord_type(), chunk.metadata()),
+            None => Default::default(),
+        };
+        build_chunked_array(chunks, coord_type, false, metadata)
+    }
+}
+
+/// Implementation that iterates over the geometry scalars of each chunk
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> MapGeometries for $type {
+            type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+            fn map_geometries<F>(&self, map_op: F) -> Self::Output
+            where
+                F: Fn(geo::Geometry) -> geo::Geometry,
+            {
+                self.try_map_geometries(|geom| Ok(map_op(geom)))
+            }
+
+            fn try_map_geometries<F>(&self, map_op: F) -> Self::Output
+            where
+                F: Fn(geo::Geometry) -> Result<geo::Geometry>,
+            {
+                let chunks = self
+                    .chunks()
+                    .iter()
+                    .map(|chunk| map_geo_geometries(chunk.iter(), &map_op))
+                    .collect::<Result<Vec<_>>>()?;
+                let (coord_type, metadata) = match self.chunks().first() {
+                    Some(chunk) => (chunk.coord_type(), chunk.metadata()),
+                    None => Default::default(),
+                };
+                build_chunked_array(chunks, coord_type, O::IS_LARGE, metadata)
+            }
+        }
+    };
+}
+
+chunked_impl!(ChunkedLineStringArray<O>);
+chunked_impl!(ChunkedPolygonArray<O>);
+chunked_impl!(ChunkedMultiPointArray<O>);
+chunked_impl!(ChunkedMultiLineStringArray<O>);
+chunked_impl!(ChunkedMultiPolygonArray<O>);
+chunked_impl!(ChunkedMixedGeometryArray<O>);
+chunked_impl!(ChunkedGeometryCollectionArray<O>);
+chunked_impl!(ChunkedWKBArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipolygon, point, polygon};
+    use geo::{point, Centroid};
+
+    #[test]
+    fn infers_single_type() {
+        let array = polygon::p_array();
+        let centroids = array
+            .map_geometries(|geom| geom.centroid().unwrap().into())
+            .unwrap();
+        assert!(matches!(centroids.data_type(), GeoDataType::Point(_)));
+
+        let expected = array
+            .iter_geo_values()
+            .map(|geom| geom.centroid().unwrap())
+            .collect::<Vec<_>>();
+        let centroids = centroids.as_any().downcast_ref::<PointArray>().unwrap();
+        assert_eq!(centroids.iter_geo_values().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn infers_multi_and_mixed_types() {
+        let array: PolygonArray<i64> = vec![polygon::p0(), polygon::p1()].as_slice().into();
+        let multi = array
+            .map_geometries(|geom| match geom {
+                geo::Geometry::Polygon(p) if p == polygon::p0() => multipolygon::mp0().into(),
+                geom => geom,
+            })
+            .unwrap();
+        assert!(matches!(
+            multi.data_type(),
+            GeoDataType::LargeMultiPolygon(_)
+        ));
+
+        let mixed = point::point_array()
+            .map_geometries(|geom| match geom {
+                geo::Geometry::Point(p) if p.x() > 0. => p.into(),
+                _ => polygon::p0().into(),
+            })
+            .unwrap();
+        assert!(matches!(mixed.data_type(), GeoDataType::Mixed(_)));
+        assert_eq!(mixed.len(), 3);
+    }
+
+    #[test]
+    fn keeps_nulls_and_propagates_errors() {
+        let array: PointArray = vec![Some(point!(x: 1., y: 2.)), None].into();
+        let mapped = array.map_geometries(|geom| geom).unwrap();
+        assert!(mapped.is_null(1));
+
+        let result = array.try_map_geometries(|_| Err(GeoArrowError::General("fail".into())));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn chunked_infers_common_type() {
+        let chunked = ChunkedGeometryArray::new(vec![
+            polygon::p_array(),
+            vec![polygon::p0()].as_slice().into(),
+        ]);
+        let mapped = chunked
+            .map_geometries(|geom| match geom {
+                geo::Geometry::Polygon(p) if p == polygon::p1() => multipolygon::mp0().into(),
+                geom => geom,
+            })
+            .unwrap();
+        assert!(matches!(mapped.data_type(), GeoDataType::MultiPolygon(_)));
+        assert_eq!(mapped.num_chunks(), 2);
+    }
+}
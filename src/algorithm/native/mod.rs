@@ -3,23 +3,87 @@
 //! Where possible, operations on scalars are implemented in terms of [geometry
 //! traits](../../geo_traits).
 
+mod approx_eq;
+mod bbox_intersects;
 mod binary;
 pub mod bounding_rect;
 mod cast;
+mod clip_to_tile;
 mod concatenate;
+mod coord_iter;
+mod coord_range;
+mod coords_to_points;
 mod downcast;
+mod drop_nulls;
 pub(crate) mod eq;
 mod explode;
-mod rechunk;
+mod fill_null;
+mod geohash;
+mod interior_rings;
+mod line_merge;
+mod linear_referencing;
+mod map_coords;
+mod map_geometries;
+mod normalize_longitude;
+mod num_coords;
+mod num_parts;
+mod offset_curve;
+mod polygonize;
+pub(crate) mod rechunk;
+mod remove_holes;
+mod rescale;
+mod reverse;
+pub(crate) mod segments;
+mod shared_paths;
+pub(crate) mod simd;
+mod space_filling_curve;
+mod split_lines;
 mod take;
+mod topology_graph;
+mod total_bounds;
 pub(crate) mod type_id;
 mod unary;
+pub(crate) mod unique;
+pub(crate) mod weighted_centroid;
+mod xy;
+mod xyz_tiles;
 
+pub use approx_eq::ApproxEq;
+pub use bbox_intersects::BBoxIntersects;
 pub use binary::Binary;
 pub use cast::Cast;
+pub use clip_to_tile::{ClipToTile, TileClip};
 pub use concatenate::Concatenate;
+pub use coord_iter::CoordIter;
+pub use coords_to_points::CoordsToPoints;
 pub use downcast::Downcast;
+pub use drop_nulls::DropNulls;
 pub use explode::Explode;
+pub use fill_null::{FillNull, FillValue};
+pub use geohash::{geohash_to_point, geohash_to_rect, Geohash};
+pub use interior_rings::InteriorRings;
+pub use line_merge::LineMerge;
+pub use linear_referencing::Routes;
+pub use map_coords::MapCoords;
+pub use map_geometries::MapGeometries;
+pub use normalize_longitude::{LongitudeRange, NormalizeLongitude, NormalizeLongitudeMode};
+pub use num_coords::NumCoords;
+pub use num_parts::NumParts;
+pub use offset_curve::{JoinStyle, OffsetCurve, OffsetSide};
+pub use polygonize::{Polygonize, Polygonized};
 pub use rechunk::Rechunk;
+pub use remove_holes::RemoveHoles;
+pub use rescale::Rescale;
+pub use reverse::Reverse;
+pub use segments::Segments;
+pub use shared_paths::{AdjacentSharedPaths, SharedPathPairs, SharedPaths};
+pub use space_filling_curve::SpaceFillingCurve;
+pub use split_lines::split_lines;
 pub use take::Take;
+pub use topology_graph::{Topology, TopologyGraph};
+pub use total_bounds::TotalBounds;
 pub use unary::Unary;
+pub use unique::Unique;
+pub use weighted_centroid::WeightedCentroid;
+pub use xy::XY;
+pub use xyz_tiles::{Tile, XyzTiles, MAX_TILE_ZOOM};
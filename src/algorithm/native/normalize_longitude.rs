@@ -0,0 +1,565 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::algorithm::native::simd;
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArraySelfMethods;
+use crate::GeometryArrayTrait;
+
+/// The interval that longitudes are normalized into.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LongitudeRange {
+    /// Longitudes in `[-180, 180)`
+    #[default]
+    Signed,
+
+    /// Longitudes in `[0, 360)`
+    Unsigned,
+}
+
+impl LongitudeRange {
+    /// The inclusive lower bound of this range.
+    fn min(&self) -> f64 {
+        match self {
+            LongitudeRange::Signed => -180.,
+            LongitudeRange::Unsigned => 0.,
+        }
+    }
+
+    /// Wrap a single longitude value into this range.
+    fn wrap(&self, x: f64) -> f64 {
+        x + self.shift(x)
+    }
+
+    /// The multiple of 360 that needs to be added to `x` to move it into this range.
+    fn shift(&self, x: f64) -> f64 {
+        -360. * ((x - self.min()) / 360.).floor()
+    }
+}
+
+/// How coordinates of a single geometry are handled when normalizing longitudes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NormalizeLongitudeMode {
+    /// Wrap every coordinate into the target range independently.
+    ///
+    /// This is the fastest option, but geometries that cross the cut of the target range will be
+    /// torn apart, e.g. a line from 170° to 190° becomes a line from 170° to -170°.
+    #[default]
+    PerCoordinate,
+
+    /// Shift each geometry as a whole by a multiple of 360° so that its westernmost coordinate
+    /// lies within the target range.
+    ///
+    /// Geometries crossing the cut are kept contiguous, which means that some of their
+    /// coordinates may lie outside of the target range.
+    PerGeometry,
+}
+
+/// Normalize longitude (x) values into a consistent range.
+///
+/// This is useful for datasets that mix the `[-180, 180)` and `[0, 360)` longitude conventions.
+/// Only the coordinate buffer is rewritten; offsets and validity are reused as-is.
+pub trait NormalizeLongitude {
+    type Output;
+
+    /// Normalize all longitudes of this array into `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{LongitudeRange, NormalizeLongitude, NormalizeLongitudeMode};
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 190., y: 10.)].as_slice().into();
+    /// let normalized =
+    ///     array.normalize_longitude(LongitudeRange::Signed, NormalizeLongitudeMode::PerCoordinate);
+    /// assert_eq!(normalized.value_as_geo(0), point!(x: -170., y: 10.));
+    /// ```
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output;
+}
+
+/// Wrap every x value of the coordinate buffer independently.
+fn wrap_coords(coords: &CoordBuffer, range: LongitudeRange) -> CoordBuffer {
+    simd::map_xy(coords, |x, y| (range.wrap(x), y))
+}
+
+/// Replace every x value of the coordinate buffer with the output of `op`, given the coordinate
+/// index and its current x value.
+fn map_x(coords: &CoordBuffer, op: impl Fn(usize, f64) -> f64) -> CoordBuffer {
+    match coords {
+        CoordBuffer::Interleaved(cb) => {
+            let mut values = cb.coords.to_vec();
+            values.chunks_exact_mut(2).enumerate().for_each(|(i, xy)| {
+                xy[0] = op(i, xy[0]);
+            });
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into()))
+        }
+        CoordBuffer::Separated(cb) => {
+            let x =
+                cb.x.iter()
+                    .enumerate()
+                    .map(|(i, x)| op(i, *x))
+                    .collect::<Vec<_>>();
+            CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), cb.y.clone()))
+        }
+    }
+}
+
+/// Shift the coordinates of each geometry as a unit, given the range of coordinate indices that
+/// make up each geometry.
+fn shift_geometries(
+    coords: &CoordBuffer,
+    range: LongitudeRange,
+    geom_coord_ranges: impl Iterator<Item = (usize, usize)>,
+) -> CoordBuffer {
+    let mut shifts = vec![0.; coords.len()];
+    for (start, end) in geom_coord_ranges {
+        let min_x = (start..end)
+            .map(|coord_idx| coords.get_x(coord_idx))
+            .fold(f64::INFINITY, f64::min);
+        if min_x.is_finite() {
+            shifts[start..end].fill(range.shift(min_x));
+        }
+    }
+
+    map_x(coords, |coord_idx, x| x + shifts[coord_idx])
+}
+
+impl NormalizeLongitude for PointArray {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        _mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        // Every point is a single coordinate, so both modes are equivalent
+        self.clone().with_coords(wrap_coords(&self.coords, range))
+    }
+}
+
+impl<O: OffsetSizeTrait> NormalizeLongitude for LineStringArray<O> {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        let coords = match mode {
+            NormalizeLongitudeMode::PerCoordinate => wrap_coords(&self.coords, range),
+            NormalizeLongitudeMode::PerGeometry => shift_geometries(
+                &self.coords,
+                range,
+                (0..self.len()).map(|i| self.geom_offsets.start_end(i)),
+            ),
+        };
+        self.clone().with_coords(coords)
+    }
+}
+
+impl<O: OffsetSizeTrait> NormalizeLongitude for MultiPointArray<O> {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        let coords = match mode {
+            NormalizeLongitudeMode::PerCoordinate => wrap_coords(&self.coords, range),
+            NormalizeLongitudeMode::PerGeometry => shift_geometries(
+                &self.coords,
+                range,
+                (0..self.len()).map(|i| self.geom_offsets.start_end(i)),
+            ),
+        };
+        self.clone().with_coords(coords)
+    }
+}
+
+/// Implementation for arrays with two levels of offsets
+macro_rules! two_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> NormalizeLongitude for $type {
+            type Output = Self;
+
+            fn normalize_longitude(
+                &self,
+                range: LongitudeRange,
+                mode: NormalizeLongitudeMode,
+            ) -> Self::Output {
+                let coords = match mode {
+                    NormalizeLongitudeMode::PerCoordinate => wrap_coords(&self.coords, range),
+                    NormalizeLongitudeMode::PerGeometry => shift_geometries(
+                        &self.coords,
+                        range,
+                        (0..self.len()).map(|i| {
+                            let (start_ring, end_ring) = self.geom_offsets.start_end(i);
+                            (
+                                self.ring_offsets[start_ring].to_usize().unwrap(),
+                                self.ring_offsets[end_ring].to_usize().unwrap(),
+                            )
+                        }),
+                    ),
+                };
+                self.clone().with_coords(coords)
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>);
+two_level_impl!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> NormalizeLongitude for MultiPolygonArray<O> {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        let coords = match mode {
+            NormalizeLongitudeMode::PerCoordinate => wrap_coords(&self.coords, range),
+            NormalizeLongitudeMode::PerGeometry => shift_geometries(
+                &self.coords,
+                range,
+                (0..self.len()).map(|i| {
+                    let (start_polygon, end_polygon) = self.geom_offsets.start_end(i);
+                    let start_ring = self.polygon_offsets[start_polygon].to_usize().unwrap();
+                    let end_ring = self.polygon_offsets[end_polygon].to_usize().unwrap();
+                    (
+                        self.ring_offsets[start_ring].to_usize().unwrap(),
+                        self.ring_offsets[end_ring].to_usize().unwrap(),
+                    )
+                }),
+            ),
+        };
+        self.clone().with_coords(coords)
+    }
+}
+
+impl<O: OffsetSizeTrait> NormalizeLongitude for MixedGeometryArray<O> {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        // Each geometry is stored in exactly one child array, so the child arrays can be
+        // normalized independently
+        let mut output = self.clone();
+        output.points = self
+            .points
+            .as_ref()
+            .map(|arr| arr.normalize_longitude(range, mode));
+        output.line_strings = self
+            .line_strings
+            .as_ref()
+            .map(|arr| arr.normalize_longitude(range, mode));
+        output.polygons = self
+            .polygons
+            .as_ref()
+            .map(|arr| arr.normalize_longitude(range, mode));
+        output.multi_points = self
+            .multi_points
+            .as_ref()
+            .map(|arr| arr.normalize_longitude(range, mode));
+        output.multi_line_strings = self
+            .multi_line_strings
+            .as_ref()
+            .map(|arr| arr.normalize_longitude(range, mode));
+        output.multi_polygons = self
+            .multi_polygons
+            .as_ref()
+            .map(|arr| arr.normalize_longitude(range, mode));
+        output
+    }
+}
+
+impl<O: OffsetSizeTrait> NormalizeLongitude for GeometryCollectionArray<O> {
+    type Output = Self;
+
+    /// Note that with [`NormalizeLongitudeMode::PerGeometry`], each geometry of a collection is
+    /// shifted on its own, rather than the collection as a whole.
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        let mut output = self.clone();
+        output.array = self.array.normalize_longitude(range, mode);
+        output
+    }
+}
+
+impl NormalizeLongitude for RectArray {
+    type Output = Self;
+
+    /// With [`NormalizeLongitudeMode::PerCoordinate`], the min and max x of a rect crossing the
+    /// cut of the target range are wrapped independently, so that its min x is larger than its
+    /// max x.
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        let mut values = self.values.to_vec();
+        values.chunks_exact_mut(4).for_each(|rect| match mode {
+            NormalizeLongitudeMode::PerCoordinate => {
+                rect[0] = range.wrap(rect[0]);
+                rect[2] = range.wrap(rect[2]);
+            }
+            NormalizeLongitudeMode::PerGeometry => {
+                let shift = range.shift(rect[0]);
+                rect[0] += shift;
+                rect[2] += shift;
+            }
+        });
+        RectArray::new(values.into(), self.nulls().cloned(), self.metadata())
+    }
+}
+
+impl NormalizeLongitude for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().normalize_longitude(range, mode)),
+            GeoDataType::LineString(_) => {
+                Arc::new(self.as_line_string().normalize_longitude(range, mode))
+            }
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().normalize_longitude(range, mode))
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().normalize_longitude(range, mode)),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().normalize_longitude(range, mode))
+            }
+            GeoDataType::MultiPoint(_) => {
+                Arc::new(self.as_multi_point().normalize_longitude(range, mode))
+            }
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().normalize_longitude(range, mode))
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().normalize_longitude(range, mode))
+            }
+            GeoDataType::LargeMultiLineString(_) => Arc::new(
+                self.as_large_multi_line_string()
+                    .normalize_longitude(range, mode),
+            ),
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().normalize_longitude(range, mode))
+            }
+            GeoDataType::LargeMultiPolygon(_) => Arc::new(
+                self.as_large_multi_polygon()
+                    .normalize_longitude(range, mode),
+            ),
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().normalize_longitude(range, mode)),
+            GeoDataType::LargeMixed(_) => {
+                Arc::new(self.as_large_mixed().normalize_longitude(range, mode))
+            }
+            GeoDataType::GeometryCollection(_) => Arc::new(
+                self.as_geometry_collection()
+                    .normalize_longitude(range, mode),
+            ),
+            GeoDataType::LargeGeometryCollection(_) => Arc::new(
+                self.as_large_geometry_collection()
+                    .normalize_longitude(range, mode),
+            ),
+            GeoDataType::Rect => Arc::new(self.as_rect().normalize_longitude(range, mode)),
+            GeoDataType::WKB | GeoDataType::LargeWKB => {
+                return Err(GeoArrowError::IncorrectType(
+                    "normalize_longitude is not supported for WKB arrays; parse them first".into(),
+                ))
+            }
+        };
+        Ok(result)
+    }
+}
+
+impl NormalizeLongitude for ChunkedPointArray {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.normalize_longitude(range, mode)))
+    }
+}
+
+/// Implementation that iterates over chunks
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> NormalizeLongitude for $type {
+            type Output = Self;
+
+            fn normalize_longitude(
+                &self,
+                range: LongitudeRange,
+                mode: NormalizeLongitudeMode,
+            ) -> Self::Output {
+                ChunkedGeometryArray::new(self.map(|chunk| chunk.normalize_longitude(range, mode)))
+            }
+        }
+    };
+}
+
+chunked_impl!(ChunkedLineStringArray<O>);
+chunked_impl!(ChunkedPolygonArray<O>);
+chunked_impl!(ChunkedMultiPointArray<O>);
+chunked_impl!(ChunkedMultiLineStringArray<O>);
+chunked_impl!(ChunkedMultiPolygonArray<O>);
+chunked_impl!(ChunkedMixedGeometryArray<O>);
+chunked_impl!(ChunkedGeometryCollectionArray<O>);
+
+impl NormalizeLongitude for ChunkedRectArray {
+    type Output = Self;
+
+    fn normalize_longitude(
+        &self,
+        range: LongitudeRange,
+        mode: NormalizeLongitudeMode,
+    ) -> Self::Output {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.normalize_longitude(range, mode)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArrayAccessor;
+    use geo::{line_string, point};
+
+    #[test]
+    fn wrap_values() {
+        assert_eq!(LongitudeRange::Signed.wrap(180.), -180.);
+        assert_eq!(LongitudeRange::Signed.wrap(-190.), 170.);
+        assert_eq!(LongitudeRange::Signed.wrap(540.), -180.);
+        assert_eq!(LongitudeRange::Unsigned.wrap(-10.), 350.);
+        assert_eq!(LongitudeRange::Unsigned.wrap(360.), 0.);
+    }
+
+    #[test]
+    fn normalize_points() {
+        let arr: PointArray = vec![point!(x: 190., y: 1.), point!(x: -10., y: 2.)]
+            .as_slice()
+            .into();
+        let signed = arr.normalize_longitude(
+            LongitudeRange::Signed,
+            NormalizeLongitudeMode::PerCoordinate,
+        );
+        assert_eq!(signed.value_as_geo(0), point!(x: -170., y: 1.));
+        assert_eq!(signed.value_as_geo(1), point!(x: -10., y: 2.));
+
+        let unsigned = arr
+            .into_coord_type(CoordType::Separated)
+            .normalize_longitude(
+                LongitudeRange::Unsigned,
+                NormalizeLongitudeMode::PerCoordinate,
+            );
+        assert_eq!(unsigned.value_as_geo(0), point!(x: 190., y: 1.));
+        assert_eq!(unsigned.value_as_geo(1), point!(x: 350., y: 2.));
+    }
+
+    #[test]
+    fn normalize_line_string_per_geometry() {
+        let arr: LineStringArray<i32> = vec![
+            line_string![(x: 170., y: 0.), (x: 190., y: 0.)],
+            line_string![(x: 370., y: 0.), (x: 380., y: 0.)],
+        ]
+        .as_slice()
+        .into();
+
+        let per_coord = arr.normalize_longitude(
+            LongitudeRange::Signed,
+            NormalizeLongitudeMode::PerCoordinate,
+        );
+        assert_eq!(
+            per_coord.value_as_geo(0),
+            line_string![(x: 170., y: 0.), (x: -170., y: 0.)]
+        );
+
+        let per_geom =
+            arr.normalize_longitude(LongitudeRange::Signed, NormalizeLongitudeMode::PerGeometry);
+        assert_eq!(
+            per_geom.value_as_geo(0),
+            line_string![(x: 170., y: 0.), (x: 190., y: 0.)]
+        );
+        assert_eq!(
+            per_geom.value_as_geo(1),
+            line_string![(x: 10., y: 0.), (x: 20., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn normalize_mixed() {
+        let arr: MixedGeometryArray<i32> = vec![
+            Some(geo::Geometry::Point(point!(x: 190., y: 1.))),
+            None,
+            Some(geo::Geometry::LineString(
+                line_string![(x: 370., y: 0.), (x: 380., y: 0.)],
+            )),
+        ]
+        .as_slice()
+        .try_into()
+        .unwrap();
+        let normalized = (&arr as &dyn GeometryArrayTrait)
+            .normalize_longitude(LongitudeRange::Signed, NormalizeLongitudeMode::PerGeometry)
+            .unwrap();
+        let normalized = normalized.as_mixed();
+        assert_eq!(
+            normalized.value_as_geo(0),
+            geo::Geometry::MultiPoint(vec![point!(x: -170., y: 1.)].into())
+        );
+        assert!(normalized.is_null(1));
+        assert_eq!(
+            normalized.value_as_geo(2),
+            geo::Geometry::MultiLineString(geo::MultiLineString::new(vec![
+                line_string![(x: 10., y: 0.), (x: 20., y: 0.)]
+            ]))
+        );
+    }
+
+    #[test]
+    fn normalize_rects() {
+        let arr: RectArray = vec![geo::Rect::new((170., 0.), (190., 1.))]
+            .as_slice()
+            .into();
+
+        let per_coord = arr.normalize_longitude(
+            LongitudeRange::Signed,
+            NormalizeLongitudeMode::PerCoordinate,
+        );
+        assert_eq!(per_coord.values.as_ref(), &[170., 0., -170., 1.]);
+
+        let per_geom = arr.normalize_longitude(
+            LongitudeRange::Unsigned,
+            NormalizeLongitudeMode::PerGeometry,
+        );
+        assert_eq!(per_geom.values.as_ref(), &[170., 0., 190., 1.]);
+        let per_geom =
+            arr.normalize_longitude(LongitudeRange::Signed, NormalizeLongitudeMode::PerGeometry);
+        assert_eq!(per_geom.values.as_ref(), &[170., 0., 190., 1.]);
+    }
+}
@@ -0,0 +1,169 @@
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+use geo::CoordsIter;
+
+use crate::algorithm::native::coord_range::CoordRange;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The number of coordinates of each geometry.
+///
+/// For arrays with a single coordinate buffer this is computed from the offset buffers only,
+/// without accessing any coordinates. Null geometries have a null count.
+pub trait NumCoords {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::NumCoords;
+    /// use geoarrow::array::PolygonArray;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// let array: PolygonArray<i32> = vec![polygon].as_slice().into();
+    ///
+    /// // The exterior ring is closed
+    /// assert_eq!(array.num_coords().value(0), 4);
+    /// ```
+    fn num_coords(&self) -> Self::Output;
+}
+
+/// Count the coordinates of each geometry from its coordinate range.
+fn count_from_ranges(array: &impl CoordRange) -> UInt32Array {
+    let values: Vec<u32> = (0..array.len())
+        .map(|geom_idx| array.coord_range(geom_idx).len() as u32)
+        .collect();
+    UInt32Array::new(values.into(), array.nulls().cloned())
+}
+
+impl NumCoords for PointArray {
+    type Output = UInt32Array;
+
+    fn num_coords(&self) -> Self::Output {
+        count_from_ranges(self)
+    }
+}
+
+/// Implementation for arrays with a single coordinate buffer
+macro_rules! coord_range_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> NumCoords for $type {
+            type Output = UInt32Array;
+
+            fn num_coords(&self) -> Self::Output {
+                count_from_ranges(self)
+            }
+        }
+    };
+}
+
+coord_range_impl!(LineStringArray<O>);
+coord_range_impl!(PolygonArray<O>);
+coord_range_impl!(MultiPointArray<O>);
+coord_range_impl!(MultiLineStringArray<O>);
+coord_range_impl!(MultiPolygonArray<O>);
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> NumCoords for $type {
+            type Output = UInt32Array;
+
+            fn num_coords(&self) -> Self::Output {
+                self.iter_geo()
+                    .map(|maybe_g| maybe_g.map(|geom| geom.coords_count() as u32))
+                    .collect()
+            }
+        }
+    };
+}
+
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+/// The coordinates of a rect are its four corners, as in [`geo::Rect`].
+impl NumCoords for RectArray {
+    type Output = UInt32Array;
+
+    fn num_coords(&self) -> Self::Output {
+        self.iter_geo()
+            .map(|maybe_g| maybe_g.map(|rect| rect.coords_count() as u32))
+            .collect()
+    }
+}
+
+impl NumCoords for &dyn GeometryArrayTrait {
+    type Output = Result<UInt32Array>;
+
+    fn num_coords(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().num_coords(),
+            GeoDataType::LineString(_) => self.as_line_string().num_coords(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().num_coords(),
+            GeoDataType::Polygon(_) => self.as_polygon().num_coords(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().num_coords(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().num_coords(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().num_coords(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().num_coords(),
+            GeoDataType::LargeMultiLineString(_) => self.as_large_multi_line_string().num_coords(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().num_coords(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().num_coords(),
+            GeoDataType::Mixed(_) => self.as_mixed().num_coords(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().num_coords(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().num_coords(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().num_coords()
+            }
+            GeoDataType::WKB => self.as_wkb().num_coords(),
+            GeoDataType::LargeWKB => self.as_large_wkb().num_coords(),
+            GeoDataType::Rect => self.as_rect().num_coords(),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait> NumCoords for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<UInt32Array>>;
+
+    fn num_coords(&self) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().num_coords())?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::mp_array;
+    use crate::trait_::GeometryArraySelfMethods;
+    use arrow_array::Array;
+    use geo::line_string;
+
+    #[test]
+    fn counts_from_offsets() {
+        let array = mp_array();
+        let expected: Vec<_> = array
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|g| g.coords_count() as u32))
+            .collect();
+        assert_eq!(array.num_coords(), UInt32Array::from(expected));
+    }
+
+    #[test]
+    fn nulls_and_slices() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            None,
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)]),
+        ]
+        .into();
+        let counts = array.slice(1, 2).num_coords();
+        assert!(counts.is_null(0));
+        assert_eq!(counts.value(1), 3);
+    }
+}
@@ -0,0 +1,191 @@
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The number of parts of each geometry.
+///
+/// The parts of a polygon are its rings, those of a multi geometry are its points, line strings
+/// or polygons, and those of a geometry collection are its geometries. Points, line strings and
+/// rects have a single part. For arrays with offsets this is computed from the offset buffers
+/// only. Null geometries have a null count.
+pub trait NumParts {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::NumParts;
+    /// use geoarrow::array::PolygonArray;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+    /// );
+    /// let array: PolygonArray<i32> = vec![polygon].as_slice().into();
+    ///
+    /// assert_eq!(array.num_parts().value(0), 2);
+    /// ```
+    fn num_parts(&self) -> Self::Output;
+}
+
+fn geometry_num_parts(geom: &geo::Geometry) -> u32 {
+    use geo::Geometry::*;
+
+    let num_parts = match geom {
+        Point(_) | Line(_) | LineString(_) | Rect(_) | Triangle(_) => 1,
+        Polygon(polygon) => {
+            if polygon.exterior().0.is_empty() {
+                0
+            } else {
+                1 + polygon.interiors().len()
+            }
+        }
+        MultiPoint(multi_point) => multi_point.0.len(),
+        MultiLineString(multi_line_string) => multi_line_string.0.len(),
+        MultiPolygon(multi_polygon) => multi_polygon.0.len(),
+        GeometryCollection(collection) => collection.0.len(),
+    };
+    num_parts as u32
+}
+
+impl NumParts for PointArray {
+    type Output = UInt32Array;
+
+    fn num_parts(&self) -> Self::Output {
+        let values = vec![1u32; self.len()];
+        UInt32Array::new(values.into(), self.nulls().cloned())
+    }
+}
+
+impl<O: OffsetSizeTrait> NumParts for LineStringArray<O> {
+    type Output = UInt32Array;
+
+    fn num_parts(&self) -> Self::Output {
+        let values = vec![1u32; self.len()];
+        UInt32Array::new(values.into(), self.nulls().cloned())
+    }
+}
+
+impl NumParts for RectArray {
+    type Output = UInt32Array;
+
+    fn num_parts(&self) -> Self::Output {
+        let values = vec![1u32; self.len()];
+        UInt32Array::new(values.into(), self.nulls().cloned())
+    }
+}
+
+/// Implementation for arrays whose parts are delimited by the geometry offsets
+macro_rules! geom_offsets_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> NumParts for $type {
+            type Output = UInt32Array;
+
+            fn num_parts(&self) -> Self::Output {
+                let values: Vec<u32> = (0..self.len())
+                    .map(|geom_idx| {
+                        let (start, end) = self.geom_offsets.start_end(geom_idx);
+                        (end - start) as u32
+                    })
+                    .collect();
+                UInt32Array::new(values.into(), self.nulls().cloned())
+            }
+        }
+    };
+}
+
+geom_offsets_impl!(PolygonArray<O>);
+geom_offsets_impl!(MultiPointArray<O>);
+geom_offsets_impl!(MultiLineStringArray<O>);
+geom_offsets_impl!(MultiPolygonArray<O>);
+geom_offsets_impl!(GeometryCollectionArray<O>);
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> NumParts for $type {
+            type Output = UInt32Array;
+
+            fn num_parts(&self) -> Self::Output {
+                self.iter_geo()
+                    .map(|maybe_g| maybe_g.map(|geom| geometry_num_parts(&geom)))
+                    .collect()
+            }
+        }
+    };
+}
+
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(WKBArray<O>);
+
+impl NumParts for &dyn GeometryArrayTrait {
+    type Output = Result<UInt32Array>;
+
+    fn num_parts(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().num_parts(),
+            GeoDataType::LineString(_) => self.as_line_string().num_parts(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().num_parts(),
+            GeoDataType::Polygon(_) => self.as_polygon().num_parts(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().num_parts(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().num_parts(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().num_parts(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().num_parts(),
+            GeoDataType::LargeMultiLineString(_) => self.as_large_multi_line_string().num_parts(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().num_parts(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().num_parts(),
+            GeoDataType::Mixed(_) => self.as_mixed().num_parts(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().num_parts(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().num_parts(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().num_parts()
+            }
+            GeoDataType::WKB => self.as_wkb().num_parts(),
+            GeoDataType::LargeWKB => self.as_large_wkb().num_parts(),
+            GeoDataType::Rect => self.as_rect().num_parts(),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait> NumParts for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<UInt32Array>>;
+
+    fn num_parts(&self) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().num_parts())?.try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::mp_array;
+
+    #[test]
+    fn offsets_match_geo() {
+        let array = mp_array();
+        let expected: Vec<_> = array
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|g| geometry_num_parts(&geo::Geometry::MultiPolygon(g))))
+            .collect();
+        let expected = UInt32Array::from(expected);
+        assert_eq!(array.num_parts(), expected);
+
+        let geometries: Vec<_> = array
+            .iter_geo_values()
+            .map(geo::Geometry::MultiPolygon)
+            .collect();
+        let mixed: MixedGeometryArray<i32> = geometries.as_slice().try_into().unwrap();
+        assert_eq!(
+            (&mixed as &dyn GeometryArrayTrait).num_parts().unwrap(),
+            expected
+        );
+    }
+}
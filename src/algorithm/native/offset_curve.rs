@@ -0,0 +1,375 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{coord, Coord, LineString, MultiLineString};
+
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The side of a line string, relative to its direction, that an offset curve is generated on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OffsetSide {
+    /// Offset to the left of the line string
+    #[default]
+    Left,
+
+    /// Offset to the right of the line string
+    Right,
+}
+
+/// How the offset segments are connected around the outside of a corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle {
+    /// Connect the segments with a circular arc around the vertex, approximated with the given
+    /// number of segments per quarter circle.
+    Round { quadrant_segments: usize },
+
+    /// Extend the segments until they meet. If the resulting point is further than `limit` times
+    /// the offset distance from the vertex, a bevel join is used instead.
+    Mitre { limit: f64 },
+
+    /// Connect the ends of the segments with a straight line.
+    Bevel,
+}
+
+impl Default for JoinStyle {
+    fn default() -> Self {
+        JoinStyle::Round {
+            quadrant_segments: 8,
+        }
+    }
+}
+
+/// Generate curves parallel to linear geometries at a fixed distance.
+///
+/// Each segment is shifted perpendicular to its direction by `distance` towards `side`. A negative
+/// distance offsets towards the opposite side. At the inside of a corner, consecutive offset
+/// segments are trimmed to where they intersect; at the outside of a corner they are connected
+/// according to the [`JoinStyle`]. Closed line strings are joined around their start point as
+/// well. The output is not cleaned up where the offset distance is larger than the curvature of
+/// the input, so such offset curves may self-intersect.
+pub trait OffsetCurve {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{JoinStyle, OffsetCurve, OffsetSide};
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::line_string;
+    ///
+    /// let array: LineStringArray<i32> =
+    ///     vec![line_string![(x: 0., y: 0.), (x: 10., y: 0.)]].as_slice().into();
+    /// let offset = array.offset_curve(2., OffsetSide::Left, JoinStyle::Bevel);
+    /// assert_eq!(offset.value_as_geo(0), line_string![(x: 0., y: 2.), (x: 10., y: 2.)]);
+    /// ```
+    fn offset_curve(&self, distance: f64, side: OffsetSide, join: JoinStyle) -> Self::Output;
+}
+
+fn cross(a: Coord, b: Coord) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Coord, b: Coord) -> f64 {
+    a.x * b.x + a.y * b.y
+}
+
+/// The unit vector pointing from `start` to `end`.
+fn unit_direction(start: Coord, end: Coord) -> Coord {
+    let delta = end - start;
+    delta / delta.x.hypot(delta.y)
+}
+
+/// The vector pointing to the left of `direction`, with the same length.
+fn left_normal(direction: Coord) -> Coord {
+    coord! { x: -direction.y, y: direction.x }
+}
+
+/// The offset vertices around `vertex`, connecting the offset of the segment arriving in
+/// direction `prev_dir` to the offset of the segment leaving in direction `next_dir`.
+///
+/// `distance` is signed, with positive values offsetting to the left.
+fn offset_corner(
+    vertex: Coord,
+    prev_dir: Coord,
+    next_dir: Coord,
+    distance: f64,
+    join: JoinStyle,
+) -> Vec<Coord> {
+    let prev_offset = left_normal(prev_dir) * distance;
+    let next_offset = left_normal(next_dir) * distance;
+    let turn = cross(prev_dir, next_dir);
+
+    if turn.abs() < 1e-12 && dot(prev_dir, next_dir) > 0. {
+        // The segments are collinear
+        return vec![vertex + prev_offset];
+    }
+
+    // Where the two offset lines intersect
+    let intersection = || {
+        let prev_point = vertex + prev_offset;
+        let s = cross(vertex + next_offset - prev_point, next_dir) / turn;
+        prev_point + prev_dir * s
+    };
+
+    if turn * distance > 0. || distance == 0. {
+        // The inside of the corner
+        return vec![intersection()];
+    }
+
+    let bevel = vec![vertex + prev_offset, vertex + next_offset];
+    match join {
+        JoinStyle::Bevel => bevel,
+        JoinStyle::Mitre { limit } => {
+            if turn.abs() < 1e-12 {
+                // The line string reverses direction, so the offset lines never meet
+                return bevel;
+            }
+            let mitre = intersection();
+            let mitre_length = (mitre - vertex).x.hypot((mitre - vertex).y);
+            if mitre_length <= limit * distance.abs() {
+                vec![mitre]
+            } else {
+                bevel
+            }
+        }
+        JoinStyle::Round { quadrant_segments } => {
+            let start_angle = prev_offset.y.atan2(prev_offset.x);
+            let end_angle = next_offset.y.atan2(next_offset.x);
+            // Go around the outside of the corner: clockwise for left offsets and counterclockwise
+            // for right offsets
+            let mut sweep = end_angle - start_angle;
+            if distance > 0. {
+                while sweep > 0. {
+                    sweep -= 2. * PI;
+                }
+            } else {
+                while sweep < 0. {
+                    sweep += 2. * PI;
+                }
+            }
+            let num_segments = ((sweep.abs() / FRAC_PI_2) * quadrant_segments as f64)
+                .ceil()
+                .max(1.) as usize;
+            let radius = distance.abs();
+            (0..=num_segments)
+                .map(|i| {
+                    let angle = start_angle + sweep * i as f64 / num_segments as f64;
+                    vertex + coord! { x: radius * angle.cos(), y: radius * angle.sin() }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Offset a single line string by a signed distance, with positive values offsetting to the left.
+fn offset_line_string(line_string: &LineString, distance: f64, join: JoinStyle) -> LineString {
+    let mut coords = line_string.0.clone();
+    coords.dedup();
+    if coords.len() < 2 {
+        return line_string.clone();
+    }
+
+    let directions: Vec<Coord> = coords
+        .windows(2)
+        .map(|segment| unit_direction(segment[0], segment[1]))
+        .collect();
+    let first_dir = directions[0];
+    let last_dir = *directions.last().unwrap();
+
+    let mut output = vec![];
+    let is_closed = coords.len() > 3 && coords.first() == coords.last();
+    let start_corner = if is_closed {
+        offset_corner(coords[0], last_dir, first_dir, distance, join)
+    } else {
+        vec![coords[0] + left_normal(first_dir) * distance]
+    };
+    output.extend_from_slice(&start_corner);
+
+    for (vertex_idx, dirs) in directions.windows(2).enumerate() {
+        output.extend(offset_corner(
+            coords[vertex_idx + 1],
+            dirs[0],
+            dirs[1],
+            distance,
+            join,
+        ));
+    }
+
+    if is_closed {
+        output.push(start_corner[0]);
+    } else {
+        output.push(*coords.last().unwrap() + left_normal(last_dir) * distance);
+    }
+    output.dedup();
+    LineString::new(output)
+}
+
+/// The offset distance, signed so that positive values offset to the left.
+fn signed_distance(distance: f64, side: OffsetSide) -> f64 {
+    match side {
+        OffsetSide::Left => distance,
+        OffsetSide::Right => -distance,
+    }
+}
+
+impl<O: OffsetSizeTrait> OffsetCurve for LineStringArray<O> {
+    type Output = Self;
+
+    fn offset_curve(&self, distance: f64, side: OffsetSide, join: JoinStyle) -> Self::Output {
+        let distance = signed_distance(distance, side);
+        let output_geoms: Vec<Option<LineString>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| offset_line_string(&geom, distance, join)))
+            .collect();
+        output_geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait> OffsetCurve for MultiLineStringArray<O> {
+    type Output = Self;
+
+    fn offset_curve(&self, distance: f64, side: OffsetSide, join: JoinStyle) -> Self::Output {
+        let distance = signed_distance(distance, side);
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g.map(|geom| {
+                    MultiLineString::new(
+                        geom.iter()
+                            .map(|line_string| offset_line_string(line_string, distance, join))
+                            .collect(),
+                    )
+                })
+            })
+            .collect();
+        output_geoms.into()
+    }
+}
+
+impl OffsetCurve for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn offset_curve(&self, distance: f64, side: OffsetSide, join: JoinStyle) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::LineString(_) => {
+                Arc::new(self.as_line_string().offset_curve(distance, side, join))
+            }
+            GeoDataType::LargeLineString(_) => Arc::new(
+                self.as_large_line_string()
+                    .offset_curve(distance, side, join),
+            ),
+            GeoDataType::MultiLineString(_) => Arc::new(
+                self.as_multi_line_string()
+                    .offset_curve(distance, side, join),
+            ),
+            GeoDataType::LargeMultiLineString(_) => Arc::new(
+                self.as_large_multi_line_string()
+                    .offset_curve(distance, side, join),
+            ),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> OffsetCurve for $type {
+            type Output = Self;
+
+            fn offset_curve(
+                &self,
+                distance: f64,
+                side: OffsetSide,
+                join: JoinStyle,
+            ) -> Self::Output {
+                ChunkedGeometryArray::new(
+                    self.map(|chunk| chunk.offset_curve(distance, side, join)),
+                )
+            }
+        }
+    };
+}
+
+chunked_impl!(ChunkedLineStringArray<O>);
+chunked_impl!(ChunkedMultiLineStringArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::line_string;
+
+    #[test]
+    fn join_styles() {
+        // Turning right, so the left offset is on the outside of the corner
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: -2.)];
+
+        let bevel = offset_line_string(&line_string, 1., JoinStyle::Bevel);
+        assert_eq!(
+            bevel,
+            line_string![(x: 0., y: 1.), (x: 2., y: 1.), (x: 3., y: 0.), (x: 3., y: -2.)]
+        );
+
+        let mitre = offset_line_string(&line_string, 1., JoinStyle::Mitre { limit: 2. });
+        assert_eq!(
+            mitre,
+            line_string![(x: 0., y: 1.), (x: 3., y: 1.), (x: 3., y: -2.)]
+        );
+
+        // The mitre of a right angle is sqrt(2) times the distance from the vertex
+        let limited = offset_line_string(&line_string, 1., JoinStyle::Mitre { limit: 1.2 });
+        assert_eq!(limited, bevel);
+
+        let round = offset_line_string(
+            &line_string,
+            1.,
+            JoinStyle::Round {
+                quadrant_segments: 4,
+            },
+        );
+        assert_eq!(round.0.len(), 7);
+        for coord in &round.0[1..6] {
+            let radius = (coord.x - 2.).hypot(coord.y);
+            assert!((radius - 1.).abs() < 1e-12);
+        }
+
+        // The right offset is on the inside of the corner
+        let inner = offset_line_string(&line_string, -1., JoinStyle::default());
+        assert_eq!(
+            inner,
+            line_string![(x: 0., y: -1.), (x: 1., y: -1.), (x: 1., y: -2.)]
+        );
+    }
+
+    #[test]
+    fn closed_ring() {
+        // Counterclockwise, so its right side is the outside
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let array: LineStringArray<i32> = vec![Some(ring), None].into();
+        let offset = array.offset_curve(1., OffsetSide::Right, JoinStyle::Mitre { limit: 2. });
+        assert_eq!(
+            offset.value_as_geo(0),
+            line_string![
+                (x: -1., y: -1.),
+                (x: 3., y: -1.),
+                (x: 3., y: 3.),
+                (x: -1., y: 3.),
+                (x: -1., y: -1.),
+            ]
+        );
+        assert!(offset.is_null(1));
+    }
+}
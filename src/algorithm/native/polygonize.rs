@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{Area, Contains, Coord, LineString, Polygon};
+
+use crate::algorithm::native::line_merge::node_key;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::trait_::GeometryArrayAccessor;
+
+/// The result of [`Polygonize`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygonized<O: OffsetSizeTrait> {
+    /// The faces enclosed by the input edges.
+    pub polygons: PolygonArray<O>,
+
+    /// Edges with an endpoint that is not shared with any other edge, after repeatedly removing
+    /// such edges. These don't enclose any face.
+    pub dangles: LineStringArray<O>,
+
+    /// Edges that connect enclosed faces but don't bound any face themselves, such as a line
+    /// connecting two separate rings.
+    pub cut_edges: LineStringArray<O>,
+}
+
+/// Build the polygons formed by a set of line strings, like PostGIS' `ST_Polygonize`.
+///
+/// The input must be correctly noded, i.e. line strings may only touch at their endpoints. Each
+/// line string is an edge of a planar graph, and every face of that graph enclosed by edges is
+/// returned as a polygon. Faces nested inside another face become holes of it. Null geometries
+/// are skipped.
+pub trait Polygonize {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Polygonize;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::line_string;
+    ///
+    /// // Two edges forming a closed ring, and a dangling edge
+    /// let array: LineStringArray<i32> = vec![
+    ///     line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+    ///     line_string![(x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)],
+    ///     line_string![(x: 1., y: 1.), (x: 2., y: 2.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let polygonized = array.polygonize();
+    /// assert_eq!(polygonized.polygons.len(), 1);
+    /// assert_eq!(polygonized.dangles.len(), 1);
+    /// assert_eq!(polygonized.cut_edges.len(), 0);
+    /// ```
+    fn polygonize(&self) -> Self::Output;
+}
+
+/// A planar graph whose edges are line strings.
+///
+/// Edge `i` has two directed half edges: `2 * i` runs from its start to its end, and `2 * i + 1`
+/// runs from its end to its start.
+struct PlanarGraph {
+    edges: Vec<LineString>,
+    alive: Vec<bool>,
+    /// The outgoing half edges at each node.
+    nodes: HashMap<(u64, u64), Vec<usize>>,
+}
+
+impl PlanarGraph {
+    fn new(edges: Vec<LineString>) -> Self {
+        let mut nodes: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+        for (edge_idx, edge) in edges.iter().enumerate() {
+            nodes
+                .entry(node_key(&edge.0[0]))
+                .or_default()
+                .push(2 * edge_idx);
+            nodes
+                .entry(node_key(edge.0.last().unwrap()))
+                .or_default()
+                .push(2 * edge_idx + 1);
+        }
+        let alive = vec![true; edges.len()];
+        Self {
+            edges,
+            alive,
+            nodes,
+        }
+    }
+
+    /// The coordinates of a half edge, in its direction.
+    fn coords(&self, half_edge: usize) -> Box<dyn Iterator<Item = &Coord> + '_> {
+        let coords = self.edges[half_edge / 2].0.iter();
+        if half_edge % 2 == 0 {
+            Box::new(coords)
+        } else {
+            Box::new(coords.rev())
+        }
+    }
+
+    /// The node a half edge starts from.
+    fn origin(&self, half_edge: usize) -> (u64, u64) {
+        node_key(self.coords(half_edge).next().unwrap())
+    }
+
+    /// The angle of the first segment of a half edge.
+    fn angle(&self, half_edge: usize) -> f64 {
+        let mut coords = self.coords(half_edge);
+        let start = coords.next().unwrap();
+        let next = coords.next().unwrap();
+        (next.y - start.y).atan2(next.x - start.x)
+    }
+
+    fn remove_edge(&mut self, edge_idx: usize) {
+        self.alive[edge_idx] = false;
+        for half_edge in [2 * edge_idx, 2 * edge_idx + 1] {
+            let origin = self.origin(half_edge);
+            self.nodes
+                .get_mut(&origin)
+                .unwrap()
+                .retain(|other| *other != half_edge);
+        }
+    }
+
+    /// Repeatedly remove edges with an endpoint of degree one, returning their indices.
+    fn remove_dangles(&mut self) -> Vec<usize> {
+        // Nodes that may have degree one, starting with all nodes that do
+        let mut worklist = self
+            .nodes
+            .iter()
+            .filter(|(_, half_edges)| half_edges.len() == 1)
+            .map(|(node, _)| *node)
+            .collect::<Vec<_>>();
+
+        let mut dangles = vec![];
+        while let Some(node) = worklist.pop() {
+            // The node may have lost its edge since it was added
+            let half_edge = match self.nodes[&node].as_slice() {
+                [half_edge] => *half_edge,
+                _ => continue,
+            };
+            let far_node = self.origin(half_edge ^ 1);
+            self.remove_edge(half_edge / 2);
+            dangles.push(half_edge / 2);
+            if self.nodes[&far_node].len() == 1 {
+                worklist.push(far_node);
+            }
+        }
+        dangles.sort_unstable();
+        dangles
+    }
+
+    /// Trace the rings of the graph, such that each ring has the face it bounds on its left.
+    ///
+    /// Returns the rings and the ring of each half edge.
+    fn trace_rings(&self) -> (Vec<LineString>, Vec<Option<usize>>) {
+        // Sort the outgoing half edges of each node counter-clockwise
+        let mut sorted_nodes = HashMap::with_capacity(self.nodes.len());
+        for (node, half_edges) in self.nodes.iter() {
+            let mut half_edges = half_edges.clone();
+            half_edges.sort_by(|a, b| self.angle(*a).total_cmp(&self.angle(*b)));
+            sorted_nodes.insert(*node, half_edges);
+        }
+
+        // The next half edge of a ring is the one clockwise from the twin of the previous one
+        let next = |half_edge: usize| {
+            let twin = half_edge ^ 1;
+            let half_edges: &Vec<usize> = &sorted_nodes[&self.origin(twin)];
+            let position = half_edges.iter().position(|e| *e == twin).unwrap();
+            half_edges[(position + half_edges.len() - 1) % half_edges.len()]
+        };
+
+        let mut rings = vec![];
+        let mut ring_of_half_edge = vec![None; self.edges.len() * 2];
+        for start in 0..self.edges.len() * 2 {
+            if !self.alive[start / 2] || ring_of_half_edge[start].is_some() {
+                continue;
+            }
+            let mut coords: Vec<Coord> = vec![];
+            let mut half_edge = start;
+            loop {
+                ring_of_half_edge[half_edge] = Some(rings.len());
+                let skip = usize::from(!coords.is_empty());
+                coords.extend(self.coords(half_edge).skip(skip));
+                half_edge = next(half_edge);
+                if half_edge == start {
+                    break;
+                }
+            }
+            rings.push(LineString::new(coords));
+        }
+        (rings, ring_of_half_edge)
+    }
+
+    /// Remove edges with the same ring on both sides, returning their indices.
+    fn remove_cut_edges(&mut self) -> Vec<usize> {
+        let (_, ring_of_half_edge) = self.trace_rings();
+        let cut_edges = (0..self.edges.len())
+            .filter(|edge_idx| {
+                self.alive[*edge_idx]
+                    && ring_of_half_edge[2 * edge_idx] == ring_of_half_edge[2 * edge_idx + 1]
+            })
+            .collect::<Vec<_>>();
+        cut_edges
+            .iter()
+            .for_each(|edge_idx| self.remove_edge(*edge_idx));
+        cut_edges
+    }
+}
+
+/// Polygonize a set of noded line strings, returning the polygons, dangles and cut edges.
+pub(crate) fn polygonize_line_strings(
+    line_strings: Vec<LineString>,
+) -> (Vec<Polygon>, Vec<LineString>, Vec<LineString>) {
+    let edges = line_strings
+        .into_iter()
+        .filter(|ls| ls.0.len() > 1)
+        .collect();
+    let mut graph = PlanarGraph::new(edges);
+    let dangles = graph.remove_dangles();
+    let cut_edges = graph.remove_cut_edges();
+    let (rings, _) = graph.trace_rings();
+
+    // Rings with the face on their left that run counter-clockwise enclose a face. Clockwise
+    // rings are the outer boundaries of connected parts of the graph.
+    let (shells, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .map(|ring| {
+            let area = Polygon::new(ring.clone(), vec![]).signed_area();
+            (ring, area)
+        })
+        .filter(|(_, area)| *area != 0.)
+        .partition(|(_, area)| *area > 0.);
+
+    let shell_polygons = shells
+        .iter()
+        .map(|(shell, _)| Polygon::new(shell.clone(), vec![]))
+        .collect::<Vec<_>>();
+    let mut polygons = shell_polygons.clone();
+    for (hole, _) in holes {
+        // A boundary that is nested inside a face is a hole of the smallest face containing it
+        let containing_shell = shells
+            .iter()
+            .enumerate()
+            .filter(|(shell_idx, _)| shell_polygons[*shell_idx].contains(&hole.0[0]))
+            .min_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+            .map(|(shell_idx, _)| shell_idx);
+        if let Some(shell_idx) = containing_shell {
+            polygons[shell_idx].interiors_push(hole);
+        }
+    }
+
+    let edges_at = |indices: Vec<usize>| {
+        indices
+            .into_iter()
+            .map(|edge_idx| graph.edges[edge_idx].clone())
+            .collect::<Vec<_>>()
+    };
+    (polygons, edges_at(dangles), edges_at(cut_edges))
+}
+
+impl<O: OffsetSizeTrait> Polygonize for LineStringArray<O> {
+    type Output = Polygonized<O>;
+
+    fn polygonize(&self) -> Self::Output {
+        let (polygons, dangles, cut_edges) =
+            polygonize_line_strings(self.iter_geo().flatten().collect());
+        Polygonized {
+            polygons: polygons.as_slice().into(),
+            dangles: dangles.as_slice().into(),
+            cut_edges: cut_edges.as_slice().into(),
+        }
+    }
+}
+
+/// Edges from all chunks are polygonized together.
+impl<O: OffsetSizeTrait> Polygonize for ChunkedGeometryArray<LineStringArray<O>> {
+    type Output = Polygonized<O>;
+
+    fn polygonize(&self) -> Self::Output {
+        let line_strings = self
+            .chunks()
+            .iter()
+            .flat_map(|chunk| chunk.iter_geo().flatten())
+            .collect();
+        let (polygons, dangles, cut_edges) = polygonize_line_strings(line_strings);
+        Polygonized {
+            polygons: polygons.as_slice().into(),
+            dangles: dangles.as_slice().into(),
+            cut_edges: cut_edges.as_slice().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::line_string;
+
+    #[test]
+    fn two_adjacent_faces() {
+        // Two unit squares sharing the edge from (1, 0) to (1, 1)
+        let line_strings = vec![
+            line_string![(x: 1., y: 0.), (x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)],
+        ];
+        let (polygons, dangles, cut_edges) = polygonize_line_strings(line_strings);
+        assert_eq!(polygons.len(), 2);
+        for polygon in polygons {
+            assert_eq!(polygon.unsigned_area(), 1.);
+        }
+        assert!(dangles.is_empty());
+        assert!(cut_edges.is_empty());
+    }
+
+    #[test]
+    fn hole_and_cut_edge() {
+        let line_strings = vec![
+            // Outer square, noded where the cut edge meets it
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+            // Inner square
+            line_string![(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.), (x: 4., y: 4.)],
+            // Separate square connected to the outer one by a cut edge
+            line_string![(x: 10., y: 0.), (x: 20., y: 0.)],
+            line_string![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.), (x: 20., y: 0.)],
+            // Dangle within the outer square
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+        ];
+        let (polygons, dangles, cut_edges) = polygonize_line_strings(line_strings);
+        assert_eq!(dangles, vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]]);
+        assert_eq!(
+            cut_edges,
+            vec![line_string![(x: 10., y: 0.), (x: 20., y: 0.)]]
+        );
+
+        let mut areas = polygons
+            .iter()
+            .map(|polygon| polygon.unsigned_area())
+            .collect::<Vec<_>>();
+        areas.sort_by(f64::total_cmp);
+        assert_eq!(areas, vec![0.5, 4., 96.]);
+        let outer = polygons.iter().find(|p| p.unsigned_area() == 96.).unwrap();
+        assert_eq!(outer.interiors().len(), 1);
+    }
+}
@@ -5,7 +5,7 @@ use arrow_array::OffsetSizeTrait;
 use crate::algorithm::native::Take;
 use crate::array::*;
 use crate::chunked_array::ChunkedGeometryArray;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 
 pub trait Rechunk {
     type Output;
@@ -19,14 +19,14 @@ pub trait Rechunk {
 }
 
 impl Rechunk for PointArray {
-    type Output = ChunkedGeometryArray<PointArray>;
+    type Output = Result<ChunkedGeometryArray<PointArray>>;
 
     fn rechunk(&self, ranges: &[Range<usize>]) -> Self::Output {
         let mut output_arrays = Vec::with_capacity(ranges.len());
         for range in ranges {
-            output_arrays.push(self.take_range(range));
+            output_arrays.push(self.take_range(range)?);
         }
-        ChunkedGeometryArray::new(output_arrays)
+        Ok(ChunkedGeometryArray::new(output_arrays))
     }
 
     // fn rechunk_num_geoms(&self, n_geoms_per_chunk: usize) -> Self::Output {
@@ -66,6 +66,53 @@ rechunk_impl!(MultiPolygonArray<O>);
 rechunk_impl!(MixedGeometryArray<O>);
 rechunk_impl!(GeometryCollectionArray<O>);
 
+/// A slice of an input chunk: its chunk index, offset and length.
+pub(crate) type ChunkSlice = (usize, usize, usize);
+
+/// Plan output chunks of `target_rows` rows each (the last may be shorter) over input chunks of
+/// the given lengths. Each output chunk is described by the slices of input chunks it's made of.
+pub(crate) fn plan_rechunk(
+    chunk_lengths: &[usize],
+    target_rows: usize,
+) -> Result<Vec<Vec<ChunkSlice>>> {
+    if target_rows == 0 {
+        return Err(GeoArrowError::General(
+            "target_rows must be positive".to_string(),
+        ));
+    }
+
+    let mut output = vec![];
+    let mut current: Vec<ChunkSlice> = vec![];
+    let mut current_len = 0;
+    for (chunk_idx, &chunk_len) in chunk_lengths.iter().enumerate() {
+        let mut offset = 0;
+        while offset < chunk_len {
+            let length = (target_rows - current_len).min(chunk_len - offset);
+            current.push((chunk_idx, offset, length));
+            current_len += length;
+            offset += length;
+            if current_len == target_rows {
+                output.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+        }
+    }
+    if !current.is_empty() {
+        output.push(current);
+    }
+    Ok(output)
+}
+
+/// The number of rows per chunk that gives chunks of about `target_bytes`, given the total size
+/// and number of rows.
+pub(crate) fn rows_for_bytes(total_bytes: usize, total_rows: usize, target_bytes: usize) -> usize {
+    if total_bytes == 0 {
+        return total_rows.max(1);
+    }
+    let bytes_per_row = total_bytes as f64 / total_rows.max(1) as f64;
+    ((target_bytes as f64 / bytes_per_row) as usize).max(1)
+}
+
 // impl<O: OffsetSizeTrait> Rechunk for LineStringArray<O> {
 //     type Output = Result<ChunkedGeometryArray<Self>>;
 
@@ -77,3 +124,59 @@ rechunk_impl!(GeometryCollectionArray<O>);
 //         Ok(ChunkedGeometryArray::new(output_arrays))
 //     }
 // }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::{p0, p1, p2, point_array, table};
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+
+    #[test]
+    fn plan_splits_and_merges() {
+        assert_eq!(
+            plan_rechunk(&[2, 3, 1], 4).unwrap(),
+            vec![vec![(0, 0, 2), (1, 0, 2)], vec![(1, 2, 1), (2, 0, 1)]]
+        );
+        assert_eq!(
+            plan_rechunk(&[5], 2).unwrap(),
+            vec![vec![(0, 0, 2)], vec![(0, 2, 2)], vec![(0, 4, 1)]]
+        );
+        assert_eq!(
+            plan_rechunk(&[0, 0], 2).unwrap(),
+            Vec::<Vec<ChunkSlice>>::new()
+        );
+        assert!(plan_rechunk(&[1], 0).is_err());
+    }
+
+    #[test]
+    fn rechunk_chunked_array() {
+        let arr = point_array();
+        let chunked = ChunkedGeometryArray::new(vec![arr.slice(0, 1), arr.slice(1, 2)]);
+        let rechunked = chunked.rechunk(2).unwrap();
+        assert_eq!(rechunked.chunks().len(), 2);
+        let points: Vec<_> = rechunked
+            .chunks()
+            .iter()
+            .flat_map(|chunk| chunk.iter_geo_values().collect::<Vec<_>>())
+            .collect();
+        assert_eq!(points, vec![p0(), p1(), p2()]);
+
+        let wkb: WKBArray<i32> = (&arr).into();
+        let chunked = ChunkedGeometryArray::new(vec![wkb.slice(0, 2), wkb.slice(2, 1)]);
+        let rechunked = chunked.rechunk(3).unwrap();
+        assert_eq!(rechunked.chunks().len(), 1);
+        assert_eq!(rechunked.chunks()[0], wkb);
+
+        let rechunked = chunked.rechunk_by_bytes(1).unwrap();
+        assert_eq!(rechunked.chunks().len(), 3);
+    }
+
+    #[test]
+    fn rechunk_table() {
+        let table = table();
+        let rechunked = table.rechunk(1).unwrap();
+        assert_eq!(rechunked.batches().len(), table.len());
+        let rechunked = rechunked.rechunk(table.len()).unwrap();
+        assert_eq!(rechunked.batches(), table.batches());
+    }
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// Remove the interior rings (holes) of polygonal geometries, keeping only their exteriors.
+///
+/// The output is computed from the offsets of the input and copies coordinates of exterior rings
+/// only; no geometries are constructed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+///
+/// use geoarrow::algorithm::native::RemoveHoles;
+/// use geoarrow::array::PolygonArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 0.)],
+///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+/// );
+/// let array: PolygonArray<i32> = vec![polygon].as_slice().into();
+/// let filled = array.remove_holes();
+/// assert!(filled.value_as_geo(0).interiors().is_empty());
+/// ```
+pub trait RemoveHoles {
+    type Output;
+
+    fn remove_holes(&self) -> Self::Output;
+}
+
+/// The coordinate range of the exterior ring of the polygon at `polygon_idx`, or `None` if
+/// the polygon is empty.
+fn exterior_range<O: OffsetSizeTrait>(
+    geom_offsets: &arrow_buffer::OffsetBuffer<O>,
+    ring_offsets: &arrow_buffer::OffsetBuffer<O>,
+    polygon_idx: usize,
+) -> Option<(usize, usize)> {
+    let (start, end) = geom_offsets.start_end(polygon_idx);
+    (start < end).then(|| ring_offsets.start_end(start))
+}
+
+impl<O: OffsetSizeTrait> RemoveHoles for PolygonArray<O> {
+    type Output = Self;
+
+    fn remove_holes(&self) -> Self::Output {
+        let mut coord_capacity = 0;
+        let mut ring_capacity = 0;
+        for i in 0..self.len() {
+            if let Some((start, end)) = exterior_range(&self.geom_offsets, &self.ring_offsets, i) {
+                ring_capacity += 1;
+                coord_capacity += end - start;
+            }
+        }
+
+        let capacity = PolygonCapacity::new(coord_capacity, ring_capacity, self.len());
+        let mut builder = PolygonBuilder::<O>::with_capacity_and_options(
+            capacity,
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                builder.push_null();
+                continue;
+            }
+
+            // Offsets can't overflow, as they are bounded by the offsets of the input
+            match exterior_range(&self.geom_offsets, &self.ring_offsets, i) {
+                Some((start, end)) => unsafe {
+                    builder.try_push_geom_offset(1).unwrap();
+                    builder.try_push_ring_offset(end - start).unwrap();
+                    for coord_idx in start..end {
+                        builder
+                            .push_xy(self.coords.get_x(coord_idx), self.coords.get_y(coord_idx))
+                            .unwrap();
+                    }
+                },
+                None => builder.push_empty(),
+            }
+        }
+
+        builder.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> RemoveHoles for MultiPolygonArray<O> {
+    type Output = Self;
+
+    fn remove_holes(&self) -> Self::Output {
+        let mut coord_capacity = 0;
+        let mut ring_capacity = 0;
+        let mut polygon_capacity = 0;
+        for i in 0..self.len() {
+            let (start, end) = self.geom_offsets.start_end(i);
+            polygon_capacity += end - start;
+            for polygon_idx in start..end {
+                if let Some((start, end)) =
+                    exterior_range(&self.polygon_offsets, &self.ring_offsets, polygon_idx)
+                {
+                    ring_capacity += 1;
+                    coord_capacity += end - start;
+                }
+            }
+        }
+
+        let capacity =
+            MultiPolygonCapacity::new(coord_capacity, ring_capacity, polygon_capacity, self.len());
+        let mut builder = MultiPolygonBuilder::<O>::with_capacity_and_options(
+            capacity,
+            self.coord_type(),
+            self.metadata(),
+        );
+
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                builder.push_null();
+                continue;
+            }
+
+            let (start, end) = self.geom_offsets.start_end(i);
+            // Offsets can't overflow, as they are bounded by the offsets of the input
+            unsafe {
+                builder.try_push_geom_offset(end - start).unwrap();
+                for polygon_idx in start..end {
+                    match exterior_range(&self.polygon_offsets, &self.ring_offsets, polygon_idx) {
+                        Some((start, end)) => {
+                            builder.try_push_polygon_offset(1).unwrap();
+                            builder.try_push_ring_offset(end - start).unwrap();
+                            for coord_idx in start..end {
+                                builder
+                                    .push_xy(
+                                        self.coords.get_x(coord_idx),
+                                        self.coords.get_y(coord_idx),
+                                    )
+                                    .unwrap();
+                            }
+                        }
+                        None => builder.try_push_polygon_offset(0).unwrap(),
+                    }
+                }
+            }
+        }
+
+        builder.finish()
+    }
+}
+
+impl RemoveHoles for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn remove_holes(&self) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().remove_holes()),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().remove_holes()),
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().remove_holes()),
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().remove_holes())
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl<O: OffsetSizeTrait> RemoveHoles for ChunkedGeometryArray<PolygonArray<O>> {
+    type Output = Self;
+
+    fn remove_holes(&self) -> Self::Output {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.remove_holes()))
+    }
+}
+
+impl<O: OffsetSizeTrait> RemoveHoles for ChunkedGeometryArray<MultiPolygonArray<O>> {
+    type Output = Self;
+
+    fn remove_holes(&self) -> Self::Output {
+        ChunkedGeometryArray::new(self.map(|chunk| chunk.remove_holes()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::mp_array;
+    use crate::test::polygon::p_array;
+    use crate::trait_::GeometryArrayAccessor;
+    use geo::{MultiPolygon, Polygon};
+
+    #[test]
+    fn polygon_remove_holes() {
+        let arr = p_array();
+        let filled = arr.remove_holes();
+        for i in 0..arr.len() {
+            let expected = Polygon::new(arr.value_as_geo(i).exterior().clone(), vec![]);
+            assert_eq!(filled.value_as_geo(i), expected);
+        }
+    }
+
+    #[test]
+    fn multi_polygon_remove_holes() {
+        let arr = mp_array();
+        let filled = arr.remove_holes();
+        for i in 0..arr.len() {
+            let expected = MultiPolygon::new(
+                arr.value_as_geo(i)
+                    .0
+                    .iter()
+                    .map(|polygon| Polygon::new(polygon.exterior().clone(), vec![]))
+                    .collect(),
+            );
+            assert_eq!(filled.value_as_geo(i), expected);
+        }
+    }
+
+    #[test]
+    fn null_and_empty() {
+        let mut builder = PolygonBuilder::<i32>::new();
+        builder.push_null();
+        builder.push_empty();
+        let arr = builder.finish();
+        let filled = arr.remove_holes();
+        assert!(filled.is_null(0));
+        assert!(filled.is_valid(1));
+        assert_eq!(filled.geom_offsets.start_end(1), (0, 0));
+    }
+}
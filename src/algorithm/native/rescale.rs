@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{AffineTransform, Rect};
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::{MapCoords, TotalBounds};
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// Rescale all coordinates of an array from its total bounds into a target rectangle.
+///
+/// This is useful to normalize geometries to e.g. `[0, 1]²` for machine learning features, or
+/// to a tile extent before quantizing coordinates. The transform that was applied is returned
+/// alongside the rescaled array, so that coordinates can be mapped back with
+/// [`AffineTransform::inverse`].
+pub trait Rescale {
+    type Output;
+
+    /// Rescale all coordinates of this array so that its total bounds fit in `target`.
+    ///
+    /// If `preserve_aspect_ratio` is `false`, x and y are scaled independently so that the total
+    /// bounds fill `target`. Otherwise the same scale is used for both axes, and the rescaled
+    /// bounds are centered in `target`. An axis along which all coordinates are equal is scaled
+    /// like the other axis, and centered in `target`.
+    ///
+    /// # Errors
+    ///
+    /// - if the array has no non-null coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Rescale;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::{coord, line_string, Rect};
+    ///
+    /// let array: LineStringArray<i32> = vec![line_string![(x: 10., y: 20.), (x: 30., y: 60.)]]
+    ///     .as_slice()
+    ///     .into();
+    /// let unit = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+    ///
+    /// let (rescaled, transform) = array.rescale(&unit, false).unwrap();
+    /// assert_eq!(rescaled.value_as_geo(0), line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+    /// assert_eq!(transform.apply(coord! { x: 20., y: 40. }), coord! { x: 0.5, y: 0.5 });
+    /// ```
+    fn rescale(&self, target: &Rect, preserve_aspect_ratio: bool) -> Self::Output;
+}
+
+/// The transform that maps `bounds` into `target`, see [`Rescale::rescale`].
+fn rescale_transform(
+    bounds: &BoundingRect,
+    target: &Rect,
+    preserve_aspect_ratio: bool,
+) -> Result<AffineTransform> {
+    if bounds.is_empty() {
+        return Err(GeoArrowError::General(
+            "Cannot rescale an array without coordinates".to_string(),
+        ));
+    }
+
+    let (width, height) = (bounds.maxx() - bounds.minx(), bounds.maxy() - bounds.miny());
+    let scale = |target_extent: f64, extent: f64| (extent > 0.).then(|| target_extent / extent);
+    let (mut x_scale, mut y_scale) = (scale(target.width(), width), scale(target.height(), height));
+    if preserve_aspect_ratio {
+        let min_scale = match (x_scale, y_scale) {
+            (Some(x_scale), Some(y_scale)) => Some(x_scale.min(y_scale)),
+            (x_scale, y_scale) => x_scale.or(y_scale),
+        };
+        (x_scale, y_scale) = (min_scale, min_scale);
+    }
+    // Degenerate axes take the scale of the other axis, or 1 if both are degenerate
+    let x_scale = x_scale.or(y_scale).unwrap_or(1.);
+    let y_scale = y_scale.unwrap_or(x_scale);
+
+    // Map the center of the bounds to the center of the target
+    let center = target.center();
+    let x_offset = center.x - x_scale * (bounds.minx() + width / 2.);
+    let y_offset = center.y - y_scale * (bounds.miny() + height / 2.);
+    Ok(AffineTransform::new(
+        x_scale, 0., x_offset, 0., y_scale, y_offset,
+    ))
+}
+
+/// Rescale an array given its total bounds.
+fn rescale_with_bounds<G: MapCoords<Output = G>>(
+    array: &G,
+    bounds: &BoundingRect,
+    target: &Rect,
+    preserve_aspect_ratio: bool,
+) -> Result<(G, AffineTransform)> {
+    let transform = rescale_transform(bounds, target, preserve_aspect_ratio)?;
+    let rescaled = array.map_coords(|x, y| {
+        let coord = transform.apply(geo::coord! { x: x, y: y });
+        (coord.x, coord.y)
+    });
+    Ok((rescaled, transform))
+}
+
+impl Rescale for PointArray {
+    type Output = Result<(Self, AffineTransform)>;
+
+    fn rescale(&self, target: &Rect, preserve_aspect_ratio: bool) -> Self::Output {
+        rescale_with_bounds(self, &self.total_bounds(), target, preserve_aspect_ratio)
+    }
+}
+
+impl Rescale for RectArray {
+    type Output = Result<(Self, AffineTransform)>;
+
+    fn rescale(&self, target: &Rect, preserve_aspect_ratio: bool) -> Self::Output {
+        rescale_with_bounds(self, &self.total_bounds(), target, preserve_aspect_ratio)
+    }
+}
+
+/// Implementation for arrays with an offset type
+macro_rules! offset_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Rescale for $type {
+            type Output = Result<(Self, AffineTransform)>;
+
+            fn rescale(&self, target: &Rect, preserve_aspect_ratio: bool) -> Self::Output {
+                rescale_with_bounds(self, &self.total_bounds(), target, preserve_aspect_ratio)
+            }
+        }
+    };
+}
+
+offset_impl!(LineStringArray<O>);
+offset_impl!(PolygonArray<O>);
+offset_impl!(MultiPointArray<O>);
+offset_impl!(MultiLineStringArray<O>);
+offset_impl!(MultiPolygonArray<O>);
+offset_impl!(MixedGeometryArray<O>);
+offset_impl!(GeometryCollectionArray<O>);
+
+impl Rescale for &dyn GeometryArrayTrait {
+    type Output = Result<(Arc<dyn GeometryArrayTrait>, AffineTransform)>;
+
+    fn rescale(&self, target: &Rect, preserve_aspect_ratio: bool) -> Self::Output {
+        let transform = rescale_transform(&self.total_bounds()?, target, preserve_aspect_ratio)?;
+        let rescaled = self.try_map_coords(|x, y| {
+            let coord = transform.apply(geo::coord! { x: x, y: y });
+            Ok((coord.x, coord.y))
+        })?;
+        Ok((rescaled, transform))
+    }
+}
+
+/// The bounds of all chunks are used, so every chunk is rescaled with the same transform.
+impl<G> Rescale for ChunkedGeometryArray<G>
+where
+    G: GeometryArrayTrait + TotalBounds<Output = BoundingRect> + MapCoords<Output = G>,
+{
+    type Output = Result<(Self, AffineTransform)>;
+
+    fn rescale(&self, target: &Rect, preserve_aspect_ratio: bool) -> Self::Output {
+        rescale_with_bounds(self, &self.total_bounds(), target, preserve_aspect_ratio)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use geo::coord;
+
+    fn unit() -> Rect {
+        Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. })
+    }
+
+    #[test]
+    fn rescale_to_unit() {
+        let array = p_array();
+        let (rescaled, transform) = array.rescale(&unit(), false).unwrap();
+        let bounds = rescaled.total_bounds();
+        assert_eq!(
+            (bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()),
+            (0., 0., 1., 1.)
+        );
+
+        // The inverse maps back to the original coordinates
+        let inverse = transform.inverse().unwrap();
+        let original = array.total_bounds();
+        let corner = inverse.apply(coord! { x: 1., y: 1. });
+        assert!((corner.x - original.maxx()).abs() < 1e-9);
+        assert!((corner.y - original.maxy()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn preserve_aspect_ratio() {
+        // p_array spans 7 by 4 degrees
+        let (rescaled, _) = p_array().rescale(&unit(), true).unwrap();
+        let bounds = rescaled.total_bounds();
+        assert!((bounds.minx() - 0.).abs() < 1e-9 && (bounds.maxx() - 1.).abs() < 1e-9);
+        let height = 4. / 7.;
+        assert!((bounds.miny() - (1. - height) / 2.).abs() < 1e-9);
+        assert!((bounds.maxy() - (1. + height) / 2.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn degenerate_and_empty() {
+        let array: PointArray = vec![geo::point!(x: 5., y: 5.)].as_slice().into();
+        let (rescaled, _) = array.rescale(&unit(), false).unwrap();
+        assert_eq!(rescaled.total_bounds().minx(), 0.5);
+
+        let array = PointArray::new_null(2);
+        assert!(array.rescale(&unit(), false).is_err());
+    }
+}
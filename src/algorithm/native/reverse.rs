@@ -0,0 +1,150 @@
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::OffsetBuffer;
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::{impl_chunked_unary, impl_dyn_unary};
+use crate::trait_::GeometryArraySelfMethods;
+use crate::GeometryArrayTrait;
+
+/// Reverse the order of the coordinates of each line string and ring, e.g. to flip the direction
+/// of lines for rendering or to fix the orientation of polygon rings.
+///
+/// The offsets and validity of the input are reused as they are, and only the coordinate buffer
+/// is rewritten, without constructing any geometries. The order of the parts of multi geometries
+/// and of the interior rings of polygons is unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::Reverse;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::line_string;
+///
+/// let array: LineStringArray<i32> =
+///     vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)]]
+///         .as_slice()
+///         .into();
+/// assert_eq!(
+///     array.reverse().value_as_geo(0),
+///     line_string![(x: 2., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)]
+/// );
+/// ```
+pub trait Reverse {
+    type Output;
+
+    fn reverse(&self) -> Self::Output;
+}
+
+/// Reverse the coordinates within each range of `offsets`. Coordinates outside all ranges, as in
+/// sliced arrays, keep their position.
+fn reverse_ranges<O: OffsetSizeTrait>(
+    coords: &CoordBuffer,
+    offsets: &OffsetBuffer<O>,
+) -> CoordBuffer {
+    let mut indices = (0..coords.len()).collect::<Vec<_>>();
+    (0..offsets.len_proxy()).for_each(|i| {
+        let (start, end) = offsets.start_end(i);
+        indices[start..end].reverse();
+    });
+
+    match coords {
+        CoordBuffer::Interleaved(cb) => {
+            let values = indices
+                .iter()
+                .flat_map(|idx| [cb.coords[idx * 2], cb.coords[idx * 2 + 1]])
+                .collect::<Vec<_>>();
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into()))
+        }
+        CoordBuffer::Separated(cb) => {
+            let x = indices.iter().map(|idx| cb.x[*idx]).collect::<Vec<_>>();
+            let y = indices.iter().map(|idx| cb.y[*idx]).collect::<Vec<_>>();
+            CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), y.into()))
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait> Reverse for LineStringArray<O> {
+    type Output = Self;
+
+    fn reverse(&self) -> Self::Output {
+        let coords = reverse_ranges(&self.coords, &self.geom_offsets);
+        self.clone().with_coords(coords)
+    }
+}
+
+/// Implementation for arrays whose innermost offsets are ring offsets
+macro_rules! ring_offsets_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Reverse for $type {
+            type Output = Self;
+
+            fn reverse(&self) -> Self::Output {
+                let coords = reverse_ranges(&self.coords, &self.ring_offsets);
+                self.clone().with_coords(coords)
+            }
+        }
+    };
+}
+
+ring_offsets_impl!(PolygonArray<O>);
+ring_offsets_impl!(MultiLineStringArray<O>);
+ring_offsets_impl!(MultiPolygonArray<O>);
+
+impl_dyn_unary!(Reverse -> Arc<dyn GeometryArrayTrait> {
+    fn reverse(&self);
+} [
+    LineString => as_line_string,
+    LargeLineString => as_large_line_string,
+    Polygon => as_polygon,
+    LargePolygon => as_large_polygon,
+    MultiLineString => as_multi_line_string,
+    LargeMultiLineString => as_large_multi_line_string,
+    MultiPolygon => as_multi_polygon,
+    LargeMultiPolygon => as_large_multi_polygon,
+]);
+
+impl_chunked_unary!(Reverse {
+    fn reverse(&self);
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Winding;
+    use crate::array::CoordType;
+    use crate::test::{multipolygon, polygon};
+    use crate::trait_::GeometryArrayAccessor;
+
+    #[test]
+    fn reverses_rings() {
+        let array = polygon::p_array();
+        let reversed = array.reverse();
+        for (input, output) in array.iter_geo_values().zip(reversed.iter_geo_values()) {
+            let mut exterior = input.exterior().clone();
+            exterior.0.reverse();
+            assert_eq!(output.exterior(), &exterior);
+            assert_eq!(output.interiors().len(), input.interiors().len());
+        }
+        assert_ne!(array.is_ccw().values(), reversed.is_ccw().values());
+        assert_eq!(reversed.reverse(), array);
+
+        let dyn_array: &dyn GeometryArrayTrait = &array;
+        assert_eq!(dyn_array.reverse().unwrap().len(), array.len());
+    }
+
+    #[test]
+    fn sliced_and_separated() {
+        let array = multipolygon::mp_array();
+        let separated = array
+            .clone()
+            .into_coord_type(CoordType::Separated)
+            .slice(1, 1);
+        assert_eq!(
+            separated.reverse().value_as_geo(0),
+            array.slice(1, 1).reverse().value_as_geo(0)
+        );
+        assert_eq!(separated.reverse().reverse(), separated);
+    }
+}
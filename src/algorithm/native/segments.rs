@@ -0,0 +1,261 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::{OffsetSizeTrait, UInt64Array};
+use geo::sweep::{Cross, LineOrPoint};
+use geo::{Coord, Line};
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// Split linear and polygonal geometries into their two-point segments.
+///
+/// Returns a [`LineStringArray`] where each geometry is a single segment, along with the index of
+/// the input geometry that each segment came from. These indices can be passed into a
+/// [`take`][arrow::compute::take] operation to carry attributes over to the segments. Polygon
+/// segments include the segments of interior rings. Null and empty geometries produce no
+/// segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+///
+/// use geoarrow::algorithm::native::Segments;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::GeometryArrayTrait;
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+/// let array: LineStringArray<i32> = vec![line_string].as_slice().into();
+/// let (segments, parents) = array.segments();
+/// assert_eq!(segments.len(), 2);
+/// assert_eq!(parents.values().as_ref(), &[0, 0]);
+/// ```
+pub trait Segments {
+    type Output;
+
+    fn segments(&self) -> Self::Output;
+}
+
+/// Build segments from the coordinate ranges of each linear ring or line string, tagged with the
+/// index of their parent geometry.
+fn segments_from_ranges<O: OffsetSizeTrait>(
+    array: &impl GeometryArrayTrait,
+    coords: &CoordBuffer,
+    ranges: Vec<(usize, Range<usize>)>,
+) -> (LineStringArray<O>, UInt64Array) {
+    let num_segments = ranges
+        .iter()
+        .map(|(_, range)| range.len().saturating_sub(1))
+        .sum();
+
+    let capacity = LineStringCapacity::new(num_segments * 2, num_segments);
+    let mut builder = LineStringBuilder::with_capacity_and_options(
+        capacity,
+        array.coord_type(),
+        array.metadata(),
+    );
+    let mut parents = Vec::with_capacity(num_segments);
+
+    for (parent, range) in ranges {
+        for coord_idx in range.start..range.end.saturating_sub(1) {
+            // Offsets can't overflow, as there are at most as many coordinates as in the input
+            unsafe {
+                builder.push_xy(coords.get_x(coord_idx), coords.get_y(coord_idx));
+                builder.push_xy(coords.get_x(coord_idx + 1), coords.get_y(coord_idx + 1));
+            }
+            builder.try_push_length(2).unwrap();
+            parents.push(parent as u64);
+        }
+    }
+
+    (builder.finish(), UInt64Array::new(parents.into(), None))
+}
+
+impl<O: OffsetSizeTrait> Segments for LineStringArray<O> {
+    type Output = (LineStringArray<O>, UInt64Array);
+
+    fn segments(&self) -> Self::Output {
+        let ranges = (0..self.len())
+            .filter(|i| self.is_valid(*i))
+            .map(|i| {
+                let (start, end) = self.geom_offsets.start_end(i);
+                (i, start..end)
+            })
+            .collect();
+        segments_from_ranges(self, &self.coords, ranges)
+    }
+}
+
+/// Implementation for arrays with two levels of offsets, where each geometry has a list of rings
+/// or line strings
+macro_rules! two_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Segments for $type {
+            type Output = (LineStringArray<O>, UInt64Array);
+
+            fn segments(&self) -> Self::Output {
+                let mut ranges = vec![];
+                for i in (0..self.len()).filter(|i| self.is_valid(*i)) {
+                    let (start, end) = self.geom_offsets.start_end(i);
+                    for ring_idx in start..end {
+                        let (start, end) = self.ring_offsets.start_end(ring_idx);
+                        ranges.push((i, start..end));
+                    }
+                }
+                segments_from_ranges(self, &self.coords, ranges)
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>);
+two_level_impl!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> Segments for MultiPolygonArray<O> {
+    type Output = (LineStringArray<O>, UInt64Array);
+
+    fn segments(&self) -> Self::Output {
+        let mut ranges = vec![];
+        for i in (0..self.len()).filter(|i| self.is_valid(*i)) {
+            let (start, end) = self.geom_offsets.start_end(i);
+            for polygon_idx in start..end {
+                let (start, end) = self.polygon_offsets.start_end(polygon_idx);
+                for ring_idx in start..end {
+                    let (start, end) = self.ring_offsets.start_end(ring_idx);
+                    ranges.push((i, start..end));
+                }
+            }
+        }
+        segments_from_ranges(self, &self.coords, ranges)
+    }
+}
+
+impl Segments for &dyn GeometryArrayTrait {
+    type Output = Result<(Arc<dyn GeometryArrayTrait>, UInt64Array)>;
+
+    fn segments(&self) -> Self::Output {
+        macro_rules! impl_segments {
+            ($array:expr) => {{
+                let (segments, parents) = $array.segments();
+                (Arc::new(segments) as Arc<dyn GeometryArrayTrait>, parents)
+            }};
+        }
+
+        let result = match self.data_type() {
+            GeoDataType::LineString(_) => impl_segments!(self.as_line_string()),
+            GeoDataType::LargeLineString(_) => impl_segments!(self.as_large_line_string()),
+            GeoDataType::Polygon(_) => impl_segments!(self.as_polygon()),
+            GeoDataType::LargePolygon(_) => impl_segments!(self.as_large_polygon()),
+            GeoDataType::MultiLineString(_) => impl_segments!(self.as_multi_line_string()),
+            GeoDataType::LargeMultiLineString(_) => {
+                impl_segments!(self.as_large_multi_line_string())
+            }
+            GeoDataType::MultiPolygon(_) => impl_segments!(self.as_multi_polygon()),
+            GeoDataType::LargeMultiPolygon(_) => impl_segments!(self.as_large_multi_polygon()),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+/// A segment of one part of some linework, tagged with its position, for finding intersections
+/// with a planar sweep.
+#[derive(Debug, Clone)]
+pub(crate) struct PartSegment {
+    pub(crate) line: Line,
+    /// The index of the part the segment belongs to.
+    pub(crate) part: usize,
+    /// The index of the segment within its part.
+    pub(crate) index: usize,
+    /// The number of segments in the part, if it is closed.
+    closed_len: Option<usize>,
+}
+
+impl Cross for PartSegment {
+    type Scalar = f64;
+
+    fn line(&self) -> LineOrPoint<f64> {
+        self.line.into()
+    }
+}
+
+impl PartSegment {
+    /// The segments of each part. The parts must not have repeated consecutive coordinates.
+    pub(crate) fn from_parts<'a>(parts: impl Iterator<Item = &'a [Coord]>) -> Vec<Self> {
+        let mut segments = vec![];
+        for (part, coords) in parts.enumerate() {
+            let closed_len =
+                (coords.len() > 2 && coords.first() == coords.last()).then_some(coords.len() - 1);
+            segments.extend(coords.windows(2).enumerate().map(|(index, pair)| Self {
+                line: Line::new(pair[0], pair[1]),
+                part,
+                index,
+                closed_len,
+            }));
+        }
+        segments
+    }
+
+    /// Whether two segments are consecutive in the same part, and so share a vertex.
+    pub(crate) fn is_adjacent(&self, other: &Self) -> bool {
+        if self.part != other.part {
+            return false;
+        }
+        let (first, last) = (self.index.min(other.index), self.index.max(other.index));
+        last - first == 1 || self.closed_len == Some(last + 1) && first == 0 && last > 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::linestring::{ls0, ls1};
+    use crate::test::multipolygon::mp_array;
+    use crate::test::polygon::p_array;
+    use crate::trait_::GeometryArrayAccessor;
+    use geo::{CoordsIter, Line, LineString};
+
+    #[test]
+    fn line_string_segments() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let (segments, parents) = arr.segments();
+
+        let expected: Vec<(u64, LineString)> = [(0, ls0()), (2, ls1())]
+            .into_iter()
+            .flat_map(|(i, ls)| {
+                ls.lines()
+                    .map(move |line: Line| (i, line.into()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(segments.len(), expected.len());
+        for (j, (parent, line)) in expected.into_iter().enumerate() {
+            assert_eq!(parents.value(j), parent);
+            assert_eq!(segments.value_as_geo(j), line);
+        }
+    }
+
+    #[test]
+    fn polygonal_segments() {
+        let arr = p_array();
+        let (segments, parents) = arr.segments();
+        let num_rings: usize = arr.iter_geo_values().map(|p| p.interiors().len() + 1).sum();
+        let num_coords: usize = arr.iter_geo_values().map(|p| p.coords_count()).sum();
+        assert_eq!(segments.len(), num_coords - num_rings);
+        assert_eq!(parents.value(segments.len() - 1), 1);
+
+        let arr = mp_array();
+        let (segments, _) = arr.segments();
+        let num_rings: usize = arr
+            .iter_geo_values()
+            .flat_map(|mp| mp.0)
+            .map(|p| p.interiors().len() + 1)
+            .sum();
+        let num_coords: usize = arr.iter_geo_values().map(|p| p.coords_count()).sum();
+        assert_eq!(segments.len(), num_coords - num_rings);
+    }
+}
@@ -0,0 +1,294 @@
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+use geo::{Coord, LineString, MultiLineString, MultiPolygon};
+
+use crate::algorithm::native::line_merge::merge_line_strings;
+use crate::algorithm::rstar::envelope_tree;
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The result of [`AdjacentSharedPaths`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedPathPairs<O: OffsetSizeTrait> {
+    /// The index of the first geometry of each pair.
+    pub left: UInt32Array,
+
+    /// The index of the second geometry of each pair, always larger than the first.
+    pub right: UInt32Array,
+
+    /// The linework shared by each pair.
+    pub paths: MultiLineStringArray<O>,
+}
+
+/// Extract the linework shared by the boundaries of corresponding polygons of two arrays.
+///
+/// A boundary segment of one polygon is shared where it overlaps a collinear boundary segment of
+/// the other polygon, so neighbouring polygons don't need to have identical vertices along their
+/// common border. Shared pieces are merged into maximal line strings, which may run in either
+/// direction. Boundaries that only cross or touch at points are not shared. A null geometry on
+/// either side gives a null output.
+///
+/// This is useful for checking that adjacent polygons, like administrative units, agree on their
+/// common borders.
+pub trait SharedPaths<Rhs = Self> {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::SharedPaths;
+    /// use geoarrow::array::PolygonArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::{line_string, polygon};
+    ///
+    /// let left: PolygonArray<i32> = vec![
+    ///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let right: PolygonArray<i32> = vec![
+    ///     polygon![(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 2., y: 3.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    ///
+    /// let shared = left.shared_paths(&right).unwrap();
+    /// assert_eq!(shared.value_as_geo(0).0, vec![line_string![(x: 2., y: 1.), (x: 2., y: 2.)]]);
+    /// ```
+    fn shared_paths(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Extract the linework shared by each pair of polygons within one array.
+///
+/// Candidate pairs are found with an R-Tree of bounding boxes, so only polygons whose bounding
+/// boxes intersect are compared. Only pairs with a non-empty shared path are returned. See
+/// [`SharedPaths`] for how shared linework is determined.
+pub trait AdjacentSharedPaths {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::AdjacentSharedPaths;
+    /// use geoarrow::array::PolygonArray;
+    /// use geo::polygon;
+    ///
+    /// let array: PolygonArray<i32> = vec![
+    ///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+    ///     polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)],
+    ///     polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    ///
+    /// let pairs = array.adjacent_shared_paths();
+    /// assert_eq!(pairs.left.values().to_vec(), vec![0]);
+    /// assert_eq!(pairs.right.values().to_vec(), vec![1]);
+    /// ```
+    fn adjacent_shared_paths(&self) -> Self::Output;
+}
+
+fn cross(a: Coord, b: Coord) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Coord, b: Coord) -> f64 {
+    a.x * b.x + a.y * b.y
+}
+
+/// The part of segment `(p, q)` that overlaps the collinear segment `(r, s)`, if any.
+///
+/// Endpoints of the overlap are taken from the input coordinates rather than interpolated, so
+/// that pieces of consecutive segments share exactly equal endpoints.
+fn segment_overlap(p: Coord, q: Coord, r: Coord, s: Coord) -> Option<(Coord, Coord)> {
+    let d = q - p;
+    let length_2 = dot(d, d);
+    if length_2 == 0. {
+        return None;
+    }
+    // Both endpoints of the other segment must lie on the line through this segment
+    let tolerance = 1e-10 * length_2;
+    if cross(d, r - p).abs() > tolerance || cross(d, s - p).abs() > tolerance {
+        return None;
+    }
+
+    let t_r = (dot(r - p, d) / length_2, r);
+    let t_s = (dot(s - p, d) / length_2, s);
+    let (lower, upper) = if t_r.0 <= t_s.0 {
+        (t_r, t_s)
+    } else {
+        (t_s, t_r)
+    };
+    let start = if lower.0 > 0. { lower } else { (0., p) };
+    let end = if upper.0 < 1. { upper } else { (1., q) };
+    (end.0 - start.0 > 1e-12).then_some((start.1, end.1))
+}
+
+/// The boundary segments of a multi polygon.
+fn boundary_segments(geom: &MultiPolygon) -> Vec<(Coord, Coord)> {
+    geom.iter()
+        .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .flat_map(|ring| ring.lines().map(|line| (line.start, line.end)))
+        .collect()
+}
+
+/// The linework shared by the boundaries of two multi polygons.
+fn shared_linework(left: &MultiPolygon, right: &MultiPolygon) -> MultiLineString {
+    let right_segments = boundary_segments(right);
+    let mut pieces = vec![];
+    for (p, q) in boundary_segments(left) {
+        let (min_x, max_x) = (p.x.min(q.x), p.x.max(q.x));
+        let (min_y, max_y) = (p.y.min(q.y), p.y.max(q.y));
+        for (r, s) in right_segments.iter() {
+            if r.x.max(s.x) < min_x
+                || r.x.min(s.x) > max_x
+                || r.y.max(s.y) < min_y
+                || r.y.min(s.y) > max_y
+            {
+                continue;
+            }
+            if let Some((start, end)) = segment_overlap(p, q, *r, *s) {
+                pieces.push(LineString::new(vec![start, end]));
+            }
+        }
+    }
+    MultiLineString::new(merge_line_strings(pieces))
+}
+
+macro_rules! impl_shared_paths {
+    ($first:ty, $second:ty) => {
+        impl<O: OffsetSizeTrait> SharedPaths<$second> for $first {
+            type Output = Result<MultiLineStringArray<O>>;
+
+            fn shared_paths(&self, rhs: &$second) -> Self::Output {
+                if self.len() != rhs.len() {
+                    return Err(GeoArrowError::General(
+                        "Arrays must have the same length".to_string(),
+                    ));
+                }
+
+                let output_geoms: Vec<Option<MultiLineString>> = self
+                    .iter_geo()
+                    .zip(rhs.iter_geo())
+                    .map(|(left, right)| Some(shared_linework(&left?.into(), &right?.into())))
+                    .collect();
+                Ok(output_geoms.into())
+            }
+        }
+    };
+}
+
+impl_shared_paths!(PolygonArray<O>, PolygonArray<O>);
+impl_shared_paths!(PolygonArray<O>, MultiPolygonArray<O>);
+impl_shared_paths!(MultiPolygonArray<O>, PolygonArray<O>);
+impl_shared_paths!(MultiPolygonArray<O>, MultiPolygonArray<O>);
+
+macro_rules! impl_adjacent_shared_paths {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> AdjacentSharedPaths for $type {
+            type Output = SharedPathPairs<O>;
+
+            fn adjacent_shared_paths(&self) -> Self::Output {
+                let tree = envelope_tree(self);
+                let mut candidates: Vec<(usize, usize)> = tree
+                    .intersection_candidates_with_other_tree(&tree)
+                    .map(|(left, right)| (left.data, right.data))
+                    .filter(|(left, right)| left < right)
+                    .collect();
+                candidates.sort_unstable();
+
+                let mut left_indices = vec![];
+                let mut right_indices = vec![];
+                let mut paths = vec![];
+                for (left, right) in candidates {
+                    let shared = shared_linework(
+                        &self.value_as_geo(left).into(),
+                        &self.value_as_geo(right).into(),
+                    );
+                    if !shared.0.is_empty() {
+                        left_indices.push(left as u32);
+                        right_indices.push(right as u32);
+                        paths.push(shared);
+                    }
+                }
+
+                SharedPathPairs {
+                    left: left_indices.into(),
+                    right: right_indices.into(),
+                    paths: paths.as_slice().into(),
+                }
+            }
+        }
+    };
+}
+
+impl_adjacent_shared_paths!(PolygonArray<O>);
+impl_adjacent_shared_paths!(MultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{coord, line_string, polygon, Polygon};
+
+    #[test]
+    fn partial_overlap_without_shared_vertices() {
+        let overlap = segment_overlap(
+            coord! { x: 0., y: 0. },
+            coord! { x: 4., y: 0. },
+            coord! { x: 5., y: 0. },
+            coord! { x: 1., y: 0. },
+        );
+        assert_eq!(
+            overlap,
+            Some((coord! { x: 1., y: 0. }, coord! { x: 4., y: 0. }))
+        );
+
+        // Touching at a point, and parallel but offset
+        assert!(segment_overlap(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 2., y: 0. },
+        )
+        .is_none());
+        assert!(segment_overlap(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 0., y: 1. },
+            coord! { x: 1., y: 1. },
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn shared_border_is_merged() {
+        // The right polygon has an extra vertex along the shared border
+        let left: Polygon =
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 2.), (x: 0., y: 2.)];
+        let right: Polygon = polygon![
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 1., y: 2.),
+            (x: 1., y: 1.),
+        ];
+        let left_array: PolygonArray<i32> = vec![Some(left.clone()), None].into();
+        let right_array: PolygonArray<i32> = vec![Some(right.clone()), Some(right)].into();
+
+        let shared = left_array.shared_paths(&right_array).unwrap();
+        let mut paths = shared.value_as_geo(0).0;
+        assert_eq!(paths.len(), 1);
+        if paths[0].0[0] != coord! { x: 1., y: 0. } {
+            paths[0].0.reverse();
+        }
+        assert_eq!(
+            paths[0],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 1., y: 2.)]
+        );
+        assert!(shared.is_null(1));
+
+        let short: PolygonArray<i32> = vec![left].as_slice().into();
+        assert!(short.shared_paths(&right_array).is_err());
+    }
+}
@@ -0,0 +1,334 @@
+//! Vectorized kernels over raw coordinate buffers.
+//!
+//! These kernels operate directly on the `f64` slices backing a [`CoordBuffer`], processing
+//! coordinates in fixed-size lanes so that the compiler can emit SIMD instructions. Each kernel is
+//! compiled twice: once for the baseline target and, on `x86_64`, once with AVX2 enabled. The AVX2
+//! version is selected at runtime if the CPU supports it.
+//!
+//! The kernels only use the same sequence of floating point operations as their scalar
+//! equivalents (no fused multiply-add), so results are bit-for-bit identical to the scalar code
+//! paths.
+
+use crate::array::{CoordBuffer, InterleavedCoordBuffer, SeparatedCoordBuffer};
+
+/// The number of coordinates processed per iteration of the inner loops.
+const LANES: usize = 4;
+
+/// Call the AVX2 version of a kernel if supported by the current CPU, otherwise fall back to the
+/// portable version.
+macro_rules! dispatch {
+    ($kernel:ident($($arg:expr),*)) => {{
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                // SAFETY: we just checked that the CPU supports AVX2.
+                return unsafe { avx2::$kernel($($arg),*) };
+            }
+        }
+        portable::$kernel($($arg),*)
+    }};
+}
+
+mod portable {
+    use super::LANES;
+
+    #[inline(always)]
+    pub(super) fn map_interleaved<F: Fn(f64, f64) -> (f64, f64)>(
+        values: &[f64],
+        op: &F,
+    ) -> Vec<f64> {
+        let mut out = vec![0.; values.len()];
+        let mut src_chunks = values.chunks_exact(2 * LANES);
+        let mut dst_chunks = out.chunks_exact_mut(2 * LANES);
+        for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+            for (src_xy, dst_xy) in src.chunks_exact(2).zip(dst.chunks_exact_mut(2)) {
+                let (x, y) = op(src_xy[0], src_xy[1]);
+                dst_xy[0] = x;
+                dst_xy[1] = y;
+            }
+        }
+        for (src_xy, dst_xy) in src_chunks
+            .remainder()
+            .chunks_exact(2)
+            .zip(dst_chunks.into_remainder().chunks_exact_mut(2))
+        {
+            let (x, y) = op(src_xy[0], src_xy[1]);
+            dst_xy[0] = x;
+            dst_xy[1] = y;
+        }
+        out
+    }
+
+    #[inline(always)]
+    pub(super) fn map_separated<F: Fn(f64, f64) -> (f64, f64)>(
+        x: &[f64],
+        y: &[f64],
+        op: &F,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let mut out_x = vec![0.; x.len()];
+        let mut out_y = vec![0.; y.len()];
+        for (((x, y), out_x), out_y) in x
+            .chunks(LANES)
+            .zip(y.chunks(LANES))
+            .zip(out_x.chunks_mut(LANES))
+            .zip(out_y.chunks_mut(LANES))
+        {
+            for (((x, y), out_x), out_y) in x.iter().zip(y).zip(out_x).zip(out_y) {
+                (*out_x, *out_y) = op(*x, *y);
+            }
+        }
+        (out_x, out_y)
+    }
+
+    /// Returns `[minx, miny, maxx, maxy]`
+    #[inline(always)]
+    pub(super) fn bounds_interleaved(values: &[f64]) -> [f64; 4] {
+        let mut min_x = [f64::INFINITY; LANES];
+        let mut min_y = [f64::INFINITY; LANES];
+        let mut max_x = [f64::NEG_INFINITY; LANES];
+        let mut max_y = [f64::NEG_INFINITY; LANES];
+
+        let mut chunks = values.chunks_exact(2 * LANES);
+        for chunk in &mut chunks {
+            for (lane, xy) in chunk.chunks_exact(2).enumerate() {
+                min_x[lane] = min_x[lane].min(xy[0]);
+                min_y[lane] = min_y[lane].min(xy[1]);
+                max_x[lane] = max_x[lane].max(xy[0]);
+                max_y[lane] = max_y[lane].max(xy[1]);
+            }
+        }
+        for xy in chunks.remainder().chunks_exact(2) {
+            min_x[0] = min_x[0].min(xy[0]);
+            min_y[0] = min_y[0].min(xy[1]);
+            max_x[0] = max_x[0].max(xy[0]);
+            max_y[0] = max_y[0].max(xy[1]);
+        }
+
+        reduce_bounds(min_x, min_y, max_x, max_y)
+    }
+
+    /// Returns `[minx, miny, maxx, maxy]`
+    #[inline(always)]
+    pub(super) fn bounds_separated(x: &[f64], y: &[f64]) -> [f64; 4] {
+        let mut min_x = [f64::INFINITY; LANES];
+        let mut min_y = [f64::INFINITY; LANES];
+        let mut max_x = [f64::NEG_INFINITY; LANES];
+        let mut max_y = [f64::NEG_INFINITY; LANES];
+
+        for (x, y) in x.chunks(LANES).zip(y.chunks(LANES)) {
+            for (lane, (x, y)) in x.iter().zip(y).enumerate() {
+                min_x[lane] = min_x[lane].min(*x);
+                min_y[lane] = min_y[lane].min(*y);
+                max_x[lane] = max_x[lane].max(*x);
+                max_y[lane] = max_y[lane].max(*y);
+            }
+        }
+
+        reduce_bounds(min_x, min_y, max_x, max_y)
+    }
+
+    #[inline(always)]
+    fn reduce_bounds(
+        min_x: [f64; LANES],
+        min_y: [f64; LANES],
+        max_x: [f64; LANES],
+        max_y: [f64; LANES],
+    ) -> [f64; 4] {
+        [
+            min_x.into_iter().fold(f64::INFINITY, f64::min),
+            min_y.into_iter().fold(f64::INFINITY, f64::min),
+            max_x.into_iter().fold(f64::NEG_INFINITY, f64::max),
+            max_y.into_iter().fold(f64::NEG_INFINITY, f64::max),
+        ]
+    }
+
+    #[inline(always)]
+    pub(super) fn deinterleave(values: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let len = values.len() / 2;
+        let mut x = vec![0.; len];
+        let mut y = vec![0.; len];
+        for ((src, x), y) in values
+            .chunks(2 * LANES)
+            .zip(x.chunks_mut(LANES))
+            .zip(y.chunks_mut(LANES))
+        {
+            for ((xy, x), y) in src.chunks_exact(2).zip(x).zip(y) {
+                *x = xy[0];
+                *y = xy[1];
+            }
+        }
+        (x, y)
+    }
+
+    #[inline(always)]
+    pub(super) fn interleave(x: &[f64], y: &[f64]) -> Vec<f64> {
+        let mut values = vec![0.; x.len() * 2];
+        for ((dst, x), y) in values
+            .chunks_mut(2 * LANES)
+            .zip(x.chunks(LANES))
+            .zip(y.chunks(LANES))
+        {
+            for ((xy, x), y) in dst.chunks_exact_mut(2).zip(x).zip(y) {
+                xy[0] = *x;
+                xy[1] = *y;
+            }
+        }
+        values
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::portable;
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn map_interleaved<F: Fn(f64, f64) -> (f64, f64)>(
+        values: &[f64],
+        op: &F,
+    ) -> Vec<f64> {
+        portable::map_interleaved(values, op)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn map_separated<F: Fn(f64, f64) -> (f64, f64)>(
+        x: &[f64],
+        y: &[f64],
+        op: &F,
+    ) -> (Vec<f64>, Vec<f64>) {
+        portable::map_separated(x, y, op)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn bounds_interleaved(values: &[f64]) -> [f64; 4] {
+        portable::bounds_interleaved(values)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn bounds_separated(x: &[f64], y: &[f64]) -> [f64; 4] {
+        portable::bounds_separated(x, y)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn deinterleave(values: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        portable::deinterleave(values)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn interleave(x: &[f64], y: &[f64]) -> Vec<f64> {
+        portable::interleave(x, y)
+    }
+}
+
+fn map_interleaved<F: Fn(f64, f64) -> (f64, f64)>(values: &[f64], op: &F) -> Vec<f64> {
+    dispatch!(map_interleaved(values, op))
+}
+
+fn map_separated<F: Fn(f64, f64) -> (f64, f64)>(
+    x: &[f64],
+    y: &[f64],
+    op: &F,
+) -> (Vec<f64>, Vec<f64>) {
+    dispatch!(map_separated(x, y, op))
+}
+
+fn bounds_interleaved(values: &[f64]) -> [f64; 4] {
+    dispatch!(bounds_interleaved(values))
+}
+
+fn bounds_separated(x: &[f64], y: &[f64]) -> [f64; 4] {
+    dispatch!(bounds_separated(x, y))
+}
+
+/// Split an interleaved `xyxyxy` buffer into separate `xxx` and `yyy` buffers.
+pub(crate) fn deinterleave(values: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    dispatch!(deinterleave(values))
+}
+
+/// Combine separate `xxx` and `yyy` buffers into an interleaved `xyxyxy` buffer.
+pub(crate) fn interleave(x: &[f64], y: &[f64]) -> Vec<f64> {
+    dispatch!(interleave(x, y))
+}
+
+/// Apply `op` to every coordinate of the buffer, returning a new buffer with the same coordinate
+/// type.
+///
+/// `op` should be cheap and free of branches for the compiler to be able to vectorize it.
+pub(crate) fn map_xy<F: Fn(f64, f64) -> (f64, f64)>(coords: &CoordBuffer, op: F) -> CoordBuffer {
+    match coords {
+        CoordBuffer::Interleaved(cb) => CoordBuffer::Interleaved(InterleavedCoordBuffer::new(
+            map_interleaved(&cb.coords, &op).into(),
+        )),
+        CoordBuffer::Separated(cb) => {
+            let (x, y) = map_separated(&cb.x, &cb.y, &op);
+            CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), y.into()))
+        }
+    }
+}
+
+/// The bounds `[minx, miny, maxx, maxy]` of the coordinates `start..end` of the buffer.
+///
+/// NaN values are ignored. If the range is empty, the mins are `+∞` and the maxes are `-∞`.
+pub(crate) fn bounds(coords: &CoordBuffer, start: usize, end: usize) -> [f64; 4] {
+    match coords {
+        CoordBuffer::Interleaved(cb) => bounds_interleaved(&cb.coords[start * 2..end * 2]),
+        CoordBuffer::Separated(cb) => bounds_separated(&cb.x[start..end], &cb.y[start..end]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values(n: usize) -> Vec<f64> {
+        (0..n * 2).map(|i| i as f64 * 0.5 - 3.).collect()
+    }
+
+    #[test]
+    fn interleave_roundtrip() {
+        // Use a length that isn't a multiple of the lane count to exercise the remainder
+        let values = values(11);
+        let (x, y) = deinterleave(&values);
+        assert_eq!(x.len(), 11);
+        assert_eq!(x[3], values[6]);
+        assert_eq!(y[3], values[7]);
+        assert_eq!(interleave(&x, &y), values);
+        assert_eq!(portable::deinterleave(&values), (x, y));
+    }
+
+    #[test]
+    fn map_matches_scalar() {
+        let values = values(13);
+        let op = |x: f64, y: f64| (x * 2. + y * 0.1 + 3., y - x);
+        let expected = values
+            .chunks_exact(2)
+            .flat_map(|xy| {
+                let (x, y) = op(xy[0], xy[1]);
+                [x, y]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(map_interleaved(&values, &op), expected);
+
+        let (x, y) = deinterleave(&values);
+        let (out_x, out_y) = map_separated(&x, &y, &op);
+        assert_eq!(interleave(&out_x, &out_y), expected);
+    }
+
+    #[test]
+    fn bounds_ignore_nan() {
+        let mut values = values(9);
+        values[4] = f64::NAN;
+        let expected = [-3., -2.5, 5., 5.5];
+        assert_eq!(bounds_interleaved(&values), expected);
+        let (x, y) = deinterleave(&values);
+        assert_eq!(bounds_separated(&x, &y), expected);
+        assert_eq!(
+            bounds_interleaved(&[]),
+            [
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY
+            ]
+        );
+    }
+}
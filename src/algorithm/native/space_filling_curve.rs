@@ -0,0 +1,260 @@
+use arrow_array::{OffsetSizeTrait, UInt64Array};
+
+use crate::algorithm::geo::BoundingRect as GeoBoundingRect;
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::TotalBounds;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::error::Result;
+use crate::geo_traits::{CoordTrait, RectTrait};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Compute the position of each geometry along a space-filling curve.
+///
+/// The center of the bounding box of each geometry is scaled to a 2^32 x 2^32 grid covering the
+/// total bounds of the array, and the position of that grid cell along the curve is returned.
+/// Sorting by these indices places geometries that are near each other in space near each other
+/// in the array. Null geometries have a null index.
+///
+/// Since the grid covers the total bounds of the array, indices computed for different arrays are
+/// not comparable. For chunked arrays, the grid covers the total bounds of all chunks.
+pub trait SpaceFillingCurve {
+    type Output;
+
+    /// The index of each geometry along a [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::SpaceFillingCurve;
+    /// use geoarrow::array::PointArray;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![
+    ///     point!(x: 0., y: 0.),
+    ///     point!(x: 0., y: 1.),
+    ///     point!(x: 1., y: 1.),
+    ///     point!(x: 1., y: 0.),
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let indices = array.hilbert_indices();
+    /// assert!(indices.values().windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    fn hilbert_indices(&self) -> Self::Output;
+
+    /// The index of each geometry along a [Z-order curve](https://en.wikipedia.org/wiki/Z-order_curve).
+    fn zorder_indices(&self) -> Self::Output;
+}
+
+/// The position of grid cell `(x, y)` along a Hilbert curve filling a 2^32 x 2^32 grid.
+fn hilbert_index(mut x: u32, mut y: u32) -> u64 {
+    let mut index = 0;
+    let mut s: u32 = 1 << 31;
+    while s > 0 {
+        let rx = (x & s > 0) as u64;
+        let ry = (y & s > 0) as u64;
+        index += (s as u64) * (s as u64) * ((3 * rx) ^ ry);
+
+        // Rotate the quadrant so that the curve is continuous
+        if ry == 0 {
+            if rx == 1 {
+                x = u32::MAX - x;
+                y = u32::MAX - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s >>= 1;
+    }
+    index
+}
+
+/// Spread the bits of `v` so that there is a zero bit between each of them.
+fn spread_bits(v: u32) -> u64 {
+    let mut v = v as u64;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    v = (v | (v << 1)) & 0x5555_5555_5555_5555;
+    v
+}
+
+/// The position of grid cell `(x, y)` along a Z-order curve, i.e. the interleaved bits of `x`
+/// and `y`.
+fn zorder_index(x: u32, y: u32) -> u64 {
+    spread_bits(x) | (spread_bits(y) << 1)
+}
+
+/// Scale `value` from the range `[min, max]` to the grid.
+fn to_grid(value: f64, min: f64, max: f64) -> u32 {
+    if max > min {
+        ((value - min) / (max - min) * u32::MAX as f64) as u32
+    } else {
+        0
+    }
+}
+
+/// Compute the curve index of the center of each rect, on a grid covering `bounds`.
+fn curve_indices(
+    rects: &RectArray,
+    bounds: &BoundingRect,
+    index_fn: impl Fn(u32, u32) -> u64,
+) -> UInt64Array {
+    rects
+        .iter()
+        .map(|maybe_rect| {
+            maybe_rect.map(|rect| {
+                let (lower, upper) = (rect.lower(), rect.upper());
+                let x = (lower.x() + upper.x()) / 2.;
+                let y = (lower.y() + upper.y()) / 2.;
+                index_fn(
+                    to_grid(x, bounds.minx(), bounds.maxx()),
+                    to_grid(y, bounds.miny(), bounds.maxy()),
+                )
+            })
+        })
+        .collect()
+}
+
+impl SpaceFillingCurve for PointArray {
+    type Output = UInt64Array;
+
+    fn hilbert_indices(&self) -> Self::Output {
+        let rects = self.bounding_rect();
+        curve_indices(&rects, &rects.total_bounds(), hilbert_index)
+    }
+
+    fn zorder_indices(&self) -> Self::Output {
+        let rects = self.bounding_rect();
+        curve_indices(&rects, &rects.total_bounds(), zorder_index)
+    }
+}
+
+impl SpaceFillingCurve for RectArray {
+    type Output = UInt64Array;
+
+    fn hilbert_indices(&self) -> Self::Output {
+        curve_indices(self, &self.total_bounds(), hilbert_index)
+    }
+
+    fn zorder_indices(&self) -> Self::Output {
+        curve_indices(self, &self.total_bounds(), zorder_index)
+    }
+}
+
+/// Implementation that computes the bounding rect of each geometry
+macro_rules! bounding_rect_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> SpaceFillingCurve for $type {
+            type Output = UInt64Array;
+
+            fn hilbert_indices(&self) -> Self::Output {
+                let rects = self.bounding_rect();
+                curve_indices(&rects, &rects.total_bounds(), hilbert_index)
+            }
+
+            fn zorder_indices(&self) -> Self::Output {
+                let rects = self.bounding_rect();
+                curve_indices(&rects, &rects.total_bounds(), zorder_index)
+            }
+        }
+    };
+}
+
+bounding_rect_impl!(LineStringArray<O>);
+bounding_rect_impl!(PolygonArray<O>);
+bounding_rect_impl!(MultiPointArray<O>);
+bounding_rect_impl!(MultiLineStringArray<O>);
+bounding_rect_impl!(MultiPolygonArray<O>);
+bounding_rect_impl!(MixedGeometryArray<O>);
+bounding_rect_impl!(GeometryCollectionArray<O>);
+bounding_rect_impl!(WKBArray<O>);
+
+impl SpaceFillingCurve for &dyn GeometryArrayTrait {
+    type Output = Result<UInt64Array>;
+
+    fn hilbert_indices(&self) -> Self::Output {
+        let rects = self.bounding_rect()?;
+        Ok(curve_indices(&rects, &rects.total_bounds(), hilbert_index))
+    }
+
+    fn zorder_indices(&self) -> Self::Output {
+        let rects = self.bounding_rect()?;
+        Ok(curve_indices(&rects, &rects.total_bounds(), zorder_index))
+    }
+}
+
+/// Compute the curve indices of each chunk on a grid covering all chunks.
+fn chunked_curve_indices(
+    chunk_rects: Vec<RectArray>,
+    index_fn: impl Fn(u32, u32) -> u64 + Copy,
+) -> ChunkedArray<UInt64Array> {
+    let mut bounds = BoundingRect::new();
+    chunk_rects
+        .iter()
+        .for_each(|rects| bounds.update(&rects.total_bounds()));
+    let chunks = chunk_rects
+        .iter()
+        .map(|rects| curve_indices(rects, &bounds, index_fn))
+        .collect();
+    ChunkedArray::new(chunks)
+}
+
+impl<G: GeometryArrayTrait> SpaceFillingCurve for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<UInt64Array>>;
+
+    fn hilbert_indices(&self) -> Self::Output {
+        let chunk_rects = self.try_map(|chunk| chunk.as_ref().bounding_rect())?;
+        Ok(chunked_curve_indices(chunk_rects, hilbert_index))
+    }
+
+    fn zorder_indices(&self) -> Self::Output {
+        let chunk_rects = self.try_map(|chunk| chunk.as_ref().bounding_rect())?;
+        Ok(chunked_curve_indices(chunk_rects, zorder_index))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use arrow_array::Array;
+    use geo::point;
+
+    #[test]
+    fn curve_index_corners() {
+        assert_eq!(hilbert_index(0, 0), 0);
+        assert_eq!(hilbert_index(0, u32::MAX), u64::MAX / 3);
+        assert_eq!(hilbert_index(u32::MAX, 0), u64::MAX);
+
+        assert_eq!(zorder_index(0b11, 0b01), 0b0111);
+        assert_eq!(zorder_index(u32::MAX, u32::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn zorder_quadrants() {
+        let array: PointArray = vec![
+            Some(point!(x: 1., y: 1.)),
+            None,
+            Some(point!(x: 0., y: 0.)),
+            Some(point!(x: 1., y: 0.)),
+            Some(point!(x: 0., y: 1.)),
+        ]
+        .into();
+        let indices = array.zorder_indices();
+        assert!(indices.is_null(1));
+        assert_eq!(indices.value(2), 0);
+        assert!(indices.value(3) < indices.value(4));
+        assert!(indices.value(4) < indices.value(0));
+    }
+
+    #[test]
+    fn chunked_indices_share_grid() {
+        let chunked = ChunkedGeometryArray::new(vec![p_array(), p_array()]);
+        let indices = chunked.hilbert_indices().unwrap();
+        assert_eq!(indices.chunks()[0], p_array().hilbert_indices());
+        assert_eq!(indices.chunks()[0], indices.chunks()[1]);
+    }
+}
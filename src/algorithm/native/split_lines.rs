@@ -0,0 +1,184 @@
+use arrow_array::OffsetSizeTrait;
+use geo::{Coord, LineString, MultiLineString};
+
+use crate::array::{LineStringArray, MultiLineStringArray, MultiPointArray};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The position along a line string closest to a point, as the index of a segment and the
+/// fraction along that segment, together with the distance from the point to that position.
+fn project(coords: &[Coord], point: Coord) -> (usize, f64, f64) {
+    let mut closest = (0, 0., f64::INFINITY);
+    for (segment_idx, segment) in coords.windows(2).enumerate() {
+        let (start, end) = (segment[0], segment[1]);
+        let delta = end - start;
+        let length_2 = delta.x * delta.x + delta.y * delta.y;
+        let t = if length_2 > 0. {
+            let offset = point - start;
+            ((offset.x * delta.x + offset.y * delta.y) / length_2).clamp(0., 1.)
+        } else {
+            0.
+        };
+        let position = start + delta * t;
+        let distance = (point.x - position.x).hypot(point.y - position.y);
+        if distance < closest.2 {
+            closest = (segment_idx, t, distance);
+        }
+    }
+    closest
+}
+
+/// Split a line string at the given points.
+fn split_line_string(
+    line_string: &LineString,
+    points: &[Coord],
+    tolerance: f64,
+) -> Vec<LineString> {
+    let coords = &line_string.0;
+    if coords.len() < 2 {
+        return vec![line_string.clone()];
+    }
+    let last_segment = coords.len() - 2;
+
+    let mut cuts = points
+        .iter()
+        .map(|point| project(coords, *point))
+        .filter(|(_, _, distance)| *distance <= tolerance)
+        .map(|(segment_idx, t, _)| {
+            // Represent vertices as the start of the following segment
+            if t == 1. && segment_idx < last_segment {
+                (segment_idx + 1, 0.)
+            } else {
+                (segment_idx, t)
+            }
+        })
+        // Cutting at the endpoints of the line string does nothing
+        .filter(|cut| *cut != (0, 0.) && *cut != (last_segment, 1.))
+        .collect::<Vec<_>>();
+    cuts.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    cuts.dedup();
+
+    let mut parts = Vec::with_capacity(cuts.len() + 1);
+    let mut current = vec![coords[0]];
+    let mut next_vertex = 1;
+    for (segment_idx, t) in cuts {
+        while next_vertex <= segment_idx {
+            current.push(coords[next_vertex]);
+            next_vertex += 1;
+        }
+        let start = coords[segment_idx];
+        let cut = start + (coords[segment_idx + 1] - start) * t;
+        if current.last() != Some(&cut) {
+            current.push(cut);
+        }
+        parts.push(LineString::new(std::mem::replace(&mut current, vec![cut])));
+    }
+    current.extend_from_slice(&coords[next_vertex..]);
+    parts.push(LineString::new(current));
+    parts
+}
+
+/// Cut each line string at the points of the corresponding multi point.
+///
+/// Each point is projected onto the closest position of its line string, and the line string is
+/// cut there if the point is within `tolerance` of it. Points further away, and points projecting
+/// onto an endpoint of the line string, are ignored. A null line string gives a null output, and
+/// a null multi point leaves its line string uncut.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::split_lines;
+/// use geoarrow::array::{LineStringArray, MultiPointArray};
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::{line_string, point, MultiPoint};
+///
+/// let lines: LineStringArray<i32> =
+///     vec![line_string![(x: 0., y: 0.), (x: 10., y: 0.)]].as_slice().into();
+/// let stops: MultiPointArray<i32> =
+///     vec![MultiPoint::new(vec![point!(x: 4., y: 0.1)])].as_slice().into();
+///
+/// let split = split_lines(&lines, &stops, 0.5).unwrap();
+/// let parts = split.value_as_geo(0);
+/// assert_eq!(parts.0[0], line_string![(x: 0., y: 0.), (x: 4., y: 0.)]);
+/// assert_eq!(parts.0[1], line_string![(x: 4., y: 0.), (x: 10., y: 0.)]);
+/// ```
+pub fn split_lines<O: OffsetSizeTrait>(
+    lines: &LineStringArray<O>,
+    points: &MultiPointArray<O>,
+    tolerance: f64,
+) -> Result<MultiLineStringArray<O>> {
+    if lines.len() != points.len() {
+        return Err(GeoArrowError::General(
+            "Lines and points must have the same length".to_string(),
+        ));
+    }
+
+    let output_geoms: Vec<Option<MultiLineString>> = lines
+        .iter_geo()
+        .zip(points.iter_geo())
+        .map(|(maybe_line, maybe_points)| {
+            maybe_line.map(|line| {
+                let points = maybe_points
+                    .map(|points| points.iter().map(|point| point.0).collect::<Vec<_>>())
+                    .unwrap_or_default();
+                MultiLineString::new(split_line_string(&line, &points, tolerance))
+            })
+        })
+        .collect();
+    Ok(output_geoms.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{coord, line_string, point, MultiPoint};
+
+    #[test]
+    fn split_at_vertices_and_segments() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let points = vec![
+            coord! { x: 2., y: 1. },
+            // At a vertex, and a duplicate of it
+            coord! { x: 2., y: 0. },
+            coord! { x: 2.1, y: 0. },
+            // At an endpoint
+            coord! { x: 0., y: 0. },
+            // Too far away
+            coord! { x: 1., y: 1. },
+        ];
+        let parts = split_line_string(&line_string, &points, 0.5);
+        assert_eq!(
+            parts,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 2., y: 1.)],
+                line_string![(x: 2., y: 1.), (x: 2., y: 2.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn split_lines_nulls() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let lines: LineStringArray<i32> =
+            vec![Some(line_string.clone()), Some(line_string.clone()), None].into();
+        let points: MultiPointArray<i32> = vec![
+            None,
+            Some(MultiPoint::new(vec![point!(x: 1., y: 0.)])),
+            Some(MultiPoint::new(vec![point!(x: 1., y: 0.)])),
+        ]
+        .into();
+        let split = split_lines(&lines, &points, 0.).unwrap();
+        assert_eq!(
+            split.value_as_geo(0),
+            MultiLineString::new(vec![line_string])
+        );
+        assert_eq!(split.value_as_geo(1).0.len(), 2);
+        assert!(split.is_null(2));
+
+        let short: MultiPointArray<i32> = Vec::<Option<MultiPoint>>::new().into();
+        assert!(split_lines(&lines, &short, 0.).is_err());
+    }
+}
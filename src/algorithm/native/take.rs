@@ -5,24 +5,67 @@ use crate::array::*;
 use crate::chunked_array::ChunkedGeometryArray;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
 use crate::GeometryArrayTrait;
-use arrow_array::{OffsetSizeTrait, UInt32Array};
+use arrow::compute::take as arrow_take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::ArrowDictionaryKeyType;
+use arrow_array::{OffsetSizeTrait, PrimitiveArray};
 use arrow_buffer::ArrowNativeType;
 
 /// Take elements by index from Array, creating a new Array from those indexes.
 pub trait Take {
     type Output;
 
-    fn take(&self, indices: &UInt32Array) -> Self::Output;
+    /// Take the geometries at `indices`.
+    ///
+    /// Indices can be of any integer type, e.g. a `UInt32Array` or an `Int32Array`. A null index
+    /// produces a null geometry in the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any index is negative or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Take;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use arrow_array::Int32Array;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)].as_slice().into();
+    /// let taken = array.take(&Int32Array::from(vec![Some(1), None, Some(0)])).unwrap();
+    /// assert_eq!(taken.get_as_geo(0), Some(point!(x: 3., y: 4.)));
+    /// assert_eq!(taken.get_as_geo(1), None);
+    /// assert_eq!(taken.get_as_geo(2), Some(point!(x: 1., y: 2.)));
+    ///
+    /// assert!(array.take(&Int32Array::from(vec![-1])).is_err());
+    /// assert!(array.take(&Int32Array::from(vec![2])).is_err());
+    /// ```
+    fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output;
 
     fn take_range(&self, range: &Range<usize>) -> Self::Output;
 }
 
+/// Check that all non-null take indices are in bounds for an array of length `len`.
+fn check_indices<I: ArrowDictionaryKeyType>(indices: &PrimitiveArray<I>, len: usize) -> Result<()> {
+    for index in indices.iter().flatten() {
+        if !index.to_usize().is_some_and(|index| index < len) {
+            return Err(GeoArrowError::General(format!(
+                "take index {index:?} is out of bounds for an array of length {len}"
+            )));
+        }
+    }
+    Ok(())
+}
+
 impl Take for PointArray {
-    type Output = Self;
+    type Output = Result<Self>;
 
-    fn take(&self, indices: &UInt32Array) -> Self::Output {
+    fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+        check_indices(indices, self.len())?;
         let mut builder = PointBuilder::with_capacity_and_options(
             indices.len(),
             self.coord_type(),
@@ -36,7 +79,7 @@ impl Take for PointArray {
             }
         }
 
-        builder.finish()
+        Ok(builder.finish())
     }
 
     fn take_range(&self, range: &Range<usize>) -> Self::Output {
@@ -48,7 +91,7 @@ impl Take for PointArray {
         for i in range.start..range.end {
             builder.push_point(self.get(i).as_ref());
         }
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
@@ -59,7 +102,8 @@ macro_rules! take_impl {
         impl<O: OffsetSizeTrait> Take for $array_type {
             type Output = Result<Self>;
 
-            fn take(&self, indices: &UInt32Array) -> Self::Output {
+            fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+                check_indices(indices, self.len())?;
                 let mut capacity = <$capacity_type>::new_empty();
 
                 for index in indices.iter().flatten() {
@@ -147,11 +191,12 @@ macro_rules! take_impl_fallible {
         impl<O: OffsetSizeTrait> Take for $array_type {
             type Output = Result<Self>;
 
-            fn take(&self, indices: &UInt32Array) -> Self::Output {
+            fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+                check_indices(indices, self.len())?;
                 let mut capacity = <$capacity_type>::new_empty();
 
                 for index in indices.iter().flatten() {
-                    capacity.$capacity_add_func(self.get(index.as_usize()).as_ref())?;
+                    capacity.$capacity_add_func(self.get(index.as_usize()).as_ref(), false)?;
                 }
 
                 let mut builder = <$builder_type>::with_capacity_and_options(
@@ -175,7 +220,7 @@ macro_rules! take_impl_fallible {
                 let mut capacity = <$capacity_type>::new_empty();
 
                 for i in range.start..range.end {
-                    capacity.$capacity_add_func(self.get(i).as_ref())?;
+                    capacity.$capacity_add_func(self.get(i).as_ref(), false)?;
                 }
 
                 let mut builder = <$builder_type>::with_capacity_and_options(
@@ -209,12 +254,51 @@ take_impl_fallible!(
     push_geometry_collection
 );
 
+impl Take for RectArray {
+    type Output = Result<Self>;
+
+    fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+        check_indices(indices, self.len())?;
+        let mut builder = RectBuilder::with_capacity(indices.len(), self.metadata());
+        for index in indices.iter() {
+            if let Some(index) = index {
+                builder.push_rect(self.get(index.as_usize()).as_ref());
+            } else {
+                builder.push_null();
+            }
+        }
+        Ok(builder.into())
+    }
+
+    fn take_range(&self, range: &Range<usize>) -> Self::Output {
+        Ok(self.slice(range.start, range.end - range.start))
+    }
+}
+
+impl<O: OffsetSizeTrait> Take for WKBArray<O> {
+    type Output = Result<Self>;
+
+    // The WKB buffers are copied as-is without being parsed
+    fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+        check_indices(indices, self.len())?;
+        let array = arrow_take(&self.array, indices, None)?;
+        Ok(WKBArray::new(
+            array.as_binary::<O>().clone(),
+            self.metadata(),
+        ))
+    }
+
+    fn take_range(&self, range: &Range<usize>) -> Self::Output {
+        Ok(self.slice(range.start, range.end - range.start))
+    }
+}
+
 impl Take for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
-    fn take(&self, indices: &UInt32Array) -> Self::Output {
+    fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
         let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
-            GeoDataType::Point(_) => Arc::new(self.as_point().take(indices)),
+            GeoDataType::Point(_) => Arc::new(self.as_point().take(indices)?),
             GeoDataType::LineString(_) => Arc::new(self.as_line_string().take(indices)?),
             GeoDataType::LargeLineString(_) => Arc::new(self.as_large_line_string().take(indices)?),
             GeoDataType::Polygon(_) => Arc::new(self.as_polygon().take(indices)?),
@@ -237,14 +321,16 @@ impl Take for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 Arc::new(self.as_large_geometry_collection().take(indices)?)
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::WKB => Arc::new(self.as_wkb().take(indices)?),
+            GeoDataType::LargeWKB => Arc::new(self.as_large_wkb().take(indices)?),
+            GeoDataType::Rect => Arc::new(self.as_rect().take(indices)?),
         };
         Ok(result)
     }
 
     fn take_range(&self, range: &Range<usize>) -> Self::Output {
         let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
-            GeoDataType::Point(_) => Arc::new(self.as_point().take_range(range)),
+            GeoDataType::Point(_) => Arc::new(self.as_point().take_range(range)?),
             GeoDataType::LineString(_) => Arc::new(self.as_line_string().take_range(range)?),
             GeoDataType::LargeLineString(_) => {
                 Arc::new(self.as_large_line_string().take_range(range)?)
@@ -273,7 +359,9 @@ impl Take for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 Arc::new(self.as_large_geometry_collection().take_range(range)?)
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::WKB => Arc::new(self.as_wkb().take_range(range)?),
+            GeoDataType::LargeWKB => Arc::new(self.as_large_wkb().take_range(range)?),
+            GeoDataType::Rect => Arc::new(self.as_rect().take_range(range)?),
         };
         Ok(result)
     }
@@ -282,22 +370,16 @@ impl Take for &dyn GeometryArrayTrait {
 impl Take for ChunkedGeometryArray<PointArray> {
     type Output = Result<ChunkedGeometryArray<PointArray>>;
 
-    fn take(&self, indices: &UInt32Array) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.take(indices));
-        }
-
-        Ok(ChunkedGeometryArray::new(output_chunks))
+    fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.take(indices))?,
+        ))
     }
 
     fn take_range(&self, range: &Range<usize>) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.take_range(range));
-        }
-
-        Ok(ChunkedGeometryArray::new(output_chunks))
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.take_range(range))?,
+        ))
     }
 }
 
@@ -307,22 +389,16 @@ macro_rules! chunked_impl {
         impl<O: OffsetSizeTrait> Take for $type {
             type Output = Result<$type>;
 
-            fn take(&self, indices: &UInt32Array) -> Self::Output {
-                let mut output_chunks = Vec::with_capacity(self.chunks.len());
-                for chunk in self.chunks.iter() {
-                    output_chunks.push(chunk.take(indices)?);
-                }
-
-                Ok(ChunkedGeometryArray::new(output_chunks))
+            fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Self::Output {
+                Ok(ChunkedGeometryArray::new(
+                    self.try_map(|chunk| chunk.take(indices))?,
+                ))
             }
 
             fn take_range(&self, range: &Range<usize>) -> Self::Output {
-                let mut output_chunks = Vec::with_capacity(self.chunks.len());
-                for chunk in self.chunks.iter() {
-                    output_chunks.push(chunk.take_range(range)?);
-                }
-
-                Ok(ChunkedGeometryArray::new(output_chunks))
+                Ok(ChunkedGeometryArray::new(
+                    self.try_map(|chunk| chunk.take_range(range))?,
+                ))
             }
         }
     };
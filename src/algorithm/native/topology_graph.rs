@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use arrow_array::{Float64Array, OffsetSizeTrait, UInt32Array};
+use geo::line_intersection::LineIntersection;
+use geo::sweep::Intersections;
+use geo::{Coord, EuclideanLength, LineString};
+
+use crate::algorithm::native::line_merge::node_key;
+use crate::algorithm::native::segments::PartSegment;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::trait_::GeometryArrayAccessor;
+
+/// A planar graph built from line strings, as returned by [`TopologyGraph::topology_graph`].
+///
+/// The edge columns all have one row per edge, so they can be used directly as the columns of an
+/// edge table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Topology<O: OffsetSizeTrait> {
+    /// The unique nodes of the graph, in the order they are first reached.
+    pub nodes: PointArray,
+
+    /// The geometry of each edge.
+    pub edges: LineStringArray<O>,
+
+    /// The index into `nodes` of the start of each edge.
+    pub from: UInt32Array,
+
+    /// The index into `nodes` of the end of each edge.
+    pub to: UInt32Array,
+
+    /// The euclidean length of each edge.
+    pub length: Float64Array,
+
+    /// The index of the input line string each edge was cut from.
+    pub source: UInt32Array,
+}
+
+/// Node line strings into a planar graph, as the basis for routing and network analysis.
+///
+/// Every line string is cut wherever it touches or crosses another line string or itself, and
+/// each part becomes an edge between two nodes. The endpoints of all line strings are nodes too.
+/// Where line strings overlap, the shared part becomes an edge of each of them. Null line strings
+/// and line strings with fewer than two distinct coordinates are skipped.
+pub trait TopologyGraph {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::UInt32Array;
+    /// use geoarrow::algorithm::native::TopologyGraph;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::line_string;
+    ///
+    /// // Two roads crossing at (1, 1)
+    /// let array: LineStringArray<i32> = vec![
+    ///     line_string![(x: 0., y: 1.), (x: 2., y: 1.)],
+    ///     line_string![(x: 1., y: 0.), (x: 1., y: 2.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    ///
+    /// let graph = array.topology_graph();
+    /// assert_eq!(graph.nodes.len(), 5);
+    /// assert_eq!(graph.edges.len(), 4);
+    /// assert_eq!(graph.source, UInt32Array::from(vec![0, 0, 1, 1]));
+    /// ```
+    fn topology_graph(&self) -> Self::Output;
+}
+
+/// Where a point on a segment cuts it, as the index of the segment and the fraction along it.
+fn cut(segment: &PartSegment, point: Coord) -> (usize, f64, Coord) {
+    let delta = segment.line.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+    let offset = point - segment.line.start;
+    let t = ((offset.x * delta.x + offset.y * delta.y) / length_2).clamp(0., 1.);
+    (segment.index, t, point)
+}
+
+/// Cut a line string at the given positions. The cuts must be sorted and the coordinates must not
+/// have repeated consecutive points.
+fn split_at_cuts(coords: &[Coord], cuts: &[(usize, f64, Coord)]) -> Vec<LineString> {
+    let push = |current: &mut Vec<Coord>, coord: Coord| {
+        if current.last() != Some(&coord) {
+            current.push(coord);
+        }
+    };
+
+    let mut parts = Vec::with_capacity(cuts.len() + 1);
+    let mut current = vec![coords[0]];
+    let mut next_vertex = 1;
+    for (segment_idx, _, cut) in cuts {
+        while next_vertex <= *segment_idx {
+            push(&mut current, coords[next_vertex]);
+            next_vertex += 1;
+        }
+        push(&mut current, *cut);
+        if current.len() > 1 {
+            parts.push(LineString::new(std::mem::replace(&mut current, vec![*cut])));
+        }
+    }
+    coords[next_vertex..]
+        .iter()
+        .for_each(|coord| push(&mut current, *coord));
+    if current.len() > 1 {
+        parts.push(LineString::new(current));
+    }
+    parts
+}
+
+/// Node a set of line strings, with the index of each line string, into a graph.
+fn build_topology<O: OffsetSizeTrait>(
+    line_strings: impl Iterator<Item = (usize, LineString)>,
+) -> Topology<O> {
+    let line_strings = line_strings
+        .map(|(source, line_string)| {
+            let mut coords = line_string.0;
+            coords.dedup();
+            (source, coords)
+        })
+        .filter(|(_, coords)| coords.len() > 1)
+        .collect::<Vec<_>>();
+
+    let segments =
+        PartSegment::from_parts(line_strings.iter().map(|(_, coords)| coords.as_slice()));
+
+    let mut cuts: Vec<Vec<(usize, f64, Coord)>> = vec![vec![]; line_strings.len()];
+    for (a, b, intersection) in Intersections::from_iter(segments) {
+        let points = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                if a.is_adjacent(&b) {
+                    continue;
+                }
+                vec![intersection]
+            }
+            LineIntersection::Collinear { intersection } => {
+                vec![intersection.start, intersection.end]
+            }
+        };
+        for point in points {
+            cuts[a.part].push(cut(&a, point));
+            cuts[b.part].push(cut(&b, point));
+        }
+    }
+
+    let mut node_ids: HashMap<(u64, u64), u32> = HashMap::new();
+    let mut nodes = vec![];
+    let mut node_id = |coord: &Coord| {
+        *node_ids.entry(node_key(coord)).or_insert_with(|| {
+            nodes.push(geo::Point(*coord));
+            (nodes.len() - 1) as u32
+        })
+    };
+
+    let mut edges = vec![];
+    let mut from = vec![];
+    let mut to = vec![];
+    let mut length = vec![];
+    let mut source = vec![];
+    for ((source_idx, coords), mut line_string_cuts) in line_strings.iter().zip(cuts) {
+        line_string_cuts.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        line_string_cuts.dedup_by(|a, b| a.2 == b.2);
+        for edge in split_at_cuts(coords, &line_string_cuts) {
+            from.push(node_id(&edge.0[0]));
+            to.push(node_id(edge.0.last().unwrap()));
+            length.push(edge.euclidean_length());
+            source.push(*source_idx as u32);
+            edges.push(edge);
+        }
+    }
+
+    Topology {
+        nodes: nodes.as_slice().into(),
+        edges: edges.as_slice().into(),
+        from: from.into(),
+        to: to.into(),
+        length: length.into(),
+        source: source.into(),
+    }
+}
+
+#[cfg(feature = "petgraph")]
+impl<O: OffsetSizeTrait> Topology<O> {
+    /// Convert to an undirected [`petgraph::Graph`], to use petgraph's shortest path and
+    /// connectivity algorithms.
+    ///
+    /// The weight of each node and edge is its row index in `nodes` and in the edge columns
+    /// respectively, so that results can be joined back to the geometries. The node and edge
+    /// indices of the graph are equal to these row indices too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::TopologyGraph;
+    /// use geoarrow::array::LineStringArray;
+    /// use geo::line_string;
+    /// use petgraph::algo::dijkstra;
+    /// use petgraph::graph::NodeIndex;
+    ///
+    /// let array: LineStringArray<i32> = vec![
+    ///     line_string![(x: 0., y: 0.), (x: 3., y: 0.)],
+    ///     line_string![(x: 1., y: -1.), (x: 1., y: 1.)],
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let topology = array.topology_graph();
+    /// let graph = topology.to_petgraph();
+    ///
+    /// let costs = dijkstra(&graph, NodeIndex::new(0), None, |edge| {
+    ///     topology.length.value(*edge.weight() as usize)
+    /// });
+    /// // Node 1 is where the lines cross, and node 2 is the far end of the first line
+    /// assert_eq!(costs[&NodeIndex::new(2)], 3.);
+    /// ```
+    pub fn to_petgraph(&self) -> petgraph::graph::UnGraph<u32, u32> {
+        use crate::GeometryArrayTrait;
+
+        let mut graph = petgraph::graph::UnGraph::with_capacity(self.nodes.len(), self.from.len());
+        (0..self.nodes.len()).for_each(|node_idx| {
+            graph.add_node(node_idx as u32);
+        });
+        self.from
+            .values()
+            .iter()
+            .zip(self.to.values().iter())
+            .enumerate()
+            .for_each(|(edge_idx, (from, to))| {
+                graph.add_edge((*from).into(), (*to).into(), edge_idx as u32);
+            });
+        graph
+    }
+}
+
+impl<O: OffsetSizeTrait> TopologyGraph for LineStringArray<O> {
+    type Output = Topology<O>;
+
+    fn topology_graph(&self) -> Self::Output {
+        build_topology(
+            self.iter_geo()
+                .enumerate()
+                .filter_map(|(idx, maybe_g)| maybe_g.map(|g| (idx, g))),
+        )
+    }
+}
+
+/// Line strings from all chunks are noded together. The `source` of each edge is its index
+/// across all chunks.
+impl<O: OffsetSizeTrait> TopologyGraph for ChunkedGeometryArray<LineStringArray<O>> {
+    type Output = Topology<O>;
+
+    fn topology_graph(&self) -> Self::Output {
+        build_topology(
+            self.chunks()
+                .iter()
+                .flat_map(|chunk| chunk.iter_geo())
+                .enumerate()
+                .filter_map(|(idx, maybe_g)| maybe_g.map(|g| (idx, g))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryArrayTrait;
+    use geo::{line_string, point};
+
+    #[test]
+    fn shared_vertices_and_overlaps() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)]),
+            None,
+            // Starts at an interior vertex of the first line string and overlaps it
+            Some(line_string![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)]),
+            // A closed ring touching nothing
+            Some(line_string![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 5.)]),
+        ]
+        .into();
+
+        let graph = array.topology_graph();
+        assert_eq!(
+            graph.edges.iter_geo_values().collect::<Vec<_>>(),
+            vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+                line_string![(x: 3., y: 0.), (x: 4., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+                line_string![(x: 3., y: 0.), (x: 3., y: 1.)],
+                line_string![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 5.)],
+            ]
+        );
+        assert_eq!(graph.source, UInt32Array::from(vec![0, 0, 0, 2, 2, 3]));
+        assert_eq!(graph.from, UInt32Array::from(vec![0, 1, 2, 1, 2, 5]));
+        assert_eq!(graph.to, UInt32Array::from(vec![1, 2, 3, 2, 4, 5]));
+        assert_eq!(graph.nodes.len(), 6);
+        assert_eq!(graph.nodes.value_as_geo(4), point!(x: 3., y: 1.));
+        assert_eq!(graph.length.value(4), 1.);
+
+        let chunked = ChunkedGeometryArray::new(vec![array.clone(), array]);
+        let chunked_graph = chunked.topology_graph();
+        // The overlapping copies of the ring are cut at each of its vertices
+        assert_eq!(chunked_graph.nodes.len(), 8);
+        assert_eq!(chunked_graph.source.value(8), 4);
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn petgraph_components() {
+        let array: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 5., y: 5.), (x: 6., y: 6.)],
+        ]
+        .as_slice()
+        .into();
+        let topology = array.topology_graph();
+        let graph = topology.to_petgraph();
+        assert_eq!(graph.node_count(), topology.nodes.len());
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(petgraph::algo::connected_components(&graph), 2);
+        assert_eq!(
+            graph.edge_weights().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::NullBuffer;
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Compute the bounding box of all geometries in an array.
+///
+/// For arrays with a single coordinate buffer this scans the buffer directly instead of
+/// iterating over geometries. Null geometries are skipped.
+pub trait TotalBounds {
+    type Output;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::TotalBounds;
+    /// use geoarrow::array::LineStringArray;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 1., y: 2.), (x: -3., y: 4.)];
+    /// let array: LineStringArray<i32> = vec![line_string].as_slice().into();
+    ///
+    /// let bounds = array.total_bounds();
+    /// assert_eq!((bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()), (-3., 2., 1., 4.));
+    /// ```
+    fn total_bounds(&self) -> Self::Output;
+}
+
+/// Add the coordinates of all valid geometries, given a function that returns the range of
+/// coordinate indices of each geometry.
+fn add_valid_geometries(
+    bounds: &mut BoundingRect,
+    coords: &CoordBuffer,
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    coord_range: impl Fn(usize) -> (usize, usize),
+) {
+    match nulls {
+        // The coordinates of all geometries are contiguous, so scan them in one go
+        None if len > 0 => {
+            let (start, _) = coord_range(0);
+            let (_, end) = coord_range(len - 1);
+            bounds.add_coords(coords, start, end);
+        }
+        None => (),
+        Some(nulls) => nulls.valid_indices().for_each(|geom_idx| {
+            let (start, end) = coord_range(geom_idx);
+            bounds.add_coords(coords, start, end);
+        }),
+    }
+}
+
+impl TotalBounds for PointArray {
+    type Output = BoundingRect;
+
+    fn total_bounds(&self) -> Self::Output {
+        let mut bounds = BoundingRect::new();
+        add_valid_geometries(&mut bounds, &self.coords, self.len(), self.nulls(), |i| {
+            (i, i + 1)
+        });
+        bounds
+    }
+}
+
+/// Implementation for arrays with one level of offsets
+macro_rules! one_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> TotalBounds for $type {
+            type Output = BoundingRect;
+
+            fn total_bounds(&self) -> Self::Output {
+                let mut bounds = BoundingRect::new();
+                add_valid_geometries(&mut bounds, &self.coords, self.len(), self.nulls(), |i| {
+                    self.geom_offsets.start_end(i)
+                });
+                bounds
+            }
+        }
+    };
+}
+
+one_level_impl!(LineStringArray<O>);
+one_level_impl!(MultiPointArray<O>);
+
+/// Implementation for arrays with two levels of offsets
+macro_rules! two_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> TotalBounds for $type {
+            type Output = BoundingRect;
+
+            fn total_bounds(&self) -> Self::Output {
+                let mut bounds = BoundingRect::new();
+                add_valid_geometries(&mut bounds, &self.coords, self.len(), self.nulls(), |i| {
+                    let (start_ring, end_ring) = self.geom_offsets.start_end(i);
+                    (
+                        self.ring_offsets[start_ring].to_usize().unwrap(),
+                        self.ring_offsets[end_ring].to_usize().unwrap(),
+                    )
+                });
+                bounds
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>);
+two_level_impl!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> TotalBounds for MultiPolygonArray<O> {
+    type Output = BoundingRect;
+
+    fn total_bounds(&self) -> Self::Output {
+        let mut bounds = BoundingRect::new();
+        add_valid_geometries(&mut bounds, &self.coords, self.len(), self.nulls(), |i| {
+            let (start_polygon, end_polygon) = self.geom_offsets.start_end(i);
+            let start_ring = self.polygon_offsets[start_polygon].to_usize().unwrap();
+            let end_ring = self.polygon_offsets[end_polygon].to_usize().unwrap();
+            (
+                self.ring_offsets[start_ring].to_usize().unwrap(),
+                self.ring_offsets[end_ring].to_usize().unwrap(),
+            )
+        });
+        bounds
+    }
+}
+
+/// Implementation that iterates over geometry scalars
+macro_rules! iter_impl {
+    ($type:ty, $add_func:ident) => {
+        impl<O: OffsetSizeTrait> TotalBounds for $type {
+            type Output = BoundingRect;
+
+            fn total_bounds(&self) -> Self::Output {
+                let mut bounds = BoundingRect::new();
+                self.iter()
+                    .flatten()
+                    .for_each(|geom| bounds.$add_func(&geom));
+                bounds
+            }
+        }
+    };
+}
+
+iter_impl!(MixedGeometryArray<O>, add_geometry);
+iter_impl!(GeometryCollectionArray<O>, add_geometry_collection);
+
+impl TotalBounds for RectArray {
+    type Output = BoundingRect;
+
+    fn total_bounds(&self) -> Self::Output {
+        let mut bounds = BoundingRect::new();
+        self.iter()
+            .flatten()
+            .for_each(|rect| bounds.add_rect(&rect));
+        bounds
+    }
+}
+
+impl TotalBounds for &dyn GeometryArrayTrait {
+    type Output = Result<BoundingRect>;
+
+    fn total_bounds(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().total_bounds(),
+            GeoDataType::LineString(_) => self.as_line_string().total_bounds(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().total_bounds(),
+            GeoDataType::Polygon(_) => self.as_polygon().total_bounds(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().total_bounds(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().total_bounds(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().total_bounds(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().total_bounds(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().total_bounds()
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().total_bounds(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().total_bounds(),
+            GeoDataType::Mixed(_) => self.as_mixed().total_bounds(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().total_bounds(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().total_bounds(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().total_bounds()
+            }
+            GeoDataType::Rect => self.as_rect().total_bounds(),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait + TotalBounds<Output = BoundingRect>> TotalBounds
+    for ChunkedGeometryArray<G>
+{
+    type Output = BoundingRect;
+
+    fn total_bounds(&self) -> Self::Output {
+        let mut bounds = BoundingRect::new();
+        for chunk_bounds in self.map(|chunk| chunk.total_bounds()) {
+            bounds.update(&chunk_bounds);
+        }
+        bounds
+    }
+}
+
+impl TotalBounds for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<BoundingRect>;
+
+    fn total_bounds(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().total_bounds(),
+            GeoDataType::LineString(_) => self.as_line_string().total_bounds(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().total_bounds(),
+            GeoDataType::Polygon(_) => self.as_polygon().total_bounds(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().total_bounds(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().total_bounds(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().total_bounds(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().total_bounds(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().total_bounds()
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().total_bounds(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().total_bounds(),
+            GeoDataType::Mixed(_) => self.as_mixed().total_bounds(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().total_bounds(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().total_bounds(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().total_bounds()
+            }
+            GeoDataType::Rect => self.as_rect().total_bounds(),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multilinestring::ml_array;
+    use crate::trait_::GeometryArraySelfMethods;
+    use geo::{line_string, BoundingRect as _BoundingRect};
+
+    #[test]
+    fn total_bounds_matches_geo() {
+        let array = ml_array();
+        let expected = array
+            .iter_geo_values()
+            .filter_map(|geom| geom.bounding_rect())
+            .reduce(|a, b| {
+                geo::Rect::new(
+                    geo::coord! { x: a.min().x.min(b.min().x), y: a.min().y.min(b.min().y) },
+                    geo::coord! { x: a.max().x.max(b.max().x), y: a.max().y.max(b.max().y) },
+                )
+            })
+            .unwrap();
+
+        for array in [
+            array.clone(),
+            array.clone().into_coord_type(CoordType::Separated),
+        ] {
+            let bounds: geo::Rect = array.total_bounds().into();
+            assert_eq!(bounds, expected);
+        }
+
+        let sliced = array.slice(1, 1);
+        let bounds: geo::Rect = sliced.total_bounds().into();
+        assert_eq!(Some(bounds), sliced.value_as_geo(0).bounding_rect());
+    }
+
+    #[test]
+    fn total_bounds_skips_nulls() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            None,
+            Some(line_string![(x: -5., y: 2.), (x: 3., y: 7.)]),
+        ]
+        .into();
+        let bounds = array.total_bounds();
+        assert_eq!(
+            (bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()),
+            (-5., 0., 3., 7.)
+        );
+        assert!(
+            LineStringArray::<i32>::from(Vec::<geo::LineString>::new().as_slice())
+                .total_bounds()
+                .is_empty()
+        );
+    }
+}
@@ -124,14 +124,17 @@ impl<O: OffsetSizeTrait> TypeIds for WKBArray<O> {
         let mut output_array = Int8Builder::with_capacity(self.len());
 
         self.iter().for_each(|maybe_wkb| {
-            output_array.append_option(maybe_wkb.map(|wkb| match wkb.get_wkb_geometry_type() {
-                Point => 0,
-                LineString => 1,
-                Polygon => 3,
-                MultiPoint => 4,
-                MultiLineString => 5,
-                MultiPolygon => 6,
-                GeometryCollection => 7,
+            output_array.append_option(maybe_wkb.map(|wkb| {
+                match wkb.get_wkb_geometry_type().linearized() {
+                    Point => 0,
+                    LineString => 1,
+                    Polygon => 3,
+                    MultiPoint => 4,
+                    MultiLineString => 5,
+                    MultiPolygon => 6,
+                    GeometryCollection => 7,
+                    _ => unreachable!("curves are linearized"),
+                }
             }))
         });
 
@@ -143,7 +146,7 @@ impl<O: OffsetSizeTrait> TypeIds for WKBArray<O> {
 
         let mut values = HashSet::new();
         self.iter().flatten().for_each(|wkb| {
-            let type_id = match wkb.get_wkb_geometry_type() {
+            let type_id = match wkb.get_wkb_geometry_type().linearized() {
                 Point => 0,
                 LineString => 1,
                 Polygon => 3,
@@ -151,6 +154,7 @@ impl<O: OffsetSizeTrait> TypeIds for WKBArray<O> {
                 MultiLineString => 5,
                 MultiPolygon => 6,
                 GeometryCollection => 7,
+                _ => unreachable!("curves are linearized"),
             };
             values.insert(type_id);
         });
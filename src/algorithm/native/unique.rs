@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+
+use arrow_array::types::UInt32Type;
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Find the distinct geometries of an array.
+///
+/// Geometries are compared exactly, by their type, structure and the bit patterns of their
+/// coordinates. This means that e.g. a polygon and a multi-polygon with one polygon are distinct,
+/// as are rings with the same coordinates but a different start point.
+pub trait Unique {
+    type Output;
+
+    /// Returns the distinct geometries of the array, in order of first occurrence, together with
+    /// the index of each geometry in the distinct geometries. Null geometries have a null index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Unique;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use arrow_array::UInt32Array;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![
+    ///     Some(point!(x: 1., y: 2.)),
+    ///     Some(point!(x: 3., y: 4.)),
+    ///     None,
+    ///     Some(point!(x: 1., y: 2.)),
+    /// ]
+    /// .into();
+    /// let unique = array.unique().unwrap();
+    /// assert_eq!(unique.values().len(), 2);
+    /// assert_eq!(
+    ///     unique.keys(),
+    ///     &UInt32Array::from(vec![Some(0), Some(1), None, Some(0)])
+    /// );
+    /// ```
+    fn unique(&self) -> Self::Output;
+}
+
+impl Unique for PointArray {
+    type Output = Result<DictionaryGeometryArray<UInt32Type, PointArray>>;
+
+    fn unique(&self) -> Self::Output {
+        DictionaryGeometryArray::try_encode(self)
+    }
+}
+
+impl Unique for RectArray {
+    type Output = Result<DictionaryGeometryArray<UInt32Type, RectArray>>;
+
+    fn unique(&self) -> Self::Output {
+        DictionaryGeometryArray::try_encode(self)
+    }
+}
+
+/// Implementation that dictionary-encodes the array
+macro_rules! encode_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Unique for $type {
+            type Output = Result<DictionaryGeometryArray<UInt32Type, $type>>;
+
+            fn unique(&self) -> Self::Output {
+                DictionaryGeometryArray::try_encode(self)
+            }
+        }
+    };
+}
+
+encode_impl!(LineStringArray<O>);
+encode_impl!(PolygonArray<O>);
+encode_impl!(MultiPointArray<O>);
+encode_impl!(MultiLineStringArray<O>);
+encode_impl!(MultiPolygonArray<O>);
+encode_impl!(MixedGeometryArray<O>);
+encode_impl!(GeometryCollectionArray<O>);
+encode_impl!(WKBArray<O>);
+
+/// For each geometry of an array, the index of its first exactly equal geometry among the
+/// distinct geometries, or `None` for null geometries. Also returns the index in the array of
+/// the first occurrence of each distinct geometry.
+pub(crate) fn unique_indices(array: &dyn GeometryArrayTrait) -> (Vec<Option<usize>>, Vec<usize>) {
+    let keys = geometry_keys(array);
+    let mut first_indices = vec![];
+    let mut key_map: HashMap<Vec<u64>, usize> = HashMap::new();
+    let indices = keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, maybe_key)| {
+            maybe_key.map(|key| {
+                *key_map.entry(key).or_insert_with(|| {
+                    first_indices.push(i);
+                    first_indices.len() - 1
+                })
+            })
+        })
+        .collect();
+    (indices, first_indices)
+}
+
+/// An exact key for each geometry of an array.
+fn geometry_keys(array: &dyn GeometryArrayTrait) -> Vec<Option<Vec<u64>>> {
+    macro_rules! keys {
+        ($arr:expr, $key_fn:expr) => {
+            $arr.iter()
+                .map(|maybe_geom| {
+                    maybe_geom.map(|geom| {
+                        let mut key = vec![];
+                        $key_fn(&mut key, &geom);
+                        key
+                    })
+                })
+                .collect()
+        };
+    }
+
+    match array.data_type() {
+        GeoDataType::Point(_) => keys!(array.as_point(), push_point),
+        GeoDataType::LineString(_) => keys!(array.as_line_string(), push_line_string),
+        GeoDataType::LargeLineString(_) => keys!(array.as_large_line_string(), push_line_string),
+        GeoDataType::Polygon(_) => keys!(array.as_polygon(), push_polygon),
+        GeoDataType::LargePolygon(_) => keys!(array.as_large_polygon(), push_polygon),
+        GeoDataType::MultiPoint(_) => keys!(array.as_multi_point(), push_multi_point),
+        GeoDataType::LargeMultiPoint(_) => keys!(array.as_large_multi_point(), push_multi_point),
+        GeoDataType::MultiLineString(_) => {
+            keys!(array.as_multi_line_string(), push_multi_line_string)
+        }
+        GeoDataType::LargeMultiLineString(_) => {
+            keys!(array.as_large_multi_line_string(), push_multi_line_string)
+        }
+        GeoDataType::MultiPolygon(_) => keys!(array.as_multi_polygon(), push_multi_polygon),
+        GeoDataType::LargeMultiPolygon(_) => {
+            keys!(array.as_large_multi_polygon(), push_multi_polygon)
+        }
+        GeoDataType::Mixed(_) => keys!(array.as_mixed(), push_geometry),
+        GeoDataType::LargeMixed(_) => keys!(array.as_large_mixed(), push_geometry),
+        GeoDataType::GeometryCollection(_) => {
+            keys!(array.as_geometry_collection(), push_geometry_collection)
+        }
+        GeoDataType::LargeGeometryCollection(_) => {
+            keys!(
+                array.as_large_geometry_collection(),
+                push_geometry_collection
+            )
+        }
+        GeoDataType::WKB => keys!(array.as_wkb(), |key, geom: &crate::scalar::WKB<'_, i32>| {
+            push_geometry(key, &geom.to_wkb_object())
+        }),
+        GeoDataType::LargeWKB => {
+            keys!(array.as_large_wkb(), |key,
+                                         geom: &crate::scalar::WKB<
+                '_,
+                i64,
+            >| {
+                push_geometry(key, &geom.to_wkb_object())
+            })
+        }
+        GeoDataType::Rect => keys!(array.as_rect(), push_rect),
+    }
+}
+
+fn push_coord(key: &mut Vec<u64>, coord: &impl CoordTrait<T = f64>) {
+    key.push(coord.x().to_bits());
+    key.push(coord.y().to_bits());
+}
+
+fn push_point(key: &mut Vec<u64>, point: &impl PointTrait<T = f64>) {
+    key.push(point.x().to_bits());
+    key.push(point.y().to_bits());
+}
+
+fn push_line_string(key: &mut Vec<u64>, line_string: &impl LineStringTrait<T = f64>) {
+    key.push(line_string.num_coords() as u64);
+    line_string
+        .coords()
+        .for_each(|coord| push_coord(key, &coord));
+}
+
+fn push_polygon(key: &mut Vec<u64>, polygon: &impl PolygonTrait<T = f64>) {
+    match polygon.exterior() {
+        Some(exterior) => push_line_string(key, &exterior),
+        None => key.push(u64::MAX),
+    }
+    key.push(polygon.num_interiors() as u64);
+    polygon
+        .interiors()
+        .for_each(|interior| push_line_string(key, &interior));
+}
+
+fn push_multi_point(key: &mut Vec<u64>, multi_point: &impl MultiPointTrait<T = f64>) {
+    key.push(multi_point.num_points() as u64);
+    multi_point
+        .points()
+        .for_each(|point| push_point(key, &point));
+}
+
+fn push_multi_line_string(
+    key: &mut Vec<u64>,
+    multi_line_string: &impl MultiLineStringTrait<T = f64>,
+) {
+    key.push(multi_line_string.num_lines() as u64);
+    multi_line_string
+        .lines()
+        .for_each(|line_string| push_line_string(key, &line_string));
+}
+
+fn push_multi_polygon(key: &mut Vec<u64>, multi_polygon: &impl MultiPolygonTrait<T = f64>) {
+    key.push(multi_polygon.num_polygons() as u64);
+    multi_polygon
+        .polygons()
+        .for_each(|polygon| push_polygon(key, &polygon));
+}
+
+fn push_geometry_collection(
+    key: &mut Vec<u64>,
+    geometry_collection: &impl GeometryCollectionTrait<T = f64>,
+) {
+    key.push(geometry_collection.num_geometries() as u64);
+    geometry_collection
+        .geometries()
+        .for_each(|geometry| push_geometry(key, &geometry));
+}
+
+fn push_rect(key: &mut Vec<u64>, rect: &impl RectTrait<T = f64>) {
+    push_coord(key, &rect.lower());
+    push_coord(key, &rect.upper());
+}
+
+/// Push the geometry type followed by the geometry, so that e.g. a point and a multi-point with
+/// a single point have different keys.
+fn push_geometry(key: &mut Vec<u64>, geometry: &impl GeometryTrait<T = f64>) {
+    match geometry.as_type() {
+        GeometryType::Point(g) => {
+            key.push(1);
+            push_point(key, g)
+        }
+        GeometryType::LineString(g) => {
+            key.push(2);
+            push_line_string(key, g)
+        }
+        GeometryType::Polygon(g) => {
+            key.push(3);
+            push_polygon(key, g)
+        }
+        GeometryType::MultiPoint(g) => {
+            key.push(4);
+            push_multi_point(key, g)
+        }
+        GeometryType::MultiLineString(g) => {
+            key.push(5);
+            push_multi_line_string(key, g)
+        }
+        GeometryType::MultiPolygon(g) => {
+            key.push(6);
+            push_multi_polygon(key, g)
+        }
+        GeometryType::GeometryCollection(g) => {
+            key.push(7);
+            push_geometry_collection(key, g)
+        }
+        GeometryType::Rect(g) => {
+            key.push(8);
+            push_rect(key, g)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipoint, point, polygon};
+    use arrow_array::UInt32Array;
+
+    #[test]
+    fn unique_polygons() {
+        let array: PolygonArray<i32> = vec![
+            Some(polygon::p0()),
+            Some(polygon::p0()),
+            None,
+            Some(polygon::p1()),
+        ]
+        .into();
+        let unique = array.unique().unwrap();
+        assert_eq!(
+            unique.values().iter_geo_values().collect::<Vec<_>>(),
+            vec![polygon::p0(), polygon::p1()]
+        );
+        assert_eq!(
+            unique.keys(),
+            &UInt32Array::from(vec![Some(0), Some(0), None, Some(1)])
+        );
+    }
+
+    #[test]
+    fn unique_mixed_distinguishes_types() {
+        let geoms = vec![
+            Some(geo::Geometry::Point(point::p0())),
+            Some(geo::Geometry::MultiPoint(vec![point::p0()].into())),
+            Some(geo::Geometry::Point(point::p0())),
+            Some(geo::Geometry::MultiPoint(multipoint::mp0())),
+        ];
+        let array: MixedGeometryArray<i32> =
+            MixedGeometryBuilder::from_nullable_geometries(&geoms, None, Default::default(), false)
+                .unwrap()
+                .into();
+        let (indices, first_indices) = unique_indices(&array);
+        assert_eq!(indices, vec![Some(0), Some(1), Some(0), Some(2)]);
+        assert_eq!(first_indices, vec![0, 1, 3]);
+    }
+}
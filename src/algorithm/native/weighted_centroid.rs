@@ -0,0 +1,185 @@
+use arrow_array::Float64Array;
+use geo::Point;
+
+use crate::algorithm::geo::Centroid;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The weighted mean of the points of an array, e.g. the population-weighted center of a set of
+/// places.
+///
+/// Rows where the point or the weight is null, or where the weight is not finite, are skipped.
+/// The output is `None` if the weights of the remaining rows sum to zero.
+pub trait WeightedCentroid<W = Float64Array> {
+    type Output;
+
+    /// # Errors
+    ///
+    /// - if `weights` doesn't have the same length as this array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::Float64Array;
+    /// use geoarrow::algorithm::native::WeightedCentroid;
+    /// use geoarrow::array::PointArray;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 0., y: 0.), point!(x: 4., y: 2.)]
+    ///     .as_slice()
+    ///     .into();
+    /// let weights = Float64Array::from(vec![1., 3.]);
+    ///
+    /// let centroid = array.weighted_centroid(&weights).unwrap();
+    /// assert_eq!(centroid, Some(point!(x: 3., y: 1.5)));
+    /// ```
+    fn weighted_centroid(&self, weights: &W) -> Self::Output;
+}
+
+/// Running sums of a weighted centroid, which can be merged across chunks.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct WeightedSum {
+    x: f64,
+    y: f64,
+    weight: f64,
+}
+
+impl WeightedSum {
+    pub(crate) fn add(&mut self, point: Point, weight: f64) {
+        if weight.is_finite() {
+            self.x += point.x() * weight;
+            self.y += point.y() * weight;
+            self.weight += weight;
+        }
+    }
+
+    pub(crate) fn merge(&mut self, other: &WeightedSum) {
+        self.x += other.x;
+        self.y += other.y;
+        self.weight += other.weight;
+    }
+
+    pub(crate) fn finish(&self) -> Option<Point> {
+        (self.weight != 0.).then(|| Point::new(self.x / self.weight, self.y / self.weight))
+    }
+
+    /// Add all valid rows of `points`.
+    pub(crate) fn add_array(&mut self, points: &PointArray, weights: &Float64Array) -> Result<()> {
+        if points.len() != weights.len() {
+            return Err(GeoArrowError::General(format!(
+                "Expected {} weights, got {}",
+                points.len(),
+                weights.len()
+            )));
+        }
+
+        points
+            .iter_geo()
+            .zip(weights.iter())
+            .for_each(|(point, weight)| {
+                if let (Some(point), Some(weight)) = (point, weight) {
+                    self.add(point, weight);
+                }
+            });
+        Ok(())
+    }
+}
+
+impl WeightedCentroid for PointArray {
+    type Output = Result<Option<Point>>;
+
+    fn weighted_centroid(&self, weights: &Float64Array) -> Self::Output {
+        let mut sum = WeightedSum::default();
+        sum.add_array(self, weights)?;
+        Ok(sum.finish())
+    }
+}
+
+/// Geometries other than points are represented by their [`Centroid`].
+impl WeightedCentroid for &dyn GeometryArrayTrait {
+    type Output = Result<Option<Point>>;
+
+    fn weighted_centroid(&self, weights: &Float64Array) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().weighted_centroid(weights),
+            _ => self.centroid()?.weighted_centroid(weights),
+        }
+    }
+}
+
+impl WeightedCentroid<ChunkedArray<Float64Array>> for ChunkedGeometryArray<PointArray> {
+    type Output = Result<Option<Point>>;
+
+    /// The weights must have the same chunk lengths as this array.
+    fn weighted_centroid(&self, weights: &ChunkedArray<Float64Array>) -> Self::Output {
+        if self.chunks().len() != weights.chunks().len() {
+            return Err(GeoArrowError::General(format!(
+                "Expected {} chunks of weights, got {}",
+                self.chunks().len(),
+                weights.chunks().len()
+            )));
+        }
+
+        let mut sum = WeightedSum::default();
+        for (points, weights) in self.chunks().iter().zip(weights.chunks()) {
+            let mut chunk_sum = WeightedSum::default();
+            chunk_sum.add_array(points, weights)?;
+            sum.merge(&chunk_sum);
+        }
+        Ok(sum.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+    use geo::point;
+
+    #[test]
+    fn skips_nulls() {
+        let array: PointArray = vec![
+            Some(point!(x: 0., y: 0.)),
+            None,
+            Some(point!(x: 2., y: 2.)),
+            Some(point!(x: 10., y: 10.)),
+        ]
+        .into();
+        let weights = Float64Array::from(vec![Some(1.), Some(5.), Some(1.), None]);
+        assert_eq!(
+            array.weighted_centroid(&weights).unwrap(),
+            Some(point!(x: 1., y: 1.))
+        );
+
+        let zero = Float64Array::from(vec![0., 0., 0., 0.]);
+        assert_eq!(array.weighted_centroid(&zero).unwrap(), None);
+        assert!(array
+            .weighted_centroid(&Float64Array::from(vec![1.]))
+            .is_err());
+    }
+
+    #[test]
+    fn chunked_and_dyn() {
+        let chunked = ChunkedGeometryArray::new(vec![point::point_array(), point::point_array()]);
+        let weights = ChunkedArray::new(vec![
+            Float64Array::from(vec![1., 0., 0.]),
+            Float64Array::from(vec![0., 0., 1.]),
+        ]);
+        assert_eq!(
+            chunked.weighted_centroid(&weights).unwrap(),
+            Some(point!(x: 1., y: 2.))
+        );
+
+        let polygons = polygon::p_array();
+        let dyn_array: &dyn GeometryArrayTrait = &polygons;
+        let weights = Float64Array::from(vec![1., 0.]);
+        assert_eq!(
+            dyn_array.weighted_centroid(&weights).unwrap(),
+            polygons.centroid().get_as_geo(0)
+        );
+    }
+}
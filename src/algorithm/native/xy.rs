@@ -0,0 +1,137 @@
+use arrow_array::{Float64Array, OffsetSizeTrait};
+
+use crate::algorithm::geo::Centroid;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+
+/// Extract the x and y coordinates of each geometry as numeric arrays.
+///
+/// For non-point geometries, the coordinates of the geometry's
+/// [centroid](crate::algorithm::geo::Centroid) are returned. Null geometries and geometries
+/// without a centroid, such as empty geometries, are null in the output.
+///
+/// Only two-dimensional coordinates are stored in this crate, so there is no `z` accessor.
+///
+/// # Examples
+///
+/// ```
+/// use geo::point;
+///
+/// use geoarrow::algorithm::native::XY;
+/// use geoarrow::array::PointArray;
+///
+/// let array: PointArray = vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]
+///     .as_slice()
+///     .into();
+/// assert_eq!(array.x().values().as_ref(), &[1., 3.]);
+/// assert_eq!(array.y().values().as_ref(), &[2., 4.]);
+/// ```
+pub trait XY {
+    type Output;
+
+    /// The x coordinate of each geometry.
+    fn x(&self) -> Self::Output;
+
+    /// The y coordinate of each geometry.
+    fn y(&self) -> Self::Output;
+}
+
+impl XY for PointArray {
+    type Output = Float64Array;
+
+    fn x(&self) -> Self::Output {
+        let values: Vec<f64> = (0..self.len()).map(|i| self.coords.get_x(i)).collect();
+        Float64Array::new(values.into(), self.nulls().cloned())
+    }
+
+    fn y(&self) -> Self::Output {
+        let values: Vec<f64> = (0..self.len()).map(|i| self.coords.get_y(i)).collect();
+        Float64Array::new(values.into(), self.nulls().cloned())
+    }
+}
+
+/// Implementation in terms of the centroid of each geometry
+macro_rules! centroid_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> XY for $type {
+            type Output = Float64Array;
+
+            fn x(&self) -> Self::Output {
+                self.centroid().x()
+            }
+
+            fn y(&self) -> Self::Output {
+                self.centroid().y()
+            }
+        }
+    };
+}
+
+centroid_impl!(LineStringArray<O>);
+centroid_impl!(PolygonArray<O>);
+centroid_impl!(MultiPointArray<O>);
+centroid_impl!(MultiLineStringArray<O>);
+centroid_impl!(MultiPolygonArray<O>);
+centroid_impl!(MixedGeometryArray<O>);
+centroid_impl!(GeometryCollectionArray<O>);
+centroid_impl!(WKBArray<O>);
+
+impl XY for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn x(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => Ok(self.as_point().x()),
+            _ => Ok(self.centroid()?.x()),
+        }
+    }
+
+    fn y(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => Ok(self.as_point().y()),
+            _ => Ok(self.centroid()?.y()),
+        }
+    }
+}
+
+impl<G: GeometryArrayTrait> XY for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn x(&self) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().x())?.try_into()
+    }
+
+    fn y(&self) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().y())?.try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::point_array;
+    use crate::test::polygon::p_array;
+    use crate::trait_::GeometryArraySelfMethods;
+    use arrow_array::Array;
+
+    #[test]
+    fn point_xy() {
+        let arr = point_array().slice(1, 2);
+        assert_eq!(arr.x().values().as_ref(), &[1., 2.]);
+        assert_eq!(arr.y().values().as_ref(), &[2., 3.]);
+    }
+
+    #[test]
+    fn polygon_centroid_xy() {
+        let arr = p_array();
+        let centroid = arr.centroid();
+        assert_eq!(arr.x(), centroid.x());
+        assert_eq!(arr.y(), centroid.y());
+
+        let arr: PolygonArray<i32> = vec![None::<geo::Polygon>].into();
+        assert!(arr.x().is_null(0));
+    }
+}
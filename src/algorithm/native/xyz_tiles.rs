@@ -0,0 +1,337 @@
+use std::f64::consts::PI;
+
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_array::{ListArray, OffsetSizeTrait};
+use geo::{coord, BoundingRect, Intersects, Rect};
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The maximum zoom level for which tiles can be represented as a [`Tile::id`].
+pub const MAX_TILE_ZOOM: u8 = 29;
+
+/// The maximum latitude covered by web mercator tiles.
+const MAX_LATITUDE: f64 = 85.0511287798066;
+
+/// A tile in the XYZ ("slippy map") tiling scheme used by web maps.
+///
+/// Tile `(0, 0)` is at the top left, i.e. the north-west corner of the web mercator extent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    pub fn new(z: u8, x: u32, y: u32) -> Self {
+        Self { z, x, y }
+    }
+
+    /// A single integer identifying this tile, which encodes the zoom level in the highest 6
+    /// bits, followed by 29 bits each for `x` and `y`.
+    pub fn id(&self) -> u64 {
+        ((self.z as u64) << 58) | ((self.x as u64) << 29) | self.y as u64
+    }
+
+    /// The tile with the given [id][Self::id].
+    pub fn from_id(id: u64) -> Self {
+        let mask = (1 << 29) - 1;
+        Self {
+            z: (id >> 58) as u8,
+            x: ((id >> 29) & mask) as u32,
+            y: (id & mask) as u32,
+        }
+    }
+
+    /// The bounds of this tile in longitude and latitude.
+    pub fn bounds(&self) -> Rect {
+        self.expanded_bounds(0.)
+    }
+
+    /// The bounds of this tile in longitude and latitude, expanded by `buffer` units on each side
+    /// for a tile that is `extent` units across.
+    ///
+    /// The buffer is applied in Web Mercator space, where the units of a tile are square, so the
+    /// buffer is wider in latitude on the side of the tile closer to the pole.
+    pub fn buffered_bounds(&self, extent: u32, buffer: u32) -> Rect {
+        self.expanded_bounds(buffer as f64 / extent as f64)
+    }
+
+    /// The bounds of this tile expanded by a fraction of the tile size on each side in Web
+    /// Mercator space, converted to longitude and latitude.
+    fn expanded_bounds(&self, buffer: f64) -> Rect {
+        let n = (1u64 << self.z) as f64;
+        let lon = |x: f64| x / n * 360. - 180.;
+        let lat = |y: f64| (PI * (1. - 2. * y / n)).sinh().atan().to_degrees();
+        let (x, y) = (self.x as f64, self.y as f64);
+        Rect::new(
+            coord! { x: lon(x - buffer), y: lat(y + 1. + buffer) },
+            coord! { x: lon(x + 1. + buffer), y: lat(y - buffer) },
+        )
+    }
+}
+
+/// The `x` and `y` of the tile containing a longitude and latitude.
+fn tile_xy(lon: f64, lat: f64, zoom: u8) -> (u32, u32) {
+    let n = (1u64 << zoom) as f64;
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    let x = (lon + 180.) / 360. * n;
+    let y = (1. - (lat.tan() + 1. / lat.cos()).ln() / PI) / 2. * n;
+    let max_index = n - 1.;
+    (
+        x.floor().clamp(0., max_index) as u32,
+        y.floor().clamp(0., max_index) as u32,
+    )
+}
+
+/// The tiles at `zoom` intersecting a geometry.
+fn covering_tiles(geom: &geo::Geometry, zoom: u8) -> Vec<Tile> {
+    let Some(bbox) = geom.bounding_rect() else {
+        return vec![];
+    };
+    let (min_x, max_y) = tile_xy(bbox.min().x, bbox.min().y, zoom);
+    let (max_x, min_y) = tile_xy(bbox.max().x, bbox.max().y, zoom);
+    if min_x == max_x && min_y == max_y {
+        return vec![Tile::new(zoom, min_x, min_y)];
+    }
+
+    let mut tiles = vec![];
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            let tile = Tile::new(zoom, x, y);
+            if tile.bounds().intersects(geom) {
+                tiles.push(tile);
+            }
+        }
+    }
+    tiles
+}
+
+fn check_zoom(zoom: u8) -> Result<()> {
+    if zoom > MAX_TILE_ZOOM {
+        return Err(GeoArrowError::General(format!(
+            "Tile zoom must be at most {MAX_TILE_ZOOM}, got {zoom}"
+        )));
+    }
+    Ok(())
+}
+
+/// Find the XYZ tiles covering each geometry.
+///
+/// Coordinates are interpreted as longitude and latitude in degrees, and latitudes beyond the
+/// web mercator extent are clamped to its edge.
+pub trait XyzTiles {
+    type Output;
+
+    /// The [ids][Tile::id] of the tiles at `zoom` intersecting each geometry. Null geometries
+    /// have a null list of tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{Tile, XyzTiles};
+    /// use geoarrow::array::PointArray;
+    /// use arrow_array::cast::AsArray;
+    /// use arrow_array::types::UInt64Type;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 2.35, y: 48.85)].as_slice().into();
+    /// let tiles = array.xyz_tiles(10).unwrap();
+    /// let tile_ids = tiles.value(0);
+    /// let tile = Tile::from_id(tile_ids.as_primitive::<UInt64Type>().value(0));
+    /// assert_eq!(tile, Tile::new(10, 518, 352));
+    /// ```
+    fn xyz_tiles(&self, zoom: u8) -> Self::Output;
+}
+
+/// Build a list array of the tile ids of each geometry.
+fn tiles_list(geoms: impl Iterator<Item = Option<geo::Geometry>>, zoom: u8) -> ListArray {
+    let mut builder = ListBuilder::new(UInt64Builder::new());
+    for maybe_geom in geoms {
+        if let Some(geom) = maybe_geom {
+            let tiles = covering_tiles(&geom, zoom);
+            builder
+                .values()
+                .extend(tiles.iter().map(|tile| Some(tile.id())));
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    builder.finish()
+}
+
+impl XyzTiles for PointArray {
+    type Output = Result<ListArray>;
+
+    fn xyz_tiles(&self, zoom: u8) -> Self::Output {
+        check_zoom(zoom)?;
+        Ok(tiles_list(self.iter().map(|g| g.map(Into::into)), zoom))
+    }
+}
+
+impl XyzTiles for RectArray {
+    type Output = Result<ListArray>;
+
+    fn xyz_tiles(&self, zoom: u8) -> Self::Output {
+        check_zoom(zoom)?;
+        Ok(tiles_list(self.iter().map(|g| g.map(Into::into)), zoom))
+    }
+}
+
+/// Implementation that iterates over geometry scalars
+macro_rules! iter_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> XyzTiles for $type {
+            type Output = Result<ListArray>;
+
+            fn xyz_tiles(&self, zoom: u8) -> Self::Output {
+                check_zoom(zoom)?;
+                Ok(tiles_list(self.iter().map(|g| g.map(Into::into)), zoom))
+            }
+        }
+    };
+}
+
+iter_impl!(LineStringArray<O>);
+iter_impl!(PolygonArray<O>);
+iter_impl!(MultiPointArray<O>);
+iter_impl!(MultiLineStringArray<O>);
+iter_impl!(MultiPolygonArray<O>);
+iter_impl!(MixedGeometryArray<O>);
+iter_impl!(GeometryCollectionArray<O>);
+iter_impl!(WKBArray<O>);
+
+impl XyzTiles for &dyn GeometryArrayTrait {
+    type Output = Result<ListArray>;
+
+    fn xyz_tiles(&self, zoom: u8) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().xyz_tiles(zoom),
+            GeoDataType::LineString(_) => self.as_line_string().xyz_tiles(zoom),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().xyz_tiles(zoom),
+            GeoDataType::Polygon(_) => self.as_polygon().xyz_tiles(zoom),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().xyz_tiles(zoom),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().xyz_tiles(zoom),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().xyz_tiles(zoom),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().xyz_tiles(zoom),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().xyz_tiles(zoom)
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().xyz_tiles(zoom),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().xyz_tiles(zoom),
+            GeoDataType::Mixed(_) => self.as_mixed().xyz_tiles(zoom),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().xyz_tiles(zoom),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().xyz_tiles(zoom),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().xyz_tiles(zoom)
+            }
+            GeoDataType::WKB => self.as_wkb().xyz_tiles(zoom),
+            GeoDataType::LargeWKB => self.as_large_wkb().xyz_tiles(zoom),
+            GeoDataType::Rect => self.as_rect().xyz_tiles(zoom),
+        }
+    }
+}
+
+impl<G: GeometryArrayTrait> XyzTiles for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<ListArray>>;
+
+    fn xyz_tiles(&self, zoom: u8) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().xyz_tiles(zoom))?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use arrow_array::Array;
+    use geo::{line_string, point};
+
+    #[test]
+    fn tile_id_round_trip() {
+        let tile = Tile::new(MAX_TILE_ZOOM, (1 << 29) - 1, 12345);
+        assert_eq!(Tile::from_id(tile.id()), tile);
+
+        let bounds = Tile::new(0, 0, 0).bounds();
+        assert_eq!(bounds.min().x, -180.);
+        assert_eq!(bounds.max().x, 180.);
+        assert!((bounds.max().y - MAX_LATITUDE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn buffered_bounds_in_web_mercator() {
+        // A buffer of half a tile reaches halfway into the neighbouring tiles
+        let tile = Tile::new(4, 5, 3);
+        let buffered = tile.buffered_bounds(4096, 2048);
+        let (north, south) = (Tile::new(4, 5, 2).bounds(), Tile::new(4, 5, 4).bounds());
+        let mid_lat = |y: f64| (PI * (1. - 2. * y / 16.)).sinh().atan().to_degrees();
+        assert!((buffered.max().y - mid_lat(2.5)).abs() < 1e-9);
+        assert!((buffered.min().y - mid_lat(4.5)).abs() < 1e-9);
+        assert!(buffered.max().y < north.max().y && buffered.max().y > north.min().y);
+        assert!(buffered.min().y < south.max().y && buffered.min().y > south.min().y);
+        assert_eq!(buffered.min().x, Tile::new(4, 4, 3).bounds().center().x);
+
+        assert_eq!(tile.buffered_bounds(4096, 0), tile.bounds());
+    }
+
+    #[test]
+    fn line_string_tiles() {
+        // Crosses from the western to the eastern hemisphere in the northern hemisphere
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: -10., y: 10.), (x: 10., y: 10.)]),
+            None,
+        ]
+        .into();
+        let tiles = array.xyz_tiles(1).unwrap();
+        assert!(tiles.is_null(1));
+
+        let tile_ids = tiles.value(0);
+        let tiles = tile_ids
+            .as_primitive::<UInt64Type>()
+            .values()
+            .iter()
+            .map(|id| Tile::from_id(*id))
+            .collect::<Vec<_>>();
+        assert_eq!(tiles, vec![Tile::new(1, 0, 0), Tile::new(1, 1, 0)]);
+    }
+
+    #[test]
+    fn partition_table_by_tile() {
+        let table = crate::test::point::table();
+        let partitions = table.partition_by_tile(8).unwrap();
+        let tiles = partitions.keys().copied().collect::<Vec<_>>();
+        assert_eq!(
+            tiles,
+            vec![
+                Tile::new(8, 128, 126),
+                Tile::new(8, 128, 127),
+                Tile::new(8, 129, 125)
+            ]
+        );
+
+        let partition = &partitions[&tiles[0]];
+        assert_eq!(partition.len(), 1);
+        assert_eq!(partition.schema(), table.schema());
+        let geometry = partition.geometry().unwrap();
+        let points = geometry
+            .as_any()
+            .downcast_ref::<ChunkedGeometryArray<PointArray>>()
+            .unwrap();
+        let points = &points.chunks()[0];
+        assert_eq!(points.value_as_geo(0), crate::test::point::p1());
+    }
+
+    #[test]
+    fn invalid_zoom() {
+        let array: PointArray = vec![point!(x: 0., y: 0.)].as_slice().into();
+        assert!(array.xyz_tiles(MAX_TILE_ZOOM + 1).is_err());
+    }
+}
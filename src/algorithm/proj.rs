@@ -2,7 +2,7 @@
 
 use crate::array::*;
 use crate::error::Result;
-use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use proj::{Proj, Transform};
@@ -12,6 +12,41 @@ pub trait Reproject {
     fn reproject(&self, proj: &Proj) -> Result<Self>
     where
         Self: Sized;
+
+    /// Transform the array with a raw PROJ pipeline string, such as a custom chain of Helmert
+    /// transformations and projections. Unlike [`Reproject::reproject`], no CRS lookup or axis
+    /// order normalization takes place, and the pipeline is applied in its forward direction
+    /// directly to the coordinate buffer, keeping the offsets and validity of the input.
+    ///
+    /// Note that the coordinate buffer also holds the placeholder coordinates of null points, so
+    /// these are transformed as well.
+    fn reproject_pipeline(&self, pipeline: &str) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+/// Apply a PROJ transformation to every coordinate of a coordinate buffer, keeping its coordinate
+/// type.
+fn transform_coords(coords: &CoordBuffer, proj: &Proj) -> Result<CoordBuffer> {
+    let mut points = (0..coords.len())
+        .map(|i| (coords.get_x(i), coords.get_y(i)))
+        .collect::<Vec<_>>();
+    proj.convert_array(&mut points)?;
+
+    let output = match coords {
+        CoordBuffer::Interleaved(_) => {
+            let values = points
+                .into_iter()
+                .flat_map(|(x, y)| [x, y])
+                .collect::<Vec<_>>();
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into()))
+        }
+        CoordBuffer::Separated(_) => {
+            let (x, y): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+            CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), y.into()))
+        }
+    };
+    Ok(output)
 }
 
 impl Reproject for PointArray {
@@ -29,6 +64,12 @@ impl Reproject for PointArray {
 
         Ok(output_array.into())
     }
+
+    fn reproject_pipeline(&self, pipeline: &str) -> Result<Self> {
+        let proj = Proj::new(pipeline)?;
+        let coords = transform_coords(&self.coords, &proj)?;
+        Ok(self.clone().with_coords(coords))
+    }
 }
 
 macro_rules! iter_geo_impl {
@@ -48,6 +89,12 @@ macro_rules! iter_geo_impl {
 
                 Ok(output_array.into())
             }
+
+            fn reproject_pipeline(&self, pipeline: &str) -> Result<Self> {
+                let proj = Proj::new(pipeline)?;
+                let coords = transform_coords(&self.coords, &proj)?;
+                Ok(self.clone().with_coords(coords))
+            }
         }
     };
 }
@@ -72,6 +119,7 @@ mod test {
     use approx::assert_relative_eq;
 
     use super::*;
+    use crate::test::linestring::{ls0, ls1};
     use crate::test::point::{p0, p1, p2};
 
     #[test]
@@ -88,4 +136,21 @@ mod test {
         assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
         dbg!(out);
     }
+
+    #[test]
+    fn pipeline_on_coords() {
+        let line_array: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let pipeline = "+proj=pipeline +step +proj=affine +xoff=10 +yoff=-5";
+
+        let out = line_array.reproject_pipeline(pipeline).unwrap();
+        assert!(out.get_as_geo(1).is_none());
+        let mut expected = ls1();
+        expected.0.iter_mut().for_each(|c| {
+            c.x += 10.;
+            c.y -= 5.;
+        });
+        assert_eq!(out.value_as_geo(2), expected);
+
+        assert!(line_array.reproject_pipeline("+proj=nonexistent").is_err());
+    }
 }
@@ -1,9 +1,18 @@
 //! Bindings to the [`rstar`] crate for dynamic R-Trees.
 
+use std::collections::HashMap;
+
 use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::PointTrait;
 use crate::trait_::GeometryArrayAccessor;
-use arrow_array::OffsetSizeTrait;
-use rstar::primitives::CachedEnvelope;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::{Float64Builder, ListBuilder, UInt32Builder};
+use arrow_array::{Float64Array, ListArray, OffsetSizeTrait, UInt32Array};
+use geo::{BoundingRect, EuclideanDistance};
+use rstar::primitives::{CachedEnvelope, GeomWithData, Rectangle};
+use rstar::{RTreeObject, AABB};
 
 /// Construct an R-Tree from a geometry array.
 pub trait RTree<'a> {
@@ -58,3 +67,647 @@ iter_cached_impl!(
     GeometryCollectionArray<O>,
     crate::scalar::GeometryCollection<'a, O>
 );
+
+/// The bounding box of a geometry, tagged with the index of its row.
+pub(crate) type RowEnvelope = GeomWithData<Rectangle<[f64; 2]>, usize>;
+
+/// Build an R-Tree of the bounding boxes of the non-null geometries of an array.
+pub(crate) fn envelope_tree<'a, A>(array: &'a A) -> rstar::RTree<RowEnvelope>
+where
+    A: GeometryArrayAccessor<'a>,
+    A::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+{
+    let envelopes = array
+        .iter()
+        .enumerate()
+        .filter_map(|(geom_idx, maybe_geom)| {
+            maybe_geom.map(|geom| {
+                let envelope = geom.envelope();
+                GeomWithData::new(
+                    Rectangle::from_corners(envelope.lower(), envelope.upper()),
+                    geom_idx,
+                )
+            })
+        })
+        .collect();
+    rstar::RTree::bulk_load(envelopes)
+}
+
+/// An R-Tree of geometry bounding boxes keyed by row id, which can be updated in place.
+///
+/// Unlike [`RTree::rstar_tree`], which borrows the array it was built from, this index only
+/// stores the bounding box of each row. This means that it can be built once and then kept in
+/// sync with a growing dataset, e.g. by calling [`extend`][Self::extend] with each new chunk of a
+/// chunked array, instead of being rebuilt after every change.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::rstar::RTreeIndex;
+/// use geoarrow::array::PointArray;
+/// use geoarrow::GeometryArrayTrait;
+/// use geo::point;
+/// use rstar::AABB;
+///
+/// let chunk: PointArray = vec![point!(x: 0., y: 0.), point!(x: 5., y: 5.)].as_slice().into();
+/// let mut index = RTreeIndex::new(&chunk);
+///
+/// // A new chunk arrives
+/// let next_chunk: PointArray = vec![point!(x: 1., y: 1.)].as_slice().into();
+/// index.extend(&next_chunk, chunk.len());
+/// index.remove(0);
+///
+/// let envelope = AABB::from_corners([-1., -1.], [2., 2.]);
+/// assert_eq!(index.locate_in_envelope_intersecting(&envelope), vec![2]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RTreeIndex {
+    tree: rstar::RTree<RowEnvelope>,
+    envelopes: HashMap<usize, AABB<[f64; 2]>>,
+}
+
+impl RTreeIndex {
+    /// Build an index of the non-null geometries of an array, keyed by their position in the
+    /// array.
+    pub fn new<'a, A>(array: &'a A) -> Self
+    where
+        A: GeometryArrayAccessor<'a>,
+        A::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+    {
+        let tree = envelope_tree(array);
+        let envelopes = tree
+            .iter()
+            .map(|envelope| (envelope.data, envelope.geom().envelope()))
+            .collect();
+        Self { tree, envelopes }
+    }
+
+    /// The number of rows in the index.
+    pub fn len(&self) -> usize {
+        self.envelopes.len()
+    }
+
+    /// Returns `true` if the index contains no rows.
+    pub fn is_empty(&self) -> bool {
+        self.envelopes.is_empty()
+    }
+
+    /// Returns `true` if the index contains the row `row_id`.
+    pub fn contains(&self, row_id: usize) -> bool {
+        self.envelopes.contains_key(&row_id)
+    }
+
+    /// The bounding box of the row `row_id`, if it is in the index.
+    pub fn envelope(&self, row_id: usize) -> Option<&AABB<[f64; 2]>> {
+        self.envelopes.get(&row_id)
+    }
+
+    /// Insert a geometry with the given row id, replacing any geometry previously stored with that
+    /// id.
+    pub fn insert(
+        &mut self,
+        row_id: usize,
+        geometry: &impl RTreeObject<Envelope = AABB<[f64; 2]>>,
+    ) {
+        self.remove(row_id);
+        let envelope = geometry.envelope();
+        self.tree
+            .insert(GeomWithData::new(Rectangle::from_aabb(envelope), row_id));
+        self.envelopes.insert(row_id, envelope);
+    }
+
+    /// Insert the non-null geometries of an array, with row ids starting at `offset`.
+    ///
+    /// Null geometries are not inserted, but any geometry previously stored with their row ids is
+    /// removed.
+    pub fn extend<'a, A>(&mut self, array: &'a A, offset: usize)
+    where
+        A: GeometryArrayAccessor<'a>,
+        A::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+    {
+        for (geom_idx, maybe_geom) in array.iter().enumerate() {
+            match maybe_geom {
+                Some(geom) => self.insert(offset + geom_idx, &geom),
+                None => {
+                    self.remove(offset + geom_idx);
+                }
+            }
+        }
+    }
+
+    /// Remove the row `row_id` from the index, returning `true` if it was present.
+    pub fn remove(&mut self, row_id: usize) -> bool {
+        let Some(envelope) = self.envelopes.remove(&row_id) else {
+            return false;
+        };
+        self.tree
+            .remove(&GeomWithData::new(Rectangle::from_aabb(envelope), row_id))
+            .is_some()
+    }
+
+    /// The row ids of the geometries whose bounding boxes intersect `envelope`, in ascending
+    /// order.
+    pub fn locate_in_envelope_intersecting(&self, envelope: &AABB<[f64; 2]>) -> Vec<usize> {
+        let mut row_ids = self
+            .tree
+            .locate_in_envelope_intersecting(envelope)
+            .map(|row_envelope| row_envelope.data)
+            .collect::<Vec<_>>();
+        row_ids.sort_unstable();
+        row_ids
+    }
+
+    /// The row ids of the geometries in order of the distance from `point` to their bounding
+    /// boxes.
+    pub fn nearest_neighbor_iter(&self, point: &[f64; 2]) -> impl Iterator<Item = usize> + '_ {
+        self.tree
+            .nearest_neighbor_iter(point)
+            .map(|row_envelope| row_envelope.data)
+    }
+}
+
+/// An R-Tree index over a chunked geometry array, which is queried without concatenating its
+/// chunks.
+///
+/// One R-Tree is built per chunk, plus a top-level R-Tree over the bounding boxes of the chunks,
+/// so that chunks that cannot contain results are skipped entirely. Results are returned as
+/// `(chunk index, row index within chunk)` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::rstar::ChunkedRTree;
+/// use geoarrow::array::PointArray;
+/// use geoarrow::chunked_array::ChunkedGeometryArray;
+/// use geo::point;
+/// use rstar::AABB;
+///
+/// let chunked = ChunkedGeometryArray::new(vec![
+///     PointArray::from(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)].as_slice()),
+///     PointArray::from(vec![point!(x: 10., y: 10.), point!(x: 0.5, y: 0.5)].as_slice()),
+/// ]);
+/// let index = ChunkedRTree::new(&chunked);
+///
+/// let envelope = AABB::from_corners([0.2, 0.2], [2., 2.]);
+/// assert_eq!(index.locate_in_envelope_intersecting(&envelope), vec![(0, 1), (1, 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedRTree {
+    chunk_trees: Vec<rstar::RTree<RowEnvelope>>,
+    /// The bounding box of each non-empty chunk, tagged with the index of the chunk.
+    chunk_envelopes: rstar::RTree<RowEnvelope>,
+}
+
+impl ChunkedRTree {
+    /// Build an index of the non-null geometries of a chunked array.
+    pub fn new<A>(chunked: &ChunkedGeometryArray<A>) -> Self
+    where
+        A: GeometryArrayTrait + for<'a> GeometryArrayAccessor<'a>,
+        for<'a> <A as GeometryArrayAccessor<'a>>::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+    {
+        let chunk_trees = chunked
+            .chunks()
+            .iter()
+            .map(|chunk| envelope_tree(chunk))
+            .collect::<Vec<_>>();
+        let chunk_envelopes = chunk_trees
+            .iter()
+            .enumerate()
+            .filter(|(_, tree)| tree.size() > 0)
+            .map(|(chunk_idx, tree)| {
+                GeomWithData::new(Rectangle::from_aabb(tree.root().envelope()), chunk_idx)
+            })
+            .collect();
+        Self {
+            chunk_trees,
+            chunk_envelopes: rstar::RTree::bulk_load(chunk_envelopes),
+        }
+    }
+
+    /// The number of chunks in the index.
+    pub fn num_chunks(&self) -> usize {
+        self.chunk_trees.len()
+    }
+
+    /// The number of non-null geometries in the index.
+    pub fn len(&self) -> usize {
+        self.chunk_trees.iter().map(|tree| tree.size()).sum()
+    }
+
+    /// Returns `true` if the index contains no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The `(chunk index, row index)` pairs of the geometries whose bounding boxes intersect
+    /// `envelope`, in ascending order.
+    pub fn locate_in_envelope_intersecting(
+        &self,
+        envelope: &AABB<[f64; 2]>,
+    ) -> Vec<(usize, usize)> {
+        let mut locations = self
+            .chunk_envelopes
+            .locate_in_envelope_intersecting(envelope)
+            .flat_map(|chunk_envelope| {
+                let chunk_idx = chunk_envelope.data;
+                self.chunk_trees[chunk_idx]
+                    .locate_in_envelope_intersecting(envelope)
+                    .map(move |row_envelope| (chunk_idx, row_envelope.data))
+            })
+            .collect::<Vec<_>>();
+        locations.sort_unstable();
+        locations
+    }
+
+    /// The `(chunk index, row index)` pairs of the geometries whose bounding boxes contain
+    /// `point`, in ascending order.
+    pub fn locate_all_at_point(&self, point: &[f64; 2]) -> Vec<(usize, usize)> {
+        self.locate_in_envelope_intersecting(&AABB::from_point(*point))
+    }
+}
+
+/// Find the geometries of an array within `distance` of `geometry`.
+///
+/// Candidates are found by searching an R-Tree of the array's bounding boxes with the bounding box
+/// of `geometry` expanded by `distance`, and are then filtered by their exact planar distance to
+/// `geometry`. Returns the indices of the matching rows in ascending order. Null geometries never
+/// match.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::rstar::query_within_distance;
+/// use geoarrow::array::PointArray;
+/// use arrow_array::UInt32Array;
+/// use geo::point;
+///
+/// let array: PointArray = vec![point!(x: 0., y: 0.), point!(x: 3., y: 4.)].as_slice().into();
+/// let query = point!(x: 0., y: 0.).into();
+/// let indices = query_within_distance(&array, &query, 4.9).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![0]));
+/// ```
+pub fn query_within_distance<'a, A>(
+    array: &'a A,
+    geometry: &geo::Geometry,
+    distance: f64,
+) -> Result<UInt32Array>
+where
+    A: GeometryArrayAccessor<'a>,
+    A::Item: RTreeObject<Envelope = AABB<[f64; 2]>> + Into<geo::Geometry>,
+{
+    if distance.is_nan() || distance < 0. {
+        return Err(GeoArrowError::General(format!(
+            "Distance must be non-negative, got {distance}"
+        )));
+    }
+    let Some(bbox) = geometry.bounding_rect() else {
+        return Ok(UInt32Array::from(Vec::<u32>::new()));
+    };
+
+    let tree = envelope_tree(array);
+    let search_envelope = AABB::from_corners(
+        [bbox.min().x - distance, bbox.min().y - distance],
+        [bbox.max().x + distance, bbox.max().y + distance],
+    );
+    let mut indices = tree
+        .locate_in_envelope_intersecting(&search_envelope)
+        .map(|candidate| candidate.data)
+        .filter(|geom_idx| {
+            let candidate: geo::Geometry = array.value(*geom_idx).into();
+            candidate.euclidean_distance(geometry) <= distance
+        })
+        .map(|geom_idx| geom_idx as u32)
+        .collect::<Vec<_>>();
+    indices.sort_unstable();
+    Ok(indices.into())
+}
+
+/// Find the pairs of rows of two arrays whose bounding boxes intersect.
+///
+/// This is the usual first stage of a spatial join or overlay: exact predicates then only need to
+/// be evaluated on the candidate pairs. A single R-Tree is built over the bounding boxes of the
+/// shorter array and queried with the bounding box of each geometry of the other array. Boxes
+/// that only touch intersect, and null geometries never match.
+///
+/// Returns the indices into `left` and into `right` of each candidate pair, sorted by left index
+/// and then right index, which can be passed to [`Take`][crate::algorithm::native::Take].
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::rstar::envelope_intersection_candidates;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use arrow_array::UInt32Array;
+/// use geo::{line_string, point};
+///
+/// let points: PointArray = vec![point!(x: 0., y: 0.), point!(x: 5., y: 5.)].as_slice().into();
+/// let lines: LineStringArray<i32> = vec![
+///     line_string![(x: 4., y: 4.), (x: 6., y: 6.)],
+///     line_string![(x: -1., y: 1.), (x: 6., y: 1.)],
+/// ]
+/// .as_slice()
+/// .into();
+///
+/// let (point_indices, line_indices) = envelope_intersection_candidates(&points, &lines);
+/// assert_eq!(point_indices, UInt32Array::from(vec![1]));
+/// assert_eq!(line_indices, UInt32Array::from(vec![0]));
+/// ```
+pub fn envelope_intersection_candidates<'a, 'b, L, R>(
+    left: &'a L,
+    right: &'b R,
+) -> (UInt32Array, UInt32Array)
+where
+    L: GeometryArrayAccessor<'a>,
+    L::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+    R: GeometryArrayAccessor<'b>,
+    R::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+{
+    let mut pairs = if left.len() <= right.len() {
+        candidate_pairs(left, right)
+    } else {
+        candidate_pairs(right, left)
+            .into_iter()
+            .map(|(right_idx, left_idx)| (left_idx, right_idx))
+            .collect()
+    };
+    pairs.sort_unstable();
+    let left_indices = pairs.iter().map(|(left_idx, _)| *left_idx);
+    let right_indices = pairs.iter().map(|(_, right_idx)| *right_idx);
+    (left_indices.collect(), right_indices.collect())
+}
+
+/// The `(indexed row, query row)` pairs whose bounding boxes intersect, using an R-Tree of
+/// `indexed`.
+fn candidate_pairs<'a, 'b, I, Q>(indexed: &'a I, queries: &'b Q) -> Vec<(u32, u32)>
+where
+    I: GeometryArrayAccessor<'a>,
+    I::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+    Q: GeometryArrayAccessor<'b>,
+    Q::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+{
+    let tree = envelope_tree(indexed);
+    queries
+        .iter()
+        .enumerate()
+        .filter_map(|(query_idx, maybe_geom)| maybe_geom.map(|geom| (query_idx, geom.envelope())))
+        .flat_map(|(query_idx, envelope)| {
+            tree.locate_in_envelope_intersecting(&envelope)
+                .map(move |candidate| (candidate.data as u32, query_idx as u32))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Find the geometries of an array nearest to query points.
+///
+/// Candidates are visited in order of the distance from the query point to their bounding boxes,
+/// so only geometries whose bounding boxes are nearer than the k-th nearest geometry found so far
+/// have their exact planar distance computed. Null geometries are never returned.
+pub trait NearestNeighbors<'a> {
+    /// The indices of the `k` geometries nearest to `query`, and their distances to it.
+    ///
+    /// Results are ordered by distance, with ties broken by row index. Fewer than `k` results are
+    /// returned if the array has fewer than `k` non-null geometries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::rstar::NearestNeighbors;
+    /// use geoarrow::array::PointArray;
+    /// use arrow_array::UInt32Array;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![
+    ///     point!(x: 0., y: 0.),
+    ///     point!(x: 3., y: 4.),
+    ///     point!(x: 1., y: 0.),
+    /// ]
+    /// .as_slice()
+    /// .into();
+    /// let (indices, distances) = array.nearest_k(&point!(x: 0., y: 0.), 2);
+    /// assert_eq!(indices, UInt32Array::from(vec![0, 2]));
+    /// assert_eq!(distances.values(), &[0., 1.]);
+    /// ```
+    fn nearest_k(
+        &'a self,
+        query: &impl PointTrait<T = f64>,
+        k: usize,
+    ) -> (UInt32Array, Float64Array);
+
+    /// The indices of the `k` geometries nearest to each query point, and their distances to it.
+    ///
+    /// The R-Tree is built once and shared by all queries. Null query points have null lists.
+    fn nearest_k_batch(&'a self, queries: &PointArray, k: usize) -> (ListArray, ListArray);
+}
+
+/// The `k` nearest geometries to `query`, as `(row index, distance)` pairs.
+fn nearest_k_in_tree<'a, A>(
+    array: &'a A,
+    tree: &rstar::RTree<RowEnvelope>,
+    query: &impl PointTrait<T = f64>,
+    k: usize,
+) -> Vec<(usize, f64)>
+where
+    A: GeometryArrayAccessor<'a>,
+    A::Item: Into<geo::Geometry>,
+{
+    let query_point = geo::Point::new(query.x(), query.y());
+    let mut nearest: Vec<(usize, f64)> = Vec::with_capacity(k);
+    if k == 0 {
+        return nearest;
+    }
+
+    for (candidate, envelope_distance_2) in
+        tree.nearest_neighbor_iter_with_distance_2(&[query.x(), query.y()])
+    {
+        // No remaining geometry can be nearer than the current k-th nearest
+        if nearest.len() == k && envelope_distance_2.sqrt() > nearest[k - 1].1 {
+            break;
+        }
+
+        let geom: geo::Geometry = array.value(candidate.data).into();
+        let distance = geom.euclidean_distance(&query_point);
+        let position = nearest.partition_point(|(geom_idx, other_distance)| {
+            (*other_distance, *geom_idx) < (distance, candidate.data)
+        });
+        if position < k {
+            nearest.insert(position, (candidate.data, distance));
+            nearest.truncate(k);
+        }
+    }
+    nearest
+}
+
+impl<'a, A> NearestNeighbors<'a> for A
+where
+    A: GeometryArrayAccessor<'a>,
+    A::Item: RTreeObject<Envelope = AABB<[f64; 2]>> + Into<geo::Geometry>,
+{
+    fn nearest_k(
+        &'a self,
+        query: &impl PointTrait<T = f64>,
+        k: usize,
+    ) -> (UInt32Array, Float64Array) {
+        let tree = envelope_tree(self);
+        let nearest = nearest_k_in_tree(self, &tree, query, k);
+        let indices = nearest.iter().map(|(geom_idx, _)| *geom_idx as u32);
+        let distances = nearest.iter().map(|(_, distance)| *distance);
+        (indices.collect(), distances.collect())
+    }
+
+    fn nearest_k_batch(&'a self, queries: &PointArray, k: usize) -> (ListArray, ListArray) {
+        let tree = envelope_tree(self);
+        let mut indices = ListBuilder::with_capacity(UInt32Builder::new(), queries.len());
+        let mut distances = ListBuilder::with_capacity(Float64Builder::new(), queries.len());
+        for maybe_query in queries.iter() {
+            if let Some(query) = maybe_query {
+                for (geom_idx, distance) in nearest_k_in_tree(self, &tree, &query, k) {
+                    indices.values().append_value(geom_idx as u32);
+                    distances.values().append_value(distance);
+                }
+                indices.append(true);
+                distances.append(true);
+            } else {
+                indices.append_null();
+                distances.append_null();
+            }
+        }
+        (indices.finish(), distances.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, UInt32Type};
+    use arrow_array::Array;
+    use geo::{line_string, point};
+
+    #[test]
+    fn within_distance_of_line_string() {
+        let array: PointArray = vec![
+            Some(point!(x: 0., y: 1.)),
+            None,
+            Some(point!(x: 5., y: 0.5)),
+            Some(point!(x: 11.5, y: 0.)),
+            Some(point!(x: 10.5, y: 0.5)),
+        ]
+        .into();
+        let query = line_string![(x: 0., y: 0.), (x: 10., y: 0.)].into();
+        let indices = query_within_distance(&array, &query, 1.).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 2, 4]));
+
+        // The corner of the search envelope is not within the distance
+        let corner: PointArray = vec![point!(x: 10.9, y: 0.9)].as_slice().into();
+        let indices = query_within_distance(&corner, &query, 1.).unwrap();
+        assert!(indices.is_empty());
+
+        assert!(query_within_distance(&array, &query, -1.).is_err());
+    }
+
+    #[test]
+    fn nearest_k_line_strings() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 5.), (x: 10., y: 5.)]),
+            None,
+            Some(line_string![(x: 0., y: -2.), (x: 10., y: -2.)]),
+            // The bounding box contains the query point, but the line is further than the others
+            Some(line_string![(x: -1., y: -1.), (x: -1., y: 20.), (x: 20., y: 20.)]),
+        ]
+        .into();
+        let (indices, distances) = array.nearest_k(&point!(x: 5., y: 0.), 2);
+        assert_eq!(indices, UInt32Array::from(vec![2, 0]));
+        assert_eq!(distances.values(), &[2., 5.]);
+
+        let (indices, _) = array.nearest_k(&point!(x: 5., y: 0.), 10);
+        assert_eq!(indices.len(), 3);
+    }
+
+    #[test]
+    fn update_index() {
+        let array: PointArray =
+            vec![Some(point!(x: 0., y: 0.)), None, Some(point!(x: 2., y: 2.))].into();
+        let mut index = RTreeIndex::new(&array);
+        assert_eq!(index.len(), 2);
+        assert!(!index.contains(1));
+
+        let envelope = AABB::from_corners([-1., -1.], [1., 1.]);
+        assert_eq!(index.locate_in_envelope_intersecting(&envelope), vec![0]);
+
+        // Replace the geometry of row 2 with one inside the envelope
+        let replacement: PointArray = vec![point!(x: 0.5, y: 0.5)].as_slice().into();
+        index.insert(2, &replacement.value(0));
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.locate_in_envelope_intersecting(&envelope), vec![0, 2]);
+
+        assert!(index.remove(0));
+        assert!(!index.remove(0));
+        assert_eq!(index.locate_in_envelope_intersecting(&envelope), vec![2]);
+
+        index.extend(&array, 3);
+        assert_eq!(index.locate_in_envelope_intersecting(&envelope), vec![2, 3]);
+        assert_eq!(index.nearest_neighbor_iter(&[2., 2.]).next(), Some(5));
+    }
+
+    #[test]
+    fn chunked_index() {
+        let chunked = ChunkedGeometryArray::new(vec![
+            PointArray::from(vec![Some(point!(x: 0., y: 0.)), None]),
+            PointArray::from(Vec::<Option<geo::Point>>::new()),
+            PointArray::from(vec![point!(x: 5., y: 5.), point!(x: 1., y: 0.)].as_slice()),
+        ]);
+        let index = ChunkedRTree::new(&chunked);
+        assert_eq!(index.num_chunks(), 3);
+        assert_eq!(index.len(), 3);
+
+        let envelope = AABB::from_corners([0., 0.], [1., 1.]);
+        assert_eq!(
+            index.locate_in_envelope_intersecting(&envelope),
+            vec![(0, 0), (2, 1)]
+        );
+        assert_eq!(index.locate_all_at_point(&[5., 5.]), vec![(2, 0)]);
+        assert!(index.locate_all_at_point(&[3., 3.]).is_empty());
+    }
+
+    #[test]
+    fn nearest_k_batch() {
+        let array: PointArray = vec![point!(x: 0., y: 0.), point!(x: 10., y: 0.)]
+            .as_slice()
+            .into();
+        let queries: PointArray = vec![Some(point!(x: 9., y: 0.)), None].into();
+        let (indices, distances) = array.nearest_k_batch(&queries, 1);
+        assert!(indices.is_null(1));
+        assert_eq!(indices.value(0).as_primitive::<UInt32Type>().value(0), 1);
+        assert_eq!(
+            distances.value(0).as_primitive::<Float64Type>().value(0),
+            1.
+        );
+    }
+
+    #[test]
+    fn envelope_candidates() {
+        let points: PointArray = vec![
+            Some(point!(x: 0., y: 0.)),
+            None,
+            Some(point!(x: 2., y: 2.)),
+            Some(point!(x: 9., y: 9.)),
+        ]
+        .into();
+        let rects: RectArray = vec![
+            Some(geo::Rect::new((-1., -1.), (2., 2.))),
+            Some(geo::Rect::new((1., 1.), (3., 3.))),
+        ]
+        .into();
+
+        let expected_left = UInt32Array::from(vec![0, 2, 2]);
+        let expected_right = UInt32Array::from(vec![0, 0, 1]);
+        let (left, right) = envelope_intersection_candidates(&points, &rects);
+        assert_eq!((&left, &right), (&expected_left, &expected_right));
+
+        // The R-Tree is built on the shorter array, which doesn't change the output
+        let (left, right) = envelope_intersection_candidates(&rects, &points);
+        assert_eq!(left, UInt32Array::from(vec![0, 0, 1]));
+        assert_eq!(right, UInt32Array::from(vec![0, 2, 2]));
+    }
+}
@@ -48,6 +48,18 @@ impl<O: OffsetSizeTrait> WKBArray<O> {
         }
     }
 
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        WKBBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = WKBBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     /// Returns true if the array is empty
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -196,6 +196,12 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         }
     }
 
+    /// Push a null value onto the end of this builder
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.0.append_null()
+    }
+
     /// Extend this builder from an iterator of Geometries.
     pub fn extend_from_iter<'a>(
         &mut self,
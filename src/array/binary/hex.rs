@@ -0,0 +1,134 @@
+use arrow_array::builder::{GenericBinaryBuilder, GenericStringBuilder};
+use arrow_array::{Array, GenericStringArray, OffsetSizeTrait};
+
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+fn hex_digit_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decode a hex string into `buf`, returning `None` if the string is not valid hex.
+fn decode_hex(hex: &str, buf: &mut Vec<u8>) -> Option<()> {
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    buf.clear();
+    for pair in hex.chunks_exact(2) {
+        buf.push(hex_digit_value(pair[0])? << 4 | hex_digit_value(pair[1])?);
+    }
+    Some(())
+}
+
+/// Encode bytes as an uppercase hex string into `buf`.
+fn encode_hex(bytes: &[u8], buf: &mut String) {
+    buf.clear();
+    for byte in bytes {
+        buf.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        buf.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+    }
+}
+
+impl<O: OffsetSizeTrait> WKBArray<O> {
+    /// Create a new WKBArray from an array of hex-encoded WKB strings, as found in many CSV files
+    /// and PostGIS dumps.
+    ///
+    /// Both uppercase and lowercase hex digits are accepted. The decoded bytes are not parsed, so
+    /// invalid WKB is only detected when the geometries are accessed. Null strings become null
+    /// geometries.
+    ///
+    /// # Errors
+    ///
+    /// Errors if any string is not valid hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::StringArray;
+    /// use geoarrow::array::WKBArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
+    /// use geo::{point, Geometry};
+    ///
+    /// let hex = StringArray::from(vec!["0101000000000000000000F03F0000000000000040"]);
+    /// let array = WKBArray::from_hex(&hex).unwrap();
+    /// assert_eq!(array.value_as_geo(0), Geometry::Point(point!(x: 1., y: 2.)));
+    /// assert_eq!(array.to_hex(), hex);
+    /// ```
+    pub fn from_hex(array: &GenericStringArray<O>) -> Result<Self> {
+        let mut builder =
+            GenericBinaryBuilder::<O>::with_capacity(array.len(), array.value_data().len() / 2);
+        let mut buf = Vec::new();
+        for (i, maybe_hex) in array.iter().enumerate() {
+            if let Some(hex) = maybe_hex {
+                decode_hex(hex, &mut buf).ok_or_else(|| {
+                    GeoArrowError::General(format!("invalid hex-encoded WKB at index {i}"))
+                })?;
+                builder.append_value(&buf);
+            } else {
+                builder.append_null();
+            }
+        }
+        Ok(Self::new(builder.finish(), Default::default()))
+    }
+
+    /// Encode the WKB of each geometry as an uppercase hex string. Null geometries become null
+    /// strings.
+    pub fn to_hex(&self) -> GenericStringArray<O> {
+        let mut builder =
+            GenericStringBuilder::<O>::with_capacity(self.len(), self.array.value_data().len() * 2);
+        let mut buf = String::new();
+        for maybe_wkb in self.array.iter() {
+            if let Some(wkb) = maybe_wkb {
+                encode_hex(wkb, &mut buf);
+                builder.append_value(&buf);
+            } else {
+                builder.append_null();
+            }
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::{p0, p1};
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::LargeStringArray;
+
+    #[test]
+    fn round_trip() {
+        let geometries = vec![
+            Some(geo::Geometry::Point(p0())),
+            None,
+            Some(geo::Geometry::Point(p1())),
+        ];
+        let array: WKBArray<i64> = geometries.as_slice().try_into().unwrap();
+
+        let hex = array.to_hex();
+        assert!(hex.is_null(1));
+        let round_tripped = WKBArray::from_hex(&hex).unwrap();
+        assert_eq!(round_tripped.get_as_geo(0), geometries[0]);
+        assert!(round_tripped.is_null(1));
+        assert_eq!(round_tripped.get_as_geo(2), geometries[2]);
+    }
+
+    #[test]
+    fn lowercase_and_invalid() {
+        let hex = LargeStringArray::from(vec!["0101000000000000000000f03f0000000000000040"]);
+        assert!(WKBArray::from_hex(&hex).is_ok());
+
+        let hex = LargeStringArray::from(vec!["010", "zz"]);
+        assert!(WKBArray::from_hex(&hex).is_err());
+    }
+}
@@ -8,3 +8,4 @@ pub use capacity::WKBCapacity;
 mod array;
 mod builder;
 mod capacity;
+mod hex;
@@ -1,5 +1,6 @@
 use crate::array::*;
 use crate::chunked_array::*;
+use crate::trait_::GeometryArrayRef;
 
 /// Helpers for downcasting a [`GeometryArrayTrait`] to a concrete implementation.
 pub trait AsGeometryArray {
@@ -174,98 +175,105 @@ pub trait AsGeometryArray {
     }
 }
 
-impl AsGeometryArray for &dyn GeometryArrayTrait {
-    #[inline]
-    fn as_point_opt(&self) -> Option<&PointArray> {
-        self.as_any().downcast_ref::<PointArray>()
-    }
-
-    #[inline]
-    fn as_line_string_opt(&self) -> Option<&LineStringArray<i32>> {
-        self.as_any().downcast_ref::<LineStringArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_line_string_opt(&self) -> Option<&LineStringArray<i64>> {
-        self.as_any().downcast_ref::<LineStringArray<i64>>()
-    }
-
-    #[inline]
-    fn as_polygon_opt(&self) -> Option<&PolygonArray<i32>> {
-        self.as_any().downcast_ref::<PolygonArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_polygon_opt(&self) -> Option<&PolygonArray<i64>> {
-        self.as_any().downcast_ref::<PolygonArray<i64>>()
-    }
-
-    #[inline]
-    fn as_multi_point_opt(&self) -> Option<&MultiPointArray<i32>> {
-        self.as_any().downcast_ref::<MultiPointArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_multi_point_opt(&self) -> Option<&MultiPointArray<i64>> {
-        self.as_any().downcast_ref::<MultiPointArray<i64>>()
-    }
-
-    #[inline]
-    fn as_multi_line_string_opt(&self) -> Option<&MultiLineStringArray<i32>> {
-        self.as_any().downcast_ref::<MultiLineStringArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_multi_line_string_opt(&self) -> Option<&MultiLineStringArray<i64>> {
-        self.as_any().downcast_ref::<MultiLineStringArray<i64>>()
-    }
-
-    #[inline]
-    fn as_multi_polygon_opt(&self) -> Option<&MultiPolygonArray<i32>> {
-        self.as_any().downcast_ref::<MultiPolygonArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_multi_polygon_opt(&self) -> Option<&MultiPolygonArray<i64>> {
-        self.as_any().downcast_ref::<MultiPolygonArray<i64>>()
-    }
-
-    #[inline]
-    fn as_mixed_opt(&self) -> Option<&MixedGeometryArray<i32>> {
-        self.as_any().downcast_ref::<MixedGeometryArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_mixed_opt(&self) -> Option<&MixedGeometryArray<i64>> {
-        self.as_any().downcast_ref::<MixedGeometryArray<i64>>()
-    }
-
-    #[inline]
-    fn as_geometry_collection_opt(&self) -> Option<&GeometryCollectionArray<i32>> {
-        self.as_any().downcast_ref::<GeometryCollectionArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_geometry_collection_opt(&self) -> Option<&GeometryCollectionArray<i64>> {
-        self.as_any().downcast_ref::<GeometryCollectionArray<i64>>()
-    }
-
-    #[inline]
-    fn as_wkb_opt(&self) -> Option<&WKBArray<i32>> {
-        self.as_any().downcast_ref::<WKBArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_wkb_opt(&self) -> Option<&WKBArray<i64>> {
-        self.as_any().downcast_ref::<WKBArray<i64>>()
-    }
-
-    #[inline]
-    fn as_rect_opt(&self) -> Option<&RectArray> {
-        self.as_any().downcast_ref::<RectArray>()
-    }
+macro_rules! impl_as_geometry_array {
+    ($type:ty) => {
+        impl AsGeometryArray for $type {
+            #[inline]
+            fn as_point_opt(&self) -> Option<&PointArray> {
+                self.as_any().downcast_ref::<PointArray>()
+            }
+
+            #[inline]
+            fn as_line_string_opt(&self) -> Option<&LineStringArray<i32>> {
+                self.as_any().downcast_ref::<LineStringArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_line_string_opt(&self) -> Option<&LineStringArray<i64>> {
+                self.as_any().downcast_ref::<LineStringArray<i64>>()
+            }
+
+            #[inline]
+            fn as_polygon_opt(&self) -> Option<&PolygonArray<i32>> {
+                self.as_any().downcast_ref::<PolygonArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_polygon_opt(&self) -> Option<&PolygonArray<i64>> {
+                self.as_any().downcast_ref::<PolygonArray<i64>>()
+            }
+
+            #[inline]
+            fn as_multi_point_opt(&self) -> Option<&MultiPointArray<i32>> {
+                self.as_any().downcast_ref::<MultiPointArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_multi_point_opt(&self) -> Option<&MultiPointArray<i64>> {
+                self.as_any().downcast_ref::<MultiPointArray<i64>>()
+            }
+
+            #[inline]
+            fn as_multi_line_string_opt(&self) -> Option<&MultiLineStringArray<i32>> {
+                self.as_any().downcast_ref::<MultiLineStringArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_multi_line_string_opt(&self) -> Option<&MultiLineStringArray<i64>> {
+                self.as_any().downcast_ref::<MultiLineStringArray<i64>>()
+            }
+
+            #[inline]
+            fn as_multi_polygon_opt(&self) -> Option<&MultiPolygonArray<i32>> {
+                self.as_any().downcast_ref::<MultiPolygonArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_multi_polygon_opt(&self) -> Option<&MultiPolygonArray<i64>> {
+                self.as_any().downcast_ref::<MultiPolygonArray<i64>>()
+            }
+
+            #[inline]
+            fn as_mixed_opt(&self) -> Option<&MixedGeometryArray<i32>> {
+                self.as_any().downcast_ref::<MixedGeometryArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_mixed_opt(&self) -> Option<&MixedGeometryArray<i64>> {
+                self.as_any().downcast_ref::<MixedGeometryArray<i64>>()
+            }
+
+            #[inline]
+            fn as_geometry_collection_opt(&self) -> Option<&GeometryCollectionArray<i32>> {
+                self.as_any().downcast_ref::<GeometryCollectionArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_geometry_collection_opt(&self) -> Option<&GeometryCollectionArray<i64>> {
+                self.as_any().downcast_ref::<GeometryCollectionArray<i64>>()
+            }
+
+            #[inline]
+            fn as_wkb_opt(&self) -> Option<&WKBArray<i32>> {
+                self.as_any().downcast_ref::<WKBArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_wkb_opt(&self) -> Option<&WKBArray<i64>> {
+                self.as_any().downcast_ref::<WKBArray<i64>>()
+            }
+
+            #[inline]
+            fn as_rect_opt(&self) -> Option<&RectArray> {
+                self.as_any().downcast_ref::<RectArray>()
+            }
+        }
+    };
 }
 
+impl_as_geometry_array!(&dyn GeometryArrayTrait);
+impl_as_geometry_array!(GeometryArrayRef);
+
 /// Helpers for downcasting a [`ChunkedGeometryArrayTrait`] to a concrete implementation.
 pub trait AsChunkedGeometryArray {
     /// Downcast this to a [`ChunkedPointArray`] returning `None` if not possible
@@ -439,102 +447,131 @@ pub trait AsChunkedGeometryArray {
     }
 }
 
-impl AsChunkedGeometryArray for &dyn ChunkedGeometryArrayTrait {
-    #[inline]
-    fn as_point_opt(&self) -> Option<&ChunkedPointArray> {
-        self.as_any().downcast_ref::<ChunkedPointArray>()
-    }
-
-    #[inline]
-    fn as_line_string_opt(&self) -> Option<&ChunkedLineStringArray<i32>> {
-        self.as_any().downcast_ref::<ChunkedLineStringArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_line_string_opt(&self) -> Option<&ChunkedLineStringArray<i64>> {
-        self.as_any().downcast_ref::<ChunkedLineStringArray<i64>>()
-    }
-
-    #[inline]
-    fn as_polygon_opt(&self) -> Option<&ChunkedPolygonArray<i32>> {
-        self.as_any().downcast_ref::<ChunkedPolygonArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_polygon_opt(&self) -> Option<&ChunkedPolygonArray<i64>> {
-        self.as_any().downcast_ref::<ChunkedPolygonArray<i64>>()
-    }
-
-    #[inline]
-    fn as_multi_point_opt(&self) -> Option<&ChunkedMultiPointArray<i32>> {
-        self.as_any().downcast_ref::<ChunkedMultiPointArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_multi_point_opt(&self) -> Option<&ChunkedMultiPointArray<i64>> {
-        self.as_any().downcast_ref::<ChunkedMultiPointArray<i64>>()
-    }
-
-    #[inline]
-    fn as_multi_line_string_opt(&self) -> Option<&ChunkedMultiLineStringArray<i32>> {
-        self.as_any()
-            .downcast_ref::<ChunkedMultiLineStringArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_multi_line_string_opt(&self) -> Option<&ChunkedMultiLineStringArray<i64>> {
-        self.as_any()
-            .downcast_ref::<ChunkedMultiLineStringArray<i64>>()
-    }
-
-    #[inline]
-    fn as_multi_polygon_opt(&self) -> Option<&ChunkedMultiPolygonArray<i32>> {
-        self.as_any()
-            .downcast_ref::<ChunkedMultiPolygonArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_multi_polygon_opt(&self) -> Option<&ChunkedMultiPolygonArray<i64>> {
-        self.as_any()
-            .downcast_ref::<ChunkedMultiPolygonArray<i64>>()
-    }
-
-    #[inline]
-    fn as_mixed_opt(&self) -> Option<&ChunkedMixedGeometryArray<i32>> {
-        self.as_any()
-            .downcast_ref::<ChunkedMixedGeometryArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_mixed_opt(&self) -> Option<&ChunkedMixedGeometryArray<i64>> {
-        self.as_any()
-            .downcast_ref::<ChunkedMixedGeometryArray<i64>>()
-    }
-
-    #[inline]
-    fn as_geometry_collection_opt(&self) -> Option<&ChunkedGeometryCollectionArray<i32>> {
-        self.as_any()
-            .downcast_ref::<ChunkedGeometryCollectionArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_geometry_collection_opt(&self) -> Option<&ChunkedGeometryCollectionArray<i64>> {
-        self.as_any()
-            .downcast_ref::<ChunkedGeometryCollectionArray<i64>>()
-    }
-
-    #[inline]
-    fn as_wkb_opt(&self) -> Option<&ChunkedWKBArray<i32>> {
-        self.as_any().downcast_ref::<ChunkedWKBArray<i32>>()
-    }
-
-    #[inline]
-    fn as_large_wkb_opt(&self) -> Option<&ChunkedWKBArray<i64>> {
-        self.as_any().downcast_ref::<ChunkedWKBArray<i64>>()
-    }
+macro_rules! impl_as_chunked_geometry_array {
+    ($type:ty) => {
+        impl AsChunkedGeometryArray for $type {
+            #[inline]
+            fn as_point_opt(&self) -> Option<&ChunkedPointArray> {
+                self.as_any().downcast_ref::<ChunkedPointArray>()
+            }
+
+            #[inline]
+            fn as_line_string_opt(&self) -> Option<&ChunkedLineStringArray<i32>> {
+                self.as_any().downcast_ref::<ChunkedLineStringArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_line_string_opt(&self) -> Option<&ChunkedLineStringArray<i64>> {
+                self.as_any().downcast_ref::<ChunkedLineStringArray<i64>>()
+            }
+
+            #[inline]
+            fn as_polygon_opt(&self) -> Option<&ChunkedPolygonArray<i32>> {
+                self.as_any().downcast_ref::<ChunkedPolygonArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_polygon_opt(&self) -> Option<&ChunkedPolygonArray<i64>> {
+                self.as_any().downcast_ref::<ChunkedPolygonArray<i64>>()
+            }
+
+            #[inline]
+            fn as_multi_point_opt(&self) -> Option<&ChunkedMultiPointArray<i32>> {
+                self.as_any().downcast_ref::<ChunkedMultiPointArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_multi_point_opt(&self) -> Option<&ChunkedMultiPointArray<i64>> {
+                self.as_any().downcast_ref::<ChunkedMultiPointArray<i64>>()
+            }
+
+            #[inline]
+            fn as_multi_line_string_opt(&self) -> Option<&ChunkedMultiLineStringArray<i32>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedMultiLineStringArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_multi_line_string_opt(&self) -> Option<&ChunkedMultiLineStringArray<i64>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedMultiLineStringArray<i64>>()
+            }
+
+            #[inline]
+            fn as_multi_polygon_opt(&self) -> Option<&ChunkedMultiPolygonArray<i32>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedMultiPolygonArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_multi_polygon_opt(&self) -> Option<&ChunkedMultiPolygonArray<i64>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedMultiPolygonArray<i64>>()
+            }
+
+            #[inline]
+            fn as_mixed_opt(&self) -> Option<&ChunkedMixedGeometryArray<i32>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedMixedGeometryArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_mixed_opt(&self) -> Option<&ChunkedMixedGeometryArray<i64>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedMixedGeometryArray<i64>>()
+            }
+
+            #[inline]
+            fn as_geometry_collection_opt(&self) -> Option<&ChunkedGeometryCollectionArray<i32>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedGeometryCollectionArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_geometry_collection_opt(
+                &self,
+            ) -> Option<&ChunkedGeometryCollectionArray<i64>> {
+                self.as_any()
+                    .downcast_ref::<ChunkedGeometryCollectionArray<i64>>()
+            }
+
+            #[inline]
+            fn as_wkb_opt(&self) -> Option<&ChunkedWKBArray<i32>> {
+                self.as_any().downcast_ref::<ChunkedWKBArray<i32>>()
+            }
+
+            #[inline]
+            fn as_large_wkb_opt(&self) -> Option<&ChunkedWKBArray<i64>> {
+                self.as_any().downcast_ref::<ChunkedWKBArray<i64>>()
+            }
+
+            #[inline]
+            fn as_rect_opt(&self) -> Option<&ChunkedRectArray> {
+                self.as_any().downcast_ref::<ChunkedRectArray>()
+            }
+        }
+    };
+}
 
-    #[inline]
-    fn as_rect_opt(&self) -> Option<&ChunkedRectArray> {
-        self.as_any().downcast_ref::<ChunkedRectArray>()
+impl_as_chunked_geometry_array!(&dyn ChunkedGeometryArrayTrait);
+impl_as_chunked_geometry_array!(ChunkedGeometryArrayRef);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+    use std::sync::Arc;
+
+    #[test]
+    fn downcast_array_ref() {
+        let array: GeometryArrayRef = Arc::new(polygon::p_array());
+        assert!(array.as_point_opt().is_none());
+        assert!(array.as_large_polygon_opt().is_none());
+        assert_eq!(array.as_polygon(), &polygon::p_array());
+
+        let chunked: ChunkedGeometryArrayRef =
+            Arc::new(ChunkedPointArray::new(vec![point::point_array()]));
+        assert!(chunked.as_polygon_opt().is_none());
+        assert_eq!(chunked.as_point().chunks().len(), 1);
     }
 }
@@ -1,9 +1,7 @@
 use std::sync::Arc;
 
-use crate::array::{
-    CoordType, InterleavedCoordBuffer, InterleavedCoordBufferBuilder, SeparatedCoordBuffer,
-    SeparatedCoordBufferBuilder,
-};
+use crate::algorithm::native::simd;
+use crate::array::{CoordType, InterleavedCoordBuffer, SeparatedCoordBuffer};
 use crate::error::GeoArrowError;
 use crate::scalar::Coord;
 use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
@@ -11,7 +9,6 @@ use crate::GeometryArrayTrait;
 use arrow_array::{Array, FixedSizeListArray, StructArray};
 use arrow_buffer::NullBuffer;
 use arrow_schema::{DataType, Field};
-use itertools::Itertools;
 
 /// An Arrow representation of an array of coordinates.
 ///
@@ -112,20 +109,13 @@ impl GeometryArraySelfMethods for CoordBuffer {
         match (self, coord_type) {
             (CoordBuffer::Interleaved(cb), CoordType::Interleaved) => CoordBuffer::Interleaved(cb),
             (CoordBuffer::Interleaved(cb), CoordType::Separated) => {
-                let mut new_buffer = SeparatedCoordBufferBuilder::with_capacity(cb.len());
-                cb.coords
-                    .into_iter()
-                    .tuples()
-                    .for_each(|(x, y)| new_buffer.push_xy(*x, *y));
-                CoordBuffer::Separated(new_buffer.into())
+                let (x, y) = simd::deinterleave(&cb.coords);
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), y.into()))
             }
             (CoordBuffer::Separated(cb), CoordType::Separated) => CoordBuffer::Separated(cb),
             (CoordBuffer::Separated(cb), CoordType::Interleaved) => {
-                let mut new_buffer = InterleavedCoordBufferBuilder::with_capacity(cb.len());
-                cb.x.into_iter()
-                    .zip(cb.y.iter())
-                    .for_each(|(x, y)| new_buffer.push_xy(*x, *y));
-                CoordBuffer::Interleaved(new_buffer.into())
+                let coords = simd::interleave(&cb.x, &cb.y);
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(coords.into()))
             }
         }
     }
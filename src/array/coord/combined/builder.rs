@@ -1,7 +1,10 @@
+use std::ops::Range;
+
 use crate::array::{
     CoordBuffer, CoordType, InterleavedCoordBufferBuilder, SeparatedCoordBufferBuilder,
 };
 use crate::geo_traits::{CoordTrait, PointTrait};
+use geo::CoordNum;
 
 /// The GeoArrow equivalent to `Vec<Coord>`: a mutable collection of coordinates.
 ///
@@ -66,11 +69,13 @@ impl CoordBufferBuilder {
         }
     }
 
-    pub fn push_point(&mut self, coord: &impl PointTrait<T = f64>) {
-        self.push_xy(coord.x(), coord.y())
+    /// Add a new point to the end of this builder, converting its values to `f64`.
+    pub fn push_point(&mut self, coord: &impl PointTrait<T = impl CoordNum>) {
+        self.push_xy(coord.x().to_f64().unwrap(), coord.y().to_f64().unwrap())
     }
 
-    pub fn push_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
+    /// Add a new coord to the end of this builder, converting its values to `f64`.
+    pub fn push_coord(&mut self, coord: &impl CoordTrait<T = impl CoordNum>) {
         match self {
             CoordBufferBuilder::Interleaved(cb) => cb.push_coord(coord),
             CoordBufferBuilder::Separated(cb) => cb.push_coord(coord),
@@ -91,6 +96,22 @@ impl CoordBufferBuilder {
         }
     }
 
+    /// Append the coordinates in `range` of `buffer`.
+    ///
+    /// If `buffer` has the same coordinate layout as this builder, its values are copied over as
+    /// whole slices.
+    pub fn extend_from_buffer(&mut self, buffer: &CoordBuffer, range: Range<usize>) {
+        match (self, buffer) {
+            (CoordBufferBuilder::Interleaved(cb), CoordBuffer::Interleaved(buffer)) => {
+                cb.extend_from_buffer(buffer, range)
+            }
+            (CoordBufferBuilder::Separated(cb), CoordBuffer::Separated(buffer)) => {
+                cb.extend_from_buffer(buffer, range)
+            }
+            (cb, buffer) => range.for_each(|i| cb.push_xy(buffer.get_x(i), buffer.get_y(i))),
+        }
+    }
+
     pub fn len(&self) -> usize {
         match self {
             CoordBufferBuilder::Interleaved(cb) => cb.len(),
@@ -1,4 +1,5 @@
 use core::panic;
+use geo::CoordNum;
 use std::sync::Arc;
 
 use crate::array::{CoordType, InterleavedCoordBufferBuilder};
@@ -201,7 +202,7 @@ impl From<&[f64]> for InterleavedCoordBuffer {
     }
 }
 
-impl<G: CoordTrait<T = f64>> From<&[G]> for InterleavedCoordBuffer {
+impl<T: CoordNum, G: CoordTrait<T = T>> From<&[G]> for InterleavedCoordBuffer {
     fn from(other: &[G]) -> Self {
         let mut_arr: InterleavedCoordBufferBuilder = other.into();
         mut_arr.into()
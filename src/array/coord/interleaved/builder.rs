@@ -1,5 +1,8 @@
+use std::ops::Range;
+
 use crate::array::InterleavedCoordBuffer;
 use crate::geo_traits::CoordTrait;
+use geo::CoordNum;
 
 /// The GeoArrow equivalent to `Vec<Coord>`: a mutable collection of coordinates.
 ///
@@ -64,9 +67,10 @@ impl InterleavedCoordBufferBuilder {
         self.coords[i * 2 + 1] = coord.y;
     }
 
-    pub fn push_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
-        self.coords.push(coord.x());
-        self.coords.push(coord.y());
+    /// Add a new coord to the end of this builder, converting its values to `f64`.
+    pub fn push_coord(&mut self, coord: &impl CoordTrait<T = impl CoordNum>) {
+        self.coords.push(coord.x().to_f64().unwrap());
+        self.coords.push(coord.y().to_f64().unwrap());
     }
 
     pub fn set_xy(&mut self, i: usize, x: f64, y: f64) {
@@ -79,6 +83,12 @@ impl InterleavedCoordBufferBuilder {
         self.coords.push(y);
     }
 
+    /// Append the coordinates in `range` of `buffer`.
+    pub fn extend_from_buffer(&mut self, buffer: &InterleavedCoordBuffer, range: Range<usize>) {
+        self.coords
+            .extend_from_slice(&buffer.coords[range.start * 2..range.end * 2]);
+    }
+
     pub fn len(&self) -> usize {
         self.coords.len() / 2
     }
@@ -100,7 +110,7 @@ impl From<InterleavedCoordBufferBuilder> for InterleavedCoordBuffer {
     }
 }
 
-impl<G: CoordTrait<T = f64>> From<&[G]> for InterleavedCoordBufferBuilder {
+impl<T: CoordNum, G: CoordTrait<T = T>> From<&[G]> for InterleavedCoordBufferBuilder {
     fn from(value: &[G]) -> Self {
         let mut buffer = InterleavedCoordBufferBuilder::with_capacity(value.len());
         for coord in value {
@@ -3,6 +3,11 @@
 //!
 //! Coordinates can be either _interleaved_, where they're represented as a `FixedSizeList`, or
 //! _separated_, where they're represented with a `StructArray`.
+//!
+//! Coordinates are always stored as `f64`, as required by the GeoArrow specification. The
+//! builders accept coordinates of any [`CoordNum`][geo::CoordNum] type, such as `f32` or `i32`,
+//! and convert them to `f64` when they're pushed, but the coordinate buffers, scalars and their
+//! geometry trait implementations are not generic over the coordinate type.
 
 mod combined;
 mod interleaved;
@@ -1,6 +1,6 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
-// use arrow2::array::{Array, PrimitiveArray, StructArray};
 use arrow_array::{Array, Float64Array, StructArray};
 use arrow_buffer::{NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
@@ -197,7 +197,7 @@ impl TryFrom<(Vec<f64>, Vec<f64>)> for SeparatedCoordBuffer {
     }
 }
 
-impl<G: CoordTrait<T = f64>> From<&[G]> for SeparatedCoordBuffer {
+impl<T: CoordNum, G: CoordTrait<T = T>> From<&[G]> for SeparatedCoordBuffer {
     fn from(other: &[G]) -> Self {
         let mut_arr: SeparatedCoordBufferBuilder = other.into();
         mut_arr.into()
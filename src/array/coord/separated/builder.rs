@@ -1,5 +1,8 @@
+use std::ops::Range;
+
 use crate::array::SeparatedCoordBuffer;
 use crate::geo_traits::CoordTrait;
+use geo::CoordNum;
 
 /// The GeoArrow equivalent to `Vec<Coord>`: a mutable collection of coordinates.
 ///
@@ -74,9 +77,10 @@ impl SeparatedCoordBufferBuilder {
         self.y[i] = coord.y;
     }
 
-    pub fn push_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
-        self.x.push(coord.x());
-        self.y.push(coord.y());
+    /// Add a new coord to the end of this builder, converting its values to `f64`.
+    pub fn push_coord(&mut self, coord: &impl CoordTrait<T = impl CoordNum>) {
+        self.x.push(coord.x().to_f64().unwrap());
+        self.y.push(coord.y().to_f64().unwrap());
     }
 
     pub fn set_xy(&mut self, i: usize, x: f64, y: f64) {
@@ -89,6 +93,12 @@ impl SeparatedCoordBufferBuilder {
         self.y.push(y);
     }
 
+    /// Append the coordinates in `range` of `buffer`.
+    pub fn extend_from_buffer(&mut self, buffer: &SeparatedCoordBuffer, range: Range<usize>) {
+        self.x.extend_from_slice(&buffer.x[range.clone()]);
+        self.y.extend_from_slice(&buffer.y[range]);
+    }
+
     pub fn len(&self) -> usize {
         self.x.len()
     }
@@ -110,7 +120,7 @@ impl From<SeparatedCoordBufferBuilder> for SeparatedCoordBuffer {
     }
 }
 
-impl<G: CoordTrait<T = f64>> From<&[G]> for SeparatedCoordBufferBuilder {
+impl<T: CoordNum, G: CoordTrait<T = T>> From<&[G]> for SeparatedCoordBufferBuilder {
     fn from(value: &[G]) -> Self {
         let mut buffer = SeparatedCoordBufferBuilder::with_capacity(value.len());
         for coord in value {
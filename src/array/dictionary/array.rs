@@ -0,0 +1,317 @@
+use std::sync::Arc;
+
+use arrow::compute::take as arrow_take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{ArrowDictionaryKeyType, UInt64Type};
+use arrow_array::{Array, ArrayRef, DictionaryArray, PrimitiveArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{DataType, Field};
+
+use crate::algorithm::native::unique::unique_indices;
+use crate::algorithm::native::Take;
+use crate::array::from_arrow_array;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// A dictionary-encoded geometry array.
+///
+/// This stores each distinct geometry once in `values`, plus a `keys` array with, for each
+/// element, the index of its geometry in `values`. A null key represents a null geometry. This
+/// saves memory when the same geometries are repeated many times, e.g. after joining a table to
+/// administrative boundaries.
+///
+/// Algorithms implemented for the dictionary array are computed once per value and then expanded
+/// to the length of the array by the keys. [`Area`][crate::algorithm::geo::Area],
+/// [`BoundingRect`][crate::algorithm::geo::BoundingRect],
+/// [`Centroid`][crate::algorithm::geo::Centroid] and
+/// [`EuclideanLength`][crate::algorithm::geo::EuclideanLength] are implemented directly. Any other
+/// kernel can be applied once per value with [`map_values`][Self::map_values] if it returns a
+/// geometry array, or by applying it to [`values`][Self::values] and expanding the result with
+/// [`take_values`][Self::take_values].
+///
+/// This is **not** an array type defined by the GeoArrow specification, and so it does not
+/// implement [`GeometryArrayTrait`]. It is exported to Arrow as a [`DictionaryArray`] with
+/// geometry values, and can be imported without decoding with
+/// [`try_from_arrow`][Self::try_from_arrow]. [`from_arrow_array`] instead decodes
+/// dictionary-encoded arrays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryGeometryArray<K: ArrowDictionaryKeyType, G: GeometryArrayTrait> {
+    keys: PrimitiveArray<K>,
+    values: G,
+}
+
+impl<K: ArrowDictionaryKeyType, G: GeometryArrayTrait> DictionaryGeometryArray<K, G> {
+    /// Create a new dictionary array from keys and values.
+    ///
+    /// # Panics
+    ///
+    /// - if a non-null key is negative or not a valid index into `values`
+    pub fn new(keys: PrimitiveArray<K>, values: G) -> Self {
+        Self::try_new(keys, values).unwrap()
+    }
+
+    /// Create a new dictionary array from keys and values.
+    ///
+    /// # Errors
+    ///
+    /// - if a non-null key is negative or not a valid index into `values`
+    pub fn try_new(keys: PrimitiveArray<K>, values: G) -> Result<Self> {
+        if let Some(key) = keys
+            .iter()
+            .flatten()
+            .find(|key| !key.to_usize().is_some_and(|key| key < values.len()))
+        {
+            return Err(GeoArrowError::General(format!(
+                "Dictionary key {key:?} out of bounds for {} values",
+                values.len()
+            )));
+        }
+
+        Ok(Self { keys, values })
+    }
+
+    /// The keys of this array, indexing into [`values`][Self::values].
+    pub fn keys(&self) -> &PrimitiveArray<K> {
+        &self.keys
+    }
+
+    /// The distinct geometries of this array.
+    pub fn values(&self) -> &G {
+        &self.values
+    }
+
+    /// Consume self and return the keys and values.
+    pub fn into_inner(self) -> (PrimitiveArray<K>, G) {
+        (self.keys, self.values)
+    }
+
+    /// The number of geometries in this array.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the array contains no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns `true` if the geometry at index `i` is null.
+    pub fn is_null(&self, i: usize) -> bool {
+        self.keys.is_null(i) || self.values.is_null(self.keys.values()[i].as_usize())
+    }
+
+    /// Apply a function to the values of this array, keeping the same keys.
+    ///
+    /// # Panics
+    ///
+    /// - if the returned array does not have the same length as the values
+    pub fn map_values<H: GeometryArrayTrait>(
+        &self,
+        map_op: impl FnOnce(&G) -> H,
+    ) -> DictionaryGeometryArray<K, H> {
+        let values = map_op(&self.values);
+        assert_eq!(values.len(), self.values.len());
+        DictionaryGeometryArray {
+            keys: self.keys.clone(),
+            values,
+        }
+    }
+
+    /// Expand an array with one element per value, e.g. the output of an algorithm applied to
+    /// [`values`][Self::values], to one element per geometry of this array.
+    pub fn take_values<A: Array + Clone + 'static>(&self, values: &A) -> Result<A> {
+        let taken = arrow_take(values, &self.keys, None)?;
+        Ok(taken.as_any().downcast_ref::<A>().unwrap().clone())
+    }
+
+    /// The Arrow [`DataType`] of this array.
+    pub fn storage_type(&self) -> DataType {
+        DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(self.values.storage_type()))
+    }
+
+    /// A [`Field`] for this array, with the extension metadata of the values.
+    pub fn extension_field(&self) -> Arc<Field> {
+        let values_field = self.values.extension_field();
+        Arc::new(
+            Field::new(values_field.name(), self.storage_type(), true)
+                .with_metadata(values_field.metadata().clone()),
+        )
+    }
+
+    /// Convert this array to an Arrow [`DictionaryArray`].
+    pub fn to_array_ref(&self) -> ArrayRef {
+        Arc::new(DictionaryArray::new(
+            self.keys.clone(),
+            self.values.to_array_ref(),
+        ))
+    }
+}
+
+impl<K: ArrowDictionaryKeyType, G: GeometryArrayTrait + Clone + 'static>
+    DictionaryGeometryArray<K, G>
+{
+    /// Dictionary-encode a geometry array.
+    ///
+    /// Geometries are deduplicated by their exact type, structure and coordinates. Null
+    /// geometries are stored as null keys.
+    ///
+    /// # Errors
+    ///
+    /// - if the number of distinct geometries does not fit in the key type
+    pub fn try_encode(array: &G) -> Result<Self> {
+        let (indices, first_indices) = unique_indices(array.as_ref());
+        let keys = indices
+            .into_iter()
+            .map(|maybe_index| {
+                maybe_index
+                    .map(|index| K::Native::from_usize(index).ok_or(GeoArrowError::Overflow))
+                    .transpose()
+            })
+            .collect::<Result<PrimitiveArray<K>>>()?;
+
+        let first_indices = PrimitiveArray::<UInt64Type>::from_iter_values(
+            first_indices.into_iter().map(|i| i as u64),
+        );
+        let values = array.as_ref().take(&first_indices)?;
+        let values = values.as_any().downcast_ref::<G>().unwrap().clone();
+        Ok(Self { keys, values })
+    }
+
+    /// Import an Arrow [`DictionaryArray`] without decoding it.
+    ///
+    /// `field` describes the dictionary array, as produced by
+    /// [`extension_field`][Self::extension_field].
+    ///
+    /// # Errors
+    ///
+    /// - if `field` is not a dictionary field with keys of type `K`
+    /// - if the values are not a geometry array of type `G`
+    /// - if a non-null key is not a valid index into the values
+    pub fn try_from_arrow(array: &DictionaryArray<K>, field: &Field) -> Result<Self> {
+        let DataType::Dictionary(key_type, values_type) = field.data_type() else {
+            return Err(GeoArrowError::General(format!(
+                "Expected a dictionary field, got {:?}",
+                field.data_type()
+            )));
+        };
+        if key_type.as_ref() != &K::DATA_TYPE {
+            return Err(GeoArrowError::General(format!(
+                "Expected dictionary keys of type {:?}, got {:?}",
+                K::DATA_TYPE,
+                key_type
+            )));
+        }
+
+        let values_field = Field::new(field.name(), values_type.as_ref().clone(), true)
+            .with_metadata(field.metadata().clone());
+        let values = from_arrow_array(array.values(), &values_field)?;
+        let values = values.as_any().downcast_ref::<G>().ok_or_else(|| {
+            GeoArrowError::General(format!(
+                "Unexpected geometry type {:?} of dictionary values",
+                values.data_type()
+            ))
+        })?;
+        Self::try_new(array.keys().clone(), values.clone())
+    }
+
+    /// Decode this array into a geometry array with one geometry per key.
+    pub fn decode(&self) -> Result<G> {
+        let decoded = self.values.as_ref().take(&self.keys)?;
+        Ok(decoded.as_any().downcast_ref::<G>().unwrap().clone())
+    }
+}
+
+/// Expand an Arrow [`DictionaryArray`] of any key type into a plain array of its values type.
+pub(crate) fn decode_dictionary_array(array: &dyn Array) -> Result<ArrayRef> {
+    let dictionary = array.as_any_dictionary();
+    Ok(arrow_take(dictionary.values(), dictionary.keys(), None)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsGeometryArray, PolygonArray, RectArray};
+    use crate::test::polygon::{p0, p1};
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::types::{Int32Type, Int8Type};
+    use arrow_array::Int32Array;
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let array: PolygonArray<i32> = vec![Some(p0()), Some(p1()), None, Some(p0()), Some(p0())]
+            .into_iter()
+            .collect();
+        let dict = DictionaryGeometryArray::<Int32Type, _>::try_encode(&array).unwrap();
+        assert_eq!(dict.values().len(), 2);
+        assert_eq!(
+            dict.keys(),
+            &Int32Array::from(vec![Some(0), Some(1), None, Some(0), Some(0)])
+        );
+        assert!(dict.is_null(2));
+
+        let decoded = dict.decode().unwrap();
+        assert_eq!(
+            decoded.iter_geo().collect::<Vec<_>>(),
+            array.iter_geo().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn key_bounds() {
+        let array: PolygonArray<i32> = vec![p0(), p1()].as_slice().into();
+        assert!(DictionaryGeometryArray::try_new(Int32Array::from(vec![0, 2]), array).is_err());
+
+        let rect_array: RectArray = vec![geo::Rect::new((0., 0.), (1., 1.)); 300]
+            .as_slice()
+            .into();
+        let dict = DictionaryGeometryArray::<Int8Type, _>::try_encode(&rect_array).unwrap();
+        assert_eq!(dict.values().len(), 1);
+    }
+
+    #[test]
+    fn to_arrow_dictionary() {
+        let array: PolygonArray<i32> = vec![p0(), p0(), p1()].as_slice().into();
+        let dict = DictionaryGeometryArray::<Int8Type, _>::try_encode(&array).unwrap();
+        let arrow_array = dict.to_array_ref();
+        assert_eq!(arrow_array.len(), 3);
+        assert_eq!(arrow_array.data_type(), &dict.storage_type());
+        assert_eq!(
+            dict.extension_field()
+                .metadata()
+                .get("ARROW:extension:name"),
+            Some(&"geoarrow.polygon".to_string())
+        );
+    }
+
+    #[test]
+    fn import_with_and_without_decoding() {
+        let array: PolygonArray<i32> = vec![Some(p0()), None, Some(p1()), Some(p0())]
+            .into_iter()
+            .collect();
+        let dict = DictionaryGeometryArray::<Int8Type, _>::try_encode(&array).unwrap();
+        let field = dict.extension_field();
+        let arrow_array = dict.to_array_ref();
+
+        let imported = DictionaryGeometryArray::<Int8Type, PolygonArray<i32>>::try_from_arrow(
+            arrow_array.as_dictionary(),
+            &field,
+        )
+        .unwrap();
+        assert_eq!(imported.keys(), dict.keys());
+        assert_eq!(imported.values().len(), 2);
+
+        let decoded = from_arrow_array(&arrow_array, &field).unwrap();
+        assert_eq!(
+            decoded.as_ref().as_polygon().iter_geo().collect::<Vec<_>>(),
+            array.iter_geo().collect::<Vec<_>>()
+        );
+
+        assert!(
+            DictionaryGeometryArray::<Int32Type, PolygonArray<i32>>::try_from_arrow(
+                &DictionaryArray::new(Int32Array::from(vec![0]), dict.values().to_array_ref()),
+                &field,
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub use array::DictionaryGeometryArray;
+
+pub(crate) use array::decode_dictionary_array;
+
+mod array;
@@ -0,0 +1,118 @@
+//! [`Display`] implementations for geometry arrays, writing each geometry as WKT.
+
+use std::fmt::{Display, Formatter, Result};
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::*;
+use crate::trait_::GeometryArrayAccessor;
+
+/// The maximum number of geometries written when displaying an array.
+const MAX_DISPLAYED_GEOMETRIES: usize = 10;
+
+/// Write the name, length and null count of an array, followed by its first geometries as WKT.
+fn fmt_array<'a, A>(f: &mut Formatter<'_>, name: &str, array: &'a A) -> Result
+where
+    A: GeometryArrayAccessor<'a>,
+    A::Item: Display,
+{
+    writeln!(
+        f,
+        "{} (length: {}, null_count: {})",
+        name,
+        array.len(),
+        array.null_count()
+    )?;
+    writeln!(f, "[")?;
+    for maybe_geom in array.iter().take(MAX_DISPLAYED_GEOMETRIES) {
+        match maybe_geom {
+            Some(geom) => writeln!(f, "  {},", geom)?,
+            None => writeln!(f, "  null,")?,
+        }
+    }
+    if array.len() > MAX_DISPLAYED_GEOMETRIES {
+        writeln!(f, "  ...{} more...", array.len() - MAX_DISPLAYED_GEOMETRIES)?;
+    }
+    write!(f, "]")
+}
+
+impl Display for PointArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_array(f, "PointArray", self)
+    }
+}
+
+impl Display for RectArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_array(f, "RectArray", self)
+    }
+}
+
+macro_rules! impl_display {
+    ($array:ty, $name:expr) => {
+        impl<O: OffsetSizeTrait> Display for $array {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                let name = if O::IS_LARGE {
+                    concat!($name, "<i64>")
+                } else {
+                    concat!($name, "<i32>")
+                };
+                fmt_array(f, name, self)
+            }
+        }
+    };
+}
+
+impl_display!(LineStringArray<O>, "LineStringArray");
+impl_display!(PolygonArray<O>, "PolygonArray");
+impl_display!(MultiPointArray<O>, "MultiPointArray");
+impl_display!(MultiLineStringArray<O>, "MultiLineStringArray");
+impl_display!(MultiPolygonArray<O>, "MultiPolygonArray");
+impl_display!(MixedGeometryArray<O>, "MixedGeometryArray");
+impl_display!(GeometryCollectionArray<O>, "GeometryCollectionArray");
+impl_display!(WKBArray<O>, "WKBArray");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{point, polygon};
+
+    #[test]
+    fn display_truncated() {
+        let mut points: Vec<Option<geo::Point>> = vec![None];
+        points.extend((0..11).map(|i| Some(point!(x: i as f64, y: 0.5))));
+        let array: PointArray = points.into();
+
+        let expected = "\
+PointArray (length: 12, null_count: 1)
+[
+  null,
+  POINT(0 0.5),
+  POINT(1 0.5),
+  POINT(2 0.5),
+  POINT(3 0.5),
+  POINT(4 0.5),
+  POINT(5 0.5),
+  POINT(6 0.5),
+  POINT(7 0.5),
+  POINT(8 0.5),
+  ...2 more...
+]";
+        assert_eq!(array.to_string(), expected);
+    }
+
+    #[test]
+    fn display_polygon_array() {
+        let array: PolygonArray<i64> = vec![polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ]]
+        .as_slice()
+        .into();
+        assert_eq!(
+            array.to_string(),
+            "PolygonArray<i64> (length: 1, null_count: 0)\n[\n  POLYGON((0 0,1 0,1 1,0 0)),\n]"
+        );
+    }
+}
@@ -14,6 +14,7 @@ use crate::array::{
 };
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
+use crate::io::wkb::reader::r#type::check_no_curves;
 use crate::scalar::Geometry;
 use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
@@ -385,6 +386,7 @@ impl<O: OffsetSizeTrait> From<MultiPolygonArray<O>> for GeometryArray<O> {
 impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryArray<O> {
     type Error = GeoArrowError;
     fn try_from(value: WKBArray<O>) -> Result<Self, Self::Error> {
+        check_no_curves(&value)?;
         let type_ids = value.get_unique_type_ids();
 
         if type_ids.is_empty() {
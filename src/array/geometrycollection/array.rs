@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -9,6 +10,7 @@ use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::geometrycollection::{GeometryCollectionBuilder, GeometryCollectionCapacity};
 use crate::array::metadata::ArrayMetadata;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
+use crate::array::validation::ValidationPolicy;
 use crate::array::{CoordBuffer, CoordType, MixedGeometryArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
@@ -26,7 +28,7 @@ pub struct GeometryCollectionArray<O: OffsetSizeTrait> {
     // Always GeoDataType::GeometryCollection or GeoDataType::LargeGeometryCollection
     data_type: GeoDataType,
 
-    metadata: Arc<ArrayMetadata>,
+    pub(crate) metadata: Arc<ArrayMetadata>,
 
     pub(crate) array: MixedGeometryArray<O>,
 
@@ -64,6 +66,26 @@ impl<O: OffsetSizeTrait> GeometryCollectionArray<O> {
         }
     }
 
+    /// Apply a [`ValidationPolicy`] to the geometries of this array.
+    ///
+    /// See [`MixedGeometryArray::with_validation`] for how geometries are validated.
+    pub fn with_validation(mut self, policy: ValidationPolicy) -> Result<Self> {
+        self.array = self.array.with_validation(policy)?;
+        Ok(self)
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        GeometryCollectionBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = GeometryCollectionBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     fn mixed_field(&self) -> Arc<Field> {
         self.array.extension_field()
     }
@@ -301,7 +323,7 @@ impl TryFrom<&dyn Array> for GeometryCollectionArray<i64> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<&[G]>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> From<&[G]>
     for GeometryCollectionArray<O>
 {
     fn from(other: &[G]) -> Self {
@@ -310,7 +332,7 @@ impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<&[G]>
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> From<Vec<Option<G>>>
     for GeometryCollectionArray<O>
 {
     fn from(other: Vec<Option<G>>) -> Self {
@@ -319,6 +341,24 @@ impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<Vec<Option<G>
     }
 }
 
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> FromIterator<G>
+    for GeometryCollectionArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: GeometryCollectionBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> FromIterator<Option<G>>
+    for GeometryCollectionArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: GeometryCollectionBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
 impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryCollectionArray<O> {
     type Error = GeoArrowError;
 
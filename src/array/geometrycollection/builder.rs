@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
 use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
@@ -6,6 +7,7 @@ use arrow_buffer::NullBufferBuilder;
 use crate::array::geometrycollection::GeometryCollectionCapacity;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::offset_builder::OffsetsBuilder;
+use crate::array::util::{extend_validity, offsets_range};
 use crate::array::{CoordType, GeometryCollectionArray, MixedGeometryBuilder, WKBArray};
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::{
@@ -14,7 +16,10 @@ use crate::geo_traits::{
 };
 use crate::io::wkb::reader::WKBGeometry;
 use crate::scalar::WKB;
-use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::trait_::{
+    GeometryArrayAccessor, GeometryArrayBuilder, GeometryArraySelfMethods, IntoArrow,
+};
+use crate::GeometryArrayTrait;
 
 /// The GeoArrow equivalent to `Vec<Option<GeometryCollection>>`: a mutable collection of
 /// GeometryCollections.
@@ -63,18 +68,29 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
         }
     }
 
+    /// Creates a new [`GeometryCollectionBuilder`] with the exact capacity needed to push
+    /// `geoms`.
+    ///
+    /// `prefer_multi` must match the value later passed when pushing these geometries.
     pub fn with_capacity_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
-        Self::with_capacity_and_options_from_iter(geoms, Default::default(), Default::default())
+        Self::with_capacity_and_options_from_iter(
+            geoms,
+            Default::default(),
+            Default::default(),
+            prefer_multi,
+        )
     }
 
     pub fn with_capacity_and_options_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait + 'a)>>,
         coord_type: CoordType,
         metadata: Arc<ArrayMetadata>,
+        prefer_multi: bool,
     ) -> Result<Self> {
-        let counter = GeometryCollectionCapacity::from_geometry_collections(geoms)?;
+        let counter = GeometryCollectionCapacity::from_geometry_collections(geoms, prefer_multi)?;
         Ok(Self::with_capacity_and_options(
             counter, coord_type, metadata,
         ))
@@ -83,8 +99,9 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     pub fn reserve_from_iter(
         &mut self,
         geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<()> {
-        let counter = GeometryCollectionCapacity::from_geometry_collections(geoms)?;
+        let counter = GeometryCollectionCapacity::from_geometry_collections(geoms, prefer_multi)?;
         self.reserve(counter);
         Ok(())
     }
@@ -92,8 +109,9 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     pub fn reserve_exact_from_iter(
         &mut self,
         geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<()> {
-        let counter = GeometryCollectionCapacity::from_geometry_collections(geoms)?;
+        let counter = GeometryCollectionCapacity::from_geometry_collections(geoms, prefer_multi)?;
         self.reserve_exact(counter);
         Ok(())
     }
@@ -140,7 +158,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_point(
         &mut self,
-        value: Option<&impl PointTrait<T = f64>>,
+        value: Option<&impl PointTrait<T = impl CoordNum>>,
         prefer_multi: bool,
     ) -> Result<()> {
         if prefer_multi {
@@ -157,7 +175,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_line_string(
         &mut self,
-        value: Option<&impl LineStringTrait<T = f64>>,
+        value: Option<&impl LineStringTrait<T = impl CoordNum>>,
         prefer_multi: bool,
     ) -> Result<()> {
         if prefer_multi {
@@ -174,7 +192,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_polygon(
         &mut self,
-        value: Option<&impl PolygonTrait<T = f64>>,
+        value: Option<&impl PolygonTrait<T = impl CoordNum>>,
         prefer_multi: bool,
     ) -> Result<()> {
         if prefer_multi {
@@ -191,7 +209,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_multi_point(
         &mut self,
-        value: Option<&impl MultiPointTrait<T = f64>>,
+        value: Option<&impl MultiPointTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.geoms.push_multi_point(value)?;
         self.geom_offsets.try_push_usize(1)?;
@@ -203,7 +221,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_multi_line_string(
         &mut self,
-        value: Option<&impl MultiLineStringTrait<T = f64>>,
+        value: Option<&impl MultiLineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.geoms.push_multi_line_string(value)?;
         self.geom_offsets.try_push_usize(1)?;
@@ -215,7 +233,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_multi_polygon(
         &mut self,
-        value: Option<&impl MultiPolygonTrait<T = f64>>,
+        value: Option<&impl MultiPolygonTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.geoms.push_multi_polygon(value)?;
         self.geom_offsets.try_push_usize(1)?;
@@ -227,7 +245,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_geometry(
         &mut self,
-        value: Option<&impl GeometryTrait<T = f64>>,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
         prefer_multi: bool,
     ) -> Result<()> {
         if let Some(g) = value {
@@ -255,8 +273,10 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
                         self.push_geometry_collection(Some(p))?
                     }
                 }
-                crate::geo_traits::GeometryType::Rect(_p) => {
-                    todo!()
+                crate::geo_traits::GeometryType::Rect(_) => {
+                    return Err(GeoArrowError::General(
+                        "rect geometries not supported in geometry collection arrays".to_string(),
+                    ));
                 }
             }
         } else {
@@ -269,7 +289,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_geometry_collection(
         &mut self,
-        value: Option<&impl GeometryCollectionTrait<T = f64>>,
+        value: Option<&impl GeometryCollectionTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(gc) = value {
             let num_geoms = gc.num_geometries();
@@ -286,7 +306,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     #[inline]
     pub fn push_geometry_collection_preferring_multi(
         &mut self,
-        value: Option<&impl GeometryCollectionTrait<T = f64>>,
+        value: Option<&impl GeometryCollectionTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(gc) = value {
             let num_geoms = gc.num_geometries();
@@ -300,9 +320,27 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
         Ok(())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The geometries referenced by `array` are appended to the mixed child builder directly, and
+    /// the offsets and validity of `array` are appended without iterating over its geometries.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &GeometryCollectionArray<O>) -> Result<()> {
+        let geom_range = offsets_range(&array.geom_offsets);
+        self.geoms
+            .extend_from_array(&array.array.slice(geom_range.start, geom_range.len()))?;
+        self.geom_offsets
+            .try_extend_from_slice(&array.geom_offsets)?;
+        extend_validity(&mut self.validity, array.nulls(), array.len());
+        Ok(())
+    }
+
     pub fn extend_from_iter(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait<T = impl CoordNum> + 'a)>>,
         prefer_multi: bool,
     ) {
         geoms
@@ -324,14 +362,15 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this builder.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
         self.validity.append(false);
     }
 
     pub fn from_geometry_collections(
-        geoms: &[impl GeometryCollectionTrait<T = f64>],
+        geoms: &[impl GeometryCollectionTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
@@ -340,13 +379,14 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
             geoms.iter().map(Some),
             coord_type.unwrap_or_default(),
             metadata,
+            prefer_multi,
         )?;
         array.extend_from_iter(geoms.iter().map(Some), prefer_multi);
         Ok(array)
     }
 
     pub fn from_nullable_geometry_collections(
-        geoms: &[Option<impl GeometryCollectionTrait<T = f64>>],
+        geoms: &[Option<impl GeometryCollectionTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
@@ -355,18 +395,20 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
             geoms.iter().map(|x| x.as_ref()),
             coord_type.unwrap_or_default(),
             metadata,
+            prefer_multi,
         )?;
         array.extend_from_iter(geoms.iter().map(|x| x.as_ref()), prefer_multi);
         Ok(array)
     }
 
     pub fn from_geometries(
-        geoms: &[impl GeometryTrait<T = f64>],
+        geoms: &[impl GeometryTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
     ) -> Result<Self> {
-        let capacity = GeometryCollectionCapacity::from_geometries(geoms.iter().map(Some))?;
+        let capacity =
+            GeometryCollectionCapacity::from_geometries(geoms.iter().map(Some), prefer_multi)?;
         let mut array =
             Self::with_capacity_and_options(capacity, coord_type.unwrap_or_default(), metadata);
         for geom in geoms {
@@ -376,13 +418,15 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
     }
 
     pub fn from_nullable_geometries(
-        geoms: &[Option<impl GeometryTrait<T = f64>>],
+        geoms: &[Option<impl GeometryTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
     ) -> Result<Self> {
-        let capacity =
-            GeometryCollectionCapacity::from_geometries(geoms.iter().map(|x| x.as_ref()))?;
+        let capacity = GeometryCollectionCapacity::from_geometries(
+            geoms.iter().map(|x| x.as_ref()),
+            prefer_multi,
+        )?;
         let mut array =
             Self::with_capacity_and_options(capacity, coord_type.unwrap_or_default(), metadata);
         for geom in geoms {
@@ -485,7 +529,7 @@ impl<O: OffsetSizeTrait> From<GeometryCollectionBuilder<O>> for GenericListArray
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<&[G]>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> From<&[G]>
     for GeometryCollectionBuilder<O>
 {
     fn from(geoms: &[G]) -> Self {
@@ -494,7 +538,7 @@ impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<&[G]>
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> From<Vec<Option<G>>>
     for GeometryCollectionBuilder<O>
 {
     fn from(geoms: Vec<Option<G>>) -> Self {
@@ -508,8 +552,26 @@ impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<Vec<Option<G>
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> FromIterator<G>
+    for GeometryCollectionBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>> FromIterator<Option<G>>
     for GeometryCollectionBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for GeometryCollectionBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         Self::from_geometry_collections(&geoms, Default::default(), Default::default(), true)
@@ -517,7 +579,7 @@ impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> From<bumpalo::coll
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryCollectionTrait<T = T>>
     From<bumpalo::collections::Vec<'_, Option<G>>> for GeometryCollectionBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
@@ -3,7 +3,7 @@ use std::ops::Add;
 use arrow_array::OffsetSizeTrait;
 
 use crate::array::mixed::MixedCapacity;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
     MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
@@ -39,18 +39,32 @@ impl GeometryCollectionCapacity {
     }
 
     #[inline]
-    fn add_valid_point(&mut self, _geom: &impl PointTrait) {
-        self.mixed_capacity.add_point();
+    fn add_valid_point(&mut self, geom: &impl PointTrait, prefer_multi: bool) {
+        if prefer_multi {
+            self.mixed_capacity.multi_point.add_point(Some(geom));
+        } else {
+            self.mixed_capacity.add_point();
+        }
     }
 
     #[inline]
-    fn add_valid_line_string(&mut self, geom: &impl LineStringTrait) {
-        self.mixed_capacity.add_line_string(Some(geom));
+    fn add_valid_line_string(&mut self, geom: &impl LineStringTrait, prefer_multi: bool) {
+        if prefer_multi {
+            self.mixed_capacity
+                .multi_line_string
+                .add_line_string(Some(geom));
+        } else {
+            self.mixed_capacity.add_line_string(Some(geom));
+        }
     }
 
     #[inline]
-    fn add_valid_polygon(&mut self, geom: &impl PolygonTrait) {
-        self.mixed_capacity.add_polygon(Some(geom));
+    fn add_valid_polygon(&mut self, geom: &impl PolygonTrait, prefer_multi: bool) {
+        if prefer_multi {
+            self.mixed_capacity.multi_polygon.add_polygon(Some(geom));
+        } else {
+            self.mixed_capacity.add_polygon(Some(geom));
+        }
     }
 
     #[inline]
@@ -69,28 +83,46 @@ impl GeometryCollectionCapacity {
     }
 
     #[inline]
-    fn add_valid_geometry_collection(&mut self, geom: &impl GeometryCollectionTrait) -> Result<()> {
+    fn add_valid_geometry_collection(
+        &mut self,
+        geom: &impl GeometryCollectionTrait,
+        prefer_multi: bool,
+    ) -> Result<()> {
         for g in geom.geometries() {
-            self.mixed_capacity.add_geometry(Some(&g))?
+            self.mixed_capacity.add_geometry(Some(&g), prefer_multi)?
         }
         Ok(())
     }
 
     /// Add a Geometry to this capacity counter.
+    ///
+    /// Each geometry is stored as its own GeometryCollection, so `prefer_multi` has the same
+    /// meaning as in [`MixedCapacity::add_geometry`].
     #[inline]
-    pub fn add_geometry(&mut self, geom: Option<&impl GeometryTrait>) -> Result<()> {
+    pub fn add_geometry(
+        &mut self,
+        geom: Option<&impl GeometryTrait>,
+        prefer_multi: bool,
+    ) -> Result<()> {
         if let Some(geom) = geom {
             match geom.as_type() {
-                GeometryType::Point(p) => self.add_valid_point(p),
-                GeometryType::LineString(p) => self.add_valid_line_string(p),
-                GeometryType::Polygon(p) => self.add_valid_polygon(p),
+                GeometryType::Point(p) => self.add_valid_point(p, prefer_multi),
+                GeometryType::LineString(p) => self.add_valid_line_string(p, prefer_multi),
+                GeometryType::Polygon(p) => self.add_valid_polygon(p, prefer_multi),
                 GeometryType::MultiPoint(p) => self.add_valid_multi_point(p),
                 GeometryType::MultiLineString(p) => self.add_valid_multi_line_string(p),
                 GeometryType::MultiPolygon(p) => self.add_valid_multi_polygon(p),
-                GeometryType::GeometryCollection(p) => self.add_valid_geometry_collection(p)?,
-                GeometryType::Rect(_) => todo!(),
+                GeometryType::GeometryCollection(p) => {
+                    self.add_valid_geometry_collection(p, prefer_multi)?
+                }
+                GeometryType::Rect(_) => {
+                    return Err(GeoArrowError::General(
+                        "rect geometries not supported in geometry collection arrays".to_string(),
+                    ));
+                }
             }
         };
+        self.geom_capacity += 1;
         Ok(())
     }
 
@@ -99,9 +131,10 @@ impl GeometryCollectionCapacity {
     pub fn add_geometry_collection<'a>(
         &mut self,
         geom: Option<&'a (impl GeometryCollectionTrait + 'a)>,
+        prefer_multi: bool,
     ) -> Result<()> {
         if let Some(geom) = geom {
-            self.add_valid_geometry_collection(geom)?;
+            self.add_valid_geometry_collection(geom, prefer_multi)?;
         }
         self.geom_capacity += 1;
         Ok(())
@@ -110,21 +143,23 @@ impl GeometryCollectionCapacity {
     /// Create a capacity counter from an iterator of GeometryCollections.
     pub fn from_geometry_collections<'a>(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryCollectionTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
         let mut counter = Self::new_empty();
         for maybe_geom in geoms.into_iter() {
-            counter.add_geometry_collection(maybe_geom)?;
+            counter.add_geometry_collection(maybe_geom, prefer_multi)?;
         }
         Ok(counter)
     }
 
-    /// Create a capacity counter from an iterator of Geometries.
+    /// Create a capacity counter from an iterator of owned GeometryCollections.
     pub fn from_owned_geometries<'a>(
         geoms: impl Iterator<Item = Option<(impl GeometryCollectionTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
         let mut counter = Self::new_empty();
         for maybe_geom in geoms.into_iter() {
-            counter.add_geometry_collection(maybe_geom.as_ref())?;
+            counter.add_geometry_collection(maybe_geom.as_ref(), prefer_multi)?;
         }
         Ok(counter)
     }
@@ -132,10 +167,11 @@ impl GeometryCollectionCapacity {
     /// Create a capacity counter from an iterator of Geometries.
     pub fn from_geometries<'a>(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
         let mut counter = Self::new_empty();
         for maybe_geom in geoms.into_iter() {
-            counter.add_geometry(maybe_geom)?;
+            counter.add_geometry(maybe_geom, prefer_multi)?;
         }
         Ok(counter)
     }
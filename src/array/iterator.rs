@@ -0,0 +1,149 @@
+//! Iterators over the scalar values of geometry arrays.
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::*;
+use crate::scalar::*;
+use crate::trait_::GeometryArrayAccessor;
+
+/// An iterator over the values of a geometry array, yielding `None` for null slots.
+///
+/// This is created by calling `into_iter` on a reference to a geometry array, which allows
+/// iterating over an array directly in a `for` loop.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::PointArray;
+/// use geo::point;
+///
+/// let array: PointArray = vec![Some(point!(x: 1., y: 2.)), None].into();
+/// for maybe_point in &array {
+///     if let Some(point) = maybe_point {
+///         assert_eq!(geo::Point::from(point), point!(x: 1., y: 2.));
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct GeometryArrayIter<'a, A: GeometryArrayAccessor<'a>> {
+    array: &'a A,
+    current: usize,
+    current_end: usize,
+}
+
+impl<'a, A: GeometryArrayAccessor<'a>> GeometryArrayIter<'a, A> {
+    /// Create a new iterator over the values of `array`.
+    pub fn new(array: &'a A) -> Self {
+        Self {
+            array,
+            current: 0,
+            current_end: array.len(),
+        }
+    }
+}
+
+impl<'a, A: GeometryArrayAccessor<'a>> Iterator for GeometryArrayIter<'a, A> {
+    type Item = Option<A::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.current_end {
+            return None;
+        }
+        // Safety: current is in bounds as it is always less than current_end
+        let value = unsafe { self.array.get_unchecked(self.current) };
+        self.current += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.current_end - self.current;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, A: GeometryArrayAccessor<'a>> DoubleEndedIterator for GeometryArrayIter<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current_end == self.current {
+            return None;
+        }
+        self.current_end -= 1;
+        // Safety: current_end is in bounds as it was decremented from at most the array length
+        Some(unsafe { self.array.get_unchecked(self.current_end) })
+    }
+}
+
+impl<'a, A: GeometryArrayAccessor<'a>> ExactSizeIterator for GeometryArrayIter<'a, A> {}
+
+macro_rules! impl_into_iter {
+    ($array:ty, $item:ty) => {
+        impl<'a> IntoIterator for &'a $array {
+            type Item = Option<$item>;
+            type IntoIter = GeometryArrayIter<'a, $array>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                GeometryArrayIter::new(self)
+            }
+        }
+    };
+}
+
+impl_into_iter!(PointArray, Point<'a>);
+impl_into_iter!(RectArray, Rect<'a>);
+
+macro_rules! impl_into_iter_generic {
+    ($array:ty, $item:ty) => {
+        impl<'a, O: OffsetSizeTrait> IntoIterator for &'a $array {
+            type Item = Option<$item>;
+            type IntoIter = GeometryArrayIter<'a, $array>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                GeometryArrayIter::new(self)
+            }
+        }
+    };
+}
+
+impl_into_iter_generic!(LineStringArray<O>, LineString<'a, O>);
+impl_into_iter_generic!(PolygonArray<O>, Polygon<'a, O>);
+impl_into_iter_generic!(MultiPointArray<O>, MultiPoint<'a, O>);
+impl_into_iter_generic!(MultiLineStringArray<O>, MultiLineString<'a, O>);
+impl_into_iter_generic!(MultiPolygonArray<O>, MultiPolygon<'a, O>);
+impl_into_iter_generic!(MixedGeometryArray<O>, Geometry<'a, O>);
+impl_into_iter_generic!(GeometryCollectionArray<O>, GeometryCollection<'a, O>);
+impl_into_iter_generic!(WKBArray<O>, WKB<'a, O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipoint, point};
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn iterate_with_nulls() {
+        let array: PointArray = vec![Some(point::p0()), None, Some(point::p2())].into();
+        let values: Vec<Option<geo::Point>> = (&array)
+            .into_iter()
+            .map(|maybe_point| maybe_point.map(|point| point.into()))
+            .collect();
+        assert_eq!(values, vec![Some(point::p0()), None, Some(point::p2())]);
+
+        let mut iter = array.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert!(iter.next_back().unwrap().is_some());
+        assert!(iter.next_back().unwrap().is_none());
+        assert_eq!(iter.len(), 1);
+    }
+
+    #[test]
+    fn for_loop() {
+        let array = multipoint::mp_array();
+        let mut count = 0;
+        for maybe_multi_point in &array {
+            assert!(maybe_multi_point.is_some());
+            count += 1;
+        }
+        assert_eq!(count, array.len());
+    }
+}
@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -5,6 +6,7 @@ use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::linestring::LineStringCapacity;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::validation;
 use crate::array::{CoordBuffer, CoordType, MultiPointArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
@@ -112,6 +114,60 @@ impl<O: OffsetSizeTrait> LineStringArray<O> {
         })
     }
 
+    /// Check the coordinates and offsets of this array.
+    ///
+    /// Construction only does `O(1)` checks of the buffer lengths. This additionally checks, for
+    /// every non-null geometry,
+    ///
+    /// - that its coordinates are finite
+    /// - that the geometry offsets do not decrease
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(n)`.
+    ///
+    /// # Errors
+    ///
+    /// - with the index of the first geometry that fails any of the above checks
+    pub fn validate(&self) -> std::result::Result<(), GeoArrowError> {
+        validation::check_geometries(&self.coords, &self.geom_offsets, |i| self.is_valid(i))
+    }
+
+    /// Apply a [`ValidationPolicy`][validation::ValidationPolicy] to this array.
+    ///
+    /// See [`validate`][Self::validate] for the checks. With
+    /// [`ValidationPolicy::Fix`][validation::ValidationPolicy::Fix], geometries with non-finite coordinates are replaced with nulls.
+    pub fn with_validation(
+        self,
+        policy: validation::ValidationPolicy,
+    ) -> std::result::Result<Self, GeoArrowError> {
+        policy.apply(self, Self::validate, |array| {
+            validation::check_offsets(&array.geom_offsets, "Geometry")?;
+            let geoms: Vec<_> = array
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.filter(validation::is_finite))
+                .collect();
+            Ok(LineStringBuilder::from_nullable_line_strings(
+                &geoms,
+                Some(array.coord_type()),
+                array.metadata(),
+            )
+            .finish())
+        })
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        LineStringBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = LineStringBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("vertices", self.coords.storage_type(), false).into()
     }
@@ -359,22 +415,42 @@ impl TryFrom<&dyn Array> for LineStringArray<i64> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<Vec<Option<G>>> for LineStringArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> From<Vec<Option<G>>>
+    for LineStringArray<O>
+{
     fn from(other: Vec<Option<G>>) -> Self {
         let mut_arr: LineStringBuilder<O> = other.into();
         mut_arr.into()
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<&[G]> for LineStringArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> FromIterator<G>
+    for LineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: LineStringBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> FromIterator<Option<G>>
+    for LineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: LineStringBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> From<&[G]> for LineStringArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: LineStringBuilder<O> = other.into();
         mut_arr.into()
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>>
-    for LineStringArray<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, Option<G>>> for LineStringArray<O>
 {
     fn from(other: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
         let mut_arr: LineStringBuilder<O> = other.into();
@@ -382,8 +458,8 @@ impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<bumpalo::collections:
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
-    for LineStringArray<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for LineStringArray<O>
 {
     fn from(other: bumpalo::collections::Vec<'_, G>) -> Self {
         let mut_arr: LineStringBuilder<O> = other.into();
@@ -1,5 +1,7 @@
 use crate::array::linestring::capacity::LineStringCapacity;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::{extend_validity, offsets_range};
+use geo::CoordNum;
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -11,6 +13,7 @@ use crate::geo_traits::{GeometryTrait, GeometryType, LineStringTrait, MultiLineS
 use crate::io::wkb::reader::WKBLineString;
 use crate::scalar::WKB;
 use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::NullBufferBuilder;
 use std::convert::From;
@@ -170,7 +173,7 @@ impl<O: OffsetSizeTrait> LineStringBuilder<O> {
     #[inline]
     pub fn push_line_string(
         &mut self,
-        value: Option<&impl LineStringTrait<T = f64>>,
+        value: Option<&impl LineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(line_string) = value {
             let num_coords = line_string.num_coords();
@@ -184,9 +187,26 @@ impl<O: OffsetSizeTrait> LineStringBuilder<O> {
         Ok(())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The coordinates, offsets and validity of `array` are appended to the buffers of this
+    /// builder directly, without iterating over its geometries.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &LineStringArray<O>) -> Result<()> {
+        self.coords
+            .extend_from_buffer(&array.coords, offsets_range(&array.geom_offsets));
+        self.geom_offsets
+            .try_extend_from_slice(&array.geom_offsets)?;
+        extend_validity(&mut self.validity, array.validity.as_ref(), array.len());
+        Ok(())
+    }
+
     pub fn extend_from_iter<'a>(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl LineStringTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl LineStringTrait<T = impl CoordNum> + 'a)>>,
     ) {
         geoms
             .into_iter()
@@ -214,14 +234,18 @@ impl<O: OffsetSizeTrait> LineStringBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this builder.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
         self.validity.append(false);
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(value) = value {
             match value.as_type() {
                 GeometryType::LineString(g) => self.push_line_string(Some(g))?,
@@ -245,7 +269,7 @@ impl<O: OffsetSizeTrait> LineStringBuilder<O> {
     }
 
     pub fn from_line_strings(
-        geoms: &[impl LineStringTrait<T = f64>],
+        geoms: &[impl LineStringTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -259,7 +283,7 @@ impl<O: OffsetSizeTrait> LineStringBuilder<O> {
     }
 
     pub fn from_nullable_line_strings(
-        geoms: &[Option<impl LineStringTrait<T = f64>>],
+        geoms: &[Option<impl LineStringTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -295,6 +319,13 @@ impl<O: OffsetSizeTrait> LineStringBuilder<O> {
     pub fn finish(self) -> LineStringArray<O> {
         self.into()
     }
+
+    /// Finish the array and [validate][LineStringArray::validate] its coordinates and offsets.
+    pub fn finish_validated(self) -> Result<LineStringArray<O>> {
+        let array = self.finish();
+        array.validate()?;
+        Ok(array)
+    }
 }
 
 impl<O: OffsetSizeTrait> GeometryArrayBuilder for LineStringBuilder<O> {
@@ -373,13 +404,15 @@ impl<O: OffsetSizeTrait> From<LineStringBuilder<O>> for GenericListArray<O> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<&[G]> for LineStringBuilder<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> From<&[G]>
+    for LineStringBuilder<O>
+{
     fn from(geoms: &[G]) -> Self {
         Self::from_line_strings(geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> From<Vec<Option<G>>>
     for LineStringBuilder<O>
 {
     fn from(geoms: Vec<Option<G>>) -> Self {
@@ -387,16 +420,34 @@ impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> FromIterator<G>
+    for LineStringBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>> FromIterator<Option<G>>
     for LineStringBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for LineStringBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         Self::from_line_strings(&geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>>
-    for LineStringBuilder<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: LineStringTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, Option<G>>> for LineStringBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
         Self::from_nullable_line_strings(&geoms, Default::default(), Default::default())
@@ -1,8 +1,28 @@
 //! Metadata contained within a GeoArrow array.
 //!
 //! This metadata is [defined by the GeoArrow specification](https://geoarrow.org/extension-types).
+//! Both the current (1.0) metadata and the legacy metadata of 0.x versions of the specification
+//! are read, and are translated to a common representation on import:
+//!
+//! - In 0.x versions, the CRS is always a string, which holds serialized PROJJSON if the CRS is
+//!   known as PROJJSON.
+//! - In 1.0, a PROJJSON CRS is stored as a JSON object, and the optional `crs_type` key describes
+//!   how a string CRS is encoded.
+//!
+//! Metadata is written in the 1.0 format by default. Use
+//! [`ArrayMetadata::to_extension_metadata`] to write metadata for consumers that only support the
+//! legacy format.
+//!
+//! Producers also differ in the names of child fields, for example naming the values of an
+//! interleaved coordinate list `"vertices"` instead of `"xy"`. These names carry no meaning, and
+//! [`translate_field`] maps a field from any producer to the field this crate writes.
 
+use arrow_schema::Field;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
 
 /// If present, instructs consumers that edges follow a spherical path rather than a planar one. If
 /// this value is omitted, edges will be interpreted as planar.
@@ -12,12 +32,45 @@ pub enum Edges {
     Spherical,
 }
 
+/// How the CRS of an array is encoded, as described by the `crs_type` key of GeoArrow 1.0
+/// metadata.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CrsType {
+    /// A PROJJSON object.
+    #[serde(rename = "projjson")]
+    Projjson,
+
+    /// A WKT2:2019 string.
+    #[serde(rename = "wkt2:2019")]
+    Wkt2_2019,
+
+    /// An authority and code, such as `EPSG:4326`.
+    #[serde(rename = "authority_code")]
+    AuthorityCode,
+
+    /// An opaque identifier, such as a numeric SRID.
+    #[serde(rename = "srid")]
+    Srid,
+}
+
+/// A version of the GeoArrow specification's extension metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MetadataVersion {
+    /// The metadata of 0.x versions of the specification, where the CRS is always a string.
+    Legacy,
+
+    /// The metadata of version 1.0 of the specification.
+    #[default]
+    V1,
+}
+
 /// A GeoArrow metadata object following the extension metadata [defined by the GeoArrow
 /// specification](https://geoarrow.org/extension-types).
 ///
 /// This is serialized to JSON when a [`geoarrow`] array is exported to an [`arrow`] array and
 /// deserialized when imported from an [`arrow`] array.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Hash)]
+#[serde(try_from = "Value", into = "Value")]
 pub struct ArrayMetadata {
     /// A JSON object describing the coordinate reference system (CRS) using PROJJSON. This key can
     /// also be omitted if the producer does not have any information about the CRS. Note that
@@ -25,9 +78,301 @@ pub struct ArrayMetadata {
     /// to the wording in the GeoPackage WKB binary encoding: axis order is always (longitude,
     /// latitude) and (easting, northing) regardless of the the axis order encoded in the CRS
     /// specification.
+    ///
+    /// A PROJJSON CRS is stored as serialized JSON.
     crs: Option<String>,
 
+    /// How `crs` is encoded, if known.
+    crs_type: Option<CrsType>,
+
     /// If present, instructs consumers that edges follow a spherical path rather than a planar
     /// one. If this value is omitted, edges will be interpreted as planar.
     edges: Option<Edges>,
 }
+
+impl ArrayMetadata {
+    /// Create metadata with a CRS and how it is encoded. A PROJJSON CRS should be serialized
+    /// JSON.
+    pub fn with_crs(mut self, crs: String, crs_type: Option<CrsType>) -> Self {
+        self.crs = Some(crs);
+        self.crs_type = crs_type;
+        self
+    }
+
+    /// Create metadata with the given edge interpretation.
+    pub fn with_edges(mut self, edges: Option<Edges>) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// The CRS of the array. A PROJJSON CRS is returned as serialized JSON.
+    pub fn crs(&self) -> Option<&str> {
+        self.crs.as_deref()
+    }
+
+    /// How the CRS of the array is encoded, if known.
+    pub fn crs_type(&self) -> Option<CrsType> {
+        self.crs_type
+    }
+
+    /// The edge interpretation of the array.
+    pub fn edges(&self) -> Option<&Edges> {
+        self.edges.as_ref()
+    }
+
+    /// Parse extension metadata in either the legacy or the 1.0 format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::metadata::{ArrayMetadata, CrsType, MetadataVersion};
+    ///
+    /// let legacy = ArrayMetadata::from_extension_metadata(r#"{"crs": "{\"id\": 4326}"}"#).unwrap();
+    /// let v1 = ArrayMetadata::from_extension_metadata(r#"{"crs": {"id": 4326}}"#).unwrap();
+    /// assert_eq!(legacy, v1);
+    /// assert_eq!(v1.crs_type(), Some(CrsType::Projjson));
+    ///
+    /// assert_eq!(
+    ///     v1.to_extension_metadata(MetadataVersion::Legacy),
+    ///     r#"{"crs":"{\"id\":4326}"}"#
+    /// );
+    /// ```
+    pub fn from_extension_metadata(metadata: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(metadata)?;
+        value.try_into()
+    }
+
+    /// Parse the extension metadata of an Arrow field, returning the default metadata if the
+    /// field has none.
+    pub fn from_field(field: &Field) -> Result<Self> {
+        match field.metadata().get("ARROW:extension:metadata") {
+            Some(metadata) if !metadata.is_empty() => Self::from_extension_metadata(metadata),
+            _ => Ok(Default::default()),
+        }
+    }
+
+    /// Serialize to extension metadata in the given format.
+    pub fn to_extension_metadata(&self, version: MetadataVersion) -> String {
+        self.to_value(version).to_string()
+    }
+
+    fn to_value(&self, version: MetadataVersion) -> Value {
+        let mut object = Map::new();
+        if let Some(crs) = &self.crs {
+            let crs = match version {
+                MetadataVersion::Legacy => Value::String(crs.clone()),
+                MetadataVersion::V1 => match self.crs_type {
+                    Some(CrsType::Projjson) => {
+                        serde_json::from_str(crs).unwrap_or_else(|_| Value::String(crs.clone()))
+                    }
+                    _ => Value::String(crs.clone()),
+                },
+            };
+            object.insert("crs".to_string(), crs);
+        }
+        if let (MetadataVersion::V1, Some(crs_type)) = (version, self.crs_type) {
+            // Objects are always PROJJSON, so the type is only needed for strings
+            if crs_type != CrsType::Projjson {
+                object.insert(
+                    "crs_type".to_string(),
+                    serde_json::to_value(crs_type).unwrap(),
+                );
+            }
+        }
+        if let Some(edges) = &self.edges {
+            object.insert("edges".to_string(), serde_json::to_value(edges).unwrap());
+        }
+        Value::Object(object)
+    }
+}
+
+impl TryFrom<Value> for ArrayMetadata {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Value) -> Result<Self> {
+        let invalid = |message: &str| GeoArrowError::General(format!("{message}: {value}"));
+
+        let object = value
+            .as_object()
+            .ok_or_else(|| invalid("GeoArrow metadata must be a JSON object"))?;
+
+        let mut crs_type = match object.get("crs_type") {
+            None | Some(Value::Null) => None,
+            Some(crs_type) => Some(
+                serde_json::from_value(crs_type.clone())
+                    .map_err(|_| invalid("unknown GeoArrow crs_type"))?,
+            ),
+        };
+        let crs = match object.get("crs") {
+            None | Some(Value::Null) => None,
+            Some(Value::Object(projjson)) => {
+                crs_type = Some(CrsType::Projjson);
+                Some(Value::Object(projjson.clone()).to_string())
+            }
+            // Legacy metadata stores PROJJSON as a string
+            Some(Value::String(crs)) => match serde_json::from_str::<Map<_, _>>(crs) {
+                Ok(projjson) if matches!(crs_type, None | Some(CrsType::Projjson)) => {
+                    crs_type = Some(CrsType::Projjson);
+                    Some(Value::Object(projjson).to_string())
+                }
+                _ => Some(crs.clone()),
+            },
+            Some(_) => return Err(invalid("GeoArrow crs must be a string or an object")),
+        };
+        let edges = match object.get("edges") {
+            None | Some(Value::Null) => None,
+            Some(edges) => Some(
+                serde_json::from_value(edges.clone())
+                    .map_err(|_| invalid("unknown GeoArrow edges"))?,
+            ),
+        };
+
+        Ok(Self {
+            crs,
+            crs_type,
+            edges,
+        })
+    }
+}
+
+impl From<ArrayMetadata> for Value {
+    fn from(value: ArrayMetadata) -> Self {
+        value.to_value(MetadataVersion::V1)
+    }
+}
+
+/// Translate a GeoArrow field written by another implementation to the field this crate writes
+/// for the same data.
+///
+/// The storage type is rebuilt with this crate's child field names, legacy extension names such
+/// as `ogc.wkb` are replaced by their GeoArrow names, and the extension metadata is written in the
+/// 1.0 format. The name and nullability of the field are kept.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+///
+/// use arrow_schema::{DataType, Field};
+/// use geoarrow::array::metadata::translate_field;
+///
+/// let vertices = Field::new("vertices", DataType::Float64, false);
+/// let field = Field::new("geometry", DataType::FixedSizeList(Arc::new(vertices), 2), true)
+///     .with_metadata(HashMap::from([(
+///         "ARROW:extension:name".to_string(),
+///         "geoarrow.point".to_string(),
+///     )]));
+///
+/// let translated = translate_field(&field).unwrap();
+/// let DataType::FixedSizeList(values, 2) = translated.data_type() else {
+///     unreachable!()
+/// };
+/// assert_eq!(values.name(), "xy");
+/// ```
+pub fn translate_field(field: &Field) -> Result<Field> {
+    let data_type = GeoDataType::try_from(field)?;
+    let metadata = ArrayMetadata::from_field(field)?;
+
+    let mut field_metadata = field.metadata().clone();
+    field_metadata.insert(
+        "ARROW:extension:name".to_string(),
+        data_type.extension_name().to_string(),
+    );
+    field_metadata.insert(
+        "ARROW:extension:metadata".to_string(),
+        metadata.to_extension_metadata(MetadataVersion::V1),
+    );
+    Ok(
+        Field::new(field.name(), data_type.to_data_type(), field.is_nullable())
+            .with_metadata(field_metadata),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_versions() {
+        let metadata = ArrayMetadata::default()
+            .with_crs("EPSG:4326".to_string(), Some(CrsType::AuthorityCode))
+            .with_edges(Some(Edges::Spherical));
+
+        let v1 = metadata.to_extension_metadata(MetadataVersion::V1);
+        assert_eq!(
+            v1,
+            r#"{"crs":"EPSG:4326","crs_type":"authority_code","edges":"spherical"}"#
+        );
+        assert_eq!(
+            ArrayMetadata::from_extension_metadata(&v1).unwrap(),
+            metadata
+        );
+
+        // The CRS type is lost in legacy metadata
+        let legacy = metadata.to_extension_metadata(MetadataVersion::Legacy);
+        assert_eq!(legacy, r#"{"crs":"EPSG:4326","edges":"spherical"}"#);
+        let parsed = ArrayMetadata::from_extension_metadata(&legacy).unwrap();
+        assert_eq!(parsed.crs(), Some("EPSG:4326"));
+        assert_eq!(parsed.crs_type(), None);
+    }
+
+    #[test]
+    fn default_and_invalid() {
+        let metadata = ArrayMetadata::default();
+        assert_eq!(serde_json::to_string(&metadata).unwrap(), "{}");
+        assert_eq!(
+            serde_json::from_str::<ArrayMetadata>("{}").unwrap(),
+            metadata
+        );
+        assert!(ArrayMetadata::from_extension_metadata(r#"{"edges": "geodesic"}"#).is_err());
+        assert!(ArrayMetadata::from_extension_metadata(r#"{"crs": 4326}"#).is_err());
+    }
+
+    #[test]
+    fn translate_legacy_field() {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        use arrow_schema::DataType;
+
+        let xy = Field::new("xy", DataType::Float64, false);
+        let coords = Field::new("xy", DataType::FixedSizeList(Arc::new(xy), 2), false);
+        let field = Field::new("geom", DataType::List(Arc::new(coords)), true).with_metadata(
+            HashMap::from([
+                (
+                    "ARROW:extension:name".to_string(),
+                    "geoarrow.linestring".to_string(),
+                ),
+                (
+                    "ARROW:extension:metadata".to_string(),
+                    r#"{"crs": "{\"id\": 4326}"}"#.to_string(),
+                ),
+            ]),
+        );
+
+        let translated = translate_field(&field).unwrap();
+        assert_eq!(translated.name(), "geom");
+        assert_eq!(
+            translated.data_type(),
+            &GeoDataType::LineString(Default::default()).to_data_type()
+        );
+        assert_eq!(
+            translated
+                .metadata()
+                .get("ARROW:extension:metadata")
+                .unwrap(),
+            r#"{"crs":{"id":4326}}"#
+        );
+
+        let field = Field::new("geom", DataType::Binary, true).with_metadata(HashMap::from([(
+            "ARROW:extension:name".to_string(),
+            "ogc.wkb".to_string(),
+        )]));
+        let translated = translate_field(&field).unwrap();
+        assert_eq!(
+            translated.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.wkb"
+        );
+    }
+}
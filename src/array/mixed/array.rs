@@ -1,13 +1,15 @@
+use geo::CoordNum;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use arrow_array::{Array, OffsetSizeTrait, UnionArray};
-use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_buffer::{NullBuffer, NullBufferBuilder, ScalarBuffer};
 use arrow_schema::{DataType, Field, UnionFields, UnionMode};
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::mixed::builder::MixedGeometryBuilder;
 use crate::array::mixed::MixedCapacity;
+use crate::array::validation::ValidationPolicy;
 use crate::array::{
     LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, PointArray,
     PolygonArray, WKBArray,
@@ -89,9 +91,15 @@ pub struct MixedGeometryArray<O: OffsetSizeTrait> {
     /// Note that this offset is only for slicing into the **fields**, i.e. the geometry arrays.
     /// The `type_ids` and `offsets` arrays are sliced as usual.
     ///
-    /// TODO: when exporting this array, export to arrow2 and then slice from scratch because we
-    /// can't set the `offset` in a UnionArray constructor
+    /// TODO: when exporting this array, slice the children from scratch because we can't set the
+    /// `offset` in a UnionArray constructor
     pub(crate) slice_offset: usize,
+
+    /// The logical nulls of this array.
+    ///
+    /// Union arrays have no validity of their own, so a slot is null iff the value it points to
+    /// in its child array is null. This is computed from the child arrays on construction.
+    nulls: Option<NullBuffer>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -186,14 +194,15 @@ impl<O: OffsetSizeTrait> MixedGeometryArray<O> {
         if let Some(ref multi_polygons) = multi_polygons {
             coord_types.insert(multi_polygons.coord_type());
         }
-        assert_eq!(coord_types.len(), 1);
-        let coord_type = coord_types.into_iter().next().unwrap();
+        // An array without any child arrays has no geometries, so its coord type doesn't matter
+        assert!(coord_types.len() <= 1);
+        let coord_type = coord_types.into_iter().next().unwrap_or_default();
         let data_type = match O::IS_LARGE {
             true => GeoDataType::LargeMixed(coord_type),
             false => GeoDataType::Mixed(coord_type),
         };
 
-        Self {
+        let mut array = Self {
             data_type,
             type_ids,
             offsets,
@@ -206,9 +215,81 @@ impl<O: OffsetSizeTrait> MixedGeometryArray<O> {
             multi_polygons,
             slice_offset: 0,
             metadata,
+            nulls: None,
+        };
+        array.nulls = array.child_nulls();
+        array
+    }
+
+    /// Apply a [`ValidationPolicy`] to the child arrays of this array.
+    ///
+    /// Note that geometry indices in validation errors are indices into the child array of the
+    /// invalid geometry's type, not into this array.
+    pub fn with_validation(mut self, policy: ValidationPolicy) -> Result<Self> {
+        self.points = self.points.map(|a| a.with_validation(policy)).transpose()?;
+        self.line_strings = self
+            .line_strings
+            .map(|a| a.with_validation(policy))
+            .transpose()?;
+        self.polygons = self
+            .polygons
+            .map(|a| a.with_validation(policy))
+            .transpose()?;
+        self.multi_points = self
+            .multi_points
+            .map(|a| a.with_validation(policy))
+            .transpose()?;
+        self.multi_line_strings = self
+            .multi_line_strings
+            .map(|a| a.with_validation(policy))
+            .transpose()?;
+        self.multi_polygons = self
+            .multi_polygons
+            .map(|a| a.with_validation(policy))
+            .transpose()?;
+        self.nulls = self.child_nulls();
+        Ok(self)
+    }
+
+    /// Whether the value slot `i` points to in its child array is null.
+    fn child_is_null(&self, i: usize) -> bool {
+        let child_index = self.type_ids[i];
+        let offset = self.offsets[i] as usize;
+        match self.map[child_index as usize].unwrap() {
+            GeometryType::Point => self.points.as_ref().unwrap().is_null(offset),
+            GeometryType::LineString => self.line_strings.as_ref().unwrap().is_null(offset),
+            GeometryType::Polygon => self.polygons.as_ref().unwrap().is_null(offset),
+            GeometryType::MultiPoint => self.multi_points.as_ref().unwrap().is_null(offset),
+            GeometryType::MultiLineString => {
+                self.multi_line_strings.as_ref().unwrap().is_null(offset)
+            }
+            GeometryType::MultiPolygon => self.multi_polygons.as_ref().unwrap().is_null(offset),
+            GeometryType::GeometryCollection => false,
         }
     }
 
+    /// Compute the logical nulls of this array from its child arrays.
+    fn child_nulls(&self) -> Option<NullBuffer> {
+        let mut builder = NullBufferBuilder::new(self.len());
+        (0..self.len()).for_each(|i| builder.append(!self.child_is_null(i)));
+        builder.finish()
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        MixedGeometryBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    ///
+    /// Union arrays have no validity bitmap of their own, so the nulls are stored in the point
+    /// child array.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = MixedGeometryBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> MixedCapacity {
         MixedCapacity::new(
@@ -374,10 +455,10 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for MixedGeometryArray<O> {
         self.type_ids.len()
     }
 
-    /// Returns the optional validity.
+    /// Returns the logical nulls of this array, computed from its child arrays.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
-        None
+        self.nulls.as_ref()
     }
 
     fn as_ref(&self) -> &dyn GeometryArrayTrait {
@@ -422,6 +503,7 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for MixedGeometryArray<O> {
             multi_polygons: self.multi_polygons.clone(),
             slice_offset: self.slice_offset + offset,
             metadata: self.metadata.clone(),
+            nulls: self.nulls.as_ref().map(|nulls| nulls.slice(offset, length)),
         }
     }
 
@@ -703,7 +785,9 @@ impl TryFrom<&dyn Array> for MixedGeometryArray<i64> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<&[G]> for MixedGeometryArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryTrait<T = T>> TryFrom<&[G]>
+    for MixedGeometryArray<O>
+{
     type Error = GeoArrowError;
 
     fn try_from(geoms: &[G]) -> Result<Self> {
@@ -712,7 +796,7 @@ impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<&[G]> for MixedGeome
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<&[Option<G>]>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryTrait<T = T>> TryFrom<&[Option<G>]>
     for MixedGeometryArray<O>
 {
     type Error = GeoArrowError;
@@ -732,6 +816,48 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MixedGeometryArray<O> {
     }
 }
 
+/// The type ids and offsets of a mixed array whose geometries all come from one child array, in
+/// order.
+fn single_type_buffers(
+    geometry_type: GeometryType,
+    len: usize,
+) -> (ScalarBuffer<i8>, ScalarBuffer<i32>) {
+    let type_ids = vec![geometry_type.default_ordering(); len];
+    let offsets = (0..len)
+        .map(|i| i32::try_from(i).expect("Mixed arrays hold at most i32::MAX geometries"))
+        .collect::<Vec<_>>();
+    (type_ids.into(), offsets.into())
+}
+
+/// Conversions of single geometry type arrays into a mixed array. These reuse the input array as
+/// the only child of the mixed array without copying coordinates.
+macro_rules! impl_from_single_type {
+    ($array:ty, $geometry_type:expr, $child_position:tt) => {
+        impl<O: OffsetSizeTrait> From<$array> for MixedGeometryArray<O> {
+            fn from(value: $array) -> Self {
+                if value.is_empty() {
+                    return Self::new_empty();
+                }
+                let (type_ids, offsets) = single_type_buffers($geometry_type, value.len());
+                let metadata = value.metadata.clone();
+                let mut children = (None, None, None, None, None, None);
+                children.$child_position = Some(value);
+                Self::new(
+                    type_ids, offsets, children.0, children.1, children.2, children.3, children.4,
+                    children.5, metadata,
+                )
+            }
+        }
+    };
+}
+
+impl_from_single_type!(PointArray, GeometryType::Point, 0);
+impl_from_single_type!(LineStringArray<O>, GeometryType::LineString, 1);
+impl_from_single_type!(PolygonArray<O>, GeometryType::Polygon, 2);
+impl_from_single_type!(MultiPointArray<O>, GeometryType::MultiPoint, 3);
+impl_from_single_type!(MultiLineStringArray<O>, GeometryType::MultiLineString, 4);
+impl_from_single_type!(MultiPolygonArray<O>, GeometryType::MultiPolygon, 5);
+
 impl From<MixedGeometryArray<i32>> for MixedGeometryArray<i64> {
     fn from(value: MixedGeometryArray<i32>) -> Self {
         Self::new(
@@ -779,9 +905,71 @@ impl<O: OffsetSizeTrait> Default for MixedGeometryArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::algorithm::native::Downcast;
     use crate::array::MixedGeometryArray;
     use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
 
+    #[test]
+    fn from_single_type() {
+        let arr: MixedGeometryArray<i32> = polygon::p_array().into();
+        assert_eq!(arr.len(), 2);
+        assert!(arr.has_polygons());
+        assert!(!arr.has_points());
+        assert_eq!(arr.value_as_geo(1), geo::Geometry::Polygon(polygon::p1()));
+
+        let points: PointArray = vec![Some(point::p0()), None].into();
+        let arr: MixedGeometryArray<i64> = points.into();
+        assert_eq!(arr.value_as_geo(0), geo::Geometry::Point(point::p0()));
+        assert!(arr.get(1).is_none());
+
+        let arr: MixedGeometryArray<i32> = MultiPointArray::<i32>::new_empty().into();
+        assert_eq!(arr.len(), 0);
+    }
+
+    #[test]
+    fn new_null() {
+        let arr = MixedGeometryArray::<i32>::new_null(2);
+        assert_eq!(arr.len(), 2);
+        assert!(arr.get(0).is_none());
+        assert!(arr.get(1).is_none());
+    }
+
+    #[test]
+    fn multi_point_offsets() {
+        let geoms = vec![
+            geo::Geometry::MultiPoint(multipoint::mp0()),
+            geo::Geometry::MultiPoint(multipoint::mp1()),
+        ];
+        let arr: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        assert_eq!(arr.value_as_geo(0), geoms[0]);
+        assert_eq!(arr.value_as_geo(1), geoms[1]);
+    }
+
+    #[test]
+    fn logical_nulls() {
+        let arr: MixedGeometryArray<i32> = vec![
+            None,
+            Some(geo::Geometry::Polygon(polygon::p0())),
+            None,
+            Some(geo::Geometry::Polygon(polygon::p1())),
+        ]
+        .as_slice()
+        .try_into()
+        .unwrap();
+        assert_eq!(arr.null_count(), 2);
+        assert_eq!(arr.logical_nulls().unwrap().null_count(), 2);
+        assert!(arr.is_null(0));
+        assert!(arr.is_valid(1));
+        assert_eq!(arr.slice(1, 2).null_count(), 1);
+
+        // The nulls are stored in the polygon child array, so no points child array is needed
+        assert!(!arr.has_points());
+        assert_eq!(
+            arr.downcasted_data_type(true),
+            GeoDataType::Polygon(Default::default())
+        );
+    }
+
     #[test]
     fn geo_roundtrip_accurate_points() {
         let geoms: Vec<geo::Geometry> = vec![
@@ -805,6 +993,86 @@ mod test {
         );
     }
 
+    #[test]
+    fn push_geometries_one_at_a_time() {
+        let mut builder = MixedGeometryBuilder::<i32>::new();
+        builder.push_point(Some(&point::p0()));
+        builder.push_null();
+        builder
+            .push_geometry(Some(&geo::Geometry::Polygon(polygon::p0())))
+            .unwrap();
+        let arr: MixedGeometryArray<i32> = builder.into();
+
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.get_as_geo(0), Some(geo::Geometry::Point(point::p0())));
+        assert!(arr.is_null(1));
+        assert_eq!(arr.get_as_geo(1), None);
+        assert_eq!(
+            arr.get_as_geo(2),
+            Some(geo::Geometry::Polygon(polygon::p0()))
+        );
+    }
+
+    #[test]
+    fn capacity_matches_buffer_lengths() {
+        let geoms: Vec<Option<geo::Geometry>> = vec![
+            Some(geo::Geometry::Point(point::p0())),
+            None,
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            Some(geo::Geometry::Polygon(polygon::p0())),
+            Some(geo::Geometry::MultiPolygon(multipolygon::mp0())),
+        ];
+
+        for prefer_multi in [true, false] {
+            let capacity =
+                MixedCapacity::from_geometries(geoms.iter().map(|g| g.as_ref()), prefer_multi)
+                    .unwrap();
+            let arr = MixedGeometryBuilder::<i32>::from_nullable_geometries(
+                &geoms,
+                None,
+                Default::default(),
+                prefer_multi,
+            )
+            .unwrap()
+            .finish();
+            let lengths = arr.buffer_lengths();
+
+            assert_eq!(capacity.total_num_geoms(), arr.len());
+            // Nulls are counted as points, but are moved to another child array if no points are
+            // added
+            assert!(capacity.point_capacity() >= lengths.point_capacity());
+            assert_eq!(
+                capacity.line_string_capacity().coord_capacity(),
+                lengths.line_string_capacity().coord_capacity()
+            );
+            assert_eq!(
+                capacity.polygon_capacity().coord_capacity(),
+                lengths.polygon_capacity().coord_capacity()
+            );
+            assert_eq!(
+                capacity.multi_point_capacity().coord_capacity(),
+                lengths.multi_point_capacity().coord_capacity()
+            );
+            assert_eq!(
+                capacity.multi_line_string_capacity().coord_capacity(),
+                lengths.multi_line_string_capacity().coord_capacity()
+            );
+            assert_eq!(
+                capacity.multi_polygon_capacity().coord_capacity(),
+                lengths.multi_polygon_capacity().coord_capacity()
+            );
+        }
+    }
+
+    #[test]
+    fn rect_is_unsupported() {
+        let rect = geo::Geometry::Rect(geo::Rect::new((0., 0.), (1., 1.)));
+        assert!(MixedCapacity::from_geometries([Some(&rect)].into_iter(), false).is_err());
+
+        let mut builder = MixedGeometryBuilder::<i32>::new();
+        assert!(builder.push_geometry(Some(&rect)).is_err());
+    }
+
     #[test]
     fn geo_roundtrip_accurate_all() {
         let geoms: Vec<geo::Geometry> = vec![
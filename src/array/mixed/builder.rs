@@ -1,3 +1,5 @@
+use geo::CoordNum;
+use std::ops::Range;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
@@ -11,7 +13,9 @@ use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::*;
 use crate::io::wkb::reader::WKBGeometry;
 use crate::scalar::WKB;
-use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::trait_::{
+    GeometryArrayAccessor, GeometryArrayBuilder, GeometryArraySelfMethods, IntoArrow,
+};
 use crate::GeometryArrayTrait;
 use arrow_array::{OffsetSizeTrait, UnionArray};
 
@@ -67,7 +71,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
         // Don't store array metadata on child arrays
         Self {
             metadata,
-            types: vec![],
+            types: Vec::with_capacity(capacity.total_num_geoms()),
             points: PointBuilder::with_capacity_and_options(
                 capacity.point,
                 coord_type,
@@ -98,22 +102,32 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
                 coord_type,
                 Default::default(),
             ),
-            offsets: vec![],
+            offsets: Vec::with_capacity(capacity.total_num_geoms()),
         }
     }
 
+    /// Creates a new [`MixedGeometryBuilder`] with the exact capacity needed to push `geoms`.
+    ///
+    /// `prefer_multi` must match the value later passed when pushing these geometries.
     pub fn with_capacity_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
-        Self::with_capacity_and_options_from_iter(geoms, Default::default(), Default::default())
+        Self::with_capacity_and_options_from_iter(
+            geoms,
+            Default::default(),
+            Default::default(),
+            prefer_multi,
+        )
     }
 
     pub fn with_capacity_and_options_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
         coord_type: CoordType,
         metadata: Arc<ArrayMetadata>,
+        prefer_multi: bool,
     ) -> Result<Self> {
-        let counter = MixedCapacity::from_geometries(geoms)?;
+        let counter = MixedCapacity::from_geometries(geoms, prefer_multi)?;
         Ok(Self::with_capacity_and_options(
             counter, coord_type, metadata,
         ))
@@ -147,8 +161,9 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     pub fn reserve_from_iter(
         &mut self,
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<()> {
-        let counter = MixedCapacity::from_geometries(geoms)?;
+        let counter = MixedCapacity::from_geometries(geoms, prefer_multi)?;
         self.reserve(counter);
         Ok(())
     }
@@ -156,8 +171,9 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     pub fn reserve_exact_from_iter(
         &mut self,
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<()> {
-        let counter = MixedCapacity::from_geometries(geoms)?;
+        let counter = MixedCapacity::from_geometries(geoms, prefer_multi)?;
         self.reserve_exact(counter);
         Ok(())
     }
@@ -193,7 +209,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
 
     /// Add a new Point to the end of this array, storing it in the PointBuilder child array.
     #[inline]
-    pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) {
+    pub fn push_point(&mut self, value: Option<&impl PointTrait<T = impl CoordNum>>) {
         self.add_point_type();
         self.points.push_point(value)
     }
@@ -209,7 +225,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_point_as_multi_point(
         &mut self,
-        value: Option<&impl PointTrait<T = f64>>,
+        value: Option<&impl PointTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_multi_point_type();
         self.multi_points.push_point(value)
@@ -224,7 +240,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_line_string(
         &mut self,
-        value: Option<&impl LineStringTrait<T = f64>>,
+        value: Option<&impl LineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_line_string_type();
         self.line_strings.push_line_string(value)
@@ -246,7 +262,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_line_string_as_multi_line_string(
         &mut self,
-        value: Option<&impl LineStringTrait<T = f64>>,
+        value: Option<&impl LineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_multi_line_string_type();
         self.multi_line_strings.push_line_string(value)
@@ -259,7 +275,10 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     ///
     /// This function errors iff the new last item is larger than what O supports.
     #[inline]
-    pub fn push_polygon(&mut self, value: Option<&impl PolygonTrait<T = f64>>) -> Result<()> {
+    pub fn push_polygon(
+        &mut self,
+        value: Option<&impl PolygonTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         self.add_polygon_type();
         self.polygons.push_polygon(value)
     }
@@ -279,7 +298,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_polygon_as_multi_polygon(
         &mut self,
-        value: Option<&impl PolygonTrait<T = f64>>,
+        value: Option<&impl PolygonTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_multi_polygon_type();
         self.multi_polygons.push_polygon(value)
@@ -293,7 +312,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_multi_point(
         &mut self,
-        value: Option<&impl MultiPointTrait<T = f64>>,
+        value: Option<&impl MultiPointTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_multi_point_type();
         self.multi_points.push_multi_point(value)
@@ -314,7 +333,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_multi_line_string(
         &mut self,
-        value: Option<&impl MultiLineStringTrait<T = f64>>,
+        value: Option<&impl MultiLineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_multi_line_string_type();
         self.multi_line_strings.push_multi_line_string(value)
@@ -336,7 +355,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     pub fn push_multi_polygon(
         &mut self,
-        value: Option<&impl MultiPolygonTrait<T = f64>>,
+        value: Option<&impl MultiPolygonTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self.add_multi_polygon_type();
         self.multi_polygons.push_multi_polygon(value)
@@ -351,14 +370,17 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&'a impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         self._push_geometry(value, false)
     }
 
     #[inline]
     pub fn push_geometry_preferring_multi(
         &mut self,
-        value: Option<&'a impl GeometryTrait<T = f64>>,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         self._push_geometry(value, true)
     }
@@ -366,7 +388,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     #[inline]
     fn _push_geometry(
         &mut self,
-        value: Option<&'a impl GeometryTrait<T = f64>>,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
         prefer_multi: bool,
     ) -> Result<()> {
         if let Some(geom) = value {
@@ -408,7 +430,11 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
                         ));
                     }
                 }
-                crate::geo_traits::GeometryType::Rect(_) => todo!(),
+                crate::geo_traits::GeometryType::Rect(_) => {
+                    return Err(GeoArrowError::General(
+                        "rect geometries not supported in mixed arrays".to_string(),
+                    ));
+                }
             };
         } else {
             self.push_null();
@@ -416,14 +442,81 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this builder.
+    ///
+    /// Union arrays have no validity bitmap of their own, so the null is stored in the
+    /// PointBuilder child array. If no points are added to this builder, the nulls are moved to
+    /// a child array that is in use when the builder is finished.
     #[inline]
     pub fn push_null(&mut self) {
-        todo!("push null geometry")
+        self.add_point_type();
+        self.points.push_null();
+    }
+
+    /// Move the nulls of the PointBuilder child array to a child array that is in use, if the
+    /// PointBuilder child array holds only nulls.
+    ///
+    /// This keeps e.g. an array of polygons and nulls from having a points child array, so that
+    /// it can still be downcast to a PolygonArray.
+    fn move_null_points(&mut self) {
+        let only_nulls = GeometryArrayBuilder::validity(&self.points)
+            .finish_cloned()
+            .is_some_and(|nulls| nulls.null_count() == nulls.len());
+        if !only_nulls {
+            return;
+        }
+
+        let target = [
+            (GeometryType::LineString, self.line_strings.len()),
+            (GeometryType::Polygon, self.polygons.len()),
+            (GeometryType::MultiPoint, self.multi_points.len()),
+            (GeometryType::MultiLineString, self.multi_line_strings.len()),
+            (GeometryType::MultiPolygon, self.multi_polygons.len()),
+        ]
+        .into_iter()
+        .find(|(_, len)| *len > 0);
+        let Some((geometry_type, _)) = target else {
+            return;
+        };
+
+        let point_type = GeometryType::Point.default_ordering();
+        for (type_id, offset) in self.types.iter_mut().zip(self.offsets.iter_mut()) {
+            if *type_id != point_type {
+                continue;
+            }
+            let child_len = match geometry_type {
+                GeometryType::LineString => {
+                    self.line_strings.push_null();
+                    self.line_strings.len()
+                }
+                GeometryType::Polygon => {
+                    self.polygons.push_null();
+                    self.polygons.len()
+                }
+                GeometryType::MultiPoint => {
+                    self.multi_points.push_null();
+                    self.multi_points.len()
+                }
+                GeometryType::MultiLineString => {
+                    self.multi_line_strings.push_null();
+                    self.multi_line_strings.len()
+                }
+                GeometryType::MultiPolygon => {
+                    self.multi_polygons.push_null();
+                    self.multi_polygons.len()
+                }
+                _ => unreachable!(),
+            };
+            *type_id = geometry_type.default_ordering();
+            *offset = (child_len - 1).try_into().unwrap();
+        }
+        self.points =
+            PointBuilder::new_with_options(self.points.coord_type(), self.metadata.clone());
     }
 
     pub fn extend_from_iter(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait<T = impl CoordNum> + 'a)>>,
         prefer_multi: bool,
     ) {
         geoms
@@ -440,7 +533,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
 
     /// Create this builder from a slice of Geometries.
     pub fn from_geometries(
-        geoms: &[impl GeometryTrait<T = f64>],
+        geoms: &[impl GeometryTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
@@ -449,6 +542,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
             geoms.iter().map(Some),
             coord_type.unwrap_or_default(),
             metadata,
+            prefer_multi,
         )?;
         array.extend_from_iter(geoms.iter().map(Some), prefer_multi);
         Ok(array)
@@ -456,7 +550,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
 
     /// Create this builder from a slice of nullable Geometries.
     pub fn from_nullable_geometries(
-        geoms: &[Option<impl GeometryTrait<T = f64>>],
+        geoms: &[Option<impl GeometryTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
@@ -465,6 +559,7 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
             geoms.iter().map(|x| x.as_ref()),
             coord_type.unwrap_or_default(),
             metadata,
+            prefer_multi,
         )?;
         array.extend_from_iter(geoms.iter().map(|x| x.as_ref()), prefer_multi);
         Ok(array)
@@ -488,6 +583,75 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
     }
 }
 
+impl<O: OffsetSizeTrait> MixedGeometryBuilder<O> {
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The range of each child array referenced by `array` is appended to the matching child
+    /// builder directly, and the type ids and offsets of `array` are appended with the offsets
+    /// shifted to point into the extended child builders.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &MixedGeometryArray<O>) -> Result<()> {
+        // The range of each child array referenced by `array`, indexed by default ordering
+        let mut child_ranges: [Option<Range<usize>>; 7] = Default::default();
+        for (type_id, offset) in array.type_ids.iter().zip(array.offsets.iter()) {
+            let child_index = array.map[*type_id as usize].unwrap().default_ordering() as usize;
+            let offset = *offset as usize;
+            let range = child_ranges[child_index].get_or_insert(offset..offset + 1);
+            range.start = range.start.min(offset);
+            range.end = range.end.max(offset + 1);
+        }
+
+        // The amount to add to each offset of `array` so that it points into this builder
+        let mut child_shifts = [0_i64; 7];
+        if let (Some(range), Some(points)) = (&child_ranges[1], &array.points) {
+            child_shifts[1] = self.points.len() as i64 - range.start as i64;
+            self.points
+                .extend_from_array(&points.slice(range.start, range.len()));
+        }
+        if let (Some(range), Some(line_strings)) = (&child_ranges[2], &array.line_strings) {
+            child_shifts[2] = self.line_strings.len() as i64 - range.start as i64;
+            self.line_strings
+                .extend_from_array(&line_strings.slice(range.start, range.len()))?;
+        }
+        if let (Some(range), Some(polygons)) = (&child_ranges[3], &array.polygons) {
+            child_shifts[3] = self.polygons.len() as i64 - range.start as i64;
+            self.polygons
+                .extend_from_array(&polygons.slice(range.start, range.len()))?;
+        }
+        if let (Some(range), Some(multi_points)) = (&child_ranges[4], &array.multi_points) {
+            child_shifts[4] = self.multi_points.len() as i64 - range.start as i64;
+            self.multi_points
+                .extend_from_array(&multi_points.slice(range.start, range.len()))?;
+        }
+        if let (Some(range), Some(multi_line_strings)) =
+            (&child_ranges[5], &array.multi_line_strings)
+        {
+            child_shifts[5] = self.multi_line_strings.len() as i64 - range.start as i64;
+            self.multi_line_strings
+                .extend_from_array(&multi_line_strings.slice(range.start, range.len()))?;
+        }
+        if let (Some(range), Some(multi_polygons)) = (&child_ranges[6], &array.multi_polygons) {
+            child_shifts[6] = self.multi_polygons.len() as i64 - range.start as i64;
+            self.multi_polygons
+                .extend_from_array(&multi_polygons.slice(range.start, range.len()))?;
+        }
+
+        self.types.reserve(array.len());
+        self.offsets.reserve(array.len());
+        for (type_id, offset) in array.type_ids.iter().zip(array.offsets.iter()) {
+            let child_index = array.map[*type_id as usize].unwrap().default_ordering();
+            let offset = *offset as i64 + child_shifts[child_index as usize];
+            self.types.push(child_index);
+            self.offsets
+                .push(offset.try_into().map_err(|_| GeoArrowError::Overflow)?);
+        }
+        Ok(())
+    }
+}
+
 impl<O: OffsetSizeTrait> Default for MixedGeometryBuilder<O> {
     fn default() -> Self {
         Self::new()
@@ -503,7 +667,8 @@ impl<O: OffsetSizeTrait> IntoArrow for MixedGeometryBuilder<O> {
 }
 
 impl<O: OffsetSizeTrait> From<MixedGeometryBuilder<O>> for MixedGeometryArray<O> {
-    fn from(other: MixedGeometryBuilder<O>) -> Self {
+    fn from(mut other: MixedGeometryBuilder<O>) -> Self {
+        other.move_null_points();
         Self::new(
             other.types.into(),
             other.offsets.into(),
@@ -542,7 +707,9 @@ impl<O: OffsetSizeTrait> From<MixedGeometryBuilder<O>> for MixedGeometryArray<O>
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<&[G]> for MixedGeometryBuilder<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryTrait<T = T>> TryFrom<&[G]>
+    for MixedGeometryBuilder<O>
+{
     type Error = GeoArrowError;
 
     fn try_from(geoms: &[G]) -> Result<Self> {
@@ -550,7 +717,7 @@ impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<&[G]> for MixedGeome
     }
 }
 
-impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> TryFrom<&[Option<G>]>
+impl<O: OffsetSizeTrait, T: CoordNum, G: GeometryTrait<T = T>> TryFrom<&[Option<G>]>
     for MixedGeometryBuilder<O>
 {
     type Error = GeoArrowError;
@@ -7,7 +7,7 @@ use crate::array::multilinestring::MultiLineStringCapacity;
 use crate::array::multipoint::MultiPointCapacity;
 use crate::array::multipolygon::MultiPolygonCapacity;
 use crate::array::polygon::PolygonCapacity;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::*;
 
 /// A counter for the buffer sizes of a [`MixedGeometryArray`][crate::array::MixedGeometryArray].
@@ -177,45 +177,86 @@ impl MixedCapacity {
         self.multi_polygon.add_multi_polygon(multi_polygon);
     }
 
+    /// Add a Geometry to this capacity counter.
+    ///
+    /// If `prefer_multi` is `true`, single-part geometries are counted towards the multi-part
+    /// child array they will be stored in, matching
+    /// [`push_geometry_preferring_multi`][crate::array::MixedGeometryBuilder::push_geometry_preferring_multi].
+    /// Null geometries are counted towards the point child array, which is where they are stored
+    /// unless no points are added.
     #[inline]
-    pub fn add_geometry(&mut self, geom: Option<&impl GeometryTrait>) -> Result<()> {
-        // TODO: what to do about null geometries? We don't know which type they have
-        assert!(geom.is_some());
+    pub fn add_geometry(
+        &mut self,
+        geom: Option<&impl GeometryTrait>,
+        prefer_multi: bool,
+    ) -> Result<()> {
         if let Some(geom) = geom {
             match geom.as_type() {
-                crate::geo_traits::GeometryType::Point(_) => self.add_point(),
-                crate::geo_traits::GeometryType::LineString(g) => self.add_line_string(Some(g)),
-                crate::geo_traits::GeometryType::Polygon(g) => self.add_polygon(Some(g)),
-                crate::geo_traits::GeometryType::MultiPoint(p) => self.add_multi_point(Some(p)),
-                crate::geo_traits::GeometryType::MultiLineString(p) => {
-                    self.add_multi_line_string(Some(p))
+                GeometryType::Point(g) => {
+                    if prefer_multi {
+                        self.multi_point.add_point(Some(g))
+                    } else {
+                        self.add_point()
+                    }
                 }
-                crate::geo_traits::GeometryType::MultiPolygon(p) => self.add_multi_polygon(Some(p)),
-                crate::geo_traits::GeometryType::GeometryCollection(_) => {
-                    panic!("nested geometry collections not supported")
+                GeometryType::LineString(g) => {
+                    if prefer_multi {
+                        self.multi_line_string.add_line_string(Some(g))
+                    } else {
+                        self.add_line_string(Some(g))
+                    }
+                }
+                GeometryType::Polygon(g) => {
+                    if prefer_multi {
+                        self.multi_polygon.add_polygon(Some(g))
+                    } else {
+                        self.add_polygon(Some(g))
+                    }
+                }
+                GeometryType::MultiPoint(p) => self.add_multi_point(Some(p)),
+                GeometryType::MultiLineString(p) => self.add_multi_line_string(Some(p)),
+                GeometryType::MultiPolygon(p) => self.add_multi_polygon(Some(p)),
+                GeometryType::GeometryCollection(gc) => {
+                    if gc.num_geometries() == 1 {
+                        self.add_geometry(Some(&gc.geometry(0).unwrap()), prefer_multi)?
+                    } else {
+                        return Err(GeoArrowError::General(
+                            "nested geometry collections not supported".to_string(),
+                        ));
+                    }
+                }
+                GeometryType::Rect(_) => {
+                    return Err(GeoArrowError::General(
+                        "rect geometries not supported in mixed arrays".to_string(),
+                    ));
                 }
-                crate::geo_traits::GeometryType::Rect(_) => todo!(),
             };
-        };
+        } else {
+            self.add_point();
+        }
         Ok(())
     }
 
+    /// Create a capacity counter from an iterator of Geometries.
     pub fn from_geometries<'a>(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
         let mut counter = Self::new_empty();
         for maybe_geom in geoms.into_iter() {
-            counter.add_geometry(maybe_geom)?;
+            counter.add_geometry(maybe_geom, prefer_multi)?;
         }
         Ok(counter)
     }
 
+    /// Create a capacity counter from an iterator of owned Geometries.
     pub fn from_owned_geometries<'a>(
         geoms: impl Iterator<Item = Option<(impl GeometryTrait + 'a)>>,
+        prefer_multi: bool,
     ) -> Result<Self> {
         let mut counter = Self::new_empty();
         for maybe_geom in geoms.into_iter() {
-            counter.add_geometry(maybe_geom.as_ref())?;
+            counter.add_geometry(maybe_geom.as_ref(), prefer_multi)?;
         }
         Ok(counter)
     }
@@ -6,9 +6,11 @@ pub use coord::{
     CoordBuffer, CoordBufferBuilder, CoordType, InterleavedCoordBuffer,
     InterleavedCoordBufferBuilder, SeparatedCoordBuffer, SeparatedCoordBufferBuilder,
 };
+pub use dictionary::DictionaryGeometryArray;
 pub use geometrycollection::{
     GeometryCollectionArray, GeometryCollectionBuilder, GeometryCollectionCapacity,
 };
+pub use iterator::GeometryArrayIter;
 pub use linestring::{LineStringArray, LineStringBuilder, LineStringCapacity};
 pub use mixed::{MixedCapacity, MixedGeometryArray, MixedGeometryBuilder};
 pub use multilinestring::{MultiLineStringArray, MultiLineStringBuilder, MultiLineStringCapacity};
@@ -17,12 +19,17 @@ pub use multipolygon::{MultiPolygonArray, MultiPolygonBuilder, MultiPolygonCapac
 pub use point::{PointArray, PointBuilder};
 pub use polygon::{PolygonArray, PolygonBuilder, PolygonCapacity};
 pub use rect::{RectArray, RectBuilder};
+pub use run_end::RunEndEncodedGeometryArray;
+pub use validation::ValidationPolicy;
 
 pub(crate) mod binary;
 mod cast;
 pub(crate) mod coord;
+pub(crate) mod dictionary;
+mod display;
 pub(crate) mod geometry;
 pub(crate) mod geometrycollection;
+mod iterator;
 pub(crate) mod linestring;
 pub mod metadata;
 pub(crate) mod mixed;
@@ -33,55 +40,123 @@ pub(crate) mod offset_builder;
 pub(crate) mod point;
 pub(crate) mod polygon;
 pub(crate) mod rect;
+pub(crate) mod run_end;
 pub(crate) mod util;
+pub(crate) mod validation;
 
 use std::sync::Arc;
 
+use arrow_array::cast::as_run_array;
+use arrow_array::types::{Int16Type, Int32Type, Int64Type};
 use arrow_array::Array;
 use arrow_schema::{DataType, Field};
 
+use crate::array::metadata::ArrayMetadata;
 use crate::error::{GeoArrowError, Result};
 use crate::GeometryArrayTrait;
 
 /// Convert an Arrow [Array] to a geoarrow GeometryArray
+///
+/// The concrete geometry array is chosen from the `ARROW:extension:name` metadata of the field,
+/// with the offset width taken from the field's data type. Arrays without extension metadata are
+/// only accepted if their type is unambiguous: binary arrays are parsed as WKB, and struct and
+/// fixed size list arrays as points.
+///
+/// Child field names are not significant, so arrays written with the child field names of other
+/// implementations are imported as well. See [`metadata::translate_field`] to translate their
+/// fields.
+///
+/// Run-end encoded and dictionary-encoded arrays are decoded to their values type, which is then
+/// interpreted as above. Use [`RunEndEncodedGeometryArray::try_from_arrow`] or
+/// [`DictionaryGeometryArray::try_from_arrow`] to import them without decoding.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::{from_arrow_array, AsGeometryArray, PolygonArray};
+/// use geoarrow::GeometryArrayTrait;
+/// use geo::polygon;
+///
+/// let array: PolygonArray<i64> = vec![
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+/// ]
+/// .as_slice()
+/// .into();
+/// let field = array.extension_field();
+/// let arrow_array = array.into_array_ref();
+///
+/// let geometry_array = from_arrow_array(&arrow_array, &field).unwrap();
+/// assert_eq!(geometry_array.as_ref().as_large_polygon().len(), 1);
+/// ```
 pub fn from_arrow_array(array: &dyn Array, field: &Field) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let unexpected_data_type = || {
+        GeoArrowError::General(format!(
+            "Unexpected data type {:?} for field {}",
+            field.data_type(),
+            field.name()
+        ))
+    };
+
+    if let DataType::RunEndEncoded(run_ends_field, values_field) = field.data_type() {
+        let decoded = match run_ends_field.data_type() {
+            DataType::Int16 => run_end::decode_run_array(as_run_array::<Int16Type>(array))?,
+            DataType::Int32 => run_end::decode_run_array(as_run_array::<Int32Type>(array))?,
+            DataType::Int64 => run_end::decode_run_array(as_run_array::<Int64Type>(array))?,
+            _ => return Err(unexpected_data_type()),
+        };
+        let decoded_field = Field::new(field.name(), values_field.data_type().clone(), true)
+            .with_metadata(field.metadata().clone());
+        return from_arrow_array(&decoded, &decoded_field);
+    }
+
+    if let DataType::Dictionary(_, values_type) = field.data_type() {
+        let decoded = dictionary::decode_dictionary_array(array)?;
+        let decoded_field = Field::new(field.name(), values_type.as_ref().clone(), true)
+            .with_metadata(field.metadata().clone());
+        return from_arrow_array(&decoded, &decoded_field);
+    }
+
     if let Some(extension_name) = field.metadata().get("ARROW:extension:name") {
+        // Legacy and 1.0 metadata are both translated on import
+        let metadata = Arc::new(ArrayMetadata::from_field(field)?);
+        macro_rules! with_metadata {
+            ($array:expr) => {{
+                let mut array = $array;
+                array.metadata = metadata;
+                Arc::new(array)
+            }};
+        }
+
         let geom_arr: Arc<dyn GeometryArrayTrait> = match extension_name.as_str() {
-            "geoarrow.point" => Arc::new(PointArray::try_from(array).unwrap()),
+            "geoarrow.point" => with_metadata!(PointArray::try_from(array)?),
             "geoarrow.linestring" => match field.data_type() {
-                DataType::List(_) => Arc::new(LineStringArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => {
-                    Arc::new(LineStringArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
+                DataType::List(_) => with_metadata!(LineStringArray::<i32>::try_from(array)?),
+                DataType::LargeList(_) => with_metadata!(LineStringArray::<i64>::try_from(array)?),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.polygon" => match field.data_type() {
-                DataType::List(_) => Arc::new(PolygonArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => Arc::new(PolygonArray::<i64>::try_from(array).unwrap()),
-                _ => panic!("Unexpected data type"),
+                DataType::List(_) => with_metadata!(PolygonArray::<i32>::try_from(array)?),
+                DataType::LargeList(_) => with_metadata!(PolygonArray::<i64>::try_from(array)?),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.multipoint" => match field.data_type() {
-                DataType::List(_) => Arc::new(MultiPointArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => {
-                    Arc::new(MultiPointArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
+                DataType::List(_) => with_metadata!(MultiPointArray::<i32>::try_from(array)?),
+                DataType::LargeList(_) => with_metadata!(MultiPointArray::<i64>::try_from(array)?),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.multilinestring" => match field.data_type() {
-                DataType::List(_) => {
-                    Arc::new(MultiLineStringArray::<i32>::try_from(array).unwrap())
-                }
+                DataType::List(_) => with_metadata!(MultiLineStringArray::<i32>::try_from(array)?),
                 DataType::LargeList(_) => {
-                    Arc::new(MultiLineStringArray::<i64>::try_from(array).unwrap())
+                    with_metadata!(MultiLineStringArray::<i64>::try_from(array)?)
                 }
-                _ => panic!("Unexpected data type"),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.multipolygon" => match field.data_type() {
-                DataType::List(_) => Arc::new(MultiPolygonArray::<i32>::try_from(array).unwrap()),
+                DataType::List(_) => with_metadata!(MultiPolygonArray::<i32>::try_from(array)?),
                 DataType::LargeList(_) => {
-                    Arc::new(MultiPolygonArray::<i64>::try_from(array).unwrap())
+                    with_metadata!(MultiPolygonArray::<i64>::try_from(array)?)
                 }
-                _ => panic!("Unexpected data type"),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.geometry" => match field.data_type() {
                 DataType::Union(fields, _) => {
@@ -97,32 +172,34 @@ pub fn from_arrow_array(array: &dyn Array, field: &Field) -> Result<Arc<dyn Geom
 
                     if large_offsets.is_empty() {
                         // Only contains a point array, we can cast to i32
-                        Arc::new(MixedGeometryArray::<i32>::try_from(array).unwrap())
+                        with_metadata!(MixedGeometryArray::<i32>::try_from(array)?)
                     } else if large_offsets.iter().all(|x| *x) {
                         // All large offsets, cast to i64
-                        Arc::new(MixedGeometryArray::<i64>::try_from(array).unwrap())
+                        with_metadata!(MixedGeometryArray::<i64>::try_from(array)?)
                     } else if large_offsets.iter().all(|x| !x) {
                         // All small offsets, cast to i32
-                        Arc::new(MixedGeometryArray::<i32>::try_from(array).unwrap())
+                        with_metadata!(MixedGeometryArray::<i32>::try_from(array)?)
                     } else {
-                        panic!("Mix of offset types");
+                        return Err(GeoArrowError::General(
+                            "Mix of offset types in geometry union".to_string(),
+                        ));
                     }
                 }
-                _ => panic!("Unexpected data type"),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.geometrycollection" => match field.data_type() {
                 DataType::List(_) => {
-                    Arc::new(GeometryCollectionArray::<i32>::try_from(array).unwrap())
+                    with_metadata!(GeometryCollectionArray::<i32>::try_from(array)?)
                 }
                 DataType::LargeList(_) => {
-                    Arc::new(GeometryCollectionArray::<i64>::try_from(array).unwrap())
+                    with_metadata!(GeometryCollectionArray::<i64>::try_from(array)?)
                 }
-                _ => panic!("Unexpected data type"),
+                _ => return Err(unexpected_data_type()),
             },
             "geoarrow.wkb" | "ogc.wkb" => match field.data_type() {
-                DataType::Binary => Arc::new(WKBArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeBinary => Arc::new(WKBArray::<i64>::try_from(array).unwrap()),
-                _ => panic!("Unexpected data type"),
+                DataType::Binary => with_metadata!(WKBArray::<i32>::try_from(array)?),
+                DataType::LargeBinary => with_metadata!(WKBArray::<i64>::try_from(array)?),
+                _ => return Err(unexpected_data_type()),
             },
             _ => {
                 return Err(GeoArrowError::General(format!(
@@ -133,23 +210,103 @@ pub fn from_arrow_array(array: &dyn Array, field: &Field) -> Result<Arc<dyn Geom
         };
         Ok(geom_arr)
     } else {
-        // TODO: better error here, and document that arrays without geoarrow extension
-        // metadata should use TryFrom for a specific geometry type directly, instead of using
-        // GeometryArray
         match field.data_type() {
-            DataType::Binary => {
-                Ok(Arc::new(WKBArray::<i32>::try_from(array).unwrap()))
-            }
-            DataType::LargeBinary => {
-                Ok(Arc::new(WKBArray::<i64>::try_from(array).unwrap()))
-            }
-            DataType::Struct(_) => {
-                Ok(Arc::new(PointArray::try_from(array).unwrap()))
-            }
-            DataType::FixedSizeList(_, _) => {
-                Ok(Arc::new(PointArray::try_from(array).unwrap()))
-            }
+            DataType::Binary => Ok(Arc::new(WKBArray::<i32>::try_from(array)?)),
+            DataType::LargeBinary => Ok(Arc::new(WKBArray::<i64>::try_from(array)?)),
+            DataType::Struct(_) => Ok(Arc::new(PointArray::try_from(array)?)),
+            DataType::FixedSizeList(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
             _ => Err(GeoArrowError::General("Only Binary, LargeBinary, FixedSizeList, and Struct arrays are unambigously typed and can be used without extension metadata.".to_string()))
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipolygon, point};
+    use arrow_array::BinaryArray;
+    use std::collections::HashMap;
+
+    #[test]
+    fn dispatch_on_extension_name() {
+        let array = multipolygon::mp_array();
+        let field = array.extension_field();
+        let geom_arr = from_arrow_array(&array.clone().into_array_ref(), &field).unwrap();
+        assert_eq!(geom_arr.as_ref().as_multi_polygon(), &array);
+
+        let array = point::point_array();
+        let field = array.extension_field();
+        let geom_arr = from_arrow_array(&array.clone().into_array_ref(), &field).unwrap();
+        assert_eq!(geom_arr.as_ref().as_point(), &array);
+    }
+
+    #[test]
+    fn plain_binary_is_wkb() {
+        let wkb_array: WKBArray<i32> = (&point::point_array()).into();
+        let binary_array = wkb_array.into_array_ref();
+        let field = Field::new("geometry", DataType::Binary, true);
+        let geom_arr = from_arrow_array(&binary_array, &field).unwrap();
+        assert_eq!(geom_arr.as_ref().as_wkb().len(), 3);
+    }
+
+    #[test]
+    fn mismatched_types_error() {
+        let binary_array = BinaryArray::from(vec![&b"not a geometry"[..]]);
+        let field =
+            Field::new("geometry", DataType::Binary, true).with_metadata(HashMap::from([(
+                "ARROW:extension:name".to_string(),
+                "geoarrow.polygon".to_string(),
+            )]));
+        assert!(from_arrow_array(&binary_array, &field).is_err());
+
+        let field = Field::new("geometry", DataType::Int32, true);
+        assert!(from_arrow_array(&binary_array, &field).is_err());
+    }
+
+    #[test]
+    fn legacy_metadata_is_translated() {
+        let array = point::point_array();
+        let field =
+            Field::new("geometry", array.storage_type(), true).with_metadata(HashMap::from([
+                (
+                    "ARROW:extension:name".to_string(),
+                    "geoarrow.point".to_string(),
+                ),
+                (
+                    "ARROW:extension:metadata".to_string(),
+                    r#"{"crs": "{\"id\": {\"code\": 4326}}"}"#.to_string(),
+                ),
+            ]));
+        let geom_arr = from_arrow_array(&array.into_array_ref(), &field).unwrap();
+        let metadata = geom_arr.metadata();
+        assert_eq!(metadata.crs_type(), Some(metadata::CrsType::Projjson));
+
+        // Exported as 1.0 metadata
+        let field = geom_arr.extension_field();
+        assert_eq!(
+            field.metadata().get("ARROW:extension:metadata").unwrap(),
+            r#"{"crs":{"id":{"code":4326}}}"#
+        );
+    }
+
+    #[test]
+    fn child_field_names_are_translated() {
+        let vertices = Arc::new(Field::new("vertices", DataType::Float64, false));
+        let values = arrow_array::Float64Array::from(vec![1., 2., 3., 4.]);
+        let array = arrow_array::FixedSizeListArray::new(vertices, 2, Arc::new(values), None);
+        let field =
+            Field::new("geometry", array.data_type().clone(), true).with_metadata(HashMap::from([
+                (
+                    "ARROW:extension:name".to_string(),
+                    "geoarrow.point".to_string(),
+                ),
+            ]));
+
+        let geom_arr = from_arrow_array(&array, &field).unwrap();
+        assert_eq!(geom_arr.len(), 2);
+        assert_eq!(
+            geom_arr.extension_field().as_ref(),
+            &metadata::translate_field(&field).unwrap()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -6,6 +7,7 @@ use crate::array::metadata::ArrayMetadata;
 use crate::array::multilinestring::MultiLineStringCapacity;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::validation;
 use crate::array::{CoordBuffer, CoordType, LineStringArray, PolygonArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
@@ -30,7 +32,7 @@ pub struct MultiLineStringArray<O: OffsetSizeTrait> {
     // Always GeoDataType::MultiLineString or GeoDataType::LargeMultiLineString
     data_type: GeoDataType,
 
-    metadata: Arc<ArrayMetadata>,
+    pub(crate) metadata: Arc<ArrayMetadata>,
 
     pub(crate) coords: CoordBuffer,
 
@@ -134,6 +136,67 @@ impl<O: OffsetSizeTrait> MultiLineStringArray<O> {
         })
     }
 
+    /// Check the coordinates and offsets of this array.
+    ///
+    /// Construction only does `O(1)` checks of the buffer lengths. This additionally checks, for
+    /// every non-null geometry,
+    ///
+    /// - that its coordinates are finite
+    /// - that the geometry and line string offsets do not decrease
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(n)`.
+    ///
+    /// # Errors
+    ///
+    /// - with the index of the first geometry that fails any of the above checks
+    pub fn validate(&self) -> std::result::Result<(), GeoArrowError> {
+        validation::check_nested_geometries(
+            &self.coords,
+            &self.geom_offsets,
+            &self.ring_offsets,
+            false,
+            |i| self.is_valid(i),
+        )
+    }
+
+    /// Apply a [`ValidationPolicy`][validation::ValidationPolicy] to this array.
+    ///
+    /// See [`validate`][Self::validate] for the checks. With
+    /// [`ValidationPolicy::Fix`][validation::ValidationPolicy::Fix], geometries with non-finite coordinates are replaced with nulls.
+    pub fn with_validation(
+        self,
+        policy: validation::ValidationPolicy,
+    ) -> std::result::Result<Self, GeoArrowError> {
+        policy.apply(self, Self::validate, |array| {
+            validation::check_offsets(&array.geom_offsets, "Geometry")?;
+            validation::check_offsets(&array.ring_offsets, "Part")?;
+            let geoms: Vec<_> = array
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.filter(validation::is_finite))
+                .collect();
+            Ok(MultiLineStringBuilder::from_nullable_multi_line_strings(
+                &geoms,
+                Some(array.coord_type()),
+                array.metadata(),
+            )
+            .finish())
+        })
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        MultiLineStringBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = MultiLineStringBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("vertices", self.coords.storage_type(), false).into()
     }
@@ -422,7 +485,7 @@ impl TryFrom<&dyn Array> for MultiLineStringArray<i64> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> From<Vec<Option<G>>>
     for MultiLineStringArray<O>
 {
     fn from(other: Vec<Option<G>>) -> Self {
@@ -431,14 +494,34 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<&[G]> for MultiLineStringArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> FromIterator<G>
+    for MultiLineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: MultiLineStringBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> FromIterator<Option<G>>
+    for MultiLineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: MultiLineStringBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> From<&[G]>
+    for MultiLineStringArray<O>
+{
     fn from(other: &[G]) -> Self {
         let mut_arr: MultiLineStringBuilder<O> = other.into();
         mut_arr.into()
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>>
     From<bumpalo::collections::Vec<'_, Option<G>>> for MultiLineStringArray<O>
 {
     fn from(other: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
@@ -447,8 +530,8 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
-    for MultiLineStringArray<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for MultiLineStringArray<O>
 {
     fn from(other: bumpalo::collections::Vec<'_, G>) -> Self {
         let mut_arr: MultiLineStringBuilder<O> = other.into();
@@ -490,7 +573,7 @@ impl<O: OffsetSizeTrait> TryFrom<LineStringArray<O>> for MultiLineStringArray<O>
 
         // Create offsets that are all of length 1
         let mut geom_offsets = OffsetsBuilder::with_capacity(geom_length);
-        for _ in 0..coords.len() {
+        for _ in 0..geom_length {
             geom_offsets.try_push_usize(1)?;
         }
 
@@ -584,6 +667,17 @@ mod test {
         assert_eq!(arr.get_as_geo(2), None);
     }
 
+    #[test]
+    fn from_line_string_array() {
+        let line_strings = crate::test::linestring::ls_array();
+        let arr: MultiLineStringArray<i32> = line_strings.clone().try_into().unwrap();
+        assert_eq!(arr.len(), line_strings.len());
+        assert_eq!(
+            arr.value_as_geo(1),
+            geo::MultiLineString::new(vec![line_strings.value_as_geo(1)])
+        );
+    }
+
     #[test]
     fn slice() {
         let arr: MultiLineStringArray<i64> = vec![ml0(), ml1()].as_slice().into();
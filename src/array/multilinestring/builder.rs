@@ -1,7 +1,9 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multilinestring::MultiLineStringCapacity;
+use crate::array::util::{extend_validity, offsets_range};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -13,6 +15,7 @@ use crate::geo_traits::{GeometryTrait, GeometryType, LineStringTrait, MultiLineS
 use crate::io::wkb::reader::WKBMaybeMultiLineString;
 use crate::scalar::WKB;
 use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::{NullBufferBuilder, OffsetBuffer};
 
@@ -196,7 +199,7 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
     #[inline]
     pub fn push_line_string(
         &mut self,
-        value: Option<&impl LineStringTrait<T = f64>>,
+        value: Option<&impl LineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(line_string) = value {
             // Total number of linestrings in this multilinestring
@@ -231,7 +234,7 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
     #[inline]
     pub fn push_multi_line_string(
         &mut self,
-        value: Option<&impl MultiLineStringTrait<T = f64>>,
+        value: Option<&impl MultiLineStringTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(multi_line_string) = value {
             // Total number of linestrings in this multilinestring
@@ -262,7 +265,10 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(value) = value {
             match value.as_type() {
                 GeometryType::LineString(g) => self.push_line_string(Some(g))?,
@@ -275,9 +281,29 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
         Ok(())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The coordinates, offsets and validity of `array` are appended to the buffers of this
+    /// builder directly, without iterating over its geometries.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &MultiLineStringArray<O>) -> Result<()> {
+        let ring_range = offsets_range(&array.geom_offsets);
+        let ring_offsets = &array.ring_offsets[ring_range.start..=ring_range.end];
+        self.coords
+            .extend_from_buffer(&array.coords, offsets_range(ring_offsets));
+        self.geom_offsets
+            .try_extend_from_slice(&array.geom_offsets)?;
+        self.ring_offsets.try_extend_from_slice(ring_offsets)?;
+        extend_validity(&mut self.validity, array.validity.as_ref(), array.len());
+        Ok(())
+    }
+
     pub fn extend_from_iter<'a>(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl MultiLineStringTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl MultiLineStringTrait<T = impl CoordNum> + 'a)>>,
     ) {
         geoms
             .into_iter()
@@ -322,8 +348,9 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this builder.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         // NOTE! Only the geom_offsets array needs to get extended, because the next geometry will
         // point to the same ring array location
         self.geom_offsets.extend_constant(1);
@@ -331,7 +358,7 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
     }
 
     pub fn from_multi_line_strings(
-        geoms: &[impl MultiLineStringTrait<T = f64>],
+        geoms: &[impl MultiLineStringTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -345,7 +372,7 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
     }
 
     pub fn from_nullable_multi_line_strings(
-        geoms: &[Option<impl MultiLineStringTrait<T = f64>>],
+        geoms: &[Option<impl MultiLineStringTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -381,6 +408,13 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
     pub fn finish(self) -> MultiLineStringArray<O> {
         self.into()
     }
+
+    /// Finish the array and [validate][MultiLineStringArray::validate] its coordinates and offsets.
+    pub fn finish_validated(self) -> Result<MultiLineStringArray<O>> {
+        let array = self.finish();
+        array.validate()?;
+        Ok(array)
+    }
 }
 
 impl<O: OffsetSizeTrait> GeometryArrayBuilder for MultiLineStringBuilder<O> {
@@ -458,7 +492,7 @@ impl<O: OffsetSizeTrait> From<MultiLineStringBuilder<O>> for MultiLineStringArra
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<&[G]>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> From<&[G]>
     for MultiLineStringBuilder<O>
 {
     fn from(geoms: &[G]) -> Self {
@@ -466,7 +500,7 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<&[G]>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> From<Vec<Option<G>>>
     for MultiLineStringBuilder<O>
 {
     fn from(geoms: Vec<Option<G>>) -> Self {
@@ -474,15 +508,33 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> FromIterator<G>
+    for MultiLineStringBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>> FromIterator<Option<G>>
     for MultiLineStringBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for MultiLineStringBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         Self::from_multi_line_strings(&geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiLineStringTrait<T = T>>
     From<bumpalo::collections::Vec<'_, Option<G>>> for MultiLineStringBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
@@ -1,7 +1,9 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipoint::MultiPointCapacity;
+use crate::array::util::{extend_validity, offsets_range};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -13,6 +15,7 @@ use crate::geo_traits::{GeometryTrait, GeometryType, MultiPointTrait, PointTrait
 use crate::io::wkb::reader::WKBMaybeMultiPoint;
 use crate::scalar::WKB;
 use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::NullBufferBuilder;
 
@@ -166,9 +169,26 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
         Arc::new(self.into_arrow())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The coordinates, offsets and validity of `array` are appended to the buffers of this
+    /// builder directly, without iterating over its geometries.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &MultiPointArray<O>) -> Result<()> {
+        self.coords
+            .extend_from_buffer(&array.coords, offsets_range(&array.geom_offsets));
+        self.geom_offsets
+            .try_extend_from_slice(&array.geom_offsets)?;
+        extend_validity(&mut self.validity, array.validity.as_ref(), array.len());
+        Ok(())
+    }
+
     pub fn extend_from_iter<'a>(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl MultiPointTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl MultiPointTrait<T = impl CoordNum> + 'a)>>,
     ) {
         geoms
             .into_iter()
@@ -182,9 +202,10 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
     ///
     /// This function errors iff the new last item is larger than what O supports.
     #[inline]
-    pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) -> Result<()> {
+    pub fn push_point(&mut self, value: Option<&impl PointTrait<T = impl CoordNum>>) -> Result<()> {
         if let Some(point) = value {
-            self.coords.push_xy(point.x(), point.y());
+            self.coords
+                .push_xy(point.x().to_f64().unwrap(), point.y().to_f64().unwrap());
             self.try_push_length(1)?;
         } else {
             self.push_null();
@@ -201,12 +222,13 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
     #[inline]
     pub fn push_multi_point(
         &mut self,
-        value: Option<&impl MultiPointTrait<T = f64>>,
+        value: Option<&impl MultiPointTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(multi_point) = value {
             let num_points = multi_point.num_points();
             for point in multi_point.points() {
-                self.coords.push_xy(point.x(), point.y());
+                self.coords
+                    .push_xy(point.x().to_f64().unwrap(), point.y().to_f64().unwrap());
             }
             self.try_push_length(num_points)?;
         } else {
@@ -216,7 +238,10 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(value) = value {
             match value.as_type() {
                 GeometryType::Point(g) => self.push_point(Some(g))?,
@@ -266,14 +291,15 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this builder.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
         self.validity.append(false);
     }
 
     pub fn from_multi_points(
-        geoms: &[impl MultiPointTrait<T = f64>],
+        geoms: &[impl MultiPointTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -287,7 +313,7 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
     }
 
     pub fn from_nullable_multi_points(
-        geoms: &[Option<impl MultiPointTrait<T = f64>>],
+        geoms: &[Option<impl MultiPointTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -323,6 +349,13 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
     pub fn finish(self) -> MultiPointArray<O> {
         self.into()
     }
+
+    /// Finish the array and [validate][MultiPointArray::validate] its coordinates and offsets.
+    pub fn finish_validated(self) -> Result<MultiPointArray<O>> {
+        let array = self.finish();
+        array.validate()?;
+        Ok(array)
+    }
 }
 
 impl<O: OffsetSizeTrait> Default for MultiPointBuilder<O> {
@@ -350,7 +383,7 @@ impl<O: OffsetSizeTrait> GeometryArrayBuilder for MultiPointBuilder<O> {
     }
 
     fn len(&self) -> usize {
-        self.coords.len()
+        self.geom_offsets.len_proxy()
     }
 
     fn validity(&self) -> &NullBufferBuilder {
@@ -406,13 +439,15 @@ impl<O: OffsetSizeTrait> From<MultiPointBuilder<O>> for GenericListArray<O> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<&[G]> for MultiPointBuilder<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPointTrait<T = T>> From<&[G]>
+    for MultiPointBuilder<O>
+{
     fn from(geoms: &[G]) -> Self {
         Self::from_multi_points(geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPointTrait<T = T>> From<Vec<Option<G>>>
     for MultiPointBuilder<O>
 {
     fn from(geoms: Vec<Option<G>>) -> Self {
@@ -420,16 +455,34 @@ impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPointTrait<T = T>> FromIterator<G>
+    for MultiPointBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPointTrait<T = T>> FromIterator<Option<G>>
     for MultiPointBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPointTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for MultiPointBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         Self::from_multi_points(&geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>>
-    for MultiPointBuilder<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPointTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, Option<G>>> for MultiPointBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
         Self::from_nullable_multi_points(&geoms, Default::default(), Default::default())
@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -6,6 +7,7 @@ use crate::array::metadata::ArrayMetadata;
 use crate::array::multipolygon::MultiPolygonCapacity;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::validation;
 use crate::array::{CoordBuffer, CoordType, PolygonArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
@@ -31,7 +33,7 @@ pub struct MultiPolygonArray<O: OffsetSizeTrait> {
     // Always GeoDataType::MultiPolygon or GeoDataType::LargeMultiPolygon
     data_type: GeoDataType,
 
-    metadata: Arc<ArrayMetadata>,
+    pub(crate) metadata: Arc<ArrayMetadata>,
 
     pub(crate) coords: CoordBuffer,
 
@@ -158,6 +160,69 @@ impl<O: OffsetSizeTrait> MultiPolygonArray<O> {
         })
     }
 
+    /// Check the coordinates and offsets of this array.
+    ///
+    /// Construction only does `O(1)` checks of the buffer lengths. This additionally checks, for
+    /// every non-null geometry,
+    ///
+    /// - that its coordinates are finite
+    /// - that its rings are closed
+    /// - that the geometry, polygon and ring offsets do not decrease
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(n)`.
+    ///
+    /// # Errors
+    ///
+    /// - with the index of the first geometry that fails any of the above checks
+    pub fn validate(&self) -> std::result::Result<(), GeoArrowError> {
+        validation::check_multi_polygons(
+            &self.coords,
+            &self.geom_offsets,
+            &self.polygon_offsets,
+            &self.ring_offsets,
+            |i| self.is_valid(i),
+        )
+    }
+
+    /// Apply a [`ValidationPolicy`][validation::ValidationPolicy] to this array.
+    ///
+    /// See [`validate`][Self::validate] for the checks. With
+    /// [`ValidationPolicy::Fix`][validation::ValidationPolicy::Fix], unclosed rings are closed and geometries with non-finite coordinates are replaced with nulls.
+    pub fn with_validation(
+        self,
+        policy: validation::ValidationPolicy,
+    ) -> std::result::Result<Self, GeoArrowError> {
+        policy.apply(self, Self::validate, |array| {
+            validation::check_offsets(&array.geom_offsets, "Geometry")?;
+            validation::check_offsets(&array.polygon_offsets, "Polygon")?;
+            validation::check_offsets(&array.ring_offsets, "Ring")?;
+            let geoms: Vec<_> = array
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.filter(validation::is_finite))
+                .collect();
+            Ok(MultiPolygonBuilder::from_nullable_multi_polygons(
+                &geoms,
+                Some(array.coord_type()),
+                array.metadata(),
+            )
+            .finish())
+        })
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        MultiPolygonBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = MultiPolygonBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("vertices", self.coords.storage_type(), false).into()
     }
@@ -494,7 +559,7 @@ impl TryFrom<&dyn Array> for MultiPolygonArray<i64> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> From<Vec<Option<G>>>
     for MultiPolygonArray<O>
 {
     fn from(other: Vec<Option<G>>) -> Self {
@@ -503,14 +568,34 @@ impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<&[G]> for MultiPolygonArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> FromIterator<G>
+    for MultiPolygonArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: MultiPolygonBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> FromIterator<Option<G>>
+    for MultiPolygonArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: MultiPolygonBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> From<&[G]>
+    for MultiPolygonArray<O>
+{
     fn from(other: &[G]) -> Self {
         let mut_arr: MultiPolygonBuilder<O> = other.into();
         mut_arr.into()
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>>
     From<bumpalo::collections::Vec<'_, Option<G>>> for MultiPolygonArray<O>
 {
     fn from(other: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
@@ -519,8 +604,8 @@ impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
-    for MultiPolygonArray<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for MultiPolygonArray<O>
 {
     fn from(other: bumpalo::collections::Vec<'_, G>) -> Self {
         let mut_arr: MultiPolygonBuilder<O> = other.into();
@@ -550,7 +635,7 @@ impl<O: OffsetSizeTrait> TryFrom<PolygonArray<O>> for MultiPolygonArray<O> {
 
         // Create offsets that are all of length 1
         let mut geom_offsets = OffsetsBuilder::with_capacity(geom_length);
-        for _ in 0..coords.len() {
+        for _ in 0..geom_length {
             geom_offsets.try_push_usize(1)?;
         }
 
@@ -649,6 +734,17 @@ mod test {
         assert_eq!(arr.get_as_geo(2), None);
     }
 
+    #[test]
+    fn from_polygon_array() {
+        let polygons = crate::test::polygon::p_array();
+        let arr: MultiPolygonArray<i32> = polygons.clone().try_into().unwrap();
+        assert_eq!(arr.len(), polygons.len());
+        assert_eq!(
+            arr.value_as_geo(1),
+            geo::MultiPolygon::new(vec![polygons.value_as_geo(1)])
+        );
+    }
+
     #[test]
     fn slice() {
         let arr: MultiPolygonArray<i64> = vec![mp0(), mp1()].as_slice().into();
@@ -1,7 +1,9 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipolygon::MultiPolygonCapacity;
+use crate::array::util::{extend_validity, offsets_range};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -15,6 +17,7 @@ use crate::geo_traits::{
 use crate::io::wkb::reader::WKBMaybeMultiPolygon;
 use crate::scalar::WKB;
 use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::{NullBufferBuilder, OffsetBuffer};
 
@@ -209,7 +212,10 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
     ///
     /// This function errors iff the new last item is larger than what O supports.
     #[inline]
-    pub fn push_polygon(&mut self, value: Option<&impl PolygonTrait<T = f64>>) -> Result<()> {
+    pub fn push_polygon(
+        &mut self,
+        value: Option<&impl PolygonTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(polygon) = value {
             let exterior_ring = polygon.exterior();
             if exterior_ring.is_none() {
@@ -246,6 +252,8 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
                     self.coords.push_coord(&coord);
                 }
             }
+
+            self.validity.append(true);
         } else {
             self.push_null();
         };
@@ -260,7 +268,7 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
     #[inline]
     pub fn push_multi_polygon(
         &mut self,
-        value: Option<&impl MultiPolygonTrait<T = f64>>,
+        value: Option<&impl MultiPolygonTrait<T = impl CoordNum>>,
     ) -> Result<()> {
         if let Some(multi_polygon) = value {
             // Total number of polygons in this MultiPolygon
@@ -303,7 +311,10 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(value) = value {
             match value.as_type() {
                 GeometryType::Polygon(g) => self.push_polygon(Some(g))?,
@@ -317,9 +328,33 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
         Ok(())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The coordinates, offsets and validity of `array` are appended to the buffers of this
+    /// builder directly, without iterating over its geometries.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &MultiPolygonArray<O>) -> Result<()> {
+        let polygon_range = offsets_range(&array.geom_offsets);
+        let polygon_offsets = &array.polygon_offsets[polygon_range.start..=polygon_range.end];
+        let ring_range = offsets_range(polygon_offsets);
+        let ring_offsets = &array.ring_offsets[ring_range.start..=ring_range.end];
+        self.coords
+            .extend_from_buffer(&array.coords, offsets_range(ring_offsets));
+        self.geom_offsets
+            .try_extend_from_slice(&array.geom_offsets)?;
+        self.polygon_offsets
+            .try_extend_from_slice(polygon_offsets)?;
+        self.ring_offsets.try_extend_from_slice(ring_offsets)?;
+        extend_validity(&mut self.validity, array.validity.as_ref(), array.len());
+        Ok(())
+    }
+
     pub fn extend_from_iter<'a>(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl MultiPolygonTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl MultiPolygonTrait<T = impl CoordNum> + 'a)>>,
     ) {
         geoms
             .into_iter()
@@ -376,14 +411,16 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
         Ok(())
     }
 
+    /// Add a valid but empty multi polygon to the end of this builder.
     #[inline]
-    pub(crate) fn push_empty(&mut self) {
+    pub fn push_empty(&mut self) {
         self.geom_offsets.try_push_usize(0).unwrap();
         self.validity.append(true);
     }
 
+    /// Add a new null value to the end of this builder.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         // NOTE! Only the geom_offsets array needs to get extended, because the next geometry will
         // point to the same polygon array location
         // Note that we don't use self.try_push_geom_offset because that sets validity to true
@@ -392,7 +429,7 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
     }
 
     pub fn from_multi_polygons(
-        geoms: &[impl MultiPolygonTrait<T = f64>],
+        geoms: &[impl MultiPolygonTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -406,7 +443,7 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
     }
 
     pub fn from_nullable_multi_polygons(
-        geoms: &[Option<impl MultiPolygonTrait<T = f64>>],
+        geoms: &[Option<impl MultiPolygonTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -442,6 +479,13 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
     pub fn finish(self) -> MultiPolygonArray<O> {
         self.into()
     }
+
+    /// Finish the array and [validate][MultiPolygonArray::validate] its coordinates and offsets.
+    pub fn finish_validated(self) -> Result<MultiPolygonArray<O>> {
+        let array = self.finish();
+        array.validate()?;
+        Ok(array)
+    }
 }
 
 impl<O: OffsetSizeTrait> Default for MultiPolygonBuilder<O> {
@@ -526,13 +570,15 @@ impl<O: OffsetSizeTrait> From<MultiPolygonBuilder<O>> for MultiPolygonArray<O> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<&[G]> for MultiPolygonBuilder<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> From<&[G]>
+    for MultiPolygonBuilder<O>
+{
     fn from(geoms: &[G]) -> Self {
         Self::from_multi_polygons(geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<Vec<Option<G>>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> From<Vec<Option<G>>>
     for MultiPolygonBuilder<O>
 {
     fn from(geoms: Vec<Option<G>>) -> Self {
@@ -540,15 +586,33 @@ impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> FromIterator<G>
     for MultiPolygonBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>> FromIterator<Option<G>>
+    for MultiPolygonBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, G>> for MultiPolygonBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         Self::from_multi_polygons(&geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: MultiPolygonTrait<T = T>>
     From<bumpalo::collections::Vec<'_, Option<G>>> for MultiPolygonBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
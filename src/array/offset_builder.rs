@@ -271,6 +271,22 @@ impl<O: OffsetSizeTrait> OffsetsBuilder<O> {
         Ok(())
     }
 
+    /// Extends itself with the lengths of `offsets`, which must have at least one element.
+    ///
+    /// The first of `offsets` is aligned with the current last offset, so these can be the
+    /// (possibly sliced) offsets of another array whose values are appended to the same child.
+    /// # Errors
+    /// This function errors iff this operation overflows for the maximum value of `O`.
+    pub fn try_extend_from_slice(&mut self, offsets: &[O]) -> Result<(), Error> {
+        let first = offsets[0];
+        let last = *self.last();
+        let new_last = last.as_usize() + (offsets[offsets.len() - 1] - first).as_usize();
+        O::from_usize(new_last).ok_or(Error::Overflow)?;
+        self.0
+            .extend(offsets[1..].iter().map(|offset| *offset - first + last));
+        Ok(())
+    }
+
     /// Returns the inner [`Vec`].
     #[inline]
     pub fn into_inner(self) -> Vec<O> {
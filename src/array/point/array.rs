@@ -1,8 +1,10 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::algorithm::native::eq::coord_eq_allow_nan;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::validation;
 use crate::array::{
     CoordBuffer, CoordType, InterleavedCoordBuffer, PointBuilder, SeparatedCoordBuffer, WKBArray,
 };
@@ -15,7 +17,7 @@ use crate::util::owned_slice_validity;
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, ArrayRef, FixedSizeListArray, OffsetSizeTrait, StructArray};
 
-use arrow_buffer::NullBuffer;
+use arrow_buffer::{Buffer, NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 
 /// An immutable array of Point geometries using GeoArrow's in-memory representation.
@@ -43,6 +45,18 @@ pub(super) fn check(
     Ok(())
 }
 
+/// Reinterpret a byte buffer as `f64` values without copying.
+fn f64_buffer(buffer: Buffer) -> Result<ScalarBuffer<f64>, GeoArrowError> {
+    let size = std::mem::size_of::<f64>();
+    if buffer.len() % size != 0 || buffer.as_ptr().align_offset(std::mem::align_of::<f64>()) != 0 {
+        return Err(GeoArrowError::General(
+            "coordinate buffer must be aligned to and a multiple of the size of f64".to_string(),
+        ));
+    }
+    let len = buffer.len() / size;
+    Ok(ScalarBuffer::new(buffer, 0, len))
+}
+
 impl PointArray {
     /// Create a new PointArray from parts
     ///
@@ -85,6 +99,120 @@ impl PointArray {
         })
     }
 
+    /// Create a new PointArray from separated x and y buffers without copying.
+    ///
+    /// This is useful for wrapping coordinates owned by other Arrow producers, such as buffers
+    /// imported via FFI.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Errors
+    ///
+    /// - if a buffer is not aligned to or a multiple of the size of an `f64`
+    /// - if the x and y buffers have different lengths
+    /// - if the validity is not `None` and its length is different from the number of geometries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use arrow_buffer::Buffer;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::GeometryArrayTrait;
+    ///
+    /// let x = Buffer::from_vec(vec![1.0_f64, 2.0]);
+    /// let y = Buffer::from_vec(vec![3.0_f64, 4.0]);
+    /// let array = PointArray::try_from_separated(x, y, None, Default::default()).unwrap();
+    /// assert_eq!(array.len(), 2);
+    /// ```
+    pub fn try_from_separated(
+        x: Buffer,
+        y: Buffer,
+        validity: Option<NullBuffer>,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Result<Self, GeoArrowError> {
+        let coords = SeparatedCoordBuffer::try_new(f64_buffer(x)?, f64_buffer(y)?)?;
+        Self::try_new(CoordBuffer::Separated(coords), validity, metadata)
+    }
+
+    /// Create a new PointArray from an interleaved `xyxy` buffer without copying.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Errors
+    ///
+    /// - if the buffer is not aligned to or a multiple of the size of an `f64`
+    /// - if the buffer holds an odd number of values
+    /// - if the validity is not `None` and its length is different from the number of geometries
+    pub fn try_from_interleaved(
+        coords: Buffer,
+        validity: Option<NullBuffer>,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Result<Self, GeoArrowError> {
+        let coords = InterleavedCoordBuffer::try_new(f64_buffer(coords)?)?;
+        Self::try_new(CoordBuffer::Interleaved(coords), validity, metadata)
+    }
+
+    /// Check the coordinates and offsets of this array.
+    ///
+    /// Construction only does `O(1)` checks of the buffer lengths. This additionally checks, for
+    /// every non-null geometry,
+    ///
+    /// - that its coordinate is finite
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(n)`.
+    ///
+    /// # Errors
+    ///
+    /// - with the index of the first geometry that fails any of the above checks
+    pub fn validate(&self) -> std::result::Result<(), GeoArrowError> {
+        for geom_idx in (0..self.len()).filter(|i| self.is_valid(*i)) {
+            validation::check_coords(&self.coords, geom_idx, geom_idx, geom_idx + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a [`ValidationPolicy`][validation::ValidationPolicy] to this array.
+    ///
+    /// See [`validate`][Self::validate] for the checks. With
+    /// [`ValidationPolicy::Fix`][validation::ValidationPolicy::Fix], geometries with non-finite coordinates are replaced with nulls.
+    pub fn with_validation(
+        self,
+        policy: validation::ValidationPolicy,
+    ) -> std::result::Result<Self, GeoArrowError> {
+        policy.apply(self, Self::validate, |array| {
+            let geoms: Vec<_> = array
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.filter(validation::is_finite))
+                .collect();
+            Ok(PointBuilder::from_nullable_points(
+                geoms.iter().map(Option::as_ref),
+                Some(array.coord_type()),
+                array.metadata(),
+            )
+            .finish())
+        })
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        PointBuilder::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = PointBuilder::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     pub fn coords(&self) -> &CoordBuffer {
         &self.coords
     }
@@ -292,28 +420,44 @@ impl TryFrom<&dyn Array> for PointArray {
     }
 }
 
-impl<G: PointTrait<T = f64>> From<Vec<Option<G>>> for PointArray {
+impl<T: CoordNum, G: PointTrait<T = T>> From<Vec<Option<G>>> for PointArray {
     fn from(other: Vec<Option<G>>) -> Self {
         let mut_arr: PointBuilder = other.into();
         mut_arr.into()
     }
 }
 
-impl<G: PointTrait<T = f64>> From<&[G]> for PointArray {
+impl<T: CoordNum, G: PointTrait<T = T>> FromIterator<G> for PointArray {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: PointBuilder = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<T: CoordNum, G: PointTrait<T = T>> FromIterator<Option<G>> for PointArray {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: PointBuilder = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<T: CoordNum, G: PointTrait<T = T>> From<&[G]> for PointArray {
     fn from(other: &[G]) -> Self {
         let mut_arr: PointBuilder = other.into();
         mut_arr.into()
     }
 }
 
-impl<G: PointTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>> for PointArray {
+impl<T: CoordNum, G: PointTrait<T = T>> From<bumpalo::collections::Vec<'_, Option<G>>>
+    for PointArray
+{
     fn from(other: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
         let mut_arr: PointBuilder = other.into();
         mut_arr.into()
     }
 }
 
-impl<G: PointTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>> for PointArray {
+impl<T: CoordNum, G: PointTrait<T = T>> From<bumpalo::collections::Vec<'_, G>> for PointArray {
     fn from(other: bumpalo::collections::Vec<'_, G>) -> Self {
         let mut_arr: PointBuilder = other.into();
         mut_arr.into()
@@ -376,6 +520,29 @@ mod test {
     use super::*;
     use geo::Point;
 
+    #[test]
+    fn zero_copy_from_buffers() {
+        let x = Buffer::from_vec(vec![1.0_f64, 2.0]);
+        let y = Buffer::from_vec(vec![3.0_f64, 4.0]);
+        let x_ptr = x.as_ptr();
+        let arr = PointArray::try_from_separated(x, y, None, Default::default()).unwrap();
+        assert_eq!(arr.value_as_geo(1), Point::new(2., 4.));
+        match arr.coords() {
+            CoordBuffer::Separated(cb) => assert_eq!(cb.x.inner().as_ptr(), x_ptr),
+            _ => unreachable!(),
+        }
+
+        let xy = Buffer::from_vec(vec![1.0_f64, 3.0, 2.0, 4.0]);
+        let arr = PointArray::try_from_interleaved(xy, None, Default::default()).unwrap();
+        assert_eq!(arr.value_as_geo(1), Point::new(2., 4.));
+
+        // Odd number of values and misaligned buffers are rejected
+        let xy = Buffer::from_vec(vec![1.0_f64, 3.0, 2.0]);
+        assert!(PointArray::try_from_interleaved(xy, None, Default::default()).is_err());
+        let xy = Buffer::from_vec(vec![1.0_f64, 3.0, 2.0, 4.0]).slice(1);
+        assert!(PointArray::try_from_interleaved(xy, None, Default::default()).is_err());
+    }
+
     #[test]
     fn geo_roundtrip_accurate() {
         let arr: PointArray = vec![p0(), p1(), p2()].as_slice().into();
@@ -384,6 +551,19 @@ mod test {
         assert_eq!(arr.value_as_geo(2), p2());
     }
 
+    #[test]
+    fn from_other_coord_num() {
+        let arr: PointArray = vec![Point::new(1.5_f32, 2.5), Point::new(-3., 4.)]
+            .as_slice()
+            .into();
+        assert_eq!(arr.value_as_geo(0), Point::new(1.5, 2.5));
+        assert_eq!(arr.value_as_geo(1), Point::new(-3., 4.));
+
+        let arr: PointArray = vec![Some(Point::new(1_i32, 2)), None].into();
+        assert_eq!(arr.get_as_geo(0), Some(Point::new(1., 2.)));
+        assert_eq!(arr.get_as_geo(1), None);
+    }
+
     #[test]
     fn geo_roundtrip_accurate_option_vec() {
         let arr: PointArray = vec![Some(p0()), Some(p1()), Some(p2()), None].into();
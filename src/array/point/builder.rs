@@ -1,6 +1,8 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::extend_validity;
 // use super::array::check;
 use crate::array::{
     CoordBufferBuilder, CoordType, InterleavedCoordBufferBuilder, PointArray,
@@ -11,6 +13,7 @@ use crate::geo_traits::{GeometryTrait, GeometryType, MultiPointTrait, PointTrait
 use crate::io::wkb::reader::WKBPoint;
 use crate::scalar::WKB;
 use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::GeometryArrayTrait;
 use arrow_array::{Array, OffsetSizeTrait};
 use arrow_buffer::NullBufferBuilder;
 
@@ -116,9 +119,10 @@ impl PointBuilder {
 
     /// Add a new point to the end of this array.
     #[inline]
-    pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) {
+    pub fn push_point(&mut self, value: Option<&impl PointTrait<T = impl CoordNum>>) {
         if let Some(value) = value {
-            self.coords.push_xy(value.x(), value.y());
+            self.coords
+                .push_xy(value.x().to_f64().unwrap(), value.y().to_f64().unwrap());
             self.validity.append(true);
         } else {
             self.push_null()
@@ -140,7 +144,10 @@ impl PointBuilder {
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(value) = value {
             match value.as_type() {
                 GeometryType::Point(p) => self.push_point(Some(p)),
@@ -159,9 +166,19 @@ impl PointBuilder {
         Ok(())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The coordinates and validity of `array` are appended to the buffers of this builder
+    /// directly, without iterating over its geometries.
+    pub fn extend_from_array(&mut self, array: &PointArray) {
+        self.coords
+            .extend_from_buffer(&array.coords, 0..array.len());
+        extend_validity(&mut self.validity, array.validity.as_ref(), array.len());
+    }
+
     pub fn extend_from_iter<'a>(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl PointTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl PointTrait<T = impl CoordNum> + 'a)>>,
     ) {
         geoms
             .into_iter()
@@ -169,7 +186,7 @@ impl PointBuilder {
     }
 
     pub fn from_points<'a>(
-        geoms: impl ExactSizeIterator + Iterator<Item = &'a (impl PointTrait<T = f64> + 'a)>,
+        geoms: impl ExactSizeIterator + Iterator<Item = &'a (impl PointTrait<T = impl CoordNum> + 'a)>,
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -182,7 +199,8 @@ impl PointBuilder {
     }
 
     pub fn from_nullable_points<'a>(
-        geoms: impl ExactSizeIterator + Iterator<Item = Option<&'a (impl PointTrait<T = f64> + 'a)>>,
+        geoms: impl ExactSizeIterator
+            + Iterator<Item = Option<&'a (impl PointTrait<T = impl CoordNum> + 'a)>>,
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -217,6 +235,13 @@ impl PointBuilder {
     pub fn finish(self) -> PointArray {
         self.into()
     }
+
+    /// Finish the array and [validate][PointArray::validate] its coordinates and offsets.
+    pub fn finish_validated(self) -> Result<PointArray> {
+        let array = self.finish();
+        array.validate()?;
+        Ok(array)
+    }
 }
 
 impl GeometryArrayBuilder for PointBuilder {
@@ -289,13 +314,13 @@ impl From<PointBuilder> for Arc<dyn Array> {
     }
 }
 
-impl<G: PointTrait<T = f64>> From<&[G]> for PointBuilder {
+impl<T: CoordNum, G: PointTrait<T = T>> From<&[G]> for PointBuilder {
     fn from(value: &[G]) -> Self {
         PointBuilder::from_points(value.iter(), Default::default(), Default::default())
     }
 }
 
-impl<G: PointTrait<T = f64>> From<Vec<Option<G>>> for PointBuilder {
+impl<T: CoordNum, G: PointTrait<T = T>> From<Vec<Option<G>>> for PointBuilder {
     fn from(geoms: Vec<Option<G>>) -> Self {
         PointBuilder::from_nullable_points(
             geoms.iter().map(|x| x.as_ref()),
@@ -305,13 +330,29 @@ impl<G: PointTrait<T = f64>> From<Vec<Option<G>>> for PointBuilder {
     }
 }
 
-impl<G: PointTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>> for PointBuilder {
+impl<T: CoordNum, G: PointTrait<T = T>> FromIterator<G> for PointBuilder {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<T: CoordNum, G: PointTrait<T = T>> FromIterator<Option<G>> for PointBuilder {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<T: CoordNum, G: PointTrait<T = T>> From<bumpalo::collections::Vec<'_, G>> for PointBuilder {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         PointBuilder::from_points(geoms.iter(), Default::default(), Default::default())
     }
 }
 
-impl<G: PointTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>> for PointBuilder {
+impl<T: CoordNum, G: PointTrait<T = T>> From<bumpalo::collections::Vec<'_, Option<G>>>
+    for PointBuilder
+{
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
         PointBuilder::from_nullable_points(
             geoms.iter().map(|x| x.as_ref()),
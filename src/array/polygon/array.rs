@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -5,6 +6,7 @@ use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::polygon::PolygonCapacity;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::validation;
 use crate::array::{CoordBuffer, CoordType, MultiLineStringArray, RectArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
@@ -134,6 +136,68 @@ impl<O: OffsetSizeTrait> PolygonArray<O> {
         })
     }
 
+    /// Check the coordinates and offsets of this array.
+    ///
+    /// Construction only does `O(1)` checks of the buffer lengths. This additionally checks, for
+    /// every non-null geometry,
+    ///
+    /// - that its coordinates are finite
+    /// - that its rings are closed
+    /// - that the geometry and ring offsets do not decrease
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(n)`.
+    ///
+    /// # Errors
+    ///
+    /// - with the index of the first geometry that fails any of the above checks
+    pub fn validate(&self) -> std::result::Result<(), GeoArrowError> {
+        validation::check_nested_geometries(
+            &self.coords,
+            &self.geom_offsets,
+            &self.ring_offsets,
+            true,
+            |i| self.is_valid(i),
+        )
+    }
+
+    /// Apply a [`ValidationPolicy`][validation::ValidationPolicy] to this array.
+    ///
+    /// See [`validate`][Self::validate] for the checks. With
+    /// [`ValidationPolicy::Fix`][validation::ValidationPolicy::Fix], unclosed rings are closed and geometries with non-finite coordinates are replaced with nulls.
+    pub fn with_validation(
+        self,
+        policy: validation::ValidationPolicy,
+    ) -> std::result::Result<Self, GeoArrowError> {
+        policy.apply(self, Self::validate, |array| {
+            validation::check_offsets(&array.geom_offsets, "Geometry")?;
+            validation::check_offsets(&array.ring_offsets, "Ring")?;
+            let geoms: Vec<_> = array
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.filter(validation::is_finite))
+                .collect();
+            Ok(PolygonBuilder::from_nullable_polygons(
+                &geoms,
+                Some(array.coord_type()),
+                array.metadata(),
+            )
+            .finish())
+        })
+    }
+
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        PolygonBuilder::<O>::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = PolygonBuilder::<O>::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("vertices", self.coords.storage_type(), false).into()
     }
@@ -422,21 +486,39 @@ impl TryFrom<&dyn Array> for PolygonArray<i64> {
         }
     }
 }
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<Vec<Option<G>>> for PolygonArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> From<Vec<Option<G>>>
+    for PolygonArray<O>
+{
     fn from(other: Vec<Option<G>>) -> Self {
         let mut_arr: PolygonBuilder<O> = other.into();
         mut_arr.into()
     }
 }
 
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<&[G]> for PolygonArray<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> FromIterator<G> for PolygonArray<O> {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: PolygonBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> FromIterator<Option<G>>
+    for PolygonArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: PolygonBuilder<O> = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> From<&[G]> for PolygonArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: PolygonBuilder<O> = other.into();
         mut_arr.into()
     }
 }
 
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> From<bumpalo::collections::Vec<'_, G>>
     for PolygonArray<O>
 {
     fn from(value: bumpalo::collections::Vec<G>) -> Self {
@@ -445,8 +527,8 @@ impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<bumpalo::collections::Ve
     }
 }
 
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>>
-    for PolygonArray<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, Option<G>>> for PolygonArray<O>
 {
     fn from(value: bumpalo::collections::Vec<Option<G>>) -> Self {
         let mut_arr: PolygonBuilder<O> = value.into();
@@ -563,9 +645,21 @@ mod test {
         example_polygon_interleaved, example_polygon_separated, example_polygon_wkb,
     };
     use crate::test::polygon::{p0, p1};
+    use geo::{polygon, MapCoords};
 
     use super::*;
 
+    #[test]
+    fn new_empty_and_new_null() {
+        let arr = PolygonArray::<i32>::new_empty();
+        assert!(arr.is_empty());
+
+        let arr = PolygonArray::<i64>::new_null(3);
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.null_count(), 3);
+        assert!(arr.get(1).is_none());
+    }
+
     #[test]
     fn geo_roundtrip_accurate() {
         let arr: PolygonArray<i64> = vec![p0(), p1()].as_slice().into();
@@ -573,6 +667,20 @@ mod test {
         assert_eq!(arr.value_as_geo(1), p1());
     }
 
+    #[test]
+    fn from_f32_and_integer_coords() {
+        let f32_polygon = p0().map_coords(|c| geo::coord! { x: c.x as f32, y: c.y as f32 });
+        let arr: PolygonArray<i32> = vec![f32_polygon].as_slice().into();
+        assert_eq!(arr.value_as_geo(0), p0());
+
+        let int_polygon = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2)];
+        let arr: PolygonArray<i32> = vec![int_polygon].as_slice().into();
+        assert_eq!(
+            arr.value_as_geo(0),
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)]
+        );
+    }
+
     #[test]
     fn geo_roundtrip_accurate_option_vec() {
         let arr: PolygonArray<i64> = vec![Some(p0()), Some(p1()), None].into();
@@ -581,6 +689,26 @@ mod test {
         assert_eq!(arr.get_as_geo(2), None);
     }
 
+    #[test]
+    fn collect_from_wkb_scalars() {
+        let arr: PolygonArray<i64> = vec![Some(p0()), None, Some(p1())].into();
+        let wkb_arr = crate::io::wkb::to_wkb::<i32>(&arr);
+        let wkb_objects: Vec<_> = wkb_arr.iter().collect();
+
+        let collected: PolygonArray<i64> = wkb_objects
+            .iter()
+            .map(|maybe_wkb| {
+                maybe_wkb
+                    .as_ref()
+                    .map(|wkb| wkb.to_wkb_object().into_polygon())
+            })
+            .collect();
+        assert_eq!(collected, arr);
+
+        let collected: PolygonArray<i64> = [p0(), p1()].into_iter().collect();
+        assert_eq!(collected, vec![p0(), p1()].as_slice().into());
+    }
+
     #[test]
     fn slice() {
         let arr: PolygonArray<i64> = vec![p0(), p1()].as_slice().into();
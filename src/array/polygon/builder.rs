@@ -1,6 +1,8 @@
+use geo::CoordNum;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::{extend_validity, offsets_range};
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::polygon::PolygonCapacity;
@@ -16,6 +18,7 @@ use crate::geo_traits::{
 use crate::io::wkb::reader::WKBPolygon;
 use crate::scalar::WKB;
 use crate::trait_::{GeometryArrayAccessor, GeometryArrayBuilder, IntoArrow};
+use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::{NullBufferBuilder, OffsetBuffer};
 
@@ -195,7 +198,10 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
     ///
     /// This function errors iff the new last item is larger than what O supports.
     #[inline]
-    pub fn push_polygon(&mut self, value: Option<&impl PolygonTrait<T = f64>>) -> Result<()> {
+    pub fn push_polygon(
+        &mut self,
+        value: Option<&impl PolygonTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(polygon) = value {
             let exterior_ring = polygon.exterior();
             if exterior_ring.is_none() {
@@ -235,7 +241,7 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
     }
 
     #[inline]
-    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = f64>>) -> Result<()> {
+    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = impl CoordNum>>) -> Result<()> {
         if let Some(rect) = value {
             // Only one ring
             self.geom_offsets.try_push_usize(1)?;
@@ -248,11 +254,16 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
             // Ref below because I always forget the ordering
             // https://github.com/georust/geo/blob/76ad2a358bd079e9d47b1229af89608744d2635b/geo-types/src/geometry/rect.rs#L217-L225
 
-            self.coords.push_xy(lower.x(), lower.y());
-            self.coords.push_xy(lower.x(), upper.y());
-            self.coords.push_xy(upper.x(), upper.y());
-            self.coords.push_xy(upper.x(), lower.y());
-            self.coords.push_xy(lower.x(), lower.y());
+            self.coords
+                .push_xy(lower.x().to_f64().unwrap(), lower.y().to_f64().unwrap());
+            self.coords
+                .push_xy(lower.x().to_f64().unwrap(), upper.y().to_f64().unwrap());
+            self.coords
+                .push_xy(upper.x().to_f64().unwrap(), upper.y().to_f64().unwrap());
+            self.coords
+                .push_xy(upper.x().to_f64().unwrap(), lower.y().to_f64().unwrap());
+            self.coords
+                .push_xy(lower.x().to_f64().unwrap(), lower.y().to_f64().unwrap());
         } else {
             self.push_null();
         }
@@ -260,7 +271,10 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
     }
 
     #[inline]
-    pub fn push_geometry(&mut self, value: Option<&impl GeometryTrait<T = f64>>) -> Result<()> {
+    pub fn push_geometry(
+        &mut self,
+        value: Option<&impl GeometryTrait<T = impl CoordNum>>,
+    ) -> Result<()> {
         if let Some(value) = value {
             match value.as_type() {
                 GeometryType::Polygon(g) => self.push_polygon(Some(g))?,
@@ -280,9 +294,29 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
         Ok(())
     }
 
+    /// Add all geometries of `array` to the end of this builder.
+    ///
+    /// The coordinates, offsets and validity of `array` are appended to the buffers of this
+    /// builder directly, without iterating over its geometries.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    pub fn extend_from_array(&mut self, array: &PolygonArray<O>) -> Result<()> {
+        let ring_range = offsets_range(&array.geom_offsets);
+        let ring_offsets = &array.ring_offsets[ring_range.start..=ring_range.end];
+        self.coords
+            .extend_from_buffer(&array.coords, offsets_range(ring_offsets));
+        self.geom_offsets
+            .try_extend_from_slice(&array.geom_offsets)?;
+        self.ring_offsets.try_extend_from_slice(ring_offsets)?;
+        extend_validity(&mut self.validity, array.validity.as_ref(), array.len());
+        Ok(())
+    }
+
     pub fn extend_from_iter<'a>(
         &mut self,
-        geoms: impl Iterator<Item = Option<&'a (impl PolygonTrait<T = f64> + 'a)>>,
+        geoms: impl Iterator<Item = Option<&'a (impl PolygonTrait<T = impl CoordNum> + 'a)>>,
     ) {
         geoms
             .into_iter()
@@ -327,14 +361,16 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
         Ok(())
     }
 
+    /// Add a valid but empty polygon to the end of this builder.
     #[inline]
-    pub(crate) fn push_empty(&mut self) {
+    pub fn push_empty(&mut self) {
         self.geom_offsets.try_push_usize(0).unwrap();
         self.validity.append(true);
     }
 
+    /// Add a new null value to the end of this builder.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         // NOTE! Only the geom_offsets array needs to get extended, because the next geometry will
         // point to the same ring array location
         self.geom_offsets.extend_constant(1);
@@ -342,7 +378,7 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
     }
 
     pub fn from_polygons(
-        geoms: &[impl PolygonTrait<T = f64>],
+        geoms: &[impl PolygonTrait<T = impl CoordNum>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -356,7 +392,7 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
     }
 
     pub fn from_nullable_polygons(
-        geoms: &[Option<impl PolygonTrait<T = f64>>],
+        geoms: &[Option<impl PolygonTrait<T = impl CoordNum>>],
         coord_type: Option<CoordType>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -392,6 +428,13 @@ impl<O: OffsetSizeTrait> PolygonBuilder<O> {
     pub fn finish(self) -> PolygonArray<O> {
         self.into()
     }
+
+    /// Finish the array and [validate][PolygonArray::validate] its coordinates and offsets.
+    pub fn finish_validated(self) -> Result<PolygonArray<O>> {
+        let array = self.finish();
+        array.validate()?;
+        Ok(array)
+    }
 }
 
 impl<O: OffsetSizeTrait> Default for PolygonBuilder<O> {
@@ -469,27 +512,47 @@ impl<O: OffsetSizeTrait> From<PolygonBuilder<O>> for PolygonArray<O> {
     }
 }
 
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<&[G]> for PolygonBuilder<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> From<&[G]> for PolygonBuilder<O> {
     fn from(geoms: &[G]) -> Self {
         Self::from_polygons(geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<Vec<Option<G>>> for PolygonBuilder<O> {
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> From<Vec<Option<G>>>
+    for PolygonBuilder<O>
+{
     fn from(geoms: Vec<Option<G>>) -> Self {
         Self::from_nullable_polygons(&geoms, Default::default(), Default::default())
     }
 }
 
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> FromIterator<G>
+    for PolygonBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> FromIterator<Option<G>>
+    for PolygonBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
+
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>> From<bumpalo::collections::Vec<'_, G>>
     for PolygonBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         Self::from_polygons(&geoms, Default::default(), Default::default())
     }
 }
-impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, Option<G>>>
-    for PolygonBuilder<O>
+impl<O: OffsetSizeTrait, T: CoordNum, G: PolygonTrait<T = T>>
+    From<bumpalo::collections::Vec<'_, Option<G>>> for PolygonBuilder<O>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<G>>) -> Self {
         Self::from_nullable_polygons(&geoms, Default::default(), Default::default())
@@ -1,3 +1,4 @@
+use geo::CoordNum;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -31,7 +32,7 @@ pub struct RectArray {
 
     /// A Buffer of float values for the bounding rectangles
     /// Invariant: the length of values must always be a multiple of 4
-    values: ScalarBuffer<f64>,
+    pub(crate) values: ScalarBuffer<f64>,
     validity: Option<NullBuffer>,
 }
 
@@ -49,6 +50,18 @@ impl RectArray {
         }
     }
 
+    /// Create a new empty array with no geometries.
+    pub fn new_empty() -> Self {
+        RectBuilder::default().into()
+    }
+
+    /// Create a new array of `length` null geometries.
+    pub fn new_null(length: usize) -> Self {
+        let mut builder = RectBuilder::default();
+        (0..length).for_each(|_| builder.push_null());
+        builder.into()
+    }
+
     fn inner_field(&self) -> Arc<Field> {
         Field::new("rect", DataType::Float64, false).into()
     }
@@ -176,16 +189,30 @@ impl IntoArrow for RectArray {
     }
 }
 
-impl<G: RectTrait<T = f64>> From<&[G]> for RectArray {
+impl<T: CoordNum, G: RectTrait<T = T>> From<&[G]> for RectArray {
     fn from(other: &[G]) -> Self {
         let mut_arr: RectBuilder = other.into();
         mut_arr.into()
     }
 }
 
-impl<G: RectTrait<T = f64>> From<Vec<Option<G>>> for RectArray {
+impl<T: CoordNum, G: RectTrait<T = T>> From<Vec<Option<G>>> for RectArray {
     fn from(other: Vec<Option<G>>) -> Self {
         let mut_arr: RectBuilder = other.into();
         mut_arr.into()
     }
 }
+
+impl<T: CoordNum, G: RectTrait<T = T>> FromIterator<G> for RectArray {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let mut_arr: RectBuilder = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
+
+impl<T: CoordNum, G: RectTrait<T = T>> FromIterator<Option<G>> for RectArray {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut_arr: RectBuilder = iter.into_iter().collect();
+        mut_arr.into()
+    }
+}
@@ -6,6 +6,7 @@ use crate::scalar::Rect;
 use crate::trait_::IntoArrow;
 use arrow_array::{Array, FixedSizeListArray};
 use arrow_buffer::NullBufferBuilder;
+use geo::CoordNum;
 use std::sync::Arc;
 
 /// The GeoArrow equivalent to `Vec<Option<Rect>>`: a mutable collection of Rects.
@@ -95,15 +96,15 @@ impl RectBuilder {
 
     /// Add a new Rect to the end of this builder.
     #[inline]
-    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = f64>>) {
+    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = impl CoordNum>>) {
         if let Some(value) = value {
             let min_coord = value.lower();
             let max_coord = value.upper();
 
-            self.values.push(min_coord.x());
-            self.values.push(min_coord.y());
-            self.values.push(max_coord.x());
-            self.values.push(max_coord.y());
+            self.values.push(min_coord.x().to_f64().unwrap());
+            self.values.push(min_coord.y().to_f64().unwrap());
+            self.values.push(max_coord.x().to_f64().unwrap());
+            self.values.push(max_coord.y().to_f64().unwrap());
             self.validity.append_non_null()
         } else {
             // Since it's a fixed size list, we still need to push coords when null
@@ -127,7 +128,7 @@ impl RectBuilder {
 
     /// Create this builder from a iterator of Rects.
     pub fn from_rects<'a>(
-        geoms: impl ExactSizeIterator + Iterator<Item = &'a (impl RectTrait<T = f64> + 'a)>,
+        geoms: impl ExactSizeIterator + Iterator<Item = &'a (impl RectTrait<T = impl CoordNum> + 'a)>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
         let mut mutable_array = Self::with_capacity(geoms.len(), metadata);
@@ -139,7 +140,8 @@ impl RectBuilder {
 
     /// Create this builder from a iterator of nullable Rects.
     pub fn from_nullable_rects<'a>(
-        geoms: impl ExactSizeIterator + Iterator<Item = Option<&'a (impl RectTrait<T = f64> + 'a)>>,
+        geoms: impl ExactSizeIterator
+            + Iterator<Item = Option<&'a (impl RectTrait<T = impl CoordNum> + 'a)>>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
         let mut mutable_array = Self::with_capacity(geoms.len(), metadata);
@@ -175,14 +177,28 @@ impl From<RectBuilder> for RectArray {
     }
 }
 
-impl<G: RectTrait<T = f64>> From<&[G]> for RectBuilder {
+impl<T: CoordNum, G: RectTrait<T = T>> From<&[G]> for RectBuilder {
     fn from(geoms: &[G]) -> Self {
         RectBuilder::from_rects(geoms.iter(), Default::default())
     }
 }
 
-impl<G: RectTrait<T = f64>> From<Vec<Option<G>>> for RectBuilder {
+impl<T: CoordNum, G: RectTrait<T = T>> From<Vec<Option<G>>> for RectBuilder {
     fn from(geoms: Vec<Option<G>>) -> Self {
         RectBuilder::from_nullable_rects(geoms.iter().map(|x| x.as_ref()), Default::default())
     }
 }
+
+impl<T: CoordNum, G: RectTrait<T = T>> FromIterator<G> for RectBuilder {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        let geoms: Vec<G> = iter.into_iter().collect();
+        geoms.as_slice().into()
+    }
+}
+
+impl<T: CoordNum, G: RectTrait<T = T>> FromIterator<Option<G>> for RectBuilder {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let geoms: Vec<Option<G>> = iter.into_iter().collect();
+        geoms.into()
+    }
+}
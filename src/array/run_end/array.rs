@@ -0,0 +1,531 @@
+use std::sync::Arc;
+
+use arrow::compute::take as arrow_take;
+use arrow_array::types::{ArrowDictionaryKeyType, RunEndIndexType, UInt64Type};
+use arrow_array::{Array, ArrayRef, BooleanArray, PrimitiveArray, RunArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{DataType, Field};
+
+use crate::algorithm::native::unique::unique_indices;
+use crate::algorithm::native::Take;
+use crate::array::from_arrow_array;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArraySelfMethods;
+use crate::GeometryArrayTrait;
+
+/// A run-end encoded geometry array.
+///
+/// This stores one geometry per _run_ of identical consecutive geometries in `values`, plus a
+/// `run_ends` array with the (exclusive) logical index at which each run ends. This keeps memory
+/// proportional to the number of runs when the same geometry is repeated many times in a row,
+/// e.g. after joining a table to administrative boundaries and sorting by the join key, or when
+/// assigning features to tiles.
+///
+/// Algorithms implemented for the run-end encoded array are computed once per run and then
+/// expanded to the length of the array. [`Area`][crate::algorithm::geo::Area],
+/// [`BoundingRect`][crate::algorithm::geo::BoundingRect],
+/// [`Centroid`][crate::algorithm::geo::Centroid] and
+/// [`EuclideanLength`][crate::algorithm::geo::EuclideanLength] are implemented directly. Any other
+/// kernel can be applied once per run with [`map_values`][Self::map_values] if it returns a
+/// geometry array, or by applying it to [`values`][Self::values] and expanding the result with
+/// [`take_values`][Self::take_values]. [`take`][Self::take] and [`filter`][Self::filter] keep the
+/// output run-end encoded.
+///
+/// This is **not** an array type defined by the GeoArrow specification, and so it does not
+/// implement [`GeometryArrayTrait`]. It is exported to Arrow as a [`RunArray`] with geometry
+/// values, and can be imported without decoding with [`try_from_arrow`][Self::try_from_arrow].
+/// [`from_arrow_array`][crate::array::from_arrow_array] instead decodes run-end encoded arrays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunEndEncodedGeometryArray<R: RunEndIndexType, G: GeometryArrayTrait> {
+    run_ends: PrimitiveArray<R>,
+    values: G,
+}
+
+impl<R: RunEndIndexType, G: GeometryArrayTrait> RunEndEncodedGeometryArray<R, G> {
+    /// Create a new run-end encoded array from run ends and values.
+    ///
+    /// # Panics
+    ///
+    /// - if the run ends are not valid, see [`try_new`][Self::try_new]
+    pub fn new(run_ends: PrimitiveArray<R>, values: G) -> Self {
+        Self::try_new(run_ends, values).unwrap()
+    }
+
+    /// Create a new run-end encoded array from run ends and values.
+    ///
+    /// # Errors
+    ///
+    /// - if the run ends contain nulls
+    /// - if the run ends are not positive and strictly increasing
+    /// - if the number of run ends is not the same as the number of values
+    pub fn try_new(run_ends: PrimitiveArray<R>, values: G) -> Result<Self> {
+        if run_ends.null_count() > 0 {
+            return Err(GeoArrowError::General(
+                "Run ends must not contain nulls".to_string(),
+            ));
+        }
+        if run_ends.len() != values.len() {
+            return Err(GeoArrowError::General(format!(
+                "Expected one run end per value, got {} run ends for {} values",
+                run_ends.len(),
+                values.len()
+            )));
+        }
+
+        let mut previous_end = 0;
+        for run_end in run_ends.values().iter() {
+            match run_end.to_usize() {
+                Some(run_end) if run_end > previous_end => previous_end = run_end,
+                _ => {
+                    return Err(GeoArrowError::General(format!(
+                        "Run ends must be positive and strictly increasing, got {run_end:?} after {previous_end}"
+                    )))
+                }
+            }
+        }
+
+        Ok(Self { run_ends, values })
+    }
+
+    /// The (exclusive) logical end index of each run.
+    pub fn run_ends(&self) -> &PrimitiveArray<R> {
+        &self.run_ends
+    }
+
+    /// The geometry of each run.
+    pub fn values(&self) -> &G {
+        &self.values
+    }
+
+    /// Consume self and return the run ends and values.
+    pub fn into_inner(self) -> (PrimitiveArray<R>, G) {
+        (self.run_ends, self.values)
+    }
+
+    /// The number of geometries in this array.
+    pub fn len(&self) -> usize {
+        self.run_ends
+            .values()
+            .last()
+            .map_or(0, |run_end| run_end.as_usize())
+    }
+
+    /// Returns `true` if the array contains no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.run_ends.is_empty()
+    }
+
+    /// The index into [`values`][Self::values] of the geometry at logical index `i`.
+    ///
+    /// # Errors
+    ///
+    /// - if `i` is out of bounds
+    pub fn physical_index(&self, i: usize) -> Result<usize> {
+        if i >= self.len() {
+            return Err(GeoArrowError::General(format!(
+                "Index {i} is out of bounds for an array of length {}",
+                self.len()
+            )));
+        }
+        Ok(self.run_index(i))
+    }
+
+    /// The index of the run containing logical index `i`, or the number of runs if `i` is out of
+    /// bounds.
+    fn run_index(&self, i: usize) -> usize {
+        self.run_ends
+            .values()
+            .partition_point(|run_end| run_end.as_usize() <= i)
+    }
+
+    /// Returns `true` if the geometry at index `i` is null.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn is_null(&self, i: usize) -> bool {
+        self.values.is_null(self.run_index(i))
+    }
+
+    /// Apply a function to the values of this array, keeping the same run ends.
+    ///
+    /// # Panics
+    ///
+    /// - if the returned array does not have the same length as the values
+    pub fn map_values<H: GeometryArrayTrait>(
+        &self,
+        map_op: impl FnOnce(&G) -> H,
+    ) -> RunEndEncodedGeometryArray<R, H> {
+        let values = map_op(&self.values);
+        assert_eq!(values.len(), self.values.len());
+        RunEndEncodedGeometryArray {
+            run_ends: self.run_ends.clone(),
+            values,
+        }
+    }
+
+    /// Expand an array with one element per run, e.g. the output of an algorithm applied to
+    /// [`values`][Self::values], to one element per geometry of this array.
+    pub fn take_values<A: Array + Clone + 'static>(&self, values: &A) -> Result<A> {
+        let taken = arrow_take(values, &self.physical_indices(), None)?;
+        Ok(taken.as_any().downcast_ref::<A>().unwrap().clone())
+    }
+
+    /// The index into [`values`][Self::values] of every geometry of this array.
+    fn physical_indices(&self) -> PrimitiveArray<UInt64Type> {
+        let mut run_start = 0;
+        let mut indices = Vec::with_capacity(self.len());
+        for (physical_index, run_end) in self.run_ends.values().iter().enumerate() {
+            let run_end = run_end.as_usize();
+            indices.extend(std::iter::repeat(physical_index as u64).take(run_end - run_start));
+            run_start = run_end;
+        }
+        PrimitiveArray::from_iter_values(indices)
+    }
+
+    /// The Arrow [`DataType`] of this array.
+    pub fn storage_type(&self) -> DataType {
+        DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", R::DATA_TYPE, false)),
+            Arc::new(Field::new("values", self.values.storage_type(), true)),
+        )
+    }
+
+    /// A [`Field`] for this array, with the extension metadata of the values.
+    pub fn extension_field(&self) -> Arc<Field> {
+        let values_field = self.values.extension_field();
+        Arc::new(
+            Field::new(values_field.name(), self.storage_type(), true)
+                .with_metadata(values_field.metadata().clone()),
+        )
+    }
+
+    /// Convert this array to an Arrow [`RunArray`].
+    pub fn to_array_ref(&self) -> ArrayRef {
+        Arc::new(RunArray::try_new(&self.run_ends, &self.values.to_array_ref()).unwrap())
+    }
+}
+
+impl<R: RunEndIndexType, G: GeometryArrayTrait + Clone + 'static> RunEndEncodedGeometryArray<R, G> {
+    /// Run-end encode a geometry array.
+    ///
+    /// Consecutive geometries are merged into one run if they are exactly equal, by their type,
+    /// structure and coordinates. Consecutive null geometries are merged into one null run.
+    ///
+    /// # Errors
+    ///
+    /// - if the length of the array does not fit in the run end type
+    pub fn try_encode(array: &G) -> Result<Self> {
+        let (indices, _) = unique_indices(array.as_ref());
+        Self::from_physical_runs(
+            array,
+            indices
+                .iter()
+                .enumerate()
+                .map(|(i, maybe_index)| (maybe_index.map(|_| i), 1)),
+            |a, b| indices[a] == indices[b],
+        )
+    }
+
+    /// Import an Arrow [`RunArray`] without decoding it.
+    ///
+    /// `field` describes the run array, as produced by
+    /// [`extension_field`][Self::extension_field].
+    ///
+    /// # Errors
+    ///
+    /// - if `field` is not a run-end encoded field with run ends of type `R`
+    /// - if the values are not a geometry array of type `G`
+    pub fn try_from_arrow(array: &RunArray<R>, field: &Field) -> Result<Self>
+    where
+        G: GeometryArraySelfMethods,
+    {
+        let DataType::RunEndEncoded(run_ends_field, values_field) = field.data_type() else {
+            return Err(GeoArrowError::General(format!(
+                "Expected a run-end encoded field, got {:?}",
+                field.data_type()
+            )));
+        };
+        if run_ends_field.data_type() != &R::DATA_TYPE {
+            return Err(GeoArrowError::General(format!(
+                "Expected run ends of type {:?}, got {:?}",
+                R::DATA_TYPE,
+                run_ends_field.data_type()
+            )));
+        }
+
+        // A sliced run array shares the buffers of its parent, so only the runs overlapping the
+        // slice are kept and their ends are rebased to the slice
+        let (offset, len) = (array.offset(), array.len());
+        let physical_range = if len == 0 {
+            0..0
+        } else {
+            array.get_start_physical_index()..array.get_end_physical_index() + 1
+        };
+        let run_ends = array.run_ends().values()[physical_range.clone()]
+            .iter()
+            .map(|run_end| R::Native::usize_as((run_end.as_usize() - offset).min(len)))
+            .collect::<Vec<_>>();
+
+        let values_field = Field::new(field.name(), values_field.data_type().clone(), true)
+            .with_metadata(field.metadata().clone());
+        let values = from_arrow_array(array.values(), &values_field)?;
+        let values = values
+            .as_any()
+            .downcast_ref::<G>()
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Unexpected geometry type {:?} of run-end encoded values",
+                    values.data_type()
+                ))
+            })?
+            .slice(physical_range.start, physical_range.len());
+        Self::try_new(PrimitiveArray::from_iter_values(run_ends), values)
+    }
+
+    /// Decode this array into a geometry array with one geometry per element.
+    pub fn decode(&self) -> Result<G> {
+        let decoded = self.values.as_ref().take(&self.physical_indices())?;
+        Ok(decoded.as_any().downcast_ref::<G>().unwrap().clone())
+    }
+
+    /// Take the geometries at `indices`, keeping the output run-end encoded.
+    ///
+    /// A null index produces a null geometry in the output.
+    ///
+    /// # Errors
+    ///
+    /// - if any index is negative or out of bounds
+    pub fn take<I: ArrowDictionaryKeyType>(&self, indices: &PrimitiveArray<I>) -> Result<Self> {
+        let physical_indices = indices
+            .iter()
+            .map(|maybe_index| {
+                let physical_index = maybe_index
+                    .map(|index| match index.to_usize() {
+                        Some(index) => self.physical_index(index),
+                        None => Err(GeoArrowError::General(format!(
+                            "Index {index:?} is out of bounds for an array of length {}",
+                            self.len()
+                        ))),
+                    })
+                    .transpose()?;
+                Ok((physical_index, 1))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_physical_runs(&self.values, physical_indices.into_iter(), |a, b| a == b)
+    }
+
+    /// Keep the geometries where `predicate` is `true`, keeping the output run-end encoded.
+    ///
+    /// Null values in `predicate` are treated as `false`.
+    ///
+    /// # Errors
+    ///
+    /// - if `predicate` does not have the same length as this array
+    pub fn filter(&self, predicate: &BooleanArray) -> Result<Self> {
+        if predicate.len() != self.len() {
+            return Err(GeoArrowError::General(format!(
+                "Filter predicate of length {} does not match array of length {}",
+                predicate.len(),
+                self.len()
+            )));
+        }
+
+        let mut run_start = 0;
+        let mut runs = Vec::with_capacity(self.run_ends.len());
+        for (physical_index, run_end) in self.run_ends.values().iter().enumerate() {
+            let run_end = run_end.as_usize();
+            let selected = (run_start..run_end)
+                .filter(|i| predicate.is_valid(*i) && predicate.value(*i))
+                .count();
+            if selected > 0 {
+                runs.push((Some(physical_index), selected));
+            }
+            run_start = run_end;
+        }
+        Self::from_physical_runs(&self.values, runs.into_iter(), |a, b| a == b)
+    }
+
+    /// Build an array from `(index into values, length)` runs, merging consecutive runs for
+    /// which `same_run` returns `true`. A `None` index produces a null run.
+    fn from_physical_runs(
+        values: &G,
+        runs: impl Iterator<Item = (Option<usize>, usize)>,
+        same_run: impl Fn(usize, usize) -> bool,
+    ) -> Result<Self> {
+        let mut run_values: Vec<Option<usize>> = vec![];
+        let mut run_ends: Vec<usize> = vec![];
+        let mut len = 0;
+        for (maybe_index, run_len) in runs {
+            len += run_len;
+            let extends_previous = match (run_values.last(), maybe_index) {
+                (Some(None), None) => true,
+                (Some(Some(previous)), Some(index)) => same_run(*previous, index),
+                _ => false,
+            };
+            if extends_previous {
+                *run_ends.last_mut().unwrap() = len;
+            } else {
+                run_values.push(maybe_index);
+                run_ends.push(len);
+            }
+        }
+
+        let run_ends = run_ends
+            .into_iter()
+            .map(|run_end| R::Native::from_usize(run_end).ok_or(GeoArrowError::Overflow))
+            .collect::<Result<Vec<_>>>()?;
+        let run_values = PrimitiveArray::<UInt64Type>::from_iter(
+            run_values
+                .into_iter()
+                .map(|maybe_index| maybe_index.map(|index| index as u64)),
+        );
+        let values = values.as_ref().take(&run_values)?;
+        let values = values.as_any().downcast_ref::<G>().unwrap().clone();
+        Ok(Self {
+            run_ends: PrimitiveArray::from_iter_values(run_ends),
+            values,
+        })
+    }
+}
+
+/// Expand an Arrow [`RunArray`] into a plain array of its values type.
+pub(crate) fn decode_run_array<R: RunEndIndexType>(array: &RunArray<R>) -> Result<ArrayRef> {
+    let logical_indices = (0..array.len() as u64).collect::<Vec<_>>();
+    let physical_indices = array.get_physical_indices(&logical_indices)?;
+    let physical_indices = PrimitiveArray::<UInt64Type>::from_iter_values(
+        physical_indices.into_iter().map(|i| i as u64),
+    );
+    Ok(arrow_take(array.values(), &physical_indices, None)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{from_arrow_array, AsGeometryArray, PolygonArray};
+    use crate::test::polygon::{p0, p1};
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::types::{Int16Type, Int32Type};
+    use arrow_array::{Int16Array, Int32Array};
+
+    fn polygons() -> PolygonArray<i32> {
+        vec![Some(p0()), Some(p0()), None, None, Some(p1()), Some(p0())]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let array = polygons();
+        let ree = RunEndEncodedGeometryArray::<Int32Type, _>::try_encode(&array).unwrap();
+        assert_eq!(ree.values().len(), 4);
+        assert_eq!(ree.run_ends(), &Int32Array::from(vec![2, 4, 5, 6]));
+        assert_eq!(ree.len(), 6);
+        assert!(ree.is_null(3));
+        assert_eq!(ree.physical_index(4).unwrap(), 2);
+        assert!(ree.physical_index(6).is_err());
+
+        let decoded = ree.decode().unwrap();
+        assert_eq!(
+            decoded.iter_geo().collect::<Vec<_>>(),
+            array.iter_geo().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn invalid_run_ends() {
+        let array: PolygonArray<i32> = vec![p0(), p1()].as_slice().into();
+        assert!(
+            RunEndEncodedGeometryArray::try_new(Int32Array::from(vec![2, 2]), array.clone())
+                .is_err()
+        );
+        assert!(RunEndEncodedGeometryArray::try_new(Int32Array::from(vec![2]), array).is_err());
+    }
+
+    #[test]
+    fn take_and_filter_stay_encoded() {
+        let ree = RunEndEncodedGeometryArray::<Int16Type, _>::try_encode(&polygons()).unwrap();
+
+        let taken = ree
+            .take(&Int32Array::from(vec![
+                Some(0),
+                Some(1),
+                None,
+                Some(2),
+                Some(5),
+            ]))
+            .unwrap();
+        // A null index and a null value are separate runs
+        assert_eq!(taken.run_ends(), &Int16Array::from(vec![2, 3, 4, 5]));
+        assert_eq!(
+            taken.decode().unwrap().iter_geo().collect::<Vec<_>>(),
+            vec![Some(p0()), Some(p0()), None, None, Some(p0())]
+        );
+        assert!(ree.take(&Int32Array::from(vec![6])).is_err());
+        assert!(ree.take(&Int32Array::from(vec![-1])).is_err());
+
+        let predicate = BooleanArray::from(vec![true, true, false, false, false, true]);
+        let filtered = ree.filter(&predicate).unwrap();
+        // The two runs of p0 are now adjacent, but are only merged by `try_encode`
+        assert_eq!(filtered.run_ends(), &Int16Array::from(vec![2, 3]));
+        assert_eq!(filtered.len(), 3);
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        let array = polygons();
+        let ree = RunEndEncodedGeometryArray::<Int32Type, _>::try_encode(&array).unwrap();
+        let arrow_array = ree.to_array_ref();
+        assert_eq!(arrow_array.len(), 6);
+        assert_eq!(arrow_array.data_type(), &ree.storage_type());
+
+        let geom_arr = from_arrow_array(&arrow_array, &ree.extension_field()).unwrap();
+        assert_eq!(
+            geom_arr
+                .as_ref()
+                .as_polygon()
+                .iter_geo()
+                .collect::<Vec<_>>(),
+            array.iter_geo().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn import_without_decoding() {
+        let ree = RunEndEncodedGeometryArray::<Int32Type, _>::try_encode(&polygons()).unwrap();
+        let field = ree.extension_field();
+        let arrow_array = ree.to_array_ref();
+        let run_array = arrow_array
+            .as_any()
+            .downcast_ref::<RunArray<Int32Type>>()
+            .unwrap();
+
+        let imported = RunEndEncodedGeometryArray::<Int32Type, PolygonArray<i32>>::try_from_arrow(
+            run_array, &field,
+        )
+        .unwrap();
+        assert_eq!(imported.run_ends(), ree.run_ends());
+        assert_eq!(imported.values().len(), ree.values().len());
+
+        // Slicing keeps only the overlapping runs
+        let sliced = run_array.slice(1, 4);
+        let imported = RunEndEncodedGeometryArray::<Int32Type, PolygonArray<i32>>::try_from_arrow(
+            sliced.as_any().downcast_ref().unwrap(),
+            &field,
+        )
+        .unwrap();
+        assert_eq!(imported.run_ends(), &Int32Array::from(vec![1, 3, 4]));
+        assert_eq!(
+            imported.decode().unwrap().iter_geo().collect::<Vec<_>>(),
+            vec![Some(p0()), None, None, Some(p1())]
+        );
+
+        let field = Field::new("geometry", DataType::Int32, true);
+        assert!(
+            RunEndEncodedGeometryArray::<Int32Type, PolygonArray<i32>>::try_from_arrow(
+                run_array, &field,
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub use array::RunEndEncodedGeometryArray;
+
+pub(crate) use array::decode_run_array;
+
+mod array;
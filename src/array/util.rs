@@ -1,9 +1,11 @@
 //! Note: This entire mod is a candidate to upstream into arrow-rs.
 
+use std::ops::Range;
+
 use arrow_array::OffsetSizeTrait;
-use arrow_buffer::OffsetBuffer;
+use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer};
 
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 
 pub(crate) fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
     let i64_offsets = offsets.iter().map(|x| *x as i64).collect::<Vec<_>>();
@@ -11,9 +13,7 @@ pub(crate) fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBu
 }
 
 pub(crate) fn offsets_buffer_i64_to_i32(offsets: &OffsetBuffer<i64>) -> Result<OffsetBuffer<i32>> {
-    // TODO: raise nicer error. Ref:
-    // https://github.com/jorgecarleitao/arrow2/blob/6a4b53169a48cbd234cecde6ab6a98f84146fca2/src/offset.rs#L492
-    i32::try_from(*offsets.last()).unwrap();
+    i32::try_from(*offsets.last()).map_err(|_| GeoArrowError::Overflow)?;
 
     let i32_offsets = offsets.iter().map(|x| *x as i32).collect::<Vec<_>>();
     Ok(unsafe { OffsetBuffer::new_unchecked(i32_offsets.into()) })
@@ -29,7 +29,28 @@ pub(crate) fn offset_lengths<O: OffsetSizeTrait>(
         .map(|w| (w[1] - w[0]).to_usize().unwrap())
 }
 
-/// Offsets utils that I miss from arrow2
+/// Returns the range of child values, such as rings or coordinates, spanned by `offsets`.
+///
+/// This is `0..last` unless the offsets have been sliced.
+#[inline]
+pub(crate) fn offsets_range<O: OffsetSizeTrait>(offsets: &[O]) -> Range<usize> {
+    offsets[0].as_usize()..offsets[offsets.len() - 1].as_usize()
+}
+
+/// Append the validity of `len` values to `builder`, where `nulls` is `None` if all of them are
+/// valid.
+pub(crate) fn extend_validity(
+    builder: &mut NullBufferBuilder,
+    nulls: Option<&NullBuffer>,
+    len: usize,
+) {
+    match nulls {
+        Some(nulls) => nulls.iter().for_each(|valid| builder.append(valid)),
+        None => builder.append_n_non_nulls(len),
+    }
+}
+
+/// Offsets utilities not provided by [`OffsetBuffer`]
 pub(crate) trait OffsetBufferUtils<O: OffsetSizeTrait> {
     /// Returns the length an array with these offsets would be.
     fn len_proxy(&self) -> usize;
@@ -69,3 +90,18 @@ impl<O: OffsetSizeTrait> OffsetBufferUtils<O> for OffsetBuffer<O> {
         self.as_ref().last().unwrap()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn i64_offsets_overflow() {
+        let offsets = OffsetBuffer::new(vec![0, i32::MAX as i64 + 1].into());
+        assert!(offsets_buffer_i64_to_i32(&offsets).is_err());
+
+        let offsets = OffsetBuffer::new(vec![0, 2, 5].into());
+        let offsets = offsets_buffer_i64_to_i32(&offsets).unwrap();
+        assert_eq!(offsets.as_ref(), &[0, 2, 5]);
+    }
+}
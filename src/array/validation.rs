@@ -0,0 +1,352 @@
+//! `O(n)` checks of the coordinates and offsets of geometry arrays, beyond the `O(1)` checks
+//! done on construction.
+
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::OffsetBuffer;
+use geo::CoordsIter;
+
+use crate::array::util::OffsetBufferUtils;
+use crate::array::{AsGeometryArray, CoordBuffer};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// How to handle invalid geometries when constructing arrays, parsing WKB or reading files.
+///
+/// Geometries are invalid if they have non-finite coordinates or unclosed rings, see e.g.
+/// [`PolygonArray::validate`][crate::array::PolygonArray::validate].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ValidationPolicy {
+    /// Don't check coordinates. This is the fastest.
+    #[default]
+    Skip,
+
+    /// Repair invalid geometries: unclosed rings are closed, and geometries with non-finite
+    /// coordinates are replaced with nulls. Offsets that decrease can't be repaired and are still
+    /// an error.
+    Fix,
+
+    /// Return an error for the first invalid geometry.
+    Error,
+}
+
+impl ValidationPolicy {
+    /// Apply this policy to `array`, given functions to validate and to repair it.
+    pub(crate) fn apply<A>(
+        self,
+        array: A,
+        validate: impl Fn(&A) -> Result<()>,
+        fix: impl FnOnce(&A) -> Result<A>,
+    ) -> Result<A> {
+        match self {
+            ValidationPolicy::Skip => Ok(array),
+            ValidationPolicy::Error => {
+                validate(&array)?;
+                Ok(array)
+            }
+            ValidationPolicy::Fix => match validate(&array) {
+                Ok(()) => Ok(array),
+                Err(_) => fix(&array),
+            },
+        }
+    }
+}
+
+/// Apply a [`ValidationPolicy`] to any geometry array.
+///
+/// Arrays without coordinate validation, i.e. WKB and rect arrays, are returned unchanged.
+pub(crate) fn apply_policy(
+    array: Arc<dyn GeometryArrayTrait>,
+    policy: ValidationPolicy,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    if policy == ValidationPolicy::Skip {
+        return Ok(array);
+    }
+
+    macro_rules! validate {
+        ($as_type:ident) => {
+            Arc::new(array.as_ref().$as_type().clone().with_validation(policy)?)
+        };
+    }
+
+    use GeoDataType::*;
+    let validated: Arc<dyn GeometryArrayTrait> = match *array.data_type() {
+        Point(_) => validate!(as_point),
+        LineString(_) => validate!(as_line_string),
+        LargeLineString(_) => validate!(as_large_line_string),
+        Polygon(_) => validate!(as_polygon),
+        LargePolygon(_) => validate!(as_large_polygon),
+        MultiPoint(_) => validate!(as_multi_point),
+        LargeMultiPoint(_) => validate!(as_large_multi_point),
+        MultiLineString(_) => validate!(as_multi_line_string),
+        LargeMultiLineString(_) => validate!(as_large_multi_line_string),
+        MultiPolygon(_) => validate!(as_multi_polygon),
+        LargeMultiPolygon(_) => validate!(as_large_multi_polygon),
+        Mixed(_) => validate!(as_mixed),
+        LargeMixed(_) => validate!(as_large_mixed),
+        GeometryCollection(_) => validate!(as_geometry_collection),
+        LargeGeometryCollection(_) => validate!(as_large_geometry_collection),
+        WKB | LargeWKB | Rect => array,
+    };
+    Ok(validated)
+}
+
+/// Whether all coordinates of a geo geometry are finite.
+pub(crate) fn is_finite<G: CoordsIter<Scalar = f64>>(geom: &G) -> bool {
+    geom.coords_iter()
+        .all(|coord| coord.x.is_finite() && coord.y.is_finite())
+}
+
+/// Check that `offsets` never decrease.
+pub(crate) fn check_offsets<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    name: &str,
+) -> Result<()> {
+    if let Some(i) = offsets.windows(2).position(|w| w[0] > w[1]) {
+        return Err(GeoArrowError::General(format!(
+            "{name} offsets decrease from {:?} to {:?} at index {}",
+            offsets[i],
+            offsets[i + 1],
+            i + 1
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the coordinates `start..end`, belonging to the geometry at `geom_idx`, are finite.
+pub(crate) fn check_coords(
+    coords: &CoordBuffer,
+    geom_idx: usize,
+    start: usize,
+    end: usize,
+) -> Result<()> {
+    for coord_idx in start..end {
+        let (x, y) = (coords.get_x(coord_idx), coords.get_y(coord_idx));
+        if !x.is_finite() || !y.is_finite() {
+            return Err(GeoArrowError::General(format!(
+                "Geometry {geom_idx} has a non-finite coordinate ({x}, {y}) at coordinate index {coord_idx}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check that the coordinates `start..end` of ring `ring_idx` of the geometry at `geom_idx` are
+/// finite and that the ring is closed. Empty rings are allowed.
+pub(crate) fn check_ring(
+    coords: &CoordBuffer,
+    geom_idx: usize,
+    ring_idx: usize,
+    start: usize,
+    end: usize,
+) -> Result<()> {
+    check_coords(coords, geom_idx, start, end)?;
+    if end > start
+        && (coords.get_x(start) != coords.get_x(end - 1)
+            || coords.get_y(start) != coords.get_y(end - 1))
+    {
+        return Err(GeoArrowError::General(format!(
+            "Geometry {geom_idx} has an unclosed ring at ring index {ring_idx}"
+        )));
+    }
+    Ok(())
+}
+
+/// Check the coordinates of each geometry of an array with one level of offsets, e.g. a
+/// `LineStringArray` or `MultiPointArray`.
+pub(crate) fn check_geometries<O: OffsetSizeTrait>(
+    coords: &CoordBuffer,
+    geom_offsets: &OffsetBuffer<O>,
+    is_valid: impl Fn(usize) -> bool,
+) -> Result<()> {
+    check_offsets(geom_offsets, "Geometry")?;
+    for geom_idx in (0..geom_offsets.len_proxy()).filter(|i| is_valid(*i)) {
+        let (start, end) = geom_offsets.start_end(geom_idx);
+        check_coords(coords, geom_idx, start, end)?;
+    }
+    Ok(())
+}
+
+/// Check the coordinates of each geometry of an array with two levels of offsets, e.g. a
+/// `PolygonArray` or `MultiLineStringArray`. If `rings` is `true`, each part must be closed.
+pub(crate) fn check_nested_geometries<O: OffsetSizeTrait>(
+    coords: &CoordBuffer,
+    geom_offsets: &OffsetBuffer<O>,
+    part_offsets: &OffsetBuffer<O>,
+    rings: bool,
+    is_valid: impl Fn(usize) -> bool,
+) -> Result<()> {
+    check_offsets(geom_offsets, "Geometry")?;
+    check_offsets(part_offsets, if rings { "Ring" } else { "Part" })?;
+    for geom_idx in (0..geom_offsets.len_proxy()).filter(|i| is_valid(*i)) {
+        let (start_part, end_part) = geom_offsets.start_end(geom_idx);
+        for part_idx in start_part..end_part {
+            let (start, end) = part_offsets.start_end(part_idx);
+            if rings {
+                check_ring(coords, geom_idx, part_idx - start_part, start, end)?;
+            } else {
+                check_coords(coords, geom_idx, start, end)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check the coordinates of each geometry of a `MultiPolygonArray`, and that each ring is closed.
+pub(crate) fn check_multi_polygons<O: OffsetSizeTrait>(
+    coords: &CoordBuffer,
+    geom_offsets: &OffsetBuffer<O>,
+    polygon_offsets: &OffsetBuffer<O>,
+    ring_offsets: &OffsetBuffer<O>,
+    is_valid: impl Fn(usize) -> bool,
+) -> Result<()> {
+    check_offsets(geom_offsets, "Geometry")?;
+    check_offsets(polygon_offsets, "Polygon")?;
+    check_offsets(ring_offsets, "Ring")?;
+    for geom_idx in (0..geom_offsets.len_proxy()).filter(|i| is_valid(*i)) {
+        let (start_polygon, end_polygon) = geom_offsets.start_end(geom_idx);
+        for polygon_idx in start_polygon..end_polygon {
+            let (start_ring, end_ring) = polygon_offsets.start_end(polygon_idx);
+            for ring_idx in start_ring..end_ring {
+                let (start, end) = ring_offsets.start_end(ring_idx);
+                check_ring(coords, geom_idx, ring_idx - start_ring, start, end)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{
+        LineStringArray, LineStringBuilder, MultiPolygonArray, PointArray, PolygonArray,
+        SeparatedCoordBuffer,
+    };
+    use crate::test::{linestring, multipolygon, point, polygon};
+    use crate::GeometryArrayTrait;
+    use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+    use geo::{line_string, point, polygon, MultiPolygon};
+
+    #[test]
+    fn valid_arrays() {
+        point::point_array().validate().unwrap();
+        linestring::ls_array().validate().unwrap();
+        polygon::p_array().validate().unwrap();
+        multipolygon::mp_array().validate().unwrap();
+    }
+
+    #[test]
+    fn non_finite_coords() {
+        let array: PointArray = vec![point!(x: 0., y: 1.), point!(x: f64::NAN, y: 1.)]
+            .as_slice()
+            .into();
+        let err = array.validate().unwrap_err().to_string();
+        assert!(err.contains("Geometry 1"), "{err}");
+
+        // Null geometries are not checked
+        let mut builder = LineStringBuilder::<i32>::new();
+        builder
+            .push_line_string(Some(&line_string![(x: 0., y: 0.), (x: 1., y: 1.)]))
+            .unwrap();
+        builder.push_null();
+        builder
+            .push_line_string(Some(
+                &line_string![(x: 0., y: f64::INFINITY), (x: 1., y: 1.)],
+            ))
+            .unwrap();
+        let err = builder.finish_validated().unwrap_err().to_string();
+        assert!(err.contains("Geometry 2"), "{err}");
+    }
+
+    #[test]
+    fn unclosed_rings() {
+        // A valid polygon followed by a polygon whose only ring is not closed
+        let coords = SeparatedCoordBuffer::new(
+            vec![0., 1., 1., 0., 0., 1., 1.].into(),
+            vec![0., 0., 1., 0., 0., 0., 1.].into(),
+        );
+        let array = PolygonArray::<i32>::new(
+            coords.into(),
+            OffsetBuffer::new(vec![0, 1, 2].into()),
+            OffsetBuffer::new(vec![0, 4, 7].into()),
+            None,
+            Default::default(),
+        );
+        let err = array.validate().unwrap_err().to_string();
+        assert!(err.contains("Geometry 1 has an unclosed ring"), "{err}");
+
+        // polygon! closes its rings
+        let array: MultiPolygonArray<i32> = vec![MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+        ])]
+        .as_slice()
+        .into();
+        array.validate().unwrap();
+    }
+
+    #[test]
+    fn decreasing_offsets() {
+        let array = linestring::ls_array();
+        let num_coords = array.coords.len() as i32;
+        // Safety: the offsets are deliberately not monotonic, which `validate` must catch
+        let offsets = unsafe {
+            OffsetBuffer::new_unchecked(ScalarBuffer::from(vec![0, num_coords, 1, num_coords]))
+        };
+        let array = LineStringArray::new(array.coords.clone(), offsets, None, Default::default());
+        let err = array.validate().unwrap_err().to_string();
+        assert!(err.contains("offsets decrease"), "{err}");
+    }
+
+    #[test]
+    fn validation_policies() {
+        use crate::array::ValidationPolicy;
+        use crate::trait_::GeometryArrayAccessor;
+
+        let coords = SeparatedCoordBuffer::new(
+            vec![0., 1., 1., 0., f64::NAN, 1., 1.].into(),
+            vec![0., 0., 1., 0., 0., 0., 1.].into(),
+        );
+        // A valid polygon, and a polygon with an unclosed ring and a NaN coordinate
+        let array = PolygonArray::<i32>::new(
+            coords.into(),
+            OffsetBuffer::new(vec![0, 1, 2].into()),
+            OffsetBuffer::new(vec![0, 4, 7].into()),
+            None,
+            Default::default(),
+        );
+
+        let skipped = array
+            .clone()
+            .with_validation(ValidationPolicy::Skip)
+            .unwrap();
+        assert_eq!(skipped.len(), 2);
+        assert!(array
+            .clone()
+            .with_validation(ValidationPolicy::Error)
+            .is_err());
+
+        let fixed = array.with_validation(ValidationPolicy::Fix).unwrap();
+        assert_eq!(
+            fixed.iter_geo().collect::<Vec<_>>(),
+            vec![
+                Some(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]),
+                None
+            ]
+        );
+
+        // Unclosed rings are closed
+        let coords = SeparatedCoordBuffer::new(vec![0., 1., 1.].into(), vec![0., 0., 1.].into());
+        let array = PolygonArray::<i32>::new(
+            coords.into(),
+            OffsetBuffer::new(vec![0, 1].into()),
+            OffsetBuffer::new(vec![0, 3].into()),
+            None,
+            Default::default(),
+        );
+        let fixed = array.with_validation(ValidationPolicy::Fix).unwrap();
+        fixed.validate().unwrap();
+        assert_eq!(fixed.coords.len(), 4);
+    }
+}
@@ -9,9 +9,12 @@ use arrow_schema::{DataType, Field};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+use crate::algorithm::native::rechunk::{plan_rechunk, rows_for_bytes};
+use crate::algorithm::native::Concatenate;
 use crate::array::*;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::trait_::{GeometryArrayRef, GeometryArraySelfMethods};
 use crate::GeometryArrayTrait;
 
 /// A collection of Arrow arrays of the same type.
@@ -148,7 +151,25 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
         self.chunks.first().unwrap().data_type()
     }
 
-    pub(crate) fn map<F: Fn(&G) -> R + Sync + Send, R: Send>(&self, map_op: F) -> Vec<R> {
+    /// Apply `map_op` to each chunk of this chunked array, collecting the results.
+    ///
+    /// If the `rayon` feature is active, chunks are processed in parallel on the global rayon
+    /// thread pool. The output preserves the chunk order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::chunked_array::ChunkedGeometryArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::point;
+    ///
+    /// let chunk: PointArray = vec![point!(x: 1., y: 2.)].as_slice().into();
+    /// let chunked = ChunkedGeometryArray::new(vec![chunk.clone(), chunk]);
+    /// let lengths = chunked.par_map(|chunk| chunk.len());
+    /// assert_eq!(lengths, vec![1, 1]);
+    /// ```
+    pub fn par_map<F: Fn(&G) -> R + Sync + Send, R: Send>(&self, map_op: F) -> Vec<R> {
         #[cfg(feature = "rayon")]
         {
             let mut output_vec = Vec::with_capacity(self.chunks.len());
@@ -165,7 +186,11 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
         }
     }
 
-    pub(crate) fn try_map<F: Fn(&G) -> Result<R> + Sync + Send, R: Send>(
+    /// Apply the fallible `map_op` to each chunk of this chunked array, collecting the results.
+    ///
+    /// If the `rayon` feature is active, chunks are processed in parallel on the global rayon
+    /// thread pool. The output preserves the chunk order.
+    pub fn try_par_map<F: Fn(&G) -> Result<R> + Sync + Send, R: Send>(
         &self,
         map_op: F,
     ) -> Result<Vec<R>> {
@@ -179,6 +204,77 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
             self.chunks.iter().map(map_op).collect()
         }
     }
+
+    pub(crate) fn map<F: Fn(&G) -> R + Sync + Send, R: Send>(&self, map_op: F) -> Vec<R> {
+        self.par_map(map_op)
+    }
+
+    pub(crate) fn try_map<F: Fn(&G) -> Result<R> + Sync + Send, R: Send>(
+        &self,
+        map_op: F,
+    ) -> Result<Vec<R>> {
+        self.try_par_map(map_op)
+    }
+}
+
+impl<G: GeometryArrayTrait + GeometryArraySelfMethods> ChunkedGeometryArray<G>
+where
+    for<'a> &'a [G]: Concatenate<Output = Result<G>>,
+{
+    /// Rechunk this array into chunks of `target_rows` geometries each; the last chunk may be
+    /// shorter.
+    ///
+    /// Small chunks are concatenated together and large chunks are split with zero-copy slices.
+    ///
+    /// # Errors
+    ///
+    /// - if `target_rows` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::chunked_array::ChunkedGeometryArray;
+    /// use geoarrow::GeometryArrayTrait;
+    /// use geo::point;
+    ///
+    /// let chunk: PointArray = vec![point!(x: 1., y: 2.)].as_slice().into();
+    /// let chunked = ChunkedGeometryArray::new(vec![chunk.clone(), chunk.clone(), chunk]);
+    /// let rechunked = chunked.rechunk(2).unwrap();
+    /// let lengths: Vec<_> = rechunked.chunks().iter().map(|chunk| chunk.len()).collect();
+    /// assert_eq!(lengths, vec![2, 1]);
+    /// ```
+    pub fn rechunk(&self, target_rows: usize) -> Result<Self> {
+        let chunk_lengths: Vec<_> = self.chunks.iter().map(|chunk| chunk.len()).collect();
+        let chunks = plan_rechunk(&chunk_lengths, target_rows)?
+            .into_iter()
+            .map(|slices| {
+                let mut parts: Vec<G> = slices
+                    .into_iter()
+                    .map(|(chunk_idx, offset, length)| self.chunks[chunk_idx].slice(offset, length))
+                    .collect();
+                if parts.len() == 1 {
+                    Ok(parts.pop().unwrap())
+                } else {
+                    parts.as_slice().concatenate()
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(chunks))
+    }
+
+    /// Rechunk this array into chunks of about `target_bytes` each.
+    ///
+    /// The size of a row is estimated from the memory used by the existing chunks, so output
+    /// chunks are only approximately of the target size.
+    pub fn rechunk_by_bytes(&self, target_bytes: usize) -> Result<Self> {
+        let total_bytes = self
+            .chunks
+            .iter()
+            .map(|chunk| chunk.to_array_ref().get_array_memory_size())
+            .sum();
+        self.rechunk(rows_for_bytes(total_bytes, self.len(), target_bytes))
+    }
 }
 
 impl<G: GeometryArrayTrait> TryFrom<Vec<G>> for ChunkedGeometryArray<G> {
@@ -202,6 +298,12 @@ pub type ChunkedRectArray = ChunkedGeometryArray<RectArray>;
 #[allow(dead_code)]
 pub type ChunkedUnknownGeometryArray = ChunkedGeometryArray<Arc<dyn GeometryArrayTrait>>;
 
+/// A reference-counted, dynamically-typed chunked geometry array.
+///
+/// Use [`AsChunkedGeometryArray`][crate::array::AsChunkedGeometryArray] to downcast it to a
+/// concrete chunked array.
+pub type ChunkedGeometryArrayRef = Arc<dyn ChunkedGeometryArrayTrait>;
+
 /// A trait implemented by all chunked geometry arrays.
 ///
 /// This trait is often used for downcasting. For example, the [`from_geoarrow_chunks`] function
@@ -634,7 +736,64 @@ pub fn from_geoarrow_chunks(
                 )),
             };
         Ok(chunked_arr)
+    } else if data_types.is_empty() {
+        Err(GeoArrowError::General(
+            "Cannot infer the type of a chunked array without chunks".to_string(),
+        ))
     } else {
-        todo!()
+        Err(GeoArrowError::General(format!(
+            "Chunks must share a single data type, found {:?}",
+            data_types
+        )))
+    }
+}
+
+/// Create a typed chunked array from dynamically-typed chunks, such as those produced when
+/// reading a file batch by batch.
+///
+/// The chunks must all hold the same geometry type with the same offset width and coordinate
+/// type. The output is the corresponding typed chunked array, such as a
+/// [`ChunkedPolygonArray`], which can be downcast with
+/// [`AsChunkedGeometryArray`][crate::array::AsChunkedGeometryArray].
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use geoarrow::array::{AsChunkedGeometryArray, PointArray};
+/// use geoarrow::chunked_array::from_geometry_array_chunks;
+/// use geoarrow::trait_::GeometryArrayRef;
+/// use geo::point;
+///
+/// let chunk: PointArray = vec![point!(x: 1., y: 2.)].as_slice().into();
+/// let chunks: Vec<GeometryArrayRef> = vec![Arc::new(chunk.clone()), Arc::new(chunk)];
+/// let chunked = from_geometry_array_chunks(&chunks).unwrap();
+/// assert_eq!(chunked.as_ref().as_point().len(), 2);
+/// ```
+pub fn from_geometry_array_chunks(
+    chunks: &[GeometryArrayRef],
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let chunks: Vec<&dyn GeometryArrayTrait> = chunks.iter().map(|chunk| chunk.as_ref()).collect();
+    from_geoarrow_chunks(&chunks)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{point, polygon};
+
+    #[test]
+    fn geometry_array_chunks() {
+        let chunks: Vec<GeometryArrayRef> =
+            vec![Arc::new(polygon::p_array()), Arc::new(polygon::p_array())];
+        let chunked = from_geometry_array_chunks(&chunks).unwrap();
+        assert_eq!(chunked.as_ref().as_polygon().len(), 4);
+
+        // Mismatched or missing types are an error
+        let chunks: Vec<GeometryArrayRef> =
+            vec![Arc::new(polygon::p_array()), Arc::new(point::point_array())];
+        assert!(from_geometry_array_chunks(&chunks).is_err());
+        assert!(from_geometry_array_chunks(&[]).is_err());
     }
 }
@@ -9,11 +9,15 @@
 
 #[allow(clippy::module_inception)]
 mod chunked_array;
+mod unary;
 
 pub use chunked_array::{
-    from_arrow_chunks, from_geoarrow_chunks, ChunkedArray, ChunkedGeometryArray,
-    ChunkedGeometryArrayTrait, ChunkedGeometryCollectionArray, ChunkedLineStringArray,
-    ChunkedMixedGeometryArray, ChunkedMultiLineStringArray, ChunkedMultiPointArray,
-    ChunkedMultiPolygonArray, ChunkedPointArray, ChunkedPolygonArray, ChunkedRectArray,
-    ChunkedWKBArray,
+    from_arrow_chunks, from_geoarrow_chunks, from_geometry_array_chunks, ChunkedArray,
+    ChunkedGeometryArray, ChunkedGeometryArrayRef, ChunkedGeometryArrayTrait,
+    ChunkedGeometryCollectionArray, ChunkedLineStringArray, ChunkedMixedGeometryArray,
+    ChunkedMultiLineStringArray, ChunkedMultiPointArray, ChunkedMultiPolygonArray,
+    ChunkedPointArray, ChunkedPolygonArray, ChunkedRectArray, ChunkedWKBArray,
 };
+pub use unary::ChunkedOutput;
+
+pub(crate) use unary::{impl_chunked_unary, impl_dyn_unary};
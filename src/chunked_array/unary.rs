@@ -0,0 +1,253 @@
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::{BooleanArray, ListArray, PrimitiveArray};
+
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+
+/// The output of an algorithm on a single chunk, which can be collected into the output of that
+/// algorithm on a chunked array.
+///
+/// Geometry arrays are collected into a [`ChunkedGeometryArray`], Arrow arrays into a
+/// [`ChunkedArray`], and fallible outputs into a fallible chunked output that returns the first
+/// error of any chunk.
+pub trait ChunkedOutput: Sized + Send {
+    type Chunked;
+
+    /// Collect the outputs of all chunks, in chunk order.
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked;
+}
+
+impl<G: GeometryArrayTrait> ChunkedOutput for G {
+    type Chunked = ChunkedGeometryArray<G>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        ChunkedGeometryArray::new(chunks)
+    }
+}
+
+impl<T: ArrowPrimitiveType> ChunkedOutput for PrimitiveArray<T> {
+    type Chunked = ChunkedArray<PrimitiveArray<T>>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        ChunkedArray::new(chunks)
+    }
+}
+
+impl ChunkedOutput for ListArray {
+    type Chunked = ChunkedArray<ListArray>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        ChunkedArray::new(chunks)
+    }
+}
+
+impl ChunkedOutput for BooleanArray {
+    type Chunked = ChunkedArray<BooleanArray>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        ChunkedArray::new(chunks)
+    }
+}
+
+impl<T: ChunkedOutput> ChunkedOutput for Result<T> {
+    type Chunked = Result<T::Chunked>;
+
+    fn from_chunks(chunks: Vec<Self>) -> Self::Chunked {
+        Ok(T::from_chunks(
+            chunks.into_iter().collect::<Result<Vec<_>>>()?,
+        ))
+    }
+}
+
+/// Implement an algorithm trait for [`ChunkedGeometryArray<G>`] for every `G` that implements it,
+/// by applying it to each chunk and collecting the outputs with [`ChunkedOutput`].
+///
+/// All methods of the trait must be listed, and their arguments must be `Copy + Sync`.
+///
+/// ```ignore
+/// impl_chunked_unary!(Densify {
+///     fn densify(&self, max_distance: f64);
+/// });
+/// ```
+///
+/// With a leading `dyn`, the trait is instead implemented for every `G` by applying the
+/// implementation for `&dyn GeometryArrayTrait` (e.g. from [`impl_dyn_unary`]) to each chunk, so
+/// that chunks of any geometry type are accepted.
+///
+/// ```ignore
+/// impl_chunked_unary!(dyn Centroid {
+///     fn centroid(&self);
+/// });
+/// ```
+///
+/// Traits with several output types, or whose chunked output is not simply the chunk outputs
+/// collected in order, keep hand-written implementations.
+macro_rules! impl_chunked_unary {
+    (dyn $trait:ident { $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*);)+ }) => {
+        impl<G> $trait for $crate::chunked_array::ChunkedGeometryArray<G>
+        where
+            G: $crate::GeometryArrayTrait,
+        {
+            type Output = <<&'static dyn $crate::GeometryArrayTrait as $trait>::Output
+                as $crate::chunked_array::ChunkedOutput>::Chunked;
+
+            $(
+                fn $method(&self $(, $arg: $arg_ty)*) -> Self::Output {
+                    $crate::chunked_array::ChunkedOutput::from_chunks(self.map(|chunk| {
+                        <&dyn $crate::GeometryArrayTrait as $trait>::$method(
+                            &chunk.as_ref() $(, $arg)*
+                        )
+                    }))
+                }
+            )+
+        }
+    };
+    ($trait:ident { $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*);)+ }) => {
+        impl<G> $trait for $crate::chunked_array::ChunkedGeometryArray<G>
+        where
+            G: $crate::GeometryArrayTrait + $trait,
+            <G as $trait>::Output: $crate::chunked_array::ChunkedOutput,
+        {
+            type Output =
+                <<G as $trait>::Output as $crate::chunked_array::ChunkedOutput>::Chunked;
+
+            $(
+                fn $method(&self $(, $arg: $arg_ty)*) -> Self::Output {
+                    $crate::chunked_array::ChunkedOutput::from_chunks(
+                        self.map(|chunk| <G as $trait>::$method(chunk $(, $arg)*)),
+                    )
+                }
+            )+
+        }
+    };
+}
+
+/// Implement an algorithm trait for `&dyn GeometryArrayTrait` by downcasting to each of the
+/// listed geometry types. Any other geometry type returns [`GeoArrowError::IncorrectType`].
+///
+/// With an output of `Arc<dyn GeometryArrayTrait>`, the output array of each geometry type is
+/// wrapped in an `Arc`, and with `Result<Arc<dyn GeometryArrayTrait>>` the fallible output of
+/// each geometry type is unwrapped with `?` first. Otherwise all geometry types must have the
+/// given output type.
+///
+/// ```ignore
+/// impl_dyn_unary!(Densify -> Arc<dyn GeometryArrayTrait> {
+///     fn densify(&self, max_distance: f64);
+/// } [LineString => as_line_string, LargeLineString => as_large_line_string]);
+/// ```
+///
+/// [`GeoArrowError::IncorrectType`]: crate::error::GeoArrowError::IncorrectType
+macro_rules! impl_dyn_unary {
+    (
+        $trait:ident -> Result<Arc<dyn GeometryArrayTrait>> {
+            $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*);)+
+        } $variants:tt
+    ) => {
+        impl $trait for &dyn $crate::GeometryArrayTrait {
+            type Output = $crate::error::Result<std::sync::Arc<dyn $crate::GeometryArrayTrait>>;
+
+            $(
+                impl_dyn_unary!(
+                    @method $method($($arg: $arg_ty),*) ($($arg),*) $variants
+                    (std::sync::Arc::new)(?) -> std::sync::Arc<dyn $crate::GeometryArrayTrait>
+                );
+            )+
+        }
+    };
+    (
+        $trait:ident -> Arc<dyn GeometryArrayTrait> {
+            $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*);)+
+        } $variants:tt
+    ) => {
+        impl $trait for &dyn $crate::GeometryArrayTrait {
+            type Output = $crate::error::Result<std::sync::Arc<dyn $crate::GeometryArrayTrait>>;
+
+            $(
+                impl_dyn_unary!(
+                    @method $method($($arg: $arg_ty),*) ($($arg),*) $variants
+                    (std::sync::Arc::new)() -> std::sync::Arc<dyn $crate::GeometryArrayTrait>
+                );
+            )+
+        }
+    };
+    (
+        $trait:ident -> $output:ty {
+            $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*);)+
+        } $variants:tt
+    ) => {
+        impl $trait for &dyn $crate::GeometryArrayTrait {
+            type Output = $crate::error::Result<$output>;
+
+            $(
+                impl_dyn_unary!(
+                    @method $method($($arg: $arg_ty),*) ($($arg),*) $variants
+                    (std::convert::identity)() -> $output
+                );
+            )+
+        }
+    };
+    (
+        @method $method:ident($($arg:ident: $arg_ty:ty),*) $call_args:tt
+        [$($variant:ident => $downcast:ident),+ $(,)?] $wrap:tt $try:tt -> $result_ty:ty
+    ) => {
+        fn $method(&self $(, $arg: $arg_ty)*) -> Self::Output {
+            use $crate::array::AsGeometryArray;
+
+            let result: $result_ty = match self.data_type() {
+                $(
+                    $crate::datatypes::GeoDataType::$variant(_) => {
+                        impl_dyn_unary!(@call $wrap $try self.$downcast().$method $call_args)
+                    }
+                )+
+                _ => return Err($crate::error::GeoArrowError::IncorrectType("".into())),
+            };
+            Ok(result)
+        }
+    };
+    (@call $wrap:tt () $value:expr) => {
+        $wrap($value)
+    };
+    (@call $wrap:tt (?) $value:expr) => {
+        $wrap($value?)
+    };
+}
+
+pub(crate) use {impl_chunked_unary, impl_dyn_unary};
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::geo::{Area, Densify, RemoveRepeatedPoints};
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::test::{linestring, point, polygon};
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn chunked_unary() {
+        let chunked =
+            ChunkedGeometryArray::new(vec![linestring::ls_array(), linestring::ls_array()]);
+        let deduped = chunked.remove_repeated_points();
+        assert_eq!(deduped.chunks(), chunked.chunks());
+
+        let densified = chunked.densify(0.1);
+        assert_eq!(densified.len(), chunked.len());
+        assert_eq!(densified.chunks().len(), 2);
+
+        let chunked = ChunkedGeometryArray::new(vec![polygon::p_array(), polygon::p_array()]);
+        let areas = chunked.unsigned_area().unwrap();
+        assert_eq!(areas.chunks().len(), 2);
+        assert_eq!(areas.chunks()[0], polygon::p_array().unsigned_area());
+    }
+
+    #[test]
+    fn dyn_unary() {
+        let array = polygon::p_array();
+        let dyn_array: &dyn GeometryArrayTrait = &array;
+        assert_eq!(dyn_array.unsigned_area().unwrap(), array.unsigned_area());
+        assert!(dyn_array.densify(1.).is_ok());
+
+        let points = point::point_array();
+        let dyn_points: &dyn GeometryArrayTrait = &points;
+        assert!(dyn_points.densify(1.).is_err());
+    }
+}
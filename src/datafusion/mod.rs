@@ -0,0 +1,30 @@
+//! Geospatial user-defined functions for [DataFusion](https://datafusion.apache.org/).
+//!
+//! Geometries are passed to and returned from these functions as WKB-encoded `Binary` columns,
+//! which is how GeoParquet stores geometries. Native GeoArrow point columns (`Struct` or
+//! `FixedSizeList`) are also accepted as input.
+//!
+//! ```no_run
+//! use datafusion::execution::context::SessionContext;
+//!
+//! let ctx = SessionContext::new();
+//! geoarrow::datafusion::register_udfs(&ctx);
+//! // ctx.sql("SELECT st_area(geometry) FROM countries")
+//! ```
+
+mod udaf;
+mod udf;
+mod util;
+
+use datafusion::execution::context::SessionContext;
+
+pub use udaf::st_union_agg;
+pub use udf::{st_area, st_centroid, st_intersects};
+
+/// Register all geospatial functions of this module with a [`SessionContext`].
+pub fn register_udfs(ctx: &SessionContext) {
+    ctx.register_udf(st_area());
+    ctx.register_udf(st_centroid());
+    ctx.register_udf(st_intersects());
+    ctx.register_udaf(st_union_agg());
+}
@@ -0,0 +1,157 @@
+use std::any::Any;
+
+use arrow_array::ArrayRef;
+use arrow_schema::DataType;
+use datafusion::common::ScalarValue;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{
+    Accumulator, AggregateUDF, AggregateUDFImpl, Signature, Volatility,
+};
+use geo::{BooleanOps, CoordsIter};
+
+use crate::datafusion::util::geometries;
+use crate::io::wkb::writer::write_multi_polygon_as_wkb;
+
+/// `st_union_agg(geometry) -> Binary`: the union of all polygonal geometries in a group,
+/// encoded as a WKB MultiPolygon.
+///
+/// Null geometries are skipped and other geometry types are an error. Returns null if the group
+/// contains no geometries.
+pub fn st_union_agg() -> AggregateUDF {
+    AggregateUDF::new_from_impl(StUnionAgg {
+        signature: Signature::any(1, Volatility::Immutable),
+    })
+}
+
+#[derive(Debug)]
+struct StUnionAgg {
+    signature: Signature,
+}
+
+impl AggregateUDFImpl for StUnionAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_union_agg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn accumulator(&self, _arg: &DataType) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::<UnionAccumulator>::default())
+    }
+
+    fn state_type(&self, _return_type: &DataType) -> Result<Vec<DataType>> {
+        Ok(vec![DataType::Binary])
+    }
+}
+
+/// Accumulates the union of polygonal geometries. The intermediate state is the union so far,
+/// encoded as WKB.
+#[derive(Debug, Default)]
+struct UnionAccumulator {
+    union: Option<geo::MultiPolygon>,
+}
+
+impl UnionAccumulator {
+    fn add_geometries(&mut self, array: &ArrayRef) -> Result<()> {
+        for geom in geometries(array)?.into_iter().flatten() {
+            let multi_polygon = match geom {
+                geo::Geometry::Polygon(polygon) => geo::MultiPolygon(vec![polygon]),
+                geo::Geometry::MultiPolygon(multi_polygon) => multi_polygon,
+                _ => {
+                    return Err(DataFusionError::Execution(
+                        "st_union_agg only supports Polygon and MultiPolygon geometries".into(),
+                    ))
+                }
+            };
+            self.union = Some(match self.union.take() {
+                Some(union) => union.union(&multi_polygon),
+                None => multi_polygon,
+            });
+        }
+        Ok(())
+    }
+
+    fn wkb_value(&self) -> Result<ScalarValue> {
+        match &self.union {
+            Some(union) => {
+                let mut buf = vec![];
+                write_multi_polygon_as_wkb(&mut buf, union)?;
+                Ok(ScalarValue::Binary(Some(buf)))
+            }
+            None => Ok(ScalarValue::Binary(None)),
+        }
+    }
+}
+
+impl Accumulator for UnionAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.add_geometries(&values[0])
+    }
+
+    fn evaluate(&self) -> Result<ScalarValue> {
+        self.wkb_value()
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self
+                .union
+                .as_ref()
+                .map(|union| union.coords_count() * std::mem::size_of::<geo::Coord>())
+                .unwrap_or_default()
+    }
+
+    fn state(&self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.wkb_value()?])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.add_geometries(&states[0])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datafusion::util::wkb_column;
+    use geo::{polygon, Area};
+
+    #[test]
+    fn union_of_overlapping_squares() {
+        let a: geo::Geometry =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+        let b: geo::Geometry =
+            polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)].into();
+
+        let mut left = UnionAccumulator::default();
+        left.update_batch(&[wkb_column(&[Some(a), None]).unwrap()])
+            .unwrap();
+        let mut right = UnionAccumulator::default();
+        right
+            .update_batch(&[wkb_column(&[Some(b)]).unwrap()])
+            .unwrap();
+
+        let state = right.state().unwrap()[0].to_array().unwrap();
+        left.merge_batch(&[state]).unwrap();
+
+        let result = left.evaluate().unwrap().to_array().unwrap();
+        let union = geometries(&result).unwrap()[0].clone().unwrap();
+        assert_eq!(union.unsigned_area(), 7.);
+    }
+
+    #[test]
+    fn empty_group_is_null() {
+        let accumulator = UnionAccumulator::default();
+        assert_eq!(accumulator.evaluate().unwrap(), ScalarValue::Binary(None));
+    }
+}
@@ -0,0 +1,193 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow_array::{BooleanArray, Float64Array};
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::{ColumnarValue, ScalarUDF, ScalarUDFImpl, Signature, Volatility};
+use geo::{Area, Centroid, Intersects};
+
+use crate::datafusion::util::{geometries, invoke_on_arrays, wkb_column};
+
+/// `st_area(geometry) -> Float64`: the unsigned planar area of each geometry.
+pub fn st_area() -> ScalarUDF {
+    ScalarUDF::from(StArea {
+        signature: Signature::any(1, Volatility::Immutable),
+    })
+}
+
+/// `st_centroid(geometry) -> Binary`: the centroid of each geometry, encoded as WKB.
+///
+/// Empty geometries return null.
+pub fn st_centroid() -> ScalarUDF {
+    ScalarUDF::from(StCentroid {
+        signature: Signature::any(1, Volatility::Immutable),
+    })
+}
+
+/// `st_intersects(left, right) -> Boolean`: whether each pair of geometries intersects.
+pub fn st_intersects() -> ScalarUDF {
+    ScalarUDF::from(StIntersects {
+        signature: Signature::any(2, Volatility::Immutable),
+    })
+}
+
+#[derive(Debug)]
+struct StArea {
+    signature: Signature,
+}
+
+impl ScalarUDFImpl for StArea {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_area"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        invoke_on_arrays(args, |arrays| {
+            let output: Float64Array = geometries(&arrays[0])?
+                .iter()
+                .map(|geom| geom.as_ref().map(|geom| geom.unsigned_area()))
+                .collect();
+            Ok(Arc::new(output))
+        })
+    }
+}
+
+#[derive(Debug)]
+struct StCentroid {
+    signature: Signature,
+}
+
+impl ScalarUDFImpl for StCentroid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_centroid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        invoke_on_arrays(args, |arrays| {
+            let output = geometries(&arrays[0])?
+                .iter()
+                .map(|geom| {
+                    geom.as_ref()
+                        .and_then(|geom| geom.centroid())
+                        .map(geo::Geometry::Point)
+                })
+                .collect::<Vec<_>>();
+            wkb_column(&output)
+        })
+    }
+}
+
+#[derive(Debug)]
+struct StIntersects {
+    signature: Signature,
+}
+
+impl ScalarUDFImpl for StIntersects {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_intersects"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        invoke_on_arrays(args, |arrays| {
+            let output: BooleanArray = geometries(&arrays[0])?
+                .iter()
+                .zip(geometries(&arrays[1])?.iter())
+                .map(|(left, right)| match (left, right) {
+                    (Some(left), Some(right)) => Some(left.intersects(right)),
+                    _ => None,
+                })
+                .collect();
+            Ok(Arc::new(output))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::{Array, ArrayRef};
+    use datafusion::common::ScalarValue;
+    use geo::{point, polygon};
+
+    fn wkb_value(geom: geo::Geometry) -> ColumnarValue {
+        ColumnarValue::Array(wkb_column(&[Some(geom), None]).unwrap())
+    }
+
+    fn invoke(udf: &dyn ScalarUDFImpl, args: &[ColumnarValue]) -> ArrayRef {
+        match udf.invoke(args).unwrap() {
+            ColumnarValue::Array(array) => array,
+            ColumnarValue::Scalar(_) => panic!("expected array output"),
+        }
+    }
+
+    #[test]
+    fn area_and_centroid() {
+        let square: geo::Geometry =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+
+        let area = invoke(st_area().inner().as_ref(), &[wkb_value(square.clone())]);
+        let area = area.as_primitive::<Float64Type>();
+        assert_eq!(area.value(0), 4.);
+        assert!(area.is_null(1));
+
+        let centroid = invoke(st_centroid().inner().as_ref(), &[wkb_value(square)]);
+        let centroid = geometries(&centroid).unwrap();
+        assert_eq!(centroid[0], Some(point!(x: 1., y: 1.).into()));
+        assert_eq!(centroid[1], None);
+    }
+
+    #[test]
+    fn intersects_scalar() {
+        let square: geo::Geometry =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+        let point = wkb_column(&[Some(point!(x: 1., y: 1.).into())]).unwrap();
+        let point = ColumnarValue::Scalar(ScalarValue::try_from_array(&point, 0).unwrap());
+
+        let output = invoke(
+            st_intersects().inner().as_ref(),
+            &[wkb_value(square), point],
+        );
+        let output = output.as_boolean();
+        assert!(output.value(0));
+        assert!(output.is_null(1));
+    }
+}
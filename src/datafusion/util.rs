@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, Field};
+use datafusion::common::ScalarValue;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::ColumnarValue;
+
+use crate::array::{from_arrow_array, WKBArray};
+use crate::error::GeoArrowError;
+use crate::io::wkb::to_wkb;
+use crate::trait_::{GeometryArrayAccessor, IntoArrow};
+
+impl From<GeoArrowError> for DataFusionError {
+    fn from(value: GeoArrowError) -> Self {
+        DataFusionError::External(Box::new(value))
+    }
+}
+
+/// Parse a geometry column into geo geometries.
+///
+/// Binary columns are interpreted as WKB. Other columns are parsed with
+/// [`from_arrow_array`], which only supports types that are unambiguous without extension
+/// metadata.
+pub(super) fn geometries(array: &ArrayRef) -> Result<Vec<Option<geo::Geometry>>> {
+    let wkb_array = match array.data_type() {
+        DataType::Binary | DataType::LargeBinary => WKBArray::<i64>::try_from(array.as_ref())?,
+        data_type => {
+            let field = Field::new("geometry", data_type.clone(), true);
+            to_wkb(from_arrow_array(array.as_ref(), &field)?.as_ref())
+        }
+    };
+    Ok(wkb_array.iter_geo().collect())
+}
+
+/// Encode geo geometries as a WKB `Binary` column.
+pub(super) fn wkb_column(geoms: &[Option<geo::Geometry>]) -> Result<ArrayRef> {
+    let wkb_array = WKBArray::<i32>::try_from(geoms)?;
+    Ok(Arc::new(wkb_array.into_arrow()))
+}
+
+/// Evaluate `op` on the arguments of a scalar function materialized as arrays.
+///
+/// If all arguments are scalars, `op` is evaluated on arrays of length one and a scalar is
+/// returned.
+pub(super) fn invoke_on_arrays(
+    args: &[ColumnarValue],
+    op: impl Fn(&[ArrayRef]) -> Result<ArrayRef>,
+) -> Result<ColumnarValue> {
+    let len = args.iter().find_map(|arg| match arg {
+        ColumnarValue::Array(array) => Some(array.len()),
+        ColumnarValue::Scalar(_) => None,
+    });
+    let arrays = args
+        .iter()
+        .map(|arg| arg.clone().into_array(len.unwrap_or(1)))
+        .collect::<Result<Vec<_>>>()?;
+    let result = op(&arrays)?;
+    match len {
+        Some(_) => Ok(ColumnarValue::Array(result)),
+        None => Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &result, 0,
+        )?)),
+    }
+}
@@ -143,7 +143,7 @@ fn polygon_data_type<O: OffsetSizeTrait>(coord_type: &CoordType) -> DataType {
 
 fn multi_point_data_type<O: OffsetSizeTrait>(coord_type: &CoordType) -> DataType {
     let coords_type = coord_type_to_data_type(coord_type);
-    let vertices_field = Field::new("points", coords_type, false).into();
+    let vertices_field = Field::new("points", coords_type, true).into();
     match O::IS_LARGE {
         true => DataType::LargeList(vertices_field),
         false => DataType::List(vertices_field),
@@ -167,8 +167,8 @@ fn multi_polygon_data_type<O: OffsetSizeTrait>(coord_type: &CoordType) -> DataTy
     let coords_type = coord_type_to_data_type(coord_type);
     let vertices_field = Field::new("vertices", coords_type, false);
     let rings_field = match O::IS_LARGE {
-        true => Field::new_large_list("rings", vertices_field, true),
-        false => Field::new_list("rings", vertices_field, true),
+        true => Field::new_large_list("rings", vertices_field, false),
+        false => Field::new_list("rings", vertices_field, false),
     };
     let polygons_field = match O::IS_LARGE {
         true => Field::new_large_list("polygons", rings_field, false).into(),
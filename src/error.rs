@@ -46,14 +46,26 @@ pub enum GeoArrowError {
     #[error(transparent)]
     GeosError(#[from] geos::Error),
 
+    #[cfg(feature = "object_store")]
+    #[error(transparent)]
+    ObjectStoreError(#[from] object_store::Error),
+
     #[cfg(feature = "parquet")]
     #[error(transparent)]
     ParquetError(#[from] parquet::errors::ParquetError),
 
+    #[cfg(feature = "polars")]
+    #[error(transparent)]
+    PolarsError(#[from] polars::error::PolarsError),
+
     #[cfg(feature = "proj")]
     #[error(transparent)]
     ProjError(#[from] proj::ProjError),
 
+    #[cfg(feature = "proj")]
+    #[error(transparent)]
+    ProjCreateError(#[from] proj::ProjCreateError),
+
     #[cfg(feature = "flatgeobuf")]
     #[error(transparent)]
     FlatgeobufError(#[from] flatgeobuf::Error),
@@ -61,7 +73,6 @@ pub enum GeoArrowError {
     #[error(transparent)]
     IOError(#[from] std::io::Error),
 
-    #[cfg(feature = "parquet")]
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
 
@@ -1,7 +1,11 @@
 //! Read from and write to [FlatGeobuf](https://flatgeobuf.org/) files.
 
 mod reader;
+#[cfg(feature = "object_store")]
+mod reader_async;
 mod writer;
 
-pub use reader::read_flatgeobuf;
+pub use reader::{read_flatgeobuf, read_flatgeobuf_batches, FlatGeobufRecordBatchReader};
+#[cfg(feature = "object_store")]
+pub use reader_async::read_flatgeobuf_async;
 pub use writer::{write_flatgeobuf, write_flatgeobuf_with_options};
@@ -24,10 +24,15 @@ use crate::array::*;
 use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::GeoRecordBatchReader;
 use crate::table::GeoTable;
-use arrow_schema::{DataType, Field, SchemaBuilder, TimeUnit};
+use crate::trait_::GeometryArrayBuilder;
+use arrow_array::RecordBatch;
+use arrow_schema::{DataType, Field, SchemaBuilder, SchemaRef, TimeUnit};
+use flatgeobuf::reader_trait::Seekable;
 use flatgeobuf::{ColumnType, GeometryType};
-use flatgeobuf::{FgbReader, Header};
+use flatgeobuf::{FallibleStreamingIterator, FeatureIter, FgbReader, Header};
+use geozero::{FeatureAccess, GeomProcessor};
 use std::io::{Read, Seek};
 use std::sync::Arc;
 
@@ -108,6 +113,155 @@ pub fn read_flatgeobuf<R: Read + Seek>(
     }
 }
 
+/// Evaluate `$body` with `$builder` as an alias for the geometry builder matching a FlatGeobuf
+/// geometry type.
+macro_rules! with_geometry_builder {
+    ($geometry_type:expr, $builder:ident => $body:expr) => {
+        match $geometry_type {
+            GeometryType::Point => {
+                type $builder = PointBuilder;
+                $body
+            }
+            GeometryType::LineString => {
+                type $builder = LineStringBuilder<i32>;
+                $body
+            }
+            GeometryType::Polygon => {
+                type $builder = PolygonBuilder<i32>;
+                $body
+            }
+            GeometryType::MultiPoint => {
+                type $builder = MultiPointBuilder<i32>;
+                $body
+            }
+            GeometryType::MultiLineString => {
+                type $builder = MultiLineStringBuilder<i32>;
+                $body
+            }
+            GeometryType::MultiPolygon => {
+                type $builder = MultiPolygonBuilder<i32>;
+                $body
+            }
+            GeometryType::Unknown => {
+                type $builder = MixedGeometryStreamBuilder<i32>;
+                $body
+            }
+            geom_type => Err(GeoArrowError::NotYetImplemented(format!(
+                "Parsing FlatGeobuf from {:?} geometry type not yet supported",
+                geom_type
+            ))),
+        }
+    };
+}
+
+/// A streaming FlatGeobuf reader, yielding record batches of at most `batch_size` rows.
+///
+/// Created by [`read_flatgeobuf_batches`].
+pub struct FlatGeobufRecordBatchReader<R: Read + Seek> {
+    features: FeatureIter<R, Seekable>,
+    geometry_type: GeometryType,
+    options: GeoTableBuilderOptions,
+    schema: SchemaRef,
+    rows_read: usize,
+}
+
+impl<R: Read + Seek> FlatGeobufRecordBatchReader<R> {
+    fn read_batch<G: GeometryArrayBuilder + GeomProcessor>(
+        &mut self,
+    ) -> Result<Option<RecordBatch>> {
+        let batch_size = self.options.batch_size;
+        let mut options = self.options.clone();
+        options.num_rows = self
+            .features
+            .features_count()
+            .map(|count| count.saturating_sub(self.rows_read).min(batch_size));
+        let mut builder = GeoTableBuilder::<G>::new_with_options(options);
+
+        let mut num_rows = 0;
+        while num_rows < batch_size {
+            let Some(feature) = self.features.next()? else {
+                break;
+            };
+            feature.process(&mut builder, self.rows_read as u64)?;
+            self.rows_read += 1;
+            num_rows += 1;
+        }
+        if num_rows == 0 {
+            return Ok(None);
+        }
+
+        let (_, batches, _) = builder.finish_without_downcast()?.into_inner();
+        let columns = batches[0].columns().to_vec();
+        Ok(Some(RecordBatch::try_new(self.schema.clone(), columns)?))
+    }
+}
+
+impl<R: Read + Seek> Iterator for FlatGeobufRecordBatchReader<R> {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        with_geometry_builder!(self.geometry_type, Builder => self.read_batch::<Builder>())
+            .transpose()
+    }
+}
+
+impl<R: Read + Seek> GeoRecordBatchReader for FlatGeobufRecordBatchReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn geometry_column_index(&self) -> usize {
+        self.schema.fields().len() - 1
+    }
+}
+
+/// Read a FlatGeobuf file as a stream of record batches.
+///
+/// Unlike [`read_flatgeobuf`], this only holds one batch in memory at a time. The geometry
+/// column has the type declared in the file header (a mixed geometry array if the header does
+/// not declare one), and is not narrowed per batch, so every batch has the same schema.
+pub fn read_flatgeobuf_batches<R: Read + Seek>(
+    file: R,
+    coord_type: CoordType,
+    batch_size: Option<usize>,
+) -> Result<FlatGeobufRecordBatchReader<R>> {
+    let features = FgbReader::open(file)?.select_all()?;
+
+    let header = features.header();
+    if header.has_m() | header.has_t() | header.has_tm() | header.has_z() {
+        return Err(GeoArrowError::General(
+            "Only XY dimensions are supported".to_string(),
+        ));
+    }
+
+    let geometry_type = header.geometry_type();
+    let properties_schema = Arc::new(infer_schema(header).finish());
+    let options = GeoTableBuilderOptions::new(
+        coord_type,
+        true,
+        batch_size,
+        Some(properties_schema.clone()),
+        None,
+        Default::default(),
+    );
+
+    let geometry_field = with_geometry_builder!(geometry_type, Builder => {
+        let builder =
+            Builder::with_geom_capacity_and_options(0, coord_type, options.metadata.clone());
+        Ok(GeometryArrayBuilder::finish(builder).extension_field())
+    })?;
+    let mut schema = SchemaBuilder::from(properties_schema.fields());
+    schema.push(geometry_field);
+
+    Ok(FlatGeobufRecordBatchReader {
+        features,
+        geometry_type,
+        options,
+        schema: Arc::new(schema.finish()),
+        rows_read: 0,
+    })
+}
+
 fn infer_schema(header: Header<'_>) -> SchemaBuilder {
     let columns = header.columns().unwrap();
     let mut schema = SchemaBuilder::with_capacity(columns.len());
@@ -163,4 +317,24 @@ mod test {
         );
         let _table = read_flatgeobuf(&mut filein, Default::default(), None).unwrap();
     }
+
+    #[test]
+    fn test_nz_buildings_batches() {
+        let filein = BufReader::new(
+            File::open("fixtures/flatgeobuf/nz-building-outlines-small.fgb").unwrap(),
+        );
+        let reader = read_flatgeobuf_batches(filein, Default::default(), Some(500)).unwrap();
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert!(batches.len() > 1);
+        assert!(batches.iter().all(|batch| batch.num_rows() <= 500));
+        assert!(batches.iter().all(|batch| batch.schema() == schema));
+
+        let mut filein = BufReader::new(
+            File::open("fixtures/flatgeobuf/nz-building-outlines-small.fgb").unwrap(),
+        );
+        let table = read_flatgeobuf(&mut filein, Default::default(), None).unwrap();
+        let num_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, table.len());
+    }
 }
@@ -0,0 +1,40 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use crate::array::CoordType;
+use crate::error::Result;
+use crate::io::flatgeobuf::read_flatgeobuf;
+use crate::table::GeoTable;
+
+use object_store::path::Path;
+use object_store::ObjectStore;
+
+/// Asynchronously read a FlatGeobuf file from an [`ObjectStore`] to a GeoTable
+///
+/// The full object is fetched into memory before being decoded, as the FlatGeobuf reader does
+/// not yet support range requests against an object store.
+pub async fn read_flatgeobuf_async(
+    store: Arc<dyn ObjectStore>,
+    location: &Path,
+    coord_type: CoordType,
+    batch_size: Option<usize>,
+) -> Result<GeoTable> {
+    let bytes = store.get(location).await?.bytes().await?;
+    read_flatgeobuf(&mut Cursor::new(bytes), coord_type, batch_size)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use object_store::local::LocalFileSystem;
+
+    #[tokio::test]
+    async fn test_countries() {
+        let store = Arc::new(LocalFileSystem::new_with_prefix("fixtures/flatgeobuf").unwrap());
+        let location = Path::from("countries.fgb");
+        let table = read_flatgeobuf_async(store, &location, Default::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(table.len(), 179);
+    }
+}
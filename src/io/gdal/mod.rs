@@ -1,3 +1,5 @@
 mod reader;
+mod writer;
 
 pub use reader::read_gdal;
+pub use writer::write_gdal;
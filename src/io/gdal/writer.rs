@@ -0,0 +1,138 @@
+use arrow::compute::cast;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int32Type, Int64Type};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::DataType;
+use gdal::vector::{Feature, FieldValue, Geometry, LayerAccess, OGRFieldType};
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::to_wkb;
+use crate::table::GeoTable;
+
+/// Write a GeoTable to a GDAL layer
+///
+/// A field is created on the layer for each non-geometry column, so the layer should not have
+/// any fields yet. Integer, floating point, boolean and string columns are supported.
+pub fn write_gdal(table: &GeoTable, layer: &mut impl LayerAccess) -> Result<()> {
+    let geometry_column_index = table.geometry_column_index();
+    let fields = table
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != geometry_column_index)
+        .map(|(i, field)| Ok((i, field.name().as_str(), ogr_field_type(field.data_type())?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let fields_def = fields
+        .iter()
+        .map(|(_, name, field_type)| (*name, *field_type))
+        .collect::<Vec<_>>();
+    layer.create_defn_fields(&fields_def)?;
+
+    let geometry = table.geometry()?;
+    for (batch, geometry_chunk) in table.batches().iter().zip(geometry.geometry_chunks()) {
+        let wkb_array = to_wkb::<i64>(geometry_chunk).into_inner();
+        let columns = fields
+            .iter()
+            .map(|(i, _, field_type)| cast_column(batch.column(*i), *field_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        for row_idx in 0..batch.num_rows() {
+            let mut feature = Feature::new(layer.defn())?;
+            if wkb_array.is_valid(row_idx) {
+                feature.set_geometry(Geometry::from_wkb(wkb_array.value(row_idx))?)?;
+            }
+
+            for ((_, name, _), column) in fields.iter().zip(columns.iter()) {
+                if let Some(value) = field_value(column, row_idx) {
+                    feature.set_field(name, &value)?;
+                }
+            }
+
+            feature.create(&*layer)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The OGR field type used to store an Arrow column.
+fn ogr_field_type(data_type: &DataType) -> Result<OGRFieldType::Type> {
+    let field_type = match data_type {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16 => OGRFieldType::OFTInteger,
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => OGRFieldType::OFTInteger64,
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => OGRFieldType::OFTReal,
+        DataType::Utf8 | DataType::LargeUtf8 => OGRFieldType::OFTString,
+        dt => {
+            return Err(GeoArrowError::NotYetImplemented(format!(
+                "Writing columns of type {dt} to GDAL"
+            )))
+        }
+    };
+    Ok(field_type)
+}
+
+/// Cast a column to the Arrow type matching its OGR field type.
+fn cast_column(array: &ArrayRef, field_type: OGRFieldType::Type) -> Result<ArrayRef> {
+    let data_type = match field_type {
+        OGRFieldType::OFTInteger => DataType::Int32,
+        OGRFieldType::OFTInteger64 => DataType::Int64,
+        OGRFieldType::OFTReal => DataType::Float64,
+        _ => DataType::Utf8,
+    };
+    Ok(cast(array, &data_type)?)
+}
+
+/// The value of a column cast with [`cast_column`] at the given row, or `None` if it is null.
+fn field_value(array: &ArrayRef, row_idx: usize) -> Option<FieldValue> {
+    if array.is_null(row_idx) {
+        return None;
+    }
+
+    let value = match array.data_type() {
+        DataType::Int32 => {
+            FieldValue::IntegerValue(array.as_primitive::<Int32Type>().value(row_idx))
+        }
+        DataType::Int64 => {
+            FieldValue::Integer64Value(array.as_primitive::<Int64Type>().value(row_idx))
+        }
+        DataType::Float64 => {
+            FieldValue::RealValue(array.as_primitive::<Float64Type>().value(row_idx))
+        }
+        _ => FieldValue::StringValue(array.as_string::<i32>().value(row_idx).to_string()),
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::gdal::read_gdal;
+    use crate::test::point;
+    use gdal::{DriverManager, LayerOptions};
+
+    #[test]
+    fn test_write_gdal() -> Result<()> {
+        let table = point::table();
+
+        let driver = DriverManager::get_driver_by_name("Memory")?;
+        let mut dataset = driver.create_vector_only("")?;
+        let mut layer = dataset.create_layer(LayerOptions {
+            name: "points",
+            ty: gdal::vector::OGRwkbGeometryType::wkbPoint,
+            ..Default::default()
+        })?;
+        write_gdal(&table, &mut layer)?;
+        assert_eq!(layer.feature_count(), table.len() as u64);
+
+        let round_trip = read_gdal(&mut layer, None)?;
+        assert_eq!(round_trip.len(), table.len());
+        Ok(())
+    }
+}
@@ -26,24 +26,17 @@ impl<'a, O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'a>>>> for LineSt
     }
 }
 
-#[allow(unused_imports)]
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::io::geos::{FromGEOS, ToGEOS};
     use crate::test::linestring::ls_array;
-    use crate::trait_::{GeometryArrayAccessor, GeometryScalarTrait};
 
-    #[ignore = "geos lifetime error"]
     #[test]
     fn geos_round_trip() {
         let arr = ls_array();
-        let _scalars = arr.iter().collect::<Vec<_>>();
-        todo!()
-        // let geos_geoms = scalars
-        //     .iter()
-        //     .map(|opt_x| opt_x.map(|x| x.to_geos().unwrap()))
-        //     .collect::<Vec<_>>();
-        // let round_trip: LineStringArray<i32> = geos_geoms.try_into().unwrap();
-        // assert_eq!(arr, round_trip);
+        let geos_geoms = arr.to_geos().unwrap();
+        let round_trip = LineStringArray::<i32>::try_from_geos(geos_geoms).unwrap();
+        assert_eq!(arr, round_trip);
     }
 }
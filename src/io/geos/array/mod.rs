@@ -4,3 +4,207 @@ mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Convert all geometries of an array to [`geos::Geometry`] objects.
+///
+/// Null geometries are returned as `None`.
+pub trait ToGEOS {
+    type Output;
+
+    fn to_geos(&self) -> Self::Output;
+}
+
+/// Create an array from [`geos::Geometry`] objects.
+///
+/// `None` values are added as null geometries. An error is returned if a geometry does not match
+/// the geometry type of the array.
+pub trait FromGEOS: Sized {
+    type Input;
+
+    fn try_from_geos(geoms: Self::Input) -> Result<Self>;
+}
+
+impl ToGEOS for PointArray {
+    type Output = Result<Vec<Option<geos::Geometry<'static>>>>;
+
+    fn to_geos(&self) -> Self::Output {
+        self.iter()
+            .map(|maybe_geom| Ok(maybe_geom.map(geos::Geometry::try_from).transpose()?))
+            .collect()
+    }
+}
+
+/// Implementation that converts each geometry scalar to GEOS
+macro_rules! iter_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> ToGEOS for $type {
+            type Output = Result<Vec<Option<geos::Geometry<'static>>>>;
+
+            fn to_geos(&self) -> Self::Output {
+                self.iter()
+                    .map(|maybe_geom| Ok(maybe_geom.map(geos::Geometry::try_from).transpose()?))
+                    .collect()
+            }
+        }
+    };
+}
+
+iter_impl!(LineStringArray<O>);
+iter_impl!(PolygonArray<O>);
+iter_impl!(MultiPointArray<O>);
+iter_impl!(MultiLineStringArray<O>);
+iter_impl!(MultiPolygonArray<O>);
+iter_impl!(MixedGeometryArray<O>);
+iter_impl!(GeometryCollectionArray<O>);
+iter_impl!(WKBArray<O>);
+
+impl ToGEOS for RectArray {
+    type Output = Result<Vec<Option<geos::Geometry<'static>>>>;
+
+    // GEOS has no rectangle type, so rects are converted to polygons
+    fn to_geos(&self) -> Self::Output {
+        self.iter_geo()
+            .map(|maybe_rect| {
+                Ok(maybe_rect
+                    .map(|rect| geos::Geometry::try_from(rect.to_polygon()))
+                    .transpose()?)
+            })
+            .collect()
+    }
+}
+
+impl<G: GeometryArrayTrait + ToGEOS<Output = Result<Vec<Option<geos::Geometry<'static>>>>>> ToGEOS
+    for ChunkedGeometryArray<G>
+{
+    type Output = Result<Vec<Vec<Option<geos::Geometry<'static>>>>>;
+
+    fn to_geos(&self) -> Self::Output {
+        self.try_map(|chunk| chunk.to_geos())
+    }
+}
+
+/// Implementation for arrays with a matching GEOS scalar type
+macro_rules! try_from_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> FromGEOS for $type {
+            type Input = Vec<Option<geos::Geometry<'static>>>;
+
+            fn try_from_geos(geoms: Self::Input) -> Result<Self> {
+                geoms.try_into()
+            }
+        }
+    };
+}
+
+try_from_impl!(LineStringArray<O>);
+try_from_impl!(PolygonArray<O>);
+try_from_impl!(MultiPointArray<O>);
+try_from_impl!(MultiLineStringArray<O>);
+try_from_impl!(MultiPolygonArray<O>);
+
+impl FromGEOS for PointArray {
+    type Input = Vec<Option<geos::Geometry<'static>>>;
+
+    fn try_from_geos(geoms: Self::Input) -> Result<Self> {
+        geoms.try_into()
+    }
+}
+
+/// Convert GEOS geometries to [`geo::Geometry`] objects, for array types that can hold any
+/// geometry type.
+fn to_geo_geometries(
+    geoms: Vec<Option<geos::Geometry<'static>>>,
+) -> Result<Vec<Option<geo::Geometry>>> {
+    geoms
+        .into_iter()
+        .map(|maybe_geom| Ok(maybe_geom.map(geo::Geometry::try_from).transpose()?))
+        .collect()
+}
+
+impl<O: OffsetSizeTrait> FromGEOS for MixedGeometryArray<O> {
+    type Input = Vec<Option<geos::Geometry<'static>>>;
+
+    fn try_from_geos(geoms: Self::Input) -> Result<Self> {
+        to_geo_geometries(geoms)?.as_slice().try_into()
+    }
+}
+
+impl<O: OffsetSizeTrait> FromGEOS for GeometryCollectionArray<O> {
+    type Input = Vec<Option<geos::Geometry<'static>>>;
+
+    fn try_from_geos(geoms: Self::Input) -> Result<Self> {
+        let geoms = to_geo_geometries(geoms)?;
+        Ok(GeometryCollectionBuilder::<O>::from_nullable_geometries(
+            &geoms,
+            Default::default(),
+            Default::default(),
+            false,
+        )?
+        .into())
+    }
+}
+
+impl<O: OffsetSizeTrait> FromGEOS for WKBArray<O> {
+    type Input = Vec<Option<geos::Geometry<'static>>>;
+
+    fn try_from_geos(geoms: Self::Input) -> Result<Self> {
+        to_geo_geometries(geoms)?.as_slice().try_into()
+    }
+}
+
+impl<G: GeometryArrayTrait + FromGEOS<Input = Vec<Option<geos::Geometry<'static>>>>> FromGEOS
+    for ChunkedGeometryArray<G>
+{
+    type Input = Vec<Vec<Option<geos::Geometry<'static>>>>;
+
+    fn try_from_geos(geoms: Self::Input) -> Result<Self> {
+        let chunks = geoms
+            .into_iter()
+            .map(G::try_from_geos)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipoint, multipolygon};
+
+    #[test]
+    fn mixed_geos_round_trip() {
+        let geoms = vec![
+            Some(geo::Geometry::MultiPolygon(multipolygon::mp0())),
+            None,
+            Some(geo::Geometry::MultiPoint(multipoint::mp0())),
+        ];
+        let arr: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        let geos_geoms = arr.to_geos().unwrap();
+        assert_eq!(geos_geoms.len(), arr.len());
+
+        let round_trip = MixedGeometryArray::<i32>::try_from_geos(geos_geoms).unwrap();
+        assert_eq!(
+            round_trip.iter_geo().collect::<Vec<_>>(),
+            arr.iter_geo().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn chunked_geos_round_trip() {
+        let chunked = ChunkedGeometryArray::new(vec![multipolygon::mp_array(); 2]);
+        let geos_geoms = chunked.to_geos().unwrap();
+        assert_eq!(geos_geoms.len(), 2);
+
+        let round_trip =
+            ChunkedGeometryArray::<MultiPolygonArray<i32>>::try_from_geos(geos_geoms).unwrap();
+        assert_eq!(round_trip.chunks(), chunked.chunks());
+    }
+}
@@ -26,24 +26,17 @@ impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'_>>>> for MultiLineS
     }
 }
 
-#[allow(unused_imports)]
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::io::geos::{FromGEOS, ToGEOS};
     use crate::test::multilinestring::ml_array;
-    use crate::trait_::{GeometryArrayAccessor, GeometryScalarTrait};
 
-    #[ignore = "geos lifetime error"]
     #[test]
     fn geos_round_trip() {
-        let _arr = ml_array();
-        todo!()
-
-        // let geos_geoms: Vec<Option<geos::Geometry>> = arr
-        //     .iter()
-        //     .map(|opt_x| opt_x.map(|x| x.to_geos().unwrap()))
-        //     .collect();
-        // let round_trip: MultiLineStringArray<i32> = geos_geoms.try_into().unwrap();
-        // assert_eq!(arr, round_trip);
+        let arr = ml_array();
+        let geos_geoms = arr.to_geos().unwrap();
+        let round_trip = MultiLineStringArray::<i32>::try_from_geos(geos_geoms).unwrap();
+        assert_eq!(arr, round_trip);
     }
 }
@@ -2,3 +2,5 @@
 
 mod array;
 pub mod scalar;
+
+pub use array::{FromGEOS, ToGEOS};
@@ -7,6 +7,7 @@ use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
 
 use crate::algorithm::native::Downcast;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::validation::{self, ValidationPolicy};
 use crate::array::CoordType;
 use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::table::builder::properties::PropertiesBatchBuilder;
@@ -32,6 +33,9 @@ pub struct GeoTableBuilderOptions {
 
     /// The number of rows to be read
     pub num_rows: Option<usize>,
+
+    /// How to handle invalid geometries
+    pub validation: ValidationPolicy,
 }
 
 impl GeoTableBuilderOptions {
@@ -50,6 +54,7 @@ impl GeoTableBuilderOptions {
             properties_schema,
             num_rows,
             metadata,
+            validation: Default::default(),
         }
     }
 }
@@ -63,6 +68,7 @@ impl Default for GeoTableBuilderOptions {
             properties_schema: None,
             num_rows: None,
             metadata: Default::default(),
+            validation: Default::default(),
         }
     }
 }
@@ -96,6 +102,9 @@ pub struct GeoTableBuilder<G: GeometryArrayBuilder + GeomProcessor> {
 
     /// Builder for the geometries of the current batch
     geom_builder: G,
+
+    /// How to handle invalid geometries, applied to each geometry array chunk when finishing
+    validation: ValidationPolicy,
 }
 
 impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
@@ -148,6 +157,7 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
             prop_builder,
             geom_arrays,
             geom_builder,
+            validation: options.validation,
         }
     }
 
@@ -185,7 +195,15 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
         Ok(())
     }
 
-    pub fn finish(mut self) -> Result<GeoTable> {
+    pub fn finish(self) -> Result<GeoTable> {
+        self.finish_without_downcast()?.downcast(false)
+    }
+
+    /// Finish the table, keeping the geometry type of the builder.
+    ///
+    /// Unlike [`finish`][Self::finish], this guarantees the geometry column has the same type
+    /// whatever rows were read, which is needed when tables are built batch by batch.
+    pub(crate) fn finish_without_downcast(mut self) -> Result<GeoTable> {
         // If there are rows that haven't flushed yet, flush them to batches
         if self.geom_builder.len() > 0 {
             self.flush_batch()?;
@@ -219,14 +237,14 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
             .into_iter()
             .zip(self.geom_arrays)
             .map(|(batch, geom_arr)| {
+                let geom_arr = validation::apply_policy(geom_arr, self.validation)?;
                 let mut columns = batch.columns().to_vec();
                 columns.push(geom_arr.to_array_ref());
                 Ok(RecordBatch::try_new(new_schema.clone(), columns)?)
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let table = GeoTable::try_new(new_schema, batches, geometry_column_index)?;
-        table.downcast(false)
+        GeoTable::try_new(new_schema, batches, geometry_column_index)
     }
 }
 
@@ -18,6 +18,14 @@ pub mod geos;
 pub mod geozero;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+#[cfg(feature = "polars")]
+pub mod polars;
 #[cfg(feature = "postgis")]
 pub mod postgis;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod stream;
 pub mod wkb;
+pub mod wkt;
+
+pub use stream::GeoRecordBatchReader;
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use parquet::file::metadata::FileMetaData;
+use parquet::file::metadata::{FileMetaData, RowGroupMetaData};
+use parquet::file::statistics::Statistics;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::algorithm::native::bounding_rect::BoundingRect;
 use crate::error::{GeoArrowError, Result};
 
 #[derive(Serialize, Deserialize)]
@@ -17,11 +19,75 @@ pub struct GeoParquetMetadata {
 pub struct GeoParquetColumnMetadata {
     pub encoding: String,
     pub geometry_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub crs: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub orientation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edges: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bbox: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub epoch: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub covering: Option<GeoParquetCovering>,
+}
+
+/// Columns that "cover" the geometry column, allowing readers to prune row groups using the
+/// column statistics of the covering columns.
+#[derive(Serialize, Deserialize)]
+pub struct GeoParquetCovering {
+    pub bbox: GeoParquetBboxCovering,
+}
+
+/// The column paths of the per-row bounding box struct column.
+#[derive(Serialize, Deserialize)]
+pub struct GeoParquetBboxCovering {
+    pub xmin: Vec<String>,
+    pub ymin: Vec<String>,
+    pub xmax: Vec<String>,
+    pub ymax: Vec<String>,
+}
+
+impl GeoParquetBboxCovering {
+    /// A covering referring to the `xmin`, `ymin`, `xmax` and `ymax` children of a struct column.
+    pub fn new(column_name: &str) -> Self {
+        let path = |child: &str| vec![column_name.to_string(), child.to_string()];
+        Self {
+            xmin: path("xmin"),
+            ymin: path("ymin"),
+            xmax: path("xmax"),
+            ymax: path("ymax"),
+        }
+    }
+
+    /// The bounding box of a row group, computed from the statistics of the covering columns.
+    ///
+    /// Returns `None` if the covering columns are missing or do not have statistics.
+    pub fn row_group_bounds(&self, row_group: &RowGroupMetaData) -> Option<BoundingRect> {
+        let column_stats = |path: &[String]| {
+            row_group
+                .columns()
+                .iter()
+                .find(|column| column.column_path().parts() == path)
+                .and_then(|column| match column.statistics() {
+                    Some(Statistics::Double(stats)) if stats.has_min_max_set() => {
+                        Some((*stats.min(), *stats.max()))
+                    }
+                    _ => None,
+                })
+        };
+
+        let (minx, _) = column_stats(&self.xmin)?;
+        let (miny, _) = column_stats(&self.ymin)?;
+        let (_, maxx) = column_stats(&self.xmax)?;
+        let (_, maxy) = column_stats(&self.ymax)?;
+
+        let mut bounds = BoundingRect::new();
+        bounds.add_coord(&geo::coord! { x: minx, y: miny });
+        bounds.add_coord(&geo::coord! { x: maxx, y: maxy });
+        Some(bounds)
+    }
 }
 
 impl GeoParquetMetadata {
@@ -1,6 +1,17 @@
-//! Read the [GeoParquet](https://github.com/opengeospatial/geoparquet) format.
+//! Read from and write to the [GeoParquet](https://github.com/opengeospatial/geoparquet) format.
 
 mod geoparquet_metadata;
 mod reader;
+#[cfg(feature = "object_store")]
+mod reader_async;
+mod writer;
 
-pub use reader::{read_geoparquet, GeoParquetReaderOptions};
+pub use reader::{
+    read_geoparquet, read_geoparquet_batches, GeoParquetReaderOptions, GeoParquetRecordBatchReader,
+};
+#[cfg(feature = "object_store")]
+pub use reader_async::read_geoparquet_async;
+pub use writer::{
+    write_geoparquet, write_geoparquet_with_options, GeoParquetWriterEncoding,
+    GeoParquetWriterOptions,
+};
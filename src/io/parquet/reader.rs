@@ -1,14 +1,19 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 
-use crate::array::CoordType;
+use crate::array::{from_arrow_array, AsGeometryArray, CoordType, ValidationPolicy};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::from_wkb_with_validation;
+use crate::io::GeoRecordBatchReader;
 use crate::table::GeoTable;
 
 use crate::io::parquet::geoparquet_metadata::GeoParquetMetadata;
-use arrow_schema::Schema;
-use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
-use parquet::file::metadata::FileMetaData;
+use arrow_array::RecordBatch;
+use arrow_schema::{DataType, Schema, SchemaRef};
+use geo::Rect;
+use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use parquet::file::metadata::{FileMetaData, ParquetMetaData};
 use parquet::file::reader::ChunkReader;
 
 // TODO: deduplicate with `resolve_types` in `downcast.rs`
@@ -52,7 +57,7 @@ fn infer_geo_data_type(
     }
 }
 
-fn parse_geoparquet_metadata(
+pub(super) fn parse_geoparquet_metadata(
     metadata: &FileMetaData,
     schema: &Schema,
     coord_type: CoordType,
@@ -81,9 +86,49 @@ fn parse_geoparquet_metadata(
     ))
 }
 
+/// The indices of the row groups whose bounding box, as recorded in the statistics of the
+/// GeoParquet bbox covering column, intersects `bbox`.
+///
+/// Returns `None` if the file has no bbox covering, in which case all row groups must be read.
+/// Row groups without statistics are always selected.
+pub(super) fn intersecting_row_groups(
+    metadata: &ParquetMetaData,
+    bbox: &Rect,
+) -> Result<Option<Vec<usize>>> {
+    let meta = GeoParquetMetadata::from_parquet_meta(metadata.file_metadata())?;
+    let Some(covering) = meta
+        .columns
+        .get(&meta.primary_column)
+        .and_then(|column_meta| column_meta.covering.as_ref())
+    else {
+        return Ok(None);
+    };
+
+    let row_groups = metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter(|(_, row_group)| {
+            covering
+                .bbox
+                .row_group_bounds(row_group)
+                .map_or(true, |bounds| {
+                    bounds.minx() <= bbox.max().x
+                        && bounds.maxx() >= bbox.min().x
+                        && bounds.miny() <= bbox.max().y
+                        && bounds.maxy() >= bbox.min().y
+                })
+        })
+        .map(|(i, _)| i)
+        .collect();
+    Ok(Some(row_groups))
+}
+
 pub struct GeoParquetReaderOptions {
-    batch_size: usize,
-    coord_type: CoordType,
+    pub(super) batch_size: usize,
+    pub(super) coord_type: CoordType,
+    pub(super) bbox: Option<Rect>,
+    pub(super) validation: ValidationPolicy,
 }
 
 impl GeoParquetReaderOptions {
@@ -91,16 +136,41 @@ impl GeoParquetReaderOptions {
         Self {
             batch_size,
             coord_type,
+            bbox: None,
+            validation: ValidationPolicy::default(),
         }
     }
+
+    /// Only read row groups whose bounding box intersects `bbox`.
+    ///
+    /// This relies on the bbox covering column written by
+    /// [`write_geoparquet`][crate::io::parquet::write_geoparquet]; files without one are read in
+    /// full. Note that row groups are pruned as a whole, so individual geometries outside `bbox`
+    /// may still be returned.
+    pub fn with_bbox(mut self, bbox: Rect) -> Self {
+        self.bbox = Some(bbox);
+        self
+    }
+
+    /// How to handle invalid geometries, see [`ValidationPolicy`]. Geometries are not validated
+    /// by default.
+    pub fn with_validation(mut self, validation: ValidationPolicy) -> Self {
+        self.validation = validation;
+        self
+    }
 }
 
 pub fn read_geoparquet<R: ChunkReader + 'static>(
     reader: R,
     options: GeoParquetReaderOptions,
 ) -> Result<GeoTable> {
-    let builder =
+    let mut builder =
         ParquetRecordBatchReaderBuilder::try_new(reader)?.with_batch_size(options.batch_size);
+    if let Some(bbox) = &options.bbox {
+        if let Some(row_groups) = intersecting_row_groups(builder.metadata(), bbox)? {
+            builder = builder.with_row_groups(row_groups);
+        }
+    }
 
     let (arrow_schema, geometry_column_index, target_geo_data_type) = {
         let parquet_meta = builder.metadata();
@@ -125,7 +195,119 @@ pub fn read_geoparquet<R: ChunkReader + 'static>(
         arrow_schema,
         Some(geometry_column_index),
         target_geo_data_type,
-    )
+    )?
+    .validate_geometry(options.validation)
+}
+
+/// A streaming GeoParquet reader, yielding record batches whose geometry column has been parsed
+/// from WKB to a GeoArrow-native array.
+///
+/// Created by [`read_geoparquet_batches`].
+pub struct GeoParquetRecordBatchReader {
+    reader: ParquetRecordBatchReader,
+    schema: SchemaRef,
+    geometry_column_index: usize,
+    target_geo_data_type: GeoDataType,
+    validation: ValidationPolicy,
+}
+
+impl GeoParquetRecordBatchReader {
+    fn parse_batch(&self, batch: RecordBatch) -> Result<RecordBatch> {
+        let mut columns = batch.columns().to_vec();
+        let column = &columns[self.geometry_column_index];
+        let schema = batch.schema();
+        let geometry = from_arrow_array(column, schema.field(self.geometry_column_index))?;
+        let parsed = match geometry.data_type() {
+            GeoDataType::WKB => from_wkb_with_validation(
+                geometry.as_wkb(),
+                self.target_geo_data_type,
+                true,
+                self.validation,
+            )?,
+            GeoDataType::LargeWKB => from_wkb_with_validation(
+                geometry.as_large_wkb(),
+                self.target_geo_data_type,
+                true,
+                self.validation,
+            )?,
+            _ => geometry,
+        };
+        columns[self.geometry_column_index] = parsed.to_array_ref();
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+}
+
+impl Iterator for GeoParquetRecordBatchReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.reader.next()?;
+        Some(
+            batch
+                .map_err(GeoArrowError::from)
+                .and_then(|batch| self.parse_batch(batch)),
+        )
+    }
+}
+
+impl GeoRecordBatchReader for GeoParquetRecordBatchReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn geometry_column_index(&self) -> usize {
+        self.geometry_column_index
+    }
+}
+
+/// Read a GeoParquet file as a stream of record batches of at most `batch_size` rows.
+///
+/// Unlike [`read_geoparquet`], this only holds one batch in memory at a time. Geometries are
+/// parsed to the type declared in the file's GeoParquet metadata, or to a mixed geometry array
+/// if the file declares several types. The geometry type is not narrowed per batch, so every
+/// batch has the same schema.
+pub fn read_geoparquet_batches<R: ChunkReader + 'static>(
+    reader: R,
+    options: GeoParquetReaderOptions,
+) -> Result<GeoParquetRecordBatchReader> {
+    let mut builder =
+        ParquetRecordBatchReaderBuilder::try_new(reader)?.with_batch_size(options.batch_size);
+    if let Some(bbox) = &options.bbox {
+        if let Some(row_groups) = intersecting_row_groups(builder.metadata(), bbox)? {
+            builder = builder.with_row_groups(row_groups);
+        }
+    }
+
+    let parquet_schema = builder.schema().clone();
+    let (geometry_column_index, target_geo_data_type) = parse_geoparquet_metadata(
+        builder.metadata().file_metadata(),
+        &parquet_schema,
+        options.coord_type,
+    )?;
+    let target_geo_data_type =
+        target_geo_data_type.unwrap_or(GeoDataType::LargeMixed(options.coord_type));
+
+    let geometry_field = parquet_schema.field(geometry_column_index);
+    let mut fields = parquet_schema.fields().to_vec();
+    if matches!(
+        geometry_field.data_type(),
+        DataType::Binary | DataType::LargeBinary
+    ) {
+        fields[geometry_column_index] =
+            Arc::new(target_geo_data_type.to_field(geometry_field.name(), true));
+    }
+    let schema = Arc::new(Schema::new_with_metadata(
+        fields,
+        parquet_schema.metadata().clone(),
+    ));
+
+    Ok(GeoParquetRecordBatchReader {
+        reader: builder.build()?,
+        schema,
+        geometry_column_index,
+        target_geo_data_type,
+        validation: options.validation,
+    })
 }
 
 #[cfg(test)]
@@ -139,4 +321,24 @@ mod test {
         let options = GeoParquetReaderOptions::new(65536, Default::default());
         let _output_ipc = read_geoparquet(file, options).unwrap();
     }
+
+    #[test]
+    fn nybb_batches() {
+        let file = File::open("fixtures/geoparquet/nybb.parquet").unwrap();
+        let options = GeoParquetReaderOptions::new(2, Default::default());
+        let reader = read_geoparquet_batches(file, options).unwrap();
+        let schema = reader.schema();
+        let geometry_column_index = reader.geometry_column_index();
+
+        let batches = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(batches.len(), 3);
+        assert!(batches.iter().all(|batch| batch.schema() == schema));
+
+        let table = GeoTable::try_new(schema, batches, geometry_column_index).unwrap();
+        assert_eq!(table.len(), 5);
+        assert!(matches!(
+            table.geometry_data_type().unwrap(),
+            GeoDataType::MultiPolygon(_)
+        ));
+    }
 }
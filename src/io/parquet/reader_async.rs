@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::io::parquet::reader::{
+    intersecting_row_groups, parse_geoparquet_metadata, GeoParquetReaderOptions,
+};
+use crate::table::GeoTable;
+
+use futures::TryStreamExt;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use parquet::arrow::async_reader::ParquetObjectReader;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+
+/// Asynchronously read a GeoParquet file from an [`ObjectStore`].
+///
+/// Only the byte ranges required to decode the file are fetched from the store, so this can be
+/// used to read remote (e.g. S3, GCS, or Azure) datasets without first copying them locally.
+pub async fn read_geoparquet_async(
+    store: Arc<dyn ObjectStore>,
+    location: &Path,
+    options: GeoParquetReaderOptions,
+) -> Result<GeoTable> {
+    let object_meta = store.head(location).await?;
+    let reader = ParquetObjectReader::new(store, object_meta);
+    let mut builder = ParquetRecordBatchStreamBuilder::new(reader)
+        .await?
+        .with_batch_size(options.batch_size);
+    if let Some(bbox) = &options.bbox {
+        if let Some(row_groups) = intersecting_row_groups(builder.metadata(), bbox)? {
+            builder = builder.with_row_groups(row_groups);
+        }
+    }
+
+    let (arrow_schema, geometry_column_index, target_geo_data_type) = {
+        let parquet_meta = builder.metadata();
+        let arrow_schema = builder.schema().clone();
+        let (geometry_column_index, target_geo_data_type) = parse_geoparquet_metadata(
+            parquet_meta.file_metadata(),
+            &arrow_schema,
+            options.coord_type,
+        )?;
+        (arrow_schema, geometry_column_index, target_geo_data_type)
+    };
+
+    let batches = builder.build()?.try_collect::<Vec<_>>().await?;
+
+    GeoTable::from_arrow(
+        batches,
+        arrow_schema,
+        Some(geometry_column_index),
+        target_geo_data_type,
+    )?
+    .validate_geometry(options.validation)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::parquet::read_geoparquet;
+    use object_store::memory::InMemory;
+    use std::fs::File;
+
+    #[tokio::test]
+    async fn nybb() {
+        let store = Arc::new(InMemory::new());
+        let location = Path::from("nybb.parquet");
+        let bytes = std::fs::read("fixtures/geoparquet/nybb.parquet").unwrap();
+        store.put(&location, bytes.into()).await.unwrap();
+
+        let options = GeoParquetReaderOptions::new(65536, Default::default());
+        let table = read_geoparquet_async(store, &location, options)
+            .await
+            .unwrap();
+
+        let file = File::open("fixtures/geoparquet/nybb.parquet").unwrap();
+        let options = GeoParquetReaderOptions::new(65536, Default::default());
+        let expected = read_geoparquet(file, options).unwrap();
+        assert_eq!(table.len(), expected.len());
+        assert_eq!(table.schema(), expected.schema());
+    }
+}
@@ -0,0 +1,435 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StructArray};
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::{
+    WriterProperties, DEFAULT_DICTIONARY_ENABLED, DEFAULT_MAX_ROW_GROUP_SIZE,
+};
+
+use crate::algorithm::geo::BoundingRect as _;
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::{Cast, TotalBounds};
+use crate::array::metadata::{ArrayMetadata, CrsType};
+use crate::array::{CoordType, RectArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::parquet::geoparquet_metadata::{
+    GeoParquetBboxCovering, GeoParquetColumnMetadata, GeoParquetCovering, GeoParquetMetadata,
+};
+use crate::io::wkb::to_wkb;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+/// The name of the per-row bounding box column added alongside the geometry column, unless the
+/// table already has a column of this name.
+const BBOX_COLUMN_NAME: &str = "bbox";
+
+/// How the geometry column is encoded in a GeoParquet file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoParquetWriterEncoding {
+    /// Encode geometries as Well-Known Binary. This is the most widely supported encoding.
+    #[default]
+    WKB,
+
+    /// Encode geometries using the native GeoArrow memory layout, with the given coordinate
+    /// layout. Only single-type geometry columns (e.g. Point or MultiPolygon) can be written
+    /// natively.
+    Native(CoordType),
+}
+
+/// Options for writing a GeoParquet file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoParquetWriterOptions {
+    /// The encoding of the geometry column.
+    pub encoding: GeoParquetWriterEncoding,
+
+    /// The compression codec, and where supported its level, applied to all columns.
+    ///
+    /// Codecs other than `UNCOMPRESSED` require the `parquet_compression` feature.
+    pub compression: Compression,
+
+    /// The maximum number of rows in a row group. Batches of the table larger than this are split
+    /// into multiple row groups.
+    pub max_row_group_size: usize,
+
+    /// Whether to dictionary-encode columns.
+    pub dictionary_enabled: bool,
+}
+
+impl Default for GeoParquetWriterOptions {
+    fn default() -> Self {
+        Self {
+            encoding: Default::default(),
+            compression: Compression::UNCOMPRESSED,
+            max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            dictionary_enabled: DEFAULT_DICTIONARY_ENABLED,
+        }
+    }
+}
+
+impl From<&GeoParquetWriterOptions> for WriterProperties {
+    fn from(options: &GeoParquetWriterOptions) -> Self {
+        WriterProperties::builder()
+            .set_compression(options.compression)
+            .set_max_row_group_size(options.max_row_group_size)
+            .set_dictionary_enabled(options.dictionary_enabled)
+            .build()
+    }
+}
+
+/// Write a GeoTable to a GeoParquet file.
+pub fn write_geoparquet<W: Write + Send>(table: &GeoTable, writer: W) -> Result<()> {
+    write_geoparquet_with_options(table, writer, Default::default())
+}
+
+/// Write a GeoTable to a GeoParquet file with specific writer options.
+///
+/// Each batch of the table is written as its own row group, and a `bbox` struct column with
+/// `xmin`, `ymin`, `xmax` and `ymax` children is added as a GeoParquet "covering" of the geometry
+/// column. If the table already has a `bbox` column, the covering is named `bbox_1`, `bbox_2`, …
+/// instead, whichever is first unused. Rect geometries are written as polygons. Parquet column statistics on these children hold the bounding box of each row group,
+/// which readers can use to skip row groups that do not intersect a query window.
+pub fn write_geoparquet_with_options<W: Write + Send>(
+    table: &GeoTable,
+    writer: W,
+    options: GeoParquetWriterOptions,
+) -> Result<()> {
+    let geometry_column_index = table.geometry_column_index();
+    let geometry = table.geometry()?;
+    let geometry_field = table.schema().field(geometry_column_index);
+    let (output_data_type, column_encoding) =
+        output_data_type(geometry.data_type(), options.encoding)?;
+    let bbox_column_name = bbox_column_name(table.schema());
+
+    let mut total_bounds = BoundingRect::new();
+    let mut batches = Vec::with_capacity(table.batches().len());
+    for (batch, geometry_chunk) in table.batches().iter().zip(geometry.geometry_chunks()) {
+        total_bounds.update(&geometry_chunk.total_bounds()?);
+
+        let mut columns = batch.columns().to_vec();
+        columns[geometry_column_index] = match output_data_type {
+            GeoDataType::WKB | GeoDataType::LargeWKB => encode_wkb(geometry_chunk),
+            _ => geometry_chunk.cast(&output_data_type)?.to_array_ref(),
+        };
+        columns.push(bbox_column(&geometry_chunk.bounding_rect()?));
+        batches.push(columns);
+    }
+
+    let schema = output_schema(
+        table.schema(),
+        geometry_column_index,
+        &output_data_type,
+        &bbox_column_name,
+    );
+    let geo_metadata = GeoParquetMetadata {
+        version: "1.1.0".to_string(),
+        primary_column: geometry_field.name().clone(),
+        columns: HashMap::from([(
+            geometry_field.name().clone(),
+            GeoParquetColumnMetadata {
+                encoding: column_encoding.to_string(),
+                geometry_types: geometry_types(geometry.data_type()),
+                crs: crs(&ArrayMetadata::from_field(geometry_field)?)?,
+                orientation: None,
+                edges: None,
+                bbox: (!total_bounds.is_empty()).then(|| {
+                    vec![
+                        total_bounds.minx(),
+                        total_bounds.miny(),
+                        total_bounds.maxx(),
+                        total_bounds.maxy(),
+                    ]
+                }),
+                epoch: None,
+                covering: Some(GeoParquetCovering {
+                    bbox: GeoParquetBboxCovering::new(&bbox_column_name),
+                }),
+            },
+        )]),
+    };
+
+    let props = WriterProperties::from(&options);
+    let mut writer = ArrowWriter::try_new(writer, schema.clone(), Some(props))?;
+    for columns in batches {
+        writer.write(&RecordBatch::try_new(schema.clone(), columns)?)?;
+        // Close the row group so that each batch gets its own bounding box statistics
+        writer.flush()?;
+    }
+    writer.append_key_value_metadata(KeyValue::new(
+        "geo".to_string(),
+        serde_json::to_string(&geo_metadata)?,
+    ));
+    writer.close()?;
+    Ok(())
+}
+
+/// The data type the geometry column is written as, and its GeoParquet encoding name.
+fn output_data_type(
+    data_type: &GeoDataType,
+    encoding: GeoParquetWriterEncoding,
+) -> Result<(GeoDataType, &'static str)> {
+    use GeoDataType::*;
+    let coord_type = match encoding {
+        GeoParquetWriterEncoding::WKB => match data_type {
+            LargeWKB => return Ok((LargeWKB, "WKB")),
+            _ => return Ok((WKB, "WKB")),
+        },
+        GeoParquetWriterEncoding::Native(coord_type) => coord_type,
+    };
+    let output = match data_type {
+        Point(_) => (Point(coord_type), "point"),
+        LineString(_) => (LineString(coord_type), "linestring"),
+        LargeLineString(_) => (LargeLineString(coord_type), "linestring"),
+        Polygon(_) => (Polygon(coord_type), "polygon"),
+        LargePolygon(_) => (LargePolygon(coord_type), "polygon"),
+        MultiPoint(_) => (MultiPoint(coord_type), "multipoint"),
+        LargeMultiPoint(_) => (LargeMultiPoint(coord_type), "multipoint"),
+        MultiLineString(_) => (MultiLineString(coord_type), "multilinestring"),
+        LargeMultiLineString(_) => (LargeMultiLineString(coord_type), "multilinestring"),
+        MultiPolygon(_) => (MultiPolygon(coord_type), "multipolygon"),
+        LargeMultiPolygon(_) => (LargeMultiPolygon(coord_type), "multipolygon"),
+        dt => {
+            return Err(GeoArrowError::General(format!(
+                "{:?} cannot be written with native GeoParquet encoding",
+                dt
+            )))
+        }
+    };
+    Ok(output)
+}
+
+fn encode_wkb(array: &dyn GeometryArrayTrait) -> ArrayRef {
+    match array.data_type() {
+        GeoDataType::WKB | GeoDataType::LargeWKB => array.to_array_ref(),
+        _ => to_wkb::<i32>(array).into_array_ref(),
+    }
+}
+
+/// A name for the bounding box column that doesn't collide with any column of the table.
+fn bbox_column_name(schema: &Schema) -> String {
+    let is_unused = |name: &str| schema.fields().iter().all(|field| field.name() != name);
+    if is_unused(BBOX_COLUMN_NAME) {
+        return BBOX_COLUMN_NAME.to_string();
+    }
+    (1..)
+        .map(|suffix| format!("{BBOX_COLUMN_NAME}_{suffix}"))
+        .find(|name| is_unused(name))
+        .unwrap()
+}
+
+fn bbox_fields() -> Fields {
+    ["xmin", "ymin", "xmax", "ymax"]
+        .into_iter()
+        .map(|name| Field::new(name, DataType::Float64, false))
+        .collect()
+}
+
+/// Split the interleaved bounds of a [RectArray] into a struct array of `xmin`, `ymin`, `xmax`
+/// and `ymax`.
+fn bbox_column(rects: &RectArray) -> ArrayRef {
+    let columns = (0..4)
+        .map(|dim| {
+            let values = rects.values.iter().skip(dim).step_by(4).copied();
+            Arc::new(Float64Array::from_iter_values(values)) as ArrayRef
+        })
+        .collect();
+    let nulls = rects.nulls().cloned();
+    Arc::new(StructArray::new(bbox_fields(), columns, nulls))
+}
+
+fn output_schema(
+    schema: &Schema,
+    geometry_column_index: usize,
+    geometry_data_type: &GeoDataType,
+    bbox_column_name: &str,
+) -> SchemaRef {
+    let mut fields = schema.fields().to_vec();
+    let geometry_field = &fields[geometry_column_index];
+    let output_field = match geometry_data_type {
+        GeoDataType::WKB => Field::new(geometry_field.name(), DataType::Binary, true),
+        GeoDataType::LargeWKB => Field::new(geometry_field.name(), DataType::LargeBinary, true),
+        _ => {
+            // Keep the extension metadata (e.g. the CRS) of the input geometry field
+            let mut metadata = geometry_field.metadata().clone();
+            metadata.insert(
+                "ARROW:extension:name".to_string(),
+                geometry_data_type.extension_name().to_string(),
+            );
+            geometry_data_type
+                .to_field(geometry_field.name(), true)
+                .with_metadata(metadata)
+        }
+    };
+    fields[geometry_column_index] = Arc::new(output_field);
+    fields.push(Arc::new(Field::new_struct(
+        bbox_column_name,
+        bbox_fields(),
+        true,
+    )));
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+fn geometry_types(data_type: &GeoDataType) -> Vec<String> {
+    let geometry_type = match data_type {
+        GeoDataType::Point(_) => "Point",
+        GeoDataType::LineString(_) | GeoDataType::LargeLineString(_) => "LineString",
+        GeoDataType::Polygon(_) | GeoDataType::LargePolygon(_) | GeoDataType::Rect => "Polygon",
+        GeoDataType::MultiPoint(_) | GeoDataType::LargeMultiPoint(_) => "MultiPoint",
+        GeoDataType::MultiLineString(_) | GeoDataType::LargeMultiLineString(_) => "MultiLineString",
+        GeoDataType::MultiPolygon(_) | GeoDataType::LargeMultiPolygon(_) => "MultiPolygon",
+        GeoDataType::GeometryCollection(_) | GeoDataType::LargeGeometryCollection(_) => {
+            "GeometryCollection"
+        }
+        // An empty list means the geometry types are unknown
+        _ => return vec![],
+    };
+    vec![geometry_type.to_string()]
+}
+
+fn crs(metadata: &ArrayMetadata) -> Result<Option<serde_json::Value>> {
+    match (metadata.crs(), metadata.crs_type()) {
+        (Some(crs), Some(CrsType::Projjson)) => Ok(Some(serde_json::from_str(crs)?)),
+        (Some(crs), _) => Ok(Some(serde_json::Value::String(crs.to_string()))),
+        (None, _) => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsChunkedGeometryArray, WKBArray};
+    use crate::io::parquet::{read_geoparquet, GeoParquetReaderOptions};
+    use crate::test::point;
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+    use bytes::Bytes;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    #[test]
+    fn writes_row_group_bounds() {
+        let table = point::table();
+        let mut buf = vec![];
+        write_geoparquet(&table, &mut buf).unwrap();
+        let buf = Bytes::from(buf);
+
+        let reader = SerializedFileReader::new(buf.clone()).unwrap();
+        let metadata = reader.metadata();
+        let geo_metadata = GeoParquetMetadata::from_parquet_meta(metadata.file_metadata()).unwrap();
+        let column_metadata = &geo_metadata.columns["geometry"];
+        assert_eq!(column_metadata.bbox, Some(vec![0., 1., 2., 3.]));
+
+        let covering = &column_metadata.covering.as_ref().unwrap().bbox;
+        let bounds = covering.row_group_bounds(metadata.row_group(0)).unwrap();
+        assert_eq!(
+            (bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()),
+            (0., 1., 2., 3.)
+        );
+
+        let options = GeoParquetReaderOptions::new(1024, Default::default());
+        let round_trip = read_geoparquet(buf, options).unwrap();
+        assert_eq!(round_trip.len(), table.len());
+        assert_eq!(
+            round_trip.geometry_data_type().unwrap(),
+            table.geometry_data_type().unwrap()
+        );
+    }
+
+    #[test]
+    fn prunes_row_groups_by_bbox() {
+        let table = point::table().rechunk(1).unwrap();
+        let mut buf = vec![];
+        write_geoparquet(&table, &mut buf).unwrap();
+        let buf = Bytes::from(buf);
+
+        let bbox = geo::Rect::new((0.5, 1.5), (1.5, 2.5));
+        let options = GeoParquetReaderOptions::new(1024, Default::default()).with_bbox(bbox);
+        let pruned = read_geoparquet(buf, options).unwrap();
+        assert_eq!(pruned.len(), 1);
+    }
+
+    #[test]
+    fn writes_native_encoding() {
+        let table = point::table();
+        let mut buf = vec![];
+        let options = GeoParquetWriterOptions {
+            encoding: GeoParquetWriterEncoding::Native(CoordType::Separated),
+            compression: Compression::UNCOMPRESSED,
+            max_row_group_size: 2,
+            dictionary_enabled: false,
+        };
+        write_geoparquet_with_options(&table, &mut buf, options).unwrap();
+        let buf = Bytes::from(buf);
+
+        let reader = SerializedFileReader::new(buf.clone()).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.num_row_groups(), 2);
+        let geo_metadata = GeoParquetMetadata::from_parquet_meta(metadata.file_metadata()).unwrap();
+        assert_eq!(geo_metadata.columns["geometry"].encoding, "point");
+
+        let options = GeoParquetReaderOptions::new(1024, Default::default());
+        let round_trip = read_geoparquet(buf, options).unwrap();
+        assert_eq!(
+            round_trip.geometry_data_type().unwrap(),
+            GeoDataType::Point(CoordType::Separated)
+        );
+        assert_eq!(
+            round_trip.geometry().unwrap().as_ref().as_point().chunks()[0],
+            point::point_array().into_coord_type(CoordType::Separated)
+        );
+    }
+
+    #[test]
+    fn encodes_rects_as_polygons() {
+        let rects: RectArray = vec![geo::Rect::new((0., 1.), (2., 3.))].as_slice().into();
+        let wkb = encode_wkb(&rects);
+        let wkb = WKBArray::<i32>::try_from(wkb.as_ref()).unwrap();
+        assert_eq!(
+            wkb.value_as_geo(0),
+            geo::Geometry::Polygon(geo::Rect::new((0., 1.), (2., 3.)).to_polygon())
+        );
+        assert_eq!(
+            geometry_types(rects.data_type()),
+            vec!["Polygon".to_string()]
+        );
+    }
+
+    #[test]
+    fn avoids_existing_bbox_column() {
+        let table = point::table();
+        let (schema, batches, geometry_column_index) = table.into_inner();
+        let mut fields = schema.fields().to_vec();
+        fields[0] = Arc::new(Field::new("bbox", DataType::UInt8, true));
+        let schema = Arc::new(Schema::new(fields));
+        let batches = batches
+            .into_iter()
+            .map(|batch| RecordBatch::try_new(schema.clone(), batch.columns().to_vec()).unwrap())
+            .collect();
+        let table = GeoTable::try_new(schema, batches, geometry_column_index).unwrap();
+
+        let mut buf = vec![];
+        write_geoparquet(&table, &mut buf).unwrap();
+        let buf = Bytes::from(buf);
+
+        let reader = SerializedFileReader::new(buf.clone()).unwrap();
+        let geo_metadata =
+            GeoParquetMetadata::from_parquet_meta(reader.metadata().file_metadata()).unwrap();
+        let covering = &geo_metadata.columns["geometry"]
+            .covering
+            .as_ref()
+            .unwrap()
+            .bbox;
+        assert_eq!(
+            covering.xmin,
+            vec!["bbox_1".to_string(), "xmin".to_string()]
+        );
+
+        let options = GeoParquetReaderOptions::new(1024, Default::default());
+        let round_trip = read_geoparquet(buf, options).unwrap();
+        assert_eq!(round_trip.schema().field(0).name(), "bbox");
+        assert_eq!(round_trip.len(), table.len());
+    }
+}
@@ -0,0 +1,261 @@
+//! Interoperability with [`polars`] `Series` and `DataFrame`.
+//!
+//! Arrays are exchanged through the [Arrow C Data
+//! Interface](https://arrow.apache.org/docs/format/CDataInterface.html), so no geometry data is
+//! copied. Polars does not keep Arrow field metadata, so GeoArrow extension types are dropped
+//! when exporting to polars. When importing, binary columns are interpreted as WKB and struct
+//! columns as points; any other native layout needs its [`GeoDataType`] passed explicitly.
+
+use std::sync::Arc;
+
+use arrow::ffi::{from_ffi, to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{make_array, Array, ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+use polars::export::arrow::array::Array as PolarsArray;
+use polars::export::arrow::datatypes::Field as PolarsField;
+use polars::export::arrow::ffi as polars_ffi;
+use polars::frame::DataFrame;
+use polars::series::Series;
+
+use crate::chunked_array::{from_arrow_chunks, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::table::GeoTable;
+
+/// Convert a polars array to an arrow-rs array.
+fn array_from_polars(array: Box<dyn PolarsArray>) -> Result<ArrayRef> {
+    let field = PolarsField::new("", array.data_type().clone(), true);
+    let c_schema = polars_ffi::export_field_to_c(&field);
+    let c_array = polars_ffi::export_array_to_c(array);
+
+    // SAFETY: both structs are ABI-compatible definitions of the Arrow C Data Interface.
+    let c_schema: FFI_ArrowSchema = unsafe { std::mem::transmute(c_schema) };
+    let c_array: FFI_ArrowArray = unsafe { std::mem::transmute(c_array) };
+    let data = unsafe { from_ffi(c_array, &c_schema)? };
+    Ok(make_array(data))
+}
+
+/// Convert an arrow-rs array to a polars array.
+fn array_to_polars(array: &dyn Array) -> Result<Box<dyn PolarsArray>> {
+    let (c_array, c_schema) = to_ffi(&array.to_data())?;
+
+    // SAFETY: both structs are ABI-compatible definitions of the Arrow C Data Interface.
+    let c_schema: polars_ffi::ArrowSchema = unsafe { std::mem::transmute(c_schema) };
+    let c_array: polars_ffi::ArrowArray = unsafe { std::mem::transmute(c_array) };
+    let field = unsafe { polars_ffi::import_field_from_c(&c_schema)? };
+    Ok(unsafe { polars_ffi::import_array_from_c(c_array, field.data_type)? })
+}
+
+/// Attach GeoArrow extension metadata to a field imported from polars.
+///
+/// If no data type is given, binary columns are tagged as WKB and other columns are left
+/// untagged.
+fn geometry_field(name: &str, data_type: DataType, geo_data_type: Option<GeoDataType>) -> Field {
+    let extension_name = match (geo_data_type, &data_type) {
+        (Some(geo_data_type), _) => Some(geo_data_type.extension_name()),
+        (None, DataType::Binary | DataType::LargeBinary) => Some("geoarrow.wkb"),
+        (None, _) => None,
+    };
+    let field = Field::new(name, data_type, true);
+    match extension_name {
+        Some(extension_name) => field.with_metadata(
+            [(
+                "ARROW:extension:name".to_string(),
+                extension_name.to_string(),
+            )]
+            .into(),
+        ),
+        None => field,
+    }
+}
+
+/// Create a chunked geometry array from a polars [`Series`].
+///
+/// Binary series are read as WKB and struct series as points. For other layouts, such as list
+/// columns previously exported with [`to_polars_series`], `geo_data_type` must be provided
+/// since polars does not store GeoArrow extension metadata.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::io::polars::{from_polars_series, to_polars_series};
+/// use geoarrow::chunked_array::ChunkedGeometryArray;
+/// use geoarrow::datatypes::GeoDataType;
+/// use geoarrow::array::PointArray;
+/// use geo::point;
+///
+/// let array: PointArray = vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)].as_slice().into();
+/// let chunked = ChunkedGeometryArray::new(vec![array]);
+///
+/// let series = to_polars_series("geometry", &chunked).unwrap();
+/// let geometry = from_polars_series(&series, None).unwrap();
+/// assert!(matches!(geometry.data_type(), GeoDataType::Point(_)));
+/// ```
+pub fn from_polars_series(
+    series: &Series,
+    geo_data_type: Option<GeoDataType>,
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let chunks = (0..series.n_chunks())
+        .map(|i| array_from_polars(series.to_arrow(i)))
+        .collect::<Result<Vec<_>>>()?;
+    let data_type = match chunks.first() {
+        Some(chunk) => chunk.data_type().clone(),
+        None => DataType::LargeBinary,
+    };
+    let field = geometry_field(series.name(), data_type, geo_data_type);
+
+    let chunk_refs = chunks.iter().map(|c| c.as_ref()).collect::<Vec<_>>();
+    from_arrow_chunks(chunk_refs.as_slice(), &field)
+}
+
+/// Export a chunked geometry array to a polars [`Series`], keeping its chunks.
+///
+/// The geometries keep their native Arrow layout, but lose their GeoArrow extension metadata.
+pub fn to_polars_series(name: &str, array: &dyn ChunkedGeometryArrayTrait) -> Result<Series> {
+    let chunks = array
+        .geometry_chunks()
+        .iter()
+        .map(|chunk| array_to_polars(chunk.to_array_ref().as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Series::try_from((name, chunks))?)
+}
+
+/// Create a [`GeoTable`] from a polars [`DataFrame`].
+///
+/// The column named `geometry_column` is interpreted as described in [`from_polars_series`].
+/// WKB columns are parsed to native GeoArrow geometries.
+pub fn from_polars(
+    df: &DataFrame,
+    geometry_column: &str,
+    geo_data_type: Option<GeoDataType>,
+) -> Result<GeoTable> {
+    let geometry_column_index = df.get_column_index(geometry_column).ok_or_else(|| {
+        polars::error::PolarsError::ColumnNotFound(geometry_column.to_string().into())
+    })?;
+
+    let mut df = df.clone();
+    df.align_chunks();
+
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|series| {
+            (0..series.n_chunks())
+                .map(|i| array_from_polars(series.to_arrow(i)))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let fields = df
+        .get_columns()
+        .iter()
+        .zip(columns.iter())
+        .enumerate()
+        .map(|(i, (series, chunks))| {
+            let data_type = chunks
+                .first()
+                .map(|chunk| chunk.data_type().clone())
+                .unwrap_or(DataType::Null);
+            if i == geometry_column_index {
+                geometry_field(series.name(), data_type, geo_data_type)
+            } else {
+                Field::new(series.name(), data_type, true)
+            }
+        })
+        .collect::<Vec<_>>();
+    let schema = Arc::new(Schema::new(fields));
+
+    let batches = (0..df.n_chunks())
+        .map(|i| {
+            let batch_columns = columns.iter().map(|chunks| chunks[i].clone()).collect();
+            Ok(RecordBatch::try_new(schema.clone(), batch_columns)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    GeoTable::from_arrow(batches, schema, Some(geometry_column_index), None)
+}
+
+/// Export a [`GeoTable`] to a polars [`DataFrame`].
+///
+/// Each record batch becomes one chunk of the resulting columns. The geometry column keeps its
+/// native Arrow layout but loses its GeoArrow extension metadata.
+pub fn to_polars(table: &GeoTable) -> Result<DataFrame> {
+    let columns = table
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let chunks = table
+                .batches()
+                .iter()
+                .map(|batch| array_to_polars(batch.column(i).as_ref()))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Series::try_from((field.name().as_str(), chunks))?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataFrame::new(columns)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsChunkedGeometryArray, LineStringArray, PointArray};
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::io::wkb::to_wkb;
+    use crate::test::{linestring, point};
+    use crate::GeometryArrayTrait;
+    use polars::prelude::NamedFrom;
+
+    #[test]
+    fn point_series_round_trip() {
+        let array = point::point_array();
+        let chunked = ChunkedGeometryArray::new(vec![array.clone(), array.clone()]);
+
+        let series = to_polars_series("geometry", &chunked).unwrap();
+        assert_eq!(series.len(), array.len() * 2);
+        assert_eq!(series.n_chunks(), 2);
+
+        let geometry = from_polars_series(&series, None).unwrap();
+        let chunks = geometry.as_ref().as_point().chunks().to_vec();
+        assert_eq!(chunks, vec![array.clone(), array]);
+    }
+
+    #[test]
+    fn line_string_series_round_trip() {
+        let array: LineStringArray<i64> =
+            vec![linestring::ls0(), linestring::ls1()].as_slice().into();
+        let chunked = ChunkedGeometryArray::new(vec![array.clone()]);
+
+        let series = to_polars_series("geometry", &chunked).unwrap();
+        assert!(from_polars_series(&series, None).is_err());
+
+        let geometry = from_polars_series(&series, Some(*array.data_type())).unwrap();
+        assert_eq!(geometry.as_ref().as_large_line_string().chunks()[0], array);
+    }
+
+    #[test]
+    fn wkb_data_frame_to_geo_table() {
+        let array: PointArray = point::point_array();
+        let wkb_array = to_wkb::<i64>(&array);
+        let geometry = Series::try_from((
+            "geometry",
+            array_to_polars(&wkb_array.into_inner()).unwrap(),
+        ))
+        .unwrap();
+        let ids = Series::new("id", &[1i32, 2, 3]);
+        let df = DataFrame::new(vec![ids, geometry]).unwrap();
+
+        let table = from_polars(&df, "geometry", None).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.num_columns(), 2);
+        assert!(matches!(
+            table.geometry_data_type().unwrap(),
+            GeoDataType::Point(_)
+        ));
+
+        let df = to_polars(&table).unwrap();
+        assert_eq!(df.get_column_names(), vec!["id", "geometry"]);
+        assert_eq!(df.height(), 3);
+    }
+}
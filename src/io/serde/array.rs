@@ -0,0 +1,211 @@
+use arrow_array::OffsetSizeTrait;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::array::*;
+use crate::io::serde::geometry::GeoJsonGeometry;
+use crate::trait_::GeometryArrayAccessor;
+
+/// Serialize geometries as a list of GeoJSON geometry objects, with `null` for null geometries.
+fn serialize_geometries<S: Serializer>(
+    geometries: impl Iterator<Item = Option<geo::Geometry>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        geometries.map(|maybe_geometry| maybe_geometry.as_ref().map(GeoJsonGeometry::from)),
+    )
+}
+
+/// Deserialize a list of GeoJSON geometry objects, with `null` for null geometries.
+fn deserialize_geometries<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Option<geo::Geometry>>, D::Error> {
+    let geometries = Vec::<Option<GeoJsonGeometry>>::deserialize(deserializer)?;
+    geometries
+        .iter()
+        .map(|maybe_geometry| {
+            maybe_geometry
+                .as_ref()
+                .map(geo::Geometry::try_from)
+                .transpose()
+                .map_err(D::Error::custom)
+        })
+        .collect()
+}
+
+/// Deserialize a list of GeoJSON geometry objects that must all be of the geometry type `G`.
+fn deserialize_typed_geometries<'de, D, G>(deserializer: D) -> Result<Vec<Option<G>>, D::Error>
+where
+    D: Deserializer<'de>,
+    G: TryFrom<geo::Geometry>,
+    G::Error: std::fmt::Display,
+{
+    deserialize_geometries(deserializer)?
+        .into_iter()
+        .map(|maybe_geometry| {
+            maybe_geometry
+                .map(G::try_from)
+                .transpose()
+                .map_err(D::Error::custom)
+        })
+        .collect()
+}
+
+impl Serialize for PointArray {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_geometries(
+            self.iter_geo().map(|g| g.map(geo::Geometry::from)),
+            serializer,
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for PointArray {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let geometries: Vec<Option<geo::Point>> = deserialize_typed_geometries(deserializer)?;
+        Ok(geometries.into())
+    }
+}
+
+/// Rects have no GeoJSON representation and are serialized as polygons.
+impl Serialize for RectArray {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_geometries(
+            self.iter_geo().map(|g| g.map(geo::Geometry::from)),
+            serializer,
+        )
+    }
+}
+
+macro_rules! impl_serde {
+    ($array:ty, $geo:ty) => {
+        impl<O: OffsetSizeTrait> Serialize for $array {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_geometries(
+                    self.iter_geo().map(|g| g.map(geo::Geometry::from)),
+                    serializer,
+                )
+            }
+        }
+
+        impl<'de, O: OffsetSizeTrait> Deserialize<'de> for $array {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let geometries: Vec<Option<$geo>> = deserialize_typed_geometries(deserializer)?;
+                Ok(geometries.into())
+            }
+        }
+    };
+}
+
+impl_serde!(LineStringArray<O>, geo::LineString);
+impl_serde!(PolygonArray<O>, geo::Polygon);
+impl_serde!(MultiPointArray<O>, geo::MultiPoint);
+impl_serde!(MultiLineStringArray<O>, geo::MultiLineString);
+impl_serde!(MultiPolygonArray<O>, geo::MultiPolygon);
+
+impl<O: OffsetSizeTrait> Serialize for GeometryCollectionArray<O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_geometries(
+            self.iter_geo()
+                .map(|g| g.map(geo::Geometry::GeometryCollection)),
+            serializer,
+        )
+    }
+}
+
+impl<'de, O: OffsetSizeTrait> Deserialize<'de> for GeometryCollectionArray<O> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let geometries = deserialize_geometries(deserializer)?
+            .into_iter()
+            .map(|maybe_geometry| match maybe_geometry {
+                Some(geo::Geometry::GeometryCollection(collection)) => Ok(Some(collection)),
+                Some(_) => Err(D::Error::custom("Expected a GeometryCollection")),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(geometries.into())
+    }
+}
+
+impl<O: OffsetSizeTrait> Serialize for MixedGeometryArray<O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_geometries(self.iter_geo(), serializer)
+    }
+}
+
+impl<'de, O: OffsetSizeTrait> Deserialize<'de> for MixedGeometryArray<O> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let geometries = deserialize_geometries(deserializer)?;
+        let builder = MixedGeometryBuilder::<O>::from_nullable_geometries(
+            &geometries,
+            None,
+            Default::default(),
+            false,
+        )
+        .map_err(D::Error::custom)?;
+        Ok(builder.into())
+    }
+}
+
+impl<O: OffsetSizeTrait> Serialize for WKBArray<O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_geometries(self.iter_geo(), serializer)
+    }
+}
+
+impl<'de, O: OffsetSizeTrait> Deserialize<'de> for WKBArray<O> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let geometries = deserialize_geometries(deserializer)?;
+        WKBArray::try_from(geometries.as_slice()).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipolygon, polygon};
+    use geo::point;
+
+    #[test]
+    fn roundtrip_polygons() {
+        let array = polygon::p_array();
+        let json = serde_json::to_string(&array).unwrap();
+        let parsed: PolygonArray<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, array);
+
+        let array = multipolygon::mp_array();
+        let json = serde_json::to_string(&array).unwrap();
+        let parsed: MultiPolygonArray<i64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.value_as_geo(1), array.value_as_geo(1));
+    }
+
+    #[test]
+    fn mixed_and_nulls() {
+        let json = r#"[
+            {"type": "Point", "coordinates": [1, 2, 3]},
+            null,
+            {"type": "LineString", "coordinates": [[0, 0], [1, 1]]}
+        ]"#;
+        let array: MixedGeometryArray<i32> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            array.value_as_geo(0),
+            geo::Geometry::Point(point!(x: 1., y: 2.))
+        );
+        assert!(array.get(1).is_none());
+
+        let scalar_json = serde_json::to_string(&array.value(2)).unwrap();
+        assert_eq!(
+            scalar_json,
+            r#"{"type":"LineString","coordinates":[[0.0,0.0],[1.0,1.0]]}"#
+        );
+    }
+
+    #[test]
+    fn wrong_geometry_type() {
+        let json = r#"[{"type": "LineString", "coordinates": [[0, 0], [1, 1]]}]"#;
+        assert!(serde_json::from_str::<PointArray>(json).is_err());
+
+        let json = r#"[{"type": "Point", "coordinates": [0]}]"#;
+        assert!(serde_json::from_str::<PointArray>(json).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+/// A GeoJSON position. Positions may have more than two elements, but only x and y are used.
+type Position = Vec<f64>;
+
+/// A GeoJSON geometry object, used as the intermediate representation for serde.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum GeoJsonGeometry {
+    Point {
+        coordinates: Position,
+    },
+    LineString {
+        coordinates: Vec<Position>,
+    },
+    Polygon {
+        coordinates: Vec<Vec<Position>>,
+    },
+    MultiPoint {
+        coordinates: Vec<Position>,
+    },
+    MultiLineString {
+        coordinates: Vec<Vec<Position>>,
+    },
+    MultiPolygon {
+        coordinates: Vec<Vec<Vec<Position>>>,
+    },
+    GeometryCollection {
+        geometries: Vec<GeoJsonGeometry>,
+    },
+}
+
+fn coord_to_position(coord: &geo::Coord) -> Position {
+    vec![coord.x, coord.y]
+}
+
+fn line_string_to_positions(line_string: &geo::LineString) -> Vec<Position> {
+    line_string.coords().map(coord_to_position).collect()
+}
+
+fn polygon_to_positions(polygon: &geo::Polygon) -> Vec<Vec<Position>> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(line_string_to_positions)
+        .collect()
+}
+
+impl From<&geo::Geometry> for GeoJsonGeometry {
+    fn from(geometry: &geo::Geometry) -> Self {
+        match geometry {
+            geo::Geometry::Point(point) => GeoJsonGeometry::Point {
+                coordinates: coord_to_position(&point.0),
+            },
+            geo::Geometry::Line(line) => GeoJsonGeometry::LineString {
+                coordinates: vec![coord_to_position(&line.start), coord_to_position(&line.end)],
+            },
+            geo::Geometry::LineString(line_string) => GeoJsonGeometry::LineString {
+                coordinates: line_string_to_positions(line_string),
+            },
+            geo::Geometry::Polygon(polygon) => GeoJsonGeometry::Polygon {
+                coordinates: polygon_to_positions(polygon),
+            },
+            geo::Geometry::MultiPoint(multi_point) => GeoJsonGeometry::MultiPoint {
+                coordinates: multi_point
+                    .iter()
+                    .map(|point| coord_to_position(&point.0))
+                    .collect(),
+            },
+            geo::Geometry::MultiLineString(multi_line_string) => GeoJsonGeometry::MultiLineString {
+                coordinates: multi_line_string
+                    .iter()
+                    .map(line_string_to_positions)
+                    .collect(),
+            },
+            geo::Geometry::MultiPolygon(multi_polygon) => GeoJsonGeometry::MultiPolygon {
+                coordinates: multi_polygon.iter().map(polygon_to_positions).collect(),
+            },
+            geo::Geometry::GeometryCollection(collection) => GeoJsonGeometry::GeometryCollection {
+                geometries: collection.iter().map(GeoJsonGeometry::from).collect(),
+            },
+            geo::Geometry::Rect(rect) => GeoJsonGeometry::Polygon {
+                coordinates: polygon_to_positions(&rect.to_polygon()),
+            },
+            geo::Geometry::Triangle(triangle) => GeoJsonGeometry::Polygon {
+                coordinates: polygon_to_positions(&triangle.to_polygon()),
+            },
+        }
+    }
+}
+
+fn position_to_coord(position: &Position) -> Result<geo::Coord, String> {
+    match position.as_slice() {
+        [x, y, ..] => Ok(geo::Coord { x: *x, y: *y }),
+        _ => Err(format!(
+            "A position must have at least two elements, found {}",
+            position.len()
+        )),
+    }
+}
+
+fn positions_to_line_string(positions: &[Position]) -> Result<geo::LineString, String> {
+    positions
+        .iter()
+        .map(position_to_coord)
+        .collect::<Result<Vec<_>, _>>()
+        .map(geo::LineString::new)
+}
+
+fn positions_to_polygon(rings: &[Vec<Position>]) -> Result<geo::Polygon, String> {
+    let mut rings = rings
+        .iter()
+        .map(|ring| positions_to_line_string(ring))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter();
+    let exterior = rings.next().unwrap_or_else(|| geo::LineString::new(vec![]));
+    Ok(geo::Polygon::new(exterior, rings.collect()))
+}
+
+impl TryFrom<&GeoJsonGeometry> for geo::Geometry {
+    type Error = String;
+
+    fn try_from(geometry: &GeoJsonGeometry) -> Result<Self, Self::Error> {
+        let geometry = match geometry {
+            GeoJsonGeometry::Point { coordinates } => {
+                geo::Geometry::Point(position_to_coord(coordinates)?.into())
+            }
+            GeoJsonGeometry::LineString { coordinates } => {
+                geo::Geometry::LineString(positions_to_line_string(coordinates)?)
+            }
+            GeoJsonGeometry::Polygon { coordinates } => {
+                geo::Geometry::Polygon(positions_to_polygon(coordinates)?)
+            }
+            GeoJsonGeometry::MultiPoint { coordinates } => geo::Geometry::MultiPoint(
+                coordinates
+                    .iter()
+                    .map(|position| position_to_coord(position).map(geo::Point::from))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into(),
+            ),
+            GeoJsonGeometry::MultiLineString { coordinates } => {
+                geo::Geometry::MultiLineString(geo::MultiLineString::new(
+                    coordinates
+                        .iter()
+                        .map(|line_string| positions_to_line_string(line_string))
+                        .collect::<Result<Vec<_>, _>>()?,
+                ))
+            }
+            GeoJsonGeometry::MultiPolygon { coordinates } => {
+                geo::Geometry::MultiPolygon(geo::MultiPolygon::new(
+                    coordinates
+                        .iter()
+                        .map(|polygon| positions_to_polygon(polygon))
+                        .collect::<Result<Vec<_>, _>>()?,
+                ))
+            }
+            GeoJsonGeometry::GeometryCollection { geometries } => {
+                geo::Geometry::GeometryCollection(geo::GeometryCollection::new_from(
+                    geometries
+                        .iter()
+                        .map(geo::Geometry::try_from)
+                        .collect::<Result<Vec<_>, _>>()?,
+                ))
+            }
+        };
+        Ok(geometry)
+    }
+}
@@ -0,0 +1,22 @@
+//! [`serde`] support for geometry arrays and scalars, using the [GeoJSON geometry
+//! representation](https://datatracker.ietf.org/doc/html/rfc7946#section-3.1).
+//!
+//! Scalars serialize to a single GeoJSON geometry object, and arrays serialize to a list of
+//! geometry objects, with `null` for null geometries. Arrays can also be deserialized from such a
+//! list. Scalars are references onto an array, so they can only be serialized.
+//!
+//! ```
+//! use geoarrow::array::PointArray;
+//! use geo::point;
+//!
+//! let array: PointArray = vec![Some(point!(x: 1., y: 2.)), None].into();
+//! let json = serde_json::to_string(&array).unwrap();
+//! assert_eq!(json, r#"[{"type":"Point","coordinates":[1.0,2.0]},null]"#);
+//!
+//! let parsed: PointArray = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed, array);
+//! ```
+
+mod array;
+mod geometry;
+mod scalar;
@@ -0,0 +1,47 @@
+use arrow_array::OffsetSizeTrait;
+use serde::{Serialize, Serializer};
+
+use crate::io::serde::geometry::GeoJsonGeometry;
+use crate::scalar::*;
+use crate::trait_::GeometryScalarTrait;
+
+macro_rules! impl_serialize {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let geometry = geo::Geometry::from(self.to_geo());
+                GeoJsonGeometry::from(&geometry).serialize(serializer)
+            }
+        }
+    };
+}
+
+impl_serialize!(LineString<'_, O>);
+impl_serialize!(Polygon<'_, O>);
+impl_serialize!(MultiPoint<'_, O>);
+impl_serialize!(MultiLineString<'_, O>);
+impl_serialize!(MultiPolygon<'_, O>);
+impl_serialize!(Geometry<'_, O>);
+impl_serialize!(WKB<'_, O>);
+
+impl<O: OffsetSizeTrait> Serialize for GeometryCollection<'_, O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let geometry = geo::Geometry::GeometryCollection(self.to_geo());
+        GeoJsonGeometry::from(&geometry).serialize(serializer)
+    }
+}
+
+impl Serialize for Point<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let geometry = geo::Geometry::Point(self.to_geo());
+        GeoJsonGeometry::from(&geometry).serialize(serializer)
+    }
+}
+
+/// Rects have no GeoJSON representation and are serialized as polygons.
+impl Serialize for Rect<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let geometry = geo::Geometry::Rect(self.to_geo());
+        GeoJsonGeometry::from(&geometry).serialize(serializer)
+    }
+}
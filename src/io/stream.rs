@@ -0,0 +1,29 @@
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+
+use crate::error::Result;
+use crate::table::GeoTable;
+
+/// A reader that yields a dataset as a sequence of record batches with a common schema, one of
+/// whose columns holds GeoArrow geometries.
+///
+/// Streaming readers decode one batch at a time, so large files can be processed with memory
+/// bounded by the batch size.
+pub trait GeoRecordBatchReader: Iterator<Item = Result<RecordBatch>> {
+    /// The schema of every batch yielded by this reader.
+    fn schema(&self) -> SchemaRef;
+
+    /// The index of the geometry column in [`schema`][Self::schema].
+    fn geometry_column_index(&self) -> usize;
+
+    /// Read all remaining batches into a [`GeoTable`].
+    fn read_table(self) -> Result<GeoTable>
+    where
+        Self: Sized,
+    {
+        let schema = self.schema();
+        let geometry_column_index = self.geometry_column_index();
+        let batches = self.collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(schema, batches, geometry_column_index)
+    }
+}
@@ -2,13 +2,18 @@ use std::sync::Arc;
 
 use crate::algorithm::native::Downcast;
 use crate::array::geometrycollection::GeometryCollectionBuilder;
+use crate::array::validation;
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::linearize_wkb;
+use crate::io::wkb::reader::r#type::{check_no_curves, infer_wkb_array_type};
+use crate::io::wkb::writer::{transcode_wkb, WKBWriteOptions};
 use crate::scalar::WKB;
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
+use arrow_array::builder::GenericBinaryBuilder;
 use arrow_array::OffsetSizeTrait;
 
 /// An optimized implementation of converting from ISO WKB-encoded geometries.
@@ -146,6 +151,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
 ) -> Result<Arc<dyn GeometryArrayTrait>> {
     use GeoDataType::*;
 
+    check_no_curves(arr)?;
     let wkb_objects: Vec<Option<crate::scalar::WKB<'_, O>>> = arr.iter().collect();
     match target_geo_data_type {
         Point(coord_type) => {
@@ -257,6 +263,139 @@ pub fn from_wkb<O: OffsetSizeTrait>(
     }
 }
 
+/// Parse each chunk of an ISO [`ChunkedWKBArray`] to a GeoArrow native chunked array.
+///
+/// This is [`from_wkb`] applied chunk by chunk, keeping the chunk boundaries of the input. If
+/// the `rayon` feature is active, chunks are parsed in parallel.
+///
+/// Does not downcast automatically
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::{CoordType, WKBArray};
+/// use geoarrow::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+/// use geoarrow::datatypes::GeoDataType;
+/// use geoarrow::io::wkb::from_chunked_wkb;
+/// use geo::{point, Geometry};
+///
+/// let chunk: WKBArray<i32> = vec![Some(Geometry::Point(point!(x: 1., y: 2.)))]
+///     .as_slice()
+///     .try_into()
+///     .unwrap();
+/// let chunked = ChunkedGeometryArray::new(vec![chunk.clone(), chunk]);
+///
+/// let parsed = from_chunked_wkb(&chunked, GeoDataType::Point(CoordType::Interleaved), false)
+///     .unwrap();
+/// assert_eq!(parsed.num_chunks(), 2);
+/// ```
+pub fn from_chunked_wkb<O: OffsetSizeTrait>(
+    arr: &ChunkedWKBArray<O>,
+    target_geo_data_type: GeoDataType,
+    prefer_multi: bool,
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let parsed_chunks =
+        arr.try_par_map(|chunk| from_wkb(chunk, target_geo_data_type, prefer_multi))?;
+    let parsed_chunks_refs = parsed_chunks
+        .iter()
+        .map(|chunk| chunk.as_ref())
+        .collect::<Vec<_>>();
+    from_geoarrow_chunks(parsed_chunks_refs.as_slice())
+}
+
+/// Options for [`from_wkb_inferred`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WKBReadOptions {
+    /// The coordinate layout of the output array. Defaults to interleaved.
+    pub coord_type: CoordType,
+
+    /// Whether to store single geometries as multi geometries, e.g. points as a
+    /// [`MultiPointArray`], and in mixed arrays. Defaults to `false`.
+    pub prefer_multi: bool,
+
+    /// If set, curved geometries such as circular strings are
+    /// [linearized][crate::io::wkb::linearize_wkb] before parsing, with at most this angle in
+    /// radians between consecutive points on an arc. Otherwise curved geometries are an error.
+    /// Defaults to `None`.
+    pub linearize_max_angle: Option<f64>,
+}
+
+/// Parse an ISO [WKBArray] to the simplest GeoArrow native array that can hold all of its
+/// geometries.
+///
+/// The geometry types are inferred by [scanning][crate::io::wkb::scan_wkb] the WKB headers,
+/// without reading coordinates, before the geometries are parsed in a single pass. An array
+/// that only holds one kind of geometry is parsed to the matching concrete array, where a mix of
+/// single and multi geometries of the same kind, such as polygons and multi polygons, is parsed
+/// to the multi array. Other combinations are parsed to a [`MixedGeometryArray`], or a
+/// [`GeometryCollectionArray`] if there are geometry collections. An array without any non-null
+/// geometries is parsed to a [`MixedGeometryArray`].
+///
+/// Offsets are 64-bit if those of the input are.
+///
+/// # Errors
+///
+/// - if any geometry is not valid WKB
+/// - if there are curved geometries and [`WKBReadOptions::linearize_max_angle`] is not set
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::WKBArray;
+/// use geoarrow::datatypes::GeoDataType;
+/// use geoarrow::io::wkb::{from_wkb_inferred, WKBReadOptions};
+/// use geo::{line_string, Geometry, MultiLineString};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+/// let array: WKBArray<i32> = vec![
+///     Some(Geometry::LineString(line_string.clone())),
+///     Some(Geometry::MultiLineString(MultiLineString::new(vec![line_string]))),
+/// ]
+/// .as_slice()
+/// .try_into()
+/// .unwrap();
+///
+/// let parsed = from_wkb_inferred(&array, &WKBReadOptions::default()).unwrap();
+/// assert!(matches!(parsed.data_type(), GeoDataType::MultiLineString(_)));
+/// ```
+pub fn from_wkb_inferred<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    options: &WKBReadOptions,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    if let Some(max_angle) = options.linearize_max_angle {
+        let linearized = linearize_wkb(arr, max_angle)?;
+        return from_wkb_inferred(
+            &linearized,
+            &WKBReadOptions {
+                linearize_max_angle: None,
+                ..*options
+            },
+        );
+    }
+
+    let data_type =
+        infer_wkb_array_type(arr, O::IS_LARGE, options.coord_type, options.prefer_multi)?
+            .unwrap_or(match O::IS_LARGE {
+                true => GeoDataType::LargeMixed(options.coord_type),
+                false => GeoDataType::Mixed(options.coord_type),
+            });
+    from_wkb(arr, data_type, options.prefer_multi)
+}
+
+/// Parse an ISO [WKBArray] to a GeometryArray with GeoArrow native encoding, applying a
+/// [`ValidationPolicy`] to the parsed geometries.
+///
+/// Does not downcast automatically
+pub fn from_wkb_with_validation<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    target_geo_data_type: GeoDataType,
+    prefer_multi: bool,
+    validation: ValidationPolicy,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let parsed = from_wkb(arr, target_geo_data_type, prefer_multi)?;
+    validation::apply_policy(parsed, validation)
+}
+
 /// Convert a geometry array to a [WKBArray].
 pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn GeometryArrayTrait) -> WKBArray<O> {
     match arr.data_type() {
@@ -333,8 +472,56 @@ pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn GeometryArrayTrait) -> WKBArray<O> {
             .into(),
         GeoDataType::WKB => todo!(),
         GeoDataType::LargeWKB => todo!(),
-        GeoDataType::Rect => todo!(),
+        GeoDataType::Rect => {
+            // WKB has no rectangle type, so rects are written as polygons
+            let rects = arr.as_any().downcast_ref::<RectArray>().unwrap();
+            (&PolygonArray::<i32>::from(rects.clone())).into()
+        }
+    }
+}
+
+/// Convert a geometry array to a [WKBArray], choosing the byte order and WKB flavor.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::{PointArray, WKBArray};
+/// use geoarrow::io::wkb::{to_wkb_with_options, Endianness, WKBFlavor, WKBWriteOptions};
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::point;
+///
+/// let array: PointArray = vec![point!(x: 1., y: 2.)].as_slice().into();
+/// let options = WKBWriteOptions {
+///     byte_order: Endianness::BigEndian,
+///     flavor: WKBFlavor::Extended { srid: Some(4326) },
+/// };
+/// let wkb_array: WKBArray<i32> = to_wkb_with_options(&array, &options);
+/// assert_eq!(wkb_array.value(0).as_ref()[..9], [0, 0x20, 0, 0, 1, 0, 0, 0x10, 0xe6]);
+/// ```
+pub fn to_wkb_with_options<O: OffsetSizeTrait>(
+    arr: &dyn GeometryArrayTrait,
+    options: &WKBWriteOptions,
+) -> WKBArray<O> {
+    let wkb_array = to_wkb::<O>(arr);
+    if *options == WKBWriteOptions::default() {
+        return wkb_array;
+    }
+
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(
+        wkb_array.len(),
+        wkb_array.buffer_lengths().buffer_capacity,
+    );
+    let mut buf = Vec::new();
+    for maybe_wkb in wkb_array.array.iter() {
+        if let Some(wkb) = maybe_wkb {
+            buf.clear();
+            transcode_wkb(wkb, options, &mut buf);
+            builder.append_value(&buf);
+        } else {
+            builder.append_null();
+        }
     }
+    WKBArray::new(builder.finish(), wkb_array.metadata())
 }
 
 #[cfg(test)]
@@ -367,4 +554,72 @@ mod test {
         let rt_point_arr = downcasted_ref.as_point();
         assert_eq!(&arr, rt_point_arr);
     }
+
+    #[test]
+    fn inferred_types() {
+        use crate::test::{linestring, polygon};
+
+        let arr = point::point_array();
+        let wkb_arr: WKBArray<i64> = to_wkb(&arr);
+        let parsed = from_wkb_inferred(&wkb_arr, &WKBReadOptions::default()).unwrap();
+        assert_eq!(&arr, parsed.as_ref().as_point());
+
+        let options = WKBReadOptions {
+            prefer_multi: true,
+            ..Default::default()
+        };
+        let parsed = from_wkb_inferred(&wkb_arr, &options).unwrap();
+        assert!(matches!(
+            parsed.data_type(),
+            GeoDataType::LargeMultiPoint(_)
+        ));
+
+        let geoms = vec![
+            Some(geo::Geometry::Point(point::p0())),
+            None,
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            Some(geo::Geometry::Polygon(polygon::p0())),
+        ];
+        let wkb_arr: WKBArray<i32> = geoms.as_slice().try_into().unwrap();
+        let parsed = from_wkb_inferred(&wkb_arr, &WKBReadOptions::default()).unwrap();
+        assert!(matches!(parsed.data_type(), GeoDataType::Mixed(_)));
+        assert_eq!(parsed.len(), 4);
+        assert!(parsed.is_null(1));
+
+        let geoms: Vec<Option<geo::Geometry>> = vec![None; 2];
+        let wkb_arr: WKBArray<i32> = geoms.as_slice().try_into().unwrap();
+        let parsed = from_wkb_inferred(&wkb_arr, &WKBReadOptions::default()).unwrap();
+        assert!(matches!(parsed.data_type(), GeoDataType::Mixed(_)));
+    }
+
+    #[test]
+    fn write_options() {
+        use crate::io::wkb::{scan_wkb, Endianness, WKBDimension, WKBFlavor};
+        use crate::test::multipolygon::mp_array;
+
+        let arr = mp_array();
+        let options = WKBWriteOptions {
+            byte_order: Endianness::BigEndian,
+            flavor: WKBFlavor::Iso,
+        };
+        let wkb_arr: WKBArray<i32> = to_wkb_with_options(&arr, &options);
+        let roundtrip = from_wkb(
+            &wkb_arr,
+            GeoDataType::MultiPolygon(CoordType::Interleaved),
+            true,
+        )
+        .unwrap();
+        assert_eq!(&arr, roundtrip.as_ref().as_multi_polygon());
+
+        let options = WKBWriteOptions {
+            byte_order: Endianness::LittleEndian,
+            flavor: WKBFlavor::Extended { srid: Some(4326) },
+        };
+        let wkb_arr: WKBArray<i32> = to_wkb_with_options(&arr, &options);
+        for header in scan_wkb(&wkb_arr) {
+            let header = header.unwrap().unwrap();
+            assert_eq!(header.byte_order, Endianness::LittleEndian);
+            assert_eq!(header.dimension, WKBDimension::XY);
+        }
+    }
 }
@@ -0,0 +1,599 @@
+//! Convert the curved geometry types of ISO SQL/MM in WKB arrays to linear geometries.
+
+use std::f64::consts::PI;
+
+use arrow_array::builder::GenericBinaryBuilder;
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::reader::{Endianness, WKBGeometryType};
+use crate::io::wkb::scan::{decode_type_code, WKBDimension, MAX_NESTING_DEPTH};
+use crate::GeometryArrayTrait;
+
+/// The maximum number of segments a single arc may be linearized into.
+const MAX_ARC_SEGMENTS: usize = 1 << 20;
+
+/// Approximate the curved geometries of a WKB array, such as those written by SQL Server, Oracle
+/// or PostGIS, with linear geometries, so that the array can be parsed with
+/// [`from_wkb`][crate::io::wkb::from_wkb].
+///
+/// Each circular arc is replaced by the line string of points on the arc, spaced so that the
+/// angle between consecutive points, seen from the center of the arc, is at most `max_angle`
+/// radians. A `CircularString` or `CompoundCurve` becomes a `LineString`, a `CurvePolygon` a
+/// `Polygon`, a `MultiCurve` a `MultiLineString` and a `MultiSurface` a `MultiPolygon`. Curves
+/// nested in geometry collections are linearized too, and linear geometries are kept as they
+/// are.
+///
+/// The output is ISO WKB in little endian byte order, so extended WKB headers and SRIDs are
+/// dropped.
+///
+/// # Errors
+///
+/// - if `max_angle` is not positive
+/// - if any geometry is not valid WKB
+/// - if a curve has a dimension other than XY, or a circular string has an even number of points
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::BinaryArray;
+/// use geoarrow::array::{LineStringArray, WKBArray};
+/// use geoarrow::io::wkb::{linearize_wkb, FromWKB};
+/// use geoarrow::trait_::GeometryArrayAccessor;
+/// use geo::CoordsIter;
+///
+/// // CIRCULARSTRING (0 0, 1 1, 2 0), a half circle
+/// let mut buf = vec![1];
+/// buf.extend(8u32.to_le_bytes());
+/// buf.extend(3u32.to_le_bytes());
+/// [0f64, 0., 1., 1., 2., 0.]
+///     .iter()
+///     .for_each(|v| buf.extend(v.to_le_bytes()));
+/// let array: WKBArray<i32> = WKBArray::new(BinaryArray::from(vec![buf.as_slice()]), Default::default());
+///
+/// let linearized = linearize_wkb(&array, std::f64::consts::PI / 8.).unwrap();
+/// let lines = LineStringArray::<i32>::from_wkb(&linearized, Default::default()).unwrap();
+/// assert_eq!(lines.value_as_geo(0).coords_count(), 9);
+/// ```
+pub fn linearize_wkb<O: OffsetSizeTrait>(arr: &WKBArray<O>, max_angle: f64) -> Result<WKBArray<O>> {
+    if max_angle.is_nan() || max_angle <= 0. {
+        return Err(GeoArrowError::General(format!(
+            "Maximum angle must be positive, got {max_angle}"
+        )));
+    }
+
+    let mut builder =
+        GenericBinaryBuilder::<O>::with_capacity(arr.len(), arr.buffer_lengths().buffer_capacity);
+    let mut out = Vec::new();
+    for geom_idx in 0..arr.len() {
+        if arr.is_null(geom_idx) {
+            builder.append_null();
+            continue;
+        }
+        out.clear();
+        let mut linearizer = Linearizer {
+            buf: arr.array.value(geom_idx),
+            offset: 0,
+            depth: 0,
+            max_angle,
+            out: &mut out,
+        };
+        linearizer.geometry()?;
+        if linearizer.offset != linearizer.buf.len() {
+            return Err(GeoArrowError::General(format!(
+                "WKB buffer has {} unexpected trailing bytes",
+                linearizer.buf.len() - linearizer.offset
+            )));
+        }
+        builder.append_value(&out);
+    }
+    Ok(WKBArray::new(builder.finish(), arr.metadata()))
+}
+
+/// The header of a geometry nested in a curve, which must be XY.
+struct CurveHeader {
+    geometry_type: WKBGeometryType,
+    byte_order: Endianness,
+}
+
+struct Linearizer<'a> {
+    buf: &'a [u8],
+    offset: usize,
+    /// The nesting depth of the geometry being read.
+    depth: usize,
+    max_angle: f64,
+    out: &'a mut Vec<u8>,
+}
+
+impl<'a> Linearizer<'a> {
+    fn take(&mut self, num_bytes: usize) -> Result<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(num_bytes)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "truncated WKB buffer: needed {} bytes at offset {}, but the buffer has {}",
+                    num_bytes,
+                    self.offset,
+                    self.buf.len()
+                ))
+            })?;
+        let bytes = &self.buf[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self, byte_order: Endianness) -> Result<u32> {
+        let bytes: [u8; 4] = self.take(4)?.try_into().unwrap();
+        Ok(match byte_order {
+            Endianness::BigEndian => u32::from_be_bytes(bytes),
+            Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_header(&mut self, geometry_type: WKBGeometryType, dimension: WKBDimension) {
+        let dimension_code = match dimension {
+            WKBDimension::XY => 0,
+            WKBDimension::XYZ => 1000,
+            WKBDimension::XYM => 2000,
+            WKBDimension::XYZM => 3000,
+        };
+        self.out.push(Endianness::LittleEndian.into());
+        self.write_u32(geometry_type as u32 + dimension_code);
+    }
+
+    fn write_coords(&mut self, coords: &[[f64; 2]]) {
+        self.write_u32(coords.len() as u32);
+        for [x, y] in coords {
+            self.out.extend_from_slice(&x.to_le_bytes());
+            self.out.extend_from_slice(&y.to_le_bytes());
+        }
+    }
+
+    /// Read the byte order and type of a geometry, skipping its SRID if it has one.
+    fn read_header(&mut self) -> Result<(WKBGeometryType, WKBDimension, Endianness)> {
+        let byte_order = match self.take(1)?[0] {
+            0 => Endianness::BigEndian,
+            1 => Endianness::LittleEndian,
+            other => {
+                return Err(GeoArrowError::General(format!(
+                    "invalid WKB byte order {other}"
+                )))
+            }
+        };
+        let (geometry_type, dimension, has_srid) = decode_type_code(self.read_u32(byte_order)?)?;
+        if has_srid {
+            self.take(4)?;
+        }
+        Ok((geometry_type, dimension, byte_order))
+    }
+
+    /// Read the header of a geometry that is part of a curve.
+    fn read_curve_header(&mut self) -> Result<CurveHeader> {
+        let (geometry_type, dimension, byte_order) = self.read_header()?;
+        if dimension != WKBDimension::XY {
+            return Err(GeoArrowError::General(
+                "Only XY dimensions are supported".to_string(),
+            ));
+        }
+        Ok(CurveHeader {
+            geometry_type,
+            byte_order,
+        })
+    }
+
+    fn read_coords(&mut self, byte_order: Endianness) -> Result<Vec<[f64; 2]>> {
+        let num_points = self.read_u32(byte_order)? as usize;
+        let bytes = self.take(num_points.saturating_mul(16))?;
+        let read_f64 = |bytes: &[u8]| {
+            let bytes: [u8; 8] = bytes.try_into().unwrap();
+            match byte_order {
+                Endianness::BigEndian => f64::from_be_bytes(bytes),
+                Endianness::LittleEndian => f64::from_le_bytes(bytes),
+            }
+        };
+        Ok(bytes
+            .chunks_exact(16)
+            .map(|coord| [read_f64(&coord[0..8]), read_f64(&coord[8..16])])
+            .collect())
+    }
+
+    /// Copy `count` coordinates of `dimension`, converting them to little endian.
+    fn copy_coords(
+        &mut self,
+        count: u32,
+        dimension: WKBDimension,
+        byte_order: Endianness,
+    ) -> Result<()> {
+        let bytes = self.take((count as usize).saturating_mul(dimension.size() * 8))?;
+        for value in bytes.chunks_exact(8) {
+            let value: [u8; 8] = value.try_into().unwrap();
+            let value = match byte_order {
+                Endianness::BigEndian => f64::from_be_bytes(value),
+                Endianness::LittleEndian => f64::from_le_bytes(value),
+            };
+            self.out.extend_from_slice(&value.to_le_bytes());
+        }
+        Ok(())
+    }
+
+    /// Track entering a nested geometry, failing once nested too deeply.
+    fn enter(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING_DEPTH {
+            return Err(GeoArrowError::General(format!(
+                "WKB geometries are nested more than {MAX_NESTING_DEPTH} levels deep"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Copy a geometry, linearizing any curves it holds.
+    fn geometry(&mut self) -> Result<()> {
+        self.enter()?;
+        self.geometry_body()?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn geometry_body(&mut self) -> Result<()> {
+        let (geometry_type, dimension, byte_order) = self.read_header()?;
+        if geometry_type.is_curve() {
+            if dimension != WKBDimension::XY {
+                return Err(GeoArrowError::General(
+                    "Only XY dimensions are supported".to_string(),
+                ));
+            }
+            return self.curve_geometry(CurveHeader {
+                geometry_type,
+                byte_order,
+            });
+        }
+
+        self.write_header(geometry_type, dimension);
+        match geometry_type {
+            WKBGeometryType::Point => self.copy_coords(1, dimension, byte_order)?,
+            WKBGeometryType::LineString => {
+                let num_points = self.read_u32(byte_order)?;
+                self.write_u32(num_points);
+                self.copy_coords(num_points, dimension, byte_order)?;
+            }
+            WKBGeometryType::Polygon => {
+                let num_rings = self.read_u32(byte_order)?;
+                self.write_u32(num_rings);
+                for _ in 0..num_rings {
+                    let num_points = self.read_u32(byte_order)?;
+                    self.write_u32(num_points);
+                    self.copy_coords(num_points, dimension, byte_order)?;
+                }
+            }
+            _ => {
+                let num_parts = self.read_u32(byte_order)?;
+                self.write_u32(num_parts);
+                for _ in 0..num_parts {
+                    self.geometry()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a curved geometry as the matching linear geometry.
+    fn curve_geometry(&mut self, header: CurveHeader) -> Result<()> {
+        match header.geometry_type {
+            WKBGeometryType::CircularString | WKBGeometryType::CompoundCurve => {
+                let coords = self.curve_body(header)?;
+                self.write_header(WKBGeometryType::LineString, WKBDimension::XY);
+                self.write_coords(&coords);
+            }
+            WKBGeometryType::CurvePolygon => self.surface_body(header)?,
+            WKBGeometryType::MultiCurve => {
+                let num_parts = self.read_u32(header.byte_order)?;
+                self.write_header(WKBGeometryType::MultiLineString, WKBDimension::XY);
+                self.write_u32(num_parts);
+                for _ in 0..num_parts {
+                    let coords = self.curve()?;
+                    self.write_header(WKBGeometryType::LineString, WKBDimension::XY);
+                    self.write_coords(&coords);
+                }
+            }
+            WKBGeometryType::MultiSurface => {
+                let num_parts = self.read_u32(header.byte_order)?;
+                self.write_header(WKBGeometryType::MultiPolygon, WKBDimension::XY);
+                self.write_u32(num_parts);
+                for _ in 0..num_parts {
+                    let header = self.read_curve_header()?;
+                    self.surface_body(header)?;
+                }
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    /// Read a line string, circular string or compound curve as a list of coordinates.
+    fn curve(&mut self) -> Result<Vec<[f64; 2]>> {
+        self.enter()?;
+        let header = self.read_curve_header()?;
+        let coords = self.curve_body(header)?;
+        self.depth -= 1;
+        Ok(coords)
+    }
+
+    fn curve_body(&mut self, header: CurveHeader) -> Result<Vec<[f64; 2]>> {
+        match header.geometry_type {
+            WKBGeometryType::LineString => self.read_coords(header.byte_order),
+            WKBGeometryType::CircularString => {
+                let control_points = self.read_coords(header.byte_order)?;
+                linearize_circular_string(&control_points, self.max_angle)
+            }
+            WKBGeometryType::CompoundCurve => {
+                let num_parts = self.read_u32(header.byte_order)?;
+                let mut coords: Vec<[f64; 2]> = vec![];
+                for _ in 0..num_parts {
+                    let part = self.curve()?;
+                    // Consecutive parts share their end points
+                    let skip =
+                        usize::from(coords.last().is_some() && coords.last() == part.first());
+                    coords.extend_from_slice(&part[skip..]);
+                }
+                Ok(coords)
+            }
+            other => Err(GeoArrowError::General(format!(
+                "Expected a curve, found {other:?}"
+            ))),
+        }
+    }
+
+    /// Write a polygon or curve polygon as a polygon.
+    fn surface_body(&mut self, header: CurveHeader) -> Result<()> {
+        let num_rings = self.read_u32(header.byte_order)?;
+        let rings = match header.geometry_type {
+            WKBGeometryType::Polygon => (0..num_rings)
+                .map(|_| self.read_coords(header.byte_order))
+                .collect::<Result<Vec<_>>>()?,
+            WKBGeometryType::CurvePolygon => (0..num_rings)
+                .map(|_| self.curve())
+                .collect::<Result<Vec<_>>>()?,
+            other => {
+                return Err(GeoArrowError::General(format!(
+                    "Expected a surface, found {other:?}"
+                )))
+            }
+        };
+        self.write_header(WKBGeometryType::Polygon, WKBDimension::XY);
+        self.write_u32(rings.len() as u32);
+        rings.iter().for_each(|ring| self.write_coords(ring));
+        Ok(())
+    }
+}
+
+/// Linearize the arcs of a circular string, each defined by three consecutive control points,
+/// where the last point of one arc is the first of the next.
+fn linearize_circular_string(control_points: &[[f64; 2]], max_angle: f64) -> Result<Vec<[f64; 2]>> {
+    if control_points.is_empty() {
+        return Ok(vec![]);
+    }
+    if control_points.len() < 3 || control_points.len() % 2 == 0 {
+        return Err(GeoArrowError::General(format!(
+            "A circular string must have an odd number of at least 3 points, got {}",
+            control_points.len()
+        )));
+    }
+
+    let mut coords = vec![control_points[0]];
+    for arc in control_points.windows(3).step_by(2) {
+        linearize_arc(arc[0], arc[1], arc[2], max_angle, &mut coords)?;
+    }
+    Ok(coords)
+}
+
+/// Append the points of the arc from `start` through `mid` to `end` to `coords`, excluding
+/// `start`.
+///
+/// Fails if the arc would need more than [`MAX_ARC_SEGMENTS`] segments.
+fn linearize_arc(
+    start: [f64; 2],
+    mid: [f64; 2],
+    end: [f64; 2],
+    max_angle: f64,
+    coords: &mut Vec<[f64; 2]>,
+) -> Result<()> {
+    let [x0, y0] = start;
+    let [x1, y1] = mid;
+    let [x2, y2] = end;
+
+    let (center, sweep) = if start == end {
+        // A full circle, whose diameter is from the start to the middle point
+        ([(x0 + x1) / 2., (y0 + y1) / 2.], 2. * PI)
+    } else {
+        let d = 2. * (x0 * (y1 - y2) + x1 * (y2 - y0) + x2 * (y0 - y1));
+        // The cross product has the sign of the direction of the arc
+        let cross = (x1 - x0) * (y2 - y1) - (y1 - y0) * (x2 - x1);
+        let scale = (x1 - x0).hypot(y1 - y0) * (x2 - x1).hypot(y2 - y1);
+        if cross.abs() <= f64::EPSILON * scale {
+            // Collinear points form a straight line
+            coords.extend([mid, end]);
+            return Ok(());
+        }
+        let sq0 = x0 * x0 + y0 * y0;
+        let sq1 = x1 * x1 + y1 * y1;
+        let sq2 = x2 * x2 + y2 * y2;
+        let center = [
+            (sq0 * (y1 - y2) + sq1 * (y2 - y0) + sq2 * (y0 - y1)) / d,
+            (sq0 * (x2 - x1) + sq1 * (x0 - x2) + sq2 * (x1 - x0)) / d,
+        ];
+        let start_angle = (y0 - center[1]).atan2(x0 - center[0]);
+        let end_angle = (y2 - center[1]).atan2(x2 - center[0]);
+        let sweep = (end_angle - start_angle).rem_euclid(2. * PI);
+        (center, if cross > 0. { sweep } else { sweep - 2. * PI })
+    };
+
+    let radius = (x0 - center[0]).hypot(y0 - center[1]);
+    let start_angle = (y0 - center[1]).atan2(x0 - center[0]);
+    let num_segments = (sweep.abs() / max_angle).ceil().max(1.);
+    if num_segments > MAX_ARC_SEGMENTS as f64 {
+        return Err(GeoArrowError::General(format!(
+            "Linearizing an arc of {sweep} radians with a maximum angle of {max_angle} would need more than {MAX_ARC_SEGMENTS} segments"
+        )));
+    }
+    let num_segments = num_segments as usize;
+    coords.extend((1..num_segments).map(|i| {
+        let angle = start_angle + sweep * i as f64 / num_segments as f64;
+        [
+            center[0] + radius * angle.cos(),
+            center[1] + radius * angle.sin(),
+        ]
+    }));
+    coords.push(end);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(deprecated)]
+    use crate::array::geometry::GeometryArray;
+    use crate::array::AsGeometryArray;
+    use crate::datatypes::GeoDataType;
+    use crate::io::wkb::{from_wkb, from_wkb_inferred, scan_wkb, WKBReadOptions};
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::BinaryArray;
+    use geo::{line_string, polygon, CoordsIter};
+
+    fn header(buf: &mut Vec<u8>, geometry_type: WKBGeometryType) {
+        buf.push(1);
+        buf.extend((geometry_type as u32).to_le_bytes());
+    }
+
+    fn points(buf: &mut Vec<u8>, coords: &[[f64; 2]]) {
+        buf.extend((coords.len() as u32).to_le_bytes());
+        coords
+            .iter()
+            .flatten()
+            .for_each(|v| buf.extend(v.to_le_bytes()));
+    }
+
+    fn circular_string(coords: &[[f64; 2]]) -> Vec<u8> {
+        let mut buf = vec![];
+        header(&mut buf, WKBGeometryType::CircularString);
+        points(&mut buf, coords);
+        buf
+    }
+
+    /// CURVEPOLYGON (COMPOUNDCURVE (CIRCULARSTRING (0 0, 1 1, 2 0), (2 0, 0 0)))
+    fn half_disc() -> Vec<u8> {
+        let mut buf = vec![];
+        header(&mut buf, WKBGeometryType::CurvePolygon);
+        buf.extend(1u32.to_le_bytes());
+        header(&mut buf, WKBGeometryType::CompoundCurve);
+        buf.extend(2u32.to_le_bytes());
+        buf.extend(circular_string(&[[0., 0.], [1., 1.], [2., 0.]]));
+        header(&mut buf, WKBGeometryType::LineString);
+        points(&mut buf, &[[2., 0.], [0., 0.]]);
+        buf
+    }
+
+    fn wkb_array(buffers: Vec<Option<&[u8]>>) -> WKBArray<i32> {
+        WKBArray::new(BinaryArray::from(buffers), Default::default())
+    }
+
+    #[test]
+    fn arcs() {
+        let half_circle = circular_string(&[[0., 0.], [1., 1.], [2., 0.]]);
+        let clockwise = circular_string(&[[2., 0.], [1., 1.], [0., 0.]]);
+        let full_circle = circular_string(&[[0., 0.], [2., 0.], [0., 0.]]);
+        let straight = circular_string(&[[0., 0.], [1., 0.], [2., 0.]]);
+        let array = wkb_array(vec![
+            Some(&half_circle),
+            Some(&clockwise),
+            Some(&full_circle),
+            Some(&straight),
+            None,
+        ]);
+        let headers = scan_wkb(&array);
+        let header = headers[0].as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(header.geometry_type, WKBGeometryType::CircularString);
+
+        let linearized = linearize_wkb(&array, PI / 4.).unwrap();
+        let parsed = from_wkb_inferred(&linearized, &WKBReadOptions::default()).unwrap();
+        let parsed = parsed.as_ref();
+        let lines = parsed.as_line_string();
+        assert!(lines.is_null(4));
+
+        let half_circle = lines.value_as_geo(0);
+        assert_eq!(half_circle.coords_count(), 5);
+        assert!((half_circle.0[2].y - 1.).abs() < 1e-12);
+        for coord in half_circle.coords_iter() {
+            assert!(((coord.x - 1.).hypot(coord.y) - 1.).abs() < 1e-12);
+        }
+        let mut clockwise = lines.value_as_geo(1);
+        clockwise.0.reverse();
+        for (a, b) in clockwise.coords_iter().zip(half_circle.coords_iter()) {
+            assert!((a.x - b.x).abs() < 1e-12 && (a.y - b.y).abs() < 1e-12);
+        }
+        assert_eq!(lines.value_as_geo(2).coords_count(), 9);
+        assert_eq!(
+            lines.value_as_geo(3),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn curve_polygon() {
+        let half_disc = half_disc();
+        let array = wkb_array(vec![Some(&half_disc)]);
+        assert!(from_wkb_inferred(&array, &WKBReadOptions::default()).is_err());
+
+        let options = WKBReadOptions {
+            linearize_max_angle: Some(PI / 2.),
+            ..Default::default()
+        };
+        let parsed = from_wkb_inferred(&array, &options).unwrap();
+        let polygon = parsed.as_ref().as_polygon().value_as_geo(0);
+        let expected = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.), (x: 0., y: 0.)];
+        for (a, b) in polygon.coords_iter().zip(expected.coords_iter()) {
+            assert!((a.x - b.x).abs() < 1e-12 && (a.y - b.y).abs() < 1e-12);
+        }
+        assert_eq!(polygon.coords_count(), expected.coords_count());
+    }
+
+    #[test]
+    fn invalid_curves() {
+        let even = circular_string(&[[0., 0.], [1., 1.]]);
+        let array = wkb_array(vec![Some(&even)]);
+        assert!(linearize_wkb(&array, PI / 4.).is_err());
+        assert!(linearize_wkb(&wkb_array(vec![]), 0.).is_err());
+
+        let half_circle = circular_string(&[[0., 0.], [1., 1.], [2., 0.]]);
+        let array = wkb_array(vec![Some(&half_circle)]);
+        assert!(linearize_wkb(&array, 1e-300).is_err());
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let mut buf = vec![];
+        for _ in 0..=MAX_NESTING_DEPTH {
+            header(&mut buf, WKBGeometryType::GeometryCollection);
+            buf.extend(1u32.to_le_bytes());
+        }
+        buf.extend(circular_string(&[[0., 0.], [1., 1.], [2., 0.]]));
+        let array = wkb_array(vec![Some(&buf)]);
+        assert!(linearize_wkb(&array, PI / 4.).is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn curves_must_be_linearized() {
+        let half_circle = circular_string(&[[0., 0.], [1., 1.], [2., 0.]]);
+        let array = wkb_array(vec![Some(&half_circle)]);
+        assert!(GeometryArray::try_from(array.clone()).is_err());
+        assert!(from_wkb(&array, GeoDataType::LineString(Default::default()), false).is_err());
+    }
+}
@@ -1,7 +1,16 @@
 //! An optimized implementation of reading and writing ISO-flavored WKB-encoded geometries.
 
 mod api;
+mod linearize;
 pub(crate) mod reader;
+mod scan;
 pub(crate) mod writer;
 
-pub use api::{from_wkb, to_wkb, FromWKB};
+pub use api::{
+    from_chunked_wkb, from_wkb, from_wkb_inferred, from_wkb_with_validation, to_wkb,
+    to_wkb_with_options, FromWKB, WKBReadOptions,
+};
+pub use linearize::linearize_wkb;
+pub use reader::{Endianness, WKBGeometryType};
+pub use scan::{scan_wkb, wkb_envelopes, wkb_total_bounds, WKBDimension, WKBHeader};
+pub use writer::{WKBFlavor, WKBWriteOptions};
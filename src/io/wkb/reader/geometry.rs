@@ -57,7 +57,7 @@ impl<'a, O: OffsetSizeTrait> WKB<'a, O> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Endianness {
     BigEndian,
     LittleEndian,
@@ -83,6 +83,17 @@ impl From<Endianness> for u8 {
     }
 }
 
+/// Read the `u32` at `offset` in a WKB buffer, such as the number of points, rings or parts of
+/// a WKB object.
+pub(crate) fn read_u32(buf: &[u8], byte_order: Endianness, offset: u64) -> u32 {
+    let mut reader = Cursor::new(buf);
+    reader.set_position(offset);
+    match byte_order {
+        Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap(),
+        Endianness::LittleEndian => reader.read_u32::<LittleEndian>().unwrap(),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum WKBGeometry<'a> {
     Point(WKBPoint<'a>),
@@ -2,8 +2,9 @@
 //!
 //! Each of the data structures in this module is intended to mirror the [WKB
 //! spec](https://portal.ogc.org/files/?artifact_id=25355). Crucially each of these data structures
-//! implement geometry access traits for interoperability and each of these data structures should
-//! be O(1) access to any given coordinate.
+//! implement geometry access traits for interoperability. Constructing any of these data
+//! structures only reads its header: nested parts and coordinates are located on demand from
+//! offsets into the raw buffer.
 
 mod coord;
 mod geometry;
@@ -1,58 +1,67 @@
-use std::io::Cursor;
-
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use std::sync::OnceLock;
 
 use crate::algorithm::native::eq::multi_line_string_eq;
 use crate::geo_traits::MultiLineStringTrait;
-use crate::io::wkb::reader::geometry::Endianness;
+use crate::io::wkb::reader::geometry::{read_u32, Endianness};
 use crate::io::wkb::reader::linestring::WKBLineString;
 
 const HEADER_BYTES: u64 = 5;
 
+/// A multi line string in a WKB buffer.
+///
+/// Only the header is read on construction. The line strings are located the first time one of
+/// them is accessed, by walking the point counts of all of them once.
 #[derive(Debug, Clone)]
 pub struct WKBMultiLineString<'a> {
-    /// A WKBLineString object for each of the internal line strings
-    wkb_line_strings: Vec<WKBLineString<'a>>,
+    buf: &'a [u8],
+    byte_order: Endianness,
+
+    /// The number of line strings in this MultiLineString
+    num_line_strings: usize,
+
+    /// The offset of each line string, followed by the offset of the end of this
+    /// MultiLineString, filled on first access
+    line_string_offsets: OnceLock<Vec<u64>>,
 }
 
 impl<'a> WKBMultiLineString<'a> {
     pub fn new(buf: &'a [u8], byte_order: Endianness) -> Self {
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES);
-        let num_line_strings = match byte_order {
-            Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap().try_into().unwrap(),
-            Endianness::LittleEndian => reader
-                .read_u32::<LittleEndian>()
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        };
-
-        // - 1: byteOrder
-        // - 4: wkbType
-        // - 4: numLineStrings
-        let mut line_string_offset = 1 + 4 + 4;
-        let mut wkb_line_strings = Vec::with_capacity(num_line_strings);
-        for _ in 0..num_line_strings {
-            let ls = WKBLineString::new(buf, byte_order, line_string_offset);
-            wkb_line_strings.push(ls);
-            line_string_offset += ls.size();
+        let num_line_strings = read_u32(buf, byte_order, HEADER_BYTES).try_into().unwrap();
+
+        Self {
+            buf,
+            byte_order,
+            num_line_strings,
+            line_string_offsets: OnceLock::new(),
         }
+    }
 
-        Self { wkb_line_strings }
+    /// The offset into this buffer of any given WKBLineString
+    ///
+    /// Passing `self.num_line_strings` returns the offset of the end of this MultiLineString.
+    pub fn line_string_offset(&self, i: usize) -> u64 {
+        let offsets = self.line_string_offsets.get_or_init(|| {
+            // - 1: byteOrder
+            // - 4: wkbType
+            // - 4: numLineStrings
+            let mut line_string_offset = 1 + 4 + 4;
+            let mut offsets = Vec::with_capacity(self.num_line_strings + 1);
+            offsets.push(line_string_offset);
+            for _ in 0..self.num_line_strings {
+                line_string_offset +=
+                    WKBLineString::new(self.buf, self.byte_order, line_string_offset).size();
+                offsets.push(line_string_offset);
+            }
+            offsets
+        });
+        offsets[i]
     }
 
     /// The number of bytes in this object, including any header
     ///
     /// Note that this is not the same as the length of the underlying buffer
     pub fn size(&self) -> u64 {
-        // - 1: byteOrder
-        // - 4: wkbType
-        // - 4: numPoints
-        // - WKBPoint::size() * self.num_points: the size of each WKBPoint for each point
-        self.wkb_line_strings
-            .iter()
-            .fold(1 + 4 + 4, |acc, ls| acc + ls.size())
+        self.line_string_offset(self.num_line_strings)
     }
 
     /// Check if this WKBMultiLineString has equal coordinates as some other MultiLineString object
@@ -66,11 +75,11 @@ impl<'a> MultiLineStringTrait for WKBMultiLineString<'a> {
     type ItemType<'b> = WKBLineString<'a> where Self: 'b;
 
     fn num_lines(&self) -> usize {
-        self.wkb_line_strings.len()
+        self.num_line_strings
     }
 
     unsafe fn line_unchecked(&self, i: usize) -> Self::ItemType<'_> {
-        *self.wkb_line_strings.get_unchecked(i)
+        WKBLineString::new(self.buf, self.byte_order, self.line_string_offset(i))
     }
 }
 
@@ -79,11 +88,11 @@ impl<'a> MultiLineStringTrait for &'a WKBMultiLineString<'a> {
     type ItemType<'b> = WKBLineString<'a> where Self: 'b;
 
     fn num_lines(&self) -> usize {
-        self.wkb_line_strings.len()
+        self.num_line_strings
     }
 
     unsafe fn line_unchecked(&self, i: usize) -> Self::ItemType<'_> {
-        *self.wkb_line_strings.get_unchecked(i)
+        WKBLineString::new(self.buf, self.byte_order, self.line_string_offset(i))
     }
 }
 
@@ -102,5 +111,6 @@ mod test {
         let wkb_geom = WKBMultiLineString::new(&buf, Endianness::LittleEndian);
 
         assert!(wkb_geom.equals_multi_line_string(&geom));
+        assert_eq!(wkb_geom.size(), buf.len() as u64);
     }
 }
@@ -1,58 +1,69 @@
-use std::io::Cursor;
-
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use std::sync::OnceLock;
 
 use crate::algorithm::native::eq::multi_polygon_eq;
 use crate::geo_traits::MultiPolygonTrait;
-use crate::io::wkb::reader::geometry::Endianness;
+use crate::io::wkb::reader::geometry::{read_u32, Endianness};
 use crate::io::wkb::reader::polygon::WKBPolygon;
 
 const HEADER_BYTES: u64 = 5;
 
+/// A multi polygon in a WKB buffer.
+///
+/// Only the header is read on construction. The polygons are located the first time one of them
+/// is accessed, by walking the ring and point counts of all of them once.
 #[derive(Debug, Clone)]
 pub struct WKBMultiPolygon<'a> {
-    // buf: &'a [u8],
-    // byte_order: Endianness,
-
-    // /// The number of polygons in this MultiPolygon
-    // num_polygons: usize,
-
-    // /// The offset in the buffer where each WKBPolygon object begins
-    // ///
-    // /// The length of this vec must match the number of polygons
-    // // polygon_offsets: Vec<usize>,
-    /// A WKBPolygon object for each of the internal line strings
-    wkb_polygons: Vec<WKBPolygon<'a>>,
+    buf: &'a [u8],
+    byte_order: Endianness,
+
+    /// The number of polygons in this MultiPolygon
+    num_polygons: usize,
+
+    /// The offset of each polygon, followed by the offset of the end of this MultiPolygon,
+    /// filled on first access
+    polygon_offsets: OnceLock<Vec<u64>>,
 }
 
 impl<'a> WKBMultiPolygon<'a> {
     pub fn new(buf: &'a [u8], byte_order: Endianness) -> Self {
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES);
-        let num_polygons = match byte_order {
-            Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap().try_into().unwrap(),
-            Endianness::LittleEndian => reader
-                .read_u32::<LittleEndian>()
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        };
-
-        // - 1: byteOrder
-        // - 4: wkbType
-        // - 4: numLineStrings
-        let mut polygon_offset = 1 + 4 + 4;
-        let mut wkb_polygons = Vec::with_capacity(num_polygons);
-        for _ in 0..num_polygons {
-            let polygon = WKBPolygon::new(buf, byte_order, polygon_offset);
-            polygon_offset += polygon.size();
-            wkb_polygons.push(polygon);
+        let num_polygons = read_u32(buf, byte_order, HEADER_BYTES).try_into().unwrap();
+
+        Self {
+            buf,
+            byte_order,
+            num_polygons,
+            polygon_offsets: OnceLock::new(),
         }
+    }
+
+    /// The offset into this buffer of any given WKBPolygon
+    ///
+    /// Passing `self.num_polygons` returns the offset of the end of this MultiPolygon.
+    pub fn polygon_offset(&self, i: usize) -> u64 {
+        let offsets = self.polygon_offsets.get_or_init(|| {
+            // - 1: byteOrder
+            // - 4: wkbType
+            // - 4: numPolygons
+            let mut polygon_offset = 1 + 4 + 4;
+            let mut offsets = Vec::with_capacity(self.num_polygons + 1);
+            offsets.push(polygon_offset);
+            for _ in 0..self.num_polygons {
+                polygon_offset += WKBPolygon::new(self.buf, self.byte_order, polygon_offset).size();
+                offsets.push(polygon_offset);
+            }
+            offsets
+        });
+        offsets[i]
+    }
 
-        Self { wkb_polygons }
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        self.polygon_offset(self.num_polygons)
     }
 
-    /// Check if this WKBMultiLineString has equal coordinates as some other MultiLineString object
+    /// Check if this WKBMultiPolygon has equal coordinates as some other MultiPolygon object
     pub fn equals_multi_polygon(&self, other: &impl MultiPolygonTrait<T = f64>) -> bool {
         multi_polygon_eq(self, other)
     }
@@ -63,11 +74,11 @@ impl<'a> MultiPolygonTrait for WKBMultiPolygon<'a> {
     type ItemType<'b> = WKBPolygon<'a> where Self: 'b;
 
     fn num_polygons(&self) -> usize {
-        self.wkb_polygons.len()
+        self.num_polygons
     }
 
     unsafe fn polygon_unchecked(&self, i: usize) -> Self::ItemType<'_> {
-        self.wkb_polygons.get_unchecked(i).clone()
+        WKBPolygon::new(self.buf, self.byte_order, self.polygon_offset(i))
     }
 }
 
@@ -76,11 +87,11 @@ impl<'a> MultiPolygonTrait for &'a WKBMultiPolygon<'a> {
     type ItemType<'b> = WKBPolygon<'a> where Self: 'b;
 
     fn num_polygons(&self) -> usize {
-        self.wkb_polygons.len()
+        self.num_polygons
     }
 
     unsafe fn polygon_unchecked(&self, i: usize) -> Self::ItemType<'_> {
-        self.wkb_polygons.get_unchecked(i).clone()
+        WKBPolygon::new(self.buf, self.byte_order, self.polygon_offset(i))
     }
 }
 
@@ -99,5 +110,6 @@ mod test {
         let wkb_geom = WKBMultiPolygon::new(&buf, Endianness::LittleEndian);
 
         assert!(wkb_geom.equals_multi_polygon(&geom));
+        assert_eq!(wkb_geom.size(), buf.len() as u64);
     }
 }
@@ -1,72 +1,87 @@
-use std::io::Cursor;
-
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use std::sync::OnceLock;
 
 use crate::algorithm::native::eq::polygon_eq;
 use crate::geo_traits::{MultiPolygonTrait, PolygonTrait};
-use crate::io::wkb::reader::geometry::Endianness;
+use crate::io::wkb::reader::geometry::{read_u32, Endianness};
 use crate::io::wkb::reader::linearring::WKBLinearRing;
 
 const WKB_POLYGON_TYPE: u32 = 3;
 
+/// A polygon in a WKB buffer.
+///
+/// Only the header is read on construction. The rings are located the first time one of them is
+/// accessed, by walking the point counts of all of them once.
 #[derive(Debug, Clone)]
 pub struct WKBPolygon<'a> {
-    wkb_linear_rings: Vec<WKBLinearRing<'a>>,
+    buf: &'a [u8],
+    byte_order: Endianness,
+
+    /// This offset will be 0 for a single WKBPolygon but it will be non zero for a WKBPolygon
+    /// contained within a WKBMultiPolygon
+    offset: u64,
+
+    /// The number of rings in this polygon
+    num_rings: usize,
+
+    /// The offset of each ring, followed by the offset of the end of this polygon, filled on
+    /// first access
+    ring_offsets: OnceLock<Vec<u64>>,
 }
 
 impl<'a> WKBPolygon<'a> {
     pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64) -> Self {
-        let mut reader = Cursor::new(buf);
-        reader.set_position(1 + offset);
-
         // Assert that this is indeed a 2D Polygon
-        assert_eq!(
-            WKB_POLYGON_TYPE,
-            match byte_order {
-                Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap(),
-                Endianness::LittleEndian => reader.read_u32::<LittleEndian>().unwrap(),
-            }
-        );
-
-        let num_rings = match byte_order {
-            Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap().try_into().unwrap(),
-            Endianness::LittleEndian => reader
-                .read_u32::<LittleEndian>()
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        };
+        assert_eq!(WKB_POLYGON_TYPE, read_u32(buf, byte_order, offset + 1));
 
         // - existing offset into buffer
         // - 1: byteOrder
         // - 4: wkbType
-        // - 4: numLineStrings
-        let mut ring_offset = offset + 1 + 4 + 4;
-        let mut wkb_linear_rings = Vec::with_capacity(num_rings);
-        for _ in 0..num_rings {
-            let polygon = WKBLinearRing::new(buf, byte_order, ring_offset);
-            wkb_linear_rings.push(polygon);
-            ring_offset += polygon.size();
+        let num_rings = read_u32(buf, byte_order, offset + 1 + 4)
+            .try_into()
+            .unwrap();
+
+        Self {
+            buf,
+            byte_order,
+            offset,
+            num_rings,
+            ring_offsets: OnceLock::new(),
         }
+    }
 
-        Self { wkb_linear_rings }
+    /// The offset into this buffer of any given ring
+    ///
+    /// Passing `self.num_rings` returns the offset of the end of this polygon.
+    pub fn ring_offset(&self, i: usize) -> u64 {
+        let offsets = self.ring_offsets.get_or_init(|| {
+            // - 1: byteOrder
+            // - 4: wkbType
+            // - 4: numRings
+            let mut ring_offset = self.offset + 1 + 4 + 4;
+            let mut offsets = Vec::with_capacity(self.num_rings + 1);
+            offsets.push(ring_offset);
+            for _ in 0..self.num_rings {
+                ring_offset += WKBLinearRing::new(self.buf, self.byte_order, ring_offset).size();
+                offsets.push(ring_offset);
+            }
+            offsets
+        });
+        offsets[i]
+    }
+
+    fn ring(&self, i: usize) -> WKBLinearRing<'a> {
+        WKBLinearRing::new(self.buf, self.byte_order, self.ring_offset(i))
     }
 
     /// The number of bytes in this object, including any header
     ///
     /// Note that this is not the same as the length of the underlying buffer
     pub fn size(&self) -> u64 {
-        // - 1: byteOrder
-        // - 4: wkbType
-        // - 4: numPoints
-        // - size of each linear ring
-        self.wkb_linear_rings
-            .iter()
-            .fold(1 + 4 + 4, |acc, ring| acc + ring.size())
+        self.ring_offset(self.num_rings) - self.offset
     }
 
     pub fn is_empty(&self) -> bool {
-        self.wkb_linear_rings.len() == 0
+        self.num_rings == 0
     }
 
     /// Check if this WKBPolygon has equal coordinates as some other Polygon object
@@ -77,27 +92,23 @@ impl<'a> WKBPolygon<'a> {
 
 impl<'a> PolygonTrait for WKBPolygon<'a> {
     type T = f64;
-    type ItemType<'b> = WKBLinearRing<'a>where Self: 'b;
+    type ItemType<'b> = WKBLinearRing<'a> where Self: 'b;
 
     fn num_interiors(&self) -> usize {
         // Support an empty polygon with no rings
-        if self.wkb_linear_rings.is_empty() {
-            0
-        } else {
-            self.wkb_linear_rings.len() - 1
-        }
+        self.num_rings.saturating_sub(1)
     }
 
     fn exterior(&self) -> Option<Self::ItemType<'_>> {
-        if self.wkb_linear_rings.is_empty() {
+        if self.is_empty() {
             None
         } else {
-            Some(self.wkb_linear_rings[0])
+            Some(self.ring(0))
         }
     }
 
     unsafe fn interior_unchecked(&self, i: usize) -> Self::ItemType<'_> {
-        *self.wkb_linear_rings.get_unchecked(i + 1)
+        self.ring(i + 1)
     }
 }
 
@@ -107,23 +118,19 @@ impl<'a> PolygonTrait for &'a WKBPolygon<'a> {
 
     fn num_interiors(&self) -> usize {
         // Support an empty polygon with no rings
-        if self.wkb_linear_rings.is_empty() {
-            0
-        } else {
-            self.wkb_linear_rings.len() - 1
-        }
+        self.num_rings.saturating_sub(1)
     }
 
     fn exterior(&self) -> Option<Self::ItemType<'_>> {
-        if self.wkb_linear_rings.is_empty() {
+        if self.is_empty() {
             None
         } else {
-            Some(self.wkb_linear_rings[0])
+            Some(self.ring(0))
         }
     }
 
     unsafe fn interior_unchecked(&self, i: usize) -> Self::ItemType<'_> {
-        *self.wkb_linear_rings.get_unchecked(i + 1)
+        self.ring(i + 1)
     }
 }
 
@@ -149,16 +156,14 @@ impl<'a> MultiPolygonTrait for &'a WKBPolygon<'a> {
     }
 
     unsafe fn polygon_unchecked(&self, _i: usize) -> Self::ItemType<'_> {
-        // TODO: this looks bad
-        #[allow(suspicious_double_ref_op)]
-        self.clone().clone()
+        (*self).clone()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test::polygon::p0;
+    use crate::test::polygon::{p0, p1};
     use geozero::{CoordDimensions, ToWkb};
 
     #[test]
@@ -171,4 +176,16 @@ mod test {
 
         assert!(wkb_geom.equals_polygon(&geom));
     }
+
+    #[test]
+    fn polygon_with_interior() {
+        let geom = p1();
+        let buf = geo::Geometry::Polygon(geom.clone())
+            .to_wkb(CoordDimensions::xy())
+            .unwrap();
+        let wkb_geom = WKBPolygon::new(&buf, Endianness::LittleEndian, 0);
+
+        assert!(wkb_geom.equals_polygon(&geom));
+        assert_eq!(wkb_geom.size(), buf.len() as u64);
+    }
 }
@@ -4,12 +4,14 @@
 use arrow_array::OffsetSizeTrait;
 use num_enum::TryFromPrimitive;
 
-use crate::array::CoordType;
+use crate::array::{CoordType, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::scan_wkb;
 use crate::scalar::WKB;
+use crate::trait_::GeometryArrayAccessor;
 
-#[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive)]
 #[repr(u32)]
 pub enum WKBGeometryType {
     Point = 1,
@@ -19,6 +21,39 @@ pub enum WKBGeometryType {
     MultiLineString = 5,
     MultiPolygon = 6,
     GeometryCollection = 7,
+    CircularString = 8,
+    CompoundCurve = 9,
+    CurvePolygon = 10,
+    MultiCurve = 11,
+    MultiSurface = 12,
+}
+
+impl WKBGeometryType {
+    /// Whether this is one of the curved geometry types of ISO SQL/MM, which need to be
+    /// [linearized][crate::io::wkb::linearize_wkb] before they can be parsed.
+    pub fn is_curve(&self) -> bool {
+        matches!(
+            self,
+            WKBGeometryType::CircularString
+                | WKBGeometryType::CompoundCurve
+                | WKBGeometryType::CurvePolygon
+                | WKBGeometryType::MultiCurve
+                | WKBGeometryType::MultiSurface
+        )
+    }
+
+    /// The linear geometry type that this type is converted to by linearization.
+    pub fn linearized(&self) -> WKBGeometryType {
+        match self {
+            WKBGeometryType::CircularString | WKBGeometryType::CompoundCurve => {
+                WKBGeometryType::LineString
+            }
+            WKBGeometryType::CurvePolygon => WKBGeometryType::Polygon,
+            WKBGeometryType::MultiCurve => WKBGeometryType::MultiLineString,
+            WKBGeometryType::MultiSurface => WKBGeometryType::MultiPolygon,
+            other => *other,
+        }
+    }
 }
 
 struct AvailableTypes {
@@ -108,9 +143,30 @@ impl AvailableTypes {
         self.polygon = false;
         self.multi_point = false;
         self.multi_line_string = false;
+        self.multi_polygon = false;
         self.mixed = false;
     }
 
+    pub fn add(&mut self, geometry_type: WKBGeometryType) {
+        match geometry_type.linearized() {
+            WKBGeometryType::Point => self.add_point(),
+            WKBGeometryType::LineString => self.add_line_string(),
+            WKBGeometryType::Polygon => self.add_polygon(),
+            WKBGeometryType::MultiPoint => self.add_multi_point(),
+            WKBGeometryType::MultiLineString => self.add_multi_line_string(),
+            WKBGeometryType::MultiPolygon => self.add_multi_polygon(),
+            WKBGeometryType::GeometryCollection => self.add_geometry_collection(),
+            _ => unreachable!("curves are linearized"),
+        }
+    }
+
+    /// Rule out the single geometry types, so that e.g. points are stored as multi points.
+    pub fn prefer_multi(&mut self) {
+        self.point = false;
+        self.line_string = false;
+        self.polygon = false;
+    }
+
     pub fn resolve_type(self, large_type: bool, coord_type: CoordType) -> Result<GeoDataType> {
         if self.all_true() {
             return Err(GeoArrowError::General(
@@ -173,15 +229,58 @@ pub(crate) fn infer_geometry_type<'a, O: OffsetSizeTrait>(
 ) -> Result<GeoDataType> {
     let mut available_type = AvailableTypes::new();
     for geom in geoms {
-        match geom.get_wkb_geometry_type() {
-            WKBGeometryType::Point => available_type.add_point(),
-            WKBGeometryType::LineString => available_type.add_line_string(),
-            WKBGeometryType::Polygon => available_type.add_polygon(),
-            WKBGeometryType::MultiPoint => available_type.add_multi_point(),
-            WKBGeometryType::MultiLineString => available_type.add_multi_line_string(),
-            WKBGeometryType::MultiPolygon => available_type.add_multi_polygon(),
-            WKBGeometryType::GeometryCollection => available_type.add_geometry_collection(),
+        let geometry_type = geom.get_wkb_geometry_type();
+        if geometry_type.is_curve() {
+            return Err(curve_error(geometry_type));
         }
+        available_type.add(geometry_type);
     }
     available_type.resolve_type(large_type, coord_type)
 }
+
+/// Check that no geometry of a WKB array is curved, as curves can't be parsed into native
+/// arrays before they are linearized with [`linearize_wkb`][crate::io::wkb::linearize_wkb].
+pub(crate) fn check_no_curves<O: OffsetSizeTrait>(arr: &WKBArray<O>) -> Result<()> {
+    for geom in arr.iter().flatten() {
+        let geometry_type = geom.get_wkb_geometry_type();
+        if geometry_type.is_curve() {
+            return Err(curve_error(geometry_type));
+        }
+    }
+    Ok(())
+}
+
+fn curve_error(geometry_type: WKBGeometryType) -> GeoArrowError {
+    GeoArrowError::General(format!(
+        "Curved geometry type {geometry_type:?} must be linearized before parsing"
+    ))
+}
+
+/// Infer the minimal GeoDataType that a WKB array can be parsed to from the headers of its
+/// geometries, without reading any coordinates.
+///
+/// Returns `None` if the array has no non-null geometries.
+pub(crate) fn infer_wkb_array_type<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    large_type: bool,
+    coord_type: CoordType,
+    prefer_multi: bool,
+) -> Result<Option<GeoDataType>> {
+    let mut available_type = AvailableTypes::new();
+    for header in scan_wkb(arr).into_iter().flatten() {
+        let geometry_type = header?.geometry_type;
+        if geometry_type.is_curve() {
+            return Err(curve_error(geometry_type));
+        }
+        available_type.add(geometry_type);
+    }
+    if available_type.all_true() {
+        return Ok(None);
+    }
+    if prefer_multi {
+        available_type.prefer_multi();
+    }
+    available_type
+        .resolve_type(large_type, coord_type)
+        .map(Some)
+}
@@ -0,0 +1,466 @@
+//! Validate WKB buffers and read their headers or bounds without parsing them into geometries.
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::array::{RectArray, WKBArray};
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::reader::{Endianness, WKBGeometryType};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The maximum depth to which WKB geometries may be nested, e.g. in geometry collections.
+///
+/// This bounds the recursion when walking untrusted buffers.
+pub(crate) const MAX_NESTING_DEPTH: usize = 64;
+
+/// The coordinate dimension of a WKB geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WKBDimension {
+    XY,
+    XYZ,
+    XYM,
+    XYZM,
+}
+
+impl WKBDimension {
+    /// The number of values per coordinate.
+    pub fn size(&self) -> usize {
+        match self {
+            WKBDimension::XY => 2,
+            WKBDimension::XYZ | WKBDimension::XYM => 3,
+            WKBDimension::XYZM => 4,
+        }
+    }
+}
+
+/// The header of a WKB geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WKBHeader {
+    pub geometry_type: WKBGeometryType,
+    pub dimension: WKBDimension,
+    pub byte_order: Endianness,
+}
+
+/// Scan each geometry of a WKB array, returning its header.
+///
+/// This walks the headers and part counts of each geometry, including those of nested
+/// geometries, to check that the buffer has exactly the expected length, but never reads
+/// coordinates. This makes it much cheaper than a full parse, so it can be used to infer the
+/// geometry types of an array or to validate it before parsing.
+///
+/// Both ISO (e.g. `1001` for a Point Z) and extended (with high bit flags) geometry type codes
+/// are recognized, as are the curved geometry types of ISO SQL/MM, such as `CircularString`.
+/// Null geometries have a `None` result, and invalid geometries, such as those with truncated
+/// buffers or unknown geometry types, have an error.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::WKBArray;
+/// use geoarrow::io::wkb::{scan_wkb, WKBDimension, WKBGeometryType};
+/// use geo::{point, Geometry};
+///
+/// let array: WKBArray<i32> = vec![Some(Geometry::Point(point!(x: 1., y: 2.))), None]
+///     .as_slice()
+///     .try_into()
+///     .unwrap();
+///
+/// let headers = scan_wkb(&array);
+/// let header = headers[0].as_ref().unwrap().as_ref().unwrap();
+/// assert_eq!(header.geometry_type, WKBGeometryType::Point);
+/// assert_eq!(header.dimension, WKBDimension::XY);
+/// assert!(headers[1].is_none());
+/// ```
+pub fn scan_wkb<O: OffsetSizeTrait>(array: &WKBArray<O>) -> Vec<Option<Result<WKBHeader>>> {
+    (0..array.len())
+        .map(|geom_idx| {
+            array
+                .get(geom_idx)
+                .map(|_| scan_buffer(array.array.value(geom_idx)))
+        })
+        .collect()
+}
+
+/// Compute the bounding box of all geometries in a WKB array.
+///
+/// Coordinates are read straight from the WKB buffers, walking them the same way as
+/// [`scan_wkb`], without building native arrays or geo objects. Only the x and y values of each
+/// coordinate are considered, and NaN coordinates (as used for empty points) are skipped. Null
+/// geometries are skipped. The bounds of curved geometries are those of their control points,
+/// which may not cover the whole curve.
+///
+/// # Errors
+///
+/// - if any geometry is not valid WKB
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::WKBArray;
+/// use geoarrow::io::wkb::wkb_total_bounds;
+/// use geo::{line_string, point, Geometry};
+///
+/// let array: WKBArray<i32> = vec![
+///     Some(Geometry::Point(point!(x: 1., y: 2.))),
+///     Some(Geometry::LineString(line_string![(x: -3., y: 4.), (x: 0., y: 0.)])),
+/// ]
+/// .as_slice()
+/// .try_into()
+/// .unwrap();
+///
+/// let bounds = wkb_total_bounds(&array).unwrap();
+/// assert_eq!((bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()), (-3., 0., 1., 4.));
+/// ```
+pub fn wkb_total_bounds<O: OffsetSizeTrait>(array: &WKBArray<O>) -> Result<BoundingRect> {
+    let mut bounds = BoundingRect::new();
+    for geom_idx in 0..array.len() {
+        if array.is_valid(geom_idx) {
+            bounds.update(&scan_buffer_bounds(array.array.value(geom_idx))?);
+        }
+    }
+    Ok(bounds)
+}
+
+/// Compute the bounding box of each geometry in a WKB array.
+///
+/// Like [`wkb_total_bounds`], this reads coordinates straight from the WKB buffers. Null and
+/// empty geometries have a null envelope.
+///
+/// # Errors
+///
+/// - if any geometry is not valid WKB
+pub fn wkb_envelopes<O: OffsetSizeTrait>(array: &WKBArray<O>) -> Result<RectArray> {
+    let envelopes = (0..array.len())
+        .map(|geom_idx| {
+            if array.is_null(geom_idx) {
+                return Ok(None);
+            }
+            let bounds = scan_buffer_bounds(array.array.value(geom_idx))?;
+            Ok((!bounds.is_empty()).then(|| geo::Rect::from(bounds)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(envelopes.into())
+}
+
+/// Scan a single WKB buffer, which must contain exactly one geometry.
+pub(crate) fn scan_buffer(buf: &[u8]) -> Result<WKBHeader> {
+    let mut reader = HeaderReader {
+        buf,
+        offset: 0,
+        depth: 0,
+        bounds: None,
+    };
+    let header = reader.scan_geometry()?;
+    reader.finish()?;
+    Ok(header)
+}
+
+/// Scan a single WKB buffer, which must contain exactly one geometry, returning its bounds.
+fn scan_buffer_bounds(buf: &[u8]) -> Result<BoundingRect> {
+    let mut reader = HeaderReader {
+        buf,
+        offset: 0,
+        depth: 0,
+        bounds: Some(BoundingRect::new()),
+    };
+    reader.scan_geometry()?;
+    reader.finish()?;
+    Ok(reader.bounds.unwrap())
+}
+
+struct HeaderReader<'a> {
+    buf: &'a [u8],
+    offset: usize,
+
+    /// The nesting depth of the geometry being scanned.
+    depth: usize,
+
+    /// If set, the bounds of the coordinates read so far. Otherwise coordinates are skipped.
+    bounds: Option<BoundingRect>,
+}
+
+impl<'a> HeaderReader<'a> {
+    /// Move past `num_bytes` bytes, erroring if the buffer is too short.
+    fn skip(&mut self, num_bytes: usize) -> Result<()> {
+        let end = self
+            .offset
+            .checked_add(num_bytes)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "truncated WKB buffer: needed {} bytes at offset {}, but the buffer has {}",
+                    num_bytes,
+                    self.offset,
+                    self.buf.len()
+                ))
+            })?;
+        self.offset = end;
+        Ok(())
+    }
+
+    /// Check that the whole buffer has been read.
+    fn finish(&self) -> Result<()> {
+        if self.offset != self.buf.len() {
+            return Err(GeoArrowError::General(format!(
+                "WKB buffer has {} unexpected trailing bytes",
+                self.buf.len() - self.offset
+            )));
+        }
+        Ok(())
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        let start = self.offset;
+        self.skip(1)?;
+        Ok(self.buf[start])
+    }
+
+    fn read_u32(&mut self, byte_order: Endianness) -> Result<u32> {
+        let start = self.offset;
+        self.skip(4)?;
+        let bytes: [u8; 4] = self.buf[start..start + 4].try_into().unwrap();
+        Ok(match byte_order {
+            Endianness::BigEndian => u32::from_be_bytes(bytes),
+            Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    /// Skip `count` coordinates of `dimension`, adding them to the bounds if they are tracked.
+    fn skip_coords(
+        &mut self,
+        count: u32,
+        dimension: WKBDimension,
+        byte_order: Endianness,
+    ) -> Result<()> {
+        let coord_size = dimension.size() * 8;
+        let num_bytes = (count as usize)
+            .checked_mul(coord_size)
+            .ok_or_else(|| GeoArrowError::General("WKB coordinate count overflows".to_string()))?;
+        let start = self.offset;
+        self.skip(num_bytes)?;
+
+        if let Some(bounds) = &mut self.bounds {
+            let read_f64 = |bytes: &[u8]| {
+                let bytes: [u8; 8] = bytes.try_into().unwrap();
+                match byte_order {
+                    Endianness::BigEndian => f64::from_be_bytes(bytes),
+                    Endianness::LittleEndian => f64::from_le_bytes(bytes),
+                }
+            };
+            for coord in self.buf[start..self.offset].chunks_exact(coord_size) {
+                let x = read_f64(&coord[0..8]);
+                let y = read_f64(&coord[8..16]);
+                if !x.is_nan() && !y.is_nan() {
+                    bounds.add_coord(&geo::coord! { x: x, y: y });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn scan_geometry(&mut self) -> Result<WKBHeader> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING_DEPTH {
+            return Err(GeoArrowError::General(format!(
+                "WKB geometries are nested more than {MAX_NESTING_DEPTH} levels deep"
+            )));
+        }
+        let header = self.scan_geometry_body()?;
+        self.depth -= 1;
+        Ok(header)
+    }
+
+    fn scan_geometry_body(&mut self) -> Result<WKBHeader> {
+        let byte_order = match self.read_u8()? {
+            0 => Endianness::BigEndian,
+            1 => Endianness::LittleEndian,
+            other => {
+                return Err(GeoArrowError::General(format!(
+                    "invalid WKB byte order {other}"
+                )))
+            }
+        };
+        let type_code = self.read_u32(byte_order)?;
+        let (geometry_type, dimension, has_srid) = decode_type_code(type_code)?;
+        if has_srid {
+            self.skip(4)?;
+        }
+
+        match geometry_type {
+            WKBGeometryType::Point => self.skip_coords(1, dimension, byte_order)?,
+            WKBGeometryType::LineString | WKBGeometryType::CircularString => {
+                let num_points = self.read_u32(byte_order)?;
+                self.skip_coords(num_points, dimension, byte_order)?;
+            }
+            WKBGeometryType::Polygon => {
+                let num_rings = self.read_u32(byte_order)?;
+                for _ in 0..num_rings {
+                    let num_points = self.read_u32(byte_order)?;
+                    self.skip_coords(num_points, dimension, byte_order)?;
+                }
+            }
+            WKBGeometryType::MultiPoint
+            | WKBGeometryType::MultiLineString
+            | WKBGeometryType::MultiPolygon
+            | WKBGeometryType::GeometryCollection
+            | WKBGeometryType::CompoundCurve
+            | WKBGeometryType::CurvePolygon
+            | WKBGeometryType::MultiCurve
+            | WKBGeometryType::MultiSurface => {
+                let num_parts = self.read_u32(byte_order)?;
+                for _ in 0..num_parts {
+                    self.scan_geometry()?;
+                }
+            }
+        }
+
+        Ok(WKBHeader {
+            geometry_type,
+            dimension,
+            byte_order,
+        })
+    }
+}
+
+/// Decode an ISO or extended WKB geometry type code into the geometry type, its dimension and
+/// whether an SRID follows.
+pub(crate) fn decode_type_code(type_code: u32) -> Result<(WKBGeometryType, WKBDimension, bool)> {
+    const EWKB_Z: u32 = 0x8000_0000;
+    const EWKB_M: u32 = 0x4000_0000;
+    const EWKB_SRID: u32 = 0x2000_0000;
+
+    let invalid = || GeoArrowError::General(format!("unknown WKB geometry type {type_code}"));
+
+    let (base_type, dimension, has_srid) = if type_code & (EWKB_Z | EWKB_M | EWKB_SRID) != 0 {
+        let dimension = match (type_code & EWKB_Z != 0, type_code & EWKB_M != 0) {
+            (false, false) => WKBDimension::XY,
+            (true, false) => WKBDimension::XYZ,
+            (false, true) => WKBDimension::XYM,
+            (true, true) => WKBDimension::XYZM,
+        };
+        (
+            type_code & !(EWKB_Z | EWKB_M | EWKB_SRID),
+            dimension,
+            type_code & EWKB_SRID != 0,
+        )
+    } else {
+        let dimension = match type_code / 1000 {
+            0 => WKBDimension::XY,
+            1 => WKBDimension::XYZ,
+            2 => WKBDimension::XYM,
+            3 => WKBDimension::XYZM,
+            _ => return Err(invalid()),
+        };
+        (type_code % 1000, dimension, false)
+    };
+
+    let geometry_type = WKBGeometryType::try_from(base_type).map_err(|_| invalid())?;
+    Ok((geometry_type, dimension, has_srid))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::mp_array;
+    use arrow_array::BinaryArray;
+
+    #[test]
+    fn nested_geometries() {
+        let geometries: Vec<_> = mp_array()
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(geo::Geometry::MultiPolygon))
+            .collect();
+        let array: WKBArray<i32> = geometries.as_slice().try_into().unwrap();
+        for header in scan_wkb(&array) {
+            let header = header.unwrap().unwrap();
+            assert_eq!(header.geometry_type, WKBGeometryType::MultiPolygon);
+            assert_eq!(header.byte_order, Endianness::LittleEndian);
+        }
+    }
+
+    #[test]
+    fn deeply_nested() {
+        // GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (... (POINT (1 2))))
+        let nested = |depth: usize| {
+            let mut buf = vec![];
+            for _ in 0..depth {
+                buf.push(1);
+                buf.extend(7u32.to_le_bytes());
+                buf.extend(1u32.to_le_bytes());
+            }
+            buf.push(1);
+            buf.extend(1u32.to_le_bytes());
+            [1f64, 2.].iter().for_each(|v| buf.extend(v.to_le_bytes()));
+            buf
+        };
+        assert!(scan_buffer(&nested(MAX_NESTING_DEPTH - 1)).is_ok());
+        assert!(scan_buffer(&nested(MAX_NESTING_DEPTH)).is_err());
+        assert!(scan_buffer_bounds(&nested(100_000)).is_err());
+    }
+
+    #[test]
+    fn truncated_and_dimensions() {
+        // POINT Z (1 2 3) as ISO WKB
+        let mut point_z = vec![1];
+        point_z.extend(1001u32.to_le_bytes());
+        [1f64, 2., 3.]
+            .iter()
+            .for_each(|v| point_z.extend(v.to_le_bytes()));
+        let truncated = point_z[..point_z.len() - 1].to_vec();
+        // POINT Z (1 2 3) as big endian EWKB with an SRID
+        let mut ewkb = vec![0];
+        ewkb.extend((1u32 | 0x8000_0000 | 0x2000_0000).to_be_bytes());
+        ewkb.extend(4326u32.to_be_bytes());
+        [1f64, 2., 3.]
+            .iter()
+            .for_each(|v| ewkb.extend(v.to_be_bytes()));
+
+        let binary = BinaryArray::from(vec![
+            point_z.as_slice(),
+            truncated.as_slice(),
+            ewkb.as_slice(),
+            &[1, 99, 0, 0, 0],
+        ]);
+        let headers = scan_wkb(&WKBArray::new(binary, Default::default()));
+
+        let header = headers[0].as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(header.dimension, WKBDimension::XYZ);
+        assert!(headers[1].as_ref().unwrap().is_err());
+        let header = headers[2].as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(header.dimension, WKBDimension::XYZ);
+        assert_eq!(header.byte_order, Endianness::BigEndian);
+        assert!(headers[3].as_ref().unwrap().is_err());
+    }
+
+    #[test]
+    fn bounds_match_native() {
+        use crate::algorithm::native::TotalBounds;
+
+        let native = mp_array();
+        let geometries: Vec<_> = native
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(geo::Geometry::MultiPolygon))
+            .collect();
+        let array: WKBArray<i32> = geometries.as_slice().try_into().unwrap();
+
+        let expected = native.total_bounds();
+        let bounds = wkb_total_bounds(&array).unwrap();
+        assert_eq!(
+            (bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()),
+            (
+                expected.minx(),
+                expected.miny(),
+                expected.maxx(),
+                expected.maxy()
+            )
+        );
+
+        let envelopes = wkb_envelopes(&array).unwrap();
+        assert_eq!(envelopes.len(), native.len());
+        for (envelope, geom) in envelopes.iter_geo().zip(geometries.iter()) {
+            let geom_bounds = geo::BoundingRect::bounding_rect(geom.as_ref().unwrap());
+            assert_eq!(envelope, geom_bounds);
+        }
+    }
+}
@@ -4,6 +4,7 @@ mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod options;
 mod point;
 mod polygon;
 
@@ -14,5 +15,7 @@ pub use linestring::{line_string_wkb_size, write_line_string_as_wkb};
 pub use multilinestring::{multi_line_string_wkb_size, write_multi_line_string_as_wkb};
 pub use multipoint::{multi_point_wkb_size, write_multi_point_as_wkb};
 pub use multipolygon::{multi_polygon_wkb_size, write_multi_polygon_as_wkb};
+pub(crate) use options::transcode_wkb;
+pub use options::{WKBFlavor, WKBWriteOptions};
 pub use point::{write_point_as_wkb, POINT_WKB_SIZE};
 pub use polygon::{polygon_wkb_size, write_polygon_as_wkb};
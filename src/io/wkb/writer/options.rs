@@ -0,0 +1,156 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::io::wkb::reader::Endianness;
+
+/// The flavor of WKB to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WKBFlavor {
+    /// ISO WKB, where the dimension is encoded in the geometry type code, e.g. `1001` for a
+    /// Point Z.
+    #[default]
+    Iso,
+
+    /// Extended WKB, as used by PostGIS, where the dimension is encoded with flags in the high
+    /// bits of the geometry type code. If `srid` is set, it is stored in the header of the
+    /// outermost geometry.
+    Extended { srid: Option<u32> },
+}
+
+/// Options for writing WKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WKBWriteOptions {
+    /// The byte order of all headers and coordinates. Defaults to little endian.
+    pub byte_order: Endianness,
+
+    /// The WKB flavor. Defaults to ISO WKB.
+    pub flavor: WKBFlavor,
+}
+
+impl Default for WKBWriteOptions {
+    fn default() -> Self {
+        Self {
+            byte_order: Endianness::LittleEndian,
+            flavor: WKBFlavor::Iso,
+        }
+    }
+}
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Re-encode a single ISO WKB geometry, as written by this crate, with the given options.
+pub(crate) fn transcode_wkb(buf: &[u8], options: &WKBWriteOptions, out: &mut Vec<u8>) {
+    let mut transcoder = Transcoder {
+        buf,
+        offset: 0,
+        options,
+        out,
+    };
+    transcoder.geometry(true);
+}
+
+struct Transcoder<'a> {
+    buf: &'a [u8],
+    offset: usize,
+    options: &'a WKBWriteOptions,
+    out: &'a mut Vec<u8>,
+}
+
+impl<'a> Transcoder<'a> {
+    fn read_u32(&mut self, byte_order: Endianness) -> u32 {
+        let bytes = &self.buf[self.offset..self.offset + 4];
+        self.offset += 4;
+        match byte_order {
+            Endianness::BigEndian => BigEndian::read_u32(bytes),
+            Endianness::LittleEndian => LittleEndian::read_u32(bytes),
+        }
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        let mut bytes = [0; 4];
+        match self.options.byte_order {
+            Endianness::BigEndian => BigEndian::write_u32(&mut bytes, value),
+            Endianness::LittleEndian => LittleEndian::write_u32(&mut bytes, value),
+        }
+        self.out.extend_from_slice(&bytes);
+    }
+
+    fn copy_u32(&mut self, byte_order: Endianness) -> u32 {
+        let value = self.read_u32(byte_order);
+        self.write_u32(value);
+        value
+    }
+
+    fn copy_f64s(&mut self, count: usize, byte_order: Endianness) {
+        for _ in 0..count {
+            let bytes = &self.buf[self.offset..self.offset + 8];
+            self.offset += 8;
+            let value = match byte_order {
+                Endianness::BigEndian => BigEndian::read_f64(bytes),
+                Endianness::LittleEndian => LittleEndian::read_f64(bytes),
+            };
+            let mut bytes = [0; 8];
+            match self.options.byte_order {
+                Endianness::BigEndian => BigEndian::write_f64(&mut bytes, value),
+                Endianness::LittleEndian => LittleEndian::write_f64(&mut bytes, value),
+            }
+            self.out.extend_from_slice(&bytes);
+        }
+    }
+
+    fn geometry(&mut self, outermost: bool) {
+        let byte_order = Endianness::from(self.buf[self.offset]);
+        self.offset += 1;
+        let type_code = self.read_u32(byte_order);
+        let (base_type, dimension) = (type_code % 1000, type_code / 1000);
+        let coord_size = match dimension {
+            0 => 2,
+            1 | 2 => 3,
+            _ => 4,
+        };
+
+        self.out.push(self.options.byte_order.into());
+        match self.options.flavor {
+            WKBFlavor::Iso => self.write_u32(type_code),
+            WKBFlavor::Extended { srid } => {
+                let mut ewkb_type = base_type;
+                if dimension == 1 || dimension == 3 {
+                    ewkb_type |= EWKB_Z;
+                }
+                if dimension == 2 || dimension == 3 {
+                    ewkb_type |= EWKB_M;
+                }
+                // Only the outermost geometry carries the SRID, as in PostGIS
+                match srid {
+                    Some(srid) if outermost => {
+                        self.write_u32(ewkb_type | EWKB_SRID);
+                        self.write_u32(srid);
+                    }
+                    _ => self.write_u32(ewkb_type),
+                }
+            }
+        }
+
+        match base_type {
+            1 => self.copy_f64s(coord_size, byte_order),
+            2 => {
+                let num_points = self.copy_u32(byte_order) as usize;
+                self.copy_f64s(num_points * coord_size, byte_order);
+            }
+            3 => {
+                let num_rings = self.copy_u32(byte_order);
+                for _ in 0..num_rings {
+                    let num_points = self.copy_u32(byte_order) as usize;
+                    self.copy_f64s(num_points * coord_size, byte_order);
+                }
+            }
+            _ => {
+                let num_parts = self.copy_u32(byte_order);
+                for _ in 0..num_parts {
+                    self.geometry(false);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Write geometries as [Well-Known Text](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry).
+//!
+//! These writers are used for the [`Display`][std::fmt::Display] implementations of geoarrow
+//! scalars and arrays. Parsing WKT is available through the `geozero` feature.
+
+mod writer;
+
+pub use writer::{
+    write_geometry, write_geometry_collection, write_line_string, write_multi_line_string,
+    write_multi_point, write_multi_polygon, write_point, write_polygon, write_rect,
+};
@@ -0,0 +1,238 @@
+use std::fmt::{Display, Result, Write};
+
+use geo::CoordFloat;
+
+use crate::geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+
+fn write_xy<T: CoordFloat + Display>(f: &mut impl Write, x: T, y: T) -> Result {
+    write!(f, "{} {}", x, y)
+}
+
+/// Write the coordinates of a ring or line string, including the surrounding parentheses.
+fn write_coords<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    line_string: &impl LineStringTrait<T = T>,
+) -> Result {
+    f.write_char('(')?;
+    for (i, coord) in line_string.coords().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_xy(f, coord.x(), coord.y())?;
+    }
+    f.write_char(')')
+}
+
+/// Write the rings of a polygon, including the surrounding parentheses.
+fn write_rings<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    polygon: &impl PolygonTrait<T = T>,
+) -> Result {
+    f.write_char('(')?;
+    if let Some(exterior) = polygon.exterior() {
+        write_coords(f, &exterior)?;
+    }
+    for interior in polygon.interiors() {
+        f.write_char(',')?;
+        write_coords(f, &interior)?;
+    }
+    f.write_char(')')
+}
+
+/// Write any Point as WKT. A point with NaN coordinates is written as `POINT EMPTY`.
+pub fn write_point<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    point: &impl PointTrait<T = T>,
+) -> Result {
+    let (x, y) = point.x_y();
+    if x.is_nan() && y.is_nan() {
+        return f.write_str("POINT EMPTY");
+    }
+    f.write_str("POINT(")?;
+    write_xy(f, x, y)?;
+    f.write_char(')')
+}
+
+/// Write any LineString as WKT.
+pub fn write_line_string<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    line_string: &impl LineStringTrait<T = T>,
+) -> Result {
+    if line_string.num_coords() == 0 {
+        return f.write_str("LINESTRING EMPTY");
+    }
+    f.write_str("LINESTRING")?;
+    write_coords(f, line_string)
+}
+
+/// Write any Polygon as WKT.
+pub fn write_polygon<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    polygon: &impl PolygonTrait<T = T>,
+) -> Result {
+    if polygon
+        .exterior()
+        .map_or(true, |exterior| exterior.num_coords() == 0)
+    {
+        return f.write_str("POLYGON EMPTY");
+    }
+    f.write_str("POLYGON")?;
+    write_rings(f, polygon)
+}
+
+/// Write any MultiPoint as WKT.
+pub fn write_multi_point<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    multi_point: &impl MultiPointTrait<T = T>,
+) -> Result {
+    if multi_point.num_points() == 0 {
+        return f.write_str("MULTIPOINT EMPTY");
+    }
+    f.write_str("MULTIPOINT(")?;
+    for (i, point) in multi_point.points().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        f.write_char('(')?;
+        write_xy(f, point.x(), point.y())?;
+        f.write_char(')')?;
+    }
+    f.write_char(')')
+}
+
+/// Write any MultiLineString as WKT.
+pub fn write_multi_line_string<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    multi_line_string: &impl MultiLineStringTrait<T = T>,
+) -> Result {
+    if multi_line_string.num_lines() == 0 {
+        return f.write_str("MULTILINESTRING EMPTY");
+    }
+    f.write_str("MULTILINESTRING(")?;
+    for (i, line_string) in multi_line_string.lines().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_coords(f, &line_string)?;
+    }
+    f.write_char(')')
+}
+
+/// Write any MultiPolygon as WKT.
+pub fn write_multi_polygon<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    multi_polygon: &impl MultiPolygonTrait<T = T>,
+) -> Result {
+    if multi_polygon.num_polygons() == 0 {
+        return f.write_str("MULTIPOLYGON EMPTY");
+    }
+    f.write_str("MULTIPOLYGON(")?;
+    for (i, polygon) in multi_polygon.polygons().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_rings(f, &polygon)?;
+    }
+    f.write_char(')')
+}
+
+/// Write any Rect as WKT. WKT has no rectangle type, so this is written as a `POLYGON`.
+pub fn write_rect<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    rect: &impl RectTrait<T = T>,
+) -> Result {
+    let (lower, upper) = (rect.lower(), rect.upper());
+    let (minx, miny, maxx, maxy) = (lower.x(), lower.y(), upper.x(), upper.y());
+    f.write_str("POLYGON((")?;
+    for (i, (x, y)) in [
+        (minx, miny),
+        (maxx, miny),
+        (maxx, maxy),
+        (minx, maxy),
+        (minx, miny),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_xy(f, x, y)?;
+    }
+    f.write_str("))")
+}
+
+/// Write any GeometryCollection as WKT.
+pub fn write_geometry_collection<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    geometry_collection: &impl GeometryCollectionTrait<T = T>,
+) -> Result {
+    if geometry_collection.num_geometries() == 0 {
+        return f.write_str("GEOMETRYCOLLECTION EMPTY");
+    }
+    f.write_str("GEOMETRYCOLLECTION(")?;
+    for (i, geometry) in geometry_collection.geometries().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_geometry(f, &geometry)?;
+    }
+    f.write_char(')')
+}
+
+/// Write any Geometry as WKT.
+pub fn write_geometry<T: CoordFloat + Display>(
+    f: &mut impl Write,
+    geometry: &impl GeometryTrait<T = T>,
+) -> Result {
+    match geometry.as_type() {
+        GeometryType::Point(geom) => write_point(f, geom),
+        GeometryType::LineString(geom) => write_line_string(f, geom),
+        GeometryType::Polygon(geom) => write_polygon(f, geom),
+        GeometryType::MultiPoint(geom) => write_multi_point(f, geom),
+        GeometryType::MultiLineString(geom) => write_multi_line_string(f, geom),
+        GeometryType::MultiPolygon(geom) => write_multi_polygon(f, geom),
+        GeometryType::GeometryCollection(geom) => write_geometry_collection(f, geom),
+        GeometryType::Rect(geom) => write_rect(f, geom),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon, Geometry, GeometryCollection, MultiPoint};
+
+    fn to_wkt(geometry: &Geometry) -> String {
+        let mut wkt = String::new();
+        write_geometry(&mut wkt, geometry).unwrap();
+        wkt
+    }
+
+    #[test]
+    fn write_geometries() {
+        assert_eq!(to_wkt(&point!(x: 1., y: 2.5).into()), "POINT(1 2.5)");
+        assert_eq!(
+            to_wkt(&line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into()),
+            "LINESTRING(0 0,1 1)"
+        );
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        assert_eq!(
+            to_wkt(&polygon.into()),
+            "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))"
+        );
+        let multi_point = MultiPoint::new(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]);
+        assert_eq!(to_wkt(&multi_point.into()), "MULTIPOINT((1 2),(3 4))");
+        assert_eq!(
+            to_wkt(&Geometry::GeometryCollection(GeometryCollection::new_from(
+                vec![]
+            ))),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+    }
+}
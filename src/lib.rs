@@ -1,11 +1,13 @@
 //! A Rust implementation of the [GeoArrow](https://github.com/geoarrow/geoarrow) specification,
 //! plus algorithms implemented on and returning these GeoArrow arrays.
 
-pub use trait_::GeometryArrayTrait;
+pub use trait_::{GeometryArrayRef, GeometryArrayTrait};
 
 pub mod algorithm;
 pub mod array;
 pub mod chunked_array;
+#[cfg(feature = "datafusion")]
+pub mod datafusion;
 pub mod datatypes;
 pub mod error;
 pub mod geo_traits;
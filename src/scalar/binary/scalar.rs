@@ -1,9 +1,11 @@
 use crate::io::geo::geometry_to_geo;
+use crate::io::wkt::write_geometry;
 use crate::trait_::GeometryScalarTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use geo::BoundingRect;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a Point
 #[derive(Debug, Clone)]
@@ -86,3 +88,10 @@ impl<O: OffsetSizeTrait> PartialEq for WKB<'_, O> {
         self.arr.value(self.geom_index) == other.arr.value(other.geom_index)
     }
 }
+
+impl<O: OffsetSizeTrait> Display for WKB<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_geometry(f, &self.to_geo())
+    }
+}
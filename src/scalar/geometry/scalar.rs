@@ -1,10 +1,12 @@
 use crate::algorithm::native::eq::geometry_eq;
 use crate::geo_traits::{GeometryTrait, GeometryType};
 use crate::io::geo::geometry_to_geo;
+use crate::io::wkt::write_geometry;
 use crate::scalar::*;
 use crate::trait_::GeometryScalarTrait;
 use arrow_array::OffsetSizeTrait;
 use rstar::{RTreeObject, AABB};
+use std::fmt::Display;
 
 /// A Geometry is an enum over the various underlying _zero copy_ GeoArrow scalar types.
 ///
@@ -155,3 +157,10 @@ impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> PartialEq<G> for Geometry<'_
         geometry_eq(self, other)
     }
 }
+
+impl<O: OffsetSizeTrait> Display for Geometry<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_geometry(f, self)
+    }
+}
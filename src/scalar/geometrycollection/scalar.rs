@@ -3,12 +3,14 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::MixedGeometryArray;
 use crate::geo_traits::GeometryCollectionTrait;
 use crate::io::geo::geometry_collection_to_geo;
+use crate::io::wkt::write_geometry_collection;
 use crate::scalar::Geometry;
 use crate::trait_::GeometryArrayAccessor;
 use crate::trait_::GeometryScalarTrait;
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use rstar::{RTreeObject, AABB};
+use std::fmt::Display;
 
 /// An Arrow equivalent of a GeometryCollection
 #[derive(Debug, Clone)]
@@ -119,3 +121,10 @@ impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> PartialEq<G>
         geometry_collection_eq(self, other)
     }
 }
+
+impl<O: OffsetSizeTrait> Display for GeometryCollection<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_geometry_collection(f, self)
+    }
+}
@@ -4,12 +4,14 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, LineStringArray};
 use crate::geo_traits::LineStringTrait;
 use crate::io::geo::line_string_to_geo;
+use crate::io::wkt::write_line_string;
 use crate::scalar::Point;
 use crate::trait_::{GeometryArraySelfMethods, GeometryScalarTrait};
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a LineString
 #[derive(Debug, Clone)]
@@ -157,6 +159,13 @@ impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> PartialEq<G> for LineStrin
     }
 }
 
+impl<O: OffsetSizeTrait> Display for LineString<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_line_string(f, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::LineStringArray;
@@ -4,6 +4,7 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, MultiLineStringArray};
 use crate::geo_traits::MultiLineStringTrait;
 use crate::io::geo::multi_line_string_to_geo;
+use crate::io::wkt::write_multi_line_string;
 use crate::scalar::LineString;
 use crate::trait_::GeometryArraySelfMethods;
 use crate::trait_::GeometryScalarTrait;
@@ -11,6 +12,7 @@ use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a MultiLineString
 #[derive(Debug, Clone)]
@@ -186,6 +188,13 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> PartialEq<G> for Mult
     }
 }
 
+impl<O: OffsetSizeTrait> Display for MultiLineString<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_multi_line_string(f, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::MultiLineStringArray;
@@ -4,6 +4,7 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, MultiPointArray};
 use crate::geo_traits::MultiPointTrait;
 use crate::io::geo::multi_point_to_geo;
+use crate::io::wkt::write_multi_point;
 use crate::scalar::Point;
 use crate::trait_::GeometryArraySelfMethods;
 use crate::trait_::GeometryScalarTrait;
@@ -11,6 +12,7 @@ use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a MultiPoint
 #[derive(Debug, Clone)]
@@ -159,6 +161,13 @@ impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> PartialEq<G> for MultiPoin
     }
 }
 
+impl<O: OffsetSizeTrait> Display for MultiPoint<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_multi_point(f, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::MultiPointArray;
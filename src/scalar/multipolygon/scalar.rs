@@ -4,12 +4,14 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, MultiPolygonArray};
 use crate::geo_traits::MultiPolygonTrait;
 use crate::io::geo::multi_polygon_to_geo;
+use crate::io::wkt::write_multi_polygon;
 use crate::scalar::Polygon;
 use crate::trait_::{GeometryArraySelfMethods, GeometryScalarTrait};
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a MultiPolygon
 #[derive(Debug, Clone)]
@@ -207,6 +209,13 @@ impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> PartialEq<G> for MultiPo
     }
 }
 
+impl<O: OffsetSizeTrait> Display for MultiPolygon<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_multi_polygon(f, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::MultiPolygonArray;
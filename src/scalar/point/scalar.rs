@@ -3,9 +3,11 @@ use crate::algorithm::native::eq::point_eq;
 use crate::array::CoordBuffer;
 use crate::geo_traits::{CoordTrait, PointTrait};
 use crate::io::geo::{coord_to_geo, point_to_geo};
+use crate::io::wkt::write_point;
 use crate::trait_::{GeometryArraySelfMethods, GeometryScalarTrait};
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a Point
 #[derive(Debug, Clone)]
@@ -164,6 +166,13 @@ impl<G: PointTrait<T = f64>> PartialEq<G> for Point<'_> {
     }
 }
 
+impl Display for Point<'_> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_point(f, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::{CoordBuffer, PointArray};
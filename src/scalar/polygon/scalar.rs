@@ -4,12 +4,14 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, PolygonArray};
 use crate::geo_traits::PolygonTrait;
 use crate::io::geo::polygon_to_geo;
+use crate::io::wkt::write_polygon;
 use crate::scalar::LineString;
 use crate::trait_::{GeometryArraySelfMethods, GeometryScalarTrait};
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 /// An Arrow equivalent of a Polygon
 #[derive(Debug, Clone)]
@@ -211,6 +213,13 @@ impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> PartialEq<G> for Polygon<'_,
     }
 }
 
+impl<O: OffsetSizeTrait> Display for Polygon<'_, O> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_polygon(f, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::PolygonArray;
@@ -1,10 +1,12 @@
 use arrow_buffer::ScalarBuffer;
 use rstar::{RTreeObject, AABB};
 use std::borrow::Cow;
+use std::fmt::Display;
 
 use crate::algorithm::native::eq::rect_eq;
 use crate::geo_traits::RectTrait;
 use crate::io::geo::rect_to_geo;
+use crate::io::wkt::write_rect;
 use crate::trait_::GeometryScalarTrait;
 
 #[derive(Debug, Clone)]
@@ -104,3 +106,10 @@ impl<G: RectTrait<T = f64>> PartialEq<G> for Rect<'_> {
         rect_eq(self, other)
     }
 }
+
+impl Display for Rect<'_> {
+    /// Write this geometry as WKT.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rect(f, self)
+    }
+}
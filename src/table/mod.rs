@@ -1,20 +1,40 @@
 //! Abstractions for Arrow tables. Useful for dataset IO where data will have geometries and
 //! attributes.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use arrow_array::{ArrayRef, RecordBatch};
-use arrow_schema::{FieldRef, SchemaBuilder, SchemaRef};
+use std::collections::{HashMap, HashSet};
 
-use crate::algorithm::native::Downcast;
+use arrow::compute::{cast, concat_batches, filter_record_batch, interleave, take};
+use arrow::row::{RowConverter, SortField};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, UInt64Type};
+use arrow_array::{new_empty_array, Array, ArrayRef, BooleanArray, RecordBatch, UInt32Array};
+use arrow_schema::{DataType, FieldRef, Schema, SchemaBuilder, SchemaRef};
+
+use crate::algorithm::geo::Centroid;
+use crate::algorithm::native::rechunk::{plan_rechunk, rows_for_bytes};
+use crate::algorithm::native::weighted_centroid::WeightedSum;
+use crate::algorithm::native::{BBoxIntersects, Downcast, FillNull, FillValue, Tile, XyzTiles};
+use crate::array::validation;
 use crate::array::*;
-use crate::chunked_array::{from_arrow_chunks, from_geoarrow_chunks, ChunkedGeometryArrayTrait};
+use crate::chunked_array::{from_arrow_chunks, ChunkedGeometryArrayTrait};
 use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::io::wkb::from_wkb;
+use crate::io::wkb::from_chunked_wkb;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use geo::BoundingRect;
 use phf::{phf_set, Set};
 
+pub use pipeline::{FilterBbox, MapCoords, Pipeline, PipelineChunk, PipelineStep, Simplify};
+pub use tile_pyramid::TilePyramidOptions;
+
+mod pipeline;
+mod tile_pyramid;
+
 static GEOARROW_EXTENSION_NAMES: Set<&'static str> = phf_set! {
     "geoarrow.point",
     "geoarrow.linestring",
@@ -28,6 +48,41 @@ static GEOARROW_EXTENSION_NAMES: Set<&'static str> = phf_set! {
     "ogc.wkb",
 };
 
+/// The kind of join performed by [`GeoTable::join`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// Only keep the rows of the left table with a match in the right table.
+    Inner,
+
+    /// Keep all rows of the left table, filling the right columns with nulls where there is no
+    /// match.
+    Left,
+}
+
+/// A spatial relationship tested by [`GeoTable::filter_predicate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialPredicate {
+    /// The geometry of a row and the query geometry share at least one point.
+    Intersects,
+
+    /// The geometry of a row contains the query geometry.
+    Contains,
+
+    /// The geometry of a row is within the query geometry.
+    Within,
+}
+
+impl SpatialPredicate {
+    fn evaluate(&self, geom: &geo::Geometry, query: &geo::Geometry) -> bool {
+        use geo::{Intersects, Relate};
+        match self {
+            SpatialPredicate::Intersects => geom.intersects(query),
+            SpatialPredicate::Contains => geom.relate(query).is_contains(),
+            SpatialPredicate::Within => geom.relate(query).is_within(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct GeoTable {
     schema: SchemaRef,
@@ -136,36 +191,22 @@ impl GeoTable {
             target_geo_data_type.unwrap_or(GeoDataType::LargeMixed(Default::default()));
         match chunked_geometry_array.data_type() {
             GeoDataType::WKB => {
-                let parsed_chunks = chunked_geometry_array
-                    .as_ref()
-                    .as_wkb()
-                    .chunks()
-                    .iter()
-                    .map(|chunk| from_wkb(chunk, target_geo_data_type, true))
-                    .collect::<Result<Vec<_>>>()?;
-                let parsed_chunks_refs = parsed_chunks
-                    .iter()
-                    .map(|chunk| chunk.as_ref())
-                    .collect::<Vec<_>>();
-                chunked_geometry_array = from_geoarrow_chunks(parsed_chunks_refs.as_slice())?
-                    .as_ref()
-                    .downcast(true);
+                chunked_geometry_array = from_chunked_wkb(
+                    chunked_geometry_array.as_ref().as_wkb(),
+                    target_geo_data_type,
+                    true,
+                )?
+                .as_ref()
+                .downcast(true);
             }
             GeoDataType::LargeWKB => {
-                let parsed_chunks = chunked_geometry_array
-                    .as_ref()
-                    .as_large_wkb()
-                    .chunks()
-                    .iter()
-                    .map(|chunk| from_wkb(chunk, target_geo_data_type, true))
-                    .collect::<Result<Vec<_>>>()?;
-                let parsed_chunks_refs = parsed_chunks
-                    .iter()
-                    .map(|chunk| chunk.as_ref())
-                    .collect::<Vec<_>>();
-                chunked_geometry_array = from_geoarrow_chunks(parsed_chunks_refs.as_slice())?
-                    .as_ref()
-                    .downcast(true);
+                chunked_geometry_array = from_chunked_wkb(
+                    chunked_geometry_array.as_ref().as_large_wkb(),
+                    target_geo_data_type,
+                    true,
+                )?
+                .as_ref()
+                .downcast(true);
             }
             _ => (),
         };
@@ -219,6 +260,451 @@ impl GeoTable {
         self.schema.fields().len()
     }
 
+    /// Split this table by the XYZ tiles at `zoom` that its geometries intersect.
+    ///
+    /// Each row is included in the table of every tile its geometry intersects, so rows whose
+    /// geometries cross tile boundaries appear in several tables. Rows with null geometries are
+    /// dropped. See [`XyzTiles`] for how tiles are assigned.
+    pub fn partition_by_tile(&self, zoom: u8) -> Result<BTreeMap<Tile, GeoTable>> {
+        // The (batch, row) indices of the rows intersecting each tile
+        let mut tile_rows: BTreeMap<Tile, Vec<(usize, usize)>> = BTreeMap::new();
+        let geometry = self.geometry()?;
+        for (batch_idx, chunk) in geometry.geometry_chunks().into_iter().enumerate() {
+            let tiles = chunk.xyz_tiles(zoom)?;
+            for row_idx in 0..tiles.len() {
+                if tiles.is_null(row_idx) {
+                    continue;
+                }
+                let tile_ids = tiles.value(row_idx);
+                for tile_id in tile_ids.as_primitive::<UInt64Type>().values() {
+                    tile_rows
+                        .entry(Tile::from_id(*tile_id))
+                        .or_default()
+                        .push((batch_idx, row_idx));
+                }
+            }
+        }
+
+        tile_rows
+            .into_iter()
+            .map(|(tile, rows)| {
+                let columns = (0..self.num_columns())
+                    .map(|col_idx| {
+                        let arrays = self
+                            .batches
+                            .iter()
+                            .map(|batch| batch.column(col_idx).as_ref())
+                            .collect::<Vec<_>>();
+                        interleave(&arrays, &rows)
+                    })
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+                let table = GeoTable::try_new(
+                    self.schema.clone(),
+                    vec![batch],
+                    self.geometry_column_index,
+                )?;
+                Ok((tile, table))
+            })
+            .collect()
+    }
+
+    /// Remove the rows of this table whose geometry is null.
+    pub fn drop_nulls(&self) -> Result<GeoTable> {
+        let geometry = self.geometry()?;
+        let batches = self
+            .batches
+            .iter()
+            .zip(geometry.geometry_chunks())
+            .map(|(batch, chunk)| {
+                let mask: BooleanArray =
+                    (0..chunk.len()).map(|i| Some(chunk.is_valid(i))).collect();
+                Ok(filter_record_batch(batch, &mask)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// Apply a [`ValidationPolicy`] to the geometry column of this table.
+    ///
+    /// With [`ValidationPolicy::Fix`], rows whose geometry can't be repaired are kept with a null
+    /// geometry.
+    pub fn validate_geometry(&self, policy: ValidationPolicy) -> Result<GeoTable> {
+        if policy == ValidationPolicy::Skip {
+            return Ok(self.clone());
+        }
+
+        let field = self.schema.field(self.geometry_column_index);
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| {
+                let geometry = from_arrow_array(batch.column(self.geometry_column_index), field)?;
+                let mut columns = batch.columns().to_vec();
+                columns[self.geometry_column_index] =
+                    validation::apply_policy(geometry, policy)?.to_array_ref();
+                Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// Start a [`Pipeline`] of operations on this table, beginning with `step`.
+    ///
+    /// Further operations are added with [`Pipeline::pipe`], e.g.
+    /// `table.pipe(Simplify(10.0)).pipe(FilterBbox(rect)).execute()`. The operations run in a
+    /// single pass over the chunks of this table.
+    pub fn pipe<'a>(&'a self, step: impl PipelineStep + 'a) -> Pipeline<'a> {
+        Pipeline::new(self).pipe(step)
+    }
+
+    /// Keep the rows of this table whose geometry's bounding box intersects `rect`.
+    ///
+    /// See [`BBoxIntersects`] for how bounding boxes are compared. Rows with null geometries are
+    /// dropped.
+    pub fn filter_bbox(&self, rect: &geo::Rect) -> Result<GeoTable> {
+        self.filter_geometry(|chunk| Ok(chunk.bbox_intersects(rect)))
+    }
+
+    /// Keep the rows of this table whose geometry satisfies `predicate` with respect to
+    /// `geometry`, e.g. the rows whose geometry is within `geometry` for
+    /// [`SpatialPredicate::Within`].
+    ///
+    /// Bounding boxes are compared first, so the exact predicate is only evaluated on candidate
+    /// rows. Rows with null geometries are dropped.
+    pub fn filter_predicate(
+        &self,
+        geometry: &geo::Geometry,
+        predicate: SpatialPredicate,
+    ) -> Result<GeoTable> {
+        let Some(query_rect) = geometry.bounding_rect() else {
+            // An empty query geometry has no relationship with any geometry
+            return self.filter_geometry(|chunk| Ok(BooleanArray::from(vec![false; chunk.len()])));
+        };
+        self.filter_geometry(|chunk| {
+            let candidates = chunk.bbox_intersects(&query_rect);
+            let geoms = geo_geometries(chunk)?;
+            Ok(candidates
+                .iter()
+                .zip(geoms)
+                .map(|(candidate, geom)| match (candidate, geom) {
+                    (Some(true), Some(geom)) => Some(predicate.evaluate(&geom, geometry)),
+                    (Some(_), _) => Some(false),
+                    (None, _) => None,
+                })
+                .collect())
+        })
+    }
+
+    /// Filter each batch with a mask computed from its geometry chunk.
+    fn filter_geometry(
+        &self,
+        mask: impl Fn(&dyn GeometryArrayTrait) -> Result<BooleanArray>,
+    ) -> Result<GeoTable> {
+        let geometry = self.geometry()?;
+        let batches = self
+            .batches
+            .iter()
+            .zip(geometry.geometry_chunks())
+            .map(|(batch, chunk)| Ok(filter_record_batch(batch, &mask(chunk)?)?))
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// Keep only the columns named in `columns`, in that order.
+    ///
+    /// # Errors
+    ///
+    /// - if a column does not exist
+    /// - if the geometry column is not selected
+    pub fn select(&self, columns: &[&str]) -> Result<GeoTable> {
+        let indices = columns
+            .iter()
+            .map(|name| Ok(self.schema.index_of(name)?))
+            .collect::<Result<Vec<_>>>()?;
+        let geometry_column_index = indices
+            .iter()
+            .position(|i| *i == self.geometry_column_index)
+            .ok_or(GeoArrowError::General(
+                "The geometry column must be selected".to_string(),
+            ))?;
+
+        let schema = Arc::new(self.schema.project(&indices)?);
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| Ok(batch.project(&indices)?))
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(schema, batches, geometry_column_index)
+    }
+
+    /// Remove the columns named in `columns`.
+    ///
+    /// # Errors
+    ///
+    /// - if a column does not exist
+    /// - if the geometry column would be dropped
+    pub fn drop(&self, columns: &[&str]) -> Result<GeoTable> {
+        for name in columns {
+            self.schema.index_of(name)?;
+        }
+        let kept = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .filter(|name| !columns.contains(name))
+            .collect::<Vec<_>>();
+        self.select(&kept)
+    }
+
+    /// Rechunk this table into batches of `target_rows` rows each; the last batch may be shorter.
+    ///
+    /// Small batches are concatenated together and large batches are split with zero-copy
+    /// slices.
+    ///
+    /// # Errors
+    ///
+    /// - if `target_rows` is zero
+    pub fn rechunk(&self, target_rows: usize) -> Result<GeoTable> {
+        let batch_lengths: Vec<_> = self.batches.iter().map(|batch| batch.num_rows()).collect();
+        let batches = plan_rechunk(&batch_lengths, target_rows)?
+            .into_iter()
+            .map(|slices| {
+                let parts: Vec<_> = slices
+                    .into_iter()
+                    .map(|(batch_idx, offset, length)| {
+                        self.batches[batch_idx].slice(offset, length)
+                    })
+                    .collect();
+                if parts.len() == 1 {
+                    Ok(parts.into_iter().next().unwrap())
+                } else {
+                    Ok(concat_batches(&self.schema, &parts)?)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// Rechunk this table into batches of about `target_bytes` each.
+    ///
+    /// The size of a row is estimated from the memory used by the existing batches, so output
+    /// batches are only approximately of the target size.
+    pub fn rechunk_by_bytes(&self, target_bytes: usize) -> Result<GeoTable> {
+        let total_bytes = self
+            .batches
+            .iter()
+            .map(|batch| batch.get_array_memory_size())
+            .sum();
+        self.rechunk(rows_for_bytes(total_bytes, self.len(), target_bytes))
+    }
+
+    /// Replace the null geometries of this table with `value`. See [`FillNull`] for details.
+    pub fn fill_null(&self, value: &FillValue) -> Result<GeoTable> {
+        let geometry = self.geometry()?;
+        let batches = self
+            .batches
+            .iter()
+            .zip(geometry.geometry_chunks())
+            .map(|(batch, chunk)| {
+                let mut columns = batch.columns().to_vec();
+                columns[self.geometry_column_index] =
+                    chunk.as_ref().fill_null(value)?.to_array_ref();
+                Ok(RecordBatch::try_new(batch.schema(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// Join the attributes of `other` onto this table, matching rows where the columns named in
+    /// `on` are equal in both.
+    ///
+    /// This is a hash join: `other` is hashed on its key columns and this table's batches are
+    /// probed against it, so `other` should usually be the smaller side. The geometry column of
+    /// this table is carried through unchanged. Rows whose keys contain a null never match.
+    ///
+    /// The key columns appear once in the output, followed by the remaining columns of `other`.
+    /// Columns of `other` whose names clash with columns of this table are suffixed with
+    /// `_right`, repeatedly until the name is unique. The schema metadata of this table and the
+    /// field metadata of both sides are kept.
+    pub fn join(&self, other: &RecordBatch, on: &[&str], how: JoinType) -> Result<GeoTable> {
+        let key_indices = |schema: &SchemaRef| {
+            on.iter()
+                .map(|name| Ok(schema.index_of(name)?))
+                .collect::<Result<Vec<_>>>()
+        };
+        let left_keys = key_indices(&self.schema)?;
+        let right_keys = key_indices(&other.schema())?;
+
+        let converter = RowConverter::new(
+            right_keys
+                .iter()
+                .map(|i| SortField::new(other.schema().field(*i).data_type().clone()))
+                .collect(),
+        )?;
+        let key_columns = |batch: &RecordBatch, indices: &[usize]| {
+            indices
+                .iter()
+                .map(|i| batch.column(*i).clone())
+                .collect::<Vec<_>>()
+        };
+        let has_null_key =
+            |columns: &[ArrayRef], row: usize| columns.iter().any(|column| column.is_null(row));
+
+        let right_key_columns = key_columns(other, &right_keys);
+        let right_rows = converter.convert_columns(&right_key_columns)?;
+        let mut right_index: HashMap<_, Vec<u32>> = HashMap::new();
+        for row in 0..other.num_rows() {
+            if !has_null_key(&right_key_columns, row) {
+                right_index
+                    .entry(right_rows.row(row))
+                    .or_default()
+                    .push(row as u32);
+            }
+        }
+
+        let right_columns = (0..other.num_columns())
+            .filter(|i| !right_keys.contains(i))
+            .collect::<Vec<_>>();
+        let mut schema_builder = SchemaBuilder::from(self.schema.as_ref().clone());
+        let mut names: HashSet<String> = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect();
+        for i in right_columns.iter() {
+            let field = other.schema().field(*i).clone();
+            let mut name = field.name().clone();
+            while names.contains(&name) {
+                name.push_str("_right");
+            }
+            names.insert(name.clone());
+            let nullable = field.is_nullable() || how == JoinType::Left;
+            schema_builder.push(field.with_name(name).with_nullable(nullable));
+        }
+        let schema = Arc::new(schema_builder.finish());
+
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| {
+                let left_key_columns = key_columns(batch, &left_keys);
+                let left_rows = converter.convert_columns(&left_key_columns)?;
+
+                let mut left_take = vec![];
+                let mut right_take = vec![];
+                for row in 0..batch.num_rows() {
+                    let matches = if has_null_key(&left_key_columns, row) {
+                        None
+                    } else {
+                        right_index.get(&left_rows.row(row))
+                    };
+                    match (matches, how) {
+                        (Some(matches), _) => {
+                            left_take.extend(std::iter::repeat(row as u32).take(matches.len()));
+                            right_take.extend(matches.iter().map(|i| Some(*i)));
+                        }
+                        (None, JoinType::Left) => {
+                            left_take.push(row as u32);
+                            right_take.push(None);
+                        }
+                        (None, JoinType::Inner) => (),
+                    }
+                }
+
+                let left_take = UInt32Array::from(left_take);
+                let right_take = UInt32Array::from(right_take);
+                let mut columns = batch
+                    .columns()
+                    .iter()
+                    .map(|column| take(column, &left_take, None))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                for i in right_columns.iter() {
+                    columns.push(take(other.column(*i), &right_take, None)?);
+                }
+                Ok(RecordBatch::try_new(schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        GeoTable::try_new(schema, batches, self.geometry_column_index)
+    }
+
+    /// Compute the [`WeightedCentroid`][crate::algorithm::native::WeightedCentroid] of the
+    /// geometries in each group of rows with equal values in the `by` column, weighted by the
+    /// `weights` column.
+    ///
+    /// The output has the `by` column followed by a point geometry column, with one row per group
+    /// in order of first appearance. Rows with a null key form one group. Geometries other than
+    /// points are represented by their centroid. The geometry of a group is null if its weights
+    /// sum to zero.
+    ///
+    /// # Errors
+    ///
+    /// - if either column doesn't exist
+    /// - if the `weights` column can't be cast to `Float64`
+    pub fn weighted_centroid_by(&self, weights: &str, by: &str) -> Result<GeoTable> {
+        let weights_index = self.schema.index_of(weights)?;
+        let by_index = self.schema.index_of(by)?;
+        let key_field = self.schema.field(by_index).clone();
+        let geometry_field = self.schema.field(self.geometry_column_index);
+        let converter = RowConverter::new(vec![SortField::new(key_field.data_type().clone())])?;
+
+        let mut groups = HashMap::new();
+        let mut first_rows = vec![];
+        let mut sums: Vec<WeightedSum> = vec![];
+        for (batch_index, batch) in self.batches.iter().enumerate() {
+            let geometry =
+                from_arrow_array(batch.column(self.geometry_column_index), geometry_field)?;
+            let points = match geometry.data_type() {
+                GeoDataType::Point(_) => geometry.as_ref().as_point().clone(),
+                _ => geometry.as_ref().centroid()?,
+            };
+            let weights = cast(batch.column(weights_index), &DataType::Float64)?;
+            let keys = converter.convert_columns(&[batch.column(by_index).clone()])?;
+
+            let values = points
+                .iter_geo()
+                .zip(weights.as_primitive::<Float64Type>().iter());
+            for (row, (point, weight)) in values.enumerate() {
+                let group = *groups.entry(keys.row(row).owned()).or_insert_with(|| {
+                    first_rows.push((batch_index, row));
+                    sums.push(WeightedSum::default());
+                    sums.len() - 1
+                });
+                if let (Some(point), Some(weight)) = (point, weight) {
+                    sums[group].add(point, weight);
+                }
+            }
+        }
+
+        let keys = if first_rows.is_empty() {
+            new_empty_array(key_field.data_type())
+        } else {
+            let key_arrays = self
+                .batches
+                .iter()
+                .map(|batch| batch.column(by_index).as_ref())
+                .collect::<Vec<_>>();
+            interleave(&key_arrays, &first_rows)?
+        };
+        let centroids: PointArray = sums
+            .iter()
+            .map(|sum| sum.finish())
+            .collect::<Vec<_>>()
+            .into();
+
+        let centroid_field = centroids
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_name(geometry_field.name());
+        let schema = Arc::new(Schema::new(vec![key_field, centroid_field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![keys, centroids.into_array_ref()])?;
+        GeoTable::try_new(schema, vec![batch], 1)
+    }
+
     pub(crate) fn remove_column(&mut self, i: usize) -> ChunkedArray<ArrayRef> {
         // NOTE: remove_column drops schema metadata as of
         // https://github.com/apache/arrow-rs/issues/5327
@@ -411,3 +897,232 @@ impl GeoTable {
         }
     }
 }
+
+/// The geometries of an array as geo objects.
+fn geo_geometries(array: &dyn GeometryArrayTrait) -> Result<Vec<Option<geo::Geometry>>> {
+    macro_rules! collect_geo {
+        ($array:expr) => {
+            collect_geo!($array, geo::Geometry::from)
+        };
+        ($array:expr, $into_geometry:expr) => {
+            $array
+                .iter_geo()
+                .map(|maybe_geom| maybe_geom.map($into_geometry))
+                .collect()
+        };
+    }
+
+    let geoms = match array.data_type() {
+        GeoDataType::Point(_) => collect_geo!(array.as_point()),
+        GeoDataType::LineString(_) => collect_geo!(array.as_line_string()),
+        GeoDataType::LargeLineString(_) => collect_geo!(array.as_large_line_string()),
+        GeoDataType::Polygon(_) => collect_geo!(array.as_polygon()),
+        GeoDataType::LargePolygon(_) => collect_geo!(array.as_large_polygon()),
+        GeoDataType::MultiPoint(_) => collect_geo!(array.as_multi_point()),
+        GeoDataType::LargeMultiPoint(_) => collect_geo!(array.as_large_multi_point()),
+        GeoDataType::MultiLineString(_) => collect_geo!(array.as_multi_line_string()),
+        GeoDataType::LargeMultiLineString(_) => collect_geo!(array.as_large_multi_line_string()),
+        GeoDataType::MultiPolygon(_) => collect_geo!(array.as_multi_polygon()),
+        GeoDataType::LargeMultiPolygon(_) => collect_geo!(array.as_large_multi_polygon()),
+        GeoDataType::Mixed(_) => collect_geo!(array.as_mixed()),
+        GeoDataType::LargeMixed(_) => collect_geo!(array.as_large_mixed()),
+        GeoDataType::GeometryCollection(_) => collect_geo!(
+            array.as_geometry_collection(),
+            geo::Geometry::GeometryCollection
+        ),
+        GeoDataType::LargeGeometryCollection(_) => collect_geo!(
+            array.as_large_geometry_collection(),
+            geo::Geometry::GeometryCollection
+        ),
+        GeoDataType::WKB => collect_geo!(array.as_wkb()),
+        GeoDataType::LargeWKB => collect_geo!(array.as_large_wkb()),
+        GeoDataType::Rect => collect_geo!(array.as_rect()),
+    };
+    Ok(geoms)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsChunkedGeometryArray;
+    use crate::test::point;
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::{StringArray, UInt8Array};
+    use arrow_schema::Field;
+
+    fn attributes() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("u8", DataType::UInt8, true),
+            Field::new("string", DataType::Utf8, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(UInt8Array::from(vec![1, 2, 2])),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])),
+                Arc::new(StringArray::from(vec!["one", "two", "two again"])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn inner_join() {
+        let table = point::table();
+        let joined = table.join(&attributes(), &["u8"], JoinType::Inner).unwrap();
+
+        // u8 values of the points are [1, 2, 3]; 2 matches twice and 3 doesn't match
+        assert_eq!(joined.len(), 3);
+        assert_eq!(joined.geometry_column_index(), 2);
+        assert_eq!(joined.schema().field(3).name(), "string_right");
+        assert_eq!(joined.schema().field(4).name(), "name");
+
+        let geometry = joined.geometry().unwrap();
+        let points = geometry.as_ref().as_point().chunks()[0].clone();
+        assert_eq!(points.value_as_geo(0), point::p0());
+        assert_eq!(points.value_as_geo(1), point::p1());
+        assert_eq!(points.value_as_geo(2), point::p1());
+    }
+
+    #[test]
+    fn left_join() {
+        let table = point::table();
+        let joined = table.join(&attributes(), &["u8"], JoinType::Left).unwrap();
+        assert_eq!(joined.len(), 4);
+        assert!(joined.schema().field(4).is_nullable());
+
+        let names = joined.batches()[0].column(4).as_string::<i32>();
+        assert_eq!(names.value(2), "two again");
+        assert!(names.is_null(3));
+    }
+
+    #[test]
+    fn join_keeps_metadata_and_unique_names() {
+        let table = point::table();
+        let metadata = HashMap::from([("key".to_string(), "value".to_string())]);
+        let schema = Arc::new(Schema::clone(table.schema()).with_metadata(metadata.clone()));
+        let batches = table
+            .batches()
+            .iter()
+            .map(|batch| batch.clone().with_schema(schema.clone()).unwrap())
+            .collect();
+        let table = GeoTable::try_new(schema, batches, table.geometry_column_index()).unwrap();
+
+        let other_schema = Schema::new(vec![
+            Field::new("u8", DataType::UInt8, true),
+            Field::new("string_right", DataType::Utf8, true),
+            Field::new("string", DataType::Utf8, true).with_metadata(metadata.clone()),
+        ]);
+        let other = RecordBatch::try_new(
+            Arc::new(other_schema),
+            vec![
+                Arc::new(UInt8Array::from(vec![1])),
+                Arc::new(StringArray::from(vec!["a"])),
+                Arc::new(StringArray::from(vec!["b"])),
+            ],
+        )
+        .unwrap();
+
+        let joined = table.join(&other, &["u8"], JoinType::Inner).unwrap();
+        assert_eq!(joined.schema().metadata(), &metadata);
+        assert_eq!(joined.schema().field(3).name(), "string_right");
+        assert_eq!(joined.schema().field(4).name(), "string_right_right");
+        assert_eq!(joined.schema().field(4).metadata(), &metadata);
+    }
+
+    #[test]
+    fn filter_bbox() {
+        let table = point::table();
+        let filtered = table
+            .filter_bbox(&geo::Rect::new((0.5, 1.5), (2., 3.)))
+            .unwrap();
+        assert_eq!(filtered.len(), 2);
+    }
+
+    #[test]
+    fn filter_predicate() {
+        let table = point::table();
+        let query: geo::Geometry = geo::Rect::new((-1., 0.), (1.5, 2.5)).to_polygon().into();
+        let within = table
+            .filter_predicate(&query, SpatialPredicate::Within)
+            .unwrap();
+        assert_eq!(within.len(), 2);
+
+        let contains = table
+            .filter_predicate(&query, SpatialPredicate::Contains)
+            .unwrap();
+        assert_eq!(contains.len(), 0);
+    }
+
+    #[test]
+    fn select_and_drop() {
+        let table = point::table();
+        let selected = table.select(&["geometry", "u8"]).unwrap();
+        assert_eq!(selected.num_columns(), 2);
+        assert_eq!(selected.geometry_column_index(), 0);
+        assert!(selected.geometry().is_ok());
+
+        let dropped = table.drop(&["string"]).unwrap();
+        assert_eq!(dropped.num_columns(), 2);
+        assert_eq!(dropped.geometry_column_index(), 1);
+
+        assert!(table.select(&["u8"]).is_err());
+        assert!(table.drop(&["geometry"]).is_err());
+        assert!(table.drop(&["missing"]).is_err());
+    }
+
+    #[test]
+    fn pipe() {
+        let table = point::table();
+        let output = table
+            .pipe(MapCoords(|x, y| Ok((x * 2., y * 2.))))
+            .pipe(FilterBbox(geo::Rect::new((1., 3.), (5., 7.))))
+            .pipe(Simplify(1.))
+            .execute()
+            .unwrap();
+        assert_eq!(output.len(), 2);
+        assert_eq!(output.schema().field(2).name(), "geometry");
+        assert_eq!(output.batches()[0].column(0).len(), 2);
+
+        let geometry = output.geometry().unwrap();
+        let points = geometry.as_ref().as_point().chunks()[0].clone();
+        assert_eq!(points.value_as_geo(0), geo::point!(x: 2., y: 4.));
+
+        let failing = table.pipe(MapCoords(|_, _| {
+            Err(GeoArrowError::General("failed".to_string()))
+        }));
+        assert!(failing.execute().is_err());
+    }
+
+    #[test]
+    fn weighted_centroid_by() {
+        let table = point::table();
+        let batch = table.batches()[0].clone();
+        let by = Arc::new(StringArray::from(vec![Some("a"), None, Some("a")]));
+        let weights = Arc::new(UInt8Array::from(vec![1, 5, 3]));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("by", DataType::Utf8, true),
+            Field::new("weight", DataType::UInt8, false),
+            table.schema().field(2).clone(),
+        ]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![by, weights, batch.column(2).clone()])
+                .unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 2).unwrap();
+
+        let grouped = table.weighted_centroid_by("weight", "by").unwrap();
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped.geometry_column_index(), 1);
+        let keys = grouped.batches()[0].column(0).as_string::<i32>();
+        assert_eq!(keys.value(0), "a");
+        assert!(keys.is_null(1));
+
+        let geometry = grouped.geometry().unwrap();
+        let points = geometry.as_ref().as_point().chunks()[0].clone();
+        // p0 = (0, 1) with weight 1 and p2 = (2, 3) with weight 3
+        assert_eq!(points.value_as_geo(0), geo::point!(x: 1.5, y: 2.5));
+        assert_eq!(points.value_as_geo(1), point::p1());
+        assert!(table.weighted_centroid_by("missing", "by").is_err());
+    }
+}
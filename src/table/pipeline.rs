@@ -0,0 +1,186 @@
+//! Lazily composed operations on the chunks of a [`GeoTable`].
+
+use std::sync::Arc;
+
+use arrow::compute::{filter, filter_record_batch};
+use arrow_array::{BooleanArray, RecordBatch};
+use arrow_schema::Schema;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::algorithm::geo::Simplify as _Simplify;
+use crate::algorithm::native::{BBoxIntersects, MapCoords as _MapCoords};
+use crate::array::from_arrow_array;
+use crate::error::Result;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+/// One chunk of a table flowing through a [`Pipeline`].
+#[derive(Debug, Clone)]
+pub struct PipelineChunk {
+    /// The attribute columns of this chunk, i.e. every column except the geometry column.
+    pub attributes: RecordBatch,
+
+    /// The geometries of this chunk.
+    pub geometry: Arc<dyn GeometryArrayTrait>,
+}
+
+impl PipelineChunk {
+    /// Keep the rows where `mask` is `true`. Null values in `mask` are treated as `false`.
+    pub fn filter(self, mask: &BooleanArray) -> Result<Self> {
+        let geometry = filter(&self.geometry.to_array_ref(), mask)?;
+        Ok(Self {
+            attributes: filter_record_batch(&self.attributes, mask)?,
+            geometry: from_arrow_array(&geometry, &self.geometry.extension_field())?,
+        })
+    }
+}
+
+/// An operation that can be applied chunk by chunk in a [`Pipeline`].
+///
+/// This is implemented for the operations in this module, and can be implemented for custom
+/// operations.
+pub trait PipelineStep: Send + Sync {
+    /// Apply this operation to one chunk of a table.
+    fn apply(&self, chunk: PipelineChunk) -> Result<PipelineChunk>;
+}
+
+/// Simplify geometries with the given epsilon, see [`Simplify`][crate::algorithm::geo::Simplify].
+#[derive(Debug, Clone, Copy)]
+pub struct Simplify(pub f64);
+
+impl PipelineStep for Simplify {
+    fn apply(&self, chunk: PipelineChunk) -> Result<PipelineChunk> {
+        Ok(PipelineChunk {
+            geometry: chunk.geometry.as_ref().simplify(&self.0)?,
+            ..chunk
+        })
+    }
+}
+
+/// Keep the rows whose geometry's bounding box intersects a rectangle, see
+/// [`GeoTable::filter_bbox`].
+#[derive(Debug, Clone, Copy)]
+pub struct FilterBbox(pub geo::Rect);
+
+impl PipelineStep for FilterBbox {
+    fn apply(&self, chunk: PipelineChunk) -> Result<PipelineChunk> {
+        let mask = chunk.geometry.as_ref().bbox_intersects(&self.0);
+        chunk.filter(&mask)
+    }
+}
+
+/// Apply a fallible function to every coordinate, e.g. to reproject geometries, see
+/// [`MapCoords`][crate::algorithm::native::MapCoords].
+pub struct MapCoords<F>(pub F);
+
+impl<F> PipelineStep for MapCoords<F>
+where
+    F: Fn(f64, f64) -> Result<(f64, f64)> + Send + Sync,
+{
+    fn apply(&self, chunk: PipelineChunk) -> Result<PipelineChunk> {
+        Ok(PipelineChunk {
+            geometry: chunk.geometry.as_ref().try_map_coords(&self.0)?,
+            ..chunk
+        })
+    }
+}
+
+/// A sequence of operations on a [`GeoTable`], created by [`GeoTable::pipe`].
+///
+/// Nothing is computed until [`execute`][Self::execute] is called. Then each chunk of the table
+/// is passed through all operations in turn, so only one intermediate result per chunk is held
+/// in memory at a time. If the `rayon` feature is active, chunks are processed in parallel.
+pub struct Pipeline<'a> {
+    table: &'a GeoTable,
+    steps: Vec<Box<dyn PipelineStep + 'a>>,
+}
+
+impl<'a> Pipeline<'a> {
+    pub(crate) fn new(table: &'a GeoTable) -> Self {
+        Self {
+            table,
+            steps: vec![],
+        }
+    }
+
+    /// Add an operation to the end of this pipeline.
+    pub fn pipe(mut self, step: impl PipelineStep + 'a) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// Run the pipeline, returning a new table.
+    ///
+    /// The geometry column keeps its position and name. Its type is that of the output of the
+    /// first chunk.
+    ///
+    /// # Errors
+    ///
+    /// - if any operation fails
+    /// - if operations return geometries of different types for different chunks
+    pub fn execute(self) -> Result<GeoTable> {
+        let geometry_column_index = self.table.geometry_column_index();
+        let geometry_field = self.table.schema().field(geometry_column_index).clone();
+        let attribute_indices = (0..self.table.num_columns())
+            .filter(|i| *i != geometry_column_index)
+            .collect::<Vec<_>>();
+
+        let run = |batch: &RecordBatch| -> Result<PipelineChunk> {
+            let mut chunk = PipelineChunk {
+                attributes: batch.project(&attribute_indices)?,
+                geometry: from_arrow_array(batch.column(geometry_column_index), &geometry_field)?,
+            };
+            for step in self.steps.iter() {
+                chunk = step.apply(chunk)?;
+            }
+            Ok(chunk)
+        };
+
+        #[cfg(feature = "rayon")]
+        let chunks = self
+            .table
+            .batches()
+            .par_iter()
+            .map(run)
+            .collect::<Result<Vec<_>>>()?;
+
+        #[cfg(not(feature = "rayon"))]
+        let chunks = self
+            .table
+            .batches()
+            .iter()
+            .map(run)
+            .collect::<Result<Vec<_>>>()?;
+
+        let Some(first) = chunks.first() else {
+            return Ok(self.table.clone());
+        };
+        let output_field = first.geometry.extension_field();
+        let mut fields = first.attributes.schema().fields().to_vec();
+        fields.insert(
+            geometry_column_index,
+            Arc::new(
+                output_field
+                    .as_ref()
+                    .clone()
+                    .with_name(geometry_field.name()),
+            ),
+        );
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.table.schema().metadata().clone(),
+        ));
+
+        let batches = chunks
+            .into_iter()
+            .map(|chunk| {
+                let mut columns = chunk.attributes.columns().to_vec();
+                columns.insert(geometry_column_index, chunk.geometry.to_array_ref());
+                Ok(RecordBatch::try_new(schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        GeoTable::try_new(schema, batches, geometry_column_index)
+    }
+}
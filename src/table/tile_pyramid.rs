@@ -0,0 +1,215 @@
+//! Building a pyramid of XYZ tiles from a [`GeoTable`].
+
+use std::collections::BTreeMap;
+
+use arrow_array::BooleanArray;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::algorithm::native::{ClipToTile, Tile, MAX_TILE_ZOOM};
+use crate::error::{GeoArrowError, Result};
+use crate::table::{GeoTable, PipelineChunk, PipelineStep, Simplify};
+
+/// Options for [`GeoTable::tile_pyramid`].
+///
+/// Distances are given in tile units, where a tile is `extent` units across, as in vector tiles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TilePyramidOptions {
+    /// The number of units across a tile.
+    pub extent: u32,
+
+    /// The distance around each tile up to which geometries are kept when clipping, so that
+    /// lines and polygon edges don't show seams at tile boundaries when rendered.
+    pub buffer: u32,
+
+    /// The epsilon with which geometries are simplified at each zoom level. Set to `0.` to turn
+    /// off simplification.
+    pub simplify_tolerance: f64,
+}
+
+impl Default for TilePyramidOptions {
+    fn default() -> Self {
+        Self {
+            extent: 4096,
+            buffer: 64,
+            simplify_tolerance: 1.,
+        }
+    }
+}
+
+impl TilePyramidOptions {
+    /// The width of a tile unit of `tile` in degrees of longitude.
+    fn unit_width(&self, tile: &Tile) -> f64 {
+        tile.bounds().width() / self.extent as f64
+    }
+}
+
+/// Clip geometries to a buffered tile, dropping the rows whose geometry lies entirely outside it,
+/// see [`ClipToTile`].
+struct ClipTile {
+    tile: Tile,
+    extent: u32,
+    buffer: u32,
+}
+
+impl PipelineStep for ClipTile {
+    fn apply(&self, chunk: PipelineChunk) -> Result<PipelineChunk> {
+        let clipped = chunk
+            .geometry
+            .as_ref()
+            .clip_to_tile(&self.tile, self.extent, self.buffer)?
+            .geometry;
+        let mask: BooleanArray = (0..clipped.len())
+            .map(|i| Some(clipped.is_valid(i)))
+            .collect();
+        PipelineChunk {
+            geometry: clipped,
+            ..chunk
+        }
+        .filter(&mask)
+    }
+}
+
+impl GeoTable {
+    /// Cut this table into the XYZ tiles of every zoom level from `min_zoom` to `max_zoom`, e.g.
+    /// to serve it as vector tiles.
+    ///
+    /// At each zoom level, rows are assigned to tiles as in [`GeoTable::partition_by_tile`]. The
+    /// geometries of each tile are then clipped to the tile, expanded by the buffer (see
+    /// [`ClipToTile`]), and
+    /// simplified with a tolerance that grows with the size of the tile, so that lower zoom
+    /// levels hold less detail. Tiles in which no geometry remains are left out. If the `rayon`
+    /// feature is active, tiles are processed in parallel.
+    ///
+    /// Coordinates are interpreted as longitude and latitude in degrees. Line strings and
+    /// polygons are returned as multi line strings and multi polygons.
+    ///
+    /// # Errors
+    ///
+    /// - if `min_zoom` is greater than `max_zoom`, or `max_zoom` is greater than
+    ///   [`MAX_TILE_ZOOM`]
+    /// - if the geometry column is not made of points, line strings or polygons
+    pub fn tile_pyramid(
+        &self,
+        min_zoom: u8,
+        max_zoom: u8,
+        options: &TilePyramidOptions,
+    ) -> Result<BTreeMap<Tile, GeoTable>> {
+        if min_zoom > max_zoom || max_zoom > MAX_TILE_ZOOM {
+            return Err(GeoArrowError::General(format!(
+                "Invalid zoom range {min_zoom}..={max_zoom}, zoom levels must be increasing and at most {MAX_TILE_ZOOM}"
+            )));
+        }
+        if options.extent == 0 {
+            return Err(GeoArrowError::General(
+                "Tile extent must be greater than 0".to_string(),
+            ));
+        }
+
+        let build_tile = |(tile, table): (Tile, GeoTable)| -> Result<Option<(Tile, GeoTable)>> {
+            let mut pipeline = table.pipe(ClipTile {
+                tile,
+                extent: options.extent,
+                buffer: options.buffer,
+            });
+            if options.simplify_tolerance > 0. {
+                let tolerance = options.simplify_tolerance * options.unit_width(&tile);
+                pipeline = pipeline.pipe(Simplify(tolerance));
+            }
+            let output = pipeline.execute()?;
+            Ok((!output.is_empty()).then_some((tile, output)))
+        };
+
+        let mut pyramid = BTreeMap::new();
+        for zoom in min_zoom..=max_zoom {
+            let partitions = self.partition_by_tile(zoom)?;
+
+            #[cfg(feature = "rayon")]
+            let tiles = partitions
+                .into_par_iter()
+                .map(build_tile)
+                .collect::<Result<Vec<_>>>()?;
+
+            #[cfg(not(feature = "rayon"))]
+            let tiles = partitions
+                .into_iter()
+                .map(build_tile)
+                .collect::<Result<Vec<_>>>()?;
+
+            pyramid.extend(tiles.into_iter().flatten());
+        }
+        Ok(pyramid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{AsGeometryArray, LineStringArray};
+    use crate::trait_::GeometryArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use arrow_array::{RecordBatch, UInt8Array};
+    use arrow_schema::{DataType, Field, Schema};
+    use geo::{line_string, BoundingRect};
+    use std::sync::Arc;
+
+    fn line_table() -> GeoTable {
+        let lines: LineStringArray<i32> = vec![
+            Some(line_string![(x: -10., y: 10.), (x: 10., y: 10.)]),
+            None,
+            Some(line_string![(x: 100., y: -20.), (x: 110., y: -30.)]),
+        ]
+        .into();
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("id", DataType::UInt8, false)),
+            lines.extension_field(),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(UInt8Array::from(vec![0, 1, 2])),
+                lines.into_array_ref(),
+            ],
+        )
+        .unwrap();
+        GeoTable::try_new(schema, vec![batch], 1).unwrap()
+    }
+
+    #[test]
+    fn clips_lines_per_zoom() {
+        let pyramid = line_table()
+            .tile_pyramid(0, 1, &TilePyramidOptions::default())
+            .unwrap();
+        let tiles = pyramid.keys().copied().collect::<Vec<_>>();
+        assert_eq!(
+            tiles,
+            vec![
+                Tile::new(0, 0, 0),
+                Tile::new(1, 0, 0),
+                Tile::new(1, 1, 0),
+                Tile::new(1, 1, 1)
+            ]
+        );
+        assert_eq!(pyramid[&Tile::new(0, 0, 0)].len(), 2);
+
+        // The line crossing the antimeridian of zoom 1 is cut at the edge of the buffer
+        let options = TilePyramidOptions::default();
+        let west = &pyramid[&Tile::new(1, 0, 0)];
+        assert_eq!(west.len(), 1);
+        let geometry = west.geometry().unwrap();
+        let chunk = geometry.geometry_chunks()[0];
+        let clipped = chunk.as_multi_line_string().value_as_geo(0);
+        let max_x = clipped.bounding_rect().unwrap().max().x;
+        let buffer = options.buffer as f64 * options.unit_width(&Tile::new(1, 0, 0));
+        assert!((max_x - buffer).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_zoom_range() {
+        let table = line_table();
+        let options = TilePyramidOptions::default();
+        assert!(table.tile_pyramid(2, 1, &options).is_err());
+        assert!(table.tile_pyramid(0, MAX_TILE_ZOOM + 1, &options).is_err());
+    }
+}
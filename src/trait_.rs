@@ -9,6 +9,11 @@ use arrow_schema::{DataType, Field};
 use std::any::Any;
 use std::sync::Arc;
 
+/// A reference-counted, dynamically-typed geometry array, analogous to Arrow's [`ArrayRef`].
+///
+/// Use [`AsGeometryArray`][crate::array::AsGeometryArray] to downcast it to a concrete array.
+pub type GeometryArrayRef = Arc<dyn GeometryArrayTrait>;
+
 /// A trait of common methods that all geometry arrays in this crate implement.
 ///
 /// This trait is often used for downcasting. If you have a dynamically-typed `Arc<dyn
@@ -208,6 +213,10 @@ pub trait GeometryArrayAccessor<'a>: GeometryArrayTrait {
         Some(self.value_as_geo(i))
     }
 
+    /// Iterator over geoarrow scalar values, taking into account validity
+    ///
+    /// References to geometry arrays also implement [`IntoIterator`] with the same items, so they
+    /// can be used directly in a `for` loop.
     fn iter(&'a self) -> impl Iterator<Item = Option<Self::Item>> + 'a {
         (0..self.len()).map(|i| unsafe { self.get_unchecked(i) })
     }